@@ -0,0 +1,69 @@
+//! Times rendering a large grid of icons, both to the virtual DOM alone and to HTML with
+//! `dioxus-ssr`, and then re-rendering the grid after its fill color changes.
+//!
+//! Run this with `cargo bench --bench grid`.
+
+use dioxus::{dioxus_core::NoOpMutations, prelude::*};
+use dioxus_heroicons::{mini, outline, solid, Icon, IconSet};
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+const ICONS: usize = 1_200;
+const ROUNDS: u32 = 20;
+
+#[allow(non_snake_case)]
+#[component]
+fn IconGrid() -> Element {
+    let red = use_context::<Rc<Cell<bool>>>().get();
+    let fill = if red { "#DC2626" } else { "currentColor" };
+    let outline = outline::Shape::all().iter().cycle();
+    let solid = solid::Shape::all().iter().cycle();
+    let mini = mini::Shape::all().iter().cycle();
+    rsx! {
+        div {
+            for (i, ((o, s), m)) in outline.zip(solid).zip(mini).take(ICONS / 3).enumerate() {
+                span {
+                    key: "{i}",
+                    Icon { icon: *o, class: "h-6 w-6", fill }
+                    Icon { icon: *s, size: 24, fill }
+                    Icon { icon: *m, size: 16, fill: "currentColor" }
+                }
+            }
+        }
+    }
+}
+
+fn new_dom(red: Rc<Cell<bool>>) -> VirtualDom {
+    let mut dom = VirtualDom::new(IconGrid).with_root_context(red);
+    dom.rebuild_in_place();
+    dom
+}
+
+fn time(mut f: impl FnMut()) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    let red = Rc::new(Cell::new(false));
+    let expected = dioxus_ssr::render(&new_dom(red.clone())).len();
+    let rebuild = time(|| drop(new_dom(red.clone())));
+    let ssr = time(|| assert_eq!(dioxus_ssr::render(&new_dom(red.clone())).len(), expected));
+    let mut dom = new_dom(red.clone());
+    let rerender = time(|| {
+        red.set(!red.get());
+        dom.mark_dirty(ScopeId::APP);
+        dom.render_immediate(&mut NoOpMutations);
+    });
+    println!("rendering {ICONS} icons, averaged over {ROUNDS} renders");
+    println!("  virtual DOM:        {rebuild:?}");
+    println!("  server-side render: {ssr:?}");
+    println!("  change fill:        {rerender:?}");
+}
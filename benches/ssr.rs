@@ -0,0 +1,54 @@
+//! Compares server-side rendering of icons with and without `prerender`.
+//!
+//! Run this with `cargo bench --bench ssr`.
+
+use dioxus::prelude::*;
+use dioxus_heroicons::{outline, Icon, IconSet};
+use std::time::{Duration, Instant};
+
+const ROUNDS: u32 = 50;
+
+#[allow(non_snake_case)]
+#[component]
+fn IconGrid(prerender: bool) -> Element {
+    rsx! {
+        div {
+            for shape in outline::Shape::all() {
+                Icon {
+                    key: "{shape.name()}",
+                    icon: *shape,
+                    prerender,
+                }
+            }
+        }
+    }
+}
+
+fn render(prerender: bool) -> String {
+    let mut dom = VirtualDom::new_with_props(IconGrid, IconGridProps { prerender });
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+fn time(prerender: bool) -> Duration {
+    // The first render builds the cached HTML for each shape, so it's not timed.
+    let expected = render(prerender);
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        assert_eq!(render(prerender).len(), expected.len());
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    let count = outline::Shape::all().len();
+    let elements = time(false);
+    let prerendered = time(true);
+    println!("rendering {count} icons, averaged over {ROUNDS} renders");
+    println!("  path elements: {elements:?}");
+    println!("  prerendered:   {prerendered:?}");
+    println!(
+        "  speedup:       {:.2}x",
+        elements.as_secs_f64() / prerendered.as_secs_f64()
+    );
+}
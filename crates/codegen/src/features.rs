@@ -0,0 +1,45 @@
+// Reads the icon features that `gen` writes into a manifest between its markers.
+
+use std::collections::BTreeSet;
+
+/// The line in a manifest that the generated icon features start after.
+pub const FEATURES_START: &str = "# BEGIN GENERATED ICON FEATURES";
+/// The line in a manifest that the generated icon features end before.
+pub const FEATURES_END: &str = "# END GENERATED ICON FEATURES";
+/// The line before the features for the old names of icons that heroicons renamed, which come
+/// after the icon features.
+pub const RENAMES_START: &str =
+    "# The old names of icons that heroicons renamed, which enable them by their new names.";
+
+/// Returns the names of the icon features between the generated markers in the contents of a
+/// manifest, without `all-icons`, `components`, or the features for the old names of renamed icons.
+#[must_use]
+pub fn icon_features(manifest: &str) -> BTreeSet<String> {
+    manifest
+        .lines()
+        .skip_while(|l| *l != FEATURES_START)
+        .take_while(|l| *l != FEATURES_END && *l != RENAMES_START)
+        .filter(|l| !l.starts_with(' ') && !l.starts_with(']'))
+        .filter_map(|l| l.split_once(" = "))
+        .map(|(name, _)| name.to_string())
+        .filter(|name| name != "all-icons" && name != "components")
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn features_between_markers() {
+        let manifest = format!(
+            "[features]\ndefault = [\"all-icons\"]\n{FEATURES_START}\ncomponents = [\n    \"a\",\n]\n\
+             all-icons = [\n    \"a\",\n    \"b\",\n]\na = []\nb = []\n{RENAMES_START}\nold-b = [\"b\"]\n\
+             {FEATURES_END}\nother = []\n"
+        );
+        assert_eq!(
+            icon_features(&manifest),
+            ["a", "b"].iter().map(ToString::to_string).collect(),
+        );
+    }
+}
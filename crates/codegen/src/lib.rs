@@ -0,0 +1,1730 @@
+//! Generates Rust modules of icon shapes from a directory of SVG files, the same way that the
+//! [`dioxus-heroicons`](https://docs.rs/dioxus-heroicons) style crates are generated from
+//! heroicons.
+//!
+//! A generated module has a `Shape` enum with a variant for each SVG file, named after the file in
+//! `UpperCamelCase`, and a [`StaticShape`] static for each shape, named in `SHOUTY_SNAKE_CASE`. By
+//! default, the `Shape` enum also implements `IconSet` and `IconShape`, so its shapes work with all
+//! of the `dioxus-heroicons` components.
+//!
+//! This is meant to be called from a `build.rs` or an xtask. For example, to generate a module for
+//! the SVGs in an `icons` directory, put this in `build.rs`:
+//!
+//! ```rust,no_run
+//! use heroicons_codegen::Generator;
+//! use std::{env, path::PathBuf};
+//!
+//! let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+//! Generator::from_dir("icons")
+//!     .unwrap()
+//!     .write_module(out_dir.join("icons.rs"))
+//!     .unwrap();
+//! println!("cargo:rerun-if-changed=icons");
+//! ```
+//!
+//! And then in the app, `mod icons { include!(concat!(env!("OUT_DIR"), "/icons.rs")); }` defines
+//! the module, and `icons::Shape::Logo` can be passed to an `Icon`.
+//!
+//! [`StaticShape`]: https://docs.rs/dioxus-heroicons/latest/dioxus_heroicons/struct.StaticShape.html
+
+mod features;
+mod minify;
+mod path;
+mod transform;
+
+pub use features::{icon_features, FEATURES_END, FEATURES_START, RENAMES_START};
+pub use minify::minify_path_data;
+use transform::{transform_path_data, Transform};
+
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use itertools::Itertools;
+use scraper::{ElementRef, Html, Selector};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+};
+use walkdir::WalkDir;
+
+/// One icon, parsed from an SVG file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Icon {
+    /// The icon's name, which is its file name without the `.svg` extension, like "arrow-left".
+    pub name: String,
+    /// The name of the icon's `Shape` variant, like `ArrowLeft`.
+    pub variant: String,
+    /// The `viewBox` of the icon's `<svg>` element.
+    pub view_box: String,
+    /// The icon's `<path>` elements, in the order they're in in the SVG.
+    pub paths: Vec<IconPath>,
+}
+
+/// One `<path>` element of an icon. A `<circle>`, `<ellipse>`, `<line>`, `<polygon>`, `<polyline>`,
+/// or `<rect>` is converted to the path data that draws the same shape, so it becomes one of these
+/// too. Each path keeps its own attributes, since the paths in one SVG can have different rules,
+/// fills, and strokes. A shape's `transform`, and those of the `<g>` elements around it, are
+/// applied to its path data, and its `stroke-width` is scaled to match, so the path is drawn in the
+/// same place without them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IconPath {
+    /// The path's `d` attribute.
+    pub d: String,
+    /// The path's `clip-rule` attribute, if it has one.
+    pub clip_rule: Option<String>,
+    /// The path's `fill-rule` attribute, if it has one.
+    pub fill_rule: Option<String>,
+    /// The path's `fill` attribute, if it has one.
+    pub fill: Option<String>,
+    /// The path's `fill-opacity` attribute, if it has one.
+    pub fill_opacity: Option<String>,
+    /// The path's `opacity` attribute, if it has one.
+    pub opacity: Option<String>,
+    /// The path's `stroke-width` attribute, if it has one.
+    pub stroke_width: Option<String>,
+    /// The path's `stroke-linecap` attribute, if it has one.
+    pub stroke_linecap: Option<String>,
+    /// The path's `stroke-linejoin` attribute, if it has one.
+    pub stroke_linejoin: Option<String>,
+}
+
+impl IconPath {
+    /// Returns the path's attributes other than `d`, by their SVG names, like `("fill-rule",
+    /// "evenodd")`. Attributes that the path doesn't have are left out.
+    #[must_use]
+    pub fn attributes(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("clip-rule", &self.clip_rule),
+            ("fill-rule", &self.fill_rule),
+            ("fill", &self.fill),
+            ("fill-opacity", &self.fill_opacity),
+            ("opacity", &self.opacity),
+            ("stroke-width", &self.stroke_width),
+            ("stroke-linecap", &self.stroke_linecap),
+            ("stroke-linejoin", &self.stroke_linejoin),
+        ]
+        .iter()
+        .filter_map(|(name, value)| Some((*name, value.as_deref()?)))
+        .collect()
+    }
+}
+
+impl Icon {
+    /// Returns the name of the icon's static, like `ARROW_LEFT`.
+    #[must_use]
+    pub fn const_name(&self) -> String {
+        self.name.to_shouty_snake_case()
+    }
+
+    // The name of the module that holds the icon's static when the module is split into one file per
+    // initial letter.
+    fn letter(&self) -> String {
+        match self.name.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() => c.to_ascii_lowercase().to_string(),
+            _ => "other".to_string(),
+        }
+    }
+
+    // The name of the module that holds the icon's static when each icon has its own file, like
+    // `arrow_left`. Names that aren't valid identifiers, like keywords or ones that start with a
+    // digit, get a `shape_` prefix.
+    fn module_name(&self) -> String {
+        let name = self.name.to_snake_case();
+        if syn::parse_str::<syn::Ident>(&name).is_ok() {
+            name
+        } else {
+            format!("shape_{name}")
+        }
+    }
+}
+
+/// How [`Generator::write_split`] splits the module into files.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Everything goes in the root file, like the code from
+    /// [`module_code`](Generator::module_code).
+    File,
+    /// The statics go in one file per initial letter, like `a.rs`. This is the default.
+    #[default]
+    Letter,
+    /// The statics go in one file per icon, like `arrow_left.rs`, so that regenerating one icon
+    /// only changes its own file.
+    Icon,
+}
+
+/// Parses every SVG file in a directory and its subdirectories, sorted by name.
+///
+/// The files are split into one chunk per CPU, and each chunk is parsed on its own thread.
+///
+/// # Errors
+///
+/// This returns an error if the directory can't be read, or for the first file that can't be read,
+/// doesn't have an `<svg>` element with a `viewBox` and at least one shape, or has a shape or
+/// transform that's invalid. Each error starts with the path of the file that it's about. Use
+/// [`parse_icons_lenient`] to get all of the errors instead.
+///
+/// # Panics
+///
+/// This panics if parsing a file panics on its thread.
+pub fn parse_icons(dir: impl AsRef<Path>) -> io::Result<Vec<Icon>> {
+    let (icons, errors) = parse_icons_lenient(dir);
+    match errors.into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(icons),
+    }
+}
+
+/// Parses every SVG file in a directory like [`parse_icons`], but keeps going after a file that
+/// can't be parsed. This returns the icons from the files that could be parsed, along with an
+/// error for each file that couldn't, so that a caller can report every problem at once or skip
+/// the invalid files.
+///
+/// # Panics
+///
+/// This panics if parsing a file panics on its thread.
+pub fn parse_icons_lenient(dir: impl AsRef<Path>) -> (Vec<Icon>, Vec<io::Error>) {
+    let mut errors = vec![];
+    let files = WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.map_err(|e| errors.push(e.into())).ok())
+        .filter(|e| e.file_type().is_file() && e.file_name().to_string_lossy().ends_with(".svg"))
+        .sorted_by(|a, b| Ord::cmp(a.file_name(), b.file_name()))
+        .map(walkdir::DirEntry::into_path)
+        .collect::<Vec<_>>();
+    let threads = thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+    let chunk_size = files.len().div_ceil(threads).max(1);
+
+    let results = thread::scope(|s| {
+        let chunks = files
+            .chunks(chunk_size)
+            .map(|chunk| {
+                s.spawn(move || {
+                    let svg_sel = Selector::parse("svg").unwrap();
+                    let shape_sel = Selector::parse(SHAPE_ELEMENTS).unwrap();
+                    chunk
+                        .iter()
+                        .map(|file| parse_icon(file, &svg_sel, &shape_sel))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        chunks
+            .into_iter()
+            .flat_map(|chunk| chunk.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+    let mut icons = vec![];
+    for result in results {
+        match result {
+            Ok(icon) => icons.push(icon),
+            Err(e) => errors.push(e),
+        }
+    }
+    (icons, errors)
+}
+
+fn parse_icon(file: &Path, svg_sel: &Selector, shape_sel: &Selector) -> io::Result<Icon> {
+    let invalid = |what: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} {}", file.display(), what),
+        )
+    };
+
+    let name = file
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| invalid("does not have a UTF-8 name"))?
+        .to_string();
+    let content = fs::read_to_string(file)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", file.display())))?;
+    let frag = Html::parse_fragment(&content);
+    let svg = frag
+        .select(svg_sel)
+        .next()
+        .ok_or_else(|| invalid("does not have an <svg> element"))?;
+    let view_box = svg
+        .value()
+        .attr("viewBox")
+        .ok_or_else(|| invalid("does not have a viewBox"))?;
+    let paths = svg
+        .select(shape_sel)
+        .map(|e| {
+            let attr = |name| e.value().attr(name).map(str::to_string);
+            let transform = element_transform(e).map_err(|e| invalid(&e))?;
+            let mut d =
+                shape_d(e.value().name(), |name| e.value().attr(name)).map_err(|e| invalid(&e))?;
+            let mut stroke_width = attr("stroke-width");
+            if transform != Transform::IDENTITY {
+                d = transform_path_data(&d, transform).ok_or_else(|| {
+                    invalid(&format!(
+                        "has a <{}> with invalid path data",
+                        e.value().name()
+                    ))
+                })?;
+                stroke_width = stroke_width.map(|w| match w.trim().parse::<f64>() {
+                    Ok(w) => (w * transform.scale()).to_string(),
+                    Err(_) => w,
+                });
+            }
+            Ok(IconPath {
+                d,
+                clip_rule: attr("clip-rule"),
+                fill_rule: attr("fill-rule"),
+                fill: attr("fill"),
+                fill_opacity: attr("fill-opacity"),
+                opacity: attr("opacity"),
+                stroke_width,
+                stroke_linecap: attr("stroke-linecap"),
+                stroke_linejoin: attr("stroke-linejoin"),
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    if paths.is_empty() {
+        return Err(invalid("does not have any paths"));
+    }
+
+    Ok(Icon {
+        variant: name.to_upper_camel_case(),
+        name,
+        view_box: view_box.to_string(),
+        paths,
+    })
+}
+
+// Formats generated code with `prettyplease`, so that it's the same everywhere without depending
+// on a `rustfmt` binary. Code that can't be parsed won't compile either, so it's returned as it is,
+// and the compiler can point out the problem.
+fn format_code(code: &str) -> String {
+    syn::parse_file(code).map_or_else(|_| code.to_string(), |file| prettyplease::unparse(&file))
+}
+
+// Returns the transform for a shape element, which combines its own `transform` attribute with
+// those of the `<g>` elements around it, so that its path data can be drawn without them.
+fn element_transform(element: ElementRef<'_>) -> Result<Transform, String> {
+    std::iter::once(element)
+        .chain(element.ancestors().filter_map(ElementRef::wrap))
+        .take_while(|e| e.value().name() != "svg")
+        .try_fold(Transform::IDENTITY, |inner, e| {
+            match e.value().attr("transform") {
+                Some(t) => Transform::parse(t).map(|t| t.then(inner)).ok_or_else(|| {
+                    format!(
+                        "has a <{}> with an invalid transform: {t}",
+                        e.value().name()
+                    )
+                }),
+                None => Ok(inner),
+            }
+        })
+}
+
+// The SVG elements that draw shapes. Everything but `<path>` is converted to path data, so every
+// shape is rendered as a `<path>`.
+const SHAPE_ELEMENTS: &str = "path, circle, ellipse, line, polygon, polyline, rect";
+
+// Returns the path data for a shape element, which is the `d` attribute of a `<path>`, or the
+// equivalent of one of the other shape elements. The `attr` function returns the element's
+// attributes.
+fn shape_d<'a>(element: &str, attr: impl Fn(&str) -> Option<&'a str>) -> Result<String, String> {
+    let num = |name: &str| -> Result<f64, String> {
+        attr(name).map_or(Ok(0.0), |v| {
+            v.trim()
+                .parse()
+                .map_err(|_| format!("has a <{element}> with an invalid {name}: {v}"))
+        })
+    };
+    let d = match element {
+        "path" => attr("d")
+            .ok_or_else(|| "has a <path> without a d attribute".to_string())?
+            .to_string(),
+        "circle" => {
+            let r = num("r")?;
+            ellipse_d(num("cx")?, num("cy")?, r, r)
+        }
+        "ellipse" => ellipse_d(num("cx")?, num("cy")?, num("rx")?, num("ry")?),
+        "line" => format!(
+            "M{} {}L{} {}",
+            num("x1")?,
+            num("y1")?,
+            num("x2")?,
+            num("y2")?,
+        ),
+        "polygon" | "polyline" => {
+            let points = attr("points")
+                .unwrap_or_default()
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|p| !p.is_empty())
+                .map(|p| {
+                    p.parse::<f64>()
+                        .map_err(|_| format!("has a <{element}> with an invalid point: {p}"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if points.len() % 2 != 0 {
+                return Err(format!(
+                    "has a <{element}> with an odd number of coordinates"
+                ));
+            }
+            if points.len() < 4 {
+                return Err(format!("has a <{element}> with fewer than two points"));
+            }
+            let lines = points
+                .chunks(2)
+                .map(|p| format!("{} {}", p[0], p[1]))
+                .join("L");
+            let close = if element == "polygon" { "Z" } else { "" };
+            format!("M{lines}{close}")
+        }
+        "rect" => {
+            let (x, y, w, h) = (num("x")?, num("y")?, num("width")?, num("height")?);
+            // A missing `rx` or `ry` is the same as the other one, and neither can be more than
+            // half the rect's size.
+            let (rx, ry) = match (attr("rx"), attr("ry")) {
+                (None, None) => (0.0, 0.0),
+                (Some(_), None) => (num("rx")?, num("rx")?),
+                (None, Some(_)) => (num("ry")?, num("ry")?),
+                (Some(_), Some(_)) => (num("rx")?, num("ry")?),
+            };
+            let (rx, ry) = (rx.min(w / 2.0), ry.min(h / 2.0));
+            if rx > 0.0 && ry > 0.0 {
+                let (iw, ih) = (w - 2.0 * rx, h - 2.0 * ry);
+                format!(
+                    "M{} {y}h{iw}a{rx} {ry} 0 0 1 {rx} {ry}v{ih}a{rx} {ry} 0 0 1 -{rx} {ry}\
+                     h-{iw}a{rx} {ry} 0 0 1 -{rx} -{ry}v-{ih}a{rx} {ry} 0 0 1 {rx} -{ry}Z",
+                    x + rx,
+                )
+            } else {
+                format!("M{x} {y}h{w}v{h}h-{w}Z")
+            }
+        }
+        _ => return Err(format!("has an unsupported <{element}> element")),
+    };
+    Ok(d)
+}
+
+// Returns the path data for an ellipse, which is drawn as two arcs, since one arc can't start and
+// end at the same point.
+fn ellipse_d(cx: f64, cy: f64, rx: f64, ry: f64) -> String {
+    format!(
+        "M{} {cy}a{rx} {ry} 0 1 0 {} 0a{rx} {ry} 0 1 0 -{} 0Z",
+        cx - rx,
+        2.0 * rx,
+        2.0 * rx,
+    )
+}
+
+/// Generates the code for a module of icon shapes.
+///
+/// The code uses the `PathData` and `StaticShape` types from [`types_path`](Generator::types_path)
+/// and, unless [`dioxus_impls`](Generator::dioxus_impls) is turned off, the `IconSet` and
+/// `IconShape` traits too. When more than one path has the same data, like a shape that's in a set
+/// under two names, the data goes in a shared const that those paths refer to.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    icons: Vec<Icon>,
+    types_path: String,
+    feature_gates: bool,
+    allow_list: bool,
+    dioxus_impls: bool,
+    tags: BTreeMap<String, Vec<String>>,
+    renames: BTreeMap<String, String>,
+    minify: Option<u32>,
+    discriminants: BTreeMap<String, u32>,
+    layout: Layout,
+    root_file: String,
+    directional: BTreeSet<String>,
+}
+
+// Path data that more than one path uses, with the name of its const and the icons that use it.
+struct SharedPath<'a> {
+    name: String,
+    icons: Vec<&'a Icon>,
+}
+
+impl Generator {
+    /// Creates a generator for the given icons.
+    #[must_use]
+    pub fn new(icons: Vec<Icon>) -> Self {
+        Self {
+            icons,
+            types_path: "::dioxus_heroicons".to_string(),
+            feature_gates: false,
+            allow_list: false,
+            dioxus_impls: true,
+            tags: BTreeMap::new(),
+            renames: BTreeMap::new(),
+            minify: None,
+            discriminants: BTreeMap::new(),
+            layout: Layout::default(),
+            root_file: "lib.rs".to_string(),
+            directional: BTreeSet::new(),
+        }
+    }
+
+    /// Creates a generator for the SVG files in a directory, as parsed by [`parse_icons`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if one of the files can't be parsed.
+    pub fn from_dir(dir: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(parse_icons(dir)?))
+    }
+
+    /// Returns the icons that the generator will write.
+    #[must_use]
+    pub fn icons(&self) -> &[Icon] {
+        &self.icons
+    }
+
+    /// Sets the path of the crate or module that the generated code gets its types and traits
+    /// from. This defaults to `::dioxus_heroicons`.
+    #[must_use]
+    pub fn types_path(mut self, path: impl Into<String>) -> Self {
+        self.types_path = path.into();
+        self
+    }
+
+    /// Sets whether each shape is behind a cargo feature with the same name as the icon, like
+    /// `arrow-left`. This is off by default.
+    #[must_use]
+    pub fn feature_gates(mut self, on: bool) -> Self {
+        self.feature_gates = on;
+        self
+    }
+
+    /// Sets whether a shape whose feature is off is still compiled when its icon is in an
+    /// `icons.toml` allow-list. This is off by default, and only matters when
+    /// [`feature_gates`](Generator::feature_gates) is on.
+    ///
+    /// The allow-list is read by `dioxus_heroicons_core::allow_list::configure`, which the crate's
+    /// build script must call. It sets a `heroicon = "name"` cfg for each listed icon.
+    #[must_use]
+    pub fn allow_list(mut self, on: bool) -> Self {
+        self.allow_list = on;
+        self
+    }
+
+    /// Sets whether the `Shape` enum implements `IconSet` and `IconShape`. This is on by default.
+    /// The generated impls refer to `dioxus::prelude::Element`, so the crate that uses the module
+    /// must depend on `dioxus`.
+    #[must_use]
+    pub fn dioxus_impls(mut self, on: bool) -> Self {
+        self.dioxus_impls = on;
+        self
+    }
+
+    /// Sets the tags for the icons, keyed by icon name, like `"trash" => ["garbage", "delete"]`.
+    ///
+    /// Each `Shape` variant gets a `#[doc(alias)]` with its icon's original name and its tags, so
+    /// that searching the docs or completing in an editor finds `Trash` when typing "garbage" or
+    /// "trash-can". Spaces in a tag become hyphens, since an alias can't contain them. The tags are
+    /// also returned by `Shape::tags`, and by `IconSet::tags` unless
+    /// [`dioxus_impls`](Generator::dioxus_impls) is turned off, so that apps can search them.
+    #[must_use]
+    pub fn tags(mut self, tags: BTreeMap<String, Vec<String>>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Sets the names of the icons that point or read in a direction, like `arrow-left` or
+    /// `list-bullet`, for which `Shape::is_directional` returns true. A layout for a right-to-left
+    /// language should mirror these shapes, and only these, so that an arrow that means "back"
+    /// still points back. Names that aren't one of the generator's icons are ignored. This is
+    /// empty by default.
+    #[must_use]
+    pub fn directional(mut self, names: BTreeSet<String>) -> Self {
+        self.directional = names;
+        self
+    }
+
+    /// Sets the old names of icons that were renamed, keyed by old name, like
+    /// `"arrow-left-on-rectangle" => "arrow-left-start-on-rectangle"`.
+    ///
+    /// For each icon that's been renamed, `Shape` gets a deprecated associated const with the old
+    /// variant name, like `Shape::ArrowLeftOnRectangle`, that's the same as the new variant, so code
+    /// that uses the old name still compiles but gets a warning that says what to use instead. The
+    /// new variant also gets the old name as a `#[doc(alias)]`. Renames whose new icon isn't one of
+    /// the generator's icons, or whose old icon still is, are ignored.
+    #[must_use]
+    pub fn renames(mut self, renames: BTreeMap<String, String>) -> Self {
+        self.renames = renames;
+        self
+    }
+
+    /// Sets whether the path data is minified with [`minify_path_data`], which rounds its numbers to
+    /// the given number of decimal places and drops the separators and command letters that it
+    /// doesn't need. This can make an app with hundreds of icons noticeably smaller, but the shapes
+    /// change slightly if `precision` is less than the number of decimal places in the SVGs. Path
+    /// data that can't be parsed is left as it is. This is off by default.
+    #[must_use]
+    pub fn minify(mut self, precision: Option<u32>) -> Self {
+        self.minify = precision;
+        self
+    }
+
+    /// Sets the discriminants that icons were given the last time that the code was generated,
+    /// keyed by icon name, like the ones returned by
+    /// [`all_discriminants`](Generator::all_discriminants).
+    ///
+    /// Each `Shape` variant has an explicit discriminant, so that a shape that's been stored as a
+    /// number, like `Shape::ArrowLeft as u32`, is still the same shape after the code is
+    /// regenerated with new icons. An icon keeps the discriminant that it's given here, and an icon
+    /// that isn't given one gets the next one after the highest discriminant here, in alphabetical
+    /// order. The discriminants of icons that have been removed are never reused, as long as they
+    /// stay in this map. Without this, the icons are numbered from 0 in alphabetical order.
+    #[must_use]
+    pub fn discriminants(mut self, discriminants: BTreeMap<String, u32>) -> Self {
+        self.discriminants = discriminants;
+        self
+    }
+
+    /// Sets how [`write_split`](Generator::write_split) splits the module into files. This defaults
+    /// to [`Layout::Letter`].
+    #[must_use]
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the name of the file that [`write_split`](Generator::write_split) writes the rest of
+    /// the module to. This defaults to `lib.rs`, for a crate of its own. Use `mod.rs` to write the
+    /// module to a directory in another crate, like `src/solid/mod.rs`.
+    #[must_use]
+    pub fn root_file(mut self, name: impl Into<String>) -> Self {
+        self.root_file = name.into();
+        self
+    }
+
+    /// Returns the discriminants from [`discriminants`](Generator::discriminants), plus the ones
+    /// that the generator gives to the icons that didn't have one, keyed by icon name. Save these
+    /// and pass them to `discriminants` the next time the code is generated to keep them stable.
+    #[must_use]
+    pub fn all_discriminants(&self) -> BTreeMap<String, u32> {
+        let mut discriminants = self.discriminants.clone();
+        let mut next = discriminants.values().max().map_or(0, |d| d + 1);
+        for icon in &self.icons {
+            discriminants.entry(icon.name.clone()).or_insert_with(|| {
+                next += 1;
+                next - 1
+            });
+        }
+        discriminants
+    }
+
+    /// Returns the code for the module as a single file, without any inner attributes or doc
+    /// comments, so it can be used with `include!`.
+    #[must_use]
+    pub fn module_code(&self) -> String {
+        let shared = self.shared_paths();
+        let statics = self
+            .icons
+            .iter()
+            .map(|i| self.static_code(i, "", &shared))
+            .collect::<String>();
+        format_code(&self.main_code(&statics, Icon::const_name, "", &shared))
+    }
+
+    /// Writes the code for the module as a single file, like [`module_code`](Self::module_code).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the file can't be written.
+    pub fn write_module(&self, file: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(file, self.module_code())
+    }
+
+    /// Writes the module to the given directory, split into files according to the
+    /// [`layout`](Generator::layout). The statics go in one file per initial letter or per icon,
+    /// which keeps each file small, and the rest goes in the [`root_file`](Generator::root_file).
+    /// The `header`, such as the crate's `//!` docs, goes at the top of the root file. This removes
+    /// the directory first, if it exists, and returns the path to the root file.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the directory can't be removed or created, or if a file can't be
+    /// written.
+    pub fn write_split(&self, dir: impl AsRef<Path>, header: &str) -> io::Result<PathBuf> {
+        let dir = dir.as_ref();
+        if dir.exists() {
+            fs::remove_dir_all(dir)?;
+        }
+        self.write_split_matching(dir, header, |_| true)
+    }
+
+    /// Writes the module like [`write_split`](Self::write_split), but only the root file and the
+    /// files for the letters or icons that have an icon that `filter` matches. The directory's
+    /// other files are left alone, so this is quicker when only some of the icons need to be
+    /// regenerated, and it doesn't touch the files that are already up to date.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the directory can't be created, or if a file can't be written.
+    pub fn write_split_matching(
+        &self,
+        dir: impl AsRef<Path>,
+        header: &str,
+        filter: impl Fn(&Icon) -> bool,
+    ) -> io::Result<PathBuf> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let shared = self.shared_paths();
+        let code = match self.layout {
+            Layout::File => {
+                let statics = self
+                    .icons
+                    .iter()
+                    .map(|i| self.static_code(i, "", &shared))
+                    .collect::<String>();
+                self.main_code(&statics, Icon::const_name, "", &shared)
+            }
+            Layout::Letter => self.write_statics(
+                dir,
+                &filter,
+                &shared,
+                Icon::letter,
+                |letter, _| format!("The shapes whose names start with \"{letter}\"."),
+                "initial letter",
+            )?,
+            Layout::Icon => self.write_statics(
+                dir,
+                &filter,
+                &shared,
+                Icon::module_name,
+                |_, icons| {
+                    format!(
+                        "The {} shape.",
+                        icons
+                            .iter()
+                            .map(|i| format!("\"{}\"", i.name))
+                            .join(" and "),
+                    )
+                },
+                "shape",
+            )?,
+        };
+        let root = dir.join(&self.root_file);
+        fs::write(
+            &root,
+            format!("{}\n\n{}", header.trim_end(), format_code(&code)),
+        )?;
+        Ok(root)
+    }
+
+    // Writes the statics to one file per module, as named by `module`, for the modules that have an
+    // icon that `filter` matches, and returns the code for the root file. Each file's docs start
+    // with the `doc` for its module's name and icons, and the `per` says what there's one module per.
+    fn write_statics(
+        &self,
+        dir: &Path,
+        filter: impl Fn(&Icon) -> bool,
+        shared: &BTreeMap<String, SharedPath<'_>>,
+        module: impl Fn(&Icon) -> String,
+        doc: impl Fn(&str, &[&Icon]) -> String,
+        per: &str,
+    ) -> io::Result<String> {
+        let mut by_module = BTreeMap::new();
+        for icon in &self.icons {
+            by_module
+                .entry(module(icon))
+                .or_insert_with(Vec::new)
+                .push(icon);
+        }
+        for (name, icons) in &by_module {
+            if !icons.iter().any(|i| filter(i)) {
+                continue;
+            }
+            let statics = icons
+                .iter()
+                .map(|i| self.static_code(i, "super::", shared))
+                .collect::<String>();
+            let code = format!("//! {}\n{statics}", doc(name, icons));
+            fs::write(dir.join(format!("{name}.rs")), format_code(&code))?;
+        }
+        // Some of these modules are empty when the features for their shapes are off, so their
+        // imports are unused.
+        let mods = format!(
+            "{}\n\n\
+             #[allow(unused_imports)]\n\
+             pub use self::{{{}}};\n",
+            by_module.keys().map(|m| format!("mod {m};")).join("\n"),
+            by_module.keys().map(|m| format!("{m}::*")).join(", "),
+        );
+
+        Ok(self.main_code(
+            &mods,
+            |i| format!("{}::{}", module(i), i.const_name()),
+            &format!(" The statics are split into one\n/// module per {per}, which keeps each file small."),
+            shared,
+        ))
+    }
+
+    /// Returns an SVG sprite sheet with a `<symbol>` for each icon, so that an app can serve its
+    /// icons as one cacheable file. The `id` of each symbol is the `prefix`, a hyphen, and the
+    /// icon's name, like "heroicon-arrow-left". This is the same as the sheets that
+    /// `dioxus_heroicons::write_sprite_sheet` writes, so a `UseIcon` with its `sprite_url` set to
+    /// the sheet's URL can show an icon from it.
+    #[must_use]
+    pub fn sprite_svg(&self, prefix: &str) -> String {
+        let symbols = self
+            .icons
+            .iter()
+            .map(|icon| {
+                let paths = icon
+                    .paths
+                    .iter()
+                    .map(|p| {
+                        let attributes = p
+                            .attributes()
+                            .iter()
+                            .map(|(name, value)| format!(r#" {name}="{value}""#))
+                            .join("");
+                        format!(r#"<path d="{}"{attributes}></path>"#, self.path_d(p))
+                    })
+                    .join("");
+                format!(
+                    r#"<symbol id="{prefix}-{}" viewBox="{}">{paths}</symbol>"#,
+                    icon.name, icon.view_box,
+                )
+            })
+            .join("\n");
+        format!("<svg xmlns=\"http://www.w3.org/2000/svg\">\n{symbols}\n</svg>\n")
+    }
+
+    /// Returns the code for a module with a constant for the `id` of each symbol in the
+    /// [`sprite_svg`](Generator::sprite_svg) sheet, named after its icon, like `ARROW_LEFT`, and a
+    /// `SYMBOL_IDS` constant with all of them. Like [`module_code`](Generator::module_code), this
+    /// can be used with `include!`.
+    #[must_use]
+    pub fn sprite_ids_code(&self, prefix: &str) -> String {
+        let consts = self
+            .icons
+            .iter()
+            .map(|i| {
+                format!(
+                    "/// The `id` of the symbol for the \"{0}\" shape.\n\
+                     pub const {1}: &str = \"{prefix}-{0}\";\n",
+                    i.name,
+                    i.const_name(),
+                )
+            })
+            .join("");
+        let code = format!(
+            "{consts}\n/// The `id`s of all the symbols in the sprite sheet.\n\
+             pub const SYMBOL_IDS: &[&str] = &[{}];\n",
+            self.icons.iter().map(Icon::const_name).join(", "),
+        );
+        format!(
+            "// This file was written by heroicons-codegen.\n\n{}",
+            format_code(&code),
+        )
+    }
+
+    /// Writes the [`sprite_svg`](Generator::sprite_svg) sheet and the
+    /// [`sprite_ids_code`](Generator::sprite_ids_code) module.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if either file can't be written.
+    pub fn write_sprite(
+        &self,
+        prefix: &str,
+        svg_file: impl AsRef<Path>,
+        rust_file: impl AsRef<Path>,
+    ) -> io::Result<()> {
+        fs::write(svg_file, self.sprite_svg(prefix))?;
+        fs::write(rust_file, self.sprite_ids_code(prefix))
+    }
+
+    // Returns the view box that the most icons use, which is the `VIEW_BOX` const, and whether all
+    // of the icons use it. Ties go to the view box that sorts first, so the choice is stable.
+    fn common_view_box(&self) -> Option<(&str, bool)> {
+        let counts = self.icons.iter().map(|i| i.view_box.as_str()).counts();
+        let (view_box, count) = counts
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))?;
+        Some((view_box, *count == self.icons.len()))
+    }
+
+    // Returns the path data that more than one path uses, after it's minified, by the data. Each
+    // one is named after the first path that uses it, so the names don't change when other shapes
+    // are added or removed.
+    fn shared_paths(&self) -> BTreeMap<String, SharedPath<'_>> {
+        let mut paths = BTreeMap::new();
+        for icon in &self.icons {
+            for (i, path) in icon.paths.iter().enumerate() {
+                paths
+                    .entry(self.path_d(path))
+                    .or_insert_with(|| SharedPath {
+                        name: format!("SHARED_{}_{i}", icon.const_name()),
+                        icons: vec![],
+                    })
+                    .icons
+                    .push(icon);
+            }
+        }
+        paths.retain(|_, p| p.icons.len() > 1);
+        paths
+    }
+
+    // Returns the code for everything but the statics, which are in `statics`.
+    fn main_code(
+        &self,
+        statics: &str,
+        static_path: impl Fn(&Icon) -> String,
+        split_note: &str,
+        shared: &BTreeMap<String, SharedPath<'_>>,
+    ) -> String {
+        let discriminants = self.all_discriminants();
+        let names = self
+            .icons
+            .iter()
+            .map(|i| {
+                format!(
+                    "{}{}{} = {},\n",
+                    self.cfg(i),
+                    self.doc_alias(i),
+                    i.variant,
+                    discriminants[&i.name],
+                )
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        let all = self
+            .icons
+            .iter()
+            .map(|i| format!("{}Shape::{},\n", self.cfg(i), i.variant))
+            .collect::<Vec<_>>()
+            .concat();
+        // Each discriminant up to the highest one gets a slot, so that a shape's entry is at its
+        // discriminant. The slots for removed icons, and for shapes whose features are off, are
+        // `None`.
+        let by_discriminant = self
+            .icons
+            .iter()
+            .map(|i| (discriminants[&i.name], i))
+            .collect::<BTreeMap<_, _>>();
+        let entries = (0..discriminants.values().max().map_or(0, |d| d + 1))
+            .map(|d| match by_discriminant.get(&d) {
+                Some(i) => {
+                    let tags = self.icon_tags(i).map(|t| format!("{t:?}")).join(", ");
+                    let entry = format!(
+                        "Some((Shape::{}, &{}, &[{tags}], {})),\n",
+                        i.variant,
+                        static_path(i),
+                        self.directional.contains(&i.name),
+                    );
+                    match self.cfg_predicate(i) {
+                        Some(p) => format!("#[cfg({p})]\n{entry}#[cfg(not({p}))]\nNone,\n"),
+                        None => entry,
+                    }
+                }
+                None => "None,\n".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        let (view_box_const, view_box) = match self.common_view_box() {
+            Some((v, true)) => (
+                format!(
+                    "/// The `viewBox` for the `<svg>` element of every shape.\n\
+                     pub const VIEW_BOX: &str = \"{v}\";\n",
+                ),
+                "VIEW_BOX",
+            ),
+            Some((v, false)) => (
+                format!(
+                    "/// The `viewBox` for the `<svg>` element of most shapes. Some shapes have \
+                     their own, so use\n/// [`Shape::view_box`] or [`StaticShape::view_box`] \
+                     to get the one for a shape.\n\
+                     pub const VIEW_BOX: &str = \"{v}\";\n",
+                ),
+                "self.entry().1.view_box()",
+            ),
+            None => (String::new(), "self.entry().1.view_box()"),
+        };
+        let renames = self
+            .icons
+            .iter()
+            .flat_map(|i| self.old_names(i).map(move |old| (old, i)))
+            .map(|(old, i)| {
+                RENAME_TEMPLATE
+                    .replace("{OLD_NAME}", old)
+                    .replace("{OLD_VARIANT}", &old.to_upper_camel_case())
+                    .replace("{NAME}", &i.name)
+                    .replace("{VARIANT}", &i.variant)
+                    .replace("{CFG}", &self.cfg(i))
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        let dioxus_impls = if self.dioxus_impls {
+            DIOXUS_IMPLS_TEMPLATE.replace("{TYPES}", &self.types_path)
+        } else {
+            String::new()
+        };
+
+        MODULE_TEMPLATE
+            .trim_start()
+            .replace("{TYPES}", &self.types_path)
+            .replace("{STATICS}", statics)
+            .replace("{VIEW_BOX_CONST}", &view_box_const)
+            .replace("{SHARED_PATHS}", &shared_paths_code(shared))
+            .replace("{VIEW_BOX}", view_box)
+            .replace("{NAMES}", &names)
+            .replace("{ALL}", &all)
+            .replace("{RENAMES}", &renames)
+            .replace("{DIOXUS_IMPLS}", &dioxus_impls)
+            .replace("{SPLIT_NOTE}", split_note)
+            .replace("{ENTRIES}", &entries)
+    }
+
+    // Returns the code for an icon's static. The `prefix` is the path to the module with the
+    // imported types and the `shared` path data, relative to the module that the static is in.
+    fn static_code(
+        &self,
+        icon: &Icon,
+        prefix: &str,
+        shared: &BTreeMap<String, SharedPath<'_>>,
+    ) -> String {
+        let view_box = match self.common_view_box() {
+            Some((v, _)) if v == icon.view_box => format!("{prefix}VIEW_BOX"),
+            _ => format!("\"{}\"", icon.view_box),
+        };
+        let paths = icon
+            .paths
+            .iter()
+            .map(|p| {
+                let attributes = p
+                    .attributes()
+                    .iter()
+                    .map(|(name, value)| format!("{}: Some(\"{value}\"),\n", name.to_snake_case()))
+                    .collect::<Vec<_>>()
+                    .concat();
+                let d = self.path_d(p);
+                let d = shared
+                    .get(&d)
+                    .map_or_else(|| format!("\"{d}\""), |s| format!("{prefix}{}", s.name));
+                PATH_TEMPLATE
+                    .replace("{D}", &d)
+                    .replace("{ATTRIBUTES}", &attributes)
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        STATIC_TEMPLATE
+            .replace("{CFG}", &self.cfg(icon))
+            .replace("{NAME}", &icon.name)
+            .replace("{CONST}", &icon.const_name())
+            .replace("{VIEW_BOX}", &view_box)
+            .replace("{PATHS}", &paths)
+            .replace("{PREFIX}", prefix)
+    }
+
+    // Returns a path's data, minified if the generator minifies it.
+    fn path_d(&self, path: &IconPath) -> String {
+        self.minify
+            .and_then(|precision| minify_path_data(&path.d, precision))
+            .unwrap_or_else(|| path.d.clone())
+    }
+
+    // Returns an icon's tags, without any blank or repeated tags, or tags that are just its name.
+    fn icon_tags<'a>(&'a self, icon: &'a Icon) -> impl Iterator<Item = &'a str> {
+        self.tags
+            .get(&icon.name)
+            .into_iter()
+            .flatten()
+            .map(|t| t.trim())
+            .filter(move |t| !t.is_empty() && !t.eq_ignore_ascii_case(&icon.name))
+            .unique()
+    }
+
+    // Returns the old names of an icon that were renamed to its name.
+    fn old_names<'a>(&'a self, icon: &'a Icon) -> impl Iterator<Item = &'a str> {
+        self.renames
+            .iter()
+            .filter(move |(old, new)| {
+                **new == icon.name && !self.icons.iter().any(|i| i.name == **old)
+            })
+            .map(|(old, _)| old.as_str())
+    }
+
+    // Returns the `#[doc(alias)]` attribute, plus a newline, with the icon's original name, any old
+    // names it was renamed from, and its tags. Aliases that only differ from the variant name by case are left out, since the docs
+    // search is case-insensitive anyway.
+    fn doc_alias(&self, icon: &Icon) -> String {
+        let aliases = std::iter::once(icon.name.as_str())
+            .chain(self.old_names(icon))
+            .chain(
+                self.tags
+                    .get(&icon.name)
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str),
+            )
+            .map(|a| a.split_whitespace().join("-").replace(['"', '\''], ""))
+            .filter(|a| !a.is_empty() && !a.eq_ignore_ascii_case(&icon.variant))
+            .unique()
+            .map(|a| format!("\"{a}\""))
+            .collect::<Vec<_>>();
+        if aliases.is_empty() {
+            String::new()
+        } else {
+            format!("#[doc(alias({}))]\n", aliases.join(", "))
+        }
+    }
+
+    // Returns the `#[cfg]` attribute, plus a newline, that gates an icon behind its feature and
+    // maybe the allow-list, or nothing if the icons aren't gated.
+    fn cfg(&self, icon: &Icon) -> String {
+        self.cfg_predicate(icon)
+            .map_or_else(String::new, |p| format!("#[cfg({p})]\n"))
+    }
+
+    // Returns the predicate for an icon's `#[cfg]` attribute, or `None` if the icons aren't gated.
+    fn cfg_predicate(&self, icon: &Icon) -> Option<String> {
+        match (self.feature_gates, self.allow_list) {
+            (true, true) => Some(format!(
+                "any(feature = \"{0}\", heroicon = \"{0}\")",
+                icon.name,
+            )),
+            (true, false) => Some(format!("feature = \"{}\"", icon.name)),
+            (false, _) => None,
+        }
+    }
+}
+
+const MODULE_TEMPLATE: &str = r#"
+use {TYPES}::{PathData, StaticShape};
+
+{STATICS}
+{VIEW_BOX_CONST}
+{SHARED_PATHS}
+/// All available icon shapes
+///
+/// See the enum variants for the shape names. These names are always the
+/// CamelCase version of the original icon name. So for example,
+/// "arrow-narrow-left" becomes `ArrowNarrowLeft`.
+///
+/// Each shape is also available as a [`StaticShape`] static, named after its
+/// original name, like `ARROW_LEFT`. Using the enum keeps every enabled shape
+/// in the binary, while using the statics only keeps the ones that are used.
+///
+/// Each shape has an explicit discriminant, which stays the same when the code is regenerated with
+/// new icons, so a shape can be stored as a number with [`Shape::discriminant`] and read back with
+/// [`Shape::from_discriminant`].
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shape {
+{NAMES}}
+
+#[allow(dead_code)]
+impl Shape {
+    /// All of the shapes, in alphabetical order.
+    pub const ALL: &'static [Shape] = &[
+{ALL}    ];
+
+    /// Returns the original name for the shape, like "arrow-left".
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        self.entry().1.name()
+    }
+
+    /// Returns the `viewBox` for the shape's `<svg>` element.
+    #[must_use]
+    pub fn view_box(self) -> &'static str {
+        {VIEW_BOX}
+    }
+
+    /// Returns the raw data for each of the shape's `<path>` elements.
+    #[must_use]
+    pub fn path_data(self) -> &'static [PathData] {
+        self.entry().1.path_data()
+    }
+
+    /// Returns the shape's search tags, which are other words for what it shows, like "garbage"
+    /// for `Trash`. Many shapes don't have any.
+    #[must_use]
+    pub fn tags(self) -> &'static [&'static str] {
+        self.entry().2
+    }
+
+    /// Returns true if the shape points or reads in a direction, like `ArrowLeft` or `ListBullet`,
+    /// so that it should be mirrored in a right-to-left layout.
+    #[must_use]
+    pub fn is_directional(self) -> bool {
+        self.entry().3
+    }
+
+    /// Returns the [`StaticShape`] for the shape.
+    #[must_use]
+    pub fn static_shape(self) -> StaticShape {
+        *self.entry().1
+    }
+
+    /// Returns the shape's discriminant, which stays the same when new icons are added.
+    #[must_use]
+    pub fn discriminant(self) -> u32 {
+        self as u32
+    }
+
+    /// Returns the shape with a discriminant from [`Shape::discriminant`], or `None` if there's no
+    /// such shape or its feature is off.
+    #[must_use]
+    pub fn from_discriminant(discriminant: u32) -> Option<Shape> {
+        ENTRIES
+            .get(discriminant as usize)
+            .copied()
+            .flatten()
+            .map(|e| e.0)
+    }
+
+    /// Returns the shape's entry. Its slot is only `None` when its feature is off, and then the
+    /// shape doesn't exist.
+    fn entry(self) -> Entry {
+        ENTRIES[self as usize].expect("every shape has an entry")
+    }
+{RENAMES}}
+{DIOXUS_IMPLS}
+/// A shape, its static, its tags, and whether it's directional.{SPLIT_NOTE}
+type Entry = (Shape, &'static StaticShape, &'static [&'static str], bool);
+
+/// Each shape's entry, indexed by its discriminant. The slots for the discriminants of icons that
+/// heroicons removed, and of shapes whose features are off, are `None`.
+static ENTRIES: &[Option<Entry>] = &[
+{ENTRIES}];
+"#;
+
+const RENAME_TEMPLATE: &str = r#"
+    /// The "{OLD_NAME}" shape, which heroicons renamed to "{NAME}".
+    #[deprecated(note = "heroicons renamed this icon to \"{NAME}\", so use `Shape::{VARIANT}`")]
+    #[allow(non_upper_case_globals)]
+    {CFG}pub const {OLD_VARIANT}: Shape = Shape::{VARIANT};
+"#;
+
+const DIOXUS_IMPLS_TEMPLATE: &str = r"
+impl {TYPES}::IconSet for Shape {
+    fn all() -> &'static [Self] {
+        Self::ALL
+    }
+
+    fn name(&self) -> &'static str {
+        Shape::name(*self)
+    }
+
+    fn tags(&self) -> &'static [&'static str] {
+        Shape::tags(*self)
+    }
+
+    fn is_directional(&self) -> bool {
+        Shape::is_directional(*self)
+    }
+}
+
+impl {TYPES}::IconShape for Shape {
+    fn view_box(&self) -> &str {
+        Shape::view_box(*self)
+    }
+
+    fn path(&self) -> dioxus::prelude::Element {
+        {TYPES}::IconShape::path(&self.static_shape())
+    }
+
+    fn path_html(&self) -> Option<&'static str> {
+        {TYPES}::IconShape::path_html(&self.static_shape())
+    }
+}
+";
+
+// Returns the code for the consts with the `shared` path data. A const is unused when the features
+// for all of the shapes that use it are off.
+fn shared_paths_code(shared: &BTreeMap<String, SharedPath<'_>>) -> String {
+    shared
+        .iter()
+        .map(|(d, p)| {
+            format!(
+                "/// The path data that the {} shapes share.\n\
+                 #[allow(dead_code)]\n\
+                 const {}: &str = \"{d}\";\n",
+                p.icons
+                    .iter()
+                    .map(|i| format!("\"{}\"", i.name))
+                    .unique()
+                    .join(", "),
+                p.name,
+            )
+        })
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+// The path data that more than one of a generator's paths use goes in a shared const, which each of
+// those paths refers to instead of repeating it, so that the generated code only has it once.
+const STATIC_TEMPLATE: &str = r#"
+/// The "{NAME}" shape.
+{CFG}pub static {CONST}: {PREFIX}StaticShape = {PREFIX}StaticShape::new(
+"{NAME}",
+{VIEW_BOX},
+&[
+{PATHS}],
+);
+"#;
+
+// Only the attributes that a path has are listed, and the rest come from `PathData::EMPTY`, so
+// that each path isn't followed by a line of `None` for every attribute it doesn't have.
+const PATH_TEMPLATE: &str = r"{PREFIX}PathData {
+d: {D},
+{ATTRIBUTES}..{PREFIX}PathData::EMPTY
+},
+";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn icon(name: &str, view_box: &str) -> Icon {
+        Icon {
+            name: name.to_string(),
+            variant: name.to_upper_camel_case(),
+            view_box: view_box.to_string(),
+            paths: vec![IconPath {
+                d: "M0 0L20 20Z".to_string(),
+                fill_rule: Some("evenodd".to_string()),
+                ..IconPath::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn module_code() {
+        let code = Generator::new(vec![
+            icon("arrow-left", "0 0 20 20"),
+            icon("x", "0 0 20 20"),
+        ])
+        .types_path("crate")
+        .module_code();
+        assert!(code.starts_with("use crate::{PathData, StaticShape};\n"));
+        assert!(code.contains(
+            "pub static ARROW_LEFT: StaticShape = StaticShape::new(\n    \"arrow-left\",\n    \
+             VIEW_BOX,\n"
+        ));
+        assert!(code.contains("fill_rule: Some(\"evenodd\"),"));
+        assert!(code.contains("pub const VIEW_BOX: &str = \"0 0 20 20\";"));
+        assert!(code.contains(
+            "pub enum Shape {\n    #[doc(alias(\"arrow-left\"))]\n    ArrowLeft = 0,\n    X = 1,\n}"
+        ));
+        assert!(code.contains("impl crate::IconShape for Shape {"));
+        assert!(!code.contains("#[cfg("));
+    }
+
+    #[test]
+    fn mixed_view_boxes() {
+        let code = Generator::new(vec![
+            icon("a", "0 0 20 20"),
+            icon("b", "0 0 24 24"),
+            icon("c", "0 0 24 24"),
+        ])
+        .feature_gates(true)
+        .dioxus_impls(false)
+        .module_code();
+        assert!(code.contains("pub const VIEW_BOX: &str = \"0 0 24 24\";"));
+        assert!(code.contains("    \"a\",\n    \"0 0 20 20\",\n"));
+        assert!(code.contains("    \"b\",\n    VIEW_BOX,\n"));
+        assert!(code.contains("self.entry().1.view_box()"));
+        assert!(code.contains("#[cfg(feature = \"b\")]\npub static B"));
+        assert!(!code.contains("IconShape"));
+    }
+
+    #[test]
+    fn multiple_paths() {
+        let mut icon = icon("a", "0 0 20 20");
+        icon.paths.push(IconPath {
+            d: "M20 0L0 20Z".to_string(),
+            clip_rule: Some("evenodd".to_string()),
+            fill_opacity: Some("0.5".to_string()),
+            stroke_linecap: Some("round".to_string()),
+            ..IconPath::default()
+        });
+        let code = Generator::new(vec![icon]).module_code();
+        assert!(code.contains(
+            "    &[\n        \
+             PathData {\n            d: \"M0 0L20 20Z\",\n            \
+             fill_rule: Some(\"evenodd\"),\n            ..PathData::EMPTY\n        },\n        \
+             PathData {\n            d: \"M20 0L0 20Z\",\n            \
+             clip_rule: Some(\"evenodd\"),\n            fill_opacity: Some(\"0.5\"),\n            \
+             stroke_linecap: Some(\"round\"),\n            ..PathData::EMPTY\n        },\n    ],\n"
+        ));
+    }
+
+    #[test]
+    fn write_split_matching() {
+        let dir = std::env::temp_dir().join(format!("heroicons-codegen-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("b.rs"), "// untouched").unwrap();
+        let generator = Generator::new(vec![icon("apple", "0 0 20 20"), icon("bell", "0 0 20 20")]);
+        let lib = generator
+            .write_split_matching(&dir, "//! Icons.", |i| i.name.starts_with('a'))
+            .unwrap();
+        assert!(fs::read_to_string(lib).unwrap().contains("mod a;\nmod b;"));
+        assert!(fs::read_to_string(dir.join("a.rs"))
+            .unwrap()
+            .contains("pub static APPLE"));
+        assert_eq!(
+            fs::read_to_string(dir.join("b.rs")).unwrap(),
+            "// untouched"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn layouts() {
+        let dir =
+            std::env::temp_dir().join(format!("heroicons-codegen-layouts-{}", std::process::id()));
+        let generator = Generator::new(vec![
+            icon("arrow-left", "0 0 20 20"),
+            icon("box", "0 0 20 20"),
+        ])
+        .layout(Layout::Icon)
+        .root_file("mod.rs");
+        let root = generator.write_split(&dir, "//! Icons.").unwrap();
+        assert_eq!(root, dir.join("mod.rs"));
+        let code = fs::read_to_string(&root).unwrap();
+        assert!(code.contains("mod arrow_left;\nmod shape_box;"));
+        assert!(code.contains("Some((Shape::ArrowLeft, &arrow_left::ARROW_LEFT, &[], false)),"));
+        assert!(fs::read_to_string(dir.join("shape_box.rs"))
+            .unwrap()
+            .starts_with("//! The \"box\" shape.\n"));
+
+        let root = generator
+            .layout(Layout::File)
+            .write_split(&dir, "//! Icons.")
+            .unwrap();
+        assert_eq!(
+            fs::read_dir(&dir).unwrap().count(),
+            1,
+            "only the root file is written",
+        );
+        let code = fs::read_to_string(root).unwrap();
+        assert!(code.starts_with("//! Icons.\n\nuse ::dioxus_heroicons::{PathData, StaticShape};"));
+        assert!(code.contains("pub static ARROW_LEFT"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn shape_elements() {
+        let d = |element: &str, attrs: &[(&str, &'static str)]| {
+            let attrs = attrs.to_vec();
+            shape_d(element, move |name| {
+                attrs.iter().find(|(n, _)| *n == name).map(|(_, v)| *v)
+            })
+        };
+        assert_eq!(d("path", &[("d", "M0 0Z")]).unwrap(), "M0 0Z");
+        assert_eq!(
+            d("circle", &[("cx", "12"), ("cy", "12"), ("r", "3")]).unwrap(),
+            "M9 12a3 3 0 1 0 6 0a3 3 0 1 0 -6 0Z",
+        );
+        assert_eq!(
+            d(
+                "ellipse",
+                &[("cx", "10"), ("cy", "5"), ("rx", "4"), ("ry", "2")]
+            )
+            .unwrap(),
+            "M6 5a4 2 0 1 0 8 0a4 2 0 1 0 -8 0Z",
+        );
+        assert_eq!(
+            d(
+                "line",
+                &[("x1", "1"), ("y1", "2"), ("x2", "3.5"), ("y2", "4")]
+            )
+            .unwrap(),
+            "M1 2L3.5 4",
+        );
+        assert_eq!(
+            d("polygon", &[("points", "0,0 10,0 5 10")]).unwrap(),
+            "M0 0L10 0L5 10Z",
+        );
+        assert_eq!(
+            d("polyline", &[("points", "0,0 10,0 5,10")]).unwrap(),
+            "M0 0L10 0L5 10",
+        );
+        assert_eq!(
+            d(
+                "rect",
+                &[("x", "2"), ("y", "3"), ("width", "10"), ("height", "8")]
+            )
+            .unwrap(),
+            "M2 3h10v8h-10Z",
+        );
+        assert_eq!(
+            d("rect", &[("width", "10"), ("height", "8"), ("rx", "2")]).unwrap(),
+            "M2 0h6a2 2 0 0 1 2 2v4a2 2 0 0 1 -2 2h-6a2 2 0 0 1 -2 -2v-4a2 2 0 0 1 2 -2Z",
+        );
+
+        assert_eq!(
+            d("path", &[]).unwrap_err(),
+            "has a <path> without a d attribute",
+        );
+        assert_eq!(
+            d("circle", &[("r", "x")]).unwrap_err(),
+            "has a <circle> with an invalid r: x",
+        );
+        assert_eq!(
+            d("polygon", &[("points", "0,0 10")]).unwrap_err(),
+            "has a <polygon> with an odd number of coordinates",
+        );
+        assert_eq!(
+            d("polyline", &[("points", "0,0")]).unwrap_err(),
+            "has a <polyline> with fewer than two points",
+        );
+    }
+
+    #[test]
+    fn minify() {
+        let mut icon = icon("a", "0 0 20 20");
+        icon.paths[0].d = "M0.504 0.5L 10.0 20.0Z".to_string();
+        icon.paths.push(IconPath {
+            d: "not path data".to_string(),
+            ..IconPath::default()
+        });
+        let code = Generator::new(vec![icon]).minify(Some(2)).module_code();
+        assert!(code.contains("d: \"M.5.5 10 20Z\","));
+        assert!(code.contains("d: \"not path data\","));
+    }
+
+    #[test]
+    fn shared_paths() {
+        let mut other = icon("other", "0 0 20 20");
+        other.paths[0].d = "M1 1Z".to_string();
+        let generator = Generator::new(vec![
+            icon("arrow-left", "0 0 20 20"),
+            icon("back", "0 0 20 20"),
+            other,
+        ]);
+        let code = generator.module_code();
+        assert!(code.contains(
+            "/// The path data that the \"arrow-left\", \"back\" shapes share.\n\
+             #[allow(dead_code)]\n\
+             const SHARED_ARROW_LEFT_0: &str = \"M0 0L20 20Z\";\n"
+        ));
+        assert_eq!(code.matches("d: SHARED_ARROW_LEFT_0,").count(), 2);
+        assert!(code.contains("d: \"M1 1Z\","));
+        assert!(!code.contains("SHARED_OTHER"));
+
+        let dir = std::env::temp_dir().join(format!(
+            "heroicons-codegen-shared-paths-{}",
+            std::process::id()
+        ));
+        generator.write_split(&dir, "//! Icons.").unwrap();
+        assert!(fs::read_to_string(dir.join("lib.rs"))
+            .unwrap()
+            .contains("const SHARED_ARROW_LEFT_0: &str"));
+        assert!(fs::read_to_string(dir.join("b.rs"))
+            .unwrap()
+            .contains("d: super::SHARED_ARROW_LEFT_0,"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn invalid_icons() {
+        let dir =
+            std::env::temp_dir().join(format!("heroicons-codegen-invalid-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("good.svg"),
+            r#"<svg viewBox="0 0 20 20"><path d="M0 0h1"/></svg>"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("no-view-box.svg"),
+            r#"<svg><path d="M0 0h1"/></svg>"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("no-d.svg"),
+            r#"<svg viewBox="0 0 20 20"><path/></svg>"#,
+        )
+        .unwrap();
+
+        let (icons, errors) = parse_icons_lenient(&dir);
+        assert_eq!(icons.len(), 1);
+        assert_eq!(icons[0].name, "good");
+        let errors = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].ends_with("no-d.svg has a <path> without a d attribute"));
+        assert!(errors[1].ends_with("no-view-box.svg does not have a viewBox"));
+        assert!(parse_icons(&dir)
+            .unwrap_err()
+            .to_string()
+            .ends_with("no-d.svg has a <path> without a d attribute"));
+
+        assert!(parse_icons(dir.join("missing")).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn transforms() {
+        let dir = std::env::temp_dir().join(format!(
+            "heroicons-codegen-transforms-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("moved.svg"),
+            r#"<svg viewBox="0 0 24 24">
+                <path d="M0 0h1"/>
+                <g transform="translate(10 5)">
+                    <g transform="scale(2)">
+                        <path d="M1 1h2v2" stroke-width="1.5"/>
+                        <rect width="1" height="1" transform="translate(1)"/>
+                    </g>
+                </g>
+            </svg>"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("bad.svg"),
+            r#"<svg viewBox="0 0 24 24"><g transform="spin(1)"><path d="M0 0"/></g></svg>"#,
+        )
+        .unwrap();
+        let err = parse_icons(&dir).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("bad.svg has a <g> with an invalid transform: spin(1)"));
+
+        fs::remove_file(dir.join("bad.svg")).unwrap();
+        let icons = parse_icons(&dir).unwrap();
+        let paths = &icons[0].paths;
+        assert_eq!(paths[0].d, "M0 0h1");
+        assert_eq!(paths[1].d, "M12 7L16 7L16 11");
+        assert_eq!(paths[1].stroke_width.as_deref(), Some("3"));
+        assert_eq!(paths[2].d, "M12 5L14 5L14 7L12 7Z");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn allow_list() {
+        let code = Generator::new(vec![icon("a", "0 0 20 20")])
+            .feature_gates(true)
+            .allow_list(true)
+            .module_code();
+        assert!(code.contains("#[cfg(any(feature = \"a\", heroicon = \"a\"))]\npub static A"));
+    }
+
+    #[test]
+    fn tags() {
+        let tags = vec![(
+            "trash".to_string(),
+            vec![
+                "garbage".to_string(),
+                "trash can".to_string(),
+                "Trash".to_string(),
+            ],
+        )];
+        let code = Generator::new(vec![icon("trash", "0 0 20 20")])
+            .tags(tags.into_iter().collect())
+            .module_code();
+        assert!(code.contains("#[doc(alias(\"garbage\", \"trash-can\"))]\n    Trash = 0,\n"));
+        assert!(
+            code.contains("Some((Shape::Trash, &TRASH, &[\"garbage\", \"trash can\"], false)),")
+        );
+    }
+
+    #[test]
+    fn directional() {
+        let code = Generator::new(vec![
+            icon("arrow-left", "0 0 20 20"),
+            icon("x", "0 0 20 20"),
+        ])
+        .directional(["arrow-left".to_string(), "y".to_string()].into())
+        .module_code();
+        assert!(code.contains("Some((Shape::ArrowLeft, &ARROW_LEFT, &[], true)),"));
+        assert!(code.contains("Some((Shape::X, &X, &[], false)),"));
+        assert!(code.contains("fn is_directional(&self) -> bool {"));
+    }
+
+    #[test]
+    fn renames() {
+        let renames = vec![
+            ("trash-can".to_string(), "trash".to_string()),
+            ("bin".to_string(), "trash".to_string()),
+            ("x".to_string(), "y".to_string()),
+            ("a".to_string(), "trash".to_string()),
+        ];
+        let code = Generator::new(vec![icon("a", "0 0 20 20"), icon("trash", "0 0 20 20")])
+            .feature_gates(true)
+            .renames(renames.into_iter().collect())
+            .module_code();
+        assert!(code.contains("#[doc(alias(\"bin\", \"trash-can\"))]\n    Trash = 1,\n"));
+        assert!(code.contains(
+            "    #[deprecated(\n        \
+             note = \"heroicons renamed this icon to \\\"trash\\\", so use `Shape::Trash`\"\n    \
+             )]\n    #[allow(non_upper_case_globals)]\n    #[cfg(feature = \"trash\")]\n    \
+             pub const TrashCan: Shape = Shape::Trash;\n"
+        ));
+        assert!(code.contains("pub const Bin: Shape = Shape::Trash;"));
+        assert!(!code.contains("pub const X:"));
+        assert!(!code.contains("pub const A:"));
+    }
+
+    #[test]
+    fn sprite() {
+        let mut b = icon("b", "0 0 24 24");
+        b.paths[0].stroke_width = Some("1.5".to_string());
+        let generator = Generator::new(vec![icon("a", "0 0 20 20"), b]);
+        let svg = generator.sprite_svg("heroicon");
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\">\n<symbol id=\"heroicon-a\" \
+             viewBox=\"0 0 20 20\"><path d=\"M0 0L20 20Z\" fill-rule=\"evenodd\"></path>\
+             </symbol>\n"
+        ));
+        assert!(svg.contains("<symbol id=\"heroicon-b\" viewBox=\"0 0 24 24\">"));
+        assert!(svg.contains(" stroke-width=\"1.5\"></path></symbol>\n</svg>\n"));
+        assert_eq!(
+            generator.sprite_ids_code("heroicon"),
+            "// This file was written by heroicons-codegen.
+
+/// The `id` of the symbol for the \"a\" shape.
+pub const A: &str = \"heroicon-a\";
+/// The `id` of the symbol for the \"b\" shape.
+pub const B: &str = \"heroicon-b\";
+/// The `id`s of all the symbols in the sprite sheet.
+pub const SYMBOL_IDS: &[&str] = &[A, B];
+",
+        );
+    }
+
+    #[test]
+    fn discriminants() {
+        let generator = Generator::new(vec![
+            icon("a", "0 0 20 20"),
+            icon("b", "0 0 20 20"),
+            icon("d", "0 0 20 20"),
+        ])
+        .discriminants(
+            vec![("b".to_string(), 0), ("c".to_string(), 1)]
+                .into_iter()
+                .collect(),
+        );
+        assert_eq!(
+            generator.all_discriminants(),
+            vec![
+                ("a".to_string(), 2),
+                ("b".to_string(), 0),
+                ("c".to_string(), 1),
+                ("d".to_string(), 3),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let code = generator.module_code();
+        assert!(code.contains("    A = 2,\n    B = 0,\n    D = 3,\n}"));
+        assert!(code.contains(
+            "static ENTRIES: &[Option<Entry>] = &[\n    \
+             Some((Shape::B, &B, &[], false)),\n    \
+             None,\n    \
+             Some((Shape::A, &A, &[], false)),\n    \
+             Some((Shape::D, &D, &[], false)),\n];"
+        ));
+        let code = generator.feature_gates(true).module_code();
+        assert!(code.contains(
+            "    #[cfg(feature = \"b\")]\n    Some((Shape::B, &B, &[], false)),\n    \
+             #[cfg(not(feature = \"b\"))]\n    None,\n    None,\n"
+        ));
+    }
+}
@@ -0,0 +1,211 @@
+// Minifies SVG path data, by rounding its numbers and leaving out everything that a renderer
+// doesn't need to read it.
+
+use crate::path::parse;
+
+/// Returns a smaller version of SVG path data, or `None` if it isn't valid path data.
+///
+/// Each number is rounded to `precision` decimal places, and written without any trailing zeros or
+/// a leading zero, like `.5` for `0.50`. A command letter is left out when it's the same as the one
+/// before it, or when it's an `L` after an `M`, since those are implied. Separators are only kept
+/// between two numbers that would otherwise run together.
+///
+/// Each relative coordinate is rounded so that the point it ends at is as close as possible to
+/// where it would be without rounding, so the rounding errors in a long run of relative commands
+/// don't add up. That means no point in the path moves by more than half of the last decimal place
+/// it keeps, like 0.005 with a `precision` of 2.
+#[must_use]
+pub fn minify_path_data(d: &str, precision: u32) -> Option<String> {
+    let segments = parse(d)?;
+    let round = |v: f64| {
+        let scale = 10_f64.powf(f64::from(precision));
+        (v * scale).round() / scale
+    };
+
+    // The current point and the start of the current subpath, both where they'd be without
+    // rounding and where they are with it.
+    let (mut exact, mut rounded) = ([0.0, 0.0], [0.0, 0.0]);
+    let (mut exact_start, mut rounded_start) = ([0.0, 0.0], [0.0, 0.0]);
+
+    let mut out = String::new();
+    let mut last_command = None;
+    let mut last_number: Option<String> = None;
+    for (command, args) in segments {
+        let relative = command.is_ascii_lowercase();
+        let upper = command.to_ascii_uppercase();
+        let mut end = exact;
+        let mut rounded_end = rounded;
+        let numbers = args
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| match axis(upper, i) {
+                Some(a) if relative => {
+                    let v = round(exact[a] + v - rounded[a]);
+                    end[a] = exact[a] + args[i];
+                    rounded_end[a] = rounded[a] + v;
+                    v
+                }
+                Some(a) => {
+                    let v = round(v);
+                    end[a] = args[i];
+                    rounded_end[a] = v;
+                    v
+                }
+                // The arc flags are 0 or 1, so they don't need rounding.
+                None if upper == b'A' && (i == 3 || i == 4) => v,
+                None => round(v),
+            })
+            .map(|v| format_number(v, precision))
+            .collect::<Vec<_>>();
+
+        if upper == b'Z' {
+            exact = exact_start;
+            rounded = rounded_start;
+        } else {
+            exact = end;
+            rounded = rounded_end;
+        }
+        if upper == b'M' {
+            exact_start = exact;
+            rounded_start = rounded;
+        }
+
+        if last_command.map(implied_command) != Some(command) {
+            out.push(char::from(command));
+            last_number = None;
+        }
+        for n in numbers {
+            if let Some(last) = &last_number {
+                if !(n.starts_with('-') || n.starts_with('.') && last.contains('.')) {
+                    out.push(' ');
+                }
+            }
+            out.push_str(&n);
+            last_number = Some(n);
+        }
+        last_command = Some(command);
+    }
+    Some(out)
+}
+
+// Returns which axis an argument of a command is a coordinate on, with 0 for x and 1 for y, or
+// `None` if it's not a coordinate, like an arc's radii, rotation, and flags.
+fn axis(command: u8, i: usize) -> Option<usize> {
+    match command {
+        b'H' => Some(0),
+        b'V' => Some(1),
+        b'A' => i.checked_sub(5),
+        _ => Some(i % 2),
+    }
+}
+
+// Returns the command that's implied by numbers that follow a command without a command letter.
+fn implied_command(command: u8) -> u8 {
+    match command {
+        b'M' => b'L',
+        b'm' => b'l',
+        // Nothing can follow a `Z` without a command letter, so this never matches.
+        b'Z' | b'z' => 0,
+        c => c,
+    }
+}
+
+// Formats a rounded number with as few characters as possible.
+fn format_number(v: f64, precision: u32) -> String {
+    let s = format!("{:.*}", precision as usize, v);
+    let s = if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &s
+    };
+    if let Some(fraction) = s.strip_prefix("0.") {
+        format!(".{fraction}")
+    } else if let Some(fraction) = s.strip_prefix("-0.") {
+        format!("-.{fraction}")
+    } else if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rounding() {
+        assert_eq!(
+            minify_path_data(
+                "M4.25933 10.1468C3.98688 12.2308 3.82139 14.3485 3.76853 16.4941",
+                2
+            )
+            .unwrap(),
+            "M4.26 10.15C3.99 12.23 3.82 14.35 3.77 16.49",
+        );
+        assert_eq!(
+            minify_path_data("M0.5 -0.5L0.001 10.000", 2).unwrap(),
+            "M.5-.5 0 10"
+        );
+    }
+
+    #[test]
+    fn implied_commands() {
+        assert_eq!(
+            minify_path_data("M1 2 L 3 4 L 5 6 H 7 Z M 8 9 M 10 11", 3).unwrap(),
+            "M1 2 3 4 5 6H7ZM8 9M10 11",
+        );
+        assert_eq!(
+            minify_path_data("m1,2 3,4 l5,6 c1 1 2 2 3 3 c4 4 5 5 6 6", 3).unwrap(),
+            "m1 2 3 4 5 6c1 1 2 2 3 3 4 4 5 5 6 6",
+        );
+    }
+
+    #[test]
+    fn separators() {
+        assert_eq!(
+            minify_path_data("M0.5 0.5L1.5 -0.5L2 0.5", 1).unwrap(),
+            "M.5.5 1.5-.5 2 .5"
+        );
+    }
+
+    #[test]
+    fn relative_rounding_does_not_drift() {
+        // Each step rounds to 0.1 on its own, which would end the path at 0.5 instead of 0.7, so
+        // some of them round up instead.
+        assert_eq!(
+            minify_path_data("m0 0h.14h.14h.14h.14h.14", 1).unwrap(),
+            "m0 0h.1.2.1.2.1",
+        );
+        // After a `Z`, the current point is back at the start of the subpath.
+        assert_eq!(
+            minify_path_data("M.04 0l.04 0zl.04 0", 1).unwrap(),
+            "M0 0l.1 0zl.1 0"
+        );
+    }
+
+    #[test]
+    fn arcs() {
+        assert_eq!(
+            minify_path_data("M9 12a3.004 3 0 1 0 6 0a3 3 0 1 0 -6 0Z", 2).unwrap(),
+            "M9 12a3 3 0 1 0 6 0 3 3 0 1 0-6 0Z",
+        );
+        assert_eq!(
+            minify_path_data("M0 0a1 1 0 01 2 2", 2).unwrap(),
+            "M0 0a1 1 0 0 1 2 2"
+        );
+        assert_eq!(
+            minify_path_data("m0 0a1 1 0 0 1 .14 .26a1 1 0 0 1 .14 .26", 1).unwrap(),
+            "m0 0a1 1 0 0 1 .1.3 1 1 0 0 1 .2.2",
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(minify_path_data("M0", 2), None);
+        assert_eq!(minify_path_data("0 0", 2), None);
+        assert_eq!(minify_path_data("M0 0Z 1 1", 2), None);
+        assert_eq!(minify_path_data("M0 0X1", 2), None);
+        assert_eq!(minify_path_data("M0 0a1 1 0 2 0 1 1", 2), None);
+    }
+}
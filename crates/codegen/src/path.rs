@@ -0,0 +1,153 @@
+// Parses SVG path data, for the code that minifies and transforms it.
+
+// Parses path data into its commands and their arguments. A command with more than one set of
+// arguments is split into one segment per set, so `L1 2 3 4` becomes `L1 2` and `L3 4`, and the
+// sets after an `M` become `L` segments.
+pub(crate) fn parse(d: &str) -> Option<Vec<(u8, Vec<f64>)>> {
+    let mut parser = Parser::new(d);
+    let mut segments = vec![];
+    let mut command = None;
+    loop {
+        parser.skip_separators();
+        let Some(next) = parser.peek() else {
+            break;
+        };
+        if next.is_ascii_alphabetic() {
+            parser.pos += 1;
+            command = Some(next);
+        } else {
+            command = match command? {
+                b'Z' | b'z' => return None,
+                b'M' => Some(b'L'),
+                b'm' => Some(b'l'),
+                c => Some(c),
+            };
+        }
+        let command = command?;
+        let upper = command.to_ascii_uppercase();
+        let count = match upper {
+            b'Z' => 0,
+            b'H' | b'V' => 1,
+            b'M' | b'L' | b'T' => 2,
+            b'S' | b'Q' => 4,
+            b'C' => 6,
+            b'A' => 7,
+            _ => return None,
+        };
+        let args = (0..count)
+            .map(|i| {
+                if upper == b'A' && (i == 3 || i == 4) {
+                    parser.flag()
+                } else {
+                    parser.number()
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+        segments.push((command, args));
+    }
+    Some(segments)
+}
+
+// Reads the numbers and commands in path data, and the numbers in other attributes, like
+// `transform`.
+pub(crate) struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self {
+            bytes: s.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    // Returns the next byte without reading it, or `None` at the end.
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    // Reads the next byte if it's the given one, after any whitespace, and returns true if it was.
+    pub(crate) fn eat(&mut self, byte: u8) -> bool {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    // Reads a run of ASCII letters, like the name of a transform function.
+    pub(crate) fn word(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos]).unwrap_or_default()
+    }
+
+    // Skips any whitespace and commas.
+    pub(crate) fn skip_separators(&mut self) {
+        while self
+            .bytes
+            .get(self.pos)
+            .is_some_and(|b| b.is_ascii_whitespace() || *b == b',')
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.bytes.get(self.pos).is_some_and(u8::is_ascii_digit) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    // Reads an arc flag, which can be written without a separator after it, like the `01` in
+    // `a1 1 0 01 2 2`.
+    fn flag(&mut self) -> Option<f64> {
+        self.skip_separators();
+        let flag = match self.bytes.get(self.pos)? {
+            b'0' => 0.0,
+            b'1' => 1.0,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(flag)
+    }
+
+    pub(crate) fn number(&mut self) -> Option<f64> {
+        self.skip_separators();
+        let start = self.pos;
+        if matches!(self.bytes.get(self.pos), Some(b'-' | b'+')) {
+            self.pos += 1;
+        }
+        let mut digits = self.skip_digits();
+        if self.bytes.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            digits += self.skip_digits();
+        }
+        if digits == 0 {
+            return None;
+        }
+        if matches!(self.bytes.get(self.pos), Some(b'e' | b'E')) {
+            self.pos += 1;
+            if matches!(self.bytes.get(self.pos), Some(b'-' | b'+')) {
+                self.pos += 1;
+            }
+            if self.skip_digits() == 0 {
+                return None;
+            }
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()?
+            .parse()
+            .ok()
+    }
+}
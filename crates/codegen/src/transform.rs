@@ -0,0 +1,240 @@
+// Applies SVG `transform` attributes to path data, so that a shape inside a `<g transform="...">`
+// can be drawn as a plain `<path>`.
+
+use crate::path::{parse, Parser};
+
+// An affine transform, as the `a` to `f` of an SVG `matrix(a b c d e f)`, which moves the point
+// (x, y) to (a * x + c * y + e, b * x + d * y + f).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Transform([f64; 6]);
+
+impl Transform {
+    pub(crate) const IDENTITY: Self = Self([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    // Parses the value of a `transform` attribute, which is a list of transform functions, like
+    // `translate(10 5) rotate(45)`. Returns `None` if it's not valid.
+    #[allow(clippy::many_single_char_names)]
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let mut parser = Parser::new(s);
+        let mut transform = Self::IDENTITY;
+        loop {
+            parser.skip_separators();
+            if parser.peek().is_none() {
+                return Some(transform);
+            }
+            let name = parser.word();
+            if !parser.eat(b'(') {
+                return None;
+            }
+            let mut args = vec![];
+            while !parser.eat(b')') {
+                args.push(parser.number()?);
+            }
+            let next = match (name, args.as_slice()) {
+                ("matrix", &[a, b, c, d, e, f]) => Self([a, b, c, d, e, f]),
+                ("translate", &[x]) => Self::translate(x, 0.0),
+                ("translate", &[x, y]) => Self::translate(x, y),
+                ("scale", &[s]) => Self([s, 0.0, 0.0, s, 0.0, 0.0]),
+                ("scale", &[x, y]) => Self([x, 0.0, 0.0, y, 0.0, 0.0]),
+                ("rotate", &[a]) => Self::rotate(a),
+                ("rotate", &[a, x, y]) => Self::translate(x, y)
+                    .then(Self::rotate(a))
+                    .then(Self::translate(-x, -y)),
+                ("skewX", &[a]) => Self([1.0, 0.0, a.to_radians().tan(), 1.0, 0.0, 0.0]),
+                ("skewY", &[a]) => Self([1.0, a.to_radians().tan(), 0.0, 1.0, 0.0, 0.0]),
+                _ => return None,
+            };
+            transform = transform.then(next);
+        }
+    }
+
+    fn translate(x: f64, y: f64) -> Self {
+        Self([1.0, 0.0, 0.0, 1.0, x, y])
+    }
+
+    fn rotate(degrees: f64) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        Self([cos, sin, -sin, cos, 0.0, 0.0])
+    }
+
+    // Returns the transform that applies `inner` first and then this one, which is what an SVG
+    // does with a transform on an element inside a `<g>` with this one.
+    #[allow(clippy::many_single_char_names)]
+    pub(crate) fn then(self, inner: Self) -> Self {
+        let [a, b, c, d, e, f] = self.0;
+        let [ia, ib, ic, id, ie, if_] = inner.0;
+        Self([
+            a * ia + c * ib,
+            b * ia + d * ib,
+            a * ic + c * id,
+            b * ic + d * id,
+            a * ie + c * if_ + e,
+            b * ie + d * if_ + f,
+        ])
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn apply(self, [x, y]: [f64; 2]) -> [f64; 2] {
+        let [a, b, c, d, e, f] = self.0;
+        [a * x + c * y + e, b * x + d * y + f]
+    }
+
+    // Returns how much the transform scales areas by, which is negative if it flips the shape over.
+    fn determinant(self) -> f64 {
+        let [a, b, c, d, ..] = self.0;
+        a * d - b * c
+    }
+
+    // Returns how much the transform scales lengths by, on average, which is how much it scales a
+    // path's stroke width.
+    pub(crate) fn scale(self) -> f64 {
+        self.determinant().abs().sqrt()
+    }
+
+    // Returns the radii and rotation, in degrees, of an ellipse with the given radii and rotation
+    // after it's transformed. These come from the singular values of the transform applied to the
+    // ellipse's axes.
+    #[allow(clippy::many_single_char_names)]
+    fn ellipse(self, rx: f64, ry: f64, degrees: f64) -> (f64, f64, f64) {
+        let [a, b, c, d, ..] = self.0;
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        // The columns of this matrix are the ellipse's axes after the transform.
+        let (m11, m21) = ((a * cos + c * sin) * rx, (b * cos + d * sin) * rx);
+        let (m12, m22) = ((c * cos - a * sin) * ry, (d * cos - b * sin) * ry);
+        let (p, q, r) = (
+            m11 * m11 + m12 * m12,
+            m11 * m21 + m12 * m22,
+            m21 * m21 + m22 * m22,
+        );
+        let mid = f64::midpoint(p, r);
+        let spread = ((p - r) / 2.0).hypot(q);
+        let angle = 0.5 * (2.0 * q).atan2(p - r);
+        (
+            (mid + spread).sqrt(),
+            (mid - spread).max(0.0).sqrt(),
+            angle.to_degrees(),
+        )
+    }
+}
+
+// Returns path data with a transform applied to it, or `None` if it isn't valid path data. The
+// result only uses absolute commands, and `H` and `V` become `L`, since a transformed horizontal
+// or vertical line usually isn't one anymore. Numbers are rounded to 6 decimal places, which gets
+// rid of the noise that floating point math adds, like 0.30000000000000004.
+pub(crate) fn transform_path_data(d: &str, transform: Transform) -> Option<String> {
+    let mut out = String::new();
+    let (mut current, mut start) = ([0.0, 0.0], [0.0, 0.0]);
+    for (command, args) in parse(d)? {
+        let upper = command.to_ascii_uppercase();
+        let offset = if command.is_ascii_lowercase() {
+            current
+        } else {
+            [0.0, 0.0]
+        };
+        let point = |x: f64, y: f64| [offset[0] + x, offset[1] + y];
+        let (letter, numbers, end) = match upper {
+            b'Z' => ('Z', vec![], start),
+            b'H' => {
+                let end = [offset[0] + args[0], current[1]];
+                ('L', transform.apply(end).to_vec(), end)
+            }
+            b'V' => {
+                let end = [current[0], offset[1] + args[0]];
+                ('L', transform.apply(end).to_vec(), end)
+            }
+            b'A' => {
+                let end = point(args[5], args[6]);
+                let (rx, ry, rotation) = transform.ellipse(args[0], args[1], args[2]);
+                // Flipping the shape over reverses the direction that the arc is drawn in.
+                let sweep = if transform.determinant() < 0.0 {
+                    1.0 - args[4]
+                } else {
+                    args[4]
+                };
+                let mut numbers = vec![rx, ry, rotation, args[3], sweep];
+                numbers.extend(transform.apply(end));
+                ('A', numbers, end)
+            }
+            _ => {
+                let points = args
+                    .chunks(2)
+                    .map(|p| point(p[0], p[1]))
+                    .collect::<Vec<_>>();
+                let numbers = points.iter().flat_map(|p| transform.apply(*p)).collect();
+                (char::from(upper), numbers, *points.last()?)
+            }
+        };
+        out.push(letter);
+        let numbers = numbers.into_iter().map(format_number).collect::<Vec<_>>();
+        out.push_str(&numbers.join(" "));
+        current = end;
+        if upper == b'M' {
+            start = end;
+        }
+    }
+    Some(out)
+}
+
+fn format_number(v: f64) -> String {
+    // Adding zero turns -0 into 0.
+    ((v * 1e6).round() / 1e6 + 0.0).to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_transforms() {
+        assert_eq!(
+            Transform::parse("translate(10, 5) scale(2)"),
+            Some(Transform([2.0, 0.0, 0.0, 2.0, 10.0, 5.0])),
+        );
+        assert_eq!(
+            Transform::parse("matrix(1 2 3 4 5 6)"),
+            Some(Transform([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])),
+        );
+        assert_eq!(
+            Transform::parse(" translate(3) "),
+            Some(Transform([1.0, 0.0, 0.0, 1.0, 3.0, 0.0])),
+        );
+        assert_eq!(Transform::parse(""), Some(Transform::IDENTITY));
+        assert_eq!(Transform::parse("translate(1 2 3)"), None);
+        assert_eq!(Transform::parse("spin(1)"), None);
+        assert_eq!(Transform::parse("scale(2"), None);
+    }
+
+    #[test]
+    fn transform_paths() {
+        let transform =
+            |t: &str, d: &str| transform_path_data(d, Transform::parse(t).unwrap()).unwrap();
+        assert_eq!(
+            transform("translate(10 5) scale(2)", "M1 1l2 0h1v1zm1 1"),
+            "M12 7L16 7L18 7L18 9ZM14 9",
+        );
+        assert_eq!(transform("rotate(90 12 12)", "M12 2L12 6"), "M22 12L18 12",);
+        assert_eq!(
+            transform("scale(0.5)", "M0 0C2 2 4 2 6 0S10 -2 12 0Q14 2 16 0T20 0"),
+            "M0 0C1 1 2 1 3 0S5 -1 6 0Q7 1 8 0T10 0",
+        );
+        assert_eq!(transform_path_data("M0 0L1", Transform::IDENTITY), None);
+    }
+
+    #[test]
+    fn transform_arcs() {
+        let transform =
+            |t: &str, d: &str| transform_path_data(d, Transform::parse(t).unwrap()).unwrap();
+        assert_eq!(
+            transform("scale(2 1)", "M0 0a1 1 0 0 1 2 0"),
+            "M0 0A2 1 0 0 1 4 0",
+        );
+        assert_eq!(
+            transform("rotate(90)", "M0 0A2 1 0 1 1 4 0"),
+            "M0 0A2 1 90 1 1 0 4",
+        );
+        assert_eq!(
+            transform("scale(-1 1)", "M0 0A1 1 0 0 1 2 0"),
+            "M0 0A1 1 0 0 0 -2 0",
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Support for picking icons with an `icons.toml` file instead of a cargo feature for each one.
+//!
+//! The style crates call [`configure`] from their build scripts. If the `DIOXUS_HEROICONS_ICONS`
+//! environment variable names an allow-list file, each icon it lists is compiled just as if its
+//! feature were on. The file has a list of icons for all styles and a list for each style, any of
+//! which can be left out. Every icon in the lists that apply to a style has to be one of its
+//! shapes:
+//!
+//! ```toml
+//! # These are compiled in every style that's enabled.
+//! icons = ["arrow-left", "trash"]
+//! # These are only compiled in one style.
+//! outline = ["bars-3"]
+//! mini = ["x-circle"]
+//! ```
+//!
+//! The easiest way to set the variable is in the app's `.cargo/config.toml`, so that the path is
+//! relative to the app instead of to the style crate's build script:
+//!
+//! ```toml
+//! [env]
+//! DIOXUS_HEROICONS_ICONS = { value = "icons.toml", relative = true }
+//! ```
+
+use crate::STYLES;
+use std::{collections::BTreeMap, env, fs, path::Path};
+use toml::{Table, Value};
+
+/// The environment variable that names the allow-list file.
+pub const ENV_VAR: &str = "DIOXUS_HEROICONS_ICONS";
+
+/// The key for the icons that apply to every style. The other keys are the names of the styles.
+const ALL_STYLES_KEY: &str = "icons";
+
+/// The icons listed in an `icons.toml` file, by style.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AllowList(BTreeMap<String, Vec<String>>);
+
+impl AllowList {
+    /// Parses an allow-list.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the text isn't valid TOML, if it has a key other than `icons` or
+    /// one of the [`STYLES`], or if a key's value isn't an array of icon names.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text.parse::<Table>().map_err(|e| e.to_string())?;
+        let mut lists = BTreeMap::new();
+        for (key, value) in table {
+            if key != ALL_STYLES_KEY && !STYLES.contains(&key.as_str()) {
+                return Err(format!(
+                    "unknown key `{key}`, expected `{ALL_STYLES_KEY}` or one of {}",
+                    STYLES.join(", "),
+                ));
+            }
+            let icons = strings(&value)
+                .ok_or_else(|| format!("the `{key}` key should be an array of icon names"))?;
+            lists.insert(key, icons);
+        }
+        Ok(Self(lists))
+    }
+
+    /// Returns the icons for a style, which are the ones listed under `icons` plus the ones listed
+    /// under the style's own key.
+    #[must_use]
+    pub fn icons(&self, style: &str) -> Vec<&str> {
+        let mut icons = [ALL_STYLES_KEY, style]
+            .iter()
+            .filter_map(|k| self.0.get(*k))
+            .flatten()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        icons.sort_unstable();
+        icons.dedup();
+        icons
+    }
+
+    /// Returns the icons for a style that aren't one of its `shapes`, which are usually typos.
+    #[must_use]
+    pub fn unknown_icons(&self, style: &str, shapes: &[String]) -> Vec<&str> {
+        self.icons(style)
+            .into_iter()
+            .filter(|i| !shapes.iter().any(|s| s == i))
+            .collect()
+    }
+}
+
+/// Tells cargo which icons of a style are in the allow-list, if there is one. Call this from the
+/// style crate's build script.
+///
+/// Each listed icon gets a `heroicon = "name"` cfg, which the generated shapes check along with
+/// their features. Cargo reruns the build script when the variable or the file changes.
+///
+/// # Panics
+///
+/// This panics if the allow-list file can't be read or parsed, or if it lists an icon that isn't
+/// one of the style's shapes, which fails the build with the error.
+pub fn configure(style: &str) {
+    println!("cargo:rustc-check-cfg=cfg(heroicon, values(any()))");
+    println!("cargo:rerun-if-env-changed={ENV_VAR}");
+    let Some(file) = env::var_os(ENV_VAR) else {
+        return;
+    };
+    let file = Path::new(&file);
+    println!("cargo:rerun-if-changed={}", file.display());
+    let text = fs::read_to_string(file).unwrap_or_else(|e| {
+        panic!(
+            "could not read the icon allow-list at {}: {e}",
+            file.display(),
+        )
+    });
+    let list = AllowList::parse(&text).unwrap_or_else(|e| {
+        panic!(
+            "could not parse the icon allow-list at {}: {e}",
+            file.display(),
+        )
+    });
+    let unknown = list.unknown_icons(style, &style_shapes());
+    assert!(
+        unknown.is_empty(),
+        "the icon allow-list at {} has icons that aren't in the {style} style: {}",
+        file.display(),
+        unknown.join(", "),
+    );
+    for icon in list.icons(style) {
+        println!("cargo:rustc-cfg=heroicon=\"{icon}\"");
+    }
+}
+
+// Returns the names of the shapes in the style crate whose build script is running, from the
+// `all-icons` feature in its manifest.
+fn style_shapes() -> Vec<String> {
+    let dir =
+        env::var_os("CARGO_MANIFEST_DIR").expect("cargo sets CARGO_MANIFEST_DIR for build scripts");
+    let manifest = Path::new(&dir).join("Cargo.toml");
+    let text = fs::read_to_string(&manifest)
+        .unwrap_or_else(|e| panic!("could not read {}: {e}", manifest.display()));
+    text.parse::<Table>()
+        .ok()
+        .and_then(|t| strings(t.get("features")?.get("all-icons")?))
+        .unwrap_or_else(|| {
+            panic!(
+                "{} does not have an `all-icons` feature with the style's shapes",
+                manifest.display(),
+            )
+        })
+}
+
+// Returns the strings in a TOML array, or `None` if it's not an array of strings.
+fn strings(value: &Value) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|v| v.as_str().map(str::to_string))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let list = AllowList::parse(
+            r#"
+# Every style.
+icons = ["trash", "arrow-left"]
+outline = [
+    "bars-3", # The menu.
+    "trash",
+]
+mini = []
+"#,
+        )
+        .unwrap();
+        assert_eq!(list.icons("outline"), ["arrow-left", "bars-3", "trash"]);
+        assert_eq!(list.icons("mini"), ["arrow-left", "trash"]);
+        assert_eq!(list.icons("solid"), ["arrow-left", "trash"]);
+        assert_eq!(AllowList::parse("").unwrap(), AllowList::default());
+
+        assert_eq!(
+            AllowList::parse("other = []").unwrap_err(),
+            "unknown key `other`, expected `icons` or one of mini, outline, solid",
+        );
+        assert_eq!(
+            AllowList::parse("icons = [\"trash\", 1]").unwrap_err(),
+            "the `icons` key should be an array of icon names",
+        );
+        assert_eq!(
+            AllowList::parse("mini = \"trash\"").unwrap_err(),
+            "the `mini` key should be an array of icon names",
+        );
+        assert!(AllowList::parse("icons = [\"trash\" \"x\"]").is_err());
+        assert!(AllowList::parse("icons = [\"trash]").is_err());
+        assert!(AllowList::parse("icons = []\nicons = []").is_err());
+    }
+
+    #[test]
+    fn unknown_icons() {
+        let list = AllowList::parse(
+            "icons = [\"trash\", \"arow-left\"]\nmini = [\"bars-3\"]\nsolid = [\"cog\"]",
+        )
+        .unwrap();
+        let shapes = ["arrow-left", "bars-3", "trash"].map(String::from);
+        assert_eq!(list.unknown_icons("mini", &shapes), ["arow-left"]);
+        assert_eq!(list.unknown_icons("solid", &shapes), ["arow-left", "cog"]);
+    }
+}
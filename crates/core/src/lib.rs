@@ -0,0 +1,120 @@
+//! The data types shared by the [`dioxus-heroicons`](https://docs.rs/dioxus-heroicons) crates.
+//!
+//! Each of the `dioxus-heroicons-mini`, `dioxus-heroicons-outline`, and `dioxus-heroicons-solid`
+//! crates describes its shapes with these types, and `dioxus-heroicons` re-exports them, so most
+//! code should use them from there instead of depending on this crate directly.
+//!
+//! It also has the [`allow_list`] module, which the style crates' build scripts use to compile the
+//! icons listed in an `icons.toml` file.
+
+#[cfg(feature = "allow-list")]
+pub mod allow_list;
+mod styles;
+
+pub use styles::STYLES;
+
+/// The raw data for one `<path>` element of an icon shape. Get these for a shape with its
+/// `path_data` method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PathData {
+    /// The path's `d` attribute.
+    pub d: &'static str,
+    /// The path's `clip-rule` attribute, if it has one.
+    pub clip_rule: Option<&'static str>,
+    /// The path's `fill-rule` attribute, if it has one.
+    pub fill_rule: Option<&'static str>,
+    /// The path's own `fill` attribute, if it has one. A path without one uses the fill of its
+    /// `<svg>` element.
+    pub fill: Option<&'static str>,
+    /// The path's `fill-opacity` attribute, if it has one.
+    pub fill_opacity: Option<&'static str>,
+    /// The path's `opacity` attribute, if it has one.
+    pub opacity: Option<&'static str>,
+    /// The path's `stroke-width` attribute, if it has one.
+    pub stroke_width: Option<&'static str>,
+    /// The path's `stroke-linecap` attribute, if it has one.
+    pub stroke_linecap: Option<&'static str>,
+    /// The path's `stroke-linejoin` attribute, if it has one.
+    pub stroke_linejoin: Option<&'static str>,
+}
+
+impl PathData {
+    /// A path with an empty `d` and none of the other attributes. The style crates fill in the
+    /// attributes that each path doesn't have from this, with `..PathData::EMPTY`.
+    pub const EMPTY: PathData = PathData {
+        d: "",
+        clip_rule: None,
+        fill_rule: None,
+        fill: None,
+        fill_opacity: None,
+        opacity: None,
+        stroke_width: None,
+        stroke_linecap: None,
+        stroke_linejoin: None,
+    };
+
+    /// Returns the path's attributes other than `d`, by their SVG names, like `("fill-rule",
+    /// "evenodd")`. Attributes that the path doesn't have are left out.
+    pub fn attributes(&self) -> impl Iterator<Item = (&'static str, &'static str)> {
+        IntoIterator::into_iter([
+            ("clip-rule", self.clip_rule),
+            ("fill-rule", self.fill_rule),
+            ("fill", self.fill),
+            ("fill-opacity", self.fill_opacity),
+            ("opacity", self.opacity),
+            ("stroke-width", self.stroke_width),
+            ("stroke-linecap", self.stroke_linecap),
+            ("stroke-linejoin", self.stroke_linejoin),
+        ])
+        .filter_map(|(name, value)| Some((name, value?)))
+    }
+}
+
+/// A single icon shape, as a value instead of as a variant of a `Shape` enum. Each of the style
+/// crates has a static of this type for each of its shapes, named after its heroicons name, like
+/// `ARROW_LEFT`.
+///
+/// A `Shape` enum has to keep the data for all of its shapes, since any of them could be picked at
+/// runtime. The statics don't, so if an app only uses the statics, the linker can drop the data for
+/// every shape that it doesn't use, without turning off any cargo features.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaticShape {
+    name: &'static str,
+    view_box: &'static str,
+    paths: &'static [PathData],
+}
+
+impl StaticShape {
+    /// Creates a shape. The style crates use this for their statics.
+    #[must_use]
+    pub const fn new(
+        name: &'static str,
+        view_box: &'static str,
+        paths: &'static [PathData],
+    ) -> Self {
+        Self {
+            name,
+            view_box,
+            paths,
+        }
+    }
+
+    /// Returns the original heroicons name for the shape, like "arrow-left".
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the `viewBox` for the shape's `<svg>` element.
+    #[must_use]
+    pub const fn view_box(self) -> &'static str {
+        self.view_box
+    }
+
+    /// Returns the raw data for each of the shape's `<path>` elements, in order. Most shapes have
+    /// just one, but each path in a shape with more than one has its own rules.
+    #[must_use]
+    pub const fn path_data(self) -> &'static [PathData] {
+        self.paths
+    }
+}
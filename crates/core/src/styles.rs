@@ -0,0 +1,6 @@
+//! The names of the heroicons styles that there are crates for.
+//!
+//! This module was written by the `gen` crate from its `style-dirs.json`, so don't edit it by hand.
+
+/// The name of each style, like `mini`, in alphabetical order.
+pub const STYLES: &[&str] = &["mini", "outline", "solid"];
@@ -0,0 +1,663 @@
+//! The shapes whose names start with "a".
+/// The "academic-cap" shape.
+#[cfg(any(feature = "academic-cap", heroicon = "academic-cap"))]
+pub static ACADEMIC_CAP: super::StaticShape = super::StaticShape::new(
+    "academic-cap",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.66413 1.31866C9.87552 1.21279 10.1244 1.21279 10.3358 1.31866C13.2902 2.7983 16.0408 4.6242 18.5343 6.74302C18.7415 6.91909 18.8372 7.1935 18.7844 7.46023C18.7316 7.72695 18.5385 7.9442 18.2799 8.02802C15.4656 8.94004 12.8246 10.2376 10.4191 11.8586C10.1658 12.0293 9.8342 12.0293 9.58086 11.8586C8.90534 11.4034 8.21125 10.9737 7.49997 10.5709V9.39384C7.49997 9.1503 7.61572 8.93111 7.80165 8.80225C8.86302 8.0666 9.96638 7.38737 11.1074 6.76888C11.4715 6.57149 11.6067 6.11626 11.4093 5.75211C11.2119 5.38795 10.7567 5.25276 10.3926 5.45016C9.20323 6.09484 8.05326 6.80277 6.94716 7.56942C6.3428 7.98831 5.99997 8.67582 5.99997 9.39384V9.7741C4.62709 9.09181 3.19747 8.5068 1.7201 8.02802C1.46144 7.9442 1.26841 7.72695 1.2156 7.46023C1.16278 7.1935 1.25847 6.91909 1.46567 6.74302C3.95918 4.6242 6.70972 2.7983 9.66413 1.31866ZM5.99997 11.4596C5.20208 11.0378 4.38297 10.651 3.54469 10.3012C3.37611 11.3268 3.24559 12.3652 3.15464 13.415C3.12783 13.7244 3.29452 14.0184 3.57385 14.1542C4.10178 14.4109 4.61994 14.6847 5.12759 14.9748C4.91888 15.2987 4.67271 15.6055 4.38907 15.8891C4.09618 16.182 4.09618 16.6569 4.38907 16.9497C4.68197 17.2426 5.15684 17.2426 5.44973 16.9497C5.81938 16.5801 6.13906 16.1793 6.40876 15.7558C7.49842 16.4631 8.53293 17.2484 9.50439 18.1037C9.78772 18.3532 10.2123 18.3532 10.4956 18.1037C12.2768 16.5355 14.2699 15.2028 16.4262 14.1542C16.7055 14.0184 16.8722 13.7244 16.8454 13.415C16.7544 12.3652 16.6239 11.3268 16.4553 10.3011C14.6241 11.0653 12.8844 12.0061 11.2574 13.1025C10.4974 13.6147 9.50263 13.6147 8.7426 13.1025C8.33322 12.8267 7.91669 12.5606 7.49337 12.3048C7.44116 13.5085 7.07958 14.7023 6.40877 15.7557C5.98972 15.4837 5.56252 15.2232 5.12761 14.9747C5.70924 14.0721 5.99997 13.0367 5.99997 12V11.4596Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "adjustments-horizontal" shape.
+#[cfg(any(feature = "adjustments-horizontal", heroicon = "adjustments-horizontal"))]
+pub static ADJUSTMENTS_HORIZONTAL: super::StaticShape = super::StaticShape::new(
+    "adjustments-horizontal",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 3.75C10 2.64543 9.10457 1.75 8 1.75C6.89543 1.75 6 2.64543 6 3.75C6 4.85457 6.89543 5.75 8 5.75C9.10457 5.75 10 4.85457 10 3.75Z M17.25 4.5C17.6642 4.5 18 4.16421 18 3.75C18 3.33579 17.6642 3 17.25 3L11.75 3C11.3358 3 11 3.33579 11 3.75C11 4.16421 11.3358 4.5 11.75 4.5L17.25 4.5Z M5 3.75C5 4.16421 4.66421 4.5 4.25 4.5H2.75C2.33579 4.5 2 4.16421 2 3.75C2 3.33579 2.33579 3 2.75 3L4.25 3C4.66421 3 5 3.33579 5 3.75Z M4.25 17C4.66421 17 5 16.6642 5 16.25C5 15.8358 4.66421 15.5 4.25 15.5H2.75C2.33579 15.5 2 15.8358 2 16.25C2 16.6642 2.33579 17 2.75 17H4.25Z M17.25 17C17.6642 17 18 16.6642 18 16.25C18 15.8358 17.6642 15.5 17.25 15.5H11.75C11.3358 15.5 11 15.8358 11 16.25C11 16.6642 11.3358 17 11.75 17H17.25Z M9 10C9 10.4142 8.66421 10.75 8.25 10.75H2.75C2.33579 10.75 2 10.4142 2 10C2 9.58579 2.33579 9.25 2.75 9.25L8.25 9.25C8.66421 9.25 9 9.58579 9 10Z M17.25 10.75C17.6642 10.75 18 10.4142 18 10C18 9.58579 17.6642 9.25 17.25 9.25H15.75C15.3358 9.25 15 9.58579 15 10C15 10.4142 15.3358 10.75 15.75 10.75H17.25Z M14 10C14 8.89543 13.1046 8 12 8C10.8954 8 10 8.89543 10 10C10 11.1046 10.8954 12 12 12C13.1046 12 14 11.1046 14 10Z M10 16.25C10 15.1454 9.10457 14.25 8 14.25C6.89543 14.25 6 15.1454 6 16.25C6 17.3546 6.89543 18.25 8 18.25C9.10457 18.25 10 17.3546 10 16.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "adjustments-vertical" shape.
+#[cfg(any(feature = "adjustments-vertical", heroicon = "adjustments-vertical"))]
+pub static ADJUSTMENTS_VERTICAL: super::StaticShape = super::StaticShape::new(
+    "adjustments-vertical",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17 2.75C17 2.33579 16.6642 2 16.25 2C15.8358 2 15.5 2.33579 15.5 2.75V8.25C15.5 8.66421 15.8358 9 16.25 9C16.6642 9 17 8.66421 17 8.25V2.75Z M17 15.75C17 15.3358 16.6642 15 16.25 15C15.8358 15 15.5 15.3358 15.5 15.75V17.25C15.5 17.6642 15.8358 18 16.25 18C16.6642 18 17 17.6642 17 17.25V15.75Z M3.75 15C4.16421 15 4.5 15.3358 4.5 15.75V17.25C4.5 17.6642 4.16421 18 3.75 18C3.33579 18 3 17.6642 3 17.25V15.75C3 15.3358 3.33579 15 3.75 15Z M4.5 2.75C4.5 2.33579 4.16421 2 3.75 2C3.33579 2 3 2.33579 3 2.75V8.25C3 8.66421 3.33579 9 3.75 9C4.16421 9 4.5 8.66421 4.5 8.25V2.75Z M10 11C10.4142 11 10.75 11.3358 10.75 11.75V17.25C10.75 17.6642 10.4142 18 10 18C9.58579 18 9.25 17.6642 9.25 17.25V11.75C9.25 11.3358 9.58579 11 10 11Z M10.75 2.75C10.75 2.33579 10.4142 2 10 2C9.58579 2 9.25 2.33579 9.25 2.75V4.25C9.25 4.66421 9.58579 5 10 5C10.4142 5 10.75 4.66421 10.75 4.25V2.75Z M10 6C8.89543 6 8 6.89543 8 8C8 9.10457 8.89543 10 10 10C11.1046 10 12 9.10457 12 8C12 6.89543 11.1046 6 10 6Z M3.75 10C2.64543 10 1.75 10.8954 1.75 12C1.75 13.1046 2.64543 14 3.75 14C4.85457 14 5.75 13.1046 5.75 12C5.75 10.8954 4.85457 10 3.75 10Z M16.25 10C15.1454 10 14.25 10.8954 14.25 12C14.25 13.1046 15.1454 14 16.25 14C17.3546 14 18.25 13.1046 18.25 12C18.25 10.8954 17.3546 10 16.25 10Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "archive-box-arrow-down" shape.
+#[cfg(any(feature = "archive-box-arrow-down", heroicon = "archive-box-arrow-down"))]
+pub static ARCHIVE_BOX_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "archive-box-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 3C1.44772 3 1 3.44772 1 4V5C1 5.55228 1.44772 6 2 6H18C18.5523 6 19 5.55228 19 5V4C19 3.44772 18.5523 3 18 3H2ZM2 7.5H18L17.1885 15.2094C17.0813 16.2273 16.223 17 15.1995 17H4.80052C3.77701 17 2.91866 16.2273 2.81151 15.2094L2 7.5ZM10 9C10.4142 9 10.75 9.33579 10.75 9.75V12.2955L11.6925 11.2483C11.9696 10.9404 12.4438 10.9154 12.7517 11.1925C13.0596 11.4696 13.0846 11.9438 12.8075 12.2517L10.5575 14.7517C10.4152 14.9098 10.2126 15 10 15C9.78738 15 9.58476 14.9098 9.44253 14.7517L7.19253 12.2517C6.91543 11.9438 6.94039 11.4696 7.24828 11.1925C7.55616 10.9154 8.03038 10.9404 8.30747 11.2483L9.25 12.2955V9.75C9.25 9.33579 9.58579 9 10 9Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "archive-box-x-mark" shape.
+#[cfg(any(feature = "archive-box-x-mark", heroicon = "archive-box-x-mark"))]
+pub static ARCHIVE_BOX_X_MARK: super::StaticShape = super::StaticShape::new(
+    "archive-box-x-mark",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 3C1.44772 3 1 3.44772 1 4V5C1 5.55228 1.44772 6 2 6H18C18.5523 6 19 5.55228 19 5V4C19 3.44772 18.5523 3 18 3H2Z M2 7.5H18L17.1885 15.2094C17.0813 16.2273 16.223 17 15.1995 17H4.80052C3.77701 17 2.91866 16.2273 2.81151 15.2094L2 7.5ZM7.21967 9.21967C7.51256 8.92678 7.98744 8.92678 8.28033 9.21967L10 10.9393L11.7197 9.21967C12.0126 8.92678 12.4874 8.92678 12.7803 9.21967C13.0732 9.51256 13.0732 9.98744 12.7803 10.2803L11.0607 12L12.7803 13.7197C13.0732 14.0126 13.0732 14.4874 12.7803 14.7803C12.4874 15.0732 12.0126 15.0732 11.7197 14.7803L10 13.0607L8.28033 14.7803C7.98744 15.0732 7.51256 15.0732 7.21967 14.7803C6.92678 14.4874 6.92678 14.0126 7.21967 13.7197L8.93934 12L7.21967 10.2803C6.92678 9.98744 6.92678 9.51256 7.21967 9.21967Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "archive-box" shape.
+#[cfg(any(feature = "archive-box", heroicon = "archive-box"))]
+pub static ARCHIVE_BOX: super::StaticShape = super::StaticShape::new(
+    "archive-box",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 3C1.44772 3 1 3.44772 1 4V5C1 5.55228 1.44772 6 2 6H18C18.5523 6 19 5.55228 19 5V4C19 3.44772 18.5523 3 18 3H2Z M2 7.5H18L17.1885 15.2094C17.0813 16.2273 16.223 17 15.1995 17H4.80052C3.77701 17 2.91866 16.2273 2.81151 15.2094L2 7.5ZM7 11C7 10.4477 7.44772 10 8 10H12C12.5523 10 13 10.4477 13 11C13 11.5523 12.5523 12 12 12H8C7.44772 12 7 11.5523 7 11Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-circle" shape.
+#[cfg(any(feature = "arrow-down-circle", heroicon = "arrow-down-circle"))]
+pub static ARROW_DOWN_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "arrow-down-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM10.75 6.75C10.75 6.33579 10.4142 6 10 6C9.58579 6 9.25 6.33579 9.25 6.75V11.3401L7.29959 9.23966C7.01774 8.93613 6.54319 8.91855 6.23966 9.20041C5.93613 9.48226 5.91855 9.95681 6.20041 10.2603L9.45041 13.7603C9.59231 13.9132 9.79145 14 10 14C10.2086 14 10.4077 13.9132 10.5496 13.7603L13.7996 10.2603C14.0814 9.95681 14.0639 9.48226 13.7603 9.20041C13.4568 8.91855 12.9823 8.93613 12.7004 9.23966L10.75 11.3401V6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-left" shape.
+#[cfg(any(feature = "arrow-down-left", heroicon = "arrow-down-left"))]
+pub static ARROW_DOWN_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-down-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.7803 5.21967C14.4874 4.92678 14.0126 4.92678 13.7197 5.21967L6.5 12.4393V6.75C6.5 6.33579 6.16421 6 5.75 6C5.33579 6 5 6.33579 5 6.75V14.25C5 14.6642 5.33579 15 5.75 15H13.25C13.6642 15 14 14.6642 14 14.25C14 13.8358 13.6642 13.5 13.25 13.5H7.56066L14.7803 6.28033C15.0732 5.98744 15.0732 5.51256 14.7803 5.21967Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-on-square-stack" shape.
+#[cfg(
+    any(feature = "arrow-down-on-square-stack", heroicon = "arrow-down-on-square-stack")
+)]
+pub static ARROW_DOWN_ON_SQUARE_STACK: super::StaticShape = super::StaticShape::new(
+    "arrow-down-on-square-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.00001 1C8.41422 1 8.75001 1.33579 8.75001 1.75V6H7.25001V1.75C7.25001 1.33579 7.58579 1 8.00001 1ZM7.25001 6V9.29553L6.30748 8.24828C6.03038 7.94039 5.55617 7.91543 5.24828 8.19253C4.9404 8.46962 4.91544 8.94384 5.19254 9.25172L7.44254 11.7517C7.58477 11.9098 7.78739 12 8.00001 12C8.21262 12 8.41525 11.9098 8.55748 11.7517L10.8075 9.25172C11.0846 8.94384 11.0596 8.46962 10.7517 8.19253C10.4438 7.91543 9.96963 7.94039 9.69254 8.24828L8.75001 9.29553V6H10.75C11.9926 6 13 7.00736 13 8.25V12.75C13 13.9926 11.9926 15 10.75 15H5.25C4.00736 15 3 13.9926 3 12.75V8.25C3 7.00736 4.00736 6 5.25 6H7.25001ZM7 16.75V16.5H10.75C12.8211 16.5 14.5 14.8211 14.5 12.75V10H14.75C15.9926 10 17 11.0074 17 12.25V16.75C17 17.9926 15.9926 19 14.75 19H9.25C8.00736 19 7 17.9926 7 16.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-on-square" shape.
+#[cfg(any(feature = "arrow-down-on-square", heroicon = "arrow-down-on-square"))]
+pub static ARROW_DOWN_ON_SQUARE: super::StaticShape = super::StaticShape::new(
+    "arrow-down-on-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.75 7H10.75V12.2955L12.6925 10.2483C12.9696 9.94039 13.4438 9.91544 13.7517 10.1925C14.0596 10.4696 14.0846 10.9438 13.8075 11.2517L10.5575 14.7517C10.4152 14.9098 10.2126 15 10 15C9.78739 15 9.58477 14.9098 9.44254 14.7517L6.19254 11.2517C5.91544 10.9438 5.9404 10.4696 6.24828 10.1925C6.55617 9.91544 7.03038 9.94039 7.30748 10.2483L9.25001 12.2955V7H10.75L10.75 1.75C10.75 1.33579 10.4142 1 10 1C9.58579 1 9.25 1.33579 9.25 1.75L9.25001 7H6.25C5.00736 7 4 8.00736 4 9.25V16.75C4 17.9926 5.00736 19 6.25 19H13.75C14.9926 19 16 17.9926 16 16.75V9.25C16 8.00736 14.9926 7 13.75 7Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-right" shape.
+#[cfg(any(feature = "arrow-down-right", heroicon = "arrow-down-right"))]
+pub static ARROW_DOWN_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-down-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.28033 5.21967C5.98744 4.92678 5.51256 4.92678 5.21967 5.21967C4.92678 5.51256 4.92678 5.98744 5.21967 6.28033L12.4393 13.5H6.75C6.33579 13.5 6 13.8358 6 14.25C6 14.6642 6.33579 15 6.75 15H14.25C14.3517 15 14.4487 14.9798 14.5371 14.9431C14.6235 14.9073 14.7047 14.8547 14.7754 14.7852C14.7787 14.782 14.782 14.7787 14.7852 14.7754C14.8547 14.7047 14.9073 14.6235 14.9431 14.5371C14.9798 14.4487 15 14.3517 15 14.25V6.75C15 6.33579 14.6642 6 14.25 6C13.8358 6 13.5 6.33579 13.5 6.75V12.4393L6.28033 5.21967Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-tray" shape.
+#[cfg(any(feature = "arrow-down-tray", heroicon = "arrow-down-tray"))]
+pub static ARROW_DOWN_TRAY: super::StaticShape = super::StaticShape::new(
+    "arrow-down-tray",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.75 2.75C10.75 2.33579 10.4142 2 10 2C9.58579 2 9.25 2.33579 9.25 2.75V11.3636L6.29526 8.23503C6.01085 7.93389 5.53617 7.92033 5.23503 8.20474C4.9339 8.48915 4.92033 8.96383 5.20474 9.26497L9.45474 13.765C9.59642 13.915 9.79366 14 10 14C10.2063 14 10.4036 13.915 10.5453 13.765L14.7953 9.26497C15.0797 8.96383 15.0661 8.48915 14.765 8.20474C14.4638 7.92033 13.9892 7.93389 13.7047 8.23503L10.75 11.3636V2.75Z M3.5 12.75C3.5 12.3358 3.16421 12 2.75 12C2.33579 12 2 12.3358 2 12.75V15.25C2 16.7688 3.23122 18 4.75 18H15.25C16.7688 18 18 16.7688 18 15.25V12.75C18 12.3358 17.6642 12 17.25 12C16.8358 12 16.5 12.3358 16.5 12.75V15.25C16.5 15.9404 15.9404 16.5 15.25 16.5H4.75C4.05964 16.5 3.5 15.9404 3.5 15.25V12.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down" shape.
+#[cfg(any(feature = "arrow-down", heroicon = "arrow-down"))]
+pub static ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 3C10.4142 3 10.75 3.33579 10.75 3.75L10.75 14.3879L14.7094 10.2302C14.9965 9.93159 15.4713 9.92228 15.7698 10.2094C16.0684 10.4965 16.0777 10.9713 15.7906 11.2698L10.5406 16.7698C10.3992 16.9169 10.204 17 10 17C9.79599 17 9.60078 16.9169 9.45938 16.7698L4.20938 11.2698C3.92228 10.9713 3.93159 10.4965 4.23017 10.2094C4.52875 9.92228 5.00353 9.93159 5.29063 10.2302L9.25 14.3879L9.25 3.75C9.25 3.33579 9.58579 3 10 3Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-left-circle" shape.
+#[cfg(any(feature = "arrow-left-circle", heroicon = "arrow-left-circle"))]
+pub static ARROW_LEFT_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "arrow-left-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM13.25 10.75C13.6642 10.75 14 10.4142 14 10C14 9.58579 13.6642 9.25 13.25 9.25H8.6599L10.7603 7.29959C11.0639 7.01774 11.0814 6.54319 10.7996 6.23966C10.5177 5.93613 10.0432 5.91855 9.73966 6.2004L6.23966 9.4504C6.08684 9.59231 6 9.79145 6 10C6 10.2086 6.08684 10.4077 6.23966 10.5496L9.73966 13.7996C10.0432 14.0814 10.5177 14.0639 10.7996 13.7603C11.0814 13.4568 11.0639 12.9823 10.7603 12.7004L8.6599 10.75H13.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-left-on-rectangle" shape.
+#[cfg(any(feature = "arrow-left-on-rectangle", heroicon = "arrow-left-on-rectangle"))]
+pub static ARROW_LEFT_ON_RECTANGLE: super::StaticShape = super::StaticShape::new(
+    "arrow-left-on-rectangle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 4.25C3 3.00736 4.00736 2 5.25 2H10.75C11.9926 2 13 3.00736 13 4.25V6.25C13 6.66421 12.6642 7 12.25 7C11.8358 7 11.5 6.66421 11.5 6.25V4.25C11.5 3.83579 11.1642 3.5 10.75 3.5H5.25C4.83579 3.5 4.5 3.83579 4.5 4.25V15.75C4.5 16.1642 4.83579 16.5 5.25 16.5H10.75C11.1642 16.5 11.5 16.1642 11.5 15.75V13.75C11.5 13.3358 11.8358 13 12.25 13C12.6642 13 13 13.3358 13 13.75V15.75C13 16.9926 11.9926 18 10.75 18H5.25C4.00736 18 3 16.9926 3 15.75V4.25Z M19 10C19 9.58579 18.6642 9.25 18.25 9.25H8.70447L9.75172 8.30747C10.0596 8.03038 10.0846 7.55616 9.80747 7.24828C9.53038 6.94039 9.05616 6.91543 8.74828 7.19253L6.24828 9.44253C6.09024 9.58476 6 9.78738 6 10C6 10.2126 6.09024 10.4152 6.24828 10.5575L8.74828 12.8075C9.05616 13.0846 9.53038 13.0596 9.80747 12.7517C10.0846 12.4438 10.0596 11.9696 9.75172 11.6925L8.70447 10.75H18.25C18.6642 10.75 19 10.4142 19 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-left" shape.
+#[cfg(any(feature = "arrow-left", heroicon = "arrow-left"))]
+pub static ARROW_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17 10C17 10.4142 16.6642 10.75 16.25 10.75L5.61208 10.75L9.76983 14.7094C10.0684 14.9965 10.0777 15.4713 9.79062 15.7698C9.50353 16.0684 9.02875 16.0777 8.73017 15.7906L3.23017 10.5406C3.08311 10.3992 3 10.204 3 10C3 9.79599 3.08311 9.60078 3.23017 9.45938L8.73017 4.20938C9.02875 3.92228 9.50353 3.93159 9.79062 4.23017C10.0777 4.52875 10.0684 5.00353 9.76983 5.29063L5.61208 9.25L16.25 9.25C16.6642 9.25 17 9.58579 17 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-long-down" shape.
+#[cfg(any(feature = "arrow-long-down", heroicon = "arrow-long-down"))]
+pub static ARROW_LONG_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-long-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 2C10.4142 2 10.75 2.33579 10.75 2.75V15.3401L12.7004 13.2397C12.9823 12.9361 13.4568 12.9186 13.7603 13.2004C14.0639 13.4823 14.0815 13.9568 13.7996 14.2603L10.5496 17.7603C10.4077 17.9132 10.2086 18 10 18C9.79145 18 9.59232 17.9132 9.45041 17.7603L6.20041 14.2603C5.91856 13.9568 5.93613 13.4823 6.23966 13.2004C6.5432 12.9186 7.01775 12.9361 7.2996 13.2397L9.25 15.3401V2.75C9.25 2.33579 9.58579 2 10 2Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-long-left" shape.
+#[cfg(any(feature = "arrow-long-left", heroicon = "arrow-long-left"))]
+pub static ARROW_LONG_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-long-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18 10C18 10.4142 17.6642 10.75 17.25 10.75L4.6599 10.75L6.76034 12.7004C7.06387 12.9823 7.08145 13.4568 6.79959 13.7603C6.51774 14.0639 6.04319 14.0815 5.73966 13.7996L2.23966 10.5496C2.08684 10.4077 2 10.2086 2 10C2 9.79145 2.08684 9.59232 2.23966 9.45041L5.73966 6.20041C6.0432 5.91856 6.51774 5.93613 6.79959 6.23966C7.08145 6.5432 7.06387 7.01775 6.76034 7.2996L4.6599 9.25L17.25 9.25C17.6642 9.25 18 9.58579 18 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-long-right" shape.
+#[cfg(any(feature = "arrow-long-right", heroicon = "arrow-long-right"))]
+pub static ARROW_LONG_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-long-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 10C2 9.58579 2.33579 9.25 2.75 9.25L15.3401 9.25L13.2397 7.2996C12.9361 7.01775 12.9186 6.5432 13.2004 6.23966C13.4823 5.93613 13.9568 5.91856 14.2603 6.20041L17.7603 9.45041C17.9132 9.59232 18 9.79145 18 10C18 10.2086 17.9132 10.4077 17.7603 10.5496L14.2603 13.7996C13.9568 14.0815 13.4823 14.0639 13.2004 13.7603C12.9186 13.4568 12.9361 12.9823 13.2397 12.7004L15.3401 10.75L2.75 10.75C2.33579 10.75 2 10.4142 2 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-long-up" shape.
+#[cfg(any(feature = "arrow-long-up", heroicon = "arrow-long-up"))]
+pub static ARROW_LONG_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-long-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C9.58578 18 9.25 17.6642 9.25 17.25L9.25 4.6599L7.29959 6.76034C7.01774 7.06387 6.54319 7.08145 6.23966 6.7996C5.93612 6.51774 5.91855 6.0432 6.2004 5.73966L9.4504 2.23966C9.59231 2.08684 9.79144 2 10 2C10.2085 2 10.4077 2.08684 10.5496 2.23966L13.7996 5.73966C14.0814 6.04319 14.0639 6.51774 13.7603 6.79959C13.4568 7.08145 12.9823 7.06387 12.7004 6.76034L10.75 4.6599L10.75 17.25C10.75 17.6642 10.4142 18 10 18Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-path-rounded-square" shape.
+#[cfg(
+    any(feature = "arrow-path-rounded-square", heroicon = "arrow-path-rounded-square")
+)]
+pub static ARROW_PATH_ROUNDED_SQUARE: super::StaticShape = super::StaticShape::new(
+    "arrow-path-rounded-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 4.5C11.215 4.5 12.4171 4.55484 13.6038 4.66214C13.9249 4.69118 14.1802 4.93931 14.2185 5.25934C14.3426 6.29702 14.4265 7.34718 14.4685 8.40824L12.7804 6.71973C12.4875 6.4268 12.0126 6.42675 11.7197 6.71961C11.4268 7.01247 11.4267 7.48735 11.7196 7.78027L14.7189 10.7802C15.0117 11.0731 15.4866 11.0732 15.7795 10.7803L18.7803 7.78041C19.0732 7.48755 19.0733 7.01268 18.7804 6.71975C18.4875 6.42681 18.0127 6.42674 17.7197 6.71959L15.9719 8.46698C15.9299 7.32601 15.8413 6.19681 15.7079 5.08123C15.5855 4.05775 14.7654 3.26106 13.7389 3.16824C12.5073 3.05688 11.2602 3 10 3C8.73981 3 7.49271 3.05688 6.26115 3.16824C5.23465 3.26105 4.41449 4.05775 4.2921 5.08123C4.22831 5.61464 4.17477 6.15117 4.13167 6.69061C4.09868 7.10351 4.40666 7.46497 4.81956 7.49796C5.23245 7.53095 5.59391 7.22297 5.6269 6.81007C5.66843 6.29024 5.72003 5.77326 5.78148 5.25934C5.81975 4.93931 6.07514 4.69118 6.39623 4.66214C7.58294 4.55484 8.78497 4.5 10 4.5ZM5.28113 9.22C4.98828 8.9271 4.51345 8.92704 4.22052 9.21987L1.21976 12.2196C0.926819 12.5124 0.926735 12.9873 1.21958 13.2802C1.51242 13.5732 1.98729 13.5733 2.28024 13.2804L4.02814 11.5331C4.0701 12.6741 4.15869 13.8032 4.2921 14.9188C4.41449 15.9422 5.23465 16.7389 6.26115 16.8318C7.49271 16.9431 8.73981 17 10 17C11.2602 17 12.5073 16.9431 13.7389 16.8318C14.7654 16.7389 15.5855 15.9423 15.7079 14.9188C15.7717 14.3849 15.8253 13.848 15.8684 13.3081C15.9014 12.8952 15.5934 12.5338 15.1805 12.5008C14.7676 12.4678 14.4062 12.7758 14.3732 13.1887C14.3316 13.709 14.28 14.2263 14.2185 14.7407C14.1802 15.0607 13.9249 15.3088 13.6038 15.3379C12.4171 15.4452 11.215 15.5 10 15.5C8.78497 15.5 7.58294 15.4452 6.39623 15.3379C6.07514 15.3088 5.81975 15.0607 5.78148 14.7407C5.6574 13.703 5.57352 12.6529 5.53148 11.5919L7.21963 13.2803C7.5125 13.5732 7.98737 13.5732 8.28029 13.2804C8.57321 12.9875 8.57324 12.5126 8.28037 12.2197L5.28113 9.22Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-path" shape.
+#[cfg(any(feature = "arrow-path", heroicon = "arrow-path"))]
+pub static ARROW_PATH: super::StaticShape = super::StaticShape::new(
+    "arrow-path",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.3124 11.4236C14.5262 14.3576 11.5104 16.0988 8.5763 15.3127C7.60728 15.053 6.77024 14.5514 6.11052 13.8904L5.79898 13.5789L8.23221 13.5785C8.64642 13.5784 8.98215 13.2425 8.98208 12.8283C8.982 12.4141 8.64616 12.0784 8.23194 12.0785L3.98851 12.0792C3.78959 12.0792 3.59884 12.1583 3.45821 12.299C3.31758 12.4397 3.2386 12.6305 3.23864 12.8294L3.23954 17.0712C3.23962 17.4854 3.57548 17.8211 3.98969 17.821C4.40391 17.821 4.73962 17.4851 4.73954 17.0709L4.73903 14.6403L5.04888 14.9501C5.88991 15.7926 6.95759 16.4318 8.18808 16.7615C11.9223 17.7621 15.7607 15.5461 16.7613 11.8118C16.8685 11.4117 16.6311 11.0005 16.231 10.8932C15.8309 10.786 15.4196 11.0235 15.3124 11.4236ZM16.5413 7.70119C16.682 7.56051 16.761 7.36971 16.7609 7.17078L16.7603 2.92883C16.7602 2.51462 16.4243 2.17889 16.0101 2.17896C15.5959 2.17902 15.2602 2.51487 15.2603 2.92908L15.2606 5.35995L14.9508 5.05013C14.1098 4.20771 13.0419 3.56827 11.8115 3.23859C8.07728 2.238 4.23892 4.45407 3.23833 8.18834C3.13112 8.58844 3.36856 8.99969 3.76866 9.1069C4.16876 9.2141 4.58001 8.97666 4.68722 8.57657C5.4734 5.6425 8.48925 3.9013 11.4233 4.68748C12.3924 4.94714 13.2294 5.44881 13.8892 6.10979L14.2003 6.4209L11.7683 6.4209C11.3541 6.4209 11.0183 6.75669 11.0183 7.1709C11.0183 7.58512 11.3541 7.9209 11.7683 7.9209H16.0109C16.2099 7.9209 16.4007 7.84187 16.5413 7.70119Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-right-circle" shape.
+#[cfg(any(feature = "arrow-right-circle", heroicon = "arrow-right-circle"))]
+pub static ARROW_RIGHT_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "arrow-right-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM6.75 9.25C6.33579 9.25 6 9.58579 6 10C6 10.4142 6.33579 10.75 6.75 10.75H11.3401L9.23966 12.7004C8.93613 12.9823 8.91855 13.4568 9.20041 13.7603C9.48226 14.0639 9.95681 14.0814 10.2603 13.7996L13.7603 10.5496C13.9132 10.4077 14 10.2086 14 10C14 9.79145 13.9132 9.59231 13.7603 9.4504L10.2603 6.2004C9.95681 5.91855 9.48226 5.93613 9.2004 6.23966C8.91855 6.54319 8.93613 7.01774 9.23966 7.2996L11.3401 9.25H6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-right-on-rectangle" shape.
+#[cfg(any(feature = "arrow-right-on-rectangle", heroicon = "arrow-right-on-rectangle"))]
+pub static ARROW_RIGHT_ON_RECTANGLE: super::StaticShape = super::StaticShape::new(
+    "arrow-right-on-rectangle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 4.25C3 3.00736 4.00736 2 5.25 2H10.75C11.9926 2 13 3.00736 13 4.25V6.25C13 6.66421 12.6642 7 12.25 7C11.8358 7 11.5 6.66421 11.5 6.25V4.25C11.5 3.83579 11.1642 3.5 10.75 3.5H5.25C4.83579 3.5 4.5 3.83579 4.5 4.25V15.75C4.5 16.1642 4.83579 16.5 5.25 16.5H10.75C11.1642 16.5 11.5 16.1642 11.5 15.75V13.75C11.5 13.3358 11.8358 13 12.25 13C12.6642 13 13 13.3358 13 13.75V15.75C13 16.9926 11.9926 18 10.75 18H5.25C4.00736 18 3 16.9926 3 15.75V4.25Z M6 10C6 9.58579 6.33579 9.25 6.75 9.25H16.2955L15.2483 8.30747C14.9404 8.03038 14.9154 7.55616 15.1925 7.24828C15.4696 6.94039 15.9438 6.91543 16.2517 7.19253L18.7517 9.44253C18.9098 9.58476 19 9.78738 19 10C19 10.2126 18.9098 10.4152 18.7517 10.5575L16.2517 12.8075C15.9438 13.0846 15.4696 13.0596 15.1925 12.7517C14.9154 12.4438 14.9404 11.9696 15.2483 11.6925L16.2955 10.75H6.75C6.33579 10.75 6 10.4142 6 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-right" shape.
+#[cfg(any(feature = "arrow-right", heroicon = "arrow-right"))]
+pub static ARROW_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 10C3 9.58579 3.33579 9.25 3.75 9.25L14.3879 9.25L10.2302 5.29062C9.93159 5.00353 9.92228 4.52875 10.2094 4.23017C10.4965 3.93159 10.9713 3.92228 11.2698 4.20937L16.7698 9.45937C16.9169 9.60078 17 9.79599 17 10C17 10.204 16.9169 10.3992 16.7698 10.5406L11.2698 15.7906C10.9713 16.0777 10.4965 16.0684 10.2094 15.7698C9.92228 15.4713 9.93159 14.9965 10.2302 14.7094L14.3879 10.75L3.75 10.75C3.33579 10.75 3 10.4142 3 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-small-down" shape.
+#[cfg(any(feature = "arrow-small-down", heroicon = "arrow-small-down"))]
+pub static ARROW_SMALL_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-small-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 5C10.4142 5 10.75 5.33579 10.75 5.75V12.3879L12.7094 10.2302C12.9965 9.93159 13.4713 9.92228 13.7698 10.2094C14.0684 10.4965 14.0777 10.9713 13.7906 11.2698L10.5406 14.7698C10.3992 14.9169 10.204 15 10 15C9.79599 15 9.60078 14.9169 9.45938 14.7698L6.20938 11.2698C5.92228 10.9713 5.93159 10.4965 6.23017 10.2094C6.52875 9.92228 7.00353 9.93159 7.29063 10.2302L9.25 12.3879V5.75C9.25 5.33579 9.58579 5 10 5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-small-left" shape.
+#[cfg(any(feature = "arrow-small-left", heroicon = "arrow-small-left"))]
+pub static ARROW_SMALL_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-small-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 10C15 10.4142 14.6642 10.75 14.25 10.75H7.61208L9.76983 12.7094C10.0684 12.9965 10.0777 13.4713 9.79062 13.7698C9.50353 14.0684 9.02875 14.0777 8.73017 13.7906L5.23017 10.5406C5.08311 10.3992 5 10.204 5 10C5 9.79599 5.08311 9.60078 5.23017 9.45938L8.73017 6.20938C9.02875 5.92228 9.50353 5.93159 9.79062 6.23017C10.0777 6.52875 10.0684 7.00353 9.76983 7.29063L7.61208 9.25L14.25 9.25C14.6642 9.25 15 9.58579 15 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-small-right" shape.
+#[cfg(any(feature = "arrow-small-right", heroicon = "arrow-small-right"))]
+pub static ARROW_SMALL_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-small-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5 10C5 9.58579 5.33579 9.25 5.75 9.25H12.3879L10.2302 7.29063C9.93159 7.00353 9.92228 6.52875 10.2094 6.23017C10.4965 5.93159 10.9713 5.92228 11.2698 6.20938L14.7698 9.45938C14.9169 9.60078 15 9.79599 15 10C15 10.204 14.9169 10.3992 14.7698 10.5406L11.2698 13.7906C10.9713 14.0777 10.4965 14.0684 10.2094 13.7698C9.92228 13.4713 9.93159 12.9965 10.2302 12.7094L12.3879 10.75H5.75C5.33579 10.75 5 10.4142 5 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-small-up" shape.
+#[cfg(any(feature = "arrow-small-up", heroicon = "arrow-small-up"))]
+pub static ARROW_SMALL_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-small-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 15C9.58579 15 9.25 14.6642 9.25 14.25L9.25 7.61208L7.29063 9.76983C7.00353 10.0684 6.52875 10.0777 6.23017 9.79062C5.93159 9.50353 5.92228 9.02875 6.20938 8.73017L9.45938 5.23017C9.60078 5.08311 9.79599 5 10 5C10.204 5 10.3992 5.08311 10.5406 5.23017L13.7906 8.73017C14.0777 9.02875 14.0684 9.50353 13.7698 9.79062C13.4713 10.0777 12.9965 10.0684 12.7094 9.76983L10.75 7.61208V14.25C10.75 14.6642 10.4142 15 10 15Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-top-right-on-square" shape.
+#[cfg(
+    any(feature = "arrow-top-right-on-square", heroicon = "arrow-top-right-on-square")
+)]
+pub static ARROW_TOP_RIGHT_ON_SQUARE: super::StaticShape = super::StaticShape::new(
+    "arrow-top-right-on-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.25 5.5C3.83579 5.5 3.5 5.83579 3.5 6.25V14.75C3.5 15.1642 3.83579 15.5 4.25 15.5H12.75C13.1642 15.5 13.5 15.1642 13.5 14.75V10.75C13.5 10.3358 13.8358 10 14.25 10C14.6642 10 15 10.3358 15 10.75V14.75C15 15.9926 13.9926 17 12.75 17H4.25C3.00736 17 2 15.9926 2 14.75V6.25C2 5.00736 3.00736 4 4.25 4H9.25C9.66421 4 10 4.33579 10 4.75C10 5.16421 9.66421 5.5 9.25 5.5H4.25Z M6.19385 12.7532C6.47175 13.0603 6.94603 13.0841 7.25319 12.8062L16.5 4.43999V7.25C16.5 7.66421 16.8358 8 17.25 8C17.6642 8 18 7.66421 18 7.25V2.75C18 2.33579 17.6642 2 17.25 2H12.75C12.3358 2 12 2.33579 12 2.75C12 3.16421 12.3358 3.5 12.75 3.5H15.3032L6.24682 11.6938C5.93966 11.9717 5.91595 12.446 6.19385 12.7532Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-trending-down" shape.
+#[cfg(any(feature = "arrow-trending-down", heroicon = "arrow-trending-down"))]
+pub static ARROW_TRENDING_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-trending-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1.21967 5.22211C1.51256 4.92922 1.98744 4.92922 2.28033 5.22211L7 9.94178L10.7685 6.17329C10.9187 6.02306 11.1256 5.94359 11.3378 5.95463C11.55 5.96568 11.7475 6.06619 11.8813 6.23121C13.5732 8.31739 14.888 10.7612 15.6939 13.4849L17.2685 10.7576C17.4756 10.3989 17.9343 10.276 18.293 10.4831C18.6517 10.6902 18.7747 11.1489 18.5675 11.5076L16.0927 15.7942C15.8856 16.153 15.4269 16.2759 15.0682 16.0688L10.7815 13.5939C10.4228 13.3868 10.2999 12.9281 10.507 12.5694C10.7141 12.2106 11.1728 12.0877 11.5315 12.2949L14.2401 13.8586C13.5741 11.6301 12.5419 9.60646 11.2278 7.83529L7.53033 11.5328C7.38968 11.6734 7.19891 11.7524 7 11.7524C6.80109 11.7524 6.61032 11.6734 6.46967 11.5328L1.21967 6.28277C0.926777 5.98988 0.926777 5.515 1.21967 5.22211Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-trending-up" shape.
+#[cfg(any(feature = "arrow-trending-up", heroicon = "arrow-trending-up"))]
+pub static ARROW_TRENDING_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-trending-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.577 4.87834C12.6842 4.47824 13.0955 4.2408 13.4956 4.34801L18.2766 5.6291C18.4688 5.68058 18.6326 5.80628 18.732 5.97854C18.8315 6.1508 18.8585 6.35552 18.807 6.54766L17.5259 11.3287C17.4187 11.7288 17.0074 11.9663 16.6073 11.8591C16.2072 11.7519 15.9698 11.3406 16.077 10.9405L16.8865 7.9195C14.6303 9.30965 12.7541 11.0901 11.2935 13.1222C11.1651 13.3009 10.9646 13.4142 10.7452 13.432C10.5259 13.4499 10.3098 13.3704 10.1542 13.2148L7 10.0607L2.28033 14.7803C1.98744 15.0732 1.51256 15.0732 1.21967 14.7803C0.926777 14.4874 0.926777 14.0126 1.21967 13.7197L6.46967 8.46968C6.76256 8.17679 7.23744 8.17679 7.53033 8.46968L10.6039 11.5433C12.1049 9.63051 13.9633 7.9506 16.1492 6.61197L13.1073 5.7969C12.7072 5.68969 12.4698 5.27844 12.577 4.87834Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-circle" shape.
+#[cfg(any(feature = "arrow-up-circle", heroicon = "arrow-up-circle"))]
+pub static ARROW_UP_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "arrow-up-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM9.25 13.25C9.25 13.6642 9.58579 14 10 14C10.4142 14 10.75 13.6642 10.75 13.25V8.6599L12.7004 10.7603C12.9823 11.0639 13.4568 11.0814 13.7603 10.7996C14.0639 10.5177 14.0814 10.0432 13.7996 9.73966L10.5496 6.23966C10.4077 6.08684 10.2086 6 10 6C9.79145 6 9.59231 6.08684 9.45041 6.23966L6.20041 9.73966C5.91855 10.0432 5.93613 10.5177 6.23966 10.7996C6.54319 11.0814 7.01774 11.0639 7.2996 10.7603L9.25 8.6599V13.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-left" shape.
+#[cfg(any(feature = "arrow-up-left", heroicon = "arrow-up-left"))]
+pub static ARROW_UP_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-up-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.7803 14.7803C14.4874 15.0732 14.0126 15.0732 13.7197 14.7803L6.5 7.56066V13.25C6.5 13.6642 6.16421 14 5.75 14C5.33579 14 5 13.6642 5 13.25V5.75C5 5.33579 5.33579 5 5.75 5H13.25C13.6642 5 14 5.33579 14 5.75C14 6.16421 13.6642 6.5 13.25 6.5H7.56066L14.7803 13.7197C15.0732 14.0126 15.0732 14.4874 14.7803 14.7803Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-on-square-stack" shape.
+#[cfg(any(feature = "arrow-up-on-square-stack", heroicon = "arrow-up-on-square-stack"))]
+pub static ARROW_UP_ON_SQUARE_STACK: super::StaticShape = super::StaticShape::new(
+    "arrow-up-on-square-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.75 6L8.75 6V10.25C8.75 10.6642 8.41421 11 8 11C7.58579 11 7.25 10.6642 7.25 10.25V6L8.74999 6V3.70447L9.69252 4.75172C9.96962 5.05961 10.4438 5.08456 10.7517 4.80747C11.0596 4.53038 11.0846 4.05616 10.8075 3.74828L8.55747 1.24828C8.41523 1.09024 8.21261 1 7.99999 1C7.78738 1 7.58476 1.09024 7.44252 1.24828L5.19252 3.74828C4.91543 4.05616 4.94039 4.53038 5.24827 4.80747C5.55615 5.08456 6.03037 5.05961 6.30746 4.75172L7.24999 3.70447V6H5.25C4.00736 6 3 7.00736 3 8.25V12.75C3 13.9926 4.00736 15 5.25 15H10.75C11.9926 15 13 13.9926 13 12.75V8.25C13 7.00736 11.9926 6 10.75 6ZM7 16.75V16.5H10.75C12.8211 16.5 14.5 14.8211 14.5 12.75V10H14.75C15.9926 10 17 11.0074 17 12.25V16.75C17 17.9926 15.9926 19 14.75 19H9.25C8.00736 19 7 17.9926 7 16.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-on-square" shape.
+#[cfg(any(feature = "arrow-up-on-square", heroicon = "arrow-up-on-square"))]
+pub static ARROW_UP_ON_SQUARE: super::StaticShape = super::StaticShape::new(
+    "arrow-up-on-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.75 7H10.75L10.75 3.6599L12.7004 5.76034C12.9823 6.06387 13.4568 6.08145 13.7603 5.7996C14.0639 5.51774 14.0814 5.04319 13.7996 4.73966L10.5496 1.23966C10.4077 1.08684 10.2085 1 10 1C9.79145 1 9.59231 1.08684 9.4504 1.23966L6.2004 4.73966C5.91855 5.04319 5.93613 5.51774 6.23966 5.79959C6.54319 6.08145 7.01774 6.06387 7.29959 5.76034L9.25 3.6599L9.25 7H6.25C5.00736 7 4 8.00736 4 9.25V16.75C4 17.9926 5.00736 19 6.25 19H13.75C14.9926 19 16 17.9926 16 16.75V9.25C16 8.00736 14.9926 7 13.75 7ZM10.75 7H9.25L9.25 12.25C9.25 12.6642 9.58579 13 10 13C10.4142 13 10.75 12.6642 10.75 12.25L10.75 7Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-right" shape.
+#[cfg(any(feature = "arrow-up-right", heroicon = "arrow-up-right"))]
+pub static ARROW_UP_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-up-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.21967 14.7803C5.51256 15.0732 5.98744 15.0732 6.28033 14.7803L13.5 7.56066V13.25C13.5 13.6642 13.8358 14 14.25 14C14.6642 14 15 13.6642 15 13.25V5.75C15 5.33579 14.6642 5 14.25 5H6.75C6.33579 5 6 5.33579 6 5.75C6 6.16421 6.33579 6.5 6.75 6.5H12.4393L5.21967 13.7197C4.92678 14.0126 4.92678 14.4874 5.21967 14.7803Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-tray" shape.
+#[cfg(any(feature = "arrow-up-tray", heroicon = "arrow-up-tray"))]
+pub static ARROW_UP_TRAY: super::StaticShape = super::StaticShape::new(
+    "arrow-up-tray",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.25 13.25C9.25 13.6642 9.58578 14 10 14C10.4142 14 10.75 13.6642 10.75 13.25L10.75 4.63642L13.7047 7.76497C13.9891 8.06611 14.4638 8.07967 14.765 7.79526C15.0661 7.51085 15.0797 7.03617 14.7953 6.73503L10.5453 2.23503C10.4036 2.08501 10.2063 2 10 2C9.79365 2 9.59642 2.08501 9.45474 2.23503L5.20474 6.73503C4.92033 7.03617 4.93389 7.51085 5.23503 7.79526C5.53617 8.07967 6.01085 8.06611 6.29526 7.76497L9.25 4.63642L9.25 13.25Z M3.5 12.75C3.5 12.3358 3.16421 12 2.75 12C2.33579 12 2 12.3358 2 12.75V15.25C2 16.7688 3.23122 18 4.75 18H15.25C16.7688 18 18 16.7688 18 15.25V12.75C18 12.3358 17.6642 12 17.25 12C16.8358 12 16.5 12.3358 16.5 12.75V15.25C16.5 15.9404 15.9404 16.5 15.25 16.5H4.75C4.05964 16.5 3.5 15.9404 3.5 15.25V12.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up" shape.
+#[cfg(any(feature = "arrow-up", heroicon = "arrow-up"))]
+pub static ARROW_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 17C9.58579 17 9.25 16.6642 9.25 16.25L9.25 5.61208L5.29062 9.76983C5.00353 10.0684 4.52875 10.0777 4.23017 9.79063C3.93159 9.50353 3.92228 9.02875 4.20937 8.73017L9.45937 3.23017C9.60078 3.08311 9.79598 3 10 3C10.204 3 10.3992 3.08311 10.5406 3.23017L15.7906 8.73017C16.0777 9.02875 16.0684 9.50353 15.7698 9.79062C15.4713 10.0777 14.9965 10.0684 14.7094 9.76983L10.75 5.61208L10.75 16.25C10.75 16.6642 10.4142 17 10 17Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-uturn-down" shape.
+#[cfg(any(feature = "arrow-uturn-down", heroicon = "arrow-uturn-down"))]
+pub static ARROW_UTURN_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-uturn-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.23214 12.2075C2.53177 11.9215 3.00651 11.9325 3.29252 12.2321L7.25 16.3781V6.375C7.25 3.40647 9.65647 1 12.625 1C15.5935 1 18 3.40647 18 6.375V9.25C18 9.66421 17.6642 10 17.25 10C16.8358 10 16.5 9.66421 16.5 9.25V6.375C16.5 4.2349 14.7651 2.5 12.625 2.5C10.4849 2.5 8.75 4.2349 8.75 6.375V16.3781L12.7075 12.2321C12.9935 11.9325 13.4682 11.9215 13.7679 12.2075C14.0675 12.4935 14.0785 12.9682 13.7925 13.2679L8.54252 18.7679C8.401 18.9161 8.20496 19 8 19C7.79504 19 7.59901 18.9161 7.45748 18.7679L2.20748 13.2679C1.92148 12.9682 1.93252 12.4935 2.23214 12.2075Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-uturn-left" shape.
+#[cfg(any(feature = "arrow-uturn-left", heroicon = "arrow-uturn-left"))]
+pub static ARROW_UTURN_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-uturn-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.79252 2.23214C8.07852 2.53177 8.06748 3.00651 7.76786 3.29252L3.62192 7.25H13.625C16.5935 7.25 19 9.65647 19 12.625C19 15.5935 16.5935 18 13.625 18H10.75C10.3358 18 10 17.6642 10 17.25C10 16.8358 10.3358 16.5 10.75 16.5H13.625C15.7651 16.5 17.5 14.7651 17.5 12.625C17.5 10.4849 15.7651 8.75 13.625 8.75H3.62192L7.76786 12.7075C8.06748 12.9935 8.07852 13.4682 7.79252 13.7679C7.50651 14.0675 7.03177 14.0785 6.73214 13.7925L1.23214 8.54252C1.08388 8.401 1 8.20496 1 8C1 7.79504 1.08388 7.59901 1.23214 7.45748L6.73214 2.20748C7.03177 1.92148 7.50651 1.93252 7.79252 2.23214Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-uturn-right" shape.
+#[cfg(any(feature = "arrow-uturn-right", heroicon = "arrow-uturn-right"))]
+pub static ARROW_UTURN_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-uturn-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.2075 2.23214C11.9215 2.53177 11.9325 3.00651 12.2321 3.29252L16.3781 7.25H6.375C3.40647 7.25 1 9.65647 1 12.625C1 15.5935 3.40647 18 6.375 18H9.25C9.66421 18 10 17.6642 10 17.25C10 16.8358 9.66421 16.5 9.25 16.5H6.375C4.2349 16.5 2.5 14.7651 2.5 12.625C2.5 10.4849 4.2349 8.75 6.375 8.75H16.3781L12.2321 12.7075C11.9325 12.9935 11.9215 13.4682 12.2075 13.7679C12.4935 14.0675 12.9682 14.0785 13.2679 13.7925L18.7679 8.54252C18.9161 8.401 19 8.20496 19 8C19 7.79504 18.9161 7.59901 18.7679 7.45748L13.2679 2.20748C12.9682 1.92148 12.4935 1.93252 12.2075 2.23214Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-uturn-up" shape.
+#[cfg(any(feature = "arrow-uturn-up", heroicon = "arrow-uturn-up"))]
+pub static ARROW_UTURN_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-uturn-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.7679 7.79252C17.4682 8.07852 16.9935 8.06748 16.7075 7.76786L12.75 3.62192L12.75 13.625C12.75 16.5935 10.3435 19 7.375 19C4.40647 19 2 16.5935 2 13.625L2 10.75C2 10.3358 2.33579 10 2.75 10C3.16421 10 3.5 10.3358 3.5 10.75L3.5 13.625C3.5 15.7651 5.2349 17.5 7.375 17.5C9.5151 17.5 11.25 15.7651 11.25 13.625L11.25 3.62192L7.29252 7.76786C7.00651 8.06748 6.53177 8.07852 6.23214 7.79252C5.93252 7.50651 5.92148 7.03177 6.20748 6.73214L11.4575 1.23214C11.599 1.08388 11.795 1 12 1C12.205 1 12.401 1.08388 12.5425 1.23214L17.7925 6.73214C18.0785 7.03177 18.0675 7.50651 17.7679 7.79252Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrows-pointing-in" shape.
+#[cfg(any(feature = "arrows-pointing-in", heroicon = "arrows-pointing-in"))]
+pub static ARROWS_POINTING_IN: super::StaticShape = super::StaticShape::new(
+    "arrows-pointing-in",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.28033 2.21967C2.98744 1.92678 2.51256 1.92678 2.21967 2.21967C1.92678 2.51256 1.92678 2.98744 2.21967 3.28033L5.43934 6.5H2.75C2.33579 6.5 2 6.83579 2 7.25C2 7.66421 2.33579 8 2.75 8H7.25C7.66421 8 8 7.66421 8 7.25V2.75C8 2.33579 7.66421 2 7.25 2C6.83579 2 6.5 2.33579 6.5 2.75V5.43934L3.28033 2.21967Z M13.5 2.75C13.5 2.33579 13.1642 2 12.75 2C12.3358 2 12 2.33579 12 2.75V7.25C12 7.66421 12.3358 8 12.75 8H17.25C17.6642 8 18 7.66421 18 7.25C18 6.83579 17.6642 6.5 17.25 6.5H14.5607L17.7803 3.28033C18.0732 2.98744 18.0732 2.51256 17.7803 2.21967C17.4874 1.92678 17.0126 1.92678 16.7197 2.21967L13.5 5.43934V2.75Z M3.28033 17.7803L6.5 14.5607V17.25C6.5 17.6642 6.83579 18 7.25 18C7.66421 18 8 17.6642 8 17.25V12.75C8 12.3358 7.66421 12 7.25 12H2.75C2.33579 12 2 12.3358 2 12.75C2 13.1642 2.33579 13.5 2.75 13.5H5.43934L2.21967 16.7197C1.92678 17.0126 1.92678 17.4874 2.21967 17.7803C2.51256 18.0732 2.98744 18.0732 3.28033 17.7803Z M13.5 14.5607L16.7197 17.7803C17.0126 18.0732 17.4874 18.0732 17.7803 17.7803C18.0732 17.4874 18.0732 17.0126 17.7803 16.7197L14.5607 13.5H17.25C17.6642 13.5 18 13.1642 18 12.75C18 12.3358 17.6642 12 17.25 12H12.75C12.3358 12 12 12.3358 12 12.75V17.25C12 17.6642 12.3358 18 12.75 18C13.1642 18 13.5 17.6642 13.5 17.25V14.5607Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrows-pointing-out" shape.
+#[cfg(any(feature = "arrows-pointing-out", heroicon = "arrows-pointing-out"))]
+pub static ARROWS_POINTING_OUT: super::StaticShape = super::StaticShape::new(
+    "arrows-pointing-out",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.2803 7.78033L16.5 4.56066V7.25C16.5 7.66421 16.8358 8 17.25 8C17.6642 8 18 7.66421 18 7.25V2.75C18 2.33579 17.6642 2 17.25 2H12.75C12.3358 2 12 2.33579 12 2.75C12 3.16421 12.3358 3.5 12.75 3.5H15.4393L12.2197 6.71967C11.9268 7.01256 11.9268 7.48744 12.2197 7.78033C12.5126 8.07322 12.9874 8.07322 13.2803 7.78033Z M2 17.25V12.75C2 12.3358 2.33579 12 2.75 12C3.16421 12 3.5 12.3358 3.5 12.75V15.4393L6.71967 12.2197C7.01256 11.9268 7.48744 11.9268 7.78033 12.2197C8.07322 12.5126 8.07322 12.9874 7.78033 13.2803L4.56066 16.5H7.25C7.66421 16.5 8 16.8358 8 17.25C8 17.6642 7.66421 18 7.25 18H2.75C2.55806 18 2.36612 17.9268 2.21967 17.7803C2.14776 17.7084 2.09351 17.6255 2.05691 17.5371C2.02024 17.4487 2 17.3517 2 17.25Z M12.2197 13.2803L15.4393 16.5H12.75C12.3358 16.5 12 16.8358 12 17.25C12 17.6642 12.3358 18 12.75 18H17.25C17.4419 18 17.6339 17.9268 17.7803 17.7803C17.8522 17.7084 17.9065 17.6255 17.9431 17.5371C17.9798 17.4487 18 17.3517 18 17.25V12.75C18 12.3358 17.6642 12 17.25 12C16.8358 12 16.5 12.3358 16.5 12.75V15.4393L13.2803 12.2197C12.9874 11.9268 12.5126 11.9268 12.2197 12.2197C11.9268 12.5126 11.9268 12.9874 12.2197 13.2803Z M3.5 4.56066L6.71967 7.78033C7.01256 8.07322 7.48744 8.07322 7.78033 7.78033C8.07322 7.48744 8.07322 7.01256 7.78033 6.71967L4.56066 3.5H7.25C7.66421 3.5 8 3.16421 8 2.75C8 2.33579 7.66421 2 7.25 2H2.75C2.33579 2 2 2.33579 2 2.75V7.25C2 7.66421 2.33579 8 2.75 8C3.16421 8 3.5 7.66421 3.5 7.25V4.56066Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrows-right-left" shape.
+#[cfg(any(feature = "arrows-right-left", heroicon = "arrows-right-left"))]
+pub static ARROWS_RIGHT_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrows-right-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.2004 2.23966C12.9186 2.5432 12.9361 3.01775 13.2397 3.2996L15.3401 5.25L6.75 5.25C6.33579 5.25 6 5.58579 6 6C6 6.41422 6.33579 6.75 6.75 6.75L15.3401 6.75L13.2397 8.70041C12.9361 8.98226 12.9186 9.45681 13.2004 9.76034C13.4823 10.0639 13.9568 10.0815 14.2603 9.7996L17.7603 6.5496C17.9132 6.40769 18 6.20855 18 6C18 5.79145 17.9132 5.59232 17.7603 5.45041L14.2603 2.20041C13.9568 1.91856 13.4823 1.93613 13.2004 2.23966ZM6.79959 10.2397C6.51774 9.93613 6.04319 9.91856 5.73966 10.2004L2.23966 13.4504C2.08684 13.5923 2 13.7915 2 14C2 14.2086 2.08684 14.4077 2.23966 14.5496L5.73966 17.7996C6.04319 18.0815 6.51774 18.0639 6.79959 17.7603C7.08145 17.4568 7.06387 16.9823 6.76034 16.7004L4.6599 14.75H13.25C13.6642 14.75 14 14.4142 14 14C14 13.5858 13.6642 13.25 13.25 13.25H4.6599L6.76034 11.2996C7.06387 11.0177 7.08145 10.5432 6.79959 10.2397Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrows-up-down" shape.
+#[cfg(any(feature = "arrows-up-down", heroicon = "arrows-up-down"))]
+pub static ARROWS_UP_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrows-up-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.23966 6.7996C2.5432 7.08145 3.01775 7.06387 3.2996 6.76034L5.25 4.6599L5.25 13.25C5.25 13.6642 5.58579 14 6 14C6.41422 14 6.75 13.6642 6.75 13.25V4.6599L8.70041 6.76034C8.98226 7.06387 9.45681 7.08145 9.76034 6.79959C10.0639 6.51774 10.0815 6.04319 9.7996 5.73966L6.5496 2.23966C6.40769 2.08684 6.20855 2 6 2C5.79145 2 5.59232 2.08684 5.45041 2.23966L2.20041 5.73966C1.91856 6.04319 1.93613 6.51774 2.23966 6.7996ZM10.2397 13.2004C9.93613 13.4823 9.91856 13.9568 10.2004 14.2603L13.4504 17.7603C13.5923 17.9132 13.7915 18 14 18C14.2086 18 14.4077 17.9132 14.5496 17.7603L17.7996 14.2603C18.0815 13.9568 18.0639 13.4823 17.7603 13.2004C17.4568 12.9186 16.9823 12.9361 16.7004 13.2397L14.75 15.3401V6.75C14.75 6.33579 14.4142 6 14 6C13.5858 6 13.25 6.33579 13.25 6.75V15.3401L11.2996 13.2397C11.0177 12.9361 10.5432 12.9186 10.2397 13.2004Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "at-symbol" shape.
+#[cfg(any(feature = "at-symbol", heroicon = "at-symbol"))]
+pub static AT_SYMBOL: super::StaticShape = super::StaticShape::new(
+    "at-symbol",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.40403 14.5962C2.86563 12.0578 2.86563 7.94221 5.40403 5.40381C7.94244 2.8654 12.058 2.8654 14.5964 5.40381C15.8658 6.67316 16.5002 8.33534 16.5002 10C16.5002 10.6904 15.9404 11.25 15.25 11.25C14.5596 11.25 14 10.6904 14 10C14 7.79086 12.2091 6 10 6C7.79086 6 6 7.79086 6 10C6 12.2091 7.79086 14 10 14C11.4553 14 12.7292 13.2228 13.429 12.0607C13.914 12.4897 14.5516 12.75 15.25 12.75C16.7614 12.75 17.9881 11.5307 17.9999 10.022C18.0001 10.0147 18.0002 10.0074 18.0002 10C18.0002 7.95378 17.219 5.9051 15.6571 4.34315C12.5329 1.21895 7.46757 1.21895 4.34337 4.34315C1.21918 7.46734 1.21918 12.5327 4.34337 15.6569C7.46757 18.781 12.5329 18.781 15.6571 15.6569C15.95 15.364 15.95 14.8891 15.6571 14.5962C15.3642 14.3033 14.8893 14.3033 14.5964 14.5962C12.058 17.1346 7.94244 17.1346 5.40403 14.5962ZM10 7.5C8.61929 7.5 7.5 8.61929 7.5 10C7.5 11.3807 8.61929 12.5 10 12.5C11.3807 12.5 12.5 11.3807 12.5 10C12.5 8.61929 11.3807 7.5 10 7.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,423 @@
+//! The shapes whose names start with "b".
+/// The "backspace" shape.
+#[cfg(any(feature = "backspace", heroicon = "backspace"))]
+pub static BACKSPACE: super::StaticShape = super::StaticShape::new(
+    "backspace",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.21967 3.21967C7.36032 3.07902 7.55109 3 7.75 3H16.75C17.9926 3 19 4.00736 19 5.25V14.75C19 15.9926 17.9926 17 16.75 17H7.75C7.55109 17 7.36032 16.921 7.21967 16.7803L0.96967 10.5303C0.676777 10.2374 0.676777 9.76256 0.96967 9.46967L7.21967 3.21967ZM10.2803 7.21967C9.98744 6.92678 9.51256 6.92678 9.21967 7.21967C8.92678 7.51256 8.92678 7.98744 9.21967 8.28033L10.9393 10L9.21967 11.7197C8.92678 12.0126 8.92678 12.4874 9.21967 12.7803C9.51256 13.0732 9.98744 13.0732 10.2803 12.7803L12 11.0607L13.7197 12.7803C14.0126 13.0732 14.4874 13.0732 14.7803 12.7803C15.0732 12.4874 15.0732 12.0126 14.7803 11.7197L13.0607 10L14.7803 8.28033C15.0732 7.98744 15.0732 7.51256 14.7803 7.21967C14.4874 6.92678 14.0126 6.92678 13.7197 7.21967L12 8.93934L10.2803 7.21967Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "backward" shape.
+#[cfg(any(feature = "backward", heroicon = "backward"))]
+pub static BACKWARD: super::StaticShape = super::StaticShape::new(
+    "backward",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.71176 4.81895C8.71109 4.20172 10 4.92057 10 6.09515V9.06794C10.1044 8.93679 10.234 8.81991 10.389 8.72419L16.7118 4.81895C17.7111 4.20172 19 4.92057 19 6.09515V13.9056C19 15.0802 17.7111 15.7991 16.7118 15.1818L10.389 11.2766C10.234 11.1809 10.1044 11.064 10 10.9328V13.9056C10 15.0802 8.7111 15.7991 7.71176 15.1818L1.38899 11.2766C0.439979 10.6904 0.439975 9.31035 1.38899 8.72419L7.71176 4.81895Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "banknotes" shape.
+#[cfg(any(feature = "banknotes", heroicon = "banknotes"))]
+pub static BANKNOTES: super::StaticShape = super::StaticShape::new(
+    "banknotes",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 4C1 3.44772 1.44772 3 2 3H18C18.5523 3 19 3.44772 19 4V12C19 12.5523 18.5523 13 18 13H2C1.44772 13 1 12.5523 1 12V4ZM13 8C13 9.65685 11.6569 11 10 11C8.34315 11 7 9.65685 7 8C7 6.34315 8.34315 5 10 5C11.6569 5 13 6.34315 13 8ZM4 9C4.55228 9 5 8.55228 5 8C5 7.44772 4.55228 7 4 7C3.44772 7 3 7.44772 3 8C3 8.55228 3.44772 9 4 9ZM17 8C17 8.55228 16.5523 9 16 9C15.4477 9 15 8.55228 15 8C15 7.44772 15.4477 7 16 7C16.5523 7 17 7.44772 17 8ZM1.75 14.5C1.33579 14.5 1 14.8358 1 15.25C1 15.6642 1.33579 16 1.75 16C6.16731 16 10.4426 16.6028 14.4987 17.7301C15.6102 18.039 16.75 17.2183 16.75 16.0336V15.25C16.75 14.8358 16.4142 14.5 16 14.5C15.5858 14.5 15.25 14.8358 15.25 15.25V16.0336C15.25 16.1952 15.0861 16.3365 14.9004 16.2849C10.7147 15.1215 6.30435 14.5 1.75 14.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-2" shape.
+#[cfg(any(feature = "bars-2", heroicon = "bars-2"))]
+pub static BARS_2: super::StaticShape = super::StaticShape::new(
+    "bars-2",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 6.75C2 6.33579 2.33579 6 2.75 6H17.25C17.6642 6 18 6.33579 18 6.75C18 7.16421 17.6642 7.5 17.25 7.5H2.75C2.33579 7.5 2 7.16421 2 6.75ZM2 13.25C2 12.8358 2.33579 12.5 2.75 12.5H17.25C17.6642 12.5 18 12.8358 18 13.25C18 13.6642 17.6642 14 17.25 14H2.75C2.33579 14 2 13.6642 2 13.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-3-bottom-left" shape.
+#[cfg(any(feature = "bars-3-bottom-left", heroicon = "bars-3-bottom-left"))]
+pub static BARS_3_BOTTOM_LEFT: super::StaticShape = super::StaticShape::new(
+    "bars-3-bottom-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.75C2 4.33579 2.33579 4 2.75 4H17.25C17.6642 4 18 4.33579 18 4.75C18 5.16421 17.6642 5.5 17.25 5.5H2.75C2.33579 5.5 2 5.16421 2 4.75ZM2 15.25C2 14.8358 2.33579 14.5 2.75 14.5H10.25C10.6642 14.5 11 14.8358 11 15.25C11 15.6642 10.6642 16 10.25 16H2.75C2.33579 16 2 15.6642 2 15.25Z M2 10C2 9.58579 2.33579 9.25 2.75 9.25H17.25C17.6642 9.25 18 9.58579 18 10C18 10.4142 17.6642 10.75 17.25 10.75H2.75C2.33579 10.75 2 10.4142 2 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-3-bottom-right" shape.
+#[cfg(any(feature = "bars-3-bottom-right", heroicon = "bars-3-bottom-right"))]
+pub static BARS_3_BOTTOM_RIGHT: super::StaticShape = super::StaticShape::new(
+    "bars-3-bottom-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.75C2 4.33579 2.33579 4 2.75 4H17.25C17.6642 4 18 4.33579 18 4.75C18 5.16421 17.6642 5.5 17.25 5.5H2.75C2.33579 5.5 2 5.16421 2 4.75ZM9 15.25C9 14.8358 9.33579 14.5 9.75 14.5H17.25C17.6642 14.5 18 14.8358 18 15.25C18 15.6642 17.6642 16 17.25 16H9.75C9.33579 16 9 15.6642 9 15.25Z M2 10C2 9.58579 2.33579 9.25 2.75 9.25H17.25C17.6642 9.25 18 9.58579 18 10C18 10.4142 17.6642 10.75 17.25 10.75H2.75C2.33579 10.75 2 10.4142 2 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-3-center-left" shape.
+#[cfg(any(feature = "bars-3-center-left", heroicon = "bars-3-center-left"))]
+pub static BARS_3_CENTER_LEFT: super::StaticShape = super::StaticShape::new(
+    "bars-3-center-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.75C2 4.33579 2.33579 4 2.75 4H17.25C17.6642 4 18 4.33579 18 4.75C18 5.16421 17.6642 5.5 17.25 5.5H2.75C2.33579 5.5 2 5.16421 2 4.75ZM2 15.25C2 14.8358 2.33579 14.5 2.75 14.5H17.25C17.6642 14.5 18 14.8358 18 15.25C18 15.6642 17.6642 16 17.25 16H2.75C2.33579 16 2 15.6642 2 15.25Z M2 10C2 9.58579 2.33579 9.25 2.75 9.25H10.25C10.6642 9.25 11 9.58579 11 10C11 10.4142 10.6642 10.75 10.25 10.75H2.75C2.33579 10.75 2 10.4142 2 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-3" shape.
+#[cfg(any(feature = "bars-3", heroicon = "bars-3"))]
+pub static BARS_3: super::StaticShape = super::StaticShape::new(
+    "bars-3",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.75C2 4.33579 2.33579 4 2.75 4H17.25C17.6642 4 18 4.33579 18 4.75C18 5.16421 17.6642 5.5 17.25 5.5H2.75C2.33579 5.5 2 5.16421 2 4.75ZM2 10C2 9.58579 2.33579 9.25 2.75 9.25H17.25C17.6642 9.25 18 9.58579 18 10C18 10.4142 17.6642 10.75 17.25 10.75H2.75C2.33579 10.75 2 10.4142 2 10ZM2 15.25C2 14.8358 2.33579 14.5 2.75 14.5H17.25C17.6642 14.5 18 14.8358 18 15.25C18 15.6642 17.6642 16 17.25 16H2.75C2.33579 16 2 15.6642 2 15.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-4" shape.
+#[cfg(any(feature = "bars-4", heroicon = "bars-4"))]
+pub static BARS_4: super::StaticShape = super::StaticShape::new(
+    "bars-4",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 3.75C2 3.33579 2.33579 3 2.75 3H17.25C17.6642 3 18 3.33579 18 3.75C18 4.16421 17.6642 4.5 17.25 4.5H2.75C2.33579 4.5 2 4.16421 2 3.75ZM2 7.91667C2 7.50245 2.33579 7.16667 2.75 7.16667H17.25C17.6642 7.16667 18 7.50245 18 7.91667C18 8.33088 17.6642 8.66667 17.25 8.66667H2.75C2.33579 8.66667 2 8.33088 2 7.91667ZM2 12.0833C2 11.6691 2.33579 11.3333 2.75 11.3333H17.25C17.6642 11.3333 18 11.6691 18 12.0833C18 12.4975 17.6642 12.8333 17.25 12.8333H2.75C2.33579 12.8333 2 12.4975 2 12.0833ZM2 16.25C2 15.8358 2.33579 15.5 2.75 15.5H17.25C17.6642 15.5 18 15.8358 18 16.25C18 16.6642 17.6642 17 17.25 17H2.75C2.33579 17 2 16.6642 2 16.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-arrow-down" shape.
+#[cfg(any(feature = "bars-arrow-down", heroicon = "bars-arrow-down"))]
+pub static BARS_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "bars-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 3.75C2 3.33579 2.33579 3 2.75 3H14.25C14.6642 3 15 3.33579 15 3.75C15 4.16421 14.6642 4.5 14.25 4.5H2.75C2.33579 4.5 2 4.16421 2 3.75ZM2 7.5C2 7.08579 2.33579 6.75 2.75 6.75H10.2582C10.6724 6.75 11.0082 7.08579 11.0082 7.5C11.0082 7.91421 10.6724 8.25 10.2582 8.25H2.75C2.33579 8.25 2 7.91421 2 7.5ZM14 7C14.4142 7 14.75 7.33579 14.75 7.75L14.75 14.3401L16.7004 12.2397C16.9823 11.9361 17.4568 11.9186 17.7603 12.2004C18.0639 12.4823 18.0814 12.9568 17.7996 13.2603L14.5496 16.7603C14.4077 16.9132 14.2085 17 14 17C13.7914 17 13.5923 16.9132 13.4504 16.7603L10.2004 13.2603C9.91855 12.9568 9.93613 12.4823 10.2397 12.2004C10.5432 11.9186 11.0177 11.9361 11.2996 12.2397L13.25 14.3401L13.25 7.75C13.25 7.33579 13.5858 7 14 7ZM2 11.25C2 10.8358 2.33579 10.5 2.75 10.5H7.31205C7.72626 10.5 8.06205 10.8358 8.06205 11.25C8.06205 11.6642 7.72626 12 7.31205 12H2.75C2.33579 12 2 11.6642 2 11.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-arrow-up" shape.
+#[cfg(any(feature = "bars-arrow-up", heroicon = "bars-arrow-up"))]
+pub static BARS_ARROW_UP: super::StaticShape = super::StaticShape::new(
+    "bars-arrow-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 3.75C2 3.33579 2.33579 3 2.75 3H14.25C14.6642 3 15 3.33579 15 3.75C15 4.16421 14.6642 4.5 14.25 4.5H2.75C2.33579 4.5 2 4.16421 2 3.75ZM2 7.5C2 7.08579 2.33579 6.75 2.75 6.75H9.11474C9.52895 6.75 9.86474 7.08579 9.86474 7.5C9.86474 7.91421 9.52895 8.25 9.11474 8.25H2.75C2.33579 8.25 2 7.91421 2 7.5ZM14 7C14.2086 7 14.4077 7.08684 14.5496 7.23966L17.7996 10.7397C18.0814 11.0432 18.0639 11.5177 17.7603 11.7996C17.4568 12.0814 16.9823 12.0639 16.7004 11.7603L14.75 9.6599L14.75 16.25C14.75 16.6642 14.4142 17 14 17C13.5858 17 13.25 16.6642 13.25 16.25L13.25 9.6599L11.2996 11.7603C11.0177 12.0639 10.5432 12.0814 10.2397 11.7996C9.93613 11.5177 9.91855 11.0432 10.2004 10.7397L13.4504 7.23966C13.5923 7.08684 13.7914 7 14 7ZM2 11.25C2 10.8358 2.33579 10.5 2.75 10.5H6.99999C7.4142 10.5 7.74999 10.8358 7.74999 11.25C7.74999 11.6642 7.4142 12 6.99999 12H2.75C2.33579 12 2 11.6642 2 11.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "battery-0" shape.
+#[cfg(any(feature = "battery-0", heroicon = "battery-0"))]
+pub static BATTERY_0: super::StaticShape = super::StaticShape::new(
+    "battery-0",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 7.25C1 6.00736 2.00736 5 3.25 5H15.75C16.9926 5 18 6.00736 18 7.25V8.33535C18.5826 8.54127 19 9.09689 19 9.75V10.25C19 10.9031 18.5826 11.4587 18 11.6646V12.75C18 13.9926 16.9926 15 15.75 15H3.25C2.00736 15 1 13.9926 1 12.75V7.25ZM3.25 6.5C2.83579 6.5 2.5 6.83579 2.5 7.25V12.75C2.5 13.1642 2.83579 13.5 3.25 13.5H15.75C16.1642 13.5 16.5 13.1642 16.5 12.75V7.25C16.5 6.83579 16.1642 6.5 15.75 6.5H3.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "battery-100" shape.
+#[cfg(any(feature = "battery-100", heroicon = "battery-100"))]
+pub static BATTERY_100: super::StaticShape = super::StaticShape::new(
+    "battery-100",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.75 8C4.33579 8 4 8.33579 4 8.75V11.25C4 11.6642 4.33579 12 4.75 12H14.25C14.6642 12 15 11.6642 15 11.25V8.75C15 8.33579 14.6642 8 14.25 8H4.75Z M1 7.25C1 6.00736 2.00736 5 3.25 5H15.75C16.9926 5 18 6.00736 18 7.25V8.33535C18.5826 8.54127 19 9.09689 19 9.75V10.25C19 10.9031 18.5826 11.4587 18 11.6646V12.75C18 13.9926 16.9926 15 15.75 15H3.25C2.00736 15 1 13.9926 1 12.75V7.25ZM3.25 6.5C2.83579 6.5 2.5 6.83579 2.5 7.25V12.75C2.5 13.1642 2.83579 13.5 3.25 13.5H15.75C16.1642 13.5 16.5 13.1642 16.5 12.75V7.25C16.5 6.83579 16.1642 6.5 15.75 6.5H3.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "battery-50" shape.
+#[cfg(any(feature = "battery-50", heroicon = "battery-50"))]
+pub static BATTERY_50: super::StaticShape = super::StaticShape::new(
+    "battery-50",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.75 8C4.33579 8 4 8.33579 4 8.75V11.25C4 11.6642 4.33579 12 4.75 12H9.5C9.91421 12 10.25 11.6642 10.25 11.25V8.75C10.25 8.33579 9.91421 8 9.5 8H4.75Z M3.25 5C2.00736 5 1 6.00736 1 7.25V12.75C1 13.9926 2.00736 15 3.25 15H15.75C16.9926 15 18 13.9926 18 12.75V11.6646C18.5826 11.4587 19 10.9031 19 10.25V9.75C19 9.09689 18.5826 8.54127 18 8.33535V7.25C18 6.00736 16.9926 5 15.75 5H3.25ZM2.5 7.25C2.5 6.83579 2.83579 6.5 3.25 6.5H15.75C16.1642 6.5 16.5 6.83579 16.5 7.25V12.75C16.5 13.1642 16.1642 13.5 15.75 13.5H3.25C2.83579 13.5 2.5 13.1642 2.5 12.75V7.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "beaker" shape.
+#[cfg(any(feature = "beaker", heroicon = "beaker"))]
+pub static BEAKER: super::StaticShape = super::StaticShape::new(
+    "beaker",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.49999 3.52795V8.17157C8.49999 8.90092 8.21026 9.60039 7.69453 10.1161L6.47812 11.3325C7.68604 11.2911 8.89515 11.5003 10.0275 11.9532L10.5296 12.1541C11.8561 12.6847 13.3098 12.8115 14.708 12.5187L12.3054 10.1161C11.7897 9.60039 11.5 8.90092 11.5 8.17157V3.52795C11.0023 3.50937 10.5023 3.5 9.99999 3.5C9.49771 3.5 8.99766 3.50937 8.49999 3.52795ZM13 3.61218C13.0635 3.61695 13.1269 3.62186 13.1903 3.62693C13.6032 3.65992 13.9646 3.35194 13.9976 2.93905C14.0306 2.52615 13.7226 2.16468 13.3097 2.13169C12.9714 2.10466 12.6319 2.08173 12.2913 2.06296C11.5327 2.02117 10.7688 2 9.99999 2C9.23118 2 8.46724 2.02117 7.70873 2.06296C7.36812 2.08173 7.02862 2.10466 6.69025 2.13169C6.27736 2.16468 5.96938 2.52615 6.00237 2.93905C6.03536 3.35194 6.39683 3.65992 6.80973 3.62693C6.8731 3.62186 6.93653 3.61695 6.99999 3.61218V8.17157C6.99999 8.50309 6.86829 8.82104 6.63387 9.05546L2.60034 13.089C1.10385 14.5855 1.78334 17.2391 4.00336 17.5645C5.9611 17.8515 7.96343 18 9.99999 18C12.0366 18 14.0389 17.8515 15.9966 17.5645C18.2166 17.2391 18.8961 14.5855 17.3996 13.089L13.3661 9.05546C13.1317 8.82104 13 8.50309 13 8.17157V3.61218Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bell-alert" shape.
+#[cfg(any(feature = "bell-alert", heroicon = "bell-alert"))]
+pub static BELL_ALERT: super::StaticShape = super::StaticShape::new(
+    "bell-alert",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.21444 3.2267C4.47824 2.90735 4.43321 2.43462 4.11386 2.17082C3.79452 1.90702 3.32178 1.95204 3.05798 2.27139C2.1587 3.36002 1.50992 4.66481 1.20153 6.09691C1.11433 6.50184 1.37191 6.90079 1.77684 6.98799C2.18177 7.07519 2.58072 6.81762 2.66792 6.41268C2.92443 5.22148 3.46427 4.13482 4.21444 3.2267Z M16.9417 2.27139C16.6779 1.95204 16.2051 1.90702 15.8858 2.17082C15.5664 2.43462 15.5214 2.90735 15.7852 3.2267C16.5354 4.13482 17.0752 5.22148 17.3317 6.41268C17.4189 6.81762 17.8179 7.07519 18.2228 6.98799C18.6277 6.90079 18.8853 6.50184 18.7981 6.09691C18.4897 4.66481 17.8409 3.36002 16.9417 2.27139Z M9.99997 1.99988C6.68626 1.99988 3.99997 4.68617 3.99997 7.99988C3.99997 9.88651 3.54624 11.665 2.7426 13.2342C2.63591 13.4425 2.6326 13.6887 2.73365 13.8998C2.83469 14.111 3.02851 14.2628 3.25769 14.3104C4.32537 14.5321 5.41181 14.7021 6.51426 14.8179C6.67494 16.6019 8.17421 17.9999 10 17.9999C11.8258 17.9999 13.3251 16.6019 13.4857 14.8179C14.5882 14.7021 15.6746 14.5321 16.7422 14.3104C16.9714 14.2628 17.1652 14.111 17.2663 13.8998C17.3673 13.6887 17.364 13.4425 17.2573 13.2342C16.4537 11.665 16 9.88651 16 7.99988C16 4.68617 13.3137 1.99988 9.99997 1.99988ZM10 16.4999C9.04777 16.4999 8.25097 15.8344 8.0493 14.9432C8.69477 14.9808 9.34517 14.9999 9.99997 14.9999C10.6548 14.9999 11.3052 14.9808 11.9507 14.9432C11.749 15.8344 10.9522 16.4999 10 16.4999Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bell-slash" shape.
+#[cfg(any(feature = "bell-slash", heroicon = "bell-slash"))]
+pub static BELL_SLASH: super::StaticShape = super::StaticShape::new(
+    "bell-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.99997 8C3.99997 7.73938 4.01658 7.48265 4.04881 7.23079L11.7713 14.9533C11.1847 14.9843 10.5942 15 9.99997 15C9.34517 15 8.69477 14.9809 8.0493 14.9433C8.25097 15.8345 9.04777 16.5 10 16.5C10.8982 16.5 11.6581 15.9079 11.9107 15.0927L13.045 16.227C12.4432 17.2858 11.305 18 10 18C8.17421 18 6.67494 16.602 6.51426 14.818C5.41181 14.7023 4.32537 14.5322 3.25769 14.3105C3.02851 14.2629 2.83469 14.1111 2.73365 13.9C2.6326 13.6888 2.63591 13.4426 2.7426 13.2343C3.54624 11.6651 3.99997 9.88663 3.99997 8Z M17.2663 13.9C17.2467 13.9408 17.2237 13.9795 17.1976 14.0156L6.38945 3.20747C7.39404 2.44946 8.64452 2 9.99997 2C13.3137 2 16 4.68629 16 8C16 9.88663 16.4537 11.6651 17.2573 13.2343C17.364 13.4426 17.3673 13.6888 17.2663 13.9Z M3.28033 2.21967C2.98744 1.92678 2.51256 1.92678 2.21967 2.21967C1.92678 2.51256 1.92678 2.98744 2.21967 3.28033L16.7197 17.7803C17.0126 18.0732 17.4874 18.0732 17.7803 17.7803C18.0732 17.4874 18.0732 17.0126 17.7803 16.7197L3.28033 2.21967Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bell-snooze" shape.
+#[cfg(any(feature = "bell-snooze", heroicon = "bell-snooze"))]
+pub static BELL_SNOOZE: super::StaticShape = super::StaticShape::new(
+    "bell-snooze",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.99997 8C3.99997 4.68629 6.68626 2 9.99997 2C13.3137 2 16 4.68629 16 8C16 9.88663 16.4537 11.6651 17.2573 13.2343C17.364 13.4426 17.3673 13.6888 17.2663 13.9C17.1652 14.1111 16.9714 14.2629 16.7422 14.3105C15.6746 14.5322 14.5882 14.7023 13.4857 14.818C13.3251 16.602 11.8258 18 10 18C8.17421 18 6.67494 16.602 6.51426 14.818C5.41181 14.7023 4.32537 14.5322 3.25769 14.3105C3.02851 14.2629 2.83469 14.1111 2.73365 13.9C2.6326 13.6888 2.63591 13.4426 2.7426 13.2343C3.54624 11.6651 3.99997 9.88663 3.99997 8ZM9.99997 15C9.34517 15 8.69477 14.9809 8.0493 14.9433C8.25097 15.8345 9.04777 16.5 10 16.5C10.9522 16.5 11.749 15.8345 11.9507 14.9433C11.3052 14.9809 10.6548 15 9.99997 15ZM8.75 6C8.33579 6 8 6.33579 8 6.75C8 7.16421 8.33579 7.5 8.75 7.5H9.79261L8.1397 9.81407C7.97641 10.0427 7.95457 10.3434 8.08312 10.5932C8.21168 10.843 8.46906 11 8.75 11H11.25C11.6642 11 12 10.6642 12 10.25C12 9.83579 11.6642 9.5 11.25 9.5H10.2074L11.8603 7.18593C12.0236 6.95732 12.0454 6.65662 11.9169 6.40681C11.7883 6.15701 11.5309 6 11.25 6H8.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bell" shape.
+#[cfg(any(feature = "bell", heroicon = "bell"))]
+pub static BELL: super::StaticShape = super::StaticShape::new(
+    "bell",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.99997 2C6.68626 2 3.99997 4.68629 3.99997 8C3.99997 9.88663 3.54624 11.6651 2.7426 13.2343C2.63591 13.4426 2.6326 13.6888 2.73365 13.9C2.83469 14.1111 3.02851 14.2629 3.25769 14.3105C4.32537 14.5322 5.41181 14.7023 6.51426 14.818C6.67494 16.602 8.17421 18 10 18C11.8258 18 13.3251 16.602 13.4857 14.818C14.5882 14.7023 15.6746 14.5322 16.7422 14.3105C16.9714 14.2629 17.1652 14.1111 17.2663 13.9C17.3673 13.6888 17.364 13.4426 17.2573 13.2343C16.4537 11.6651 16 9.88663 16 8C16 4.68629 13.3137 2 9.99997 2ZM8.0493 14.9433C8.69477 14.9809 9.34517 15 9.99997 15C10.6548 15 11.3052 14.9809 11.9507 14.9433C11.749 15.8345 10.9522 16.5 10 16.5C9.04777 16.5 8.25097 15.8345 8.0493 14.9433Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bolt-slash" shape.
+#[cfg(any(feature = "bolt-slash", heroicon = "bolt-slash"))]
+pub static BOLT_SLASH: super::StaticShape = super::StaticShape::new(
+    "bolt-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.21967 2.21967C2.51256 1.92678 2.98744 1.92678 3.28033 2.21967L17.7803 16.7197C18.0732 17.0126 18.0732 17.4874 17.7803 17.7803C17.4874 18.0732 17.0126 18.0732 16.7197 17.7803L2.21967 3.28033C1.92678 2.98744 1.92678 2.51256 2.21967 2.21967Z M4.73016 7.91214L2.19108 10.7499C1.99385 10.9704 1.9446 11.2861 2.06533 11.5562C2.18607 11.8262 2.45423 12 2.75001 12H8.81805L4.73016 7.91214Z M9.23329 12.4153L8.01666 18.0929C7.9454 18.4255 8.10685 18.7644 8.41002 18.9185C8.71318 19.0727 9.08215 19.0036 9.30894 18.7501L12.2647 15.4467L9.23329 12.4153Z M15.2699 12.0879L17.8089 9.25013C18.0062 9.0297 18.0554 8.71393 17.9347 8.4439C17.814 8.17388 17.5458 8.00003 17.25 8.00003H11.182L15.2699 12.0879Z M10.7667 7.58476L11.9834 1.90718C12.0546 1.57461 11.8932 1.23571 11.59 1.08152C11.2868 0.927338 10.9179 0.996463 10.6911 1.24994L7.73537 4.55339L10.7667 7.58476Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bolt" shape.
+#[cfg(any(feature = "bolt", heroicon = "bolt"))]
+pub static BOLT: super::StaticShape = super::StaticShape::new(
+    "bolt",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.9834 1.90718C12.0546 1.57461 11.8932 1.23571 11.59 1.08152C11.2868 0.927338 10.9179 0.996463 10.6911 1.24994L2.19108 10.7499C1.99385 10.9704 1.9446 11.2861 2.06533 11.5562C2.18607 11.8262 2.45423 12 2.75001 12H9.32227L8.01666 18.0929C7.9454 18.4255 8.10685 18.7644 8.41002 18.9185C8.71318 19.0727 9.08215 19.0036 9.30894 18.7501L17.8089 9.25013C18.0062 9.0297 18.0554 8.71393 17.9347 8.4439C17.814 8.17388 17.5458 8.00003 17.25 8.00003H10.6778L11.9834 1.90718Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "book-open" shape.
+#[cfg(any(feature = "book-open", heroicon = "book-open"))]
+pub static BOOK_OPEN: super::StaticShape = super::StaticShape::new(
+    "book-open",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.75 16.8195C11.9579 15.9871 13.4212 15.5 15 15.5C15.7103 15.5 16.3964 15.5985 17.0459 15.7822C17.272 15.8462 17.515 15.8005 17.7024 15.6587C17.8899 15.5169 18 15.2955 18 15.0605V4.06055C18 3.72495 17.7771 3.4302 17.4541 3.33886C16.6731 3.11796 15.8497 3 15 3C13.4636 3 12.016 3.38549 10.75 4.06487V16.8195Z M9.25 4.06487C7.98396 3.38549 6.5364 3 5 3C4.15029 3 3.32689 3.11796 2.54588 3.33886C2.22295 3.4302 2 3.72495 2 4.06055V15.0605C2 15.2955 2.11014 15.5169 2.29756 15.6587C2.48497 15.8005 2.728 15.8462 2.95412 15.7822C3.60361 15.5985 4.28967 15.5 5 15.5C6.57884 15.5 8.04208 15.9871 9.25 16.8195V4.06487Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bookmark-slash" shape.
+#[cfg(any(feature = "bookmark-slash", heroicon = "bookmark-slash"))]
+pub static BOOKMARK_SLASH: super::StaticShape = super::StaticShape::new(
+    "bookmark-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17 4.51661V13.818L5.43349 2.25151C6.93301 2.0853 8.45668 2 10 2C11.7163 2 13.4084 2.10551 15.07 2.31046C16.1942 2.44913 17 3.41374 17 4.51661Z M3 17.25V6.18198L13.6537 16.8357L10 15.0819L4.07455 17.9261C3.84215 18.0377 3.56875 18.0221 3.35057 17.8848C3.13239 17.7475 3 17.5078 3 17.25Z M3.28033 2.21967C2.98744 1.92678 2.51256 1.92678 2.21967 2.21967C1.92678 2.51256 1.92678 2.98744 2.21967 3.28033L16.7197 17.7803C17.0126 18.0732 17.4874 18.0732 17.7803 17.7803C18.0732 17.4874 18.0732 17.0126 17.7803 16.7197L3.28033 2.21967Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bookmark-square" shape.
+#[cfg(any(feature = "bookmark-square", heroicon = "bookmark-square"))]
+pub static BOOKMARK_SQUARE: super::StaticShape = super::StaticShape::new(
+    "bookmark-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.25 2C3.00736 2 2 3.00736 2 4.25V15.75C2 16.9926 3.00736 18 4.25 18H15.75C16.9926 18 18 16.9926 18 15.75V4.25C18 3.00736 16.9926 2 15.75 2H4.25ZM6 13.25V3.5H14V13.25C14 13.5058 13.8697 13.7439 13.6542 13.8818C13.4388 14.0196 13.1679 14.0382 12.9357 13.931L10 12.576L7.06429 13.931C6.83207 14.0382 6.56123 14.0196 6.34578 13.8818C6.13034 13.7439 6 13.5058 6 13.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bookmark" shape.
+#[cfg(any(feature = "bookmark", heroicon = "bookmark"))]
+pub static BOOKMARK: super::StaticShape = super::StaticShape::new(
+    "bookmark",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 2C8.28365 2 6.5916 2.10551 4.93005 2.31046C3.80579 2.44913 3 3.41374 3 4.51661V17.25C3 17.5078 3.13239 17.7475 3.35057 17.8848C3.56875 18.0221 3.84215 18.0377 4.07455 17.9261L10 15.0819L15.9255 17.9261C16.1578 18.0377 16.4312 18.0221 16.6494 17.8848C16.8676 17.7475 17 17.5078 17 17.25V4.51661C17 3.41374 16.1942 2.44913 15.07 2.31046C13.4084 2.10551 11.7163 2 10 2Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "briefcase" shape.
+#[cfg(any(feature = "briefcase", heroicon = "briefcase"))]
+pub static BRIEFCASE: super::StaticShape = super::StaticShape::new(
+    "briefcase",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6 3.75C6 2.23122 7.23122 1 8.75 1H11.25C12.7688 1 14 2.23122 14 3.75V4.19269C14.572 4.24808 15.1407 4.31524 15.7057 4.39392C17.0526 4.58149 18 5.74901 18 7.07023V10.5386C18 11.6653 17.3058 12.7301 16.1705 13.0786C14.2185 13.6778 12.1462 14 10 14C7.8538 14 5.78149 13.6778 3.82951 13.0786C2.69423 12.7301 2 11.6653 2 10.5386V7.07023C2 5.74901 2.94737 4.58149 4.29435 4.39392C4.85933 4.31524 5.42796 4.24808 6 4.19269V3.75ZM12.5 3.75V4.07499C11.673 4.02523 10.8394 4 10 4C9.16061 4 8.32704 4.02523 7.5 4.07499V3.75C7.5 3.05964 8.05964 2.5 8.75 2.5H11.25C11.9404 2.5 12.5 3.05964 12.5 3.75ZM10 10C9.44772 10 9 10.4477 9 11V11.01C9 11.5623 9.44772 12.01 10 12.01H10.01C10.5623 12.01 11.01 11.5623 11.01 11.01V11C11.01 10.4477 10.5623 10 10.01 10H10Z M3 15.0552V14.3714C3.1256 14.4243 3.25542 14.4715 3.38933 14.5126C5.48234 15.1551 7.70295 15.5 10 15.5C12.297 15.5 14.5177 15.1551 16.6107 14.5126C16.7446 14.4715 16.8744 14.4243 17 14.3714V15.0552C17 16.4024 16.0154 17.5854 14.6369 17.7406C13.1147 17.9119 11.5675 17.9999 10 17.9999C8.43253 17.9999 6.88533 17.9119 5.36311 17.7406C3.98461 17.5854 3 16.4024 3 15.0552Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bug-ant" shape.
+#[cfg(any(feature = "bug-ant", heroicon = "bug-ant"))]
+pub static BUG_ANT: super::StaticShape = super::StaticShape::new(
+    "bug-ant",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.56061 1.13953C6.8982 1.37954 6.9773 1.84778 6.73729 2.18537C6.54915 2.45 6.39257 2.73822 6.27282 3.04462C6.45824 3.21559 6.6554 3.37394 6.86291 3.51833C7.59496 2.59421 8.72765 2.00006 10 2.00006C11.2723 2.00006 12.405 2.59421 13.1371 3.51832C13.3446 3.37394 13.5418 3.21558 13.7272 3.04462C13.6074 2.73822 13.4509 2.45 13.2627 2.18537C13.0227 1.84778 13.1018 1.37955 13.4394 1.13953C13.777 0.899519 14.2452 0.978621 14.4852 1.31621C14.8539 1.83478 15.1353 2.4206 15.3072 3.05202C15.3752 3.30189 15.3095 3.56919 15.1334 3.75907C14.7474 4.17534 14.3111 4.54466 13.8342 4.85743C13.9421 5.21997 14 5.60366 14 6.00006C14 6.52021 13.6988 6.96322 13.277 7.18703C12.9093 7.38217 12.5221 7.54547 12.1193 7.6733C12.2496 7.88101 12.3502 8.10924 12.4152 8.35204C13.8282 8.17757 15.194 7.85199 16.4961 7.39205C16.4987 7.26171 16.5 7.13104 16.5 7.00006C16.5 6.34965 16.4682 5.70689 16.4061 5.07324C16.3656 4.661 16.6671 4.29405 17.0793 4.25364C17.4915 4.21322 17.8585 4.51464 17.8989 4.92688C17.9658 5.6091 18 6.3007 18 7.00006C18 7.32005 17.9928 7.63842 17.9787 7.95502C17.9653 8.25337 17.7762 8.51537 17.4972 8.62201C15.867 9.24525 14.1395 9.67115 12.3441 9.87064C12.3126 9.9553 12.2767 10.0378 12.2367 10.1178C12.8138 10.179 13.384 10.2635 13.9464 10.3704C15.1681 10.6028 16.3525 10.941 17.4895 11.3751C17.7934 11.4912 17.9879 11.79 17.9709 12.115C17.8783 13.8835 17.5668 15.5949 17.0628 17.222C16.9402 17.6177 16.5201 17.839 16.1244 17.7165C15.7287 17.5939 15.5074 17.1738 15.6299 16.7781C16.0452 15.4376 16.3198 14.0349 16.4364 12.5871C15.941 12.4139 15.4366 12.2602 14.9239 12.1269C14.9739 12.4106 15 12.7024 15 13.0001C15 14.8138 14.4833 16.312 13.5738 17.3694C12.6592 18.4326 11.3874 19.0001 10 19.0001C8.61265 19.0001 7.34085 18.4326 6.42621 17.3694C5.51672 16.312 5 14.8138 5 13.0001C5 12.7024 5.02608 12.4106 5.07611 12.1269C4.56343 12.2602 4.05896 12.4139 3.56361 12.5871C3.68017 14.0349 3.95479 15.4376 4.37006 16.7781C4.49264 17.1738 4.27125 17.5939 3.87559 17.7165C3.47993 17.839 3.05982 17.6177 2.93724 17.222C2.43318 15.5949 2.12168 13.8836 2.02911 12.115C2.0121 11.79 2.20656 11.4912 2.51054 11.3751C3.6475 10.941 4.83186 10.6028 6.05361 10.3704C6.61601 10.2635 7.18625 10.179 7.76335 10.1178C7.72328 10.0378 7.68738 9.9553 7.65593 9.87064C5.86054 9.67116 4.13298 9.24526 2.50277 8.62202C2.2238 8.51537 2.0347 8.25338 2.02134 7.95503C2.00716 7.63843 2 7.32005 2 7.00006C2 6.3007 2.03421 5.6091 2.1011 4.92688C2.14151 4.51464 2.50846 4.21322 2.9207 4.25364C3.33293 4.29405 3.63436 4.661 3.59394 5.07324C3.53182 5.70689 3.5 6.34965 3.5 7.00006C3.5 7.13104 3.50129 7.26171 3.50386 7.39206C4.80604 7.852 6.17184 8.17757 7.5848 8.35204C7.64979 8.10924 7.75041 7.88101 7.88072 7.6733C7.47789 7.54547 7.09072 7.38217 6.72298 7.18703C6.30121 6.96322 6 6.52021 6 6.00006C6 5.60366 6.05787 5.21996 6.16579 4.85743C5.68885 4.54466 5.25263 4.17534 4.86656 3.75907C4.69045 3.56919 4.62477 3.30189 4.69281 3.05202C4.86474 2.4206 5.14609 1.83478 5.51477 1.31621C5.75478 0.978621 6.22302 0.899519 6.56061 1.13953Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "building-library" shape.
+#[cfg(any(feature = "building-library", heroicon = "building-library"))]
+pub static BUILDING_LIBRARY: super::StaticShape = super::StaticShape::new(
+    "building-library",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.67411 2.07459C9.88011 1.97514 10.1202 1.97514 10.3262 2.07459L17.5762 5.57459C17.9493 5.75467 18.1057 6.20304 17.9256 6.57606C17.7576 6.92403 17.3561 7.08351 17.0002 6.95718V16.5H17.2502C17.6644 16.5 18.0002 16.8358 18.0002 17.25C18.0002 17.6642 17.6644 18 17.2502 18H2.75017C2.33596 18 2.00017 17.6642 2.00017 17.25C2.00017 16.8358 2.33596 16.5 2.75017 16.5H3.00017V6.95718C2.6442 7.08351 2.24274 6.92403 2.07476 6.57606C1.89468 6.20304 2.05109 5.75467 2.42411 5.57459L9.67411 2.07459ZM11 6C11 6.55228 10.5523 7 10 7C9.44772 7 9 6.55228 9 6C9 5.44772 9.44772 5 10 5C10.5523 5 11 5.44772 11 6ZM7.5 9.75C7.5 9.33579 7.16421 9 6.75 9C6.33579 9 6 9.33579 6 9.75V15.25C6 15.6642 6.33579 16 6.75 16C7.16421 16 7.5 15.6642 7.5 15.25V9.75ZM10.75 9.75C10.75 9.33579 10.4142 9 10 9C9.58579 9 9.25 9.33579 9.25 9.75V15.25C9.25 15.6642 9.58579 16 10 16C10.4142 16 10.75 15.6642 10.75 15.25V9.75ZM14 9.75C14 9.33579 13.6642 9 13.25 9C12.8358 9 12.5 9.33579 12.5 9.75V15.25C12.5 15.6642 12.8358 16 13.25 16C13.6642 16 14 15.6642 14 15.25V9.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "building-office-2" shape.
+#[cfg(any(feature = "building-office-2", heroicon = "building-office-2"))]
+pub static BUILDING_OFFICE_2: super::StaticShape = super::StaticShape::new(
+    "building-office-2",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 2.75C1 2.33579 1.33579 2 1.75 2H12.25C12.6642 2 13 2.33579 13 2.75C13 3.16421 12.6642 3.5 12.25 3.5H12V17.25C12 17.6642 11.6642 18 11.25 18H9.75C9.33579 18 9 17.6642 9 17.25V14.75C9 14.3358 8.66421 14 8.25 14H5.75C5.33579 14 5 14.3358 5 14.75V17.25C5 17.6642 4.66421 18 4.25 18H1.75C1.33579 18 1 17.6642 1 17.25C1 16.8358 1.33579 16.5 1.75 16.5H2V3.5H1.75C1.33579 3.5 1 3.16421 1 2.75ZM4 5.5C4 5.22386 4.22386 5 4.5 5H5.5C5.77614 5 6 5.22386 6 5.5V6.5C6 6.77614 5.77614 7 5.5 7H4.5C4.22386 7 4 6.77614 4 6.5V5.5ZM4.5 9C4.22386 9 4 9.22386 4 9.5V10.5C4 10.7761 4.22386 11 4.5 11H5.5C5.77614 11 6 10.7761 6 10.5V9.5C6 9.22386 5.77614 9 5.5 9H4.5ZM8 5.5C8 5.22386 8.22386 5 8.5 5H9.5C9.77614 5 10 5.22386 10 5.5V6.5C10 6.77614 9.77614 7 9.5 7H8.5C8.22386 7 8 6.77614 8 6.5V5.5ZM8.5 9C8.22386 9 8 9.22386 8 9.5V10.5C8 10.7761 8.22386 11 8.5 11H9.5C9.77614 11 10 10.7761 10 10.5V9.5C10 9.22386 9.77614 9 9.5 9H8.5Z M14.25 6C13.8358 6 13.5 6.33579 13.5 6.75V17C13.5 17.5523 13.9477 18 14.5 18H18.25C18.6642 18 19 17.6642 19 17.25C19 16.8358 18.6642 16.5 18.25 16.5H18V7.5H18.25C18.6642 7.5 19 7.16421 19 6.75C19 6.33579 18.6642 6 18.25 6H14.25ZM14.75 9.5C14.75 9.22386 14.9739 9 15.25 9H16.25C16.5261 9 16.75 9.22386 16.75 9.5V10.5C16.75 10.7761 16.5261 11 16.25 11H15.25C14.9739 11 14.75 10.7761 14.75 10.5V9.5ZM15.25 13C14.9739 13 14.75 13.2239 14.75 13.5V14.5C14.75 14.7761 14.9739 15 15.25 15H16.25C16.5261 15 16.75 14.7761 16.75 14.5V13.5C16.75 13.2239 16.5261 13 16.25 13H15.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "building-office" shape.
+#[cfg(any(feature = "building-office", heroicon = "building-office"))]
+pub static BUILDING_OFFICE: super::StaticShape = super::StaticShape::new(
+    "building-office",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4 16.5V3.5H3.75C3.33579 3.5 3 3.16421 3 2.75C3 2.33579 3.33579 2 3.75 2H16.25C16.6642 2 17 2.33579 17 2.75C17 3.16421 16.6642 3.5 16.25 3.5H16V16.5H16.25C16.6642 16.5 17 16.8358 17 17.25C17 17.6642 16.6642 18 16.25 18H12.75C12.3358 18 12 17.6642 12 17.25V14.75C12 14.3358 11.6642 14 11.25 14H8.75C8.33579 14 8 14.3358 8 14.75V17.25C8 17.6642 7.66421 18 7.25 18H3.75C3.33579 18 3 17.6642 3 17.25C3 16.8358 3.33579 16.5 3.75 16.5H4ZM7 5.5C7 5.22386 7.22386 5 7.5 5H8.5C8.77614 5 9 5.22386 9 5.5V6.5C9 6.77614 8.77614 7 8.5 7H7.5C7.22386 7 7 6.77614 7 6.5V5.5ZM7.5 9C7.22386 9 7 9.22386 7 9.5V10.5C7 10.7761 7.22386 11 7.5 11H8.5C8.77614 11 9 10.7761 9 10.5V9.5C9 9.22386 8.77614 9 8.5 9H7.5ZM11 5.5C11 5.22386 11.2239 5 11.5 5H12.5C12.7761 5 13 5.22386 13 5.5V6.5C13 6.77614 12.7761 7 12.5 7H11.5C11.2239 7 11 6.77614 11 6.5V5.5ZM11.5 9C11.2239 9 11 9.22386 11 9.5V10.5C11 10.7761 11.2239 11 11.5 11H12.5C12.7761 11 13 10.7761 13 10.5V9.5C13 9.22386 12.7761 9 12.5 9H11.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "building-storefront" shape.
+#[cfg(any(feature = "building-storefront", heroicon = "building-storefront"))]
+pub static BUILDING_STOREFRONT: super::StaticShape = super::StaticShape::new(
+    "building-storefront",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.87868 7.12106C4.05025 8.29263 5.94975 8.29263 7.12132 7.12106C7.26529 6.97709 7.39156 6.82213 7.50015 6.65889C8.03763 7.46711 8.95661 7.99977 10 7.99977C11.0435 7.99977 11.9626 7.46697 12.5001 6.65856C12.6087 6.82194 12.7351 6.97702 12.8791 7.12109C14.0507 8.29267 15.9502 8.29267 17.1218 7.12109C18.2933 5.94952 18.2933 4.05003 17.1218 2.87845L16.8291 2.58579C16.454 2.21071 15.9453 2 15.4149 2H4.58552C4.05509 2 3.54638 2.21071 3.17131 2.58579L2.87868 2.87842C1.70711 4.04999 1.70711 5.94949 2.87868 7.12106Z M3 9.03223C4.42799 9.74067 6.15393 9.64395 7.50057 8.74205C8.21499 9.22007 9.07471 9.49977 10 9.49977C10.9254 9.49977 11.7852 9.22002 12.4996 8.74191C13.8462 9.64388 15.572 9.74073 17 9.03249V16.5H17.25C17.6642 16.5 18 16.8358 18 17.25C18 17.6642 17.6642 18 17.25 18H12.75C12.3358 18 12 17.6642 12 17.25V13.75C12 13.3358 11.6642 13 11.25 13H8.75C8.33579 13 8 13.3358 8 13.75V17.25C8 17.6642 7.66421 18 7.25 18H2.75C2.33579 18 2 17.6642 2 17.25C2 16.8358 2.33579 16.5 2.75 16.5H3V9.03223Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
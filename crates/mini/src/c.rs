@@ -0,0 +1,775 @@
+//! The shapes whose names start with "c".
+/// The "cake" shape.
+#[cfg(any(feature = "cake", heroicon = "cake"))]
+pub static CAKE: super::StaticShape = super::StaticShape::new(
+    "cake",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.74999 0.979492L5.86612 1.86339C5.37796 2.35154 5.37796 3.143 5.86612 3.63115C6.35427 4.11931 7.14573 4.11931 7.63388 3.63115C8.12204 3.143 8.12204 2.35154 7.63388 1.86339L6.74999 0.979492Z M13.25 0.979492L12.3661 1.86339C11.878 2.35154 11.878 3.143 12.3661 3.63115C12.8543 4.11931 13.6457 4.11931 14.1339 3.63115C14.622 3.143 14.622 2.35154 14.1339 1.86339L13.25 0.979492Z M9.99999 0.979492L10.8839 1.86339C11.372 2.35154 11.372 3.143 10.8839 3.63115C10.3957 4.11931 9.60427 4.11931 9.11612 3.63115C8.62796 3.143 8.62796 2.35154 9.11612 1.86339L9.99999 0.979492Z M7.5 5.75015C7.5 5.33594 7.16421 5.00015 6.75 5.00015C6.33579 5.00015 6 5.33594 6 5.75015V6.21394C4.82122 6.51862 4 7.60448 4 8.83585V8.93037C4.10036 8.90975 4.20229 8.89245 4.30573 8.87859C6.16935 8.62892 8.07023 8.50015 10 8.50015C11.9298 8.50015 13.8306 8.62892 15.6943 8.87859C15.7977 8.89245 15.8996 8.90975 16 8.93037V8.83585C16 7.60447 15.1788 6.51862 14 6.21394V5.75015C14 5.33594 13.6642 5.00015 13.25 5.00015C12.8358 5.00015 12.5 5.33594 12.5 5.75015V6.06844C11.9195 6.03665 11.3361 6.01586 10.75 6.00628V5.75015C10.75 5.33594 10.4142 5.00015 10 5.00015C9.58579 5.00015 9.25 5.33594 9.25 5.75015V6.00628C8.66391 6.01586 8.08051 6.03665 7.5 6.06844V5.75015Z M4.50491 10.3653C6.30269 10.1245 8.13702 10.0002 10 10.0002C11.863 10.0002 13.6973 10.1245 15.4951 10.3653C16.9666 10.5625 18 11.838 18 13.279V13.9723C17.4297 13.9723 16.8594 13.8413 16.3354 13.5793C14.8652 12.8442 13.1348 12.8442 11.6646 13.5793C10.6167 14.1033 9.38329 14.1033 8.33541 13.5793C6.86524 12.8442 5.13476 12.8442 3.66459 13.5793C3.14065 13.8413 2.57032 13.9723 2 13.9723V13.279C2 11.838 3.03337 10.5625 4.50491 10.3653Z M15.6646 14.921C16.3997 15.2885 17.1998 15.4723 18 15.4723V16.5002C18 17.3286 17.3284 18.0002 16.5 18.0002H3.5C2.67157 18.0002 2 17.3286 2 16.5002V15.4723C2.80016 15.4723 3.60032 15.2885 4.33541 14.921C5.38329 14.397 6.61671 14.397 7.66459 14.921C9.13476 15.6561 10.8652 15.6561 12.3354 14.921C13.3833 14.397 14.6167 14.397 15.6646 14.921Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "calculator" shape.
+#[cfg(any(feature = "calculator", heroicon = "calculator"))]
+pub static CALCULATOR: super::StaticShape = super::StaticShape::new(
+    "calculator",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 1C8.28365 1 6.5916 1.10551 4.93005 1.31046C3.80579 1.44913 3 2.41374 3 3.51661V16.75C3 17.9926 4.00736 19 5.25 19H14.75C15.9926 19 17 17.9926 17 16.75V3.51661C17 2.41374 16.1942 1.44913 15.07 1.31046C13.4084 1.10551 11.7163 1 10 1ZM5.99023 8.75C5.99023 8.33579 6.32602 8 6.74023 8H6.75023C7.16445 8 7.50023 8.33579 7.50023 8.75V8.76C7.50023 9.17421 7.16445 9.51 6.75023 9.51H6.74023C6.32602 9.51 5.99023 9.17421 5.99023 8.76V8.75ZM6.74023 10.1666C6.32602 10.1666 5.99023 10.5024 5.99023 10.9166V10.9266C5.99023 11.3408 6.32602 11.6766 6.74023 11.6766H6.75023C7.16445 11.6766 7.50023 11.3408 7.50023 10.9266V10.9166C7.50023 10.5024 7.16445 10.1666 6.75023 10.1666H6.74023ZM5.99023 13.0833C5.99023 12.669 6.32602 12.3333 6.74023 12.3333H6.75023C7.16445 12.3333 7.50023 12.669 7.50023 13.0833V13.0933C7.50023 13.5075 7.16445 13.8433 6.75023 13.8433H6.74023C6.32602 13.8433 5.99023 13.5075 5.99023 13.0933V13.0833ZM6.74023 14.4999C6.32602 14.4999 5.99023 14.8357 5.99023 15.2499V15.2599C5.99023 15.6741 6.32602 16.0099 6.74023 16.0099H6.75023C7.16445 16.0099 7.50023 15.6741 7.50023 15.2599V15.2499C7.50023 14.8357 7.16445 14.4999 6.75023 14.4999H6.74023ZM8.15691 8.75C8.15691 8.33579 8.49269 8 8.90691 8H8.91691C9.33112 8 9.66691 8.33579 9.66691 8.75V8.76C9.66691 9.17421 9.33112 9.51 8.91691 9.51H8.90691C8.49269 9.51 8.15691 9.17421 8.15691 8.76V8.75ZM8.90691 10.1666C8.49269 10.1666 8.15691 10.5024 8.15691 10.9166V10.9266C8.15691 11.3408 8.49269 11.6766 8.90691 11.6766H8.91691C9.33112 11.6766 9.66691 11.3408 9.66691 10.9266V10.9166C9.66691 10.5024 9.33112 10.1666 8.91691 10.1666H8.90691ZM8.15691 13.0833C8.15691 12.669 8.49269 12.3333 8.90691 12.3333H8.91691C9.33112 12.3333 9.66691 12.669 9.66691 13.0833V13.0933C9.66691 13.5075 9.33112 13.8433 8.91691 13.8433H8.90691C8.49269 13.8433 8.15691 13.5075 8.15691 13.0933V13.0833ZM8.90691 14.4999C8.49269 14.4999 8.15691 14.8357 8.15691 15.2499V15.2599C8.15691 15.6741 8.49269 16.0099 8.90691 16.0099H8.91691C9.33112 16.0099 9.66691 15.6741 9.66691 15.2599V15.2499C9.66691 14.8357 9.33112 14.4999 8.91691 14.4999H8.90691ZM10.3269 8.75C10.3269 8.33579 10.6627 8 11.0769 8H11.0869C11.5011 8 11.8369 8.33579 11.8369 8.75V8.76C11.8369 9.17421 11.5011 9.51 11.0869 9.51H11.0769C10.6627 9.51 10.3269 9.17421 10.3269 8.76V8.75ZM11.0769 10.1666C10.6627 10.1666 10.3269 10.5024 10.3269 10.9166V10.9266C10.3269 11.3408 10.6627 11.6766 11.0769 11.6766H11.0869C11.5011 11.6766 11.8369 11.3408 11.8369 10.9266V10.9166C11.8369 10.5024 11.5011 10.1666 11.0869 10.1666H11.0769ZM10.3269 13.0833C10.3269 12.669 10.6627 12.3333 11.0769 12.3333H11.0869C11.5011 12.3333 11.8369 12.669 11.8369 13.0833V13.0933C11.8369 13.5075 11.5011 13.8433 11.0869 13.8433H11.0769C10.6627 13.8433 10.3269 13.5075 10.3269 13.0933V13.0833ZM11.0769 14.4999C10.6627 14.4999 10.3269 14.8357 10.3269 15.2499V15.2599C10.3269 15.6741 10.6627 16.0099 11.0769 16.0099H11.0869C11.5011 16.0099 11.8369 15.6741 11.8369 15.2599V15.2499C11.8369 14.8357 11.5011 14.4999 11.0869 14.4999H11.0769ZM12.5002 8.75C12.5002 8.33579 12.836 8 13.2502 8H13.2602C13.6744 8 14.0102 8.33579 14.0102 8.75V8.76C14.0102 9.17421 13.6744 9.51 13.2602 9.51H13.2502C12.836 9.51 12.5002 9.17421 12.5002 8.76V8.75ZM13.2502 10.1666C12.836 10.1666 12.5002 10.5024 12.5002 10.9166V10.9266C12.5002 11.3408 12.836 11.6766 13.2502 11.6766H13.2602C13.6744 11.6766 14.0102 11.3408 14.0102 10.9266V10.9166C14.0102 10.5024 13.6744 10.1666 13.2602 10.1666H13.2502ZM13.2502 12.3334C13.6644 12.3334 14.0002 12.6692 14.0002 13.0834V15.25C14.0002 15.6643 13.6644 16 13.2502 16C12.836 16 12.5002 15.6643 12.5002 15.25V13.0834C12.5002 12.6692 12.836 12.3334 13.2502 12.3334ZM6.75 4C6.33579 4 6 4.33579 6 4.75V5.25C6 5.66421 6.33579 6 6.75 6H13.25C13.6642 6 14 5.66421 14 5.25V4.75C14 4.33579 13.6642 4 13.25 4H6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "calendar-days" shape.
+#[cfg(any(feature = "calendar-days", heroicon = "calendar-days"))]
+pub static CALENDAR_DAYS: super::StaticShape = super::StaticShape::new(
+    "calendar-days",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.25 12C5.25 11.5858 5.58579 11.25 6 11.25H6.01C6.42421 11.25 6.76 11.5858 6.76 12V12.01C6.76 12.4242 6.42421 12.76 6.01 12.76H6C5.58579 12.76 5.25 12.4242 5.25 12.01V12Z M6 13.25C5.58579 13.25 5.25 13.5858 5.25 14V14.01C5.25 14.4242 5.58579 14.76 6 14.76H6.01C6.42421 14.76 6.76 14.4242 6.76 14.01V14C6.76 13.5858 6.42421 13.25 6.01 13.25H6Z M7.25 12C7.25 11.5858 7.58579 11.25 8 11.25H8.01C8.42421 11.25 8.76 11.5858 8.76 12V12.01C8.76 12.4242 8.42421 12.76 8.01 12.76H8C7.58579 12.76 7.25 12.4242 7.25 12.01V12Z M8 13.25C7.58579 13.25 7.25 13.5858 7.25 14V14.01C7.25 14.4242 7.58579 14.76 8 14.76H8.01C8.42421 14.76 8.76 14.4242 8.76 14.01V14C8.76 13.5858 8.42421 13.25 8.01 13.25H8Z M9.25 10C9.25 9.58579 9.58579 9.25 10 9.25H10.01C10.4242 9.25 10.76 9.58579 10.76 10V10.01C10.76 10.4242 10.4242 10.76 10.01 10.76H10C9.58579 10.76 9.25 10.4242 9.25 10.01V10Z M10 11.25C9.58579 11.25 9.25 11.5858 9.25 12V12.01C9.25 12.4242 9.58579 12.76 10 12.76H10.01C10.4242 12.76 10.76 12.4242 10.76 12.01V12C10.76 11.5858 10.4242 11.25 10.01 11.25H10Z M9.25 14C9.25 13.5858 9.58579 13.25 10 13.25H10.01C10.4242 13.25 10.76 13.5858 10.76 14V14.01C10.76 14.4242 10.4242 14.76 10.01 14.76H10C9.58579 14.76 9.25 14.4242 9.25 14.01V14Z M12 9.25C11.5858 9.25 11.25 9.58579 11.25 10V10.01C11.25 10.4242 11.5858 10.76 12 10.76H12.01C12.4242 10.76 12.76 10.4242 12.76 10.01V10C12.76 9.58579 12.4242 9.25 12.01 9.25H12Z M11.25 12C11.25 11.5858 11.5858 11.25 12 11.25H12.01C12.4242 11.25 12.76 11.5858 12.76 12V12.01C12.76 12.4242 12.4242 12.76 12.01 12.76H12C11.5858 12.76 11.25 12.4242 11.25 12.01V12Z M12 13.25C11.5858 13.25 11.25 13.5858 11.25 14V14.01C11.25 14.4242 11.5858 14.76 12 14.76H12.01C12.4242 14.76 12.76 14.4242 12.76 14.01V14C12.76 13.5858 12.4242 13.25 12.01 13.25H12Z M13.25 10C13.25 9.58579 13.5858 9.25 14 9.25H14.01C14.4242 9.25 14.76 9.58579 14.76 10V10.01C14.76 10.4242 14.4242 10.76 14.01 10.76H14C13.5858 10.76 13.25 10.4242 13.25 10.01V10Z M14 11.25C13.5858 11.25 13.25 11.5858 13.25 12V12.01C13.25 12.4242 13.5858 12.76 14 12.76H14.01C14.4242 12.76 14.76 12.4242 14.76 12.01V12C14.76 11.5858 14.4242 11.25 14.01 11.25H14Z M5.75 2C6.16421 2 6.5 2.33579 6.5 2.75V4H13.5V2.75C13.5 2.33579 13.8358 2 14.25 2C14.6642 2 15 2.33579 15 2.75V4H15.25C16.7688 4 18 5.23122 18 6.75V15.25C18 16.7688 16.7688 18 15.25 18H4.75C3.23122 18 2 16.7688 2 15.25V6.75C2 5.23122 3.23122 4 4.75 4H5V2.75C5 2.33579 5.33579 2 5.75 2ZM4.75 7.5C4.05964 7.5 3.5 8.05964 3.5 8.75V15.25C3.5 15.9404 4.05964 16.5 4.75 16.5H15.25C15.9404 16.5 16.5 15.9404 16.5 15.25V8.75C16.5 8.05964 15.9404 7.5 15.25 7.5H4.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "calendar" shape.
+#[cfg(any(feature = "calendar", heroicon = "calendar"))]
+pub static CALENDAR: super::StaticShape = super::StaticShape::new(
+    "calendar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.75 2C6.16421 2 6.5 2.33579 6.5 2.75V4H13.5V2.75C13.5 2.33579 13.8358 2 14.25 2C14.6642 2 15 2.33579 15 2.75V4H15.25C16.7688 4 18 5.23122 18 6.75V15.25C18 16.7688 16.7688 18 15.25 18H4.75C3.23122 18 2 16.7688 2 15.25V6.75C2 5.23122 3.23122 4 4.75 4H5V2.75C5 2.33579 5.33579 2 5.75 2ZM4.75 7.5C4.05964 7.5 3.5 8.05964 3.5 8.75V15.25C3.5 15.9404 4.05964 16.5 4.75 16.5H15.25C15.9404 16.5 16.5 15.9404 16.5 15.25V8.75C16.5 8.05964 15.9404 7.5 15.25 7.5H4.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "camera" shape.
+#[cfg(any(feature = "camera", heroicon = "camera"))]
+pub static CAMERA: super::StaticShape = super::StaticShape::new(
+    "camera",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 8C1 6.89543 1.89543 6 3 6H3.92963C4.59834 6 5.2228 5.6658 5.59373 5.1094L6.40627 3.8906C6.7772 3.3342 7.40166 3 8.07037 3H11.9296C12.5983 3 13.2228 3.3342 13.5937 3.8906L14.4063 5.1094C14.7772 5.6658 15.4017 6 16.0704 6H17C18.1046 6 19 6.89543 19 8V15C19 16.1046 18.1046 17 17 17H3C1.89543 17 1 16.1046 1 15V8ZM14.5 11C14.5 13.4853 12.4853 15.5 10 15.5C7.51472 15.5 5.5 13.4853 5.5 11C5.5 8.51472 7.51472 6.5 10 6.5C12.4853 6.5 14.5 8.51472 14.5 11ZM10 14C11.6569 14 13 12.6569 13 11C13 9.34315 11.6569 8 10 8C8.34315 8 7 9.34315 7 11C7 12.6569 8.34315 14 10 14Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chart-bar-square" shape.
+#[cfg(any(feature = "chart-bar-square", heroicon = "chart-bar-square"))]
+pub static CHART_BAR_SQUARE: super::StaticShape = super::StaticShape::new(
+    "chart-bar-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.25 2C3.00736 2 2 3.00736 2 4.25V15.75C2 16.9926 3.00736 18 4.25 18H15.75C16.9926 18 18 16.9926 18 15.75V4.25C18 3.00736 16.9926 2 15.75 2H4.25ZM15 5.75C15 5.33579 14.6642 5 14.25 5C13.8358 5 13.5 5.33579 13.5 5.75V14.25C13.5 14.6642 13.8358 15 14.25 15C14.6642 15 15 14.6642 15 14.25V5.75ZM6.5 11.75C6.5 11.3358 6.16421 11 5.75 11C5.33579 11 5 11.3358 5 11.75V14.25C5 14.6642 5.33579 15 5.75 15C6.16421 15 6.5 14.6642 6.5 14.25V11.75ZM8.5835 9C8.99771 9 9.3335 9.33579 9.3335 9.75V14.25C9.3335 14.6642 8.99771 15 8.5835 15C8.16928 15 7.8335 14.6642 7.8335 14.25V9.75C7.8335 9.33579 8.16928 9 8.5835 9ZM12.1636 7.75C12.1636 7.33579 11.8278 7 11.4136 7C10.9994 7 10.6636 7.33579 10.6636 7.75V14.25C10.6636 14.6642 10.9994 15 11.4136 15C11.8278 15 12.1636 14.6642 12.1636 14.25V7.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chart-bar" shape.
+#[cfg(any(feature = "chart-bar", heroicon = "chart-bar"))]
+pub static CHART_BAR: super::StaticShape = super::StaticShape::new(
+    "chart-bar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.5 2C14.6716 2 14 2.67157 14 3.5V16.5C14 17.3284 14.6716 18 15.5 18H16.5C17.3284 18 18 17.3284 18 16.5V3.5C18 2.67157 17.3284 2 16.5 2H15.5Z M9.5 6C8.67157 6 8 6.67157 8 7.5V16.5C8 17.3284 8.67157 18 9.5 18H10.5C11.3284 18 12 17.3284 12 16.5V7.5C12 6.67157 11.3284 6 10.5 6H9.5Z M3.5 10C2.67157 10 2 10.6716 2 11.5V16.5C2 17.3284 2.67157 18 3.5 18H4.5C5.32843 18 6 17.3284 6 16.5V11.5C6 10.6716 5.32843 10 4.5 10H3.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chart-pie" shape.
+#[cfg(any(feature = "chart-pie", heroicon = "chart-pie"))]
+pub static CHART_PIE: super::StaticShape = super::StaticShape::new(
+    "chart-pie",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 8.99979C11.4477 8.99979 11 8.55207 11 7.99979V2.99979C11 2.4475 11.4503 1.99229 11.997 2.07025C15.0651 2.50777 17.492 4.93467 17.9295 8.00276C18.0075 8.54951 17.5523 8.99979 17 8.99979H12Z M8.00297 4.07025C8.54972 3.99229 9 4.4475 9 4.99979V9.99979C9 10.5521 9.44772 10.9998 10 10.9998H15C15.5523 10.9998 16.0075 11.4501 15.9295 11.9968C15.4456 15.3906 12.5275 17.9998 9 17.9998C5.13401 17.9998 2 14.8658 2 10.9998C2 7.47229 4.60923 4.5542 8.00297 4.07025Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-bottom-center-text" shape.
+#[cfg(
+    any(
+        feature = "chat-bubble-bottom-center-text",
+        heroicon = "chat-bubble-bottom-center-text"
+    )
+)]
+pub static CHAT_BUBBLE_BOTTOM_CENTER_TEXT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-bottom-center-text",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 2C7.76407 2 5.56943 2.17905 3.42976 2.52374C1.99339 2.75513 1 4.01325 1 5.42588V10.5741C1 11.9867 1.99338 13.2449 3.42976 13.4763C4.59764 13.6644 5.78193 13.8032 6.9804 13.8905C7.2601 13.9108 7.5012 14.0703 7.62247 14.3035L9.33459 17.596C9.46367 17.8443 9.7202 18 10 18C10.2798 18 10.5363 17.8443 10.6654 17.596L12.3775 14.3035C12.4988 14.0703 12.7399 13.9108 13.0196 13.8905C14.2181 13.8032 15.4024 13.6644 16.5702 13.4763C18.0066 13.2449 19 11.9867 19 10.5741V5.42588C19 4.01325 18.0066 2.75513 16.5702 2.52374C14.4306 2.17905 12.2359 2 10 2ZM6.75 6C6.33579 6 6 6.33579 6 6.75C6 7.16421 6.33579 7.5 6.75 7.5H13.25C13.6642 7.5 14 7.16421 14 6.75C14 6.33579 13.6642 6 13.25 6H6.75ZM6.75 8.5C6.33579 8.5 6 8.83579 6 9.25C6 9.66421 6.33579 10 6.75 10H10.25C10.6642 10 11 9.66421 11 9.25C11 8.83579 10.6642 8.5 10.25 8.5H6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-bottom-center" shape.
+#[cfg(
+    any(feature = "chat-bubble-bottom-center", heroicon = "chat-bubble-bottom-center")
+)]
+pub static CHAT_BUBBLE_BOTTOM_CENTER: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-bottom-center",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.42976 2.52374C5.56943 2.17905 7.76407 2 10 2C12.2359 2 14.4306 2.17905 16.5702 2.52374C18.0066 2.75513 19 4.01325 19 5.42588V10.5741C19 11.9867 18.0066 13.2449 16.5702 13.4763C15.4024 13.6644 14.2181 13.8032 13.0196 13.8905C12.7399 13.9108 12.4988 14.0703 12.3775 14.3035L10.6654 17.596C10.5363 17.8443 10.2798 18 10 18C9.7202 18 9.46367 17.8443 9.33459 17.596L7.62247 14.3035C7.5012 14.0703 7.2601 13.9108 6.9804 13.8905C5.78193 13.8032 4.59764 13.6644 3.42976 13.4763C1.99338 13.2449 1 11.9867 1 10.5741V5.42588C1 4.01325 1.99339 2.75513 3.42976 2.52374Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-left-ellipsis" shape.
+#[cfg(
+    any(feature = "chat-bubble-left-ellipsis", heroicon = "chat-bubble-left-ellipsis")
+)]
+pub static CHAT_BUBBLE_LEFT_ELLIPSIS: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-left-ellipsis",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 2C7.76407 2 5.56943 2.17905 3.42976 2.52374C1.99339 2.75513 1 4.01325 1 5.42588V10.5741C1 11.9868 1.99339 13.2449 3.42976 13.4763C4.27814 13.6129 5.13517 13.7236 6 13.8073V17.25C6 17.5533 6.18273 17.8268 6.46299 17.9429C6.74324 18.059 7.06583 17.9948 7.28033 17.7803L10.8594 14.2013C10.9953 14.0654 11.1832 13.9837 11.3869 13.977C13.1447 13.9185 14.8747 13.7494 16.5702 13.4763C18.0066 13.2449 19 11.9867 19 10.5741V5.42588C19 4.01325 18.0066 2.75513 16.5702 2.52374C14.4306 2.17905 12.2359 2 10 2ZM10 9C10.5523 9 11 8.55228 11 8C11 7.44772 10.5523 7 10 7C9.44771 7 9 7.44772 9 8C9 8.55228 9.44771 9 10 9ZM8 8C8 8.55228 7.55228 9 7 9C6.44772 9 6 8.55228 6 8C6 7.44772 6.44772 7 7 7C7.55228 7 8 7.44772 8 8ZM13 9C13.5523 9 14 8.55228 14 8C14 7.44772 13.5523 7 13 7C12.4477 7 12 7.44772 12 8C12 8.55228 12.4477 9 13 9Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-left-right" shape.
+#[cfg(any(feature = "chat-bubble-left-right", heroicon = "chat-bubble-left-right"))]
+pub static CHAT_BUBBLE_LEFT_RIGHT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-left-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.50491 2.36515C5.30269 2.1243 7.13702 2 9 2C10.863 2 12.6973 2.1243 14.4951 2.36515C15.7418 2.53218 16.6741 3.4733 16.9298 4.6333C16.7425 4.59823 16.5501 4.57486 16.3531 4.5641C15.5739 4.52154 14.7894 4.5 14 4.5C13.2106 4.5 12.4261 4.52154 11.6469 4.5641C9.22913 4.69615 7.5 6.72739 7.5 8.99794V11.2377C7.5 12.6511 8.17008 13.9726 9.26042 14.8002L6.28033 17.7803C6.06583 17.9948 5.74324 18.059 5.46299 17.9429C5.18273 17.8268 5 17.5533 5 17.25V13.8073C4.49897 13.7588 4.00056 13.7013 3.50493 13.6349C2.0334 13.4377 1 12.1622 1 10.7211V5.27886C1 3.83785 2.03337 2.5623 3.50491 2.36515Z M14 6C13.2379 6 12.4807 6.0208 11.7287 6.06187C10.1572 6.1477 9 7.47212 9 8.99794V11.2377C9 12.7565 10.1467 14.077 11.7102 14.1726C11.9238 14.1857 12.1379 14.1971 12.3524 14.2068C12.5526 14.216 12.7366 14.2973 12.8702 14.4309L15.2197 16.7803C15.4342 16.9948 15.7568 17.059 16.037 16.9429C16.3173 16.8268 16.5 16.5533 16.5 16.25V14.1795C17.9525 13.9847 19 12.7165 19 11.2648V8.99794C19 7.47212 17.8428 6.1477 16.2713 6.06187C15.5193 6.0208 14.7621 6 14 6Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-left" shape.
+#[cfg(any(feature = "chat-bubble-left", heroicon = "chat-bubble-left"))]
+pub static CHAT_BUBBLE_LEFT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.42976 2.52374C5.56943 2.17905 7.76407 2 10 2C12.2359 2 14.4306 2.17905 16.5702 2.52374C18.0066 2.75513 19 4.01325 19 5.42588V10.5741C19 11.9867 18.0066 13.2449 16.5702 13.4763C14.8747 13.7494 13.1447 13.9185 11.3869 13.977C11.1832 13.9837 10.9953 14.0654 10.8594 14.2013L7.28033 17.7803C7.06583 17.9948 6.74324 18.059 6.46299 17.9429C6.18273 17.8268 6 17.5533 6 17.25V13.8073C5.13517 13.7236 4.27814 13.6129 3.42976 13.4763C1.99339 13.2449 1 11.9868 1 10.5741V5.42588C1 4.01325 1.99339 2.75513 3.42976 2.52374Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-oval-left-ellipsis" shape.
+#[cfg(
+    any(
+        feature = "chat-bubble-oval-left-ellipsis",
+        heroicon = "chat-bubble-oval-left-ellipsis"
+    )
+)]
+pub static CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-oval-left-ellipsis",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 3C5.69006 3 2 6.0334 2 10C2 12.0244 2.97849 13.8253 4.49899 15.0848C4.48371 15.7294 4.29476 16.329 3.97742 16.8409C3.83914 17.064 3.82753 17.3431 3.9468 17.5769C4.06608 17.8107 4.29888 17.9651 4.56065 17.9841C4.70585 17.9947 4.85237 18 5 18C6.3037 18 7.51177 17.5834 8.49617 16.8766C8.98381 16.9577 9.48658 17 10 17C14.3099 17 18 13.9666 18 10C18 6.0334 14.3099 3 10 3ZM10 11C10.5523 11 11 10.5523 11 10C11 9.44772 10.5523 9 10 9C9.44772 9 9 9.44772 9 10C9 10.5523 9.44772 11 10 11ZM8 10C8 10.5523 7.55228 11 7 11C6.44772 11 6 10.5523 6 10C6 9.44772 6.44772 9 7 9C7.55228 9 8 9.44772 8 10ZM13 11C13.5523 11 14 10.5523 14 10C14 9.44772 13.5523 9 13 9C12.4477 9 12 9.44772 12 10C12 10.5523 12.4477 11 13 11Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-oval-left" shape.
+#[cfg(any(feature = "chat-bubble-oval-left", heroicon = "chat-bubble-oval-left"))]
+pub static CHAT_BUBBLE_OVAL_LEFT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-oval-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 10C2 6.0334 5.69006 3 10 3C14.3099 3 18 6.0334 18 10C18 13.9666 14.3099 17 10 17C9.48658 17 8.98381 16.9577 8.49617 16.8766C7.51177 17.5834 6.3037 18 5 18C4.85237 18 4.70585 17.9947 4.56065 17.9841C4.29888 17.9651 4.06608 17.8107 3.9468 17.5769C3.82753 17.3431 3.83914 17.064 3.97742 16.8409C4.29476 16.329 4.48371 15.7294 4.49899 15.0848C2.97849 13.8253 2 12.0244 2 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "check-badge" shape.
+#[cfg(any(feature = "check-badge", heroicon = "check-badge"))]
+pub static CHECK_BADGE: super::StaticShape = super::StaticShape::new(
+    "check-badge",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.4032 12.6523C17.353 12.1487 18 11.1499 18 10C18 8.85007 17.353 7.85126 16.4032 7.34771C16.7188 6.32002 16.47 5.15625 15.6569 4.34312C14.8437 3.53 13.68 3.28122 12.6523 3.59679C12.1487 2.64698 11.1499 2 10 2C8.85007 2 7.85125 2.64699 7.3477 3.59681C6.32002 3.28126 5.15627 3.53004 4.34315 4.34316C3.53003 5.15628 3.28125 6.32003 3.5968 7.34771C2.64699 7.85126 2 8.85007 2 10C2 11.1499 2.64699 12.1487 3.59681 12.6523C3.28124 13.68 3.53001 14.8437 4.34314 15.6569C5.15627 16.47 6.32003 16.7188 7.34771 16.4032C7.85126 17.353 8.85007 18 10 18C11.1499 18 12.1488 17.353 12.6523 16.4032C13.68 16.7187 14.8437 16.47 15.6569 15.6568C16.47 14.8437 16.7188 13.68 16.4032 12.6523ZM13.8566 8.19113C14.1002 7.85614 14.0261 7.38708 13.6911 7.14345C13.3561 6.89982 12.8871 6.97388 12.6434 7.30887L9.15969 12.099L7.28033 10.2197C6.98744 9.92678 6.51256 9.92678 6.21967 10.2197C5.92678 10.5126 5.92678 10.9874 6.21967 11.2803L8.71967 13.7803C8.87477 13.9354 9.08999 14.0149 9.30867 13.9977C9.52734 13.9805 9.72754 13.8685 9.85655 13.6911L13.8566 8.19113Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "check-circle" shape.
+#[cfg(any(feature = "check-circle", heroicon = "check-circle"))]
+pub static CHECK_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "check-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM13.8566 8.19113C14.1002 7.85614 14.0261 7.38708 13.6911 7.14345C13.3561 6.89982 12.8871 6.97388 12.6434 7.30887L9.15969 12.099L7.28033 10.2197C6.98744 9.92678 6.51256 9.92678 6.21967 10.2197C5.92678 10.5126 5.92678 10.9874 6.21967 11.2803L8.71967 13.7803C8.87477 13.9354 9.08999 14.0149 9.30867 13.9977C9.52734 13.9805 9.72754 13.8685 9.85655 13.6911L13.8566 8.19113Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "check" shape.
+#[cfg(any(feature = "check", heroicon = "check"))]
+pub static CHECK: super::StaticShape = super::StaticShape::new(
+    "check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.7045 4.15347C17.034 4.4045 17.0976 4.87509 16.8466 5.20457L8.84657 15.7046C8.71541 15.8767 8.51627 15.9838 8.30033 15.9983C8.08439 16.0129 7.87271 15.9334 7.71967 15.7804L3.21967 11.2804C2.92678 10.9875 2.92678 10.5126 3.21967 10.2197C3.51256 9.92682 3.98744 9.92682 4.28033 10.2197L8.17351 14.1129L15.6534 4.29551C15.9045 3.96603 16.3751 3.90243 16.7045 4.15347Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-down" shape.
+#[cfg(any(feature = "chevron-double-down", heroicon = "chevron-double-down"))]
+pub static CHEVRON_DOUBLE_DOWN: super::StaticShape = super::StaticShape::new(
+    "chevron-double-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.7698 4.20938C15.0684 4.49647 15.0777 4.97125 14.7906 5.26983L10.5406 9.76983C10.3992 9.91689 10.204 10 10 10C9.79599 10 9.60078 9.91689 9.45937 9.76983L5.20937 5.26983C4.92228 4.97125 4.93159 4.49647 5.23017 4.20938C5.52875 3.92228 6.00353 3.93159 6.29062 4.23017L10 8.16792L13.7094 4.23017C13.9965 3.93159 14.4713 3.92228 14.7698 4.20938ZM14.7698 10.2094C15.0684 10.4965 15.0777 10.9713 14.7906 11.2698L10.5406 15.7698C10.3992 15.9169 10.204 16 10 16C9.79599 16 9.60078 15.9169 9.45937 15.7698L5.20937 11.2698C4.92228 10.9713 4.93159 10.4965 5.23017 10.2094C5.52875 9.92228 6.00353 9.93159 6.29062 10.2302L10 14.1679L13.7094 10.2302C13.9965 9.93159 14.4713 9.92228 14.7698 10.2094Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-left" shape.
+#[cfg(any(feature = "chevron-double-left", heroicon = "chevron-double-left"))]
+pub static CHEVRON_DOUBLE_LEFT: super::StaticShape = super::StaticShape::new(
+    "chevron-double-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.7906 14.7698C15.5035 15.0684 15.0287 15.0777 14.7302 14.7906L10.2302 10.5406C10.0831 10.3992 10 10.204 10 10C10 9.79599 10.0831 9.60078 10.2302 9.45938L14.7302 5.20937C15.0287 4.92228 15.5035 4.93159 15.7906 5.23017C16.0777 5.52875 16.0684 6.00353 15.7698 6.29062L11.8321 10L15.7698 13.7094C16.0684 13.9965 16.0777 14.4713 15.7906 14.7698ZM9.79062 14.7698C9.50353 15.0684 9.02875 15.0777 8.73017 14.7906L4.23017 10.5406C4.08311 10.3992 4 10.204 4 10C4 9.79599 4.08311 9.60078 4.23017 9.45938L8.73017 5.20938C9.02875 4.92228 9.50353 4.93159 9.79062 5.23017C10.0777 5.52875 10.0684 6.00353 9.76983 6.29062L5.83208 10L9.76983 13.7094C10.0684 13.9965 10.0777 14.4713 9.79062 14.7698Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-right" shape.
+#[cfg(any(feature = "chevron-double-right", heroicon = "chevron-double-right"))]
+pub static CHEVRON_DOUBLE_RIGHT: super::StaticShape = super::StaticShape::new(
+    "chevron-double-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.2094 14.7698C9.92228 14.4713 9.93159 13.9965 10.2302 13.7094L14.1679 10L10.2302 6.29062C9.93159 6.00353 9.92228 5.52875 10.2094 5.23017C10.4965 4.93159 10.9713 4.92228 11.2698 5.20937L15.7698 9.45937C15.9169 9.60078 16 9.79599 16 10C16 10.204 15.9169 10.3992 15.7698 10.5406L11.2698 14.7906C10.9713 15.0777 10.4965 15.0684 10.2094 14.7698Z M4.20938 14.7698C3.92228 14.4713 3.93159 13.9965 4.23017 13.7094L8.16792 10L4.23017 6.29062C3.93159 6.00353 3.92228 5.52875 4.20938 5.23017C4.49647 4.93159 4.97125 4.92228 5.26983 5.20937L9.76983 9.45937C9.91689 9.60078 10 9.79599 10 10C10 10.204 9.91689 10.3992 9.76983 10.5406L5.26983 14.7906C4.97125 15.0777 4.49647 15.0684 4.20938 14.7698Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-up" shape.
+#[cfg(any(feature = "chevron-double-up", heroicon = "chevron-double-up"))]
+pub static CHEVRON_DOUBLE_UP: super::StaticShape = super::StaticShape::new(
+    "chevron-double-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.23017 15.7906C4.93159 15.5035 4.92228 15.0287 5.20938 14.7302L9.45938 10.2302C9.60078 10.0831 9.79599 10 10 10C10.204 10 10.3992 10.0831 10.5406 10.2302L14.7906 14.7302C15.0777 15.0287 15.0684 15.5035 14.7698 15.7906C14.4713 16.0777 13.9965 16.0684 13.7094 15.7698L10 11.8321L6.29063 15.7698C6.00353 16.0684 5.52875 16.0777 5.23017 15.7906ZM5.23017 9.79062C4.93159 9.50353 4.92228 9.02875 5.20938 8.73017L9.45938 4.23017C9.60078 4.08311 9.79599 4 10 4C10.204 4 10.3992 4.08311 10.5406 4.23017L14.7906 8.73017C15.0777 9.02875 15.0684 9.50353 14.7698 9.79062C14.4713 10.0777 13.9965 10.0684 13.7094 9.76983L10 5.83208L6.29063 9.76983C6.00353 10.0684 5.52875 10.0777 5.23017 9.79062Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-down" shape.
+#[cfg(any(feature = "chevron-down", heroicon = "chevron-down"))]
+pub static CHEVRON_DOWN: super::StaticShape = super::StaticShape::new(
+    "chevron-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.23017 7.20938C5.52875 6.92228 6.00353 6.93159 6.29063 7.23017L10 11.1679L13.7094 7.23017C13.9965 6.93159 14.4713 6.92228 14.7698 7.20938C15.0684 7.49647 15.0777 7.97125 14.7906 8.26983L10.5406 12.7698C10.3992 12.9169 10.204 13 10 13C9.79599 13 9.60078 12.9169 9.45938 12.7698L5.20938 8.26983C4.92228 7.97125 4.93159 7.49647 5.23017 7.20938Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-left" shape.
+#[cfg(any(feature = "chevron-left", heroicon = "chevron-left"))]
+pub static CHEVRON_LEFT: super::StaticShape = super::StaticShape::new(
+    "chevron-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.7906 5.23017C13.0777 5.52875 13.0684 6.00353 12.7698 6.29063L8.83208 10L12.7698 13.7094C13.0684 13.9965 13.0777 14.4713 12.7906 14.7698C12.5035 15.0684 12.0287 15.0777 11.7302 14.7906L7.23017 10.5406C7.08311 10.3992 7 10.204 7 10C7 9.79599 7.08311 9.60078 7.23017 9.45938L11.7302 5.20938C12.0287 4.92228 12.5035 4.93159 12.7906 5.23017Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-right" shape.
+#[cfg(any(feature = "chevron-right", heroicon = "chevron-right"))]
+pub static CHEVRON_RIGHT: super::StaticShape = super::StaticShape::new(
+    "chevron-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.20938 14.7698C6.92228 14.4713 6.93159 13.9965 7.23017 13.7094L11.1679 10L7.23017 6.29062C6.93159 6.00353 6.92228 5.52875 7.20938 5.23017C7.49647 4.93159 7.97125 4.92228 8.26983 5.20937L12.7698 9.45937C12.9169 9.60078 13 9.79599 13 10C13 10.204 12.9169 10.3992 12.7698 10.5406L8.26983 14.7906C7.97125 15.0777 7.49647 15.0684 7.20938 14.7698Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-up-down" shape.
+#[cfg(any(feature = "chevron-up-down", heroicon = "chevron-up-down"))]
+pub static CHEVRON_UP_DOWN: super::StaticShape = super::StaticShape::new(
+    "chevron-up-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 3C10.2086 3 10.4077 3.08684 10.5496 3.23966L13.7996 6.73966C14.0815 7.0432 14.0639 7.51774 13.7603 7.7996C13.4568 8.08145 12.9823 8.06387 12.7004 7.76034L10 4.85221L7.2996 7.76034C7.01775 8.06387 6.5432 8.08145 6.23966 7.79959C5.93613 7.51774 5.91856 7.04319 6.20041 6.73966L9.45041 3.23966C9.59232 3.08684 9.79145 3 10 3ZM6.23967 12.2004C6.5432 11.9186 7.01775 11.9361 7.2996 12.2397L10 15.1478L12.7004 12.2397C12.9823 11.9361 13.4568 11.9186 13.7603 12.2004C14.0639 12.4823 14.0815 12.9568 13.7996 13.2603L10.5496 16.7603C10.4077 16.9132 10.2086 17 10 17C9.79145 17 9.59232 16.9132 9.45041 16.7603L6.20041 13.2603C5.91856 12.9568 5.93613 12.4823 6.23967 12.2004Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-up" shape.
+#[cfg(any(feature = "chevron-up", heroicon = "chevron-up"))]
+pub static CHEVRON_UP: super::StaticShape = super::StaticShape::new(
+    "chevron-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.7698 12.7906C14.4713 13.0777 13.9965 13.0684 13.7094 12.7698L10 8.83208L6.29062 12.7698C6.00353 13.0684 5.52875 13.0777 5.23017 12.7906C4.93159 12.5035 4.92228 12.0287 5.20937 11.7302L9.45937 7.23017C9.60078 7.08311 9.79599 7 10 7C10.204 7 10.3992 7.08311 10.5406 7.23017L14.7906 11.7302C15.0777 12.0287 15.0684 12.5035 14.7698 12.7906Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "circle-stack" shape.
+#[cfg(any(feature = "circle-stack", heroicon = "circle-stack"))]
+pub static CIRCLE_STACK: super::StaticShape = super::StaticShape::new(
+    "circle-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 1C13.866 1 17 2.79086 17 5C17 7.20914 13.866 9 10 9C6.13401 9 3 7.20914 3 5C3 2.79086 6.13401 1 10 1ZM15.694 9.13079C16.1576 8.86588 16.6044 8.54736 17 8.17775V10C17 12.2091 13.866 14 10 14C6.13401 14 3 12.2091 3 10V8.17775C3.3956 8.54736 3.84244 8.86588 4.30604 9.13079C5.83803 10.0062 7.85433 10.5 10 10.5C12.1457 10.5 14.162 10.0062 15.694 9.13079ZM3 13.1777V15C3 17.2091 6.13401 19 10 19C13.866 19 17 17.2091 17 15V13.1777C16.6044 13.5474 16.1576 13.8659 15.694 14.1308C14.162 15.0062 12.1457 15.5 10 15.5C7.85433 15.5 5.83803 15.0062 4.30604 14.1308C3.84244 13.8659 3.3956 13.5474 3 13.1777Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard-document-check" shape.
+#[cfg(any(feature = "clipboard-document-check", heroicon = "clipboard-document-check"))]
+pub static CLIPBOARD_DOCUMENT_CHECK: super::StaticShape = super::StaticShape::new(
+    "clipboard-document-check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.9999 5.25C17.9999 4.08761 17.1185 3.1311 15.9875 3.0124C15.8688 1.88145 14.9123 1 13.7499 1H12.2499C11.0875 1 10.131 1.88145 10.0123 3.0124C9.13721 3.10424 8.4115 3.69769 8.12793 4.5H10.9999C12.3806 4.5 13.4999 5.61929 13.4999 7V14H15.7499C16.9925 14 17.9999 12.9926 17.9999 11.75V5.25ZM12.2499 2.5C11.8357 2.5 11.4999 2.83579 11.4999 3.25V3.5H14.4999V3.25C14.4999 2.83579 14.1641 2.5 13.7499 2.5H12.2499Z M3 6C2.44772 6 2 6.44771 2 7V17C2 17.5523 2.44772 18 3 18H11C11.5523 18 12 17.5523 12 17V7C12 6.44772 11.5523 6 11 6H3ZM9.87404 10.166C10.1038 9.82138 10.0107 9.35573 9.66603 9.12596C9.32138 8.8962 8.85573 8.98933 8.62596 9.33397L6.13343 13.0728L5.28033 12.2197C4.98744 11.9268 4.51256 11.9268 4.21967 12.2197C3.92678 12.5126 3.92678 12.9874 4.21967 13.2803L5.71967 14.7803C5.87855 14.9392 6.1003 15.0185 6.3239 14.9963C6.5475 14.9742 6.7494 14.853 6.87404 14.666L9.87404 10.166Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard-document-list" shape.
+#[cfg(any(feature = "clipboard-document-list", heroicon = "clipboard-document-list"))]
+pub static CLIPBOARD_DOCUMENT_LIST: super::StaticShape = super::StaticShape::new(
+    "clipboard-document-list",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.9875 3.0124C17.1185 3.1311 17.9999 4.08761 17.9999 5.25V11.75C17.9999 12.9926 16.9925 14 15.7499 14H13.4999V7C13.4999 5.61929 12.3806 4.5 10.9999 4.5H8.12793C8.4115 3.69769 9.13721 3.10424 10.0123 3.0124C10.131 1.88145 11.0875 1 12.2499 1H13.7499C14.9123 1 15.8688 1.88145 15.9875 3.0124ZM11.4999 3.25C11.4999 2.83579 11.8357 2.5 12.2499 2.5H13.7499C14.1641 2.5 14.4999 2.83579 14.4999 3.25V3.5H11.4999V3.25Z M2 7C2 6.44771 2.44772 6 3 6H11C11.5523 6 12 6.44772 12 7V17C12 17.5523 11.5523 18 11 18H3C2.44772 18 2 17.5523 2 17V7ZM4 10.25C4 9.83579 4.33579 9.5 4.75 9.5H9.25C9.66421 9.5 10 9.83579 10 10.25C10 10.6642 9.66421 11 9.25 11H4.75C4.33579 11 4 10.6642 4 10.25ZM4 13.75C4 13.3358 4.33579 13 4.75 13H9.25C9.66421 13 10 13.3358 10 13.75C10 14.1642 9.66421 14.5 9.25 14.5H4.75C4.33579 14.5 4 14.1642 4 13.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard-document" shape.
+#[cfg(any(feature = "clipboard-document", heroicon = "clipboard-document"))]
+pub static CLIPBOARD_DOCUMENT: super::StaticShape = super::StaticShape::new(
+    "clipboard-document",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.9877 3.0124C17.1186 3.1311 18.0001 4.08761 18.0001 5.25V11.75C18.0001 12.9926 16.9927 14 15.7501 14H13.5001V10.6213C13.5001 9.82567 13.184 9.06261 12.6214 8.5L9.50008 5.37868C9.1106 4.9892 8.62505 4.71787 8.09912 4.58776C8.35944 3.74123 9.10578 3.10756 10.0125 3.0124C10.1312 1.88145 11.0877 1 12.2501 1H13.7501C14.9125 1 15.869 1.88145 15.9877 3.0124ZM11.5001 3.25C11.5001 2.83579 11.8359 2.5 12.2501 2.5H13.7501C14.1643 2.5 14.5001 2.83579 14.5001 3.25V3.5H11.5001V3.25Z M3.5 6C2.67157 6 2 6.67157 2 7.5V16.5C2 17.3284 2.67157 18 3.5 18H10.5C11.3284 18 12 17.3284 12 16.5V10.6213C12 10.2235 11.842 9.84196 11.5607 9.56066L8.43934 6.43934C8.15804 6.15804 7.7765 6 7.37868 6H3.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard" shape.
+#[cfg(any(feature = "clipboard", heroicon = "clipboard"))]
+pub static CLIPBOARD: super::StaticShape = super::StaticShape::new(
+    "clipboard",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.8871 3.18189C14.283 3.21913 14.6773 3.262 15.07 3.31043C16.1942 3.44911 17 4.41371 17 5.51659V16.75C17 17.9926 15.9926 19 14.75 19H5.25C4.00736 19 3 17.9926 3 16.75V5.51659C3 4.41371 3.80579 3.44911 4.93005 3.31043C5.32266 3.26201 5.71697 3.21913 6.11291 3.18189C6.46903 1.92267 7.62676 1 9 1H11C12.3732 1 13.531 1.92267 13.8871 3.18189ZM7.5 4C7.5 3.17157 8.17157 2.5 9 2.5H11C11.8284 2.5 12.5 3.17157 12.5 4V4.5H7.5V4Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clock" shape.
+#[cfg(any(feature = "clock", heroicon = "clock"))]
+pub static CLOCK: super::StaticShape = super::StaticShape::new(
+    "clock",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM10.75 5C10.75 4.58579 10.4142 4.25 10 4.25C9.58579 4.25 9.25 4.58579 9.25 5V10C9.25 10.4142 9.58579 10.75 10 10.75H14C14.4142 10.75 14.75 10.4142 14.75 10C14.75 9.58579 14.4142 9.25 14 9.25H10.75V5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cloud-arrow-down" shape.
+#[cfg(any(feature = "cloud-arrow-down", heroicon = "cloud-arrow-down"))]
+pub static CLOUD_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "cloud-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.5 17C3.01472 17 1 14.9853 1 12.5C1 10.5184 2.28084 8.83595 4.05979 8.2354C4.02046 7.9961 4 7.75044 4 7.5C4 5.01472 6.01472 3 8.5 3C10.1404 3 11.5758 3.87771 12.3621 5.18913C12.7189 5.06655 13.1017 5 13.5 5C15.433 5 17 6.567 17 8.5C17 8.83334 16.9534 9.15579 16.8664 9.4612C18.1353 10.1318 19 11.4649 19 13C19 15.2091 17.2091 17 15 17H5.5ZM10.75 7.75C10.75 7.33579 10.4142 7 10 7C9.58579 7 9.25 7.33579 9.25 7.75V12.3401L7.29959 10.2397C7.01774 9.93613 6.54319 9.91855 6.23966 10.2004C5.93613 10.4823 5.91855 10.9568 6.20041 11.2603L9.45041 14.7603C9.59231 14.9132 9.79145 15 10 15C10.2086 15 10.4077 14.9132 10.5496 14.7603L13.7996 11.2603C14.0814 10.9568 14.0639 10.4823 13.7603 10.2004C13.4568 9.91855 12.9823 9.93613 12.7004 10.2397L10.75 12.3401V7.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cloud-arrow-up" shape.
+#[cfg(any(feature = "cloud-arrow-up", heroicon = "cloud-arrow-up"))]
+pub static CLOUD_ARROW_UP: super::StaticShape = super::StaticShape::new(
+    "cloud-arrow-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.5 17C3.01472 17 1 14.9853 1 12.5C1 10.5184 2.28084 8.83595 4.05979 8.2354C4.02046 7.9961 4 7.75044 4 7.5C4 5.01472 6.01472 3 8.5 3C10.1404 3 11.5758 3.87771 12.3621 5.18913C12.7189 5.06655 13.1017 5 13.5 5C15.433 5 17 6.567 17 8.5C17 8.83334 16.9534 9.15579 16.8664 9.4612C18.1353 10.1318 19 11.4649 19 13C19 15.2091 17.2091 17 15 17H5.5ZM9.25 14.25C9.25 14.6642 9.58579 15 10 15C10.4142 15 10.75 14.6642 10.75 14.25V9.6599L12.7004 11.7603C12.9823 12.0639 13.4568 12.0814 13.7603 11.7996C14.0639 11.5177 14.0814 11.0432 13.7996 10.7397L10.5496 7.23966C10.4077 7.08684 10.2086 7 10 7C9.79145 7 9.59231 7.08684 9.45041 7.23966L6.20041 10.7397C5.91855 11.0432 5.93613 11.5177 6.23966 11.7996C6.54319 12.0814 7.01774 12.0639 7.2996 11.7603L9.25 9.6599V14.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cloud" shape.
+#[cfg(any(feature = "cloud", heroicon = "cloud"))]
+pub static CLOUD: super::StaticShape = super::StaticShape::new(
+    "cloud",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 12.5C1 14.9853 3.01472 17 5.5 17H15C17.2091 17 19 15.2091 19 13C19 11.4649 18.1353 10.1318 16.8664 9.4612C16.9534 9.15579 17 8.83334 17 8.5C17 6.567 15.433 5 13.5 5C13.1017 5 12.7189 5.06655 12.3621 5.18913C11.5758 3.87771 10.1404 3 8.5 3C6.01472 3 4 5.01472 4 7.5C4 7.75044 4.02046 7.9961 4.05979 8.2354C2.28084 8.83595 1 10.5184 1 12.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "code-bracket-square" shape.
+#[cfg(any(feature = "code-bracket-square", heroicon = "code-bracket-square"))]
+pub static CODE_BRACKET_SQUARE: super::StaticShape = super::StaticShape::new(
+    "code-bracket-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.25 2C3.00736 2 2 3.00736 2 4.25V15.75C2 16.9926 3.00736 18 4.25 18H15.75C16.9926 18 18 16.9926 18 15.75V4.25C18 3.00736 16.9926 2 15.75 2H4.25ZM8.28033 8.28033C8.57322 7.98744 8.57322 7.51256 8.28033 7.21967C7.98744 6.92678 7.51256 6.92678 7.21967 7.21967L4.96967 9.46967C4.67678 9.76256 4.67678 10.2374 4.96967 10.5303L7.21967 12.7803C7.51256 13.0732 7.98744 13.0732 8.28033 12.7803C8.57322 12.4874 8.57322 12.0126 8.28033 11.7197L6.56066 10L8.28033 8.28033ZM12.7803 7.21967C12.4874 6.92678 12.0126 6.92678 11.7197 7.21967C11.4268 7.51256 11.4268 7.98744 11.7197 8.28033L13.4393 10L11.7197 11.7197C11.4268 12.0126 11.4268 12.4874 11.7197 12.7803C12.0126 13.0732 12.4874 13.0732 12.7803 12.7803L15.0303 10.5303C15.3232 10.2374 15.3232 9.76256 15.0303 9.46967L12.7803 7.21967Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "code-bracket" shape.
+#[cfg(any(feature = "code-bracket", heroicon = "code-bracket"))]
+pub static CODE_BRACKET: super::StaticShape = super::StaticShape::new(
+    "code-bracket",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.28033 5.21979C6.57322 5.51269 6.57322 5.98756 6.28033 6.28045L2.56066 10.0001L6.28033 13.7198C6.57322 14.0127 6.57322 14.4876 6.28033 14.7805C5.98744 15.0733 5.51256 15.0733 5.21967 14.7805L0.96967 10.5305C0.676777 10.2376 0.676777 9.76269 0.96967 9.46979L5.21967 5.21979C5.51256 4.9269 5.98744 4.9269 6.28033 5.21979ZM13.7197 5.21979C14.0126 4.9269 14.4874 4.9269 14.7803 5.21979L19.0303 9.46979C19.3232 9.76269 19.3232 10.2376 19.0303 10.5305L14.7803 14.7805C14.4874 15.0733 14.0126 15.0733 13.7197 14.7805C13.4268 14.4876 13.4268 14.0127 13.7197 13.7198L17.4393 10.0001L13.7197 6.28045C13.4268 5.98756 13.4268 5.51269 13.7197 5.21979Z M11.3774 2.01103C11.7856 2.0814 12.0595 2.46936 11.9891 2.87755L9.48909 17.3776C9.41872 17.7857 9.03076 18.0596 8.62257 17.9892C8.21438 17.9188 7.94053 17.5309 8.0109 17.1227L10.5109 2.62269C10.5813 2.2145 10.9692 1.94065 11.3774 2.01103Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cog-6-tooth" shape.
+#[cfg(any(feature = "cog-6-tooth", heroicon = "cog-6-tooth"))]
+pub static COG_6_TOOTH: super::StaticShape = super::StaticShape::new(
+    "cog-6-tooth",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.83922 1.80388C7.9327 1.33646 8.34312 1 8.8198 1H11.1802C11.6569 1 12.0673 1.33646 12.1608 1.80388L12.4913 3.45629C13.1956 3.72458 13.8454 4.10332 14.4196 4.57133L16.0179 4.03065C16.4694 3.8779 16.966 4.06509 17.2043 4.47791L18.3845 6.52207C18.6229 6.93489 18.5367 7.45855 18.1786 7.77322L16.9119 8.88645C16.9699 9.24909 17 9.62103 17 10C17 10.379 16.9699 10.7509 16.9119 11.1135L18.1786 12.2268C18.5367 12.5414 18.6229 13.0651 18.3845 13.4779L17.2043 15.5221C16.966 15.9349 16.4694 16.1221 16.0179 15.9693L14.4196 15.4287C13.8454 15.8967 13.1956 16.2754 12.4913 16.5437L12.1608 18.1961C12.0673 18.6635 11.6569 19 11.1802 19H8.8198C8.34312 19 7.9327 18.6635 7.83922 18.1961L7.50874 16.5437C6.80442 16.2754 6.1546 15.8967 5.58042 15.4287L3.98213 15.9694C3.53059 16.1221 3.034 15.9349 2.79566 15.5221L1.61546 13.4779C1.37712 13.0651 1.4633 12.5415 1.82136 12.2268L3.08808 11.1135C3.03011 10.7509 2.99999 10.379 2.99999 10C2.99999 9.62103 3.03011 9.2491 3.08808 8.88647L1.82136 7.77324C1.4633 7.45857 1.37712 6.93491 1.61546 6.52209L2.79566 4.47793C3.034 4.06511 3.53059 3.87791 3.98213 4.03066L5.58041 4.57134C6.15459 4.10332 6.80442 3.72459 7.50874 3.45629L7.83922 1.80388ZM9.99999 13C11.6568 13 13 11.6569 13 10C13 8.34315 11.6568 7 9.99999 7C8.34314 7 6.99999 8.34315 6.99999 10C6.99999 11.6569 8.34314 13 9.99999 13Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cog-8-tooth" shape.
+#[cfg(any(feature = "cog-8-tooth", heroicon = "cog-8-tooth"))]
+pub static COG_8_TOOTH: super::StaticShape = super::StaticShape::new(
+    "cog-8-tooth",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.33922 1.80388C8.43271 1.33646 8.84312 1 9.3198 1H10.6802C11.1569 1 11.5673 1.33646 11.6608 1.80388L11.9553 3.27675C12.4522 3.42101 12.9263 3.61886 13.3709 3.86363L14.6212 3.03014C15.0178 2.76572 15.5459 2.81802 15.883 3.15508L16.8449 4.11702C17.182 4.45409 17.2343 4.98221 16.9699 5.37883L16.1364 6.62908C16.3811 7.07369 16.579 7.54778 16.7232 8.04465L18.1961 8.33922C18.6635 8.43271 19 8.84312 19 9.3198V10.6802C19 11.1569 18.6635 11.5673 18.1961 11.6608L16.7232 11.9553C16.579 12.4522 16.3812 12.9262 16.1364 13.3708L16.97 14.6212C17.2344 15.0178 17.1821 15.5459 16.845 15.883L15.8831 16.8449C15.546 17.182 15.0179 17.2343 14.6213 16.9699L13.371 16.1363C12.9264 16.3811 12.4522 16.579 11.9554 16.7232L11.6608 18.1961C11.5673 18.6635 11.1569 19 10.6802 19H9.3198C8.84312 19 8.43271 18.6635 8.33922 18.1961L8.04465 16.7232C7.54778 16.579 7.0737 16.3811 6.62908 16.1364L5.37882 16.9699C4.9822 17.2343 4.45408 17.182 4.11701 16.8449L3.15507 15.883C2.81801 15.5459 2.76571 15.0178 3.03013 14.6212L3.86363 13.3709C3.61886 12.9263 3.42101 12.4522 3.27675 11.9554L1.80388 11.6608C1.33646 11.5673 1 11.1569 1 10.6802L1 9.3198C1 8.84312 1.33646 8.43271 1.80388 8.33922L3.27675 8.04465C3.42102 7.54774 3.61889 7.07363 3.86368 6.62898L3.03024 5.37882C2.76582 4.9822 2.81812 4.45408 3.15518 4.11701L4.11712 3.15507C4.45419 2.81801 4.98231 2.76571 5.37893 3.03013L6.62913 3.86359C7.07373 3.61884 7.5478 3.42101 8.04465 3.27675L8.33922 1.80388ZM13 10C13 11.6569 11.6569 13 10 13C8.34315 13 7 11.6569 7 10C7 8.34315 8.34315 7 10 7C11.6569 7 13 8.34315 13 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cog" shape.
+#[cfg(any(feature = "cog", heroicon = "cog"))]
+pub static COG: super::StaticShape = super::StaticShape::new(
+    "cog",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.0242 9.24999C13.4944 9.24999 13.8513 8.81719 13.6614 8.38695C13.0412 6.9812 11.6352 6 10 6C9.85376 6 9.70936 6.00785 9.56719 6.02314C9.09929 6.07349 8.90249 6.59904 9.13779 7.00659L10.2165 8.87499C10.3505 9.10704 10.5981 9.24999 10.866 9.24999L13.0242 9.24999Z M7.83948 7.75785C7.60411 7.35018 7.05027 7.25778 6.77194 7.63742C6.28661 8.29942 6 9.11624 6 10C6 10.8838 6.28662 11.7006 6.77198 12.3626C7.05031 12.7423 7.60415 12.6499 7.83952 12.2422L8.91751 10.3751C9.05149 10.143 9.05149 9.85711 8.91751 9.62506L7.83948 7.75785Z M9.13785 12.9934C8.90255 13.401 9.09936 13.9265 9.56726 13.9769C9.70941 13.9922 9.85379 14 10 14C11.6352 14 13.0412 13.0188 13.6614 11.613C13.8513 11.1828 13.4944 10.75 13.0242 10.75H10.8661C10.5982 10.75 10.3506 10.8929 10.2166 11.125L9.13785 12.9934Z M14.1295 4.34724L14.7744 3.23028C14.9815 2.87156 14.8586 2.41286 14.4999 2.20576C14.1412 1.99865 13.6825 2.12156 13.4754 2.48028L12.8311 3.59615C12.1832 3.30927 11.4835 3.11784 10.75 3.03971V1.75C10.75 1.33579 10.4142 1 10 1C9.58579 1 9.25 1.33579 9.25 1.75V3.03971C8.51649 3.11784 7.81683 3.30927 7.16886 3.59616L6.52462 2.4803C6.31752 2.12158 5.85882 1.99867 5.5001 2.20578C5.14139 2.41289 5.01848 2.87158 5.22559 3.2303L5.87046 4.34725C5.28784 4.7736 4.77359 5.28784 4.34725 5.87046L3.23009 5.22547C2.87137 5.01836 2.41267 5.14127 2.20557 5.49999C1.99846 5.85871 2.12137 6.3174 2.48009 6.52451L3.59615 7.16887C3.30927 7.81683 3.11784 8.51649 3.03971 9.25H1.75C1.33579 9.25 1 9.58579 1 10C1 10.4142 1.33579 10.75 1.75 10.75H3.03971C3.11784 11.4835 3.30926 12.1832 3.59614 12.8311L2.48009 13.4755C2.12137 13.6826 1.99846 14.1413 2.20557 14.5C2.41267 14.8587 2.87137 14.9816 3.23009 14.7745L4.34723 14.1295C4.77355 14.7121 5.28775 15.2263 5.87031 15.6526L5.22538 16.7697C5.01827 17.1284 5.14118 17.5871 5.4999 17.7942C5.85861 18.0013 6.31731 17.8784 6.52441 17.5197L7.1687 16.4038C7.81671 16.6907 8.51643 16.8822 9.25 16.9603V18.25C9.25 18.6642 9.58579 19 10 19C10.4142 19 10.75 18.6642 10.75 18.25V16.9603C11.4836 16.8822 12.1833 16.6907 12.8313 16.4038L13.4756 17.5197C13.6827 17.8784 14.1414 18.0013 14.5001 17.7942C14.8588 17.5871 14.9817 17.1284 14.7746 16.7697L14.1297 15.6526C14.7122 15.2263 15.2264 14.7121 15.6527 14.1296L16.7698 14.7745C17.1285 14.9816 17.5872 14.8587 17.7943 14.5C18.0014 14.1413 17.8785 13.6826 17.5198 13.4755L16.4038 12.8312C16.6907 12.1832 16.8822 11.4835 16.9603 10.75H18.25C18.6642 10.75 19 10.4142 19 10C19 9.58579 18.6642 9.25 18.25 9.25H16.9603C16.8822 8.51646 16.6907 7.81678 16.4038 7.16879L17.5198 6.52451C17.8785 6.3174 18.0014 5.85871 17.7943 5.49999C17.5872 5.14127 17.1285 5.01836 16.7698 5.22547L15.6527 5.8704C15.2264 5.2878 14.7121 4.77358 14.1295 4.34724ZM10 4.5C9.0112 4.5 8.08334 4.76094 7.28153 5.2177C7.27126 5.22431 7.26079 5.2307 7.2501 5.23687C7.23978 5.24283 7.22937 5.24852 7.21889 5.25393C6.40668 5.7309 5.72776 6.4104 5.25148 7.22307C5.24674 7.2321 5.2418 7.24107 5.23666 7.24999C5.2313 7.25926 5.22578 7.26837 5.2201 7.27733C4.76185 8.0801 4.5 9.00947 4.5 10C4.5 10.9904 4.76179 11.9197 5.21995 12.7224C5.22569 12.7314 5.23126 12.7406 5.23666 12.75C5.24185 12.759 5.24683 12.768 5.25161 12.7772C5.72819 13.5903 6.40765 14.27 7.2205 14.747C7.23036 14.7521 7.24017 14.7575 7.2499 14.7631C7.26 14.769 7.26992 14.775 7.27965 14.7812C8.08189 15.2387 9.01042 15.5 10 15.5C10.9897 15.5 11.9184 15.2386 12.7207 14.781C12.7303 14.7749 12.7401 14.7689 12.7501 14.7632C12.7597 14.7576 12.7694 14.7523 12.7792 14.7472C13.5913 14.2707 14.2704 13.5918 14.7469 12.7797C14.7521 12.7697 14.7575 12.7598 14.7632 12.75C14.7691 12.7398 14.7751 12.7298 14.7814 12.72C15.2387 11.9179 15.5 10.9894 15.5 10C15.5 9.01046 15.2387 8.08196 14.7813 7.27974C14.7751 7.27001 14.769 7.26009 14.7632 7.24999C14.7576 7.24025 14.7522 7.23044 14.7471 7.22057C14.2708 6.40891 13.5923 5.73024 12.7808 5.25375C12.7704 5.24838 12.7601 5.24275 12.7499 5.23685C12.7393 5.23074 12.7289 5.22441 12.7188 5.21788C11.9169 4.761 10.9889 4.5 10 4.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "command-line" shape.
+#[cfg(any(feature = "command-line", heroicon = "command-line"))]
+pub static COMMAND_LINE: super::StaticShape = super::StaticShape::new(
+    "command-line",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.25 3C2.00736 3 1 4.00736 1 5.25V14.75C1 15.9926 2.00736 17 3.25 17H16.75C17.9926 17 19 15.9926 19 14.75V5.25C19 4.00736 17.9926 3 16.75 3H3.25ZM4.19253 11.7517C3.91544 11.4438 3.94039 10.9696 4.24828 10.6925L6.12886 9L4.24828 7.30747C3.94039 7.03038 3.91543 6.55616 4.19253 6.24828C4.46962 5.94039 4.94384 5.91544 5.25172 6.19253L7.75172 8.44253C7.90976 8.58476 8 8.78738 8 9C8 9.21261 7.90976 9.41524 7.75172 9.55747L5.25172 11.8075C4.94384 12.0846 4.46962 12.0596 4.19253 11.7517ZM9.75 10.25C9.33579 10.25 9 10.5858 9 11C9 11.4142 9.33579 11.75 9.75 11.75H12.25C12.6642 11.75 13 11.4142 13 11C13 10.5858 12.6642 10.25 12.25 10.25H9.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "computer-desktop" shape.
+#[cfg(any(feature = "computer-desktop", heroicon = "computer-desktop"))]
+pub static COMPUTER_DESKTOP: super::StaticShape = super::StaticShape::new(
+    "computer-desktop",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.25C2 3.00736 3.00736 2 4.25 2H15.75C16.9926 2 18 3.00736 18 4.25V12.75C18 13.9926 16.9926 15 15.75 15H12.6448C12.8417 15.6619 13.2292 16.2418 13.7449 16.6767C13.9856 16.8798 14.0738 17.2116 13.9657 17.5074C13.8576 17.8032 13.5762 18 13.2613 18H6.73881C6.42387 18 6.14248 17.8032 6.03437 17.5074C5.92627 17.2116 6.01449 16.8798 6.25522 16.6767C6.77086 16.2418 7.15838 15.6619 7.35525 15H4.25C3.00736 15 2 13.9926 2 12.75V4.25ZM3.5 4.25C3.5 3.83579 3.83579 3.5 4.25 3.5H15.75C16.1642 3.5 16.5 3.83579 16.5 4.25V11.75C16.5 12.1642 16.1642 12.5 15.75 12.5H4.25C3.83579 12.5 3.5 12.1642 3.5 11.75V4.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cpu-chip" shape.
+#[cfg(any(feature = "cpu-chip", heroicon = "cpu-chip"))]
+pub static CPU_CHIP: super::StaticShape = super::StaticShape::new(
+    "cpu-chip",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14 6H6V14H14V6Z M9.25 3V1.75C9.25 1.33579 9.58579 1 10 1C10.4142 1 10.75 1.33579 10.75 1.75V3H12.25V1.75C12.25 1.33579 12.5858 1 13 1C13.4142 1 13.75 1.33579 13.75 1.75V3H14.25C15.7688 3 17 4.23122 17 5.75V6.25H18.25C18.6642 6.25 19 6.58579 19 7C19 7.41421 18.6642 7.75 18.25 7.75H17V9.25H18.25C18.6642 9.25 19 9.58579 19 10C19 10.4142 18.6642 10.75 18.25 10.75H17V12.25H18.25C18.6642 12.25 19 12.5858 19 13C19 13.4142 18.6642 13.75 18.25 13.75H17V14.25C17 15.7688 15.7688 17 14.25 17H13.75V18.25C13.75 18.6642 13.4142 19 13 19C12.5858 19 12.25 18.6642 12.25 18.25V17H10.75V18.25C10.75 18.6642 10.4142 19 10 19C9.58579 19 9.25 18.6642 9.25 18.25V17H7.75V18.25C7.75 18.6642 7.41421 19 7 19C6.58579 19 6.25 18.6642 6.25 18.25V17H5.75C4.23122 17 3 15.7688 3 14.25V13.75H1.75C1.33579 13.75 1 13.4142 1 13C1 12.5858 1.33579 12.25 1.75 12.25H3V10.75H1.75C1.33579 10.75 1 10.4142 1 10C1 9.58579 1.33579 9.25 1.75 9.25H3V7.75H1.75C1.33579 7.75 1 7.41421 1 7C1 6.58579 1.33579 6.25 1.75 6.25H3V5.75C3 4.23122 4.23122 3 5.75 3H6.25V1.75C6.25 1.33579 6.58579 1 7 1C7.41421 1 7.75 1.33579 7.75 1.75V3H9.25ZM4.5 5.75C4.5 5.05964 5.05964 4.5 5.75 4.5H14.25C14.9404 4.5 15.5 5.05964 15.5 5.75V14.25C15.5 14.9404 14.9404 15.5 14.25 15.5H5.75C5.05964 15.5 4.5 14.9404 4.5 14.25V5.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "credit-card" shape.
+#[cfg(any(feature = "credit-card", heroicon = "credit-card"))]
+pub static CREDIT_CARD: super::StaticShape = super::StaticShape::new(
+    "credit-card",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.5 4C1.67157 4 1 4.67157 1 5.5V6H19V5.5C19 4.67157 18.3284 4 17.5 4H2.5ZM19 8.5H1V14.5C1 15.3284 1.67157 16 2.5 16H17.5C18.3284 16 19 15.3284 19 14.5V8.5ZM3 13.25C3 12.8358 3.33579 12.5 3.75 12.5H5.25C5.66421 12.5 6 12.8358 6 13.25C6 13.6642 5.66421 14 5.25 14H3.75C3.33579 14 3 13.6642 3 13.25ZM7.75 12.5C7.33579 12.5 7 12.8358 7 13.25C7 13.6642 7.33579 14 7.75 14H11.25C11.6642 14 12 13.6642 12 13.25C12 12.8358 11.6642 12.5 11.25 12.5H7.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cube-transparent" shape.
+#[cfg(any(feature = "cube-transparent", heroicon = "cube-transparent"))]
+pub static CUBE_TRANSPARENT: super::StaticShape = super::StaticShape::new(
+    "cube-transparent",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.63769 1.09332C9.86321 0.968894 10.1368 0.968894 10.3623 1.09332L12.3623 2.19676C12.725 2.39686 12.8568 2.85308 12.6567 3.21576C12.4566 3.57843 12.0004 3.71023 11.6377 3.51013L10 2.60658L8.36231 3.51013C7.99963 3.71023 7.54341 3.57843 7.34332 3.21576C7.14322 2.85308 7.27502 2.39686 7.63769 2.19676L9.63769 1.09332ZM5.40258 4.28651C5.60268 4.64918 5.47088 5.1054 5.10821 5.3055L4.30255 5.75L5.10821 6.1945C5.47088 6.3946 5.60268 6.85082 5.40258 7.21349C5.20248 7.57617 4.74627 7.70797 4.38359 7.50787L3.5 7.02037V7.75C3.5 8.16421 3.16421 8.5 2.75 8.5C2.33579 8.5 2 8.16421 2 7.75V5.75C2 5.47684 2.14852 5.22528 2.38769 5.09332L4.38359 3.99213C4.74627 3.79203 5.20248 3.92383 5.40258 4.28651ZM14.5974 4.28651C14.7975 3.92383 15.2537 3.79203 15.6164 3.99213L17.6123 5.09332C17.8515 5.22528 18 5.47684 18 5.75V7.75C18 8.16421 17.6642 8.5 17.25 8.5C16.8358 8.5 16.5 8.16421 16.5 7.75V7.02037L15.6164 7.50787C15.2537 7.70797 14.7975 7.57617 14.5974 7.21349C14.3973 6.85082 14.5291 6.3946 14.8918 6.1945L15.6975 5.75L14.8918 5.3055C14.5291 5.1054 14.3973 4.64918 14.5974 4.28651ZM7.34332 8.28424C7.54341 7.92157 7.99963 7.78977 8.36231 7.98987L10 8.89342L11.6377 7.98987C12.0004 7.78977 12.4566 7.92157 12.6567 8.28424C12.8568 8.64692 12.725 9.10314 12.3623 9.30324L10.75 10.1928V11.75C10.75 12.1642 10.4142 12.5 10 12.5C9.58579 12.5 9.25 12.1642 9.25 11.75V10.1928L7.63769 9.30324C7.27502 9.10314 7.14322 8.64692 7.34332 8.28424ZM2.75 11.5C3.16421 11.5 3.5 11.8358 3.5 12.25V13.8072L5.10821 14.6945C5.47088 14.8946 5.60268 15.3508 5.40258 15.7135C5.20248 16.0762 4.74627 16.208 4.38359 16.0079L2.38769 14.9067C2.14852 14.7747 2 14.5232 2 14.25V12.25C2 11.8358 2.33579 11.5 2.75 11.5ZM17.25 11.5C17.6642 11.5 18 11.8358 18 12.25V14.25C18 14.5232 17.8515 14.7747 17.6123 14.9067L15.6164 16.0079C15.2537 16.208 14.7975 16.0762 14.5974 15.7135C14.3973 15.3508 14.5291 14.8946 14.8918 14.6945L16.5 13.8072V12.25C16.5 11.8358 16.8358 11.5 17.25 11.5ZM10 15.5C10.4142 15.5 10.75 15.8358 10.75 16.25V16.9796L11.6377 16.4899C12.0004 16.2898 12.4566 16.4216 12.6567 16.7842C12.8568 17.1469 12.725 17.6031 12.3623 17.8032L10.3623 18.9067C10.1368 19.0311 9.86321 19.0311 9.63769 18.9067L7.63769 17.8032C7.27501 17.6031 7.14322 17.1469 7.34332 16.7842C7.54341 16.4216 7.99963 16.2898 8.36231 16.4899L9.25 16.9796V16.25C9.25 15.8358 9.58579 15.5 10 15.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cube" shape.
+#[cfg(any(feature = "cube", heroicon = "cube"))]
+pub static CUBE: super::StaticShape = super::StaticShape::new(
+    "cube",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.3623 1.09332C10.1368 0.968894 9.86321 0.968894 9.63769 1.09332L2.52344 5.01842L10 9.14342L17.4766 5.01842L10.3623 1.09332ZM18 6.44278L10.75 10.4428V18.6928L17.6123 14.9067C17.8515 14.7747 18 14.5232 18 14.25V6.44278ZM9.25 18.6928V10.4428L2 6.44278V14.25C2 14.5232 2.14852 14.7747 2.38769 14.9067L9.25 18.6928Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-bangladeshi" shape.
+#[cfg(any(feature = "currency-bangladeshi", heroicon = "currency-bangladeshi"))]
+pub static CURRENCY_BANGLADESHI: super::StaticShape = super::StaticShape::new(
+    "currency-bangladeshi",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2ZM5.93934 5.49996C6.88428 4.55502 8.5 5.22426 8.5 6.56062V8H14.25C14.6642 8 15 8.33579 15 8.75C15 9.16421 14.6642 9.5 14.25 9.5H8.5V13.775C8.5 14.0711 8.64363 14.23 8.75926 14.2739C9.1439 14.4198 9.56172 14.5 10 14.5C11.3936 14.5 12.5986 13.6853 13.1616 12.5037H12.75C12.3358 12.5037 12 12.1679 12 11.7537C12 11.3395 12.3358 11.0037 12.75 11.0037H13.2872C13.7494 11.0037 14.1744 11.2149 14.4434 11.5599C14.7206 11.9155 14.8262 12.4125 14.6272 12.8974C13.8806 14.7166 12.091 16 10 16C9.37719 16 8.77933 15.8857 8.22739 15.6764C7.37603 15.3536 7 14.513 7 13.775V9.5H5.75C5.33579 9.5 5 9.16421 5 8.75C5 8.33579 5.33579 8 5.75 8H7V6.56062L6.78033 6.78029C6.48744 7.07319 6.01256 7.07319 5.71967 6.78029C5.42678 6.4874 5.42678 6.01253 5.71967 5.71963L5.93934 5.49996Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-dollar" shape.
+#[cfg(any(feature = "currency-dollar", heroicon = "currency-dollar"))]
+pub static CURRENCY_DOLLAR: super::StaticShape = super::StaticShape::new(
+    "currency-dollar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.7499 10.8176V13.4324C11.1816 13.3527 11.5745 13.2046 11.8876 12.9999C12.3698 12.6846 12.4999 12.352 12.4999 12.125C12.4999 11.898 12.3698 11.5654 11.8876 11.2501C11.5745 11.0454 11.1816 10.8973 10.7499 10.8176Z M8.32961 8.61947C8.38337 8.67543 8.44464 8.73053 8.51404 8.78416C8.72197 8.94484 8.97355 9.06777 9.25 9.1469V6.60315C9.17545 6.62449 9.10271 6.64901 9.03215 6.6766C8.98721 6.69417 8.94315 6.71299 8.90007 6.73302C8.75996 6.79816 8.63019 6.87614 8.51404 6.96589C8.13658 7.25757 8 7.59253 8 7.87503C8 8.05887 8.05784 8.26493 8.20228 8.46683C8.23898 8.51812 8.28126 8.56915 8.32961 8.61947Z M18 10C18 14.4183 14.4183 18 10 18C5.58172 18 2 14.4183 2 10C2 5.58172 5.58172 2 10 2C14.4183 2 18 5.58172 18 10ZM9.99994 4C10.4142 4 10.7499 4.33579 10.7499 4.75V5.06584C11.3423 5.17106 11.9182 5.40427 12.4031 5.77893C12.8293 6.10829 13.1467 6.51836 13.3282 6.97896C13.4801 7.36432 13.2908 7.79985 12.9055 7.95174C12.5201 8.10363 12.0846 7.91437 11.9327 7.52901C11.8599 7.34437 11.72 7.14675 11.4859 6.96586C11.278 6.80519 11.0264 6.68225 10.7499 6.60312V9.29944C11.448 9.39233 12.1327 9.61819 12.7085 9.99467C13.4955 10.5093 13.9999 11.2644 13.9999 12.125C13.9999 12.9856 13.4955 13.7407 12.7085 14.2553C12.1327 14.6318 11.448 14.8577 10.7499 14.9506V15.25C10.7499 15.6642 10.4142 16 9.99994 16C9.58573 16 9.24994 15.6642 9.24994 15.25V14.9506C8.55186 14.8577 7.8672 14.6318 7.29141 14.2553C6.80887 13.9398 6.4337 13.5376 6.21337 13.0672C6.0377 12.692 6.19937 12.2455 6.57449 12.0699C6.9496 11.8942 7.39611 12.0559 7.57178 12.431C7.65258 12.6035 7.81692 12.8067 8.11229 12.9999C8.42537 13.2046 8.8183 13.3526 9.24994 13.4324V10.6842C8.65762 10.5789 8.08167 10.3457 7.59681 9.97107C6.90033 9.43288 6.49994 8.68017 6.49994 7.875C6.49994 7.06983 6.90034 6.31712 7.59681 5.77893C8.08167 5.40427 8.65762 5.17106 9.24994 5.06584V4.75C9.24994 4.33579 9.58573 4 9.99994 4Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-euro" shape.
+#[cfg(any(feature = "currency-euro", heroicon = "currency-euro"))]
+pub static CURRENCY_EURO: super::StaticShape = super::StaticShape::new(
+    "currency-euro",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM8.79767 7.45038C9.31032 6.78 9.93284 6.5 10.5 6.5C11.0672 6.5 11.6897 6.78 12.2023 7.45038C12.4539 7.77942 12.9247 7.84218 13.2537 7.59056C13.5827 7.33895 13.6455 6.86824 13.3939 6.53921C12.6373 5.54986 11.5963 5 10.5 5C9.40366 5 8.36269 5.54986 7.60613 6.53921C7.27168 6.97657 7.01308 7.47233 6.83031 8H5.75C5.33579 8 5 8.33579 5 8.75C5 9.16421 5.33579 9.5 5.75 9.5H6.51977C6.49341 9.83243 6.49341 10.1676 6.51977 10.5H5.75C5.33579 10.5 5 10.8358 5 11.25C5 11.6642 5.33579 12 5.75 12H6.83031C7.01308 12.5277 7.27168 13.0234 7.60613 13.4608C8.36269 14.4501 9.40366 15 10.5 15C11.5963 15 12.6373 14.4501 13.3939 13.4608C13.6455 13.1318 13.5827 12.661 13.2537 12.4094C12.9247 12.1578 12.4539 12.2206 12.2023 12.5496C11.6897 13.22 11.0672 13.5 10.5 13.5C9.93284 13.5 9.31032 13.22 8.79767 12.5496C8.66735 12.3792 8.55299 12.1948 8.4546 12H10.25C10.6642 12 11 11.6642 11 11.25C11 10.8358 10.6642 10.5 10.25 10.5H8.02592C7.99136 10.1685 7.99136 9.83152 8.02592 9.5H10.25C10.6642 9.5 11 9.16421 11 8.75C11 8.33579 10.6642 8 10.25 8H8.4546C8.55299 7.80515 8.66735 7.62081 8.79767 7.45038Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-pound" shape.
+#[cfg(any(feature = "currency-pound", heroicon = "currency-pound"))]
+pub static CURRENCY_POUND: super::StaticShape = super::StaticShape::new(
+    "currency-pound",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM8.73223 6.23223C9.70854 5.25592 11.2915 5.25592 12.2678 6.23223C12.5607 6.52513 13.0355 6.52513 13.3284 6.23223C13.6213 5.93934 13.6213 5.46447 13.3284 5.17157C11.7663 3.60948 9.23367 3.60948 7.67157 5.17157C6.89067 5.95247 6.5 6.97747 6.5 8V8.16481C6.5 8.52945 6.53382 8.89272 6.60072 9.25H6.25C5.83579 9.25 5.5 9.58579 5.5 10C5.5 10.4142 5.83579 10.75 6.25 10.75H6.98724C7.175 11.782 7.05016 12.853 6.61952 13.8219L6.56464 13.9454C6.44963 14.2042 6.49018 14.5057 6.66948 14.7249C6.84878 14.9441 7.13625 15.0436 7.4127 14.9821L8.68531 14.6993C9.21441 14.5818 9.76361 14.589 10.2894 14.7204C11.0842 14.9191 11.9158 14.9191 12.7106 14.7204L13.6819 14.4776C14.0837 14.3771 14.3281 13.9699 14.2276 13.5681C14.1271 13.1663 13.7199 12.9219 13.3181 13.0224L12.3468 13.2652C11.7908 13.4042 11.2092 13.4042 10.6532 13.2652C9.91296 13.0802 9.14026 13.0673 8.39452 13.2275C8.58441 12.4159 8.62237 11.5757 8.5063 10.75H9.75C10.1642 10.75 10.5 10.4142 10.5 10C10.5 9.58579 10.1642 9.25 9.75 9.25H8.13603C8.0458 8.89574 8 8.53121 8 8.16481V8C8 7.35903 8.24393 6.72054 8.73223 6.23223Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-rupee" shape.
+#[cfg(any(feature = "currency-rupee", heroicon = "currency-rupee"))]
+pub static CURRENCY_RUPEE: super::StaticShape = super::StaticShape::new(
+    "currency-rupee",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM6.00002 5.75C6.00002 5.33579 6.33581 5 6.75002 5H13.25C13.6642 5 14 5.33579 14 5.75C14 6.16421 13.6642 6.5 13.25 6.5H11.1227C11.5229 6.99922 11.8059 7.59647 11.9298 8.25H13.25C13.6642 8.25 14 8.58579 14 9C14 9.41421 13.6642 9.75 13.25 9.75H11.9298C11.6108 11.4316 10.2393 12.7405 8.52636 12.9657L10.2804 14.7197C10.5732 15.0126 10.5732 15.4874 10.2804 15.7803C9.98746 16.0732 9.51259 16.0732 9.21969 15.7803L6.21969 12.7803C6.00519 12.5658 5.94103 12.2432 6.05711 11.963C6.1732 11.6827 6.44668 11.5 6.75002 11.5H8.00002C9.11943 11.5 10.067 10.7643 10.3856 9.75H6.75C6.33579 9.75 6 9.41421 6 9C6 8.58579 6.33579 8.25 6.75 8.25H10.3856C10.067 7.23572 9.11943 6.5 8.00002 6.5H6.75002C6.33581 6.5 6.00002 6.16421 6.00002 5.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-yen" shape.
+#[cfg(any(feature = "currency-yen", heroicon = "currency-yen"))]
+pub static CURRENCY_YEN: super::StaticShape = super::StaticShape::new(
+    "currency-yen",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM7.34581 5.29439C7.09419 4.96536 6.62349 4.9026 6.29445 5.15421C5.96542 5.40582 5.90266 5.87653 6.15427 6.20557L9.05588 9.99998H6.75004C6.33583 9.99998 6.00004 10.3358 6.00004 10.75C6.00004 11.1642 6.33583 11.5 6.75004 11.5H9.25004V12.5H6.75004C6.33583 12.5 6.00004 12.8358 6.00004 13.25C6.00004 13.6642 6.33583 14 6.75004 14H9.25004V15.25C9.25004 15.6642 9.58583 16 10 16C10.4143 16 10.75 15.6642 10.75 15.25V14H13.25C13.6643 14 14 13.6642 14 13.25C14 12.8358 13.6643 12.5 13.25 12.5H10.75V11.5H13.25C13.6643 11.5 14 11.1642 14 10.75C14 10.3358 13.6643 9.99998 13.25 9.99998H10.9442L13.8458 6.20557C14.0974 5.87653 14.0347 5.40582 13.7056 5.15421C13.3766 4.9026 12.9059 4.96536 12.6543 5.29439L10 8.76531L7.34581 5.29439Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cursor-arrow-rays" shape.
+#[cfg(any(feature = "cursor-arrow-rays", heroicon = "cursor-arrow-rays"))]
+pub static CURSOR_ARROW_RAYS: super::StaticShape = super::StaticShape::new(
+    "cursor-arrow-rays",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 1C10.4142 1 10.75 1.33579 10.75 1.75V3.25C10.75 3.66421 10.4142 4 10 4C9.58579 4 9.25 3.66421 9.25 3.25V1.75C9.25 1.33579 9.58579 1 10 1ZM5.05025 3.05025C5.34315 2.75736 5.81802 2.75736 6.11091 3.05025L7.17157 4.11091C7.46447 4.40381 7.46447 4.87868 7.17157 5.17157C6.87868 5.46447 6.40381 5.46447 6.11091 5.17157L5.05025 4.11091C4.75736 3.81802 4.75736 3.34315 5.05025 3.05025ZM14.9497 3.05025C15.2426 3.34315 15.2426 3.81802 14.9497 4.11091L13.8891 5.17157C13.5962 5.46447 13.1213 5.46447 12.8284 5.17157C12.5355 4.87868 12.5355 4.40381 12.8284 4.11091L13.8891 3.05025C14.182 2.75736 14.6569 2.75736 14.9497 3.05025ZM3 8C3 7.58579 3.33579 7.25 3.75 7.25H5.25C5.66421 7.25 6 7.58579 6 8C6 8.41421 5.66421 8.75 5.25 8.75H3.75C3.33579 8.75 3 8.41421 3 8ZM14 8C14 7.58579 14.3358 7.25 14.75 7.25H16.25C16.6642 7.25 17 7.58579 17 8C17 8.41421 16.6642 8.75 16.25 8.75H14.75C14.3358 8.75 14 8.41421 14 8ZM7.17157 10.8284C7.46447 11.1213 7.46447 11.5962 7.17157 11.8891L6.11091 12.9497C5.81802 13.2426 5.34315 13.2426 5.05025 12.9497C4.75736 12.6569 4.75736 12.182 5.05025 11.8891L6.11091 10.8284C6.40381 10.5355 6.87868 10.5355 7.17157 10.8284ZM10.7657 7.51062C10.5871 7.24492 10.2596 7.12184 9.95029 7.20417C9.64094 7.2865 9.41795 7.5561 9.3951 7.8754L8.90409 14.7363C8.88303 15.0306 9.0365 15.3099 9.29622 15.4499C9.55594 15.59 9.87365 15.5647 10.108 15.3854L11.1508 14.5873L12.1363 18.2653C12.2435 18.6654 12.6548 18.9028 13.0549 18.7956C13.455 18.6884 13.6924 18.2771 13.5852 17.877L12.6083 14.2312L13.9005 14.4349C14.1951 14.4814 14.4893 14.3489 14.6497 14.0974C14.81 13.846 14.8062 13.5233 14.6398 13.2758L10.7657 7.51062Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cursor-arrow-ripple" shape.
+#[cfg(any(feature = "cursor-arrow-ripple", heroicon = "cursor-arrow-ripple"))]
+pub static CURSOR_ARROW_RIPPLE: super::StaticShape = super::StaticShape::new(
+    "cursor-arrow-ripple",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.11097 11.8891C3.96309 9.7412 3.96309 6.2588 6.11097 4.11091C8.25886 1.96303 11.7413 1.96303 13.8891 4.11091C14.9632 5.185 15.5001 6.59127 15.5001 8C15.5001 8.41421 15.8358 8.75 16.2501 8.75C16.6643 8.75 17.0001 8.41421 17.0001 8C17.0001 6.2097 16.3165 4.41694 14.9498 3.05025C12.2161 0.316583 7.78398 0.316582 5.05031 3.05025C2.31664 5.78392 2.31664 10.2161 5.05031 12.9497C5.34321 13.2426 5.81808 13.2426 6.11097 12.9497C6.40387 12.6569 6.40387 12.182 6.11097 11.8891ZM8.23233 6.23223C7.25602 7.20854 7.25602 8.79146 8.23233 9.76777C8.52522 10.0607 8.52522 10.5355 8.23233 10.8284C7.93944 11.1213 7.46456 11.1213 7.17167 10.8284C5.60957 9.26633 5.60957 6.73367 7.17167 5.17157C8.73377 3.60948 11.2664 3.60948 12.8285 5.17157C13.6094 5.95247 14.0001 6.97747 14.0001 8C14.0001 8.41421 13.6643 8.75 13.2501 8.75C12.8359 8.75 12.5001 8.41421 12.5001 8C12.5001 7.35903 12.2562 6.72054 11.7679 6.23223C10.7916 5.25592 9.20864 5.25592 8.23233 6.23223ZM10.7657 7.51062C10.5871 7.24492 10.2596 7.12184 9.95027 7.20417C9.64092 7.2865 9.41793 7.5561 9.39508 7.8754L8.90407 14.7363C8.88301 15.0306 9.03648 15.3099 9.2962 15.4499C9.55592 15.59 9.87363 15.5647 10.108 15.3854L11.1508 14.5873L12.1363 18.2653C12.2435 18.6654 12.6548 18.9028 13.0549 18.7956C13.455 18.6884 13.6924 18.2771 13.5852 17.877L12.6083 14.2312L13.9005 14.4349C14.1951 14.4814 14.4892 14.3489 14.6496 14.0974C14.81 13.846 14.8062 13.5233 14.6398 13.2758L10.7657 7.51062Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
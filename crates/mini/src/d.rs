@@ -0,0 +1,167 @@
+//! The shapes whose names start with "d".
+/// The "device-phone-mobile" shape.
+#[cfg(any(feature = "device-phone-mobile", heroicon = "device-phone-mobile"))]
+pub static DEVICE_PHONE_MOBILE: super::StaticShape = super::StaticShape::new(
+    "device-phone-mobile",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8 16.25C8 15.8358 8.33579 15.5 8.75 15.5H11.25C11.6642 15.5 12 15.8358 12 16.25C12 16.6642 11.6642 17 11.25 17H8.75C8.33579 17 8 16.6642 8 16.25Z M4 4C4 2.34315 5.34315 1 7 1H13C14.6569 1 16 2.34315 16 4V16C16 17.6569 14.6569 19 13 19H7C5.34315 19 4 17.6569 4 16V4ZM8 2.5V3.25C8 3.66421 8.33579 4 8.75 4H11.25C11.6642 4 12 3.66421 12 3.25V2.5H13C13.8284 2.5 14.5 3.17157 14.5 4V16C14.5 16.8284 13.8284 17.5 13 17.5H7C6.17157 17.5 5.5 16.8284 5.5 16V4C5.5 3.17157 6.17157 2.5 7 2.5H8Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "device-tablet" shape.
+#[cfg(any(feature = "device-tablet", heroicon = "device-tablet"))]
+pub static DEVICE_TABLET: super::StaticShape = super::StaticShape::new(
+    "device-tablet",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5 1C3.34315 1 2 2.34315 2 4V16C2 17.6569 3.34315 19 5 19H15C16.6569 19 18 17.6569 18 16V4C18 2.34315 16.6569 1 15 1H5ZM3.5 4C3.5 3.17157 4.17157 2.5 5 2.5H15C15.8284 2.5 16.5 3.17157 16.5 4V16C16.5 16.8284 15.8284 17.5 15 17.5H5C4.17157 17.5 3.5 16.8284 3.5 16V4ZM8.75 15.5C8.33579 15.5 8 15.8358 8 16.25C8 16.6642 8.33579 17 8.75 17H11.25C11.6642 17 12 16.6642 12 16.25C12 15.8358 11.6642 15.5 11.25 15.5H8.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-arrow-down" shape.
+#[cfg(any(feature = "document-arrow-down", heroicon = "document-arrow-down"))]
+pub static DOCUMENT_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "document-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 2C3.67157 2 3 2.67157 3 3.5V16.5C3 17.3284 3.67157 18 4.5 18H15.5C16.3284 18 17 17.3284 17 16.5V7.62132C17 7.2235 16.842 6.84197 16.5607 6.56066L12.4393 2.43934C12.158 2.15804 11.7765 2 11.3787 2H4.5ZM9.25 8.75C9.25 8.33579 9.58579 8 10 8C10.4142 8 10.75 8.33579 10.75 8.75V11.2955L11.6925 10.2483C11.9696 9.94039 12.4438 9.91543 12.7517 10.1925C13.0596 10.4696 13.0846 10.9438 12.8075 11.2517L10.5575 13.7517C10.4152 13.9098 10.2126 14 10 14C9.78739 14 9.58476 13.9098 9.44253 13.7517L7.19253 11.2517C6.91544 10.9438 6.94039 10.4696 7.24828 10.1925C7.55616 9.91544 8.03038 9.94039 8.30747 10.2483L9.25 11.2955V8.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-arrow-up" shape.
+#[cfg(any(feature = "document-arrow-up", heroicon = "document-arrow-up"))]
+pub static DOCUMENT_ARROW_UP: super::StaticShape = super::StaticShape::new(
+    "document-arrow-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 2C3.67157 2 3 2.67157 3 3.5V16.5C3 17.3284 3.67157 18 4.5 18H15.5C16.3284 18 17 17.3284 17 16.5V7.62132C17 7.2235 16.842 6.84197 16.5607 6.56066L12.4393 2.43934C12.158 2.15804 11.7765 2 11.3787 2H4.5ZM9.25 13.25C9.25 13.6642 9.58579 14 10 14C10.4142 14 10.75 13.6642 10.75 13.25V10.7045L11.6925 11.7517C11.9696 12.0596 12.4438 12.0846 12.7517 11.8075C13.0596 11.5304 13.0846 11.0562 12.8075 10.7483L10.5575 8.24828C10.4152 8.09024 10.2126 8 10 8C9.78739 8 9.58476 8.09024 9.44253 8.24828L7.19253 10.7483C6.91544 11.0562 6.94039 11.5304 7.24828 11.8075C7.55616 12.0846 8.03038 12.0596 8.30747 11.7517L9.25 10.7045V13.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-chart-bar" shape.
+#[cfg(any(feature = "document-chart-bar", heroicon = "document-chart-bar"))]
+pub static DOCUMENT_CHART_BAR: super::StaticShape = super::StaticShape::new(
+    "document-chart-bar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 3.5C3 2.67157 3.67157 2 4.5 2H11.3787C11.7765 2 12.158 2.15804 12.4393 2.43934L16.5607 6.56066C16.842 6.84197 17 7.2235 17 7.62132V16.5C17 17.3284 16.3284 18 15.5 18H4.5C3.67157 18 3 17.3284 3 16.5V3.5ZM13.25 9C13.6642 9 14 9.33579 14 9.75V14.25C14 14.6642 13.6642 15 13.25 15C12.8358 15 12.5 14.6642 12.5 14.25V9.75C12.5 9.33579 12.8358 9 13.25 9ZM6.75 13C7.16421 13 7.5 13.3358 7.5 13.75V14.25C7.5 14.6642 7.16421 15 6.75 15C6.33579 15 6 14.6642 6 14.25V13.75C6 13.3358 6.33579 13 6.75 13ZM10.75 11.75C10.75 11.3358 10.4142 11 10 11C9.58579 11 9.25 11.3358 9.25 11.75V14.25C9.25 14.6642 9.58579 15 10 15C10.4142 15 10.75 14.6642 10.75 14.25V11.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-check" shape.
+#[cfg(any(feature = "document-check", heroicon = "document-check"))]
+pub static DOCUMENT_CHECK: super::StaticShape = super::StaticShape::new(
+    "document-check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 3.5C3 2.67157 3.67157 2 4.5 2H11.3787C11.7765 2 12.158 2.15804 12.4393 2.43934L16.5607 6.56066C16.842 6.84197 17 7.2235 17 7.62132V16.5C17 17.3284 16.3284 18 15.5 18H4.5C3.67157 18 3 17.3284 3 16.5V3.5ZM13.8566 9.19113C14.1002 8.85614 14.0261 8.38708 13.6911 8.14345C13.3561 7.89982 12.8871 7.97388 12.6434 8.30887L9.15969 13.099L7.28033 11.2197C6.98744 10.9268 6.51256 10.9268 6.21967 11.2197C5.92678 11.5126 5.92678 11.9874 6.21967 12.2803L8.71967 14.7803C8.87477 14.9354 9.08999 15.0149 9.30867 14.9977C9.52734 14.9805 9.72754 14.8685 9.85655 14.6911L13.8566 9.19113Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-duplicate" shape.
+#[cfg(any(feature = "document-duplicate", heroicon = "document-duplicate"))]
+pub static DOCUMENT_DUPLICATE: super::StaticShape = super::StaticShape::new(
+    "document-duplicate",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7 3.5C7 2.67157 7.67157 2 8.5 2H12.3787C12.7765 2 13.158 2.15804 13.4393 2.43934L16.5607 5.56066C16.842 5.84197 17 6.2235 17 6.62132V12.5C17 13.3284 16.3284 14 15.5 14H14.5V10.6213C14.5 9.82567 14.1839 9.06261 13.6213 8.5L10.5 5.37868C9.93739 4.81607 9.17433 4.5 8.37868 4.5H7V3.5Z M4.5 6C3.67157 6 3 6.67157 3 7.5V16.5C3 17.3284 3.67157 18 4.5 18H11.5C12.3284 18 13 17.3284 13 16.5V10.6213C13 10.2235 12.842 9.84197 12.5607 9.56066L9.43934 6.43934C9.15804 6.15804 8.7765 6 8.37868 6H4.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-magnifying-glass" shape.
+#[cfg(
+    any(feature = "document-magnifying-glass", heroicon = "document-magnifying-glass")
+)]
+pub static DOCUMENT_MAGNIFYING_GLASS: super::StaticShape = super::StaticShape::new(
+    "document-magnifying-glass",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8 10C8 9.17157 8.67157 8.5 9.5 8.5C10.3284 8.5 11 9.17157 11 10C11 10.4144 10.8329 10.7884 10.5607 11.0607C10.2884 11.3329 9.91442 11.5 9.5 11.5C8.67157 11.5 8 10.8284 8 10Z M4.5 2C3.67157 2 3 2.67157 3 3.5V16.5C3 17.3284 3.67157 18 4.5 18H15.5C16.3284 18 17 17.3284 17 16.5V7.62132C17 7.2235 16.842 6.84197 16.5607 6.56066L12.4393 2.43934C12.158 2.15804 11.7765 2 11.3787 2H4.5ZM9.5 7C7.84315 7 6.5 8.34315 6.5 10C6.5 11.6569 7.84315 13 9.5 13C10.056 13 10.5773 12.8483 11.0239 12.5845L12.2197 13.7803C12.5126 14.0732 12.9874 14.0732 13.2803 13.7803C13.5732 13.4874 13.5732 13.0126 13.2803 12.7197L12.0845 11.5239C12.3483 11.0773 12.5 10.556 12.5 10C12.5 8.34315 11.1569 7 9.5 7Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-minus" shape.
+#[cfg(any(feature = "document-minus", heroicon = "document-minus"))]
+pub static DOCUMENT_MINUS: super::StaticShape = super::StaticShape::new(
+    "document-minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 2C3.67157 2 3 2.67157 3 3.5V16.5C3 17.3284 3.67157 18 4.5 18H15.5C16.3284 18 17 17.3284 17 16.5V7.62132C17 7.2235 16.842 6.84197 16.5607 6.56066L12.4393 2.43934C12.158 2.15804 11.7765 2 11.3787 2H4.5ZM12.25 11.75C12.6642 11.75 13 11.4142 13 11C13 10.5858 12.6642 10.25 12.25 10.25H7.75C7.33579 10.25 7 10.5858 7 11C7 11.4142 7.33579 11.75 7.75 11.75H12.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-plus" shape.
+#[cfg(any(feature = "document-plus", heroicon = "document-plus"))]
+pub static DOCUMENT_PLUS: super::StaticShape = super::StaticShape::new(
+    "document-plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 2C3.67157 2 3 2.67157 3 3.5V16.5C3 17.3284 3.67157 18 4.5 18H15.5C16.3284 18 17 17.3284 17 16.5V7.62132C17 7.2235 16.842 6.84197 16.5607 6.56066L12.4393 2.43934C12.158 2.15804 11.7765 2 11.3787 2H4.5ZM10 8C10.4142 8 10.75 8.33579 10.75 8.75V10.25H12.25C12.6642 10.25 13 10.5858 13 11C13 11.4142 12.6642 11.75 12.25 11.75H10.75V13.25C10.75 13.6642 10.4142 14 10 14C9.58579 14 9.25 13.6642 9.25 13.25V11.75H7.75C7.33579 11.75 7 11.4142 7 11C7 10.5858 7.33579 10.25 7.75 10.25H9.25V8.75C9.25 8.33579 9.58579 8 10 8Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-text" shape.
+#[cfg(any(feature = "document-text", heroicon = "document-text"))]
+pub static DOCUMENT_TEXT: super::StaticShape = super::StaticShape::new(
+    "document-text",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 2C3.67157 2 3 2.67157 3 3.5V16.5C3 17.3284 3.67157 18 4.5 18H15.5C16.3284 18 17 17.3284 17 16.5V7.62132C17 7.2235 16.842 6.84197 16.5607 6.56066L12.4393 2.43934C12.158 2.15804 11.7765 2 11.3787 2H4.5ZM6.75 10.5C6.33579 10.5 6 10.8358 6 11.25C6 11.6642 6.33579 12 6.75 12H13.25C13.6642 12 14 11.6642 14 11.25C14 10.8358 13.6642 10.5 13.25 10.5H6.75ZM6.75 13.5C6.33579 13.5 6 13.8358 6 14.25C6 14.6642 6.33579 15 6.75 15H13.25C13.6642 15 14 14.6642 14 14.25C14 13.8358 13.6642 13.5 13.25 13.5H6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document" shape.
+#[cfg(any(feature = "document", heroicon = "document"))]
+pub static DOCUMENT: super::StaticShape = super::StaticShape::new(
+    "document",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 3.5C3 2.67157 3.67157 2 4.5 2H11.3787C11.7765 2 12.158 2.15804 12.4393 2.43934L16.5607 6.56066C16.842 6.84197 17 7.2235 17 7.62132V16.5C17 17.3284 16.3284 18 15.5 18H4.5C3.67157 18 3 17.3284 3 16.5V3.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,137 @@
+//! The shapes whose names start with "e".
+/// The "ellipsis-horizontal-circle" shape.
+#[cfg(
+    any(feature = "ellipsis-horizontal-circle", heroicon = "ellipsis-horizontal-circle")
+)]
+pub static ELLIPSIS_HORIZONTAL_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "ellipsis-horizontal-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 10C2 5.58172 5.58172 2 10 2C14.4183 2 18 5.58172 18 10C18 14.4183 14.4183 18 10 18C5.58172 18 2 14.4183 2 10ZM10 11C10.5523 11 11 10.5523 11 10C11 9.44772 10.5523 9 10 9C9.44772 9 9 9.44772 9 10C9 10.5523 9.44772 11 10 11ZM7 10C7 10.5523 6.55228 11 6 11C5.44772 11 5 10.5523 5 10C5 9.44772 5.44772 9 6 9C6.55228 9 7 9.44772 7 10ZM14 11C14.5523 11 15 10.5523 15 10C15 9.44772 14.5523 9 14 9C13.4477 9 13 9.44772 13 10C13 10.5523 13.4477 11 14 11Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "ellipsis-horizontal" shape.
+#[cfg(any(feature = "ellipsis-horizontal", heroicon = "ellipsis-horizontal"))]
+pub static ELLIPSIS_HORIZONTAL: super::StaticShape = super::StaticShape::new(
+    "ellipsis-horizontal",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 10C3 9.17157 3.67157 8.5 4.5 8.5C5.32843 8.5 6 9.17157 6 10C6 10.8284 5.32843 11.5 4.5 11.5C3.67157 11.5 3 10.8284 3 10Z M8.5 10C8.5 9.17157 9.17157 8.5 10 8.5C10.8284 8.5 11.5 9.17157 11.5 10C11.5 10.8284 10.8284 11.5 10 11.5C9.17157 11.5 8.5 10.8284 8.5 10Z M15.5 8.5C14.6716 8.5 14 9.17157 14 10C14 10.8284 14.6716 11.5 15.5 11.5C16.3284 11.5 17 10.8284 17 10C17 9.17157 16.3284 8.5 15.5 8.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "ellipsis-vertical" shape.
+#[cfg(any(feature = "ellipsis-vertical", heroicon = "ellipsis-vertical"))]
+pub static ELLIPSIS_VERTICAL: super::StaticShape = super::StaticShape::new(
+    "ellipsis-vertical",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 3C10.8284 3 11.5 3.67157 11.5 4.5C11.5 5.32843 10.8284 6 10 6C9.17157 6 8.5 5.32843 8.5 4.5C8.5 3.67157 9.17157 3 10 3Z M10 8.5C10.8284 8.5 11.5 9.17157 11.5 10C11.5 10.8284 10.8284 11.5 10 11.5C9.17157 11.5 8.5 10.8284 8.5 10C8.5 9.17157 9.17157 8.5 10 8.5Z M11.5 15.5C11.5 14.6716 10.8284 14 10 14C9.17157 14 8.5 14.6716 8.5 15.5C8.5 16.3284 9.17157 17 10 17C10.8284 17 11.5 16.3284 11.5 15.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "envelope-open" shape.
+#[cfg(any(feature = "envelope-open", heroicon = "envelope-open"))]
+pub static ENVELOPE_OPEN: super::StaticShape = super::StaticShape::new(
+    "envelope-open",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.10557 6.44747C1.428 6.78626 1 7.47878 1 8.23633V16.0003C1 17.1048 1.89543 18.0003 3 18.0003H17C18.1046 18.0003 19 17.1048 19 16.0003V8.23633C19 7.47878 18.572 6.78626 17.8944 6.44747L10.8944 2.94747C10.3314 2.66595 9.66863 2.66595 9.10557 2.94747L2.10557 6.44747ZM3.58541 10.4544C3.21493 10.2692 2.76442 10.4194 2.57918 10.7899C2.39394 11.1603 2.54411 11.6108 2.91459 11.7961L8.77016 14.7239C9.54436 15.111 10.4556 15.111 11.2298 14.7239L17.0823 11.7976C17.4528 11.6124 17.6029 11.1619 17.4177 10.7914C17.2325 10.4209 16.7819 10.2708 16.4115 10.456L10.559 13.3822C10.2071 13.5582 9.79289 13.5582 9.44098 13.3822L3.58541 10.4544Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "envelope" shape.
+#[cfg(any(feature = "envelope", heroicon = "envelope"))]
+pub static ENVELOPE: super::StaticShape = super::StaticShape::new(
+    "envelope",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 4C1.89543 4 1 4.89543 1 6V7.16147L9.44098 11.382C9.79289 11.5579 10.2071 11.5579 10.559 11.382L19 7.16147V6C19 4.89543 18.1046 4 17 4H3Z M19 8.83853L11.2298 12.7236C10.4556 13.1107 9.54436 13.1107 8.77016 12.7236L1 8.83853V14C1 15.1046 1.89543 16 3 16H17C18.1046 16 19 15.1046 19 14V8.83853Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "exclamation-circle" shape.
+#[cfg(any(feature = "exclamation-circle", heroicon = "exclamation-circle"))]
+pub static EXCLAMATION_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "exclamation-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18 10C18 14.4183 14.4183 18 10 18C5.58172 18 2 14.4183 2 10C2 5.58172 5.58172 2 10 2C14.4183 2 18 5.58172 18 10ZM10 5C10.4142 5 10.75 5.33579 10.75 5.75V10.25C10.75 10.6642 10.4142 11 10 11C9.58579 11 9.25 10.6642 9.25 10.25V5.75C9.25 5.33579 9.58579 5 10 5ZM10 15C10.5523 15 11 14.5523 11 14C11 13.4477 10.5523 13 10 13C9.44771 13 9 13.4477 9 14C9 14.5523 9.44771 15 10 15Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "exclamation-triangle" shape.
+#[cfg(any(feature = "exclamation-triangle", heroicon = "exclamation-triangle"))]
+pub static EXCLAMATION_TRIANGLE: super::StaticShape = super::StaticShape::new(
+    "exclamation-triangle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.4845 2.49499C9.15808 1.32833 10.842 1.32833 11.5156 2.495L17.7943 13.37C18.4678 14.5367 17.6259 15.995 16.2787 15.995H3.72136C2.37421 15.995 1.53224 14.5367 2.20582 13.37L8.4845 2.49499ZM10 5C10.4142 5 10.75 5.33579 10.75 5.75V9.25C10.75 9.66421 10.4142 10 10 10C9.58579 10 9.25 9.66421 9.25 9.25L9.25 5.75C9.25 5.33579 9.58579 5 10 5ZM10 14C10.5523 14 11 13.5523 11 13C11 12.4477 10.5523 12 10 12C9.44772 12 9 12.4477 9 13C9 13.5523 9.44772 14 10 14Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "eye-dropper" shape.
+#[cfg(any(feature = "eye-dropper", heroicon = "eye-dropper"))]
+pub static EYE_DROPPER: super::StaticShape = super::StaticShape::new(
+    "eye-dropper",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.0998 3.66668C12.4737 2.136 13.8542 1 15.5 1C15.5146 0.999999 15.5292 1.00009 15.5438 1.00027C15.9323 1.00503 16.3056 1.07311 16.6538 1.19465C17.1364 1.3626 17.5896 1.6393 17.9754 2.02503C18.892 2.94167 19.193 4.23984 18.8818 5.40523C18.7282 5.98079 18.4253 6.52479 17.9754 6.97477C17.6708 7.27935 17.3231 7.51652 16.9518 7.68564C16.7625 7.77206 16.5641 7.8421 16.3585 7.89394L16 7.99V9.99998C16 10.1989 15.921 10.3897 15.7803 10.5303L15.5303 10.7803C15.2374 11.0732 14.7626 11.0732 14.4697 10.7803L13.625 9.93564L7.21967 16.341C6.79771 16.7629 6.22541 17 5.62868 17H5.12132C4.92241 17 4.73164 17.079 4.59099 17.2196L3.03033 18.7803C2.73744 19.0732 2.26256 19.0732 1.96967 18.7803L1.21967 18.0303C0.926777 17.7374 0.926777 17.2625 1.21967 16.9696L2.78033 15.409C2.92098 15.2683 3 15.0776 3 14.8787V14.3713C3 13.7746 3.23705 13.2023 3.65901 12.7803L10.0643 6.37498L9.21967 5.53031C8.92678 5.23741 8.92678 4.76254 9.21967 4.46965L9.46967 4.21965C9.61032 4.07899 9.80109 3.99998 10 3.99998H12.0105L12.0998 3.66668ZM4.71967 13.841L11.125 7.43564L12.5643 8.87498L6.15901 15.2803C6.01836 15.421 5.82759 15.5 5.62868 15.5H5.12132C4.86336 15.5 4.60997 15.5443 4.37132 15.6287C4.4557 15.39 4.5 15.1366 4.5 14.8787V14.3713C4.5 14.1724 4.57902 13.9816 4.71967 13.841Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "eye-slash" shape.
+#[cfg(any(feature = "eye-slash", heroicon = "eye-slash"))]
+pub static EYE_SLASH: super::StaticShape = super::StaticShape::new(
+    "eye-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.28033 2.21967C2.98744 1.92678 2.51256 1.92678 2.21967 2.21967C1.92678 2.51256 1.92678 2.98744 2.21967 3.28033L16.7197 17.7803C17.0126 18.0732 17.4874 18.0732 17.7803 17.7803C18.0732 17.4874 18.0732 17.0126 17.7803 16.7197L16.0352 14.9745C17.5064 13.8594 18.6595 12.3465 19.3344 10.5959C19.4814 10.2144 19.4816 9.79127 19.3347 9.40962C17.892 5.66051 14.256 3 9.99859 3C8.28207 3 6.66657 3.43249 5.2551 4.19444L3.28033 2.21967ZM7.75194 6.69128L8.84367 7.78301C9.18951 7.60223 9.58291 7.5 10.0002 7.5C11.3809 7.5 12.5002 8.61929 12.5002 10C12.5002 10.4173 12.398 10.8107 12.2172 11.1565L13.3091 12.2484C13.7454 11.6077 14.0004 10.8336 14.0004 10C14.0004 7.79086 12.2095 6 10.0004 6C9.16675 6 8.39268 6.25501 7.75194 6.69128Z M10.7484 13.9302L13.2711 16.4529C12.2462 16.8074 11.1458 17 10.0004 17C5.74298 17 2.10698 14.3395 0.664255 10.5904C0.517392 10.2087 0.517518 9.78563 0.66461 9.40408C1.15603 8.12932 1.90108 6.98057 2.83791 6.01969L6.0702 9.25198C6.02436 9.4943 6.00037 9.74435 6.00037 10C6.00037 12.2091 7.79123 14 10.0004 14C10.256 14 10.5061 13.976 10.7484 13.9302Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "eye" shape.
+#[cfg(any(feature = "eye", heroicon = "eye"))]
+pub static EYE: super::StaticShape = super::StaticShape::new(
+    "eye",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 12.5C11.3807 12.5 12.5 11.3807 12.5 10C12.5 8.61929 11.3807 7.5 10 7.5C8.61929 7.5 7.5 8.61929 7.5 10C7.5 11.3807 8.61929 12.5 10 12.5Z M0.664255 10.5904C0.517392 10.2087 0.517518 9.78563 0.66461 9.40408C2.10878 5.65788 5.7433 3 9.99859 3C14.256 3 17.892 5.66051 19.3347 9.40962C19.4816 9.79127 19.4814 10.2144 19.3344 10.5959C17.8902 14.3421 14.2557 17 10.0004 17C5.74298 17 2.10698 14.3395 0.664255 10.5904ZM14.0004 10C14.0004 12.2091 12.2095 14 10.0004 14C7.79123 14 6.00037 12.2091 6.00037 10C6.00037 7.79086 7.79123 6 10.0004 6C12.2095 6 14.0004 7.79086 14.0004 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,175 @@
+//! The shapes whose names start with "f".
+/// The "face-frown" shape.
+#[cfg(any(feature = "face-frown", heroicon = "face-frown"))]
+pub static FACE_FROWN: super::StaticShape = super::StaticShape::new(
+    "face-frown",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM6.46447 14.5251C6.75736 14.818 7.23223 14.818 7.52513 14.5251C8.89196 13.1583 11.108 13.1583 12.4749 14.5251C12.7678 14.818 13.2426 14.818 13.5355 14.5251C13.8284 14.2323 13.8284 13.7574 13.5355 13.4645C11.5829 11.5119 8.41709 11.5119 6.46447 13.4645C6.17157 13.7574 6.17157 14.2323 6.46447 14.5251ZM9 8.5C9 9.32843 8.55228 10 8 10C7.44772 10 7 9.32843 7 8.5C7 7.67157 7.44772 7 8 7C8.55228 7 9 7.67157 9 8.5ZM12 10C12.5523 10 13 9.32843 13 8.5C13 7.67157 12.5523 7 12 7C11.4477 7 11 7.67157 11 8.5C11 9.32843 11.4477 10 12 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "face-smile" shape.
+#[cfg(any(feature = "face-smile", heroicon = "face-smile"))]
+pub static FACE_SMILE: super::StaticShape = super::StaticShape::new(
+    "face-smile",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM13.5355 13.5355C13.8284 13.2426 13.8284 12.7678 13.5355 12.4749C13.2426 12.182 12.7678 12.182 12.4749 12.4749C11.108 13.8417 8.89196 13.8417 7.52513 12.4749C7.23223 12.182 6.75736 12.182 6.46447 12.4749C6.17157 12.7678 6.17157 13.2426 6.46447 13.5355C8.41709 15.4881 11.5829 15.4881 13.5355 13.5355ZM9 8.5C9 9.32843 8.55228 10 8 10C7.44772 10 7 9.32843 7 8.5C7 7.67157 7.44772 7 8 7C8.55228 7 9 7.67157 9 8.5ZM12 10C12.5523 10 13 9.32843 13 8.5C13 7.67157 12.5523 7 12 7C11.4477 7 11 7.67157 11 8.5C11 9.32843 11.4477 10 12 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "film" shape.
+#[cfg(any(feature = "film", heroicon = "film"))]
+pub static FILM: super::StaticShape = super::StaticShape::new(
+    "film",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 4.75C1 3.7835 1.7835 3 2.75 3H17.25C18.2165 3 19 3.7835 19 4.75V15.265C19 16.2315 18.2165 17.015 17.25 17.015H15.75C15.6719 17.015 15.5951 17.0099 15.5197 17H4.48027C4.40492 17.0099 4.32806 17.015 4.25 17.015H2.75C1.7835 17.015 1 16.2315 1 15.265V4.75ZM17.5 12.135V11.01C17.5 10.8719 17.3881 10.76 17.25 10.76H15.75C15.6119 10.76 15.5 10.8719 15.5 11.01V12.135C15.5 12.2731 15.6119 12.385 15.75 12.385H17.25C17.3881 12.385 17.5 12.2731 17.5 12.135ZM17.5 14.14C17.5 14.0019 17.3881 13.89 17.25 13.89H15.75C15.6119 13.89 15.5 14.0019 15.5 14.14V15.265C15.5 15.373 15.5685 15.4651 15.6645 15.5H17.25C17.3881 15.5 17.5 15.3881 17.5 15.25V14.14ZM2.5 15.25V14.14C2.5 14.0019 2.61193 13.89 2.75 13.89H4.25C4.38807 13.89 4.5 14.0019 4.5 14.14V15.265C4.5 15.373 4.43148 15.4651 4.33553 15.5H2.75C2.61193 15.5 2.5 15.3881 2.5 15.25ZM4.5 11.01V12.135C4.5 12.2731 4.38807 12.385 4.25 12.385H2.75C2.61193 12.385 2.5 12.2731 2.5 12.135V11.01C2.5 10.8719 2.61193 10.76 2.75 10.76H4.25C4.38807 10.76 4.5 10.8719 4.5 11.01ZM17.5 9.005V7.88C17.5 7.74193 17.3881 7.63 17.25 7.63H15.75C15.6119 7.63 15.5 7.74193 15.5 7.88V9.005C15.5 9.14308 15.6119 9.255 15.75 9.255H17.25C17.3881 9.255 17.5 9.14308 17.5 9.005ZM4.25 7.63C4.38807 7.63 4.5 7.74193 4.5 7.88V9.005C4.5 9.14308 4.38807 9.255 4.25 9.255H2.75C2.61193 9.255 2.5 9.14308 2.5 9.005V7.88C2.5 7.74193 2.61193 7.63 2.75 7.63H4.25ZM4.25 4.5C4.38807 4.5 4.5 4.61193 4.5 4.75V5.875C4.5 6.01307 4.38807 6.125 4.25 6.125H2.75C2.61193 6.125 2.5 6.01307 2.5 5.875V4.75C2.5 4.61193 2.61193 4.5 2.75 4.5H4.25ZM15.75 6.125C15.6119 6.125 15.5 6.01307 15.5 5.875V4.75C15.5 4.61193 15.6119 4.5 15.75 4.5H17.25C17.3881 4.5 17.5 4.61193 17.5 4.75V5.875C17.5 6.01307 17.3881 6.125 17.25 6.125H15.75ZM6.75 9.25C6.33579 9.25 6 9.58579 6 10C6 10.4142 6.33579 10.75 6.75 10.75H13.25C13.6642 10.75 14 10.4142 14 10C14 9.58579 13.6642 9.25 13.25 9.25H6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "finger-print" shape.
+#[cfg(any(feature = "finger-print", heroicon = "finger-print"))]
+pub static FINGER_PRINT: super::StaticShape = super::StaticShape::new(
+    "finger-print",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.99999 2.5C8.69048 2.5 7.47351 2.88645 6.45417 3.55132C6.10723 3.77761 5.64254 3.6798 5.41625 3.33287C5.18996 2.98593 5.28777 2.52123 5.6347 2.29494C6.89037 1.47594 8.39074 1 9.99999 1C14.4183 1 18 4.58172 18 9C18 11.573 17.5678 14.0467 16.7714 16.3514C16.6361 16.7429 16.2091 16.9506 15.8176 16.8153C15.4261 16.6801 15.2184 16.253 15.3537 15.8615C16.0963 13.7123 16.5 11.4041 16.5 9C16.5 5.41015 13.5898 2.5 9.99999 2.5ZM4.33285 4.41627C4.67979 4.64256 4.77759 5.10725 4.5513 5.45419C3.88644 6.47353 3.49999 7.69049 3.49999 9C3.49999 10.6078 3.02489 12.107 2.20721 13.362C1.98109 13.709 1.51645 13.807 1.1694 13.5809C0.82235 13.3548 0.724315 12.8902 0.950433 12.5431C1.61422 11.5243 1.99999 10.3084 1.99999 9C1.99999 7.39075 2.47592 5.89038 3.29493 4.63472C3.52122 4.28778 3.98591 4.18998 4.33285 4.41627ZM9.99999 6.12C8.34313 6.12 6.99999 7.46314 6.99999 9.12C6.99999 9.13378 6.99961 9.14748 6.99888 9.16108C6.96101 11.9169 5.95296 14.4401 4.30164 16.4018C4.03489 16.7187 3.56176 16.7593 3.24487 16.4926C2.92798 16.2258 2.88733 15.7527 3.15408 15.4358C4.61842 13.6962 5.49999 11.452 5.49999 9C5.49999 8.97218 5.5015 8.94471 5.50445 8.91767C5.61027 6.52628 7.58251 4.62 9.99999 4.62C12.4174 4.62 14.3896 6.52613 14.4955 8.91754C14.4985 8.94516 14.5 8.97323 14.5 9.00166L14.4998 9.0815L14.5 9.11633C14.5 9.12755 14.4998 9.13872 14.4994 9.14983C14.4986 9.24922 14.4971 9.34844 14.4948 9.44749C14.4852 9.86159 14.1418 10.1895 13.7277 10.1799C13.3136 10.1704 12.9856 9.8269 12.9952 9.4128C12.9977 9.30372 12.9993 9.19439 12.9998 9.08481C12.981 7.44426 11.6451 6.12 9.99999 6.12ZM9.99999 8.25C10.4142 8.25 10.75 8.58579 10.75 9C10.75 12.5103 9.56303 15.7455 7.569 18.3232C7.31556 18.6509 6.84451 18.711 6.51688 18.4576C6.18925 18.2041 6.12912 17.7331 6.38256 17.4054C8.18049 15.0812 9.24999 12.1666 9.24999 9C9.24999 8.58579 9.58577 8.25 9.99999 8.25ZM13.5288 11.9478C13.9346 12.0309 14.1962 12.4273 14.113 12.8331C13.6842 14.9261 12.9112 16.8937 11.8562 18.6738C11.645 19.0302 11.1849 19.1478 10.8286 18.9366C10.4723 18.7254 10.3546 18.2654 10.5658 17.9091C11.5374 16.2697 12.2488 14.4585 12.6436 12.532C12.7267 12.1262 13.123 11.8646 13.5288 11.9478Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "fire" shape.
+#[cfg(any(feature = "fire", heroicon = "fire"))]
+pub static FIRE: super::StaticShape = super::StaticShape::new(
+    "fire",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.4997 4.93762C16.8478 6.87062 17.9949 11.1518 16.0619 14.4998C14.1289 17.8479 9.84775 18.995 6.4997 17.062C3.15166 15.129 2.00453 10.8479 3.93753 7.4998C4.10592 7.20813 4.29214 6.93316 4.49401 6.67548C4.69562 6.41812 5.08463 6.45704 5.28714 6.71368C5.56487 7.06565 5.88119 7.38577 6.22971 7.66764C6.56235 7.93667 7.01647 7.61943 7.00304 7.19183C7.00103 7.12812 7.00003 7.06416 7.00003 6.99997C7.00003 6.08143 7.20643 5.2111 7.57539 4.43282C8.10854 3.30822 8.98111 2.37583 10.0608 1.76798C10.3078 1.62893 10.6112 1.7522 10.7378 2.00584C11.3297 3.1927 12.2651 4.2248 13.4997 4.93762ZM14 12C14 14.2091 12.2092 16 10 16C8.08674 16 6.4791 14.6016 6.09017 12.8183C5.9966 12.3894 6.52967 12.1749 6.90396 12.4045C7.38998 12.7025 7.93731 12.8964 8.50538 12.9685C8.80801 13.0068 9.03609 12.7289 9.01482 12.4246C9.00501 12.2844 9.00002 12.1428 9.00002 12C9.00002 10.5731 9.49812 9.26254 10.3299 8.23269C10.4337 8.10417 10.599 8.04108 10.7612 8.07233C12.6063 8.4278 14 10.0511 14 12Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "flag" shape.
+#[cfg(any(feature = "flag", heroicon = "flag"))]
+pub static FLAG: super::StaticShape = super::StaticShape::new(
+    "flag",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.5 2.75C3.5 2.33579 3.16421 2 2.75 2C2.33579 2 2 2.33579 2 2.75V17.25C2 17.6642 2.33579 18 2.75 18C3.16421 18 3.5 17.6642 3.5 17.25V12.8585L5.15742 12.5096C6.60458 12.2049 8.11248 12.407 9.42842 13.0818C11.2723 14.0274 13.4125 14.2155 15.393 13.6061L17.4706 12.9668C17.7853 12.87 18 12.5793 18 12.25V3.75C18 3.52398 17.8981 3.31001 17.7226 3.1676C17.547 3.02519 17.3167 2.96952 17.0955 3.01609L14.7157 3.51709C13.3151 3.81195 11.8585 3.68564 10.5296 3.15406L10.0275 2.95322C8.42359 2.31166 6.66566 2.15921 4.97524 2.51509L3.5 2.82566V2.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder-arrow-down" shape.
+#[cfg(any(feature = "folder-arrow-down", heroicon = "folder-arrow-down"))]
+pub static FOLDER_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "folder-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.75C2 3.7835 2.7835 3 3.75 3H8.58579C9.04992 3 9.49503 3.18437 9.82322 3.51256L11.2374 4.92678C11.2843 4.97366 11.3479 5 11.4142 5H16.25C17.2165 5 18 5.7835 18 6.75V15.25C18 16.2165 17.2165 17 16.25 17H3.75C2.7835 17 2 16.2165 2 15.25V4.75ZM10.75 8.75C10.75 8.33579 10.4142 8 10 8C9.58579 8 9.25 8.33579 9.25 8.75V11.2955L8.30747 10.2483C8.03038 9.94039 7.55616 9.91543 7.24828 10.1925C6.94039 10.4696 6.91544 10.9438 7.19253 11.2517L9.44253 13.7517C9.58476 13.9098 9.78738 14 10 14C10.2126 14 10.4152 13.9098 10.5575 13.7517L12.8075 11.2517C13.0846 10.9438 13.0596 10.4696 12.7517 10.1925C12.4438 9.91543 11.9696 9.94039 11.6925 10.2483L10.75 11.2955V8.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder-minus" shape.
+#[cfg(any(feature = "folder-minus", heroicon = "folder-minus"))]
+pub static FOLDER_MINUS: super::StaticShape = super::StaticShape::new(
+    "folder-minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.75C2 3.7835 2.7835 3 3.75 3H8.58579C9.04992 3 9.49503 3.18437 9.82322 3.51256L11.2374 4.92678C11.2843 4.97366 11.3479 5 11.4142 5H16.25C17.2165 5 18 5.7835 18 6.75V15.25C18 16.2165 17.2165 17 16.25 17H3.75C2.7835 17 2 16.2165 2 15.25V4.75ZM12.25 11.75C12.6642 11.75 13 11.4142 13 11C13 10.5858 12.6642 10.25 12.25 10.25H7.75C7.33579 10.25 7 10.5858 7 11C7 11.4142 7.33579 11.75 7.75 11.75H12.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder-open" shape.
+#[cfg(any(feature = "folder-open", heroicon = "folder-open"))]
+pub static FOLDER_OPEN: super::StaticShape = super::StaticShape::new(
+    "folder-open",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.75 3C3.7835 3 3 3.7835 3 4.75V7.50159C3.03443 7.50053 3.06902 7.5 3.10378 7.5H16.8959C16.9307 7.5 16.9654 7.50054 17 7.50161V6.75C17 5.7835 16.2165 5 15.25 5H11.4142C11.3479 5 11.2843 4.97366 11.2374 4.92678L9.82322 3.51256C9.49504 3.18437 9.04992 3 8.58579 3H4.75Z M3.10373 9C1.92632 9 1.08486 10.1393 1.43112 11.2647L2.81573 15.7647C3.04167 16.4989 3.72009 17 4.48835 17H15.5112C16.2795 17 16.9579 16.4989 17.1838 15.7647L18.5684 11.2647C18.9147 10.1393 18.0732 9 16.8958 9H3.10373Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder-plus" shape.
+#[cfg(any(feature = "folder-plus", heroicon = "folder-plus"))]
+pub static FOLDER_PLUS: super::StaticShape = super::StaticShape::new(
+    "folder-plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 3C2.7835 3 2 3.7835 2 4.75V15.25C2 16.2165 2.7835 17 3.75 17H16.25C17.2165 17 18 16.2165 18 15.25V6.75C18 5.7835 17.2165 5 16.25 5H11.4142C11.3479 5 11.2843 4.97366 11.2374 4.92678L9.82322 3.51256C9.49503 3.18437 9.04992 3 8.58579 3H3.75ZM10 8C10.4142 8 10.75 8.33579 10.75 8.75V10.25H12.25C12.6642 10.25 13 10.5858 13 11C13 11.4142 12.6642 11.75 12.25 11.75H10.75V13.25C10.75 13.6642 10.4142 14 10 14C9.58579 14 9.25 13.6642 9.25 13.25V11.75H7.75C7.33579 11.75 7 11.4142 7 11C7 10.5858 7.33579 10.25 7.75 10.25H9.25V8.75C9.25 8.33579 9.58579 8 10 8Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder" shape.
+#[cfg(any(feature = "folder", heroicon = "folder"))]
+pub static FOLDER: super::StaticShape = super::StaticShape::new(
+    "folder",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 3C2.7835 3 2 3.7835 2 4.75V8.01091C2.50515 7.6875 3.10568 7.5 3.75 7.5H16.25C16.8943 7.5 17.4949 7.6875 18 8.01091V6.75C18 5.7835 17.2165 5 16.25 5H11.4142C11.3479 5 11.2843 4.97366 11.2374 4.92678L9.82322 3.51256C9.49503 3.18437 9.04992 3 8.58579 3H3.75Z M3.75 9C2.7835 9 2 9.7835 2 10.75V15.25C2 16.2165 2.7835 17 3.75 17H16.25C17.2165 17 18 16.2165 18 15.25V10.75C18 9.7835 17.2165 9 16.25 9H3.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "forward" shape.
+#[cfg(any(feature = "forward", heroicon = "forward"))]
+pub static FORWARD: super::StaticShape = super::StaticShape::new(
+    "forward",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.28824 4.81895C2.28891 4.20172 1 4.92057 1 6.09515V13.9056C1 15.0802 2.2889 15.7991 3.28824 15.1818L9.61101 11.2766C9.76598 11.1809 9.89564 11.064 10 10.9328V13.9056C10 15.0802 11.2889 15.7991 12.2882 15.1818L18.611 11.2766C19.56 10.6904 19.56 9.31035 18.611 8.72419L12.2882 4.81895C11.2889 4.20172 10 4.92057 10 6.09515V9.06794C9.89564 8.93679 9.76598 8.81991 9.61101 8.72419L3.28824 4.81895Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "funnel" shape.
+#[cfg(any(feature = "funnel", heroicon = "funnel"))]
+pub static FUNNEL: super::StaticShape = super::StaticShape::new(
+    "funnel",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.62803 1.60102C5.02718 1.2056 7.48986 1 10 1C12.5101 1 14.9728 1.2056 17.372 1.60102C17.7342 1.66072 18 1.97389 18 2.34103V4.62868C18 5.22542 17.7629 5.79771 17.341 6.21967L12.659 10.9017C12.2371 11.3236 12 11.8959 12 12.4926V15.5291C12 16.2126 11.6893 16.859 11.1556 17.286L9.21852 18.8357C8.99339 19.0158 8.68496 19.0509 8.42511 18.926C8.16526 18.8011 8 18.5383 8 18.25V12.4926C8 11.8959 7.76295 11.3236 7.34099 10.9017L2.65901 6.21967C2.23705 5.79771 2 5.22542 2 4.62868V2.34103C2 1.97389 2.26578 1.66072 2.62803 1.60102Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,97 @@
+//! The shapes whose names start with "g".
+/// The "gif" shape.
+#[cfg(any(feature = "gif", heroicon = "gif"))]
+pub static GIF: super::StaticShape = super::StaticShape::new(
+    "gif",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 5.25C1 4.00736 2.00736 3 3.25 3H16.75C17.9926 3 19 4.00736 19 5.25V14.75C19 15.9926 17.9926 17 16.75 17H3.25C2.00736 17 1 15.9926 1 14.75V5.25ZM5.02565 8.12881C5.35663 7.65074 5.7212 7.5 6 7.5C6.2788 7.5 6.64338 7.65074 6.97435 8.12881C7.21012 8.46937 7.67733 8.55432 8.0179 8.31855C8.35846 8.08277 8.44341 7.61556 8.20763 7.275C7.65993 6.48386 6.87285 6 6 6C5.12715 6 4.34007 6.48386 3.79237 7.275C3.24966 8.0589 3 9.04757 3 10C3 10.9524 3.24966 11.9411 3.79237 12.725C4.34007 13.5161 5.12715 14 6 14C6.87285 14 7.65993 13.5161 8.20763 12.725C8.29447 12.5996 8.34099 12.4507 8.34099 12.2981V10C8.34099 9.58579 8.0052 9.25 7.59099 9.25H6.25C5.83579 9.25 5.5 9.58579 5.5 10C5.5 10.4142 5.83579 10.75 6.25 10.75H6.84099V12.0447C6.54806 12.3866 6.24178 12.5 6 12.5C5.7212 12.5 5.35663 12.3493 5.02565 11.8712C4.68968 11.3859 4.5 10.7111 4.5 10C4.5 9.28893 4.68968 8.61411 5.02565 8.12881ZM10.75 6C11.1642 6 11.5 6.33579 11.5 6.75V13.25C11.5 13.6642 11.1642 14 10.75 14C10.3358 14 10 13.6642 10 13.25V6.75C10 6.33579 10.3358 6 10.75 6ZM13.75 6H16.25C16.6642 6 17 6.33579 17 6.75C17 7.16421 16.6642 7.5 16.25 7.5H14.5V9.25L15.25 9.25C15.6642 9.25 16 9.58579 16 10C16 10.4142 15.6642 10.75 15.25 10.75L14.5 10.75V13.25C14.5 13.6642 14.1642 14 13.75 14C13.3358 14 13 13.6642 13 13.25V6.75C13 6.33579 13.3358 6 13.75 6Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "gift-top" shape.
+#[cfg(any(feature = "gift-top", heroicon = "gift-top"))]
+pub static GIFT_TOP: super::StaticShape = super::StaticShape::new(
+    "gift-top",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.25 3H3.5C2.67157 3 2 3.67157 2 4.5V9.25H5.36497C4.36193 8.17155 4.38539 6.48382 5.43534 5.43387C6.48481 4.3844 8.17149 4.36049 9.25 5.36214V3ZM2 10.75V15.5C2 16.3284 2.67157 17 3.5 17H9.25V12.1236C8.47036 13.6809 6.86003 14.75 5 14.75C4.58579 14.75 4.25 14.4142 4.25 14C4.25 13.5858 4.58579 13.25 5 13.25C6.53679 13.25 7.82445 12.1834 8.16303 10.75H2ZM10.75 17H16.5C17.3284 17 18 16.3284 18 15.5V10.75H11.837C12.1755 12.1834 13.4632 13.25 15 13.25C15.4142 13.25 15.75 13.5858 15.75 14C15.75 14.4142 15.4142 14.75 15 14.75C13.14 14.75 11.5296 13.6809 10.75 12.1236V17ZM18 9.25V4.5C18 3.67157 17.3284 3 16.5 3H10.75V5.36214C11.8285 4.36049 13.5152 4.3844 14.5647 5.43387C15.6146 6.48382 15.6381 8.17155 14.635 9.25H18ZM13.504 6.49453C13.0158 6.00638 12.2244 6.00638 11.7362 6.49453C11.3768 6.85395 11.2105 7.49384 11.1772 8.19191C11.1664 8.41998 11.1706 8.63456 11.1809 8.81764C11.364 8.82791 11.5785 8.83216 11.8066 8.82129C12.5047 8.78805 13.1446 8.62172 13.504 8.2623C13.9922 7.77414 13.9922 6.98269 13.504 6.49453ZM8.26377 6.49453C7.77561 6.00638 6.98415 6.00638 6.496 6.49453C6.00784 6.98269 6.00784 7.77414 6.496 8.2623C6.85542 8.62172 7.49531 8.78805 8.19337 8.82129C8.42145 8.83216 8.63602 8.82791 8.81911 8.81764C8.82938 8.63456 8.83362 8.41998 8.82276 8.19191C8.78952 7.49384 8.62318 6.85395 8.26377 6.49453Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "gift" shape.
+#[cfg(any(feature = "gift", heroicon = "gift"))]
+pub static GIFT: super::StaticShape = super::StaticShape::new(
+    "gift",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.0002 6C14.314 5.58217 14.5 5.0628 14.5 4.5C14.5 3.11929 13.3807 2 12 2C11.1822 2 10.4561 2.39267 10 2.99976C9.54389 2.39267 8.8178 2 8 2C6.61929 2 5.5 3.11929 5.5 4.5C5.5 5.0628 5.68597 5.58217 5.99982 6H3.25C2.55964 6 2 6.55964 2 7.25V7.75C2 8.44036 2.55964 9 3.25 9H9.25V6H10.75V9H16.75C17.4404 9 18 8.44036 18 7.75V7.25C18 6.55964 17.4404 6 16.75 6H14.0002ZM13 4.5C13 5.05228 12.5523 5.5 12 5.5H11L11 4.5C11 3.94772 11.4477 3.5 12 3.5C12.5523 3.5 13 3.94772 13 4.5ZM7 4.5C7 5.05228 7.44772 5.5 8 5.5H9V4.5C9 3.94772 8.55228 3.5 8 3.5C7.44772 3.5 7 3.94772 7 4.5Z M9.25 10.5H3V15.25C3 16.7688 4.23122 18 5.75 18H9.25V10.5Z M10.75 18V10.5H17V15.25C17 16.7688 15.7688 18 14.25 18H10.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "globe-alt" shape.
+#[cfg(any(feature = "globe-alt", heroicon = "globe-alt"))]
+pub static GLOBE_ALT: super::StaticShape = super::StaticShape::new(
+    "globe-alt",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.5546 5.41215C15.6845 4.17138 14.4711 3.18897 13.0522 2.60288C13.8305 3.9731 14.3991 5.47789 14.7147 7.07367C15.4062 6.61183 16.0263 6.05128 16.5546 5.41215Z M13.3257 7.82478C12.9801 5.69142 12.1346 3.72563 10.9132 2.05155C10.6135 2.0175 10.3088 2 10 2C9.69121 2 9.3865 2.0175 9.08682 2.05155C7.86543 3.72563 7.0199 5.69141 6.67433 7.82478C7.69581 8.25948 8.81982 8.5 10 8.5C11.1802 8.5 12.3042 8.25948 13.3257 7.82478Z M6.51418 9.37568C7.59957 9.77938 8.77402 10 10 10C11.226 10 12.4004 9.77938 13.4858 9.37568C13.4952 9.58261 13.5 9.79075 13.5 10C13.5 11.079 13.3734 12.1284 13.1343 13.1343C12.1284 13.3734 11.079 13.5 10 13.5C8.92099 13.5 7.87155 13.3734 6.86572 13.1343C6.62659 12.1284 6.5 11.079 6.5 10C6.5 9.79075 6.50476 9.58261 6.51418 9.37568Z M5.28529 7.07367C5.60086 5.47789 6.16954 3.9731 6.94776 2.60288C5.52894 3.18896 4.3155 4.17138 3.44542 5.41215C3.97374 6.05128 4.59375 6.61183 5.28529 7.07367Z M17.3336 6.79843C17.7622 7.77878 18 8.86162 18 10C18 10.3088 17.9825 10.6135 17.9484 10.9132C16.9787 11.6207 15.911 12.2021 14.7696 12.6333C14.921 11.7783 15 10.8984 15 10C15 9.5601 14.9811 9.12463 14.944 8.69435C15.8352 8.18645 16.6408 7.54546 17.3336 6.79843Z M2.66636 6.79843C3.3592 7.54546 4.16477 8.18645 5.05604 8.69435C5.01894 9.12463 5 9.5601 5 10C5 10.8984 5.07898 11.7783 5.23037 12.6333C4.08897 12.2021 3.02132 11.6207 2.05155 10.9132C2.0175 10.6135 2 10.3088 2 10C2 8.86162 2.23777 7.77878 2.66636 6.79843Z M10 15C10.8984 15 11.7783 14.921 12.6333 14.7696C12.2021 15.911 11.6207 16.9787 10.9132 17.9485C10.6135 17.9825 10.3088 18 10 18C9.69121 18 9.3865 17.9825 9.08682 17.9485C8.37929 16.9787 7.79789 15.911 7.36674 14.7696C8.22167 14.921 9.10161 15 10 15Z M14.3573 14.3573C14.0334 15.4259 13.5935 16.4441 13.0522 17.3971C15.0158 16.586 16.586 15.0158 17.3971 13.0522C16.4441 13.5935 15.4259 14.0334 14.3573 14.3573Z M6.94776 17.3971C4.98419 16.586 3.41399 15.0158 2.60288 13.0522C3.55593 13.5935 4.57408 14.0334 5.64268 14.3573C5.96656 15.4259 6.40648 16.4441 6.94776 17.3971Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "globe-americas" shape.
+#[cfg(any(feature = "globe-americas", heroicon = "globe-americas"))]
+pub static GLOBE_AMERICAS: super::StaticShape = super::StaticShape::new(
+    "globe-americas",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18 10C18 14.4183 14.4183 18 10 18C5.58172 18 2 14.4183 2 10C2 5.58172 5.58172 2 10 2C14.4183 2 18 5.58172 18 10ZM16.5 10C16.5 13.5899 13.5899 16.5 10 16.5C6.41015 16.5 3.5 13.5899 3.5 10C3.5 8.15664 4.26733 6.4925 5.5 5.30957V5.75736C5.5 6.68562 5.86875 7.57586 6.52513 8.23223L8.29289 10L8 10.2929C7.60947 10.6834 7.60948 11.3166 8 11.7071L9.06066 12.7678C9.34197 13.0491 9.5 13.4306 9.5 13.8284V14.191C9.5 14.5698 9.714 14.916 10.0528 15.0854L10.3292 15.2236C10.8232 15.4706 11.4238 15.2704 11.6708 14.7764L13.1249 11.8683C13.4136 11.2908 13.3004 10.5933 12.8439 10.1368L12.0721 9.36495C11.8042 9.09712 11.4081 9.0036 11.0487 9.12338L10.6647 9.2514C10.4286 9.33008 10.1706 9.22322 10.0593 9.00066L9.76344 8.40885C9.67084 8.22365 9.70714 7.99997 9.85355 7.85355C9.99997 7.70714 10.2236 7.67084 10.4088 7.76344L10.6708 7.89443C10.8097 7.96385 10.9628 8 11.118 8H11.3063C11.9888 8 12.4708 7.3313 12.255 6.68377L12.187 6.47978C12.1227 6.28692 12.1816 6.07434 12.3359 5.94204L13.7754 4.70821C15.4248 5.8871 16.5 7.81799 16.5 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "globe-asia-australia" shape.
+#[cfg(any(feature = "globe-asia-australia", heroicon = "globe-asia-australia"))]
+pub static GLOBE_ASIA_AUSTRALIA: super::StaticShape = super::StaticShape::new(
+    "globe-asia-australia",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18 10C18 14.4183 14.4183 18 10 18C5.58172 18 2 14.4183 2 10C2 5.58172 5.58172 2 10 2C14.4183 2 18 5.58172 18 10ZM11.5 16.3261C11.0185 16.4398 10.5163 16.5 10 16.5C7.98332 16.5 6.18115 15.5816 4.98891 14.1402L5.47949 13.1591C5.59335 12.9313 5.88103 12.8549 6.09287 12.9962L6.38719 13.1924C6.78056 13.4546 7.30434 13.4028 7.63864 13.0685C7.90427 12.8028 7.99702 12.4099 7.87823 12.0535L7.68051 11.4604C7.6409 11.3416 7.6947 11.2117 7.80674 11.1556L9.78018 10.1689C10.2846 9.9167 10.4539 9.27942 10.141 8.81015C9.99285 8.58788 10.1177 8.28635 10.3797 8.23396L10.5047 8.20896C11.3568 8.03855 12.0522 7.42466 12.3269 6.60033L12.3766 6.45127C12.4964 6.09194 12.4029 5.69577 12.135 5.42794L10.6464 3.93934C10.5527 3.84557 10.5 3.71839 10.5 3.58579V3.51894C13.8562 3.77426 16.5 6.57839 16.5 10C16.5 10.9822 16.2821 11.9136 15.8921 12.7484C15.6958 12.6128 15.4634 12.5258 15.212 12.5049L14.6464 11.9393C14.3651 11.658 13.9836 11.5 13.5858 11.5H13.4142C13.0164 11.5 12.6349 11.658 12.3536 11.9393L11.7612 12.5317C11.7232 12.5697 11.6793 12.6013 11.6312 12.6254L10.0528 13.4146C9.714 13.584 9.5 13.9302 9.5 14.309V14.5C9.5 15.0523 9.94772 15.5 10.5 15.5H11C11.2761 15.5 11.5 15.7239 11.5 16V16.3261Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "globe-europe-africa" shape.
+#[cfg(any(feature = "globe-europe-africa", heroicon = "globe-europe-africa"))]
+pub static GLOBE_EUROPE_AFRICA: super::StaticShape = super::StaticShape::new(
+    "globe-europe-africa",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18 10C18 14.4183 14.4183 18 10 18C5.58172 18 2 14.4183 2 10C2 5.58172 5.58172 2 10 2C14.4183 2 18 5.58172 18 10ZM16.4969 10.2041C16.389 13.6995 13.5216 16.5 10 16.5C6.41015 16.5 3.5 13.5899 3.5 10C3.5 7.12656 5.36451 4.68861 7.94969 3.82998L6.92671 5.62019C6.60171 6.18894 6.69759 6.90485 7.16078 7.36805C7.6029 7.81017 8.27833 7.91977 8.83757 7.64015L9.0123 7.55279C9.08173 7.51807 9.15829 7.5 9.23591 7.5H9.38181C9.5712 7.5 9.74433 7.607 9.82902 7.77639C9.8994 7.91716 9.8994 8.08284 9.82902 8.22361L9.80123 8.27919C9.73357 8.41452 9.59525 8.5 9.44395 8.5H8.9418C8.18614 8.5 7.48048 8.87766 7.06131 9.50641L7.01751 9.57212C6.65828 10.111 6.56795 10.7855 6.77274 11.3998C6.98201 12.0276 7.47787 12.5197 8.10338 12.7282C8.33997 12.8071 8.49984 13.0292 8.49984 13.2749V14.3246C8.49984 14.9738 9.02608 15.5 9.67523 15.5C10.0323 15.5 10.37 15.3377 10.5931 15.0589L12.2044 13.0446C12.3957 12.8056 12.4998 12.5086 12.4998 12.2025C12.4998 11.8866 12.6279 11.5791 12.8531 11.3539C13.3088 10.8982 13.3864 10.1784 13.0257 9.63738L12.5617 8.94145C12.5214 8.88091 12.4998 8.80979 12.4998 8.73703C12.4998 8.43336 12.8465 8.26002 13.0895 8.44222L13.4322 8.69925C13.748 8.93609 14.1704 8.97373 14.523 8.79746C14.7488 8.68456 15.0216 8.72887 15.1996 8.90689L16.4969 10.2041Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,91 @@
+//! The shapes whose names start with "h".
+/// The "hand-raised" shape.
+#[cfg(any(feature = "hand-raised", heroicon = "hand-raised"))]
+pub static HAND_RAISED: super::StaticShape = super::StaticShape::new(
+    "hand-raised",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11 2C11 1.44772 10.5523 1 10 1C9.44772 1 9 1.44772 9 2V8V8.5C9 8.77614 8.77614 9 8.5 9C8.22386 9 8 8.77614 8 8.5V8V3C8 2.44772 7.55229 2 7 2C6.44771 2 6 2.44772 6 3V8V8.5C6 8.77614 5.77614 9 5.5 9C5.22386 9 5 8.77614 5 8.5V8V5C5 4.44772 4.55229 4 4 4C3.44771 4 3 4.44772 3 5V11V12C3 15.866 6.13401 19 10 19C13.866 19 17 15.866 17 12V11V8C17 7.44772 16.5523 7 16 7C15.4477 7 15 7.44772 15 8V11V11.5C15 11.7761 14.7761 12 14.5 12C14.2239 12 14 11.7761 14 11.5V11V10V8V3C14 2.44772 13.5523 2 13 2C12.4477 2 12 2.44772 12 3V8V8.5C12 8.77614 11.7761 9 11.5 9C11.2239 9 11 8.77614 11 8.5V8V2Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "hand-thumb-down" shape.
+#[cfg(any(feature = "hand-thumb-down", heroicon = "hand-thumb-down"))]
+pub static HAND_THUMB_DOWN: super::StaticShape = super::StaticShape::new(
+    "hand-thumb-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18.9053 12.75C18.9053 13.4404 18.3456 14 17.6553 14C16.9649 14 16.4053 13.4404 16.4053 12.75L16.4053 5.25C16.4053 4.55964 16.9649 4 17.6553 4C18.3456 4 18.9053 4.55964 18.9053 5.25L18.9053 12.75Z M8.90527 17L8.90527 18.2998C8.90527 18.5676 8.76554 18.8259 8.51033 18.9068C8.31945 18.9674 8.11618 19 7.90527 19C6.8007 19 5.90527 18.1046 5.90527 17C5.90527 16.005 6.08694 15.0524 6.4189 14.1735C6.62302 13.6332 6.25287 13 5.67524 13L3.15527 13C1.91263 13 0.894185 11.9904 1.00907 10.7531C1.20216 8.67339 1.66061 6.67064 2.35024 4.77906C2.75183 3.67752 3.83297 3 5.00543 3L8.19707 3C8.66281 3 9.12214 3.10843 9.53871 3.31672L12.2718 4.68328C12.6884 4.89156 13.1477 5 13.6135 5L14.9053 5L14.9053 12L13.9421 12C13.2576 12 12.6842 12.4825 12.3299 13.0681C11.8414 13.8752 11.0756 14.4958 10.1647 14.7977C9.73188 14.9412 9.31105 15.184 9.15279 15.6116C8.99272 16.0441 8.90527 16.5119 8.90527 17Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "hand-thumb-up" shape.
+#[cfg(any(feature = "hand-thumb-up", heroicon = "hand-thumb-up"))]
+pub static HAND_THUMB_UP: super::StaticShape = super::StaticShape::new(
+    "hand-thumb-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 8.25C1 7.55964 1.55964 7 2.25 7C2.94036 7 3.5 7.55964 3.5 8.25V15.75C3.5 16.4404 2.94036 17 2.25 17C1.55964 17 1 16.4404 1 15.75V8.25Z M11 3V1.70016C11 1.43243 11.1397 1.17406 11.3949 1.09316C11.5858 1.03264 11.7891 1 12 1C13.1046 1 14 1.89543 14 3C14 3.99504 13.8183 4.94764 13.4864 5.82646C13.2823 6.36683 13.6524 7 14.23 7H16.75C17.9926 7 19.0111 8.00957 18.8962 9.24689C18.7031 11.3266 18.2447 13.3294 17.555 15.2209C17.1534 16.3225 16.0723 17 14.8998 17H11.7082C11.2425 17 10.7831 16.8916 10.3666 16.6833L7.63344 15.3167C7.21687 15.1084 6.75753 15 6.2918 15H5V8H5.9632C6.64763 8 7.22105 7.51748 7.57541 6.93193C8.06387 6.1248 8.82962 5.50423 9.74061 5.20229C10.1734 5.05884 10.5942 4.81595 10.7525 4.38836C10.9126 3.95587 11 3.48815 11 3Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "hashtag" shape.
+#[cfg(any(feature = "hashtag", heroicon = "hashtag"))]
+pub static HASHTAG: super::StaticShape = super::StaticShape::new(
+    "hashtag",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.49306 2.85257C9.54965 2.44224 9.2629 2.06372 8.85257 2.00713C8.44224 1.95053 8.06372 2.23728 8.00712 2.64761L7.54471 6.00009H4.19831C3.7841 6.00009 3.44831 6.33588 3.44831 6.75009C3.44831 7.16431 3.7841 7.50009 4.19831 7.50009H7.33782L6.64816 12.5001H3.30176C2.88754 12.5001 2.55176 12.8359 2.55176 13.2501C2.55176 13.6643 2.88754 14.0001 3.30176 14.0001H6.44127L6.00713 17.1476C5.95053 17.5579 6.23728 17.9365 6.64761 17.9931C7.05794 18.0497 7.43646 17.7629 7.49306 17.3526L7.95547 14.0001H10.9413L10.5071 17.1476C10.4505 17.5579 10.7373 17.9365 11.1476 17.9931C11.5579 18.0497 11.9365 17.7629 11.9931 17.3526L12.4555 14.0001H15.8018C16.216 14.0001 16.5518 13.6643 16.5518 13.2501C16.5518 12.8359 16.216 12.5001 15.8018 12.5001H12.6624L13.352 7.50009H16.6983C17.1125 7.50009 17.4483 7.16431 17.4483 6.75009C17.4483 6.33588 17.1125 6.00009 16.6983 6.00009H13.5589L13.9931 2.85257C14.0497 2.44224 13.7629 2.06372 13.3526 2.00713C12.9422 1.95053 12.5637 2.23728 12.5071 2.64761L12.0447 6.00009H9.05892L9.49306 2.85257ZM8.85202 7.50009L8.16236 12.5001H11.1482L11.8378 7.50009H8.85202Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "heart" shape.
+#[cfg(any(feature = "heart", heroicon = "heart"))]
+pub static HEART: super::StaticShape = super::StaticShape::new(
+    "heart",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.65298 16.9149L9.6476 16.9121L9.62912 16.9024C9.61341 16.8941 9.59102 16.8822 9.56238 16.8667C9.50511 16.8358 9.42281 16.7907 9.31906 16.732C9.11164 16.6146 8.81794 16.4425 8.46663 16.2206C7.76556 15.7777 6.82731 15.1314 5.88539 14.3197C4.04447 12.7332 2 10.3523 2 7.5C2 5.01472 4.01472 3 6.5 3C7.9144 3 9.17542 3.65238 10 4.67158C10.8246 3.65238 12.0856 3 13.5 3C15.9853 3 18 5.01472 18 7.5C18 10.3523 15.9555 12.7332 14.1146 14.3197C13.1727 15.1314 12.2344 15.7777 11.5334 16.2206C11.1821 16.4425 10.8884 16.6146 10.6809 16.732C10.5772 16.7907 10.4949 16.8358 10.4376 16.8667C10.409 16.8822 10.3866 16.8941 10.3709 16.9024L10.3524 16.9121L10.347 16.9149L10.3453 16.9158C10.13 17.03 9.87 17.03 9.65529 16.9161L9.65298 16.9149Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "home-modern" shape.
+#[cfg(any(feature = "home-modern", heroicon = "home-modern"))]
+pub static HOME_MODERN: super::StaticShape = super::StaticShape::new(
+    "home-modern",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.9156 2.40414C15.1067 2.77164 14.9637 3.22447 14.5962 3.41557L14 3.72558V17.0002C14 17.5524 13.5523 18.0002 13 18.0002H10.74C10.3258 18.0002 9.99 17.6644 9.99 17.2502V13.7502C9.99 13.3359 9.65421 13.0002 9.24 13.0002H6.75C6.33579 13.0002 6 13.3359 6 13.7502V17.2502C6 17.6644 5.66421 18.0002 5.25 18.0002H1.75C1.33579 18.0002 1 17.6644 1 17.2502C1 16.8359 1.33579 16.5002 1.75 16.5002H2V9.95741C1.65296 10.0805 1.25946 9.93216 1.08474 9.59617C0.893644 9.22867 1.03664 8.77584 1.40414 8.58474L2 8.27489V5.75015C2 5.33594 2.33579 5.00015 2.75 5.00015C3.16421 5.00015 3.5 5.33594 3.5 5.75015V7.49489L13.9041 2.08474C14.2716 1.89364 14.7245 2.03664 14.9156 2.40414Z M15.8615 8.56938C16.0854 8.4334 16.3642 8.42419 16.5967 8.54508L18.596 9.58475C18.9635 9.77584 19.1065 10.2287 18.9154 10.5962C18.7407 10.9321 18.3473 11.0805 18.0003 10.9575V16.5002H18.25C18.6642 16.5002 19 16.8359 19 17.2502C19 17.6644 18.6642 18.0002 18.25 18.0002H16.25C16.0511 18.0002 15.8603 17.9211 15.7196 17.7805C15.579 17.6398 15.5 17.449 15.5 17.2501L15.5006 9.21043C15.5007 8.94839 15.6375 8.70536 15.8615 8.56938Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "home" shape.
+#[cfg(any(feature = "home", heroicon = "home"))]
+pub static HOME: super::StaticShape = super::StaticShape::new(
+    "home",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.29292 2.29289C9.68345 1.90237 10.3166 1.90237 10.7071 2.29289L17.7071 9.29289C17.9931 9.57889 18.0787 10.009 17.9239 10.3827C17.7691 10.7564 17.4045 11 17 11H16V17C16 17.5523 15.5523 18 15 18H13C12.4477 18 12 17.5523 12 17V14C12 13.4477 11.5523 13 11 13H9.00003C8.44774 13 8.00003 13.4477 8.00003 14V17C8.00003 17.5523 7.55231 18 7.00003 18H5.00003C4.44774 18 4.00003 17.5523 4.00003 17V11H3.00003C2.59557 11 2.23093 10.7564 2.07615 10.3827C1.92137 10.009 2.00692 9.57889 2.29292 9.29289L9.29292 2.29289Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
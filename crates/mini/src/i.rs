@@ -0,0 +1,69 @@
+//! The shapes whose names start with "i".
+/// The "identification" shape.
+#[cfg(any(feature = "identification", heroicon = "identification"))]
+pub static IDENTIFICATION: super::StaticShape = super::StaticShape::new(
+    "identification",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 6C1 4.34315 2.34315 3 4 3H16C17.6569 3 19 4.34315 19 6V14C19 15.6569 17.6569 17 16 17H4C2.34315 17 1 15.6569 1 14V6ZM5 7.5C5 6.39543 5.89543 5.5 7 5.5C8.10457 5.5 9 6.39543 9 7.5C9 8.60457 8.10457 9.5 7 9.5C5.89543 9.5 5 8.60457 5 7.5ZM6.99987 10.5C5.36027 10.5 3.95272 11.4864 3.3351 12.895C3.25293 13.0824 3.2509 13.2952 3.32946 13.4842C3.40802 13.6731 3.56036 13.8217 3.75117 13.8956C4.75988 14.2862 5.85579 14.5 6.99987 14.5C8.14395 14.5 9.23987 14.2862 10.2486 13.8956C10.4394 13.8217 10.5917 13.6731 10.6703 13.4842C10.7488 13.2952 10.7468 13.0824 10.6647 12.895C10.047 11.4864 8.63947 10.5 6.99987 10.5ZM12 6.75C12 6.33579 12.3358 6 12.75 6H15.25C15.6642 6 16 6.33579 16 6.75C16 7.16421 15.6642 7.5 15.25 7.5H12.75C12.3358 7.5 12 7.16421 12 6.75ZM12 13.25C12 12.8358 12.3358 12.5 12.75 12.5H15.25C15.6642 12.5 16 12.8358 16 13.25C16 13.6642 15.6642 14 15.25 14H12.75C12.3358 14 12 13.6642 12 13.25ZM12.75 9.25C12.3358 9.25 12 9.58579 12 10C12 10.4142 12.3358 10.75 12.75 10.75L15.25 10.75C15.6642 10.75 16 10.4142 16 10C16 9.58579 15.6642 9.25 15.25 9.25L12.75 9.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "inbox-arrow-down" shape.
+#[cfg(any(feature = "inbox-arrow-down", heroicon = "inbox-arrow-down"))]
+pub static INBOX_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "inbox-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 2C10.4142 2 10.75 2.33579 10.75 2.75V8.3401L12.7004 6.23966C12.9823 5.93613 13.4568 5.91855 13.7603 6.20041C14.0639 6.48226 14.0814 6.95681 13.7996 7.26034L10.5496 10.7603C10.4077 10.9132 10.2086 11 10 11C9.79145 11 9.59231 10.9132 9.45041 10.7603L6.20041 7.26034C5.91855 6.95681 5.93613 6.48226 6.23966 6.20041C6.54319 5.91855 7.01774 5.93613 7.29959 6.23966L9.25 8.3401V2.75C9.25 2.33579 9.58579 2 10 2Z M5.27298 4.5C4.71065 4.5 4.21753 4.8755 4.06799 5.41759L2.54501 10.9384C2.53938 10.9588 2.53427 10.9794 2.52969 11H6C6.37877 11 6.72504 11.214 6.89443 11.5528L7.34164 12.4472C7.51103 12.786 7.8573 13 8.23607 13H11.674C12.0269 13 12.3537 12.814 12.5339 12.5105L13.1401 11.4895C13.3203 11.186 13.6471 11 14 11H17.4703C17.4657 10.9794 17.4606 10.9588 17.455 10.9384L15.932 5.41759C15.7825 4.8755 15.2894 4.5 14.727 4.5H13.75C13.3358 4.5 13 4.16421 13 3.75C13 3.33579 13.3358 3 13.75 3H14.727C15.9642 3 17.049 3.8261 17.378 5.0187L18.901 10.5395C18.9667 10.7777 19 11.0237 19 11.2708V15C19 16.1046 18.1046 17 17 17H3C1.89543 17 1 16.1046 1 15V11.2708C1 11.0237 1.03331 10.7777 1.09902 10.5395L2.622 5.0187C2.95099 3.82611 4.03584 3 5.27298 3H6.25C6.66421 3 7 3.33579 7 3.75C7 4.16421 6.66421 4.5 6.25 4.5H5.27298Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "inbox-stack" shape.
+#[cfg(any(feature = "inbox-stack", heroicon = "inbox-stack"))]
+pub static INBOX_STACK: super::StaticShape = super::StaticShape::new(
+    "inbox-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1.04461 6.95375C1.08721 6.72011 1.16003 6.49228 1.26175 6.27613L2.53096 3.57906C2.98454 2.6152 3.95396 2 5.01921 2H14.9808C16.046 2 17.0155 2.6152 17.469 3.57906L18.7383 6.27613C18.84 6.49228 18.9128 6.72011 18.9554 6.95375C18.9844 7.04736 19 7.14686 19 7.25V8.75C19 10.2688 17.7688 11.5 16.25 11.5H3.75C2.23122 11.5 1 10.2688 1 8.75V7.25C1 7.14686 1.01562 7.04736 1.04461 6.95375ZM3.88819 4.21775C4.09436 3.77964 4.53501 3.5 5.01921 3.5H14.9808C15.465 3.5 15.9056 3.77964 16.1118 4.21775L17.0682 6.25H14C13.6471 6.25 13.3203 6.43601 13.1401 6.73946L12.5339 7.76054C12.3537 8.06399 12.0269 8.25 11.674 8.25H8.23607C7.8573 8.25 7.51103 8.036 7.34164 7.69721L6.89443 6.80279C6.72504 6.464 6.37877 6.25 6 6.25H2.93184L3.88819 4.21775Z M1 14C1 13.4477 1.44772 13 2 13H6C6.37877 13 6.72504 13.214 6.89443 13.5528L7.34164 14.4472C7.51103 14.786 7.8573 15 8.23607 15H11.674C12.0269 15 12.3537 14.814 12.5339 14.5105L13.1401 13.4895C13.3203 13.186 13.6471 13 14 13H18C18.5523 13 19 13.4477 19 14V16C19 17.1046 18.1046 18 17 18H3C1.89543 18 1 17.1046 1 16V14Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "inbox" shape.
+#[cfg(any(feature = "inbox", heroicon = "inbox"))]
+pub static INBOX: super::StaticShape = super::StaticShape::new(
+    "inbox",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 11.2708C1 11.0237 1.03331 10.7777 1.09902 10.5395L2.622 5.0187C2.95099 3.82611 4.03584 3 5.27298 3H14.727C15.9642 3 17.049 3.8261 17.378 5.0187L18.901 10.5395C18.9667 10.7777 19 11.0237 19 11.2708V15C19 16.1046 18.1046 17 17 17H3C1.89543 17 1 16.1046 1 15V11.2708ZM4.06799 5.41759C4.21753 4.8755 4.71065 4.5 5.27298 4.5H14.727C15.2894 4.5 15.7825 4.8755 15.932 5.41759L17.455 10.9384C17.4606 10.9588 17.4657 10.9794 17.4703 11H14C13.6471 11 13.3203 11.186 13.1401 11.4895L12.5339 12.5105C12.3537 12.814 12.0269 13 11.674 13H8.23607C7.8573 13 7.51103 12.786 7.34164 12.4472L6.89443 11.5528C6.72504 11.214 6.37877 11 6 11H2.52969C2.53427 10.9794 2.53938 10.9588 2.54501 10.9384L4.06799 5.41759Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "information-circle" shape.
+#[cfg(any(feature = "information-circle", heroicon = "information-circle"))]
+pub static INFORMATION_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "information-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18 10C18 14.4183 14.4183 18 10 18C5.58172 18 2 14.4183 2 10C2 5.58172 5.58172 2 10 2C14.4183 2 18 5.58172 18 10ZM11 6C11 6.55228 10.5523 7 10 7C9.44771 7 9 6.55228 9 6C9 5.44772 9.44771 5 10 5C10.5523 5 11 5.44772 11 6ZM9 9C8.58579 9 8.25 9.33579 8.25 9.75C8.25 10.1642 8.58579 10.5 9 10.5H9.25338C9.41332 10.5 9.53213 10.6481 9.49743 10.8042L9.03829 12.8704C8.79542 13.9633 9.62706 15 10.7466 15H11C11.4142 15 11.75 14.6642 11.75 14.25C11.75 13.8358 11.4142 13.5 11 13.5H10.7466C10.5867 13.5 10.4679 13.3519 10.5026 13.1958L10.9617 11.1296C11.2046 10.0367 10.3729 9 9.25338 9H9Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,95 @@
+//! The shapes whose names start with "l".
+/// The "language" shape.
+#[cfg(any(feature = "language", heroicon = "language"))]
+pub static LANGUAGE: super::StaticShape = super::StaticShape::new(
+    "language",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.75009 2.75C7.75009 2.33579 7.4143 2 7.00009 2C6.58587 2 6.25009 2.33579 6.25009 2.75V4.00842C5.03323 4.03578 3.8325 4.12954 2.65142 4.28627C2.24081 4.34076 1.95211 4.7178 2.0066 5.12841C2.06109 5.53902 2.43813 5.82772 2.84875 5.77323C4.20658 5.59304 5.59228 5.5 7.00009 5.5C7.57065 5.5 8.13758 5.51528 8.70048 5.54546C8.2826 6.95686 7.70916 8.30168 6.99996 9.56001C6.62755 8.89925 6.29259 8.21466 5.99793 7.50912C5.8383 7.1269 5.39905 6.94646 5.01683 7.10608C4.63461 7.26571 4.45416 7.70497 4.61379 8.08718C5.03667 9.09975 5.5365 10.072 6.10593 10.9967C5.03656 12.5598 3.74539 13.9596 2.27753 15.1506C1.95588 15.4116 1.9067 15.8839 2.16769 16.2055C2.42867 16.5272 2.90099 16.5764 3.22264 16.3154C4.64811 15.1588 5.91919 13.8189 6.99996 12.3314C7.0954 12.4628 7.19233 12.593 7.29071 12.722C7.54187 13.0514 8.01249 13.1148 8.34187 12.8637C8.67125 12.6125 8.73467 12.1419 8.48351 11.8125C8.2802 11.5459 8.08361 11.2739 7.894 10.9967C8.90466 9.35554 9.69602 7.56455 10.227 5.66453C10.5365 5.69627 10.8447 5.73253 11.1514 5.77323C11.562 5.82772 11.9391 5.53902 11.9936 5.12841C12.0481 4.7178 11.7594 4.34076 11.3487 4.28627C10.8114 4.21496 10.27 4.15669 9.72489 4.11178C9.07179 4.05797 8.41333 4.02333 7.75009 4.00842V2.75Z M13.0002 8C13.2842 8 13.5439 8.1605 13.671 8.41459L17.921 16.9146C18.1062 17.2851 17.9561 17.7356 17.5856 17.9208C17.2151 18.1061 16.7646 17.9559 16.5793 17.5854L15.7866 16H10.2137L9.42098 17.5854C9.23574 17.9559 8.78524 18.1061 8.41475 17.9208C8.04427 17.7356 7.8941 17.2851 8.07934 16.9146L12.3293 8.41459C12.4564 8.1605 12.7161 8 13.0002 8ZM15.0366 14.5L13.0002 10.4271L10.9637 14.5H15.0366Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "lifebuoy" shape.
+#[cfg(any(feature = "lifebuoy", heroicon = "lifebuoy"))]
+pub static LIFEBUOY: super::StaticShape = super::StaticShape::new(
+    "lifebuoy",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.17111 4.1457L9.11848 6.61237C9.69601 6.46254 10.304 6.46254 10.8815 6.61237L12.8289 4.1457C11.0472 3.28477 8.95276 3.28477 7.17111 4.1457ZM15.8543 7.17111L13.3876 9.11848C13.5375 9.69601 13.5375 10.304 13.3876 10.8815L15.8543 12.8289C16.7152 11.0472 16.7152 8.95276 15.8543 7.17111ZM12.8289 15.8543L10.8815 13.3876C10.304 13.5375 9.69601 13.5375 9.11848 13.3876L7.17111 15.8543C8.95276 16.7152 11.0472 16.7152 12.8289 15.8543ZM4.1457 12.8289L6.61237 10.8815C6.46254 10.304 6.46254 9.69601 6.61237 9.11848L4.1457 7.17111C3.28477 8.95276 3.28477 11.0472 4.1457 12.8289ZM5.63091 3.29667C8.27818 1.56778 11.7218 1.56778 14.3691 3.29667C14.8253 3.59461 15.2573 3.94356 15.6569 4.34315C16.0564 4.74273 16.4054 5.17471 16.7033 5.63091C18.4322 8.27817 18.4322 11.7218 16.7033 14.3691C16.4054 14.8253 16.0564 15.2573 15.6569 15.6569C15.2573 16.0564 14.8253 16.4054 14.3691 16.7033C11.7218 18.4322 8.27818 18.4322 5.63091 16.7033C5.1747 16.4054 4.74273 16.0564 4.34315 15.6569C3.94356 15.2573 3.59461 14.8253 3.29667 14.3691C1.56778 11.7218 1.56778 8.27818 3.29667 5.63091C3.59461 5.17471 3.94356 4.74273 4.34315 4.34315C4.74273 3.94356 5.17471 3.59461 5.63091 3.29667ZM10.8287 8.17897C10.3041 7.94035 9.69587 7.94035 9.17126 8.17897C8.95999 8.27506 8.76133 8.41024 8.58579 8.58579C8.41024 8.76133 8.27506 8.95999 8.17897 9.17126C7.94035 9.69587 7.94035 10.3041 8.17897 10.8287C8.27506 11.04 8.41024 11.2387 8.58579 11.4142C8.76133 11.5898 8.95999 11.7249 9.17126 11.821C9.69587 12.0597 10.3041 12.0597 10.8287 11.821C11.04 11.7249 11.2387 11.5898 11.4142 11.4142C11.5898 11.2387 11.7249 11.04 11.821 10.8287C12.0597 10.3041 12.0597 9.69587 11.821 9.17126C11.7249 8.95999 11.5898 8.76133 11.4142 8.58579C11.2387 8.41024 11.04 8.27506 10.8287 8.17897Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "light-bulb" shape.
+#[cfg(any(feature = "light-bulb", heroicon = "light-bulb"))]
+pub static LIGHT_BULB: super::StaticShape = super::StaticShape::new(
+    "light-bulb",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 1C6.68629 1 4 3.68629 4 7C4 8.86519 4.85197 10.532 6.18519 11.6313C7.23747 12.4989 8 13.4427 8 14.4557V15.1003C8 15.4459 8.23625 15.7468 8.57205 15.8289C9.03046 15.9408 9.50883 16 10 16C10.4912 16 10.9695 15.9408 11.428 15.8289C11.7637 15.7468 12 15.4459 12 15.1003V14.4557C12 13.4427 12.7625 12.4989 13.8148 11.6313C15.148 10.532 16 8.86519 16 7C16 3.68629 13.3137 1 10 1Z M8.8628 17.4144C8.45329 17.3522 8.07086 17.6337 8.00862 18.0432C7.94638 18.4527 8.22789 18.8352 8.6374 18.8974C9.08221 18.965 9.53731 19 10.0001 19C10.4629 19 10.918 18.965 11.3628 18.8974C11.7723 18.8352 12.0538 18.4527 11.9916 18.0432C11.9293 17.6337 11.5469 17.3522 11.1374 17.4144C10.7669 17.4708 10.3872 17.5 10.0001 17.5C9.61302 17.5 9.23326 17.4708 8.8628 17.4144Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "link" shape.
+#[cfg(any(feature = "link", heroicon = "link"))]
+pub static LINK: super::StaticShape = super::StaticShape::new(
+    "link",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.2322 4.23223C13.2085 3.25592 14.7915 3.25592 15.7678 4.23223C16.7441 5.20854 16.7441 6.79146 15.7678 7.76777L14.5434 8.9921C14.2505 9.28499 14.2505 9.75987 14.5434 10.0528C14.8363 10.3457 15.3112 10.3457 15.6041 10.0528L16.8284 8.82843C18.3905 7.26633 18.3905 4.73367 16.8284 3.17157C15.2663 1.60948 12.7337 1.60948 11.1716 3.17157L8.17157 6.17157C6.60948 7.73367 6.60948 10.2663 8.17157 11.8284C8.24449 11.9013 8.31963 11.9709 8.3968 12.0372C8.71107 12.307 9.18457 12.271 9.45441 11.9567C9.72424 11.6425 9.68822 11.169 9.37395 10.8991C9.32556 10.8576 9.27828 10.8138 9.23223 10.7678C8.25592 9.79145 8.25592 8.20854 9.23223 7.23223L12.2322 4.23223Z M11.6032 7.96278C11.2889 7.69295 10.8154 7.72897 10.5456 8.04324C10.2758 8.3575 10.3118 8.83101 10.626 9.10084C10.6744 9.14239 10.7217 9.18618 10.7678 9.23222C11.7441 10.2085 11.7441 11.7914 10.7678 12.7678L7.76777 15.7678C6.79146 16.7441 5.20854 16.7441 4.23223 15.7678C3.25592 14.7914 3.25592 13.2085 4.23223 12.2322L5.45657 11.0079C5.74946 10.715 5.74946 10.2401 5.45657 9.94723C5.16367 9.65433 4.6888 9.65433 4.39591 9.94723L3.17157 11.1716C1.60948 12.7337 1.60948 15.2663 3.17157 16.8284C4.73367 18.3905 7.26633 18.3905 8.82843 16.8284L11.8284 13.8284C13.3905 12.2663 13.3905 9.73366 11.8284 8.17156C11.7555 8.09864 11.6804 8.02904 11.6032 7.96278Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "list-bullet" shape.
+#[cfg(any(feature = "list-bullet", heroicon = "list-bullet"))]
+pub static LIST_BULLET: super::StaticShape = super::StaticShape::new(
+    "list-bullet",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6 4.75C6 4.33579 6.33579 4 6.75 4H17.25C17.6642 4 18 4.33579 18 4.75C18 5.16421 17.6642 5.5 17.25 5.5H6.75C6.33579 5.5 6 5.16421 6 4.75ZM6 10C6 9.58579 6.33579 9.25 6.75 9.25H17.25C17.6642 9.25 18 9.58579 18 10C18 10.4142 17.6642 10.75 17.25 10.75H6.75C6.33579 10.75 6 10.4142 6 10ZM6 15.25C6 14.8358 6.33579 14.5 6.75 14.5H17.25C17.6642 14.5 18 14.8358 18 15.25C18 15.6642 17.6642 16 17.25 16H6.75C6.33579 16 6 15.6642 6 15.25Z M1.98999 4.75C1.98999 4.19772 2.43771 3.75 2.98999 3.75H2.99999C3.55228 3.75 3.99999 4.19772 3.99999 4.75V4.76C3.99999 5.31229 3.55228 5.76 2.99999 5.76H2.98999C2.43771 5.76 1.98999 5.31229 1.98999 4.76V4.75Z M1.98999 15.25C1.98999 14.6977 2.43771 14.25 2.98999 14.25H2.99999C3.55228 14.25 3.99999 14.6977 3.99999 15.25V15.26C3.99999 15.8123 3.55228 16.26 2.99999 16.26H2.98999C2.43771 16.26 1.98999 15.8123 1.98999 15.26V15.25Z M1.98999 10C1.98999 9.44772 2.43771 9 2.98999 9H2.99999C3.55228 9 3.99999 9.44772 3.99999 10V10.01C3.99999 10.5623 3.55228 11.01 2.99999 11.01H2.98999C2.43771 11.01 1.98999 10.5623 1.98999 10.01V10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "lock-closed" shape.
+#[cfg(any(feature = "lock-closed", heroicon = "lock-closed"))]
+pub static LOCK_CLOSED: super::StaticShape = super::StaticShape::new(
+    "lock-closed",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 1C7.51472 1 5.5 3.01472 5.5 5.5V9H5C3.89543 9 3 9.89543 3 11V17C3 18.1046 3.89543 19 5 19H15C16.1046 19 17 18.1046 17 17V11C17 9.89543 16.1046 9 15 9H14.5V5.5C14.5 3.01472 12.4853 1 10 1ZM13 9V5.5C13 3.84315 11.6569 2.5 10 2.5C8.34315 2.5 7 3.84315 7 5.5V9H13Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "lock-open" shape.
+#[cfg(any(feature = "lock-open", heroicon = "lock-open"))]
+pub static LOCK_OPEN: super::StaticShape = super::StaticShape::new(
+    "lock-open",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.5 1C12.0147 1 10 3.01472 10 5.5V9H3C1.89543 9 1 9.89543 1 11V17C1 18.1046 1.89543 19 3 19H13C14.1046 19 15 18.1046 15 17V11C15 9.89543 14.1046 9 13 9H11.5V5.5C11.5 3.84315 12.8431 2.5 14.5 2.5C16.1569 2.5 17.5 3.84315 17.5 5.5V8.25C17.5 8.66421 17.8358 9 18.25 9C18.6642 9 19 8.66421 19 8.25V5.5C19 3.01472 16.9853 1 14.5 1Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
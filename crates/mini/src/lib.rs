@@ -0,0 +1,3083 @@
+//! The mini [heroicons](https://heroicons.com/) shapes.
+//!
+//! This crate has a [`Shape`] enum with all of the mini shapes, and a [`StaticShape`] static
+//! for each shape. It's re-exported as the `mini` module of
+//! [`dioxus-heroicons`](https://docs.rs/dioxus-heroicons), which has the Dioxus components that
+//! draw these shapes, so most apps should depend on that crate instead of this one.
+//!
+//! There is one feature for each icon, named after its heroicons name, like `arrow-left`. The
+//! `all-icons` feature, which is on by default, enables all of them.
+//!
+//! This crate was written by the `gen` crate in the dioxus-heroicons repo, so don't edit it by hand.
+
+use dioxus_heroicons_core::{PathData, StaticShape};
+mod a;
+mod b;
+mod c;
+mod d;
+mod e;
+mod f;
+mod g;
+mod h;
+mod i;
+mod k;
+mod l;
+mod m;
+mod n;
+mod p;
+mod q;
+mod r;
+mod s;
+mod t;
+mod u;
+mod v;
+mod w;
+mod x;
+#[allow(unused_imports)]
+pub use self::{
+    a::*, b::*, c::*, d::*, e::*, f::*, g::*, h::*, i::*, k::*, l::*, m::*, n::*, p::*,
+    q::*, r::*, s::*, t::*, u::*, v::*, w::*, x::*,
+};
+/// The `viewBox` for the `<svg>` element of every shape.
+pub const VIEW_BOX: &str = "0 0 20 20";
+/// All available icon shapes
+///
+/// See the enum variants for the shape names. These names are always the
+/// CamelCase version of the original icon name. So for example,
+/// "arrow-narrow-left" becomes `ArrowNarrowLeft`.
+///
+/// Each shape is also available as a [`StaticShape`] static, named after its
+/// original name, like `ARROW_LEFT`. Using the enum keeps every enabled shape
+/// in the binary, while using the statics only keeps the ones that are used.
+///
+/// Each shape has an explicit discriminant, which stays the same when the code is regenerated with
+/// new icons, so a shape can be stored as a number with [`Shape::discriminant`] and read back with
+/// [`Shape::from_discriminant`].
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shape {
+    #[cfg(any(feature = "academic-cap", heroicon = "academic-cap"))]
+    #[doc(alias("academic-cap"))]
+    AcademicCap = 0,
+    #[cfg(any(feature = "adjustments-horizontal", heroicon = "adjustments-horizontal"))]
+    #[doc(alias("adjustments-horizontal"))]
+    AdjustmentsHorizontal = 1,
+    #[cfg(any(feature = "adjustments-vertical", heroicon = "adjustments-vertical"))]
+    #[doc(alias("adjustments-vertical"))]
+    AdjustmentsVertical = 2,
+    #[cfg(any(feature = "archive-box-arrow-down", heroicon = "archive-box-arrow-down"))]
+    #[doc(alias("archive-box-arrow-down"))]
+    ArchiveBoxArrowDown = 3,
+    #[cfg(any(feature = "archive-box-x-mark", heroicon = "archive-box-x-mark"))]
+    #[doc(alias("archive-box-x-mark"))]
+    ArchiveBoxXMark = 4,
+    #[cfg(any(feature = "archive-box", heroicon = "archive-box"))]
+    #[doc(alias("archive-box"))]
+    ArchiveBox = 5,
+    #[cfg(any(feature = "arrow-down-circle", heroicon = "arrow-down-circle"))]
+    #[doc(alias("arrow-down-circle"))]
+    ArrowDownCircle = 6,
+    #[cfg(any(feature = "arrow-down-left", heroicon = "arrow-down-left"))]
+    #[doc(alias("arrow-down-left"))]
+    ArrowDownLeft = 7,
+    #[cfg(
+        any(
+            feature = "arrow-down-on-square-stack",
+            heroicon = "arrow-down-on-square-stack"
+        )
+    )]
+    #[doc(alias("arrow-down-on-square-stack"))]
+    ArrowDownOnSquareStack = 8,
+    #[cfg(any(feature = "arrow-down-on-square", heroicon = "arrow-down-on-square"))]
+    #[doc(alias("arrow-down-on-square"))]
+    ArrowDownOnSquare = 9,
+    #[cfg(any(feature = "arrow-down-right", heroicon = "arrow-down-right"))]
+    #[doc(alias("arrow-down-right"))]
+    ArrowDownRight = 10,
+    #[cfg(any(feature = "arrow-down-tray", heroicon = "arrow-down-tray"))]
+    #[doc(alias("arrow-down-tray", "download"))]
+    ArrowDownTray = 11,
+    #[cfg(any(feature = "arrow-down", heroicon = "arrow-down"))]
+    #[doc(alias("arrow-down"))]
+    ArrowDown = 12,
+    #[cfg(any(feature = "arrow-left-circle", heroicon = "arrow-left-circle"))]
+    #[doc(alias("arrow-left-circle"))]
+    ArrowLeftCircle = 13,
+    #[cfg(
+        any(feature = "arrow-left-on-rectangle", heroicon = "arrow-left-on-rectangle")
+    )]
+    #[doc(alias("arrow-left-on-rectangle"))]
+    ArrowLeftOnRectangle = 14,
+    #[cfg(any(feature = "arrow-left", heroicon = "arrow-left"))]
+    #[doc(alias("arrow-left"))]
+    ArrowLeft = 15,
+    #[cfg(any(feature = "arrow-long-down", heroicon = "arrow-long-down"))]
+    #[doc(alias("arrow-long-down"))]
+    ArrowLongDown = 16,
+    #[cfg(any(feature = "arrow-long-left", heroicon = "arrow-long-left"))]
+    #[doc(alias("arrow-long-left"))]
+    ArrowLongLeft = 17,
+    #[cfg(any(feature = "arrow-long-right", heroicon = "arrow-long-right"))]
+    #[doc(alias("arrow-long-right"))]
+    ArrowLongRight = 18,
+    #[cfg(any(feature = "arrow-long-up", heroicon = "arrow-long-up"))]
+    #[doc(alias("arrow-long-up"))]
+    ArrowLongUp = 19,
+    #[cfg(
+        any(
+            feature = "arrow-path-rounded-square",
+            heroicon = "arrow-path-rounded-square"
+        )
+    )]
+    #[doc(alias("arrow-path-rounded-square"))]
+    ArrowPathRoundedSquare = 20,
+    #[cfg(any(feature = "arrow-path", heroicon = "arrow-path"))]
+    #[doc(alias("arrow-path", "refresh", "reload", "retry", "sync"))]
+    ArrowPath = 21,
+    #[cfg(any(feature = "arrow-right-circle", heroicon = "arrow-right-circle"))]
+    #[doc(alias("arrow-right-circle"))]
+    ArrowRightCircle = 22,
+    #[cfg(
+        any(feature = "arrow-right-on-rectangle", heroicon = "arrow-right-on-rectangle")
+    )]
+    #[doc(alias("arrow-right-on-rectangle"))]
+    ArrowRightOnRectangle = 23,
+    #[cfg(any(feature = "arrow-right", heroicon = "arrow-right"))]
+    #[doc(alias("arrow-right"))]
+    ArrowRight = 24,
+    #[cfg(any(feature = "arrow-small-down", heroicon = "arrow-small-down"))]
+    #[doc(alias("arrow-small-down"))]
+    ArrowSmallDown = 25,
+    #[cfg(any(feature = "arrow-small-left", heroicon = "arrow-small-left"))]
+    #[doc(alias("arrow-small-left"))]
+    ArrowSmallLeft = 26,
+    #[cfg(any(feature = "arrow-small-right", heroicon = "arrow-small-right"))]
+    #[doc(alias("arrow-small-right"))]
+    ArrowSmallRight = 27,
+    #[cfg(any(feature = "arrow-small-up", heroicon = "arrow-small-up"))]
+    #[doc(alias("arrow-small-up"))]
+    ArrowSmallUp = 28,
+    #[cfg(
+        any(
+            feature = "arrow-top-right-on-square",
+            heroicon = "arrow-top-right-on-square"
+        )
+    )]
+    #[doc(alias("arrow-top-right-on-square"))]
+    ArrowTopRightOnSquare = 29,
+    #[cfg(any(feature = "arrow-trending-down", heroicon = "arrow-trending-down"))]
+    #[doc(alias("arrow-trending-down"))]
+    ArrowTrendingDown = 30,
+    #[cfg(any(feature = "arrow-trending-up", heroicon = "arrow-trending-up"))]
+    #[doc(alias("arrow-trending-up"))]
+    ArrowTrendingUp = 31,
+    #[cfg(any(feature = "arrow-up-circle", heroicon = "arrow-up-circle"))]
+    #[doc(alias("arrow-up-circle"))]
+    ArrowUpCircle = 32,
+    #[cfg(any(feature = "arrow-up-left", heroicon = "arrow-up-left"))]
+    #[doc(alias("arrow-up-left"))]
+    ArrowUpLeft = 33,
+    #[cfg(
+        any(feature = "arrow-up-on-square-stack", heroicon = "arrow-up-on-square-stack")
+    )]
+    #[doc(alias("arrow-up-on-square-stack"))]
+    ArrowUpOnSquareStack = 34,
+    #[cfg(any(feature = "arrow-up-on-square", heroicon = "arrow-up-on-square"))]
+    #[doc(alias("arrow-up-on-square"))]
+    ArrowUpOnSquare = 35,
+    #[cfg(any(feature = "arrow-up-right", heroicon = "arrow-up-right"))]
+    #[doc(alias("arrow-up-right"))]
+    ArrowUpRight = 36,
+    #[cfg(any(feature = "arrow-up-tray", heroicon = "arrow-up-tray"))]
+    #[doc(alias("arrow-up-tray", "upload"))]
+    ArrowUpTray = 37,
+    #[cfg(any(feature = "arrow-up", heroicon = "arrow-up"))]
+    #[doc(alias("arrow-up"))]
+    ArrowUp = 38,
+    #[cfg(any(feature = "arrow-uturn-down", heroicon = "arrow-uturn-down"))]
+    #[doc(alias("arrow-uturn-down"))]
+    ArrowUturnDown = 39,
+    #[cfg(any(feature = "arrow-uturn-left", heroicon = "arrow-uturn-left"))]
+    #[doc(alias("arrow-uturn-left"))]
+    ArrowUturnLeft = 40,
+    #[cfg(any(feature = "arrow-uturn-right", heroicon = "arrow-uturn-right"))]
+    #[doc(alias("arrow-uturn-right"))]
+    ArrowUturnRight = 41,
+    #[cfg(any(feature = "arrow-uturn-up", heroicon = "arrow-uturn-up"))]
+    #[doc(alias("arrow-uturn-up"))]
+    ArrowUturnUp = 42,
+    #[cfg(any(feature = "arrows-pointing-in", heroicon = "arrows-pointing-in"))]
+    #[doc(alias("arrows-pointing-in"))]
+    ArrowsPointingIn = 43,
+    #[cfg(any(feature = "arrows-pointing-out", heroicon = "arrows-pointing-out"))]
+    #[doc(alias("arrows-pointing-out"))]
+    ArrowsPointingOut = 44,
+    #[cfg(any(feature = "arrows-right-left", heroicon = "arrows-right-left"))]
+    #[doc(alias("arrows-right-left"))]
+    ArrowsRightLeft = 45,
+    #[cfg(any(feature = "arrows-up-down", heroicon = "arrows-up-down"))]
+    #[doc(alias("arrows-up-down"))]
+    ArrowsUpDown = 46,
+    #[cfg(any(feature = "at-symbol", heroicon = "at-symbol"))]
+    #[doc(alias("at-symbol"))]
+    AtSymbol = 47,
+    #[cfg(any(feature = "backspace", heroicon = "backspace"))]
+    Backspace = 48,
+    #[cfg(any(feature = "backward", heroicon = "backward"))]
+    Backward = 49,
+    #[cfg(any(feature = "banknotes", heroicon = "banknotes"))]
+    Banknotes = 50,
+    #[cfg(any(feature = "bars-2", heroicon = "bars-2"))]
+    #[doc(alias("bars-2"))]
+    Bars2 = 51,
+    #[cfg(any(feature = "bars-3-bottom-left", heroicon = "bars-3-bottom-left"))]
+    #[doc(alias("bars-3-bottom-left"))]
+    Bars3BottomLeft = 52,
+    #[cfg(any(feature = "bars-3-bottom-right", heroicon = "bars-3-bottom-right"))]
+    #[doc(alias("bars-3-bottom-right"))]
+    Bars3BottomRight = 53,
+    #[cfg(any(feature = "bars-3-center-left", heroicon = "bars-3-center-left"))]
+    #[doc(alias("bars-3-center-left"))]
+    Bars3CenterLeft = 54,
+    #[cfg(any(feature = "bars-3", heroicon = "bars-3"))]
+    #[doc(alias("bars-3", "hamburger", "menu"))]
+    Bars3 = 55,
+    #[cfg(any(feature = "bars-4", heroicon = "bars-4"))]
+    #[doc(alias("bars-4"))]
+    Bars4 = 56,
+    #[cfg(any(feature = "bars-arrow-down", heroicon = "bars-arrow-down"))]
+    #[doc(alias("bars-arrow-down"))]
+    BarsArrowDown = 57,
+    #[cfg(any(feature = "bars-arrow-up", heroicon = "bars-arrow-up"))]
+    #[doc(alias("bars-arrow-up"))]
+    BarsArrowUp = 58,
+    #[cfg(any(feature = "battery-0", heroicon = "battery-0"))]
+    #[doc(alias("battery-0"))]
+    Battery0 = 59,
+    #[cfg(any(feature = "battery-100", heroicon = "battery-100"))]
+    #[doc(alias("battery-100"))]
+    Battery100 = 60,
+    #[cfg(any(feature = "battery-50", heroicon = "battery-50"))]
+    #[doc(alias("battery-50"))]
+    Battery50 = 61,
+    #[cfg(any(feature = "beaker", heroicon = "beaker"))]
+    Beaker = 62,
+    #[cfg(any(feature = "bell-alert", heroicon = "bell-alert"))]
+    #[doc(alias("bell-alert"))]
+    BellAlert = 63,
+    #[cfg(any(feature = "bell-slash", heroicon = "bell-slash"))]
+    #[doc(alias("bell-slash"))]
+    BellSlash = 64,
+    #[cfg(any(feature = "bell-snooze", heroicon = "bell-snooze"))]
+    #[doc(alias("bell-snooze"))]
+    BellSnooze = 65,
+    #[cfg(any(feature = "bell", heroicon = "bell"))]
+    #[doc(alias("alert", "notification"))]
+    Bell = 66,
+    #[cfg(any(feature = "bolt-slash", heroicon = "bolt-slash"))]
+    #[doc(alias("bolt-slash"))]
+    BoltSlash = 67,
+    #[cfg(any(feature = "bolt", heroicon = "bolt"))]
+    Bolt = 68,
+    #[cfg(any(feature = "book-open", heroicon = "book-open"))]
+    #[doc(alias("book-open"))]
+    BookOpen = 69,
+    #[cfg(any(feature = "bookmark-slash", heroicon = "bookmark-slash"))]
+    #[doc(alias("bookmark-slash"))]
+    BookmarkSlash = 70,
+    #[cfg(any(feature = "bookmark-square", heroicon = "bookmark-square"))]
+    #[doc(alias("bookmark-square"))]
+    BookmarkSquare = 71,
+    #[cfg(any(feature = "bookmark", heroicon = "bookmark"))]
+    Bookmark = 72,
+    #[cfg(any(feature = "briefcase", heroicon = "briefcase"))]
+    Briefcase = 73,
+    #[cfg(any(feature = "bug-ant", heroicon = "bug-ant"))]
+    #[doc(alias("bug-ant"))]
+    BugAnt = 74,
+    #[cfg(any(feature = "building-library", heroicon = "building-library"))]
+    #[doc(alias("building-library"))]
+    BuildingLibrary = 75,
+    #[cfg(any(feature = "building-office-2", heroicon = "building-office-2"))]
+    #[doc(alias("building-office-2"))]
+    BuildingOffice2 = 76,
+    #[cfg(any(feature = "building-office", heroicon = "building-office"))]
+    #[doc(alias("building-office"))]
+    BuildingOffice = 77,
+    #[cfg(any(feature = "building-storefront", heroicon = "building-storefront"))]
+    #[doc(alias("building-storefront"))]
+    BuildingStorefront = 78,
+    #[cfg(any(feature = "cake", heroicon = "cake"))]
+    Cake = 79,
+    #[cfg(any(feature = "calculator", heroicon = "calculator"))]
+    Calculator = 80,
+    #[cfg(any(feature = "calendar-days", heroicon = "calendar-days"))]
+    #[doc(alias("calendar-days"))]
+    CalendarDays = 81,
+    #[cfg(any(feature = "calendar", heroicon = "calendar"))]
+    #[doc(alias("date", "schedule"))]
+    Calendar = 82,
+    #[cfg(any(feature = "camera", heroicon = "camera"))]
+    Camera = 83,
+    #[cfg(any(feature = "chart-bar-square", heroicon = "chart-bar-square"))]
+    #[doc(alias("chart-bar-square"))]
+    ChartBarSquare = 84,
+    #[cfg(any(feature = "chart-bar", heroicon = "chart-bar"))]
+    #[doc(alias("chart-bar"))]
+    ChartBar = 85,
+    #[cfg(any(feature = "chart-pie", heroicon = "chart-pie"))]
+    #[doc(alias("chart-pie"))]
+    ChartPie = 86,
+    #[cfg(
+        any(
+            feature = "chat-bubble-bottom-center-text",
+            heroicon = "chat-bubble-bottom-center-text"
+        )
+    )]
+    #[doc(alias("chat-bubble-bottom-center-text"))]
+    ChatBubbleBottomCenterText = 87,
+    #[cfg(
+        any(
+            feature = "chat-bubble-bottom-center",
+            heroicon = "chat-bubble-bottom-center"
+        )
+    )]
+    #[doc(alias("chat-bubble-bottom-center"))]
+    ChatBubbleBottomCenter = 88,
+    #[cfg(
+        any(
+            feature = "chat-bubble-left-ellipsis",
+            heroicon = "chat-bubble-left-ellipsis"
+        )
+    )]
+    #[doc(alias("chat-bubble-left-ellipsis"))]
+    ChatBubbleLeftEllipsis = 89,
+    #[cfg(any(feature = "chat-bubble-left-right", heroicon = "chat-bubble-left-right"))]
+    #[doc(alias("chat-bubble-left-right"))]
+    ChatBubbleLeftRight = 90,
+    #[cfg(any(feature = "chat-bubble-left", heroicon = "chat-bubble-left"))]
+    #[doc(alias("chat-bubble-left", "chat", "comment", "message"))]
+    ChatBubbleLeft = 91,
+    #[cfg(
+        any(
+            feature = "chat-bubble-oval-left-ellipsis",
+            heroicon = "chat-bubble-oval-left-ellipsis"
+        )
+    )]
+    #[doc(alias("chat-bubble-oval-left-ellipsis"))]
+    ChatBubbleOvalLeftEllipsis = 92,
+    #[cfg(any(feature = "chat-bubble-oval-left", heroicon = "chat-bubble-oval-left"))]
+    #[doc(alias("chat-bubble-oval-left"))]
+    ChatBubbleOvalLeft = 93,
+    #[cfg(any(feature = "check-badge", heroicon = "check-badge"))]
+    #[doc(alias("check-badge"))]
+    CheckBadge = 94,
+    #[cfg(any(feature = "check-circle", heroicon = "check-circle"))]
+    #[doc(alias("check-circle"))]
+    CheckCircle = 95,
+    #[cfg(any(feature = "check", heroicon = "check"))]
+    #[doc(alias("confirm", "done", "tick"))]
+    Check = 96,
+    #[cfg(any(feature = "chevron-double-down", heroicon = "chevron-double-down"))]
+    #[doc(alias("chevron-double-down"))]
+    ChevronDoubleDown = 97,
+    #[cfg(any(feature = "chevron-double-left", heroicon = "chevron-double-left"))]
+    #[doc(alias("chevron-double-left"))]
+    ChevronDoubleLeft = 98,
+    #[cfg(any(feature = "chevron-double-right", heroicon = "chevron-double-right"))]
+    #[doc(alias("chevron-double-right"))]
+    ChevronDoubleRight = 99,
+    #[cfg(any(feature = "chevron-double-up", heroicon = "chevron-double-up"))]
+    #[doc(alias("chevron-double-up"))]
+    ChevronDoubleUp = 100,
+    #[cfg(any(feature = "chevron-down", heroicon = "chevron-down"))]
+    #[doc(alias("chevron-down"))]
+    ChevronDown = 101,
+    #[cfg(any(feature = "chevron-left", heroicon = "chevron-left"))]
+    #[doc(alias("chevron-left"))]
+    ChevronLeft = 102,
+    #[cfg(any(feature = "chevron-right", heroicon = "chevron-right"))]
+    #[doc(alias("chevron-right"))]
+    ChevronRight = 103,
+    #[cfg(any(feature = "chevron-up-down", heroicon = "chevron-up-down"))]
+    #[doc(alias("chevron-up-down"))]
+    ChevronUpDown = 104,
+    #[cfg(any(feature = "chevron-up", heroicon = "chevron-up"))]
+    #[doc(alias("chevron-up"))]
+    ChevronUp = 105,
+    #[cfg(any(feature = "circle-stack", heroicon = "circle-stack"))]
+    #[doc(alias("circle-stack"))]
+    CircleStack = 106,
+    #[cfg(
+        any(feature = "clipboard-document-check", heroicon = "clipboard-document-check")
+    )]
+    #[doc(alias("clipboard-document-check"))]
+    ClipboardDocumentCheck = 107,
+    #[cfg(
+        any(feature = "clipboard-document-list", heroicon = "clipboard-document-list")
+    )]
+    #[doc(alias("clipboard-document-list"))]
+    ClipboardDocumentList = 108,
+    #[cfg(any(feature = "clipboard-document", heroicon = "clipboard-document"))]
+    #[doc(alias("clipboard-document"))]
+    ClipboardDocument = 109,
+    #[cfg(any(feature = "clipboard", heroicon = "clipboard"))]
+    #[doc(alias("copy", "paste"))]
+    Clipboard = 110,
+    #[cfg(any(feature = "clock", heroicon = "clock"))]
+    #[doc(alias("time"))]
+    Clock = 111,
+    #[cfg(any(feature = "cloud-arrow-down", heroicon = "cloud-arrow-down"))]
+    #[doc(alias("cloud-arrow-down"))]
+    CloudArrowDown = 112,
+    #[cfg(any(feature = "cloud-arrow-up", heroicon = "cloud-arrow-up"))]
+    #[doc(alias("cloud-arrow-up"))]
+    CloudArrowUp = 113,
+    #[cfg(any(feature = "cloud", heroicon = "cloud"))]
+    Cloud = 114,
+    #[cfg(any(feature = "code-bracket-square", heroicon = "code-bracket-square"))]
+    #[doc(alias("code-bracket-square"))]
+    CodeBracketSquare = 115,
+    #[cfg(any(feature = "code-bracket", heroicon = "code-bracket"))]
+    #[doc(alias("code-bracket"))]
+    CodeBracket = 116,
+    #[cfg(any(feature = "cog-6-tooth", heroicon = "cog-6-tooth"))]
+    #[doc(alias("cog-6-tooth", "gear", "preferences", "settings"))]
+    Cog6Tooth = 117,
+    #[cfg(any(feature = "cog-8-tooth", heroicon = "cog-8-tooth"))]
+    #[doc(alias("cog-8-tooth"))]
+    Cog8Tooth = 118,
+    #[cfg(any(feature = "cog", heroicon = "cog"))]
+    #[doc(alias("gear", "settings"))]
+    Cog = 119,
+    #[cfg(any(feature = "command-line", heroicon = "command-line"))]
+    #[doc(alias("command-line"))]
+    CommandLine = 120,
+    #[cfg(any(feature = "computer-desktop", heroicon = "computer-desktop"))]
+    #[doc(alias("computer-desktop"))]
+    ComputerDesktop = 121,
+    #[cfg(any(feature = "cpu-chip", heroicon = "cpu-chip"))]
+    #[doc(alias("cpu-chip"))]
+    CpuChip = 122,
+    #[cfg(any(feature = "credit-card", heroicon = "credit-card"))]
+    #[doc(alias("credit-card"))]
+    CreditCard = 123,
+    #[cfg(any(feature = "cube-transparent", heroicon = "cube-transparent"))]
+    #[doc(alias("cube-transparent"))]
+    CubeTransparent = 124,
+    #[cfg(any(feature = "cube", heroicon = "cube"))]
+    Cube = 125,
+    #[cfg(any(feature = "currency-bangladeshi", heroicon = "currency-bangladeshi"))]
+    #[doc(alias("currency-bangladeshi"))]
+    CurrencyBangladeshi = 126,
+    #[cfg(any(feature = "currency-dollar", heroicon = "currency-dollar"))]
+    #[doc(alias("currency-dollar"))]
+    CurrencyDollar = 127,
+    #[cfg(any(feature = "currency-euro", heroicon = "currency-euro"))]
+    #[doc(alias("currency-euro"))]
+    CurrencyEuro = 128,
+    #[cfg(any(feature = "currency-pound", heroicon = "currency-pound"))]
+    #[doc(alias("currency-pound"))]
+    CurrencyPound = 129,
+    #[cfg(any(feature = "currency-rupee", heroicon = "currency-rupee"))]
+    #[doc(alias("currency-rupee"))]
+    CurrencyRupee = 130,
+    #[cfg(any(feature = "currency-yen", heroicon = "currency-yen"))]
+    #[doc(alias("currency-yen"))]
+    CurrencyYen = 131,
+    #[cfg(any(feature = "cursor-arrow-rays", heroicon = "cursor-arrow-rays"))]
+    #[doc(alias("cursor-arrow-rays"))]
+    CursorArrowRays = 132,
+    #[cfg(any(feature = "cursor-arrow-ripple", heroicon = "cursor-arrow-ripple"))]
+    #[doc(alias("cursor-arrow-ripple"))]
+    CursorArrowRipple = 133,
+    #[cfg(any(feature = "device-phone-mobile", heroicon = "device-phone-mobile"))]
+    #[doc(alias("device-phone-mobile"))]
+    DevicePhoneMobile = 134,
+    #[cfg(any(feature = "device-tablet", heroicon = "device-tablet"))]
+    #[doc(alias("device-tablet"))]
+    DeviceTablet = 135,
+    #[cfg(any(feature = "document-arrow-down", heroicon = "document-arrow-down"))]
+    #[doc(alias("document-arrow-down"))]
+    DocumentArrowDown = 136,
+    #[cfg(any(feature = "document-arrow-up", heroicon = "document-arrow-up"))]
+    #[doc(alias("document-arrow-up"))]
+    DocumentArrowUp = 137,
+    #[cfg(any(feature = "document-chart-bar", heroicon = "document-chart-bar"))]
+    #[doc(alias("document-chart-bar"))]
+    DocumentChartBar = 138,
+    #[cfg(any(feature = "document-check", heroicon = "document-check"))]
+    #[doc(alias("document-check"))]
+    DocumentCheck = 139,
+    #[cfg(any(feature = "document-duplicate", heroicon = "document-duplicate"))]
+    #[doc(alias("document-duplicate"))]
+    DocumentDuplicate = 140,
+    #[cfg(
+        any(
+            feature = "document-magnifying-glass",
+            heroicon = "document-magnifying-glass"
+        )
+    )]
+    #[doc(alias("document-magnifying-glass"))]
+    DocumentMagnifyingGlass = 141,
+    #[cfg(any(feature = "document-minus", heroicon = "document-minus"))]
+    #[doc(alias("document-minus"))]
+    DocumentMinus = 142,
+    #[cfg(any(feature = "document-plus", heroicon = "document-plus"))]
+    #[doc(alias("document-plus"))]
+    DocumentPlus = 143,
+    #[cfg(any(feature = "document-text", heroicon = "document-text"))]
+    #[doc(alias("document-text"))]
+    DocumentText = 144,
+    #[cfg(any(feature = "document", heroicon = "document"))]
+    #[doc(alias("file", "page"))]
+    Document = 145,
+    #[cfg(
+        any(
+            feature = "ellipsis-horizontal-circle",
+            heroicon = "ellipsis-horizontal-circle"
+        )
+    )]
+    #[doc(alias("ellipsis-horizontal-circle"))]
+    EllipsisHorizontalCircle = 146,
+    #[cfg(any(feature = "ellipsis-horizontal", heroicon = "ellipsis-horizontal"))]
+    #[doc(alias("ellipsis-horizontal"))]
+    EllipsisHorizontal = 147,
+    #[cfg(any(feature = "ellipsis-vertical", heroicon = "ellipsis-vertical"))]
+    #[doc(alias("ellipsis-vertical"))]
+    EllipsisVertical = 148,
+    #[cfg(any(feature = "envelope-open", heroicon = "envelope-open"))]
+    #[doc(alias("envelope-open"))]
+    EnvelopeOpen = 149,
+    #[cfg(any(feature = "envelope", heroicon = "envelope"))]
+    #[doc(alias("email", "mail", "message"))]
+    Envelope = 150,
+    #[cfg(any(feature = "exclamation-circle", heroicon = "exclamation-circle"))]
+    #[doc(alias("exclamation-circle"))]
+    ExclamationCircle = 151,
+    #[cfg(any(feature = "exclamation-triangle", heroicon = "exclamation-triangle"))]
+    #[doc(alias("exclamation-triangle", "alert", "caution", "warning"))]
+    ExclamationTriangle = 152,
+    #[cfg(any(feature = "eye-dropper", heroicon = "eye-dropper"))]
+    #[doc(alias("eye-dropper"))]
+    EyeDropper = 153,
+    #[cfg(any(feature = "eye-slash", heroicon = "eye-slash"))]
+    #[doc(alias("eye-slash", "hidden", "hide", "invisible"))]
+    EyeSlash = 154,
+    #[cfg(any(feature = "eye", heroicon = "eye"))]
+    #[doc(alias("show", "view", "visible"))]
+    Eye = 155,
+    #[cfg(any(feature = "face-frown", heroicon = "face-frown"))]
+    #[doc(alias("face-frown"))]
+    FaceFrown = 156,
+    #[cfg(any(feature = "face-smile", heroicon = "face-smile"))]
+    #[doc(alias("face-smile"))]
+    FaceSmile = 157,
+    #[cfg(any(feature = "film", heroicon = "film"))]
+    Film = 158,
+    #[cfg(any(feature = "finger-print", heroicon = "finger-print"))]
+    #[doc(alias("finger-print"))]
+    FingerPrint = 159,
+    #[cfg(any(feature = "fire", heroicon = "fire"))]
+    Fire = 160,
+    #[cfg(any(feature = "flag", heroicon = "flag"))]
+    Flag = 161,
+    #[cfg(any(feature = "folder-arrow-down", heroicon = "folder-arrow-down"))]
+    #[doc(alias("folder-arrow-down"))]
+    FolderArrowDown = 162,
+    #[cfg(any(feature = "folder-minus", heroicon = "folder-minus"))]
+    #[doc(alias("folder-minus"))]
+    FolderMinus = 163,
+    #[cfg(any(feature = "folder-open", heroicon = "folder-open"))]
+    #[doc(alias("folder-open"))]
+    FolderOpen = 164,
+    #[cfg(any(feature = "folder-plus", heroicon = "folder-plus"))]
+    #[doc(alias("folder-plus"))]
+    FolderPlus = 165,
+    #[cfg(any(feature = "folder", heroicon = "folder"))]
+    #[doc(alias("directory"))]
+    Folder = 166,
+    #[cfg(any(feature = "forward", heroicon = "forward"))]
+    Forward = 167,
+    #[cfg(any(feature = "funnel", heroicon = "funnel"))]
+    Funnel = 168,
+    #[cfg(any(feature = "gif", heroicon = "gif"))]
+    Gif = 169,
+    #[cfg(any(feature = "gift-top", heroicon = "gift-top"))]
+    #[doc(alias("gift-top"))]
+    GiftTop = 170,
+    #[cfg(any(feature = "gift", heroicon = "gift"))]
+    Gift = 171,
+    #[cfg(any(feature = "globe-alt", heroicon = "globe-alt"))]
+    #[doc(alias("globe-alt", "internet", "web", "world"))]
+    GlobeAlt = 172,
+    #[cfg(any(feature = "globe-americas", heroicon = "globe-americas"))]
+    #[doc(alias("globe-americas"))]
+    GlobeAmericas = 173,
+    #[cfg(any(feature = "globe-asia-australia", heroicon = "globe-asia-australia"))]
+    #[doc(alias("globe-asia-australia"))]
+    GlobeAsiaAustralia = 174,
+    #[cfg(any(feature = "globe-europe-africa", heroicon = "globe-europe-africa"))]
+    #[doc(alias("globe-europe-africa"))]
+    GlobeEuropeAfrica = 175,
+    #[cfg(any(feature = "hand-raised", heroicon = "hand-raised"))]
+    #[doc(alias("hand-raised"))]
+    HandRaised = 176,
+    #[cfg(any(feature = "hand-thumb-down", heroicon = "hand-thumb-down"))]
+    #[doc(alias("hand-thumb-down"))]
+    HandThumbDown = 177,
+    #[cfg(any(feature = "hand-thumb-up", heroicon = "hand-thumb-up"))]
+    #[doc(alias("hand-thumb-up"))]
+    HandThumbUp = 178,
+    #[cfg(any(feature = "hashtag", heroicon = "hashtag"))]
+    Hashtag = 179,
+    #[cfg(any(feature = "heart", heroicon = "heart"))]
+    #[doc(alias("favorite", "like", "love"))]
+    Heart = 180,
+    #[cfg(any(feature = "home-modern", heroicon = "home-modern"))]
+    #[doc(alias("home-modern"))]
+    HomeModern = 181,
+    #[cfg(any(feature = "home", heroicon = "home"))]
+    #[doc(alias("house"))]
+    Home = 182,
+    #[cfg(any(feature = "identification", heroicon = "identification"))]
+    Identification = 183,
+    #[cfg(any(feature = "inbox-arrow-down", heroicon = "inbox-arrow-down"))]
+    #[doc(alias("inbox-arrow-down"))]
+    InboxArrowDown = 184,
+    #[cfg(any(feature = "inbox-stack", heroicon = "inbox-stack"))]
+    #[doc(alias("inbox-stack"))]
+    InboxStack = 185,
+    #[cfg(any(feature = "inbox", heroicon = "inbox"))]
+    Inbox = 186,
+    #[cfg(any(feature = "information-circle", heroicon = "information-circle"))]
+    #[doc(alias("information-circle", "about", "info"))]
+    InformationCircle = 187,
+    #[cfg(any(feature = "key", heroicon = "key"))]
+    Key = 188,
+    #[cfg(any(feature = "language", heroicon = "language"))]
+    Language = 189,
+    #[cfg(any(feature = "lifebuoy", heroicon = "lifebuoy"))]
+    Lifebuoy = 190,
+    #[cfg(any(feature = "light-bulb", heroicon = "light-bulb"))]
+    #[doc(alias("light-bulb"))]
+    LightBulb = 191,
+    #[cfg(any(feature = "link", heroicon = "link"))]
+    #[doc(alias("chain", "hyperlink", "url"))]
+    Link = 192,
+    #[cfg(any(feature = "list-bullet", heroicon = "list-bullet"))]
+    #[doc(alias("list-bullet"))]
+    ListBullet = 193,
+    #[cfg(any(feature = "lock-closed", heroicon = "lock-closed"))]
+    #[doc(alias("lock-closed", "locked", "password", "secure"))]
+    LockClosed = 194,
+    #[cfg(any(feature = "lock-open", heroicon = "lock-open"))]
+    #[doc(alias("lock-open", "unlocked"))]
+    LockOpen = 195,
+    #[cfg(
+        any(feature = "magnifying-glass-circle", heroicon = "magnifying-glass-circle")
+    )]
+    #[doc(alias("magnifying-glass-circle"))]
+    MagnifyingGlassCircle = 196,
+    #[cfg(any(feature = "magnifying-glass-minus", heroicon = "magnifying-glass-minus"))]
+    #[doc(alias("magnifying-glass-minus"))]
+    MagnifyingGlassMinus = 197,
+    #[cfg(any(feature = "magnifying-glass-plus", heroicon = "magnifying-glass-plus"))]
+    #[doc(alias("magnifying-glass-plus"))]
+    MagnifyingGlassPlus = 198,
+    #[cfg(any(feature = "magnifying-glass", heroicon = "magnifying-glass"))]
+    #[doc(alias("magnifying-glass", "find", "search"))]
+    MagnifyingGlass = 199,
+    #[cfg(any(feature = "map-pin", heroicon = "map-pin"))]
+    #[doc(alias("map-pin", "location", "marker", "place"))]
+    MapPin = 200,
+    #[cfg(any(feature = "map", heroicon = "map"))]
+    Map = 201,
+    #[cfg(any(feature = "megaphone", heroicon = "megaphone"))]
+    Megaphone = 202,
+    #[cfg(any(feature = "microphone", heroicon = "microphone"))]
+    Microphone = 203,
+    #[cfg(any(feature = "minus-circle", heroicon = "minus-circle"))]
+    #[doc(alias("minus-circle"))]
+    MinusCircle = 204,
+    #[cfg(any(feature = "minus-small", heroicon = "minus-small"))]
+    #[doc(alias("minus-small"))]
+    MinusSmall = 205,
+    #[cfg(any(feature = "minus", heroicon = "minus"))]
+    #[doc(alias("subtract"))]
+    Minus = 206,
+    #[cfg(any(feature = "moon", heroicon = "moon"))]
+    #[doc(alias("dark", "night"))]
+    Moon = 207,
+    #[cfg(any(feature = "musical-note", heroicon = "musical-note"))]
+    #[doc(alias("musical-note"))]
+    MusicalNote = 208,
+    #[cfg(any(feature = "newspaper", heroicon = "newspaper"))]
+    Newspaper = 209,
+    #[cfg(any(feature = "no-symbol", heroicon = "no-symbol"))]
+    #[doc(alias("no-symbol"))]
+    NoSymbol = 210,
+    #[cfg(any(feature = "paint-brush", heroicon = "paint-brush"))]
+    #[doc(alias("paint-brush"))]
+    PaintBrush = 211,
+    #[cfg(any(feature = "paper-airplane", heroicon = "paper-airplane"))]
+    #[doc(alias("paper-airplane"))]
+    PaperAirplane = 212,
+    #[cfg(any(feature = "paper-clip", heroicon = "paper-clip"))]
+    #[doc(alias("paper-clip"))]
+    PaperClip = 213,
+    #[cfg(any(feature = "pause-circle", heroicon = "pause-circle"))]
+    #[doc(alias("pause-circle"))]
+    PauseCircle = 214,
+    #[cfg(any(feature = "pause", heroicon = "pause"))]
+    Pause = 215,
+    #[cfg(any(feature = "pencil-square", heroicon = "pencil-square"))]
+    #[doc(alias("pencil-square", "compose", "edit"))]
+    PencilSquare = 216,
+    #[cfg(any(feature = "pencil", heroicon = "pencil"))]
+    #[doc(alias("edit", "write"))]
+    Pencil = 217,
+    #[cfg(any(feature = "phone-arrow-down-left", heroicon = "phone-arrow-down-left"))]
+    #[doc(alias("phone-arrow-down-left"))]
+    PhoneArrowDownLeft = 218,
+    #[cfg(any(feature = "phone-arrow-up-right", heroicon = "phone-arrow-up-right"))]
+    #[doc(alias("phone-arrow-up-right"))]
+    PhoneArrowUpRight = 219,
+    #[cfg(any(feature = "phone-x-mark", heroicon = "phone-x-mark"))]
+    #[doc(alias("phone-x-mark"))]
+    PhoneXMark = 220,
+    #[cfg(any(feature = "phone", heroicon = "phone"))]
+    #[doc(alias("call", "telephone"))]
+    Phone = 221,
+    #[cfg(any(feature = "photo", heroicon = "photo"))]
+    #[doc(alias("image", "picture"))]
+    Photo = 222,
+    #[cfg(any(feature = "play-circle", heroicon = "play-circle"))]
+    #[doc(alias("play-circle"))]
+    PlayCircle = 223,
+    #[cfg(any(feature = "play-pause", heroicon = "play-pause"))]
+    #[doc(alias("play-pause"))]
+    PlayPause = 224,
+    #[cfg(any(feature = "play", heroicon = "play"))]
+    Play = 225,
+    #[cfg(any(feature = "plus-circle", heroicon = "plus-circle"))]
+    #[doc(alias("plus-circle"))]
+    PlusCircle = 226,
+    #[cfg(any(feature = "plus-small", heroicon = "plus-small"))]
+    #[doc(alias("plus-small"))]
+    PlusSmall = 227,
+    #[cfg(any(feature = "plus", heroicon = "plus"))]
+    #[doc(alias("add", "create", "new"))]
+    Plus = 228,
+    #[cfg(any(feature = "power", heroicon = "power"))]
+    Power = 229,
+    #[cfg(any(feature = "presentation-chart-bar", heroicon = "presentation-chart-bar"))]
+    #[doc(alias("presentation-chart-bar"))]
+    PresentationChartBar = 230,
+    #[cfg(
+        any(feature = "presentation-chart-line", heroicon = "presentation-chart-line")
+    )]
+    #[doc(alias("presentation-chart-line"))]
+    PresentationChartLine = 231,
+    #[cfg(any(feature = "printer", heroicon = "printer"))]
+    Printer = 232,
+    #[cfg(any(feature = "puzzle-piece", heroicon = "puzzle-piece"))]
+    #[doc(alias("puzzle-piece"))]
+    PuzzlePiece = 233,
+    #[cfg(any(feature = "qr-code", heroicon = "qr-code"))]
+    #[doc(alias("qr-code"))]
+    QrCode = 234,
+    #[cfg(any(feature = "question-mark-circle", heroicon = "question-mark-circle"))]
+    #[doc(alias("question-mark-circle", "faq", "help", "support"))]
+    QuestionMarkCircle = 235,
+    #[cfg(any(feature = "queue-list", heroicon = "queue-list"))]
+    #[doc(alias("queue-list"))]
+    QueueList = 236,
+    #[cfg(any(feature = "radio", heroicon = "radio"))]
+    Radio = 237,
+    #[cfg(any(feature = "receipt-percent", heroicon = "receipt-percent"))]
+    #[doc(alias("receipt-percent"))]
+    ReceiptPercent = 238,
+    #[cfg(any(feature = "receipt-refund", heroicon = "receipt-refund"))]
+    #[doc(alias("receipt-refund"))]
+    ReceiptRefund = 239,
+    #[cfg(any(feature = "rectangle-group", heroicon = "rectangle-group"))]
+    #[doc(alias("rectangle-group"))]
+    RectangleGroup = 240,
+    #[cfg(any(feature = "rectangle-stack", heroicon = "rectangle-stack"))]
+    #[doc(alias("rectangle-stack"))]
+    RectangleStack = 241,
+    #[cfg(any(feature = "rocket-launch", heroicon = "rocket-launch"))]
+    #[doc(alias("rocket-launch"))]
+    RocketLaunch = 242,
+    #[cfg(any(feature = "rss", heroicon = "rss"))]
+    Rss = 243,
+    #[cfg(any(feature = "scale", heroicon = "scale"))]
+    Scale = 244,
+    #[cfg(any(feature = "scissors", heroicon = "scissors"))]
+    Scissors = 245,
+    #[cfg(any(feature = "server-stack", heroicon = "server-stack"))]
+    #[doc(alias("server-stack"))]
+    ServerStack = 246,
+    #[cfg(any(feature = "server", heroicon = "server"))]
+    Server = 247,
+    #[cfg(any(feature = "share", heroicon = "share"))]
+    #[doc(alias("send"))]
+    Share = 248,
+    #[cfg(any(feature = "shield-check", heroicon = "shield-check"))]
+    #[doc(alias("shield-check"))]
+    ShieldCheck = 249,
+    #[cfg(any(feature = "shield-exclamation", heroicon = "shield-exclamation"))]
+    #[doc(alias("shield-exclamation"))]
+    ShieldExclamation = 250,
+    #[cfg(any(feature = "shopping-bag", heroicon = "shopping-bag"))]
+    #[doc(alias("shopping-bag"))]
+    ShoppingBag = 251,
+    #[cfg(any(feature = "shopping-cart", heroicon = "shopping-cart"))]
+    #[doc(alias("shopping-cart", "basket", "cart", "checkout"))]
+    ShoppingCart = 252,
+    #[cfg(any(feature = "signal-slash", heroicon = "signal-slash"))]
+    #[doc(alias("signal-slash"))]
+    SignalSlash = 253,
+    #[cfg(any(feature = "signal", heroicon = "signal"))]
+    Signal = 254,
+    #[cfg(any(feature = "sparkles", heroicon = "sparkles"))]
+    Sparkles = 255,
+    #[cfg(any(feature = "speaker-wave", heroicon = "speaker-wave"))]
+    #[doc(alias("speaker-wave"))]
+    SpeakerWave = 256,
+    #[cfg(any(feature = "speaker-x-mark", heroicon = "speaker-x-mark"))]
+    #[doc(alias("speaker-x-mark"))]
+    SpeakerXMark = 257,
+    #[cfg(any(feature = "square-2-stack", heroicon = "square-2-stack"))]
+    #[doc(alias("square-2-stack"))]
+    Square2Stack = 258,
+    #[cfg(any(feature = "square-3-stack-3d", heroicon = "square-3-stack-3d"))]
+    #[doc(alias("square-3-stack-3d"))]
+    Square3Stack3d = 259,
+    #[cfg(any(feature = "squares-2x2", heroicon = "squares-2x2"))]
+    #[doc(alias("squares-2x2"))]
+    Squares2x2 = 260,
+    #[cfg(any(feature = "squares-plus", heroicon = "squares-plus"))]
+    #[doc(alias("squares-plus"))]
+    SquaresPlus = 261,
+    #[cfg(any(feature = "star", heroicon = "star"))]
+    #[doc(alias("favorite", "rating"))]
+    Star = 262,
+    #[cfg(any(feature = "stop-circle", heroicon = "stop-circle"))]
+    #[doc(alias("stop-circle"))]
+    StopCircle = 263,
+    #[cfg(any(feature = "stop", heroicon = "stop"))]
+    Stop = 264,
+    #[cfg(any(feature = "sun", heroicon = "sun"))]
+    #[doc(alias("brightness", "day", "light"))]
+    Sun = 265,
+    #[cfg(any(feature = "swatch", heroicon = "swatch"))]
+    Swatch = 266,
+    #[cfg(any(feature = "table-cells", heroicon = "table-cells"))]
+    #[doc(alias("table-cells"))]
+    TableCells = 267,
+    #[cfg(any(feature = "tag", heroicon = "tag"))]
+    Tag = 268,
+    #[cfg(any(feature = "ticket", heroicon = "ticket"))]
+    Ticket = 269,
+    #[cfg(any(feature = "trash", heroicon = "trash"))]
+    #[doc(alias("bin", "delete", "garbage", "remove", "rubbish", "trash-can"))]
+    Trash = 270,
+    #[cfg(any(feature = "trophy", heroicon = "trophy"))]
+    Trophy = 271,
+    #[cfg(any(feature = "truck", heroicon = "truck"))]
+    Truck = 272,
+    #[cfg(any(feature = "tv", heroicon = "tv"))]
+    Tv = 273,
+    #[cfg(any(feature = "user-circle", heroicon = "user-circle"))]
+    #[doc(alias("user-circle"))]
+    UserCircle = 274,
+    #[cfg(any(feature = "user-group", heroicon = "user-group"))]
+    #[doc(alias("user-group"))]
+    UserGroup = 275,
+    #[cfg(any(feature = "user-minus", heroicon = "user-minus"))]
+    #[doc(alias("user-minus"))]
+    UserMinus = 276,
+    #[cfg(any(feature = "user-plus", heroicon = "user-plus"))]
+    #[doc(alias("user-plus"))]
+    UserPlus = 277,
+    #[cfg(any(feature = "user", heroicon = "user"))]
+    #[doc(alias("account", "person", "profile"))]
+    User = 278,
+    #[cfg(any(feature = "users", heroicon = "users"))]
+    #[doc(alias("group", "people", "team"))]
+    Users = 279,
+    #[cfg(any(feature = "variable", heroicon = "variable"))]
+    Variable = 280,
+    #[cfg(any(feature = "video-camera-slash", heroicon = "video-camera-slash"))]
+    #[doc(alias("video-camera-slash"))]
+    VideoCameraSlash = 281,
+    #[cfg(any(feature = "video-camera", heroicon = "video-camera"))]
+    #[doc(alias("video-camera"))]
+    VideoCamera = 282,
+    #[cfg(any(feature = "view-columns", heroicon = "view-columns"))]
+    #[doc(alias("view-columns"))]
+    ViewColumns = 283,
+    #[cfg(any(feature = "viewfinder-circle", heroicon = "viewfinder-circle"))]
+    #[doc(alias("viewfinder-circle"))]
+    ViewfinderCircle = 284,
+    #[cfg(any(feature = "wallet", heroicon = "wallet"))]
+    Wallet = 285,
+    #[cfg(any(feature = "wifi", heroicon = "wifi"))]
+    Wifi = 286,
+    #[cfg(any(feature = "window", heroicon = "window"))]
+    Window = 287,
+    #[cfg(any(feature = "wrench-screwdriver", heroicon = "wrench-screwdriver"))]
+    #[doc(alias("wrench-screwdriver"))]
+    WrenchScrewdriver = 288,
+    #[cfg(any(feature = "wrench", heroicon = "wrench"))]
+    Wrench = 289,
+    #[cfg(any(feature = "x-circle", heroicon = "x-circle"))]
+    #[doc(alias("x-circle"))]
+    XCircle = 290,
+    #[cfg(any(feature = "x-mark", heroicon = "x-mark"))]
+    #[doc(alias("x-mark", "cancel", "close", "dismiss"))]
+    XMark = 291,
+}
+#[allow(dead_code)]
+impl Shape {
+    /// All of the shapes, in alphabetical order.
+    pub const ALL: &'static [Shape] = &[
+        #[cfg(any(feature = "academic-cap", heroicon = "academic-cap"))]
+        Shape::AcademicCap,
+        #[cfg(
+            any(feature = "adjustments-horizontal", heroicon = "adjustments-horizontal")
+        )]
+        Shape::AdjustmentsHorizontal,
+        #[cfg(any(feature = "adjustments-vertical", heroicon = "adjustments-vertical"))]
+        Shape::AdjustmentsVertical,
+        #[cfg(
+            any(feature = "archive-box-arrow-down", heroicon = "archive-box-arrow-down")
+        )]
+        Shape::ArchiveBoxArrowDown,
+        #[cfg(any(feature = "archive-box-x-mark", heroicon = "archive-box-x-mark"))]
+        Shape::ArchiveBoxXMark,
+        #[cfg(any(feature = "archive-box", heroicon = "archive-box"))]
+        Shape::ArchiveBox,
+        #[cfg(any(feature = "arrow-down-circle", heroicon = "arrow-down-circle"))]
+        Shape::ArrowDownCircle,
+        #[cfg(any(feature = "arrow-down-left", heroicon = "arrow-down-left"))]
+        Shape::ArrowDownLeft,
+        #[cfg(
+            any(
+                feature = "arrow-down-on-square-stack",
+                heroicon = "arrow-down-on-square-stack"
+            )
+        )]
+        Shape::ArrowDownOnSquareStack,
+        #[cfg(any(feature = "arrow-down-on-square", heroicon = "arrow-down-on-square"))]
+        Shape::ArrowDownOnSquare,
+        #[cfg(any(feature = "arrow-down-right", heroicon = "arrow-down-right"))]
+        Shape::ArrowDownRight,
+        #[cfg(any(feature = "arrow-down-tray", heroicon = "arrow-down-tray"))]
+        Shape::ArrowDownTray,
+        #[cfg(any(feature = "arrow-down", heroicon = "arrow-down"))]
+        Shape::ArrowDown,
+        #[cfg(any(feature = "arrow-left-circle", heroicon = "arrow-left-circle"))]
+        Shape::ArrowLeftCircle,
+        #[cfg(
+            any(
+                feature = "arrow-left-on-rectangle",
+                heroicon = "arrow-left-on-rectangle"
+            )
+        )]
+        Shape::ArrowLeftOnRectangle,
+        #[cfg(any(feature = "arrow-left", heroicon = "arrow-left"))]
+        Shape::ArrowLeft,
+        #[cfg(any(feature = "arrow-long-down", heroicon = "arrow-long-down"))]
+        Shape::ArrowLongDown,
+        #[cfg(any(feature = "arrow-long-left", heroicon = "arrow-long-left"))]
+        Shape::ArrowLongLeft,
+        #[cfg(any(feature = "arrow-long-right", heroicon = "arrow-long-right"))]
+        Shape::ArrowLongRight,
+        #[cfg(any(feature = "arrow-long-up", heroicon = "arrow-long-up"))]
+        Shape::ArrowLongUp,
+        #[cfg(
+            any(
+                feature = "arrow-path-rounded-square",
+                heroicon = "arrow-path-rounded-square"
+            )
+        )]
+        Shape::ArrowPathRoundedSquare,
+        #[cfg(any(feature = "arrow-path", heroicon = "arrow-path"))]
+        Shape::ArrowPath,
+        #[cfg(any(feature = "arrow-right-circle", heroicon = "arrow-right-circle"))]
+        Shape::ArrowRightCircle,
+        #[cfg(
+            any(
+                feature = "arrow-right-on-rectangle",
+                heroicon = "arrow-right-on-rectangle"
+            )
+        )]
+        Shape::ArrowRightOnRectangle,
+        #[cfg(any(feature = "arrow-right", heroicon = "arrow-right"))]
+        Shape::ArrowRight,
+        #[cfg(any(feature = "arrow-small-down", heroicon = "arrow-small-down"))]
+        Shape::ArrowSmallDown,
+        #[cfg(any(feature = "arrow-small-left", heroicon = "arrow-small-left"))]
+        Shape::ArrowSmallLeft,
+        #[cfg(any(feature = "arrow-small-right", heroicon = "arrow-small-right"))]
+        Shape::ArrowSmallRight,
+        #[cfg(any(feature = "arrow-small-up", heroicon = "arrow-small-up"))]
+        Shape::ArrowSmallUp,
+        #[cfg(
+            any(
+                feature = "arrow-top-right-on-square",
+                heroicon = "arrow-top-right-on-square"
+            )
+        )]
+        Shape::ArrowTopRightOnSquare,
+        #[cfg(any(feature = "arrow-trending-down", heroicon = "arrow-trending-down"))]
+        Shape::ArrowTrendingDown,
+        #[cfg(any(feature = "arrow-trending-up", heroicon = "arrow-trending-up"))]
+        Shape::ArrowTrendingUp,
+        #[cfg(any(feature = "arrow-up-circle", heroicon = "arrow-up-circle"))]
+        Shape::ArrowUpCircle,
+        #[cfg(any(feature = "arrow-up-left", heroicon = "arrow-up-left"))]
+        Shape::ArrowUpLeft,
+        #[cfg(
+            any(
+                feature = "arrow-up-on-square-stack",
+                heroicon = "arrow-up-on-square-stack"
+            )
+        )]
+        Shape::ArrowUpOnSquareStack,
+        #[cfg(any(feature = "arrow-up-on-square", heroicon = "arrow-up-on-square"))]
+        Shape::ArrowUpOnSquare,
+        #[cfg(any(feature = "arrow-up-right", heroicon = "arrow-up-right"))]
+        Shape::ArrowUpRight,
+        #[cfg(any(feature = "arrow-up-tray", heroicon = "arrow-up-tray"))]
+        Shape::ArrowUpTray,
+        #[cfg(any(feature = "arrow-up", heroicon = "arrow-up"))]
+        Shape::ArrowUp,
+        #[cfg(any(feature = "arrow-uturn-down", heroicon = "arrow-uturn-down"))]
+        Shape::ArrowUturnDown,
+        #[cfg(any(feature = "arrow-uturn-left", heroicon = "arrow-uturn-left"))]
+        Shape::ArrowUturnLeft,
+        #[cfg(any(feature = "arrow-uturn-right", heroicon = "arrow-uturn-right"))]
+        Shape::ArrowUturnRight,
+        #[cfg(any(feature = "arrow-uturn-up", heroicon = "arrow-uturn-up"))]
+        Shape::ArrowUturnUp,
+        #[cfg(any(feature = "arrows-pointing-in", heroicon = "arrows-pointing-in"))]
+        Shape::ArrowsPointingIn,
+        #[cfg(any(feature = "arrows-pointing-out", heroicon = "arrows-pointing-out"))]
+        Shape::ArrowsPointingOut,
+        #[cfg(any(feature = "arrows-right-left", heroicon = "arrows-right-left"))]
+        Shape::ArrowsRightLeft,
+        #[cfg(any(feature = "arrows-up-down", heroicon = "arrows-up-down"))]
+        Shape::ArrowsUpDown,
+        #[cfg(any(feature = "at-symbol", heroicon = "at-symbol"))]
+        Shape::AtSymbol,
+        #[cfg(any(feature = "backspace", heroicon = "backspace"))]
+        Shape::Backspace,
+        #[cfg(any(feature = "backward", heroicon = "backward"))]
+        Shape::Backward,
+        #[cfg(any(feature = "banknotes", heroicon = "banknotes"))]
+        Shape::Banknotes,
+        #[cfg(any(feature = "bars-2", heroicon = "bars-2"))]
+        Shape::Bars2,
+        #[cfg(any(feature = "bars-3-bottom-left", heroicon = "bars-3-bottom-left"))]
+        Shape::Bars3BottomLeft,
+        #[cfg(any(feature = "bars-3-bottom-right", heroicon = "bars-3-bottom-right"))]
+        Shape::Bars3BottomRight,
+        #[cfg(any(feature = "bars-3-center-left", heroicon = "bars-3-center-left"))]
+        Shape::Bars3CenterLeft,
+        #[cfg(any(feature = "bars-3", heroicon = "bars-3"))]
+        Shape::Bars3,
+        #[cfg(any(feature = "bars-4", heroicon = "bars-4"))]
+        Shape::Bars4,
+        #[cfg(any(feature = "bars-arrow-down", heroicon = "bars-arrow-down"))]
+        Shape::BarsArrowDown,
+        #[cfg(any(feature = "bars-arrow-up", heroicon = "bars-arrow-up"))]
+        Shape::BarsArrowUp,
+        #[cfg(any(feature = "battery-0", heroicon = "battery-0"))]
+        Shape::Battery0,
+        #[cfg(any(feature = "battery-100", heroicon = "battery-100"))]
+        Shape::Battery100,
+        #[cfg(any(feature = "battery-50", heroicon = "battery-50"))]
+        Shape::Battery50,
+        #[cfg(any(feature = "beaker", heroicon = "beaker"))]
+        Shape::Beaker,
+        #[cfg(any(feature = "bell-alert", heroicon = "bell-alert"))]
+        Shape::BellAlert,
+        #[cfg(any(feature = "bell-slash", heroicon = "bell-slash"))]
+        Shape::BellSlash,
+        #[cfg(any(feature = "bell-snooze", heroicon = "bell-snooze"))]
+        Shape::BellSnooze,
+        #[cfg(any(feature = "bell", heroicon = "bell"))]
+        Shape::Bell,
+        #[cfg(any(feature = "bolt-slash", heroicon = "bolt-slash"))]
+        Shape::BoltSlash,
+        #[cfg(any(feature = "bolt", heroicon = "bolt"))]
+        Shape::Bolt,
+        #[cfg(any(feature = "book-open", heroicon = "book-open"))]
+        Shape::BookOpen,
+        #[cfg(any(feature = "bookmark-slash", heroicon = "bookmark-slash"))]
+        Shape::BookmarkSlash,
+        #[cfg(any(feature = "bookmark-square", heroicon = "bookmark-square"))]
+        Shape::BookmarkSquare,
+        #[cfg(any(feature = "bookmark", heroicon = "bookmark"))]
+        Shape::Bookmark,
+        #[cfg(any(feature = "briefcase", heroicon = "briefcase"))]
+        Shape::Briefcase,
+        #[cfg(any(feature = "bug-ant", heroicon = "bug-ant"))]
+        Shape::BugAnt,
+        #[cfg(any(feature = "building-library", heroicon = "building-library"))]
+        Shape::BuildingLibrary,
+        #[cfg(any(feature = "building-office-2", heroicon = "building-office-2"))]
+        Shape::BuildingOffice2,
+        #[cfg(any(feature = "building-office", heroicon = "building-office"))]
+        Shape::BuildingOffice,
+        #[cfg(any(feature = "building-storefront", heroicon = "building-storefront"))]
+        Shape::BuildingStorefront,
+        #[cfg(any(feature = "cake", heroicon = "cake"))]
+        Shape::Cake,
+        #[cfg(any(feature = "calculator", heroicon = "calculator"))]
+        Shape::Calculator,
+        #[cfg(any(feature = "calendar-days", heroicon = "calendar-days"))]
+        Shape::CalendarDays,
+        #[cfg(any(feature = "calendar", heroicon = "calendar"))]
+        Shape::Calendar,
+        #[cfg(any(feature = "camera", heroicon = "camera"))]
+        Shape::Camera,
+        #[cfg(any(feature = "chart-bar-square", heroicon = "chart-bar-square"))]
+        Shape::ChartBarSquare,
+        #[cfg(any(feature = "chart-bar", heroicon = "chart-bar"))]
+        Shape::ChartBar,
+        #[cfg(any(feature = "chart-pie", heroicon = "chart-pie"))]
+        Shape::ChartPie,
+        #[cfg(
+            any(
+                feature = "chat-bubble-bottom-center-text",
+                heroicon = "chat-bubble-bottom-center-text"
+            )
+        )]
+        Shape::ChatBubbleBottomCenterText,
+        #[cfg(
+            any(
+                feature = "chat-bubble-bottom-center",
+                heroicon = "chat-bubble-bottom-center"
+            )
+        )]
+        Shape::ChatBubbleBottomCenter,
+        #[cfg(
+            any(
+                feature = "chat-bubble-left-ellipsis",
+                heroicon = "chat-bubble-left-ellipsis"
+            )
+        )]
+        Shape::ChatBubbleLeftEllipsis,
+        #[cfg(
+            any(feature = "chat-bubble-left-right", heroicon = "chat-bubble-left-right")
+        )]
+        Shape::ChatBubbleLeftRight,
+        #[cfg(any(feature = "chat-bubble-left", heroicon = "chat-bubble-left"))]
+        Shape::ChatBubbleLeft,
+        #[cfg(
+            any(
+                feature = "chat-bubble-oval-left-ellipsis",
+                heroicon = "chat-bubble-oval-left-ellipsis"
+            )
+        )]
+        Shape::ChatBubbleOvalLeftEllipsis,
+        #[cfg(
+            any(feature = "chat-bubble-oval-left", heroicon = "chat-bubble-oval-left")
+        )]
+        Shape::ChatBubbleOvalLeft,
+        #[cfg(any(feature = "check-badge", heroicon = "check-badge"))]
+        Shape::CheckBadge,
+        #[cfg(any(feature = "check-circle", heroicon = "check-circle"))]
+        Shape::CheckCircle,
+        #[cfg(any(feature = "check", heroicon = "check"))]
+        Shape::Check,
+        #[cfg(any(feature = "chevron-double-down", heroicon = "chevron-double-down"))]
+        Shape::ChevronDoubleDown,
+        #[cfg(any(feature = "chevron-double-left", heroicon = "chevron-double-left"))]
+        Shape::ChevronDoubleLeft,
+        #[cfg(any(feature = "chevron-double-right", heroicon = "chevron-double-right"))]
+        Shape::ChevronDoubleRight,
+        #[cfg(any(feature = "chevron-double-up", heroicon = "chevron-double-up"))]
+        Shape::ChevronDoubleUp,
+        #[cfg(any(feature = "chevron-down", heroicon = "chevron-down"))]
+        Shape::ChevronDown,
+        #[cfg(any(feature = "chevron-left", heroicon = "chevron-left"))]
+        Shape::ChevronLeft,
+        #[cfg(any(feature = "chevron-right", heroicon = "chevron-right"))]
+        Shape::ChevronRight,
+        #[cfg(any(feature = "chevron-up-down", heroicon = "chevron-up-down"))]
+        Shape::ChevronUpDown,
+        #[cfg(any(feature = "chevron-up", heroicon = "chevron-up"))]
+        Shape::ChevronUp,
+        #[cfg(any(feature = "circle-stack", heroicon = "circle-stack"))]
+        Shape::CircleStack,
+        #[cfg(
+            any(
+                feature = "clipboard-document-check",
+                heroicon = "clipboard-document-check"
+            )
+        )]
+        Shape::ClipboardDocumentCheck,
+        #[cfg(
+            any(
+                feature = "clipboard-document-list",
+                heroicon = "clipboard-document-list"
+            )
+        )]
+        Shape::ClipboardDocumentList,
+        #[cfg(any(feature = "clipboard-document", heroicon = "clipboard-document"))]
+        Shape::ClipboardDocument,
+        #[cfg(any(feature = "clipboard", heroicon = "clipboard"))]
+        Shape::Clipboard,
+        #[cfg(any(feature = "clock", heroicon = "clock"))]
+        Shape::Clock,
+        #[cfg(any(feature = "cloud-arrow-down", heroicon = "cloud-arrow-down"))]
+        Shape::CloudArrowDown,
+        #[cfg(any(feature = "cloud-arrow-up", heroicon = "cloud-arrow-up"))]
+        Shape::CloudArrowUp,
+        #[cfg(any(feature = "cloud", heroicon = "cloud"))]
+        Shape::Cloud,
+        #[cfg(any(feature = "code-bracket-square", heroicon = "code-bracket-square"))]
+        Shape::CodeBracketSquare,
+        #[cfg(any(feature = "code-bracket", heroicon = "code-bracket"))]
+        Shape::CodeBracket,
+        #[cfg(any(feature = "cog-6-tooth", heroicon = "cog-6-tooth"))]
+        Shape::Cog6Tooth,
+        #[cfg(any(feature = "cog-8-tooth", heroicon = "cog-8-tooth"))]
+        Shape::Cog8Tooth,
+        #[cfg(any(feature = "cog", heroicon = "cog"))]
+        Shape::Cog,
+        #[cfg(any(feature = "command-line", heroicon = "command-line"))]
+        Shape::CommandLine,
+        #[cfg(any(feature = "computer-desktop", heroicon = "computer-desktop"))]
+        Shape::ComputerDesktop,
+        #[cfg(any(feature = "cpu-chip", heroicon = "cpu-chip"))]
+        Shape::CpuChip,
+        #[cfg(any(feature = "credit-card", heroicon = "credit-card"))]
+        Shape::CreditCard,
+        #[cfg(any(feature = "cube-transparent", heroicon = "cube-transparent"))]
+        Shape::CubeTransparent,
+        #[cfg(any(feature = "cube", heroicon = "cube"))]
+        Shape::Cube,
+        #[cfg(any(feature = "currency-bangladeshi", heroicon = "currency-bangladeshi"))]
+        Shape::CurrencyBangladeshi,
+        #[cfg(any(feature = "currency-dollar", heroicon = "currency-dollar"))]
+        Shape::CurrencyDollar,
+        #[cfg(any(feature = "currency-euro", heroicon = "currency-euro"))]
+        Shape::CurrencyEuro,
+        #[cfg(any(feature = "currency-pound", heroicon = "currency-pound"))]
+        Shape::CurrencyPound,
+        #[cfg(any(feature = "currency-rupee", heroicon = "currency-rupee"))]
+        Shape::CurrencyRupee,
+        #[cfg(any(feature = "currency-yen", heroicon = "currency-yen"))]
+        Shape::CurrencyYen,
+        #[cfg(any(feature = "cursor-arrow-rays", heroicon = "cursor-arrow-rays"))]
+        Shape::CursorArrowRays,
+        #[cfg(any(feature = "cursor-arrow-ripple", heroicon = "cursor-arrow-ripple"))]
+        Shape::CursorArrowRipple,
+        #[cfg(any(feature = "device-phone-mobile", heroicon = "device-phone-mobile"))]
+        Shape::DevicePhoneMobile,
+        #[cfg(any(feature = "device-tablet", heroicon = "device-tablet"))]
+        Shape::DeviceTablet,
+        #[cfg(any(feature = "document-arrow-down", heroicon = "document-arrow-down"))]
+        Shape::DocumentArrowDown,
+        #[cfg(any(feature = "document-arrow-up", heroicon = "document-arrow-up"))]
+        Shape::DocumentArrowUp,
+        #[cfg(any(feature = "document-chart-bar", heroicon = "document-chart-bar"))]
+        Shape::DocumentChartBar,
+        #[cfg(any(feature = "document-check", heroicon = "document-check"))]
+        Shape::DocumentCheck,
+        #[cfg(any(feature = "document-duplicate", heroicon = "document-duplicate"))]
+        Shape::DocumentDuplicate,
+        #[cfg(
+            any(
+                feature = "document-magnifying-glass",
+                heroicon = "document-magnifying-glass"
+            )
+        )]
+        Shape::DocumentMagnifyingGlass,
+        #[cfg(any(feature = "document-minus", heroicon = "document-minus"))]
+        Shape::DocumentMinus,
+        #[cfg(any(feature = "document-plus", heroicon = "document-plus"))]
+        Shape::DocumentPlus,
+        #[cfg(any(feature = "document-text", heroicon = "document-text"))]
+        Shape::DocumentText,
+        #[cfg(any(feature = "document", heroicon = "document"))]
+        Shape::Document,
+        #[cfg(
+            any(
+                feature = "ellipsis-horizontal-circle",
+                heroicon = "ellipsis-horizontal-circle"
+            )
+        )]
+        Shape::EllipsisHorizontalCircle,
+        #[cfg(any(feature = "ellipsis-horizontal", heroicon = "ellipsis-horizontal"))]
+        Shape::EllipsisHorizontal,
+        #[cfg(any(feature = "ellipsis-vertical", heroicon = "ellipsis-vertical"))]
+        Shape::EllipsisVertical,
+        #[cfg(any(feature = "envelope-open", heroicon = "envelope-open"))]
+        Shape::EnvelopeOpen,
+        #[cfg(any(feature = "envelope", heroicon = "envelope"))]
+        Shape::Envelope,
+        #[cfg(any(feature = "exclamation-circle", heroicon = "exclamation-circle"))]
+        Shape::ExclamationCircle,
+        #[cfg(any(feature = "exclamation-triangle", heroicon = "exclamation-triangle"))]
+        Shape::ExclamationTriangle,
+        #[cfg(any(feature = "eye-dropper", heroicon = "eye-dropper"))]
+        Shape::EyeDropper,
+        #[cfg(any(feature = "eye-slash", heroicon = "eye-slash"))]
+        Shape::EyeSlash,
+        #[cfg(any(feature = "eye", heroicon = "eye"))]
+        Shape::Eye,
+        #[cfg(any(feature = "face-frown", heroicon = "face-frown"))]
+        Shape::FaceFrown,
+        #[cfg(any(feature = "face-smile", heroicon = "face-smile"))]
+        Shape::FaceSmile,
+        #[cfg(any(feature = "film", heroicon = "film"))]
+        Shape::Film,
+        #[cfg(any(feature = "finger-print", heroicon = "finger-print"))]
+        Shape::FingerPrint,
+        #[cfg(any(feature = "fire", heroicon = "fire"))]
+        Shape::Fire,
+        #[cfg(any(feature = "flag", heroicon = "flag"))]
+        Shape::Flag,
+        #[cfg(any(feature = "folder-arrow-down", heroicon = "folder-arrow-down"))]
+        Shape::FolderArrowDown,
+        #[cfg(any(feature = "folder-minus", heroicon = "folder-minus"))]
+        Shape::FolderMinus,
+        #[cfg(any(feature = "folder-open", heroicon = "folder-open"))]
+        Shape::FolderOpen,
+        #[cfg(any(feature = "folder-plus", heroicon = "folder-plus"))]
+        Shape::FolderPlus,
+        #[cfg(any(feature = "folder", heroicon = "folder"))]
+        Shape::Folder,
+        #[cfg(any(feature = "forward", heroicon = "forward"))]
+        Shape::Forward,
+        #[cfg(any(feature = "funnel", heroicon = "funnel"))]
+        Shape::Funnel,
+        #[cfg(any(feature = "gif", heroicon = "gif"))]
+        Shape::Gif,
+        #[cfg(any(feature = "gift-top", heroicon = "gift-top"))]
+        Shape::GiftTop,
+        #[cfg(any(feature = "gift", heroicon = "gift"))]
+        Shape::Gift,
+        #[cfg(any(feature = "globe-alt", heroicon = "globe-alt"))]
+        Shape::GlobeAlt,
+        #[cfg(any(feature = "globe-americas", heroicon = "globe-americas"))]
+        Shape::GlobeAmericas,
+        #[cfg(any(feature = "globe-asia-australia", heroicon = "globe-asia-australia"))]
+        Shape::GlobeAsiaAustralia,
+        #[cfg(any(feature = "globe-europe-africa", heroicon = "globe-europe-africa"))]
+        Shape::GlobeEuropeAfrica,
+        #[cfg(any(feature = "hand-raised", heroicon = "hand-raised"))]
+        Shape::HandRaised,
+        #[cfg(any(feature = "hand-thumb-down", heroicon = "hand-thumb-down"))]
+        Shape::HandThumbDown,
+        #[cfg(any(feature = "hand-thumb-up", heroicon = "hand-thumb-up"))]
+        Shape::HandThumbUp,
+        #[cfg(any(feature = "hashtag", heroicon = "hashtag"))]
+        Shape::Hashtag,
+        #[cfg(any(feature = "heart", heroicon = "heart"))]
+        Shape::Heart,
+        #[cfg(any(feature = "home-modern", heroicon = "home-modern"))]
+        Shape::HomeModern,
+        #[cfg(any(feature = "home", heroicon = "home"))]
+        Shape::Home,
+        #[cfg(any(feature = "identification", heroicon = "identification"))]
+        Shape::Identification,
+        #[cfg(any(feature = "inbox-arrow-down", heroicon = "inbox-arrow-down"))]
+        Shape::InboxArrowDown,
+        #[cfg(any(feature = "inbox-stack", heroicon = "inbox-stack"))]
+        Shape::InboxStack,
+        #[cfg(any(feature = "inbox", heroicon = "inbox"))]
+        Shape::Inbox,
+        #[cfg(any(feature = "information-circle", heroicon = "information-circle"))]
+        Shape::InformationCircle,
+        #[cfg(any(feature = "key", heroicon = "key"))]
+        Shape::Key,
+        #[cfg(any(feature = "language", heroicon = "language"))]
+        Shape::Language,
+        #[cfg(any(feature = "lifebuoy", heroicon = "lifebuoy"))]
+        Shape::Lifebuoy,
+        #[cfg(any(feature = "light-bulb", heroicon = "light-bulb"))]
+        Shape::LightBulb,
+        #[cfg(any(feature = "link", heroicon = "link"))]
+        Shape::Link,
+        #[cfg(any(feature = "list-bullet", heroicon = "list-bullet"))]
+        Shape::ListBullet,
+        #[cfg(any(feature = "lock-closed", heroicon = "lock-closed"))]
+        Shape::LockClosed,
+        #[cfg(any(feature = "lock-open", heroicon = "lock-open"))]
+        Shape::LockOpen,
+        #[cfg(
+            any(
+                feature = "magnifying-glass-circle",
+                heroicon = "magnifying-glass-circle"
+            )
+        )]
+        Shape::MagnifyingGlassCircle,
+        #[cfg(
+            any(feature = "magnifying-glass-minus", heroicon = "magnifying-glass-minus")
+        )]
+        Shape::MagnifyingGlassMinus,
+        #[cfg(
+            any(feature = "magnifying-glass-plus", heroicon = "magnifying-glass-plus")
+        )]
+        Shape::MagnifyingGlassPlus,
+        #[cfg(any(feature = "magnifying-glass", heroicon = "magnifying-glass"))]
+        Shape::MagnifyingGlass,
+        #[cfg(any(feature = "map-pin", heroicon = "map-pin"))]
+        Shape::MapPin,
+        #[cfg(any(feature = "map", heroicon = "map"))]
+        Shape::Map,
+        #[cfg(any(feature = "megaphone", heroicon = "megaphone"))]
+        Shape::Megaphone,
+        #[cfg(any(feature = "microphone", heroicon = "microphone"))]
+        Shape::Microphone,
+        #[cfg(any(feature = "minus-circle", heroicon = "minus-circle"))]
+        Shape::MinusCircle,
+        #[cfg(any(feature = "minus-small", heroicon = "minus-small"))]
+        Shape::MinusSmall,
+        #[cfg(any(feature = "minus", heroicon = "minus"))]
+        Shape::Minus,
+        #[cfg(any(feature = "moon", heroicon = "moon"))]
+        Shape::Moon,
+        #[cfg(any(feature = "musical-note", heroicon = "musical-note"))]
+        Shape::MusicalNote,
+        #[cfg(any(feature = "newspaper", heroicon = "newspaper"))]
+        Shape::Newspaper,
+        #[cfg(any(feature = "no-symbol", heroicon = "no-symbol"))]
+        Shape::NoSymbol,
+        #[cfg(any(feature = "paint-brush", heroicon = "paint-brush"))]
+        Shape::PaintBrush,
+        #[cfg(any(feature = "paper-airplane", heroicon = "paper-airplane"))]
+        Shape::PaperAirplane,
+        #[cfg(any(feature = "paper-clip", heroicon = "paper-clip"))]
+        Shape::PaperClip,
+        #[cfg(any(feature = "pause-circle", heroicon = "pause-circle"))]
+        Shape::PauseCircle,
+        #[cfg(any(feature = "pause", heroicon = "pause"))]
+        Shape::Pause,
+        #[cfg(any(feature = "pencil-square", heroicon = "pencil-square"))]
+        Shape::PencilSquare,
+        #[cfg(any(feature = "pencil", heroicon = "pencil"))]
+        Shape::Pencil,
+        #[cfg(
+            any(feature = "phone-arrow-down-left", heroicon = "phone-arrow-down-left")
+        )]
+        Shape::PhoneArrowDownLeft,
+        #[cfg(any(feature = "phone-arrow-up-right", heroicon = "phone-arrow-up-right"))]
+        Shape::PhoneArrowUpRight,
+        #[cfg(any(feature = "phone-x-mark", heroicon = "phone-x-mark"))]
+        Shape::PhoneXMark,
+        #[cfg(any(feature = "phone", heroicon = "phone"))]
+        Shape::Phone,
+        #[cfg(any(feature = "photo", heroicon = "photo"))]
+        Shape::Photo,
+        #[cfg(any(feature = "play-circle", heroicon = "play-circle"))]
+        Shape::PlayCircle,
+        #[cfg(any(feature = "play-pause", heroicon = "play-pause"))]
+        Shape::PlayPause,
+        #[cfg(any(feature = "play", heroicon = "play"))]
+        Shape::Play,
+        #[cfg(any(feature = "plus-circle", heroicon = "plus-circle"))]
+        Shape::PlusCircle,
+        #[cfg(any(feature = "plus-small", heroicon = "plus-small"))]
+        Shape::PlusSmall,
+        #[cfg(any(feature = "plus", heroicon = "plus"))]
+        Shape::Plus,
+        #[cfg(any(feature = "power", heroicon = "power"))]
+        Shape::Power,
+        #[cfg(
+            any(feature = "presentation-chart-bar", heroicon = "presentation-chart-bar")
+        )]
+        Shape::PresentationChartBar,
+        #[cfg(
+            any(
+                feature = "presentation-chart-line",
+                heroicon = "presentation-chart-line"
+            )
+        )]
+        Shape::PresentationChartLine,
+        #[cfg(any(feature = "printer", heroicon = "printer"))]
+        Shape::Printer,
+        #[cfg(any(feature = "puzzle-piece", heroicon = "puzzle-piece"))]
+        Shape::PuzzlePiece,
+        #[cfg(any(feature = "qr-code", heroicon = "qr-code"))]
+        Shape::QrCode,
+        #[cfg(any(feature = "question-mark-circle", heroicon = "question-mark-circle"))]
+        Shape::QuestionMarkCircle,
+        #[cfg(any(feature = "queue-list", heroicon = "queue-list"))]
+        Shape::QueueList,
+        #[cfg(any(feature = "radio", heroicon = "radio"))]
+        Shape::Radio,
+        #[cfg(any(feature = "receipt-percent", heroicon = "receipt-percent"))]
+        Shape::ReceiptPercent,
+        #[cfg(any(feature = "receipt-refund", heroicon = "receipt-refund"))]
+        Shape::ReceiptRefund,
+        #[cfg(any(feature = "rectangle-group", heroicon = "rectangle-group"))]
+        Shape::RectangleGroup,
+        #[cfg(any(feature = "rectangle-stack", heroicon = "rectangle-stack"))]
+        Shape::RectangleStack,
+        #[cfg(any(feature = "rocket-launch", heroicon = "rocket-launch"))]
+        Shape::RocketLaunch,
+        #[cfg(any(feature = "rss", heroicon = "rss"))]
+        Shape::Rss,
+        #[cfg(any(feature = "scale", heroicon = "scale"))]
+        Shape::Scale,
+        #[cfg(any(feature = "scissors", heroicon = "scissors"))]
+        Shape::Scissors,
+        #[cfg(any(feature = "server-stack", heroicon = "server-stack"))]
+        Shape::ServerStack,
+        #[cfg(any(feature = "server", heroicon = "server"))]
+        Shape::Server,
+        #[cfg(any(feature = "share", heroicon = "share"))]
+        Shape::Share,
+        #[cfg(any(feature = "shield-check", heroicon = "shield-check"))]
+        Shape::ShieldCheck,
+        #[cfg(any(feature = "shield-exclamation", heroicon = "shield-exclamation"))]
+        Shape::ShieldExclamation,
+        #[cfg(any(feature = "shopping-bag", heroicon = "shopping-bag"))]
+        Shape::ShoppingBag,
+        #[cfg(any(feature = "shopping-cart", heroicon = "shopping-cart"))]
+        Shape::ShoppingCart,
+        #[cfg(any(feature = "signal-slash", heroicon = "signal-slash"))]
+        Shape::SignalSlash,
+        #[cfg(any(feature = "signal", heroicon = "signal"))]
+        Shape::Signal,
+        #[cfg(any(feature = "sparkles", heroicon = "sparkles"))]
+        Shape::Sparkles,
+        #[cfg(any(feature = "speaker-wave", heroicon = "speaker-wave"))]
+        Shape::SpeakerWave,
+        #[cfg(any(feature = "speaker-x-mark", heroicon = "speaker-x-mark"))]
+        Shape::SpeakerXMark,
+        #[cfg(any(feature = "square-2-stack", heroicon = "square-2-stack"))]
+        Shape::Square2Stack,
+        #[cfg(any(feature = "square-3-stack-3d", heroicon = "square-3-stack-3d"))]
+        Shape::Square3Stack3d,
+        #[cfg(any(feature = "squares-2x2", heroicon = "squares-2x2"))]
+        Shape::Squares2x2,
+        #[cfg(any(feature = "squares-plus", heroicon = "squares-plus"))]
+        Shape::SquaresPlus,
+        #[cfg(any(feature = "star", heroicon = "star"))]
+        Shape::Star,
+        #[cfg(any(feature = "stop-circle", heroicon = "stop-circle"))]
+        Shape::StopCircle,
+        #[cfg(any(feature = "stop", heroicon = "stop"))]
+        Shape::Stop,
+        #[cfg(any(feature = "sun", heroicon = "sun"))]
+        Shape::Sun,
+        #[cfg(any(feature = "swatch", heroicon = "swatch"))]
+        Shape::Swatch,
+        #[cfg(any(feature = "table-cells", heroicon = "table-cells"))]
+        Shape::TableCells,
+        #[cfg(any(feature = "tag", heroicon = "tag"))]
+        Shape::Tag,
+        #[cfg(any(feature = "ticket", heroicon = "ticket"))]
+        Shape::Ticket,
+        #[cfg(any(feature = "trash", heroicon = "trash"))]
+        Shape::Trash,
+        #[cfg(any(feature = "trophy", heroicon = "trophy"))]
+        Shape::Trophy,
+        #[cfg(any(feature = "truck", heroicon = "truck"))]
+        Shape::Truck,
+        #[cfg(any(feature = "tv", heroicon = "tv"))]
+        Shape::Tv,
+        #[cfg(any(feature = "user-circle", heroicon = "user-circle"))]
+        Shape::UserCircle,
+        #[cfg(any(feature = "user-group", heroicon = "user-group"))]
+        Shape::UserGroup,
+        #[cfg(any(feature = "user-minus", heroicon = "user-minus"))]
+        Shape::UserMinus,
+        #[cfg(any(feature = "user-plus", heroicon = "user-plus"))]
+        Shape::UserPlus,
+        #[cfg(any(feature = "user", heroicon = "user"))]
+        Shape::User,
+        #[cfg(any(feature = "users", heroicon = "users"))]
+        Shape::Users,
+        #[cfg(any(feature = "variable", heroicon = "variable"))]
+        Shape::Variable,
+        #[cfg(any(feature = "video-camera-slash", heroicon = "video-camera-slash"))]
+        Shape::VideoCameraSlash,
+        #[cfg(any(feature = "video-camera", heroicon = "video-camera"))]
+        Shape::VideoCamera,
+        #[cfg(any(feature = "view-columns", heroicon = "view-columns"))]
+        Shape::ViewColumns,
+        #[cfg(any(feature = "viewfinder-circle", heroicon = "viewfinder-circle"))]
+        Shape::ViewfinderCircle,
+        #[cfg(any(feature = "wallet", heroicon = "wallet"))]
+        Shape::Wallet,
+        #[cfg(any(feature = "wifi", heroicon = "wifi"))]
+        Shape::Wifi,
+        #[cfg(any(feature = "window", heroicon = "window"))]
+        Shape::Window,
+        #[cfg(any(feature = "wrench-screwdriver", heroicon = "wrench-screwdriver"))]
+        Shape::WrenchScrewdriver,
+        #[cfg(any(feature = "wrench", heroicon = "wrench"))]
+        Shape::Wrench,
+        #[cfg(any(feature = "x-circle", heroicon = "x-circle"))]
+        Shape::XCircle,
+        #[cfg(any(feature = "x-mark", heroicon = "x-mark"))]
+        Shape::XMark,
+    ];
+    /// Returns the original name for the shape, like "arrow-left".
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        self.entry().1.name()
+    }
+    /// Returns the `viewBox` for the shape's `<svg>` element.
+    #[must_use]
+    pub fn view_box(self) -> &'static str {
+        VIEW_BOX
+    }
+    /// Returns the raw data for each of the shape's `<path>` elements.
+    #[must_use]
+    pub fn path_data(self) -> &'static [PathData] {
+        self.entry().1.path_data()
+    }
+    /// Returns the shape's search tags, which are other words for what it shows, like "garbage"
+    /// for `Trash`. Many shapes don't have any.
+    #[must_use]
+    pub fn tags(self) -> &'static [&'static str] {
+        self.entry().2
+    }
+    /// Returns true if the shape points or reads in a direction, like `ArrowLeft` or `ListBullet`,
+    /// so that it should be mirrored in a right-to-left layout.
+    #[must_use]
+    pub fn is_directional(self) -> bool {
+        self.entry().3
+    }
+    /// Returns the [`StaticShape`] for the shape.
+    #[must_use]
+    pub fn static_shape(self) -> StaticShape {
+        *self.entry().1
+    }
+    /// Returns the shape's discriminant, which stays the same when new icons are added.
+    #[must_use]
+    pub fn discriminant(self) -> u32 {
+        self as u32
+    }
+    /// Returns the shape with a discriminant from [`Shape::discriminant`], or `None` if there's no
+    /// such shape or its feature is off.
+    #[must_use]
+    pub fn from_discriminant(discriminant: u32) -> Option<Shape> {
+        ENTRIES.get(discriminant as usize).copied().flatten().map(|e| e.0)
+    }
+    /// Returns the shape's entry. Its slot is only `None` when its feature is off, and then the
+    /// shape doesn't exist.
+    fn entry(self) -> Entry {
+        ENTRIES[self as usize].expect("every shape has an entry")
+    }
+}
+/// A shape, its static, its tags, and whether it's directional. The statics are split into one
+/// module per initial letter, which keeps each file small.
+type Entry = (Shape, &'static StaticShape, &'static [&'static str], bool);
+/// Each shape's entry, indexed by its discriminant. The slots for the discriminants of icons that
+/// heroicons removed, and of shapes whose features are off, are `None`.
+static ENTRIES: &[Option<Entry>] = &[
+    #[cfg(any(feature = "academic-cap", heroicon = "academic-cap"))]
+    Some((Shape::AcademicCap, &a::ACADEMIC_CAP, &[], false)),
+    #[cfg(not(any(feature = "academic-cap", heroicon = "academic-cap")))]
+    None,
+    #[cfg(any(feature = "adjustments-horizontal", heroicon = "adjustments-horizontal"))]
+    Some((Shape::AdjustmentsHorizontal, &a::ADJUSTMENTS_HORIZONTAL, &[], false)),
+    #[cfg(
+        not(any(feature = "adjustments-horizontal", heroicon = "adjustments-horizontal"))
+    )]
+    None,
+    #[cfg(any(feature = "adjustments-vertical", heroicon = "adjustments-vertical"))]
+    Some((Shape::AdjustmentsVertical, &a::ADJUSTMENTS_VERTICAL, &[], false)),
+    #[cfg(not(any(feature = "adjustments-vertical", heroicon = "adjustments-vertical")))]
+    None,
+    #[cfg(any(feature = "archive-box-arrow-down", heroicon = "archive-box-arrow-down"))]
+    Some((Shape::ArchiveBoxArrowDown, &a::ARCHIVE_BOX_ARROW_DOWN, &[], false)),
+    #[cfg(
+        not(any(feature = "archive-box-arrow-down", heroicon = "archive-box-arrow-down"))
+    )]
+    None,
+    #[cfg(any(feature = "archive-box-x-mark", heroicon = "archive-box-x-mark"))]
+    Some((Shape::ArchiveBoxXMark, &a::ARCHIVE_BOX_X_MARK, &[], false)),
+    #[cfg(not(any(feature = "archive-box-x-mark", heroicon = "archive-box-x-mark")))]
+    None,
+    #[cfg(any(feature = "archive-box", heroicon = "archive-box"))]
+    Some((Shape::ArchiveBox, &a::ARCHIVE_BOX, &[], false)),
+    #[cfg(not(any(feature = "archive-box", heroicon = "archive-box")))]
+    None,
+    #[cfg(any(feature = "arrow-down-circle", heroicon = "arrow-down-circle"))]
+    Some((Shape::ArrowDownCircle, &a::ARROW_DOWN_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "arrow-down-circle", heroicon = "arrow-down-circle")))]
+    None,
+    #[cfg(any(feature = "arrow-down-left", heroicon = "arrow-down-left"))]
+    Some((Shape::ArrowDownLeft, &a::ARROW_DOWN_LEFT, &[], true)),
+    #[cfg(not(any(feature = "arrow-down-left", heroicon = "arrow-down-left")))]
+    None,
+    #[cfg(
+        any(
+            feature = "arrow-down-on-square-stack",
+            heroicon = "arrow-down-on-square-stack"
+        )
+    )]
+    Some((Shape::ArrowDownOnSquareStack, &a::ARROW_DOWN_ON_SQUARE_STACK, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "arrow-down-on-square-stack",
+                heroicon = "arrow-down-on-square-stack"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "arrow-down-on-square", heroicon = "arrow-down-on-square"))]
+    Some((Shape::ArrowDownOnSquare, &a::ARROW_DOWN_ON_SQUARE, &[], false)),
+    #[cfg(not(any(feature = "arrow-down-on-square", heroicon = "arrow-down-on-square")))]
+    None,
+    #[cfg(any(feature = "arrow-down-right", heroicon = "arrow-down-right"))]
+    Some((Shape::ArrowDownRight, &a::ARROW_DOWN_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "arrow-down-right", heroicon = "arrow-down-right")))]
+    None,
+    #[cfg(any(feature = "arrow-down-tray", heroicon = "arrow-down-tray"))]
+    Some((Shape::ArrowDownTray, &a::ARROW_DOWN_TRAY, &["download"], false)),
+    #[cfg(not(any(feature = "arrow-down-tray", heroicon = "arrow-down-tray")))]
+    None,
+    #[cfg(any(feature = "arrow-down", heroicon = "arrow-down"))]
+    Some((Shape::ArrowDown, &a::ARROW_DOWN, &[], false)),
+    #[cfg(not(any(feature = "arrow-down", heroicon = "arrow-down")))]
+    None,
+    #[cfg(any(feature = "arrow-left-circle", heroicon = "arrow-left-circle"))]
+    Some((Shape::ArrowLeftCircle, &a::ARROW_LEFT_CIRCLE, &[], true)),
+    #[cfg(not(any(feature = "arrow-left-circle", heroicon = "arrow-left-circle")))]
+    None,
+    #[cfg(
+        any(feature = "arrow-left-on-rectangle", heroicon = "arrow-left-on-rectangle")
+    )]
+    Some((Shape::ArrowLeftOnRectangle, &a::ARROW_LEFT_ON_RECTANGLE, &[], true)),
+    #[cfg(
+        not(
+            any(
+                feature = "arrow-left-on-rectangle",
+                heroicon = "arrow-left-on-rectangle"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "arrow-left", heroicon = "arrow-left"))]
+    Some((Shape::ArrowLeft, &a::ARROW_LEFT, &[], true)),
+    #[cfg(not(any(feature = "arrow-left", heroicon = "arrow-left")))]
+    None,
+    #[cfg(any(feature = "arrow-long-down", heroicon = "arrow-long-down"))]
+    Some((Shape::ArrowLongDown, &a::ARROW_LONG_DOWN, &[], false)),
+    #[cfg(not(any(feature = "arrow-long-down", heroicon = "arrow-long-down")))]
+    None,
+    #[cfg(any(feature = "arrow-long-left", heroicon = "arrow-long-left"))]
+    Some((Shape::ArrowLongLeft, &a::ARROW_LONG_LEFT, &[], true)),
+    #[cfg(not(any(feature = "arrow-long-left", heroicon = "arrow-long-left")))]
+    None,
+    #[cfg(any(feature = "arrow-long-right", heroicon = "arrow-long-right"))]
+    Some((Shape::ArrowLongRight, &a::ARROW_LONG_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "arrow-long-right", heroicon = "arrow-long-right")))]
+    None,
+    #[cfg(any(feature = "arrow-long-up", heroicon = "arrow-long-up"))]
+    Some((Shape::ArrowLongUp, &a::ARROW_LONG_UP, &[], false)),
+    #[cfg(not(any(feature = "arrow-long-up", heroicon = "arrow-long-up")))]
+    None,
+    #[cfg(
+        any(
+            feature = "arrow-path-rounded-square",
+            heroicon = "arrow-path-rounded-square"
+        )
+    )]
+    Some((Shape::ArrowPathRoundedSquare, &a::ARROW_PATH_ROUNDED_SQUARE, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "arrow-path-rounded-square",
+                heroicon = "arrow-path-rounded-square"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "arrow-path", heroicon = "arrow-path"))]
+    Some((
+        Shape::ArrowPath,
+        &a::ARROW_PATH,
+        &["refresh", "reload", "retry", "sync"],
+        false,
+    )),
+    #[cfg(not(any(feature = "arrow-path", heroicon = "arrow-path")))]
+    None,
+    #[cfg(any(feature = "arrow-right-circle", heroicon = "arrow-right-circle"))]
+    Some((Shape::ArrowRightCircle, &a::ARROW_RIGHT_CIRCLE, &[], true)),
+    #[cfg(not(any(feature = "arrow-right-circle", heroicon = "arrow-right-circle")))]
+    None,
+    #[cfg(
+        any(feature = "arrow-right-on-rectangle", heroicon = "arrow-right-on-rectangle")
+    )]
+    Some((Shape::ArrowRightOnRectangle, &a::ARROW_RIGHT_ON_RECTANGLE, &[], true)),
+    #[cfg(
+        not(
+            any(
+                feature = "arrow-right-on-rectangle",
+                heroicon = "arrow-right-on-rectangle"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "arrow-right", heroicon = "arrow-right"))]
+    Some((Shape::ArrowRight, &a::ARROW_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "arrow-right", heroicon = "arrow-right")))]
+    None,
+    #[cfg(any(feature = "arrow-small-down", heroicon = "arrow-small-down"))]
+    Some((Shape::ArrowSmallDown, &a::ARROW_SMALL_DOWN, &[], false)),
+    #[cfg(not(any(feature = "arrow-small-down", heroicon = "arrow-small-down")))]
+    None,
+    #[cfg(any(feature = "arrow-small-left", heroicon = "arrow-small-left"))]
+    Some((Shape::ArrowSmallLeft, &a::ARROW_SMALL_LEFT, &[], true)),
+    #[cfg(not(any(feature = "arrow-small-left", heroicon = "arrow-small-left")))]
+    None,
+    #[cfg(any(feature = "arrow-small-right", heroicon = "arrow-small-right"))]
+    Some((Shape::ArrowSmallRight, &a::ARROW_SMALL_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "arrow-small-right", heroicon = "arrow-small-right")))]
+    None,
+    #[cfg(any(feature = "arrow-small-up", heroicon = "arrow-small-up"))]
+    Some((Shape::ArrowSmallUp, &a::ARROW_SMALL_UP, &[], false)),
+    #[cfg(not(any(feature = "arrow-small-up", heroicon = "arrow-small-up")))]
+    None,
+    #[cfg(
+        any(
+            feature = "arrow-top-right-on-square",
+            heroicon = "arrow-top-right-on-square"
+        )
+    )]
+    Some((Shape::ArrowTopRightOnSquare, &a::ARROW_TOP_RIGHT_ON_SQUARE, &[], true)),
+    #[cfg(
+        not(
+            any(
+                feature = "arrow-top-right-on-square",
+                heroicon = "arrow-top-right-on-square"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "arrow-trending-down", heroicon = "arrow-trending-down"))]
+    Some((Shape::ArrowTrendingDown, &a::ARROW_TRENDING_DOWN, &[], true)),
+    #[cfg(not(any(feature = "arrow-trending-down", heroicon = "arrow-trending-down")))]
+    None,
+    #[cfg(any(feature = "arrow-trending-up", heroicon = "arrow-trending-up"))]
+    Some((Shape::ArrowTrendingUp, &a::ARROW_TRENDING_UP, &[], true)),
+    #[cfg(not(any(feature = "arrow-trending-up", heroicon = "arrow-trending-up")))]
+    None,
+    #[cfg(any(feature = "arrow-up-circle", heroicon = "arrow-up-circle"))]
+    Some((Shape::ArrowUpCircle, &a::ARROW_UP_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "arrow-up-circle", heroicon = "arrow-up-circle")))]
+    None,
+    #[cfg(any(feature = "arrow-up-left", heroicon = "arrow-up-left"))]
+    Some((Shape::ArrowUpLeft, &a::ARROW_UP_LEFT, &[], true)),
+    #[cfg(not(any(feature = "arrow-up-left", heroicon = "arrow-up-left")))]
+    None,
+    #[cfg(
+        any(feature = "arrow-up-on-square-stack", heroicon = "arrow-up-on-square-stack")
+    )]
+    Some((Shape::ArrowUpOnSquareStack, &a::ARROW_UP_ON_SQUARE_STACK, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "arrow-up-on-square-stack",
+                heroicon = "arrow-up-on-square-stack"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "arrow-up-on-square", heroicon = "arrow-up-on-square"))]
+    Some((Shape::ArrowUpOnSquare, &a::ARROW_UP_ON_SQUARE, &[], false)),
+    #[cfg(not(any(feature = "arrow-up-on-square", heroicon = "arrow-up-on-square")))]
+    None,
+    #[cfg(any(feature = "arrow-up-right", heroicon = "arrow-up-right"))]
+    Some((Shape::ArrowUpRight, &a::ARROW_UP_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "arrow-up-right", heroicon = "arrow-up-right")))]
+    None,
+    #[cfg(any(feature = "arrow-up-tray", heroicon = "arrow-up-tray"))]
+    Some((Shape::ArrowUpTray, &a::ARROW_UP_TRAY, &["upload"], false)),
+    #[cfg(not(any(feature = "arrow-up-tray", heroicon = "arrow-up-tray")))]
+    None,
+    #[cfg(any(feature = "arrow-up", heroicon = "arrow-up"))]
+    Some((Shape::ArrowUp, &a::ARROW_UP, &[], false)),
+    #[cfg(not(any(feature = "arrow-up", heroicon = "arrow-up")))]
+    None,
+    #[cfg(any(feature = "arrow-uturn-down", heroicon = "arrow-uturn-down"))]
+    Some((Shape::ArrowUturnDown, &a::ARROW_UTURN_DOWN, &[], false)),
+    #[cfg(not(any(feature = "arrow-uturn-down", heroicon = "arrow-uturn-down")))]
+    None,
+    #[cfg(any(feature = "arrow-uturn-left", heroicon = "arrow-uturn-left"))]
+    Some((Shape::ArrowUturnLeft, &a::ARROW_UTURN_LEFT, &[], true)),
+    #[cfg(not(any(feature = "arrow-uturn-left", heroicon = "arrow-uturn-left")))]
+    None,
+    #[cfg(any(feature = "arrow-uturn-right", heroicon = "arrow-uturn-right"))]
+    Some((Shape::ArrowUturnRight, &a::ARROW_UTURN_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "arrow-uturn-right", heroicon = "arrow-uturn-right")))]
+    None,
+    #[cfg(any(feature = "arrow-uturn-up", heroicon = "arrow-uturn-up"))]
+    Some((Shape::ArrowUturnUp, &a::ARROW_UTURN_UP, &[], false)),
+    #[cfg(not(any(feature = "arrow-uturn-up", heroicon = "arrow-uturn-up")))]
+    None,
+    #[cfg(any(feature = "arrows-pointing-in", heroicon = "arrows-pointing-in"))]
+    Some((Shape::ArrowsPointingIn, &a::ARROWS_POINTING_IN, &[], false)),
+    #[cfg(not(any(feature = "arrows-pointing-in", heroicon = "arrows-pointing-in")))]
+    None,
+    #[cfg(any(feature = "arrows-pointing-out", heroicon = "arrows-pointing-out"))]
+    Some((Shape::ArrowsPointingOut, &a::ARROWS_POINTING_OUT, &[], false)),
+    #[cfg(not(any(feature = "arrows-pointing-out", heroicon = "arrows-pointing-out")))]
+    None,
+    #[cfg(any(feature = "arrows-right-left", heroicon = "arrows-right-left"))]
+    Some((Shape::ArrowsRightLeft, &a::ARROWS_RIGHT_LEFT, &[], false)),
+    #[cfg(not(any(feature = "arrows-right-left", heroicon = "arrows-right-left")))]
+    None,
+    #[cfg(any(feature = "arrows-up-down", heroicon = "arrows-up-down"))]
+    Some((Shape::ArrowsUpDown, &a::ARROWS_UP_DOWN, &[], false)),
+    #[cfg(not(any(feature = "arrows-up-down", heroicon = "arrows-up-down")))]
+    None,
+    #[cfg(any(feature = "at-symbol", heroicon = "at-symbol"))]
+    Some((Shape::AtSymbol, &a::AT_SYMBOL, &[], false)),
+    #[cfg(not(any(feature = "at-symbol", heroicon = "at-symbol")))]
+    None,
+    #[cfg(any(feature = "backspace", heroicon = "backspace"))]
+    Some((Shape::Backspace, &b::BACKSPACE, &[], true)),
+    #[cfg(not(any(feature = "backspace", heroicon = "backspace")))]
+    None,
+    #[cfg(any(feature = "backward", heroicon = "backward"))]
+    Some((Shape::Backward, &b::BACKWARD, &[], false)),
+    #[cfg(not(any(feature = "backward", heroicon = "backward")))]
+    None,
+    #[cfg(any(feature = "banknotes", heroicon = "banknotes"))]
+    Some((Shape::Banknotes, &b::BANKNOTES, &[], false)),
+    #[cfg(not(any(feature = "banknotes", heroicon = "banknotes")))]
+    None,
+    #[cfg(any(feature = "bars-2", heroicon = "bars-2"))]
+    Some((Shape::Bars2, &b::BARS_2, &[], false)),
+    #[cfg(not(any(feature = "bars-2", heroicon = "bars-2")))]
+    None,
+    #[cfg(any(feature = "bars-3-bottom-left", heroicon = "bars-3-bottom-left"))]
+    Some((Shape::Bars3BottomLeft, &b::BARS_3_BOTTOM_LEFT, &[], true)),
+    #[cfg(not(any(feature = "bars-3-bottom-left", heroicon = "bars-3-bottom-left")))]
+    None,
+    #[cfg(any(feature = "bars-3-bottom-right", heroicon = "bars-3-bottom-right"))]
+    Some((Shape::Bars3BottomRight, &b::BARS_3_BOTTOM_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "bars-3-bottom-right", heroicon = "bars-3-bottom-right")))]
+    None,
+    #[cfg(any(feature = "bars-3-center-left", heroicon = "bars-3-center-left"))]
+    Some((Shape::Bars3CenterLeft, &b::BARS_3_CENTER_LEFT, &[], true)),
+    #[cfg(not(any(feature = "bars-3-center-left", heroicon = "bars-3-center-left")))]
+    None,
+    #[cfg(any(feature = "bars-3", heroicon = "bars-3"))]
+    Some((Shape::Bars3, &b::BARS_3, &["hamburger", "menu"], false)),
+    #[cfg(not(any(feature = "bars-3", heroicon = "bars-3")))]
+    None,
+    #[cfg(any(feature = "bars-4", heroicon = "bars-4"))]
+    Some((Shape::Bars4, &b::BARS_4, &[], false)),
+    #[cfg(not(any(feature = "bars-4", heroicon = "bars-4")))]
+    None,
+    #[cfg(any(feature = "bars-arrow-down", heroicon = "bars-arrow-down"))]
+    Some((Shape::BarsArrowDown, &b::BARS_ARROW_DOWN, &[], false)),
+    #[cfg(not(any(feature = "bars-arrow-down", heroicon = "bars-arrow-down")))]
+    None,
+    #[cfg(any(feature = "bars-arrow-up", heroicon = "bars-arrow-up"))]
+    Some((Shape::BarsArrowUp, &b::BARS_ARROW_UP, &[], false)),
+    #[cfg(not(any(feature = "bars-arrow-up", heroicon = "bars-arrow-up")))]
+    None,
+    #[cfg(any(feature = "battery-0", heroicon = "battery-0"))]
+    Some((Shape::Battery0, &b::BATTERY_0, &[], false)),
+    #[cfg(not(any(feature = "battery-0", heroicon = "battery-0")))]
+    None,
+    #[cfg(any(feature = "battery-100", heroicon = "battery-100"))]
+    Some((Shape::Battery100, &b::BATTERY_100, &[], false)),
+    #[cfg(not(any(feature = "battery-100", heroicon = "battery-100")))]
+    None,
+    #[cfg(any(feature = "battery-50", heroicon = "battery-50"))]
+    Some((Shape::Battery50, &b::BATTERY_50, &[], false)),
+    #[cfg(not(any(feature = "battery-50", heroicon = "battery-50")))]
+    None,
+    #[cfg(any(feature = "beaker", heroicon = "beaker"))]
+    Some((Shape::Beaker, &b::BEAKER, &[], false)),
+    #[cfg(not(any(feature = "beaker", heroicon = "beaker")))]
+    None,
+    #[cfg(any(feature = "bell-alert", heroicon = "bell-alert"))]
+    Some((Shape::BellAlert, &b::BELL_ALERT, &[], false)),
+    #[cfg(not(any(feature = "bell-alert", heroicon = "bell-alert")))]
+    None,
+    #[cfg(any(feature = "bell-slash", heroicon = "bell-slash"))]
+    Some((Shape::BellSlash, &b::BELL_SLASH, &[], false)),
+    #[cfg(not(any(feature = "bell-slash", heroicon = "bell-slash")))]
+    None,
+    #[cfg(any(feature = "bell-snooze", heroicon = "bell-snooze"))]
+    Some((Shape::BellSnooze, &b::BELL_SNOOZE, &[], false)),
+    #[cfg(not(any(feature = "bell-snooze", heroicon = "bell-snooze")))]
+    None,
+    #[cfg(any(feature = "bell", heroicon = "bell"))]
+    Some((Shape::Bell, &b::BELL, &["alert", "notification"], false)),
+    #[cfg(not(any(feature = "bell", heroicon = "bell")))]
+    None,
+    #[cfg(any(feature = "bolt-slash", heroicon = "bolt-slash"))]
+    Some((Shape::BoltSlash, &b::BOLT_SLASH, &[], false)),
+    #[cfg(not(any(feature = "bolt-slash", heroicon = "bolt-slash")))]
+    None,
+    #[cfg(any(feature = "bolt", heroicon = "bolt"))]
+    Some((Shape::Bolt, &b::BOLT, &[], false)),
+    #[cfg(not(any(feature = "bolt", heroicon = "bolt")))]
+    None,
+    #[cfg(any(feature = "book-open", heroicon = "book-open"))]
+    Some((Shape::BookOpen, &b::BOOK_OPEN, &[], false)),
+    #[cfg(not(any(feature = "book-open", heroicon = "book-open")))]
+    None,
+    #[cfg(any(feature = "bookmark-slash", heroicon = "bookmark-slash"))]
+    Some((Shape::BookmarkSlash, &b::BOOKMARK_SLASH, &[], false)),
+    #[cfg(not(any(feature = "bookmark-slash", heroicon = "bookmark-slash")))]
+    None,
+    #[cfg(any(feature = "bookmark-square", heroicon = "bookmark-square"))]
+    Some((Shape::BookmarkSquare, &b::BOOKMARK_SQUARE, &[], false)),
+    #[cfg(not(any(feature = "bookmark-square", heroicon = "bookmark-square")))]
+    None,
+    #[cfg(any(feature = "bookmark", heroicon = "bookmark"))]
+    Some((Shape::Bookmark, &b::BOOKMARK, &[], false)),
+    #[cfg(not(any(feature = "bookmark", heroicon = "bookmark")))]
+    None,
+    #[cfg(any(feature = "briefcase", heroicon = "briefcase"))]
+    Some((Shape::Briefcase, &b::BRIEFCASE, &[], false)),
+    #[cfg(not(any(feature = "briefcase", heroicon = "briefcase")))]
+    None,
+    #[cfg(any(feature = "bug-ant", heroicon = "bug-ant"))]
+    Some((Shape::BugAnt, &b::BUG_ANT, &[], false)),
+    #[cfg(not(any(feature = "bug-ant", heroicon = "bug-ant")))]
+    None,
+    #[cfg(any(feature = "building-library", heroicon = "building-library"))]
+    Some((Shape::BuildingLibrary, &b::BUILDING_LIBRARY, &[], false)),
+    #[cfg(not(any(feature = "building-library", heroicon = "building-library")))]
+    None,
+    #[cfg(any(feature = "building-office-2", heroicon = "building-office-2"))]
+    Some((Shape::BuildingOffice2, &b::BUILDING_OFFICE_2, &[], false)),
+    #[cfg(not(any(feature = "building-office-2", heroicon = "building-office-2")))]
+    None,
+    #[cfg(any(feature = "building-office", heroicon = "building-office"))]
+    Some((Shape::BuildingOffice, &b::BUILDING_OFFICE, &[], false)),
+    #[cfg(not(any(feature = "building-office", heroicon = "building-office")))]
+    None,
+    #[cfg(any(feature = "building-storefront", heroicon = "building-storefront"))]
+    Some((Shape::BuildingStorefront, &b::BUILDING_STOREFRONT, &[], false)),
+    #[cfg(not(any(feature = "building-storefront", heroicon = "building-storefront")))]
+    None,
+    #[cfg(any(feature = "cake", heroicon = "cake"))]
+    Some((Shape::Cake, &c::CAKE, &[], false)),
+    #[cfg(not(any(feature = "cake", heroicon = "cake")))]
+    None,
+    #[cfg(any(feature = "calculator", heroicon = "calculator"))]
+    Some((Shape::Calculator, &c::CALCULATOR, &[], false)),
+    #[cfg(not(any(feature = "calculator", heroicon = "calculator")))]
+    None,
+    #[cfg(any(feature = "calendar-days", heroicon = "calendar-days"))]
+    Some((Shape::CalendarDays, &c::CALENDAR_DAYS, &[], false)),
+    #[cfg(not(any(feature = "calendar-days", heroicon = "calendar-days")))]
+    None,
+    #[cfg(any(feature = "calendar", heroicon = "calendar"))]
+    Some((Shape::Calendar, &c::CALENDAR, &["date", "schedule"], false)),
+    #[cfg(not(any(feature = "calendar", heroicon = "calendar")))]
+    None,
+    #[cfg(any(feature = "camera", heroicon = "camera"))]
+    Some((Shape::Camera, &c::CAMERA, &[], false)),
+    #[cfg(not(any(feature = "camera", heroicon = "camera")))]
+    None,
+    #[cfg(any(feature = "chart-bar-square", heroicon = "chart-bar-square"))]
+    Some((Shape::ChartBarSquare, &c::CHART_BAR_SQUARE, &[], false)),
+    #[cfg(not(any(feature = "chart-bar-square", heroicon = "chart-bar-square")))]
+    None,
+    #[cfg(any(feature = "chart-bar", heroicon = "chart-bar"))]
+    Some((Shape::ChartBar, &c::CHART_BAR, &[], false)),
+    #[cfg(not(any(feature = "chart-bar", heroicon = "chart-bar")))]
+    None,
+    #[cfg(any(feature = "chart-pie", heroicon = "chart-pie"))]
+    Some((Shape::ChartPie, &c::CHART_PIE, &[], false)),
+    #[cfg(not(any(feature = "chart-pie", heroicon = "chart-pie")))]
+    None,
+    #[cfg(
+        any(
+            feature = "chat-bubble-bottom-center-text",
+            heroicon = "chat-bubble-bottom-center-text"
+        )
+    )]
+    Some((
+        Shape::ChatBubbleBottomCenterText,
+        &c::CHAT_BUBBLE_BOTTOM_CENTER_TEXT,
+        &[],
+        false,
+    )),
+    #[cfg(
+        not(
+            any(
+                feature = "chat-bubble-bottom-center-text",
+                heroicon = "chat-bubble-bottom-center-text"
+            )
+        )
+    )]
+    None,
+    #[cfg(
+        any(
+            feature = "chat-bubble-bottom-center",
+            heroicon = "chat-bubble-bottom-center"
+        )
+    )]
+    Some((Shape::ChatBubbleBottomCenter, &c::CHAT_BUBBLE_BOTTOM_CENTER, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "chat-bubble-bottom-center",
+                heroicon = "chat-bubble-bottom-center"
+            )
+        )
+    )]
+    None,
+    #[cfg(
+        any(
+            feature = "chat-bubble-left-ellipsis",
+            heroicon = "chat-bubble-left-ellipsis"
+        )
+    )]
+    Some((Shape::ChatBubbleLeftEllipsis, &c::CHAT_BUBBLE_LEFT_ELLIPSIS, &[], true)),
+    #[cfg(
+        not(
+            any(
+                feature = "chat-bubble-left-ellipsis",
+                heroicon = "chat-bubble-left-ellipsis"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "chat-bubble-left-right", heroicon = "chat-bubble-left-right"))]
+    Some((Shape::ChatBubbleLeftRight, &c::CHAT_BUBBLE_LEFT_RIGHT, &[], true)),
+    #[cfg(
+        not(any(feature = "chat-bubble-left-right", heroicon = "chat-bubble-left-right"))
+    )]
+    None,
+    #[cfg(any(feature = "chat-bubble-left", heroicon = "chat-bubble-left"))]
+    Some((
+        Shape::ChatBubbleLeft,
+        &c::CHAT_BUBBLE_LEFT,
+        &["chat", "comment", "message"],
+        true,
+    )),
+    #[cfg(not(any(feature = "chat-bubble-left", heroicon = "chat-bubble-left")))]
+    None,
+    #[cfg(
+        any(
+            feature = "chat-bubble-oval-left-ellipsis",
+            heroicon = "chat-bubble-oval-left-ellipsis"
+        )
+    )]
+    Some((
+        Shape::ChatBubbleOvalLeftEllipsis,
+        &c::CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS,
+        &[],
+        true,
+    )),
+    #[cfg(
+        not(
+            any(
+                feature = "chat-bubble-oval-left-ellipsis",
+                heroicon = "chat-bubble-oval-left-ellipsis"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "chat-bubble-oval-left", heroicon = "chat-bubble-oval-left"))]
+    Some((Shape::ChatBubbleOvalLeft, &c::CHAT_BUBBLE_OVAL_LEFT, &[], true)),
+    #[cfg(
+        not(any(feature = "chat-bubble-oval-left", heroicon = "chat-bubble-oval-left"))
+    )]
+    None,
+    #[cfg(any(feature = "check-badge", heroicon = "check-badge"))]
+    Some((Shape::CheckBadge, &c::CHECK_BADGE, &[], false)),
+    #[cfg(not(any(feature = "check-badge", heroicon = "check-badge")))]
+    None,
+    #[cfg(any(feature = "check-circle", heroicon = "check-circle"))]
+    Some((Shape::CheckCircle, &c::CHECK_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "check-circle", heroicon = "check-circle")))]
+    None,
+    #[cfg(any(feature = "check", heroicon = "check"))]
+    Some((Shape::Check, &c::CHECK, &["confirm", "done", "tick"], false)),
+    #[cfg(not(any(feature = "check", heroicon = "check")))]
+    None,
+    #[cfg(any(feature = "chevron-double-down", heroicon = "chevron-double-down"))]
+    Some((Shape::ChevronDoubleDown, &c::CHEVRON_DOUBLE_DOWN, &[], false)),
+    #[cfg(not(any(feature = "chevron-double-down", heroicon = "chevron-double-down")))]
+    None,
+    #[cfg(any(feature = "chevron-double-left", heroicon = "chevron-double-left"))]
+    Some((Shape::ChevronDoubleLeft, &c::CHEVRON_DOUBLE_LEFT, &[], true)),
+    #[cfg(not(any(feature = "chevron-double-left", heroicon = "chevron-double-left")))]
+    None,
+    #[cfg(any(feature = "chevron-double-right", heroicon = "chevron-double-right"))]
+    Some((Shape::ChevronDoubleRight, &c::CHEVRON_DOUBLE_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "chevron-double-right", heroicon = "chevron-double-right")))]
+    None,
+    #[cfg(any(feature = "chevron-double-up", heroicon = "chevron-double-up"))]
+    Some((Shape::ChevronDoubleUp, &c::CHEVRON_DOUBLE_UP, &[], false)),
+    #[cfg(not(any(feature = "chevron-double-up", heroicon = "chevron-double-up")))]
+    None,
+    #[cfg(any(feature = "chevron-down", heroicon = "chevron-down"))]
+    Some((Shape::ChevronDown, &c::CHEVRON_DOWN, &[], false)),
+    #[cfg(not(any(feature = "chevron-down", heroicon = "chevron-down")))]
+    None,
+    #[cfg(any(feature = "chevron-left", heroicon = "chevron-left"))]
+    Some((Shape::ChevronLeft, &c::CHEVRON_LEFT, &[], true)),
+    #[cfg(not(any(feature = "chevron-left", heroicon = "chevron-left")))]
+    None,
+    #[cfg(any(feature = "chevron-right", heroicon = "chevron-right"))]
+    Some((Shape::ChevronRight, &c::CHEVRON_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "chevron-right", heroicon = "chevron-right")))]
+    None,
+    #[cfg(any(feature = "chevron-up-down", heroicon = "chevron-up-down"))]
+    Some((Shape::ChevronUpDown, &c::CHEVRON_UP_DOWN, &[], false)),
+    #[cfg(not(any(feature = "chevron-up-down", heroicon = "chevron-up-down")))]
+    None,
+    #[cfg(any(feature = "chevron-up", heroicon = "chevron-up"))]
+    Some((Shape::ChevronUp, &c::CHEVRON_UP, &[], false)),
+    #[cfg(not(any(feature = "chevron-up", heroicon = "chevron-up")))]
+    None,
+    #[cfg(any(feature = "circle-stack", heroicon = "circle-stack"))]
+    Some((Shape::CircleStack, &c::CIRCLE_STACK, &[], false)),
+    #[cfg(not(any(feature = "circle-stack", heroicon = "circle-stack")))]
+    None,
+    #[cfg(
+        any(feature = "clipboard-document-check", heroicon = "clipboard-document-check")
+    )]
+    Some((Shape::ClipboardDocumentCheck, &c::CLIPBOARD_DOCUMENT_CHECK, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "clipboard-document-check",
+                heroicon = "clipboard-document-check"
+            )
+        )
+    )]
+    None,
+    #[cfg(
+        any(feature = "clipboard-document-list", heroicon = "clipboard-document-list")
+    )]
+    Some((Shape::ClipboardDocumentList, &c::CLIPBOARD_DOCUMENT_LIST, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "clipboard-document-list",
+                heroicon = "clipboard-document-list"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "clipboard-document", heroicon = "clipboard-document"))]
+    Some((Shape::ClipboardDocument, &c::CLIPBOARD_DOCUMENT, &[], false)),
+    #[cfg(not(any(feature = "clipboard-document", heroicon = "clipboard-document")))]
+    None,
+    #[cfg(any(feature = "clipboard", heroicon = "clipboard"))]
+    Some((Shape::Clipboard, &c::CLIPBOARD, &["copy", "paste"], false)),
+    #[cfg(not(any(feature = "clipboard", heroicon = "clipboard")))]
+    None,
+    #[cfg(any(feature = "clock", heroicon = "clock"))]
+    Some((Shape::Clock, &c::CLOCK, &["time"], false)),
+    #[cfg(not(any(feature = "clock", heroicon = "clock")))]
+    None,
+    #[cfg(any(feature = "cloud-arrow-down", heroicon = "cloud-arrow-down"))]
+    Some((Shape::CloudArrowDown, &c::CLOUD_ARROW_DOWN, &[], false)),
+    #[cfg(not(any(feature = "cloud-arrow-down", heroicon = "cloud-arrow-down")))]
+    None,
+    #[cfg(any(feature = "cloud-arrow-up", heroicon = "cloud-arrow-up"))]
+    Some((Shape::CloudArrowUp, &c::CLOUD_ARROW_UP, &[], false)),
+    #[cfg(not(any(feature = "cloud-arrow-up", heroicon = "cloud-arrow-up")))]
+    None,
+    #[cfg(any(feature = "cloud", heroicon = "cloud"))]
+    Some((Shape::Cloud, &c::CLOUD, &[], false)),
+    #[cfg(not(any(feature = "cloud", heroicon = "cloud")))]
+    None,
+    #[cfg(any(feature = "code-bracket-square", heroicon = "code-bracket-square"))]
+    Some((Shape::CodeBracketSquare, &c::CODE_BRACKET_SQUARE, &[], false)),
+    #[cfg(not(any(feature = "code-bracket-square", heroicon = "code-bracket-square")))]
+    None,
+    #[cfg(any(feature = "code-bracket", heroicon = "code-bracket"))]
+    Some((Shape::CodeBracket, &c::CODE_BRACKET, &[], false)),
+    #[cfg(not(any(feature = "code-bracket", heroicon = "code-bracket")))]
+    None,
+    #[cfg(any(feature = "cog-6-tooth", heroicon = "cog-6-tooth"))]
+    Some((
+        Shape::Cog6Tooth,
+        &c::COG_6_TOOTH,
+        &["gear", "preferences", "settings"],
+        false,
+    )),
+    #[cfg(not(any(feature = "cog-6-tooth", heroicon = "cog-6-tooth")))]
+    None,
+    #[cfg(any(feature = "cog-8-tooth", heroicon = "cog-8-tooth"))]
+    Some((Shape::Cog8Tooth, &c::COG_8_TOOTH, &[], false)),
+    #[cfg(not(any(feature = "cog-8-tooth", heroicon = "cog-8-tooth")))]
+    None,
+    #[cfg(any(feature = "cog", heroicon = "cog"))]
+    Some((Shape::Cog, &c::COG, &["gear", "settings"], false)),
+    #[cfg(not(any(feature = "cog", heroicon = "cog")))]
+    None,
+    #[cfg(any(feature = "command-line", heroicon = "command-line"))]
+    Some((Shape::CommandLine, &c::COMMAND_LINE, &[], false)),
+    #[cfg(not(any(feature = "command-line", heroicon = "command-line")))]
+    None,
+    #[cfg(any(feature = "computer-desktop", heroicon = "computer-desktop"))]
+    Some((Shape::ComputerDesktop, &c::COMPUTER_DESKTOP, &[], false)),
+    #[cfg(not(any(feature = "computer-desktop", heroicon = "computer-desktop")))]
+    None,
+    #[cfg(any(feature = "cpu-chip", heroicon = "cpu-chip"))]
+    Some((Shape::CpuChip, &c::CPU_CHIP, &[], false)),
+    #[cfg(not(any(feature = "cpu-chip", heroicon = "cpu-chip")))]
+    None,
+    #[cfg(any(feature = "credit-card", heroicon = "credit-card"))]
+    Some((Shape::CreditCard, &c::CREDIT_CARD, &[], false)),
+    #[cfg(not(any(feature = "credit-card", heroicon = "credit-card")))]
+    None,
+    #[cfg(any(feature = "cube-transparent", heroicon = "cube-transparent"))]
+    Some((Shape::CubeTransparent, &c::CUBE_TRANSPARENT, &[], false)),
+    #[cfg(not(any(feature = "cube-transparent", heroicon = "cube-transparent")))]
+    None,
+    #[cfg(any(feature = "cube", heroicon = "cube"))]
+    Some((Shape::Cube, &c::CUBE, &[], false)),
+    #[cfg(not(any(feature = "cube", heroicon = "cube")))]
+    None,
+    #[cfg(any(feature = "currency-bangladeshi", heroicon = "currency-bangladeshi"))]
+    Some((Shape::CurrencyBangladeshi, &c::CURRENCY_BANGLADESHI, &[], false)),
+    #[cfg(not(any(feature = "currency-bangladeshi", heroicon = "currency-bangladeshi")))]
+    None,
+    #[cfg(any(feature = "currency-dollar", heroicon = "currency-dollar"))]
+    Some((Shape::CurrencyDollar, &c::CURRENCY_DOLLAR, &[], false)),
+    #[cfg(not(any(feature = "currency-dollar", heroicon = "currency-dollar")))]
+    None,
+    #[cfg(any(feature = "currency-euro", heroicon = "currency-euro"))]
+    Some((Shape::CurrencyEuro, &c::CURRENCY_EURO, &[], false)),
+    #[cfg(not(any(feature = "currency-euro", heroicon = "currency-euro")))]
+    None,
+    #[cfg(any(feature = "currency-pound", heroicon = "currency-pound"))]
+    Some((Shape::CurrencyPound, &c::CURRENCY_POUND, &[], false)),
+    #[cfg(not(any(feature = "currency-pound", heroicon = "currency-pound")))]
+    None,
+    #[cfg(any(feature = "currency-rupee", heroicon = "currency-rupee"))]
+    Some((Shape::CurrencyRupee, &c::CURRENCY_RUPEE, &[], false)),
+    #[cfg(not(any(feature = "currency-rupee", heroicon = "currency-rupee")))]
+    None,
+    #[cfg(any(feature = "currency-yen", heroicon = "currency-yen"))]
+    Some((Shape::CurrencyYen, &c::CURRENCY_YEN, &[], false)),
+    #[cfg(not(any(feature = "currency-yen", heroicon = "currency-yen")))]
+    None,
+    #[cfg(any(feature = "cursor-arrow-rays", heroicon = "cursor-arrow-rays"))]
+    Some((Shape::CursorArrowRays, &c::CURSOR_ARROW_RAYS, &[], false)),
+    #[cfg(not(any(feature = "cursor-arrow-rays", heroicon = "cursor-arrow-rays")))]
+    None,
+    #[cfg(any(feature = "cursor-arrow-ripple", heroicon = "cursor-arrow-ripple"))]
+    Some((Shape::CursorArrowRipple, &c::CURSOR_ARROW_RIPPLE, &[], false)),
+    #[cfg(not(any(feature = "cursor-arrow-ripple", heroicon = "cursor-arrow-ripple")))]
+    None,
+    #[cfg(any(feature = "device-phone-mobile", heroicon = "device-phone-mobile"))]
+    Some((Shape::DevicePhoneMobile, &d::DEVICE_PHONE_MOBILE, &[], false)),
+    #[cfg(not(any(feature = "device-phone-mobile", heroicon = "device-phone-mobile")))]
+    None,
+    #[cfg(any(feature = "device-tablet", heroicon = "device-tablet"))]
+    Some((Shape::DeviceTablet, &d::DEVICE_TABLET, &[], false)),
+    #[cfg(not(any(feature = "device-tablet", heroicon = "device-tablet")))]
+    None,
+    #[cfg(any(feature = "document-arrow-down", heroicon = "document-arrow-down"))]
+    Some((Shape::DocumentArrowDown, &d::DOCUMENT_ARROW_DOWN, &[], false)),
+    #[cfg(not(any(feature = "document-arrow-down", heroicon = "document-arrow-down")))]
+    None,
+    #[cfg(any(feature = "document-arrow-up", heroicon = "document-arrow-up"))]
+    Some((Shape::DocumentArrowUp, &d::DOCUMENT_ARROW_UP, &[], false)),
+    #[cfg(not(any(feature = "document-arrow-up", heroicon = "document-arrow-up")))]
+    None,
+    #[cfg(any(feature = "document-chart-bar", heroicon = "document-chart-bar"))]
+    Some((Shape::DocumentChartBar, &d::DOCUMENT_CHART_BAR, &[], false)),
+    #[cfg(not(any(feature = "document-chart-bar", heroicon = "document-chart-bar")))]
+    None,
+    #[cfg(any(feature = "document-check", heroicon = "document-check"))]
+    Some((Shape::DocumentCheck, &d::DOCUMENT_CHECK, &[], false)),
+    #[cfg(not(any(feature = "document-check", heroicon = "document-check")))]
+    None,
+    #[cfg(any(feature = "document-duplicate", heroicon = "document-duplicate"))]
+    Some((Shape::DocumentDuplicate, &d::DOCUMENT_DUPLICATE, &[], false)),
+    #[cfg(not(any(feature = "document-duplicate", heroicon = "document-duplicate")))]
+    None,
+    #[cfg(
+        any(
+            feature = "document-magnifying-glass",
+            heroicon = "document-magnifying-glass"
+        )
+    )]
+    Some((Shape::DocumentMagnifyingGlass, &d::DOCUMENT_MAGNIFYING_GLASS, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "document-magnifying-glass",
+                heroicon = "document-magnifying-glass"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "document-minus", heroicon = "document-minus"))]
+    Some((Shape::DocumentMinus, &d::DOCUMENT_MINUS, &[], false)),
+    #[cfg(not(any(feature = "document-minus", heroicon = "document-minus")))]
+    None,
+    #[cfg(any(feature = "document-plus", heroicon = "document-plus"))]
+    Some((Shape::DocumentPlus, &d::DOCUMENT_PLUS, &[], false)),
+    #[cfg(not(any(feature = "document-plus", heroicon = "document-plus")))]
+    None,
+    #[cfg(any(feature = "document-text", heroicon = "document-text"))]
+    Some((Shape::DocumentText, &d::DOCUMENT_TEXT, &[], false)),
+    #[cfg(not(any(feature = "document-text", heroicon = "document-text")))]
+    None,
+    #[cfg(any(feature = "document", heroicon = "document"))]
+    Some((Shape::Document, &d::DOCUMENT, &["file", "page"], false)),
+    #[cfg(not(any(feature = "document", heroicon = "document")))]
+    None,
+    #[cfg(
+        any(
+            feature = "ellipsis-horizontal-circle",
+            heroicon = "ellipsis-horizontal-circle"
+        )
+    )]
+    Some((Shape::EllipsisHorizontalCircle, &e::ELLIPSIS_HORIZONTAL_CIRCLE, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "ellipsis-horizontal-circle",
+                heroicon = "ellipsis-horizontal-circle"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "ellipsis-horizontal", heroicon = "ellipsis-horizontal"))]
+    Some((Shape::EllipsisHorizontal, &e::ELLIPSIS_HORIZONTAL, &[], false)),
+    #[cfg(not(any(feature = "ellipsis-horizontal", heroicon = "ellipsis-horizontal")))]
+    None,
+    #[cfg(any(feature = "ellipsis-vertical", heroicon = "ellipsis-vertical"))]
+    Some((Shape::EllipsisVertical, &e::ELLIPSIS_VERTICAL, &[], false)),
+    #[cfg(not(any(feature = "ellipsis-vertical", heroicon = "ellipsis-vertical")))]
+    None,
+    #[cfg(any(feature = "envelope-open", heroicon = "envelope-open"))]
+    Some((Shape::EnvelopeOpen, &e::ENVELOPE_OPEN, &[], false)),
+    #[cfg(not(any(feature = "envelope-open", heroicon = "envelope-open")))]
+    None,
+    #[cfg(any(feature = "envelope", heroicon = "envelope"))]
+    Some((Shape::Envelope, &e::ENVELOPE, &["email", "mail", "message"], false)),
+    #[cfg(not(any(feature = "envelope", heroicon = "envelope")))]
+    None,
+    #[cfg(any(feature = "exclamation-circle", heroicon = "exclamation-circle"))]
+    Some((Shape::ExclamationCircle, &e::EXCLAMATION_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "exclamation-circle", heroicon = "exclamation-circle")))]
+    None,
+    #[cfg(any(feature = "exclamation-triangle", heroicon = "exclamation-triangle"))]
+    Some((
+        Shape::ExclamationTriangle,
+        &e::EXCLAMATION_TRIANGLE,
+        &["alert", "caution", "warning"],
+        false,
+    )),
+    #[cfg(not(any(feature = "exclamation-triangle", heroicon = "exclamation-triangle")))]
+    None,
+    #[cfg(any(feature = "eye-dropper", heroicon = "eye-dropper"))]
+    Some((Shape::EyeDropper, &e::EYE_DROPPER, &[], false)),
+    #[cfg(not(any(feature = "eye-dropper", heroicon = "eye-dropper")))]
+    None,
+    #[cfg(any(feature = "eye-slash", heroicon = "eye-slash"))]
+    Some((Shape::EyeSlash, &e::EYE_SLASH, &["hidden", "hide", "invisible"], false)),
+    #[cfg(not(any(feature = "eye-slash", heroicon = "eye-slash")))]
+    None,
+    #[cfg(any(feature = "eye", heroicon = "eye"))]
+    Some((Shape::Eye, &e::EYE, &["show", "view", "visible"], false)),
+    #[cfg(not(any(feature = "eye", heroicon = "eye")))]
+    None,
+    #[cfg(any(feature = "face-frown", heroicon = "face-frown"))]
+    Some((Shape::FaceFrown, &f::FACE_FROWN, &[], false)),
+    #[cfg(not(any(feature = "face-frown", heroicon = "face-frown")))]
+    None,
+    #[cfg(any(feature = "face-smile", heroicon = "face-smile"))]
+    Some((Shape::FaceSmile, &f::FACE_SMILE, &[], false)),
+    #[cfg(not(any(feature = "face-smile", heroicon = "face-smile")))]
+    None,
+    #[cfg(any(feature = "film", heroicon = "film"))]
+    Some((Shape::Film, &f::FILM, &[], false)),
+    #[cfg(not(any(feature = "film", heroicon = "film")))]
+    None,
+    #[cfg(any(feature = "finger-print", heroicon = "finger-print"))]
+    Some((Shape::FingerPrint, &f::FINGER_PRINT, &[], false)),
+    #[cfg(not(any(feature = "finger-print", heroicon = "finger-print")))]
+    None,
+    #[cfg(any(feature = "fire", heroicon = "fire"))]
+    Some((Shape::Fire, &f::FIRE, &[], false)),
+    #[cfg(not(any(feature = "fire", heroicon = "fire")))]
+    None,
+    #[cfg(any(feature = "flag", heroicon = "flag"))]
+    Some((Shape::Flag, &f::FLAG, &[], false)),
+    #[cfg(not(any(feature = "flag", heroicon = "flag")))]
+    None,
+    #[cfg(any(feature = "folder-arrow-down", heroicon = "folder-arrow-down"))]
+    Some((Shape::FolderArrowDown, &f::FOLDER_ARROW_DOWN, &[], false)),
+    #[cfg(not(any(feature = "folder-arrow-down", heroicon = "folder-arrow-down")))]
+    None,
+    #[cfg(any(feature = "folder-minus", heroicon = "folder-minus"))]
+    Some((Shape::FolderMinus, &f::FOLDER_MINUS, &[], false)),
+    #[cfg(not(any(feature = "folder-minus", heroicon = "folder-minus")))]
+    None,
+    #[cfg(any(feature = "folder-open", heroicon = "folder-open"))]
+    Some((Shape::FolderOpen, &f::FOLDER_OPEN, &[], false)),
+    #[cfg(not(any(feature = "folder-open", heroicon = "folder-open")))]
+    None,
+    #[cfg(any(feature = "folder-plus", heroicon = "folder-plus"))]
+    Some((Shape::FolderPlus, &f::FOLDER_PLUS, &[], false)),
+    #[cfg(not(any(feature = "folder-plus", heroicon = "folder-plus")))]
+    None,
+    #[cfg(any(feature = "folder", heroicon = "folder"))]
+    Some((Shape::Folder, &f::FOLDER, &["directory"], false)),
+    #[cfg(not(any(feature = "folder", heroicon = "folder")))]
+    None,
+    #[cfg(any(feature = "forward", heroicon = "forward"))]
+    Some((Shape::Forward, &f::FORWARD, &[], false)),
+    #[cfg(not(any(feature = "forward", heroicon = "forward")))]
+    None,
+    #[cfg(any(feature = "funnel", heroicon = "funnel"))]
+    Some((Shape::Funnel, &f::FUNNEL, &[], false)),
+    #[cfg(not(any(feature = "funnel", heroicon = "funnel")))]
+    None,
+    #[cfg(any(feature = "gif", heroicon = "gif"))]
+    Some((Shape::Gif, &g::GIF, &[], false)),
+    #[cfg(not(any(feature = "gif", heroicon = "gif")))]
+    None,
+    #[cfg(any(feature = "gift-top", heroicon = "gift-top"))]
+    Some((Shape::GiftTop, &g::GIFT_TOP, &[], false)),
+    #[cfg(not(any(feature = "gift-top", heroicon = "gift-top")))]
+    None,
+    #[cfg(any(feature = "gift", heroicon = "gift"))]
+    Some((Shape::Gift, &g::GIFT, &[], false)),
+    #[cfg(not(any(feature = "gift", heroicon = "gift")))]
+    None,
+    #[cfg(any(feature = "globe-alt", heroicon = "globe-alt"))]
+    Some((Shape::GlobeAlt, &g::GLOBE_ALT, &["internet", "web", "world"], false)),
+    #[cfg(not(any(feature = "globe-alt", heroicon = "globe-alt")))]
+    None,
+    #[cfg(any(feature = "globe-americas", heroicon = "globe-americas"))]
+    Some((Shape::GlobeAmericas, &g::GLOBE_AMERICAS, &[], false)),
+    #[cfg(not(any(feature = "globe-americas", heroicon = "globe-americas")))]
+    None,
+    #[cfg(any(feature = "globe-asia-australia", heroicon = "globe-asia-australia"))]
+    Some((Shape::GlobeAsiaAustralia, &g::GLOBE_ASIA_AUSTRALIA, &[], false)),
+    #[cfg(not(any(feature = "globe-asia-australia", heroicon = "globe-asia-australia")))]
+    None,
+    #[cfg(any(feature = "globe-europe-africa", heroicon = "globe-europe-africa"))]
+    Some((Shape::GlobeEuropeAfrica, &g::GLOBE_EUROPE_AFRICA, &[], false)),
+    #[cfg(not(any(feature = "globe-europe-africa", heroicon = "globe-europe-africa")))]
+    None,
+    #[cfg(any(feature = "hand-raised", heroicon = "hand-raised"))]
+    Some((Shape::HandRaised, &h::HAND_RAISED, &[], false)),
+    #[cfg(not(any(feature = "hand-raised", heroicon = "hand-raised")))]
+    None,
+    #[cfg(any(feature = "hand-thumb-down", heroicon = "hand-thumb-down"))]
+    Some((Shape::HandThumbDown, &h::HAND_THUMB_DOWN, &[], false)),
+    #[cfg(not(any(feature = "hand-thumb-down", heroicon = "hand-thumb-down")))]
+    None,
+    #[cfg(any(feature = "hand-thumb-up", heroicon = "hand-thumb-up"))]
+    Some((Shape::HandThumbUp, &h::HAND_THUMB_UP, &[], false)),
+    #[cfg(not(any(feature = "hand-thumb-up", heroicon = "hand-thumb-up")))]
+    None,
+    #[cfg(any(feature = "hashtag", heroicon = "hashtag"))]
+    Some((Shape::Hashtag, &h::HASHTAG, &[], false)),
+    #[cfg(not(any(feature = "hashtag", heroicon = "hashtag")))]
+    None,
+    #[cfg(any(feature = "heart", heroicon = "heart"))]
+    Some((Shape::Heart, &h::HEART, &["favorite", "like", "love"], false)),
+    #[cfg(not(any(feature = "heart", heroicon = "heart")))]
+    None,
+    #[cfg(any(feature = "home-modern", heroicon = "home-modern"))]
+    Some((Shape::HomeModern, &h::HOME_MODERN, &[], false)),
+    #[cfg(not(any(feature = "home-modern", heroicon = "home-modern")))]
+    None,
+    #[cfg(any(feature = "home", heroicon = "home"))]
+    Some((Shape::Home, &h::HOME, &["house"], false)),
+    #[cfg(not(any(feature = "home", heroicon = "home")))]
+    None,
+    #[cfg(any(feature = "identification", heroicon = "identification"))]
+    Some((Shape::Identification, &i::IDENTIFICATION, &[], false)),
+    #[cfg(not(any(feature = "identification", heroicon = "identification")))]
+    None,
+    #[cfg(any(feature = "inbox-arrow-down", heroicon = "inbox-arrow-down"))]
+    Some((Shape::InboxArrowDown, &i::INBOX_ARROW_DOWN, &[], false)),
+    #[cfg(not(any(feature = "inbox-arrow-down", heroicon = "inbox-arrow-down")))]
+    None,
+    #[cfg(any(feature = "inbox-stack", heroicon = "inbox-stack"))]
+    Some((Shape::InboxStack, &i::INBOX_STACK, &[], false)),
+    #[cfg(not(any(feature = "inbox-stack", heroicon = "inbox-stack")))]
+    None,
+    #[cfg(any(feature = "inbox", heroicon = "inbox"))]
+    Some((Shape::Inbox, &i::INBOX, &[], false)),
+    #[cfg(not(any(feature = "inbox", heroicon = "inbox")))]
+    None,
+    #[cfg(any(feature = "information-circle", heroicon = "information-circle"))]
+    Some((Shape::InformationCircle, &i::INFORMATION_CIRCLE, &["about", "info"], false)),
+    #[cfg(not(any(feature = "information-circle", heroicon = "information-circle")))]
+    None,
+    #[cfg(any(feature = "key", heroicon = "key"))]
+    Some((Shape::Key, &k::KEY, &[], false)),
+    #[cfg(not(any(feature = "key", heroicon = "key")))]
+    None,
+    #[cfg(any(feature = "language", heroicon = "language"))]
+    Some((Shape::Language, &l::LANGUAGE, &[], false)),
+    #[cfg(not(any(feature = "language", heroicon = "language")))]
+    None,
+    #[cfg(any(feature = "lifebuoy", heroicon = "lifebuoy"))]
+    Some((Shape::Lifebuoy, &l::LIFEBUOY, &[], false)),
+    #[cfg(not(any(feature = "lifebuoy", heroicon = "lifebuoy")))]
+    None,
+    #[cfg(any(feature = "light-bulb", heroicon = "light-bulb"))]
+    Some((Shape::LightBulb, &l::LIGHT_BULB, &[], false)),
+    #[cfg(not(any(feature = "light-bulb", heroicon = "light-bulb")))]
+    None,
+    #[cfg(any(feature = "link", heroicon = "link"))]
+    Some((Shape::Link, &l::LINK, &["chain", "hyperlink", "url"], false)),
+    #[cfg(not(any(feature = "link", heroicon = "link")))]
+    None,
+    #[cfg(any(feature = "list-bullet", heroicon = "list-bullet"))]
+    Some((Shape::ListBullet, &l::LIST_BULLET, &[], true)),
+    #[cfg(not(any(feature = "list-bullet", heroicon = "list-bullet")))]
+    None,
+    #[cfg(any(feature = "lock-closed", heroicon = "lock-closed"))]
+    Some((Shape::LockClosed, &l::LOCK_CLOSED, &["locked", "password", "secure"], false)),
+    #[cfg(not(any(feature = "lock-closed", heroicon = "lock-closed")))]
+    None,
+    #[cfg(any(feature = "lock-open", heroicon = "lock-open"))]
+    Some((Shape::LockOpen, &l::LOCK_OPEN, &["unlocked"], false)),
+    #[cfg(not(any(feature = "lock-open", heroicon = "lock-open")))]
+    None,
+    #[cfg(
+        any(feature = "magnifying-glass-circle", heroicon = "magnifying-glass-circle")
+    )]
+    Some((Shape::MagnifyingGlassCircle, &m::MAGNIFYING_GLASS_CIRCLE, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "magnifying-glass-circle",
+                heroicon = "magnifying-glass-circle"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "magnifying-glass-minus", heroicon = "magnifying-glass-minus"))]
+    Some((Shape::MagnifyingGlassMinus, &m::MAGNIFYING_GLASS_MINUS, &[], false)),
+    #[cfg(
+        not(any(feature = "magnifying-glass-minus", heroicon = "magnifying-glass-minus"))
+    )]
+    None,
+    #[cfg(any(feature = "magnifying-glass-plus", heroicon = "magnifying-glass-plus"))]
+    Some((Shape::MagnifyingGlassPlus, &m::MAGNIFYING_GLASS_PLUS, &[], false)),
+    #[cfg(
+        not(any(feature = "magnifying-glass-plus", heroicon = "magnifying-glass-plus"))
+    )]
+    None,
+    #[cfg(any(feature = "magnifying-glass", heroicon = "magnifying-glass"))]
+    Some((Shape::MagnifyingGlass, &m::MAGNIFYING_GLASS, &["find", "search"], false)),
+    #[cfg(not(any(feature = "magnifying-glass", heroicon = "magnifying-glass")))]
+    None,
+    #[cfg(any(feature = "map-pin", heroicon = "map-pin"))]
+    Some((Shape::MapPin, &m::MAP_PIN, &["location", "marker", "place"], false)),
+    #[cfg(not(any(feature = "map-pin", heroicon = "map-pin")))]
+    None,
+    #[cfg(any(feature = "map", heroicon = "map"))]
+    Some((Shape::Map, &m::MAP, &[], false)),
+    #[cfg(not(any(feature = "map", heroicon = "map")))]
+    None,
+    #[cfg(any(feature = "megaphone", heroicon = "megaphone"))]
+    Some((Shape::Megaphone, &m::MEGAPHONE, &[], false)),
+    #[cfg(not(any(feature = "megaphone", heroicon = "megaphone")))]
+    None,
+    #[cfg(any(feature = "microphone", heroicon = "microphone"))]
+    Some((Shape::Microphone, &m::MICROPHONE, &[], false)),
+    #[cfg(not(any(feature = "microphone", heroicon = "microphone")))]
+    None,
+    #[cfg(any(feature = "minus-circle", heroicon = "minus-circle"))]
+    Some((Shape::MinusCircle, &m::MINUS_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "minus-circle", heroicon = "minus-circle")))]
+    None,
+    #[cfg(any(feature = "minus-small", heroicon = "minus-small"))]
+    Some((Shape::MinusSmall, &m::MINUS_SMALL, &[], false)),
+    #[cfg(not(any(feature = "minus-small", heroicon = "minus-small")))]
+    None,
+    #[cfg(any(feature = "minus", heroicon = "minus"))]
+    Some((Shape::Minus, &m::MINUS, &["subtract"], false)),
+    #[cfg(not(any(feature = "minus", heroicon = "minus")))]
+    None,
+    #[cfg(any(feature = "moon", heroicon = "moon"))]
+    Some((Shape::Moon, &m::MOON, &["dark", "night"], false)),
+    #[cfg(not(any(feature = "moon", heroicon = "moon")))]
+    None,
+    #[cfg(any(feature = "musical-note", heroicon = "musical-note"))]
+    Some((Shape::MusicalNote, &m::MUSICAL_NOTE, &[], false)),
+    #[cfg(not(any(feature = "musical-note", heroicon = "musical-note")))]
+    None,
+    #[cfg(any(feature = "newspaper", heroicon = "newspaper"))]
+    Some((Shape::Newspaper, &n::NEWSPAPER, &[], false)),
+    #[cfg(not(any(feature = "newspaper", heroicon = "newspaper")))]
+    None,
+    #[cfg(any(feature = "no-symbol", heroicon = "no-symbol"))]
+    Some((Shape::NoSymbol, &n::NO_SYMBOL, &[], false)),
+    #[cfg(not(any(feature = "no-symbol", heroicon = "no-symbol")))]
+    None,
+    #[cfg(any(feature = "paint-brush", heroicon = "paint-brush"))]
+    Some((Shape::PaintBrush, &p::PAINT_BRUSH, &[], false)),
+    #[cfg(not(any(feature = "paint-brush", heroicon = "paint-brush")))]
+    None,
+    #[cfg(any(feature = "paper-airplane", heroicon = "paper-airplane"))]
+    Some((Shape::PaperAirplane, &p::PAPER_AIRPLANE, &[], true)),
+    #[cfg(not(any(feature = "paper-airplane", heroicon = "paper-airplane")))]
+    None,
+    #[cfg(any(feature = "paper-clip", heroicon = "paper-clip"))]
+    Some((Shape::PaperClip, &p::PAPER_CLIP, &[], false)),
+    #[cfg(not(any(feature = "paper-clip", heroicon = "paper-clip")))]
+    None,
+    #[cfg(any(feature = "pause-circle", heroicon = "pause-circle"))]
+    Some((Shape::PauseCircle, &p::PAUSE_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "pause-circle", heroicon = "pause-circle")))]
+    None,
+    #[cfg(any(feature = "pause", heroicon = "pause"))]
+    Some((Shape::Pause, &p::PAUSE, &[], false)),
+    #[cfg(not(any(feature = "pause", heroicon = "pause")))]
+    None,
+    #[cfg(any(feature = "pencil-square", heroicon = "pencil-square"))]
+    Some((Shape::PencilSquare, &p::PENCIL_SQUARE, &["compose", "edit"], false)),
+    #[cfg(not(any(feature = "pencil-square", heroicon = "pencil-square")))]
+    None,
+    #[cfg(any(feature = "pencil", heroicon = "pencil"))]
+    Some((Shape::Pencil, &p::PENCIL, &["edit", "write"], false)),
+    #[cfg(not(any(feature = "pencil", heroicon = "pencil")))]
+    None,
+    #[cfg(any(feature = "phone-arrow-down-left", heroicon = "phone-arrow-down-left"))]
+    Some((Shape::PhoneArrowDownLeft, &p::PHONE_ARROW_DOWN_LEFT, &[], true)),
+    #[cfg(
+        not(any(feature = "phone-arrow-down-left", heroicon = "phone-arrow-down-left"))
+    )]
+    None,
+    #[cfg(any(feature = "phone-arrow-up-right", heroicon = "phone-arrow-up-right"))]
+    Some((Shape::PhoneArrowUpRight, &p::PHONE_ARROW_UP_RIGHT, &[], true)),
+    #[cfg(not(any(feature = "phone-arrow-up-right", heroicon = "phone-arrow-up-right")))]
+    None,
+    #[cfg(any(feature = "phone-x-mark", heroicon = "phone-x-mark"))]
+    Some((Shape::PhoneXMark, &p::PHONE_X_MARK, &[], false)),
+    #[cfg(not(any(feature = "phone-x-mark", heroicon = "phone-x-mark")))]
+    None,
+    #[cfg(any(feature = "phone", heroicon = "phone"))]
+    Some((Shape::Phone, &p::PHONE, &["call", "telephone"], false)),
+    #[cfg(not(any(feature = "phone", heroicon = "phone")))]
+    None,
+    #[cfg(any(feature = "photo", heroicon = "photo"))]
+    Some((Shape::Photo, &p::PHOTO, &["image", "picture"], false)),
+    #[cfg(not(any(feature = "photo", heroicon = "photo")))]
+    None,
+    #[cfg(any(feature = "play-circle", heroicon = "play-circle"))]
+    Some((Shape::PlayCircle, &p::PLAY_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "play-circle", heroicon = "play-circle")))]
+    None,
+    #[cfg(any(feature = "play-pause", heroicon = "play-pause"))]
+    Some((Shape::PlayPause, &p::PLAY_PAUSE, &[], false)),
+    #[cfg(not(any(feature = "play-pause", heroicon = "play-pause")))]
+    None,
+    #[cfg(any(feature = "play", heroicon = "play"))]
+    Some((Shape::Play, &p::PLAY, &[], false)),
+    #[cfg(not(any(feature = "play", heroicon = "play")))]
+    None,
+    #[cfg(any(feature = "plus-circle", heroicon = "plus-circle"))]
+    Some((Shape::PlusCircle, &p::PLUS_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "plus-circle", heroicon = "plus-circle")))]
+    None,
+    #[cfg(any(feature = "plus-small", heroicon = "plus-small"))]
+    Some((Shape::PlusSmall, &p::PLUS_SMALL, &[], false)),
+    #[cfg(not(any(feature = "plus-small", heroicon = "plus-small")))]
+    None,
+    #[cfg(any(feature = "plus", heroicon = "plus"))]
+    Some((Shape::Plus, &p::PLUS, &["add", "create", "new"], false)),
+    #[cfg(not(any(feature = "plus", heroicon = "plus")))]
+    None,
+    #[cfg(any(feature = "power", heroicon = "power"))]
+    Some((Shape::Power, &p::POWER, &[], false)),
+    #[cfg(not(any(feature = "power", heroicon = "power")))]
+    None,
+    #[cfg(any(feature = "presentation-chart-bar", heroicon = "presentation-chart-bar"))]
+    Some((Shape::PresentationChartBar, &p::PRESENTATION_CHART_BAR, &[], false)),
+    #[cfg(
+        not(any(feature = "presentation-chart-bar", heroicon = "presentation-chart-bar"))
+    )]
+    None,
+    #[cfg(
+        any(feature = "presentation-chart-line", heroicon = "presentation-chart-line")
+    )]
+    Some((Shape::PresentationChartLine, &p::PRESENTATION_CHART_LINE, &[], false)),
+    #[cfg(
+        not(
+            any(
+                feature = "presentation-chart-line",
+                heroicon = "presentation-chart-line"
+            )
+        )
+    )]
+    None,
+    #[cfg(any(feature = "printer", heroicon = "printer"))]
+    Some((Shape::Printer, &p::PRINTER, &[], false)),
+    #[cfg(not(any(feature = "printer", heroicon = "printer")))]
+    None,
+    #[cfg(any(feature = "puzzle-piece", heroicon = "puzzle-piece"))]
+    Some((Shape::PuzzlePiece, &p::PUZZLE_PIECE, &[], false)),
+    #[cfg(not(any(feature = "puzzle-piece", heroicon = "puzzle-piece")))]
+    None,
+    #[cfg(any(feature = "qr-code", heroicon = "qr-code"))]
+    Some((Shape::QrCode, &q::QR_CODE, &[], false)),
+    #[cfg(not(any(feature = "qr-code", heroicon = "qr-code")))]
+    None,
+    #[cfg(any(feature = "question-mark-circle", heroicon = "question-mark-circle"))]
+    Some((
+        Shape::QuestionMarkCircle,
+        &q::QUESTION_MARK_CIRCLE,
+        &["faq", "help", "support"],
+        false,
+    )),
+    #[cfg(not(any(feature = "question-mark-circle", heroicon = "question-mark-circle")))]
+    None,
+    #[cfg(any(feature = "queue-list", heroicon = "queue-list"))]
+    Some((Shape::QueueList, &q::QUEUE_LIST, &[], true)),
+    #[cfg(not(any(feature = "queue-list", heroicon = "queue-list")))]
+    None,
+    #[cfg(any(feature = "radio", heroicon = "radio"))]
+    Some((Shape::Radio, &r::RADIO, &[], false)),
+    #[cfg(not(any(feature = "radio", heroicon = "radio")))]
+    None,
+    #[cfg(any(feature = "receipt-percent", heroicon = "receipt-percent"))]
+    Some((Shape::ReceiptPercent, &r::RECEIPT_PERCENT, &[], false)),
+    #[cfg(not(any(feature = "receipt-percent", heroicon = "receipt-percent")))]
+    None,
+    #[cfg(any(feature = "receipt-refund", heroicon = "receipt-refund"))]
+    Some((Shape::ReceiptRefund, &r::RECEIPT_REFUND, &[], false)),
+    #[cfg(not(any(feature = "receipt-refund", heroicon = "receipt-refund")))]
+    None,
+    #[cfg(any(feature = "rectangle-group", heroicon = "rectangle-group"))]
+    Some((Shape::RectangleGroup, &r::RECTANGLE_GROUP, &[], false)),
+    #[cfg(not(any(feature = "rectangle-group", heroicon = "rectangle-group")))]
+    None,
+    #[cfg(any(feature = "rectangle-stack", heroicon = "rectangle-stack"))]
+    Some((Shape::RectangleStack, &r::RECTANGLE_STACK, &[], false)),
+    #[cfg(not(any(feature = "rectangle-stack", heroicon = "rectangle-stack")))]
+    None,
+    #[cfg(any(feature = "rocket-launch", heroicon = "rocket-launch"))]
+    Some((Shape::RocketLaunch, &r::ROCKET_LAUNCH, &[], false)),
+    #[cfg(not(any(feature = "rocket-launch", heroicon = "rocket-launch")))]
+    None,
+    #[cfg(any(feature = "rss", heroicon = "rss"))]
+    Some((Shape::Rss, &r::RSS, &[], false)),
+    #[cfg(not(any(feature = "rss", heroicon = "rss")))]
+    None,
+    #[cfg(any(feature = "scale", heroicon = "scale"))]
+    Some((Shape::Scale, &s::SCALE, &[], false)),
+    #[cfg(not(any(feature = "scale", heroicon = "scale")))]
+    None,
+    #[cfg(any(feature = "scissors", heroicon = "scissors"))]
+    Some((Shape::Scissors, &s::SCISSORS, &[], false)),
+    #[cfg(not(any(feature = "scissors", heroicon = "scissors")))]
+    None,
+    #[cfg(any(feature = "server-stack", heroicon = "server-stack"))]
+    Some((Shape::ServerStack, &s::SERVER_STACK, &[], false)),
+    #[cfg(not(any(feature = "server-stack", heroicon = "server-stack")))]
+    None,
+    #[cfg(any(feature = "server", heroicon = "server"))]
+    Some((Shape::Server, &s::SERVER, &[], false)),
+    #[cfg(not(any(feature = "server", heroicon = "server")))]
+    None,
+    #[cfg(any(feature = "share", heroicon = "share"))]
+    Some((Shape::Share, &s::SHARE, &["send"], false)),
+    #[cfg(not(any(feature = "share", heroicon = "share")))]
+    None,
+    #[cfg(any(feature = "shield-check", heroicon = "shield-check"))]
+    Some((Shape::ShieldCheck, &s::SHIELD_CHECK, &[], false)),
+    #[cfg(not(any(feature = "shield-check", heroicon = "shield-check")))]
+    None,
+    #[cfg(any(feature = "shield-exclamation", heroicon = "shield-exclamation"))]
+    Some((Shape::ShieldExclamation, &s::SHIELD_EXCLAMATION, &[], false)),
+    #[cfg(not(any(feature = "shield-exclamation", heroicon = "shield-exclamation")))]
+    None,
+    #[cfg(any(feature = "shopping-bag", heroicon = "shopping-bag"))]
+    Some((Shape::ShoppingBag, &s::SHOPPING_BAG, &[], false)),
+    #[cfg(not(any(feature = "shopping-bag", heroicon = "shopping-bag")))]
+    None,
+    #[cfg(any(feature = "shopping-cart", heroicon = "shopping-cart"))]
+    Some((
+        Shape::ShoppingCart,
+        &s::SHOPPING_CART,
+        &["basket", "cart", "checkout"],
+        false,
+    )),
+    #[cfg(not(any(feature = "shopping-cart", heroicon = "shopping-cart")))]
+    None,
+    #[cfg(any(feature = "signal-slash", heroicon = "signal-slash"))]
+    Some((Shape::SignalSlash, &s::SIGNAL_SLASH, &[], false)),
+    #[cfg(not(any(feature = "signal-slash", heroicon = "signal-slash")))]
+    None,
+    #[cfg(any(feature = "signal", heroicon = "signal"))]
+    Some((Shape::Signal, &s::SIGNAL, &[], false)),
+    #[cfg(not(any(feature = "signal", heroicon = "signal")))]
+    None,
+    #[cfg(any(feature = "sparkles", heroicon = "sparkles"))]
+    Some((Shape::Sparkles, &s::SPARKLES, &[], false)),
+    #[cfg(not(any(feature = "sparkles", heroicon = "sparkles")))]
+    None,
+    #[cfg(any(feature = "speaker-wave", heroicon = "speaker-wave"))]
+    Some((Shape::SpeakerWave, &s::SPEAKER_WAVE, &[], false)),
+    #[cfg(not(any(feature = "speaker-wave", heroicon = "speaker-wave")))]
+    None,
+    #[cfg(any(feature = "speaker-x-mark", heroicon = "speaker-x-mark"))]
+    Some((Shape::SpeakerXMark, &s::SPEAKER_X_MARK, &[], false)),
+    #[cfg(not(any(feature = "speaker-x-mark", heroicon = "speaker-x-mark")))]
+    None,
+    #[cfg(any(feature = "square-2-stack", heroicon = "square-2-stack"))]
+    Some((Shape::Square2Stack, &s::SQUARE_2_STACK, &[], false)),
+    #[cfg(not(any(feature = "square-2-stack", heroicon = "square-2-stack")))]
+    None,
+    #[cfg(any(feature = "square-3-stack-3d", heroicon = "square-3-stack-3d"))]
+    Some((Shape::Square3Stack3d, &s::SQUARE_3_STACK_3D, &[], false)),
+    #[cfg(not(any(feature = "square-3-stack-3d", heroicon = "square-3-stack-3d")))]
+    None,
+    #[cfg(any(feature = "squares-2x2", heroicon = "squares-2x2"))]
+    Some((Shape::Squares2x2, &s::SQUARES_2X2, &[], false)),
+    #[cfg(not(any(feature = "squares-2x2", heroicon = "squares-2x2")))]
+    None,
+    #[cfg(any(feature = "squares-plus", heroicon = "squares-plus"))]
+    Some((Shape::SquaresPlus, &s::SQUARES_PLUS, &[], false)),
+    #[cfg(not(any(feature = "squares-plus", heroicon = "squares-plus")))]
+    None,
+    #[cfg(any(feature = "star", heroicon = "star"))]
+    Some((Shape::Star, &s::STAR, &["favorite", "rating"], false)),
+    #[cfg(not(any(feature = "star", heroicon = "star")))]
+    None,
+    #[cfg(any(feature = "stop-circle", heroicon = "stop-circle"))]
+    Some((Shape::StopCircle, &s::STOP_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "stop-circle", heroicon = "stop-circle")))]
+    None,
+    #[cfg(any(feature = "stop", heroicon = "stop"))]
+    Some((Shape::Stop, &s::STOP, &[], false)),
+    #[cfg(not(any(feature = "stop", heroicon = "stop")))]
+    None,
+    #[cfg(any(feature = "sun", heroicon = "sun"))]
+    Some((Shape::Sun, &s::SUN, &["brightness", "day", "light"], false)),
+    #[cfg(not(any(feature = "sun", heroicon = "sun")))]
+    None,
+    #[cfg(any(feature = "swatch", heroicon = "swatch"))]
+    Some((Shape::Swatch, &s::SWATCH, &[], false)),
+    #[cfg(not(any(feature = "swatch", heroicon = "swatch")))]
+    None,
+    #[cfg(any(feature = "table-cells", heroicon = "table-cells"))]
+    Some((Shape::TableCells, &t::TABLE_CELLS, &[], false)),
+    #[cfg(not(any(feature = "table-cells", heroicon = "table-cells")))]
+    None,
+    #[cfg(any(feature = "tag", heroicon = "tag"))]
+    Some((Shape::Tag, &t::TAG, &[], false)),
+    #[cfg(not(any(feature = "tag", heroicon = "tag")))]
+    None,
+    #[cfg(any(feature = "ticket", heroicon = "ticket"))]
+    Some((Shape::Ticket, &t::TICKET, &[], false)),
+    #[cfg(not(any(feature = "ticket", heroicon = "ticket")))]
+    None,
+    #[cfg(any(feature = "trash", heroicon = "trash"))]
+    Some((
+        Shape::Trash,
+        &t::TRASH,
+        &["bin", "delete", "garbage", "remove", "rubbish", "trash-can"],
+        false,
+    )),
+    #[cfg(not(any(feature = "trash", heroicon = "trash")))]
+    None,
+    #[cfg(any(feature = "trophy", heroicon = "trophy"))]
+    Some((Shape::Trophy, &t::TROPHY, &[], false)),
+    #[cfg(not(any(feature = "trophy", heroicon = "trophy")))]
+    None,
+    #[cfg(any(feature = "truck", heroicon = "truck"))]
+    Some((Shape::Truck, &t::TRUCK, &[], false)),
+    #[cfg(not(any(feature = "truck", heroicon = "truck")))]
+    None,
+    #[cfg(any(feature = "tv", heroicon = "tv"))]
+    Some((Shape::Tv, &t::TV, &[], false)),
+    #[cfg(not(any(feature = "tv", heroicon = "tv")))]
+    None,
+    #[cfg(any(feature = "user-circle", heroicon = "user-circle"))]
+    Some((Shape::UserCircle, &u::USER_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "user-circle", heroicon = "user-circle")))]
+    None,
+    #[cfg(any(feature = "user-group", heroicon = "user-group"))]
+    Some((Shape::UserGroup, &u::USER_GROUP, &[], false)),
+    #[cfg(not(any(feature = "user-group", heroicon = "user-group")))]
+    None,
+    #[cfg(any(feature = "user-minus", heroicon = "user-minus"))]
+    Some((Shape::UserMinus, &u::USER_MINUS, &[], false)),
+    #[cfg(not(any(feature = "user-minus", heroicon = "user-minus")))]
+    None,
+    #[cfg(any(feature = "user-plus", heroicon = "user-plus"))]
+    Some((Shape::UserPlus, &u::USER_PLUS, &[], false)),
+    #[cfg(not(any(feature = "user-plus", heroicon = "user-plus")))]
+    None,
+    #[cfg(any(feature = "user", heroicon = "user"))]
+    Some((Shape::User, &u::USER, &["account", "person", "profile"], false)),
+    #[cfg(not(any(feature = "user", heroicon = "user")))]
+    None,
+    #[cfg(any(feature = "users", heroicon = "users"))]
+    Some((Shape::Users, &u::USERS, &["group", "people", "team"], false)),
+    #[cfg(not(any(feature = "users", heroicon = "users")))]
+    None,
+    #[cfg(any(feature = "variable", heroicon = "variable"))]
+    Some((Shape::Variable, &v::VARIABLE, &[], false)),
+    #[cfg(not(any(feature = "variable", heroicon = "variable")))]
+    None,
+    #[cfg(any(feature = "video-camera-slash", heroicon = "video-camera-slash"))]
+    Some((Shape::VideoCameraSlash, &v::VIDEO_CAMERA_SLASH, &[], false)),
+    #[cfg(not(any(feature = "video-camera-slash", heroicon = "video-camera-slash")))]
+    None,
+    #[cfg(any(feature = "video-camera", heroicon = "video-camera"))]
+    Some((Shape::VideoCamera, &v::VIDEO_CAMERA, &[], false)),
+    #[cfg(not(any(feature = "video-camera", heroicon = "video-camera")))]
+    None,
+    #[cfg(any(feature = "view-columns", heroicon = "view-columns"))]
+    Some((Shape::ViewColumns, &v::VIEW_COLUMNS, &[], false)),
+    #[cfg(not(any(feature = "view-columns", heroicon = "view-columns")))]
+    None,
+    #[cfg(any(feature = "viewfinder-circle", heroicon = "viewfinder-circle"))]
+    Some((Shape::ViewfinderCircle, &v::VIEWFINDER_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "viewfinder-circle", heroicon = "viewfinder-circle")))]
+    None,
+    #[cfg(any(feature = "wallet", heroicon = "wallet"))]
+    Some((Shape::Wallet, &w::WALLET, &[], false)),
+    #[cfg(not(any(feature = "wallet", heroicon = "wallet")))]
+    None,
+    #[cfg(any(feature = "wifi", heroicon = "wifi"))]
+    Some((Shape::Wifi, &w::WIFI, &[], false)),
+    #[cfg(not(any(feature = "wifi", heroicon = "wifi")))]
+    None,
+    #[cfg(any(feature = "window", heroicon = "window"))]
+    Some((Shape::Window, &w::WINDOW, &[], false)),
+    #[cfg(not(any(feature = "window", heroicon = "window")))]
+    None,
+    #[cfg(any(feature = "wrench-screwdriver", heroicon = "wrench-screwdriver"))]
+    Some((Shape::WrenchScrewdriver, &w::WRENCH_SCREWDRIVER, &[], false)),
+    #[cfg(not(any(feature = "wrench-screwdriver", heroicon = "wrench-screwdriver")))]
+    None,
+    #[cfg(any(feature = "wrench", heroicon = "wrench"))]
+    Some((Shape::Wrench, &w::WRENCH, &[], false)),
+    #[cfg(not(any(feature = "wrench", heroicon = "wrench")))]
+    None,
+    #[cfg(any(feature = "x-circle", heroicon = "x-circle"))]
+    Some((Shape::XCircle, &x::X_CIRCLE, &[], false)),
+    #[cfg(not(any(feature = "x-circle", heroicon = "x-circle")))]
+    None,
+    #[cfg(any(feature = "x-mark", heroicon = "x-mark"))]
+    Some((Shape::XMark, &x::X_MARK, &["cancel", "close", "dismiss"], false)),
+    #[cfg(not(any(feature = "x-mark", heroicon = "x-mark")))]
+    None,
+];
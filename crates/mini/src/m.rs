@@ -0,0 +1,177 @@
+//! The shapes whose names start with "m".
+/// The "magnifying-glass-circle" shape.
+#[cfg(any(feature = "magnifying-glass-circle", heroicon = "magnifying-glass-circle"))]
+pub static MAGNIFYING_GLASS_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.5 9C6.5 7.61929 7.61929 6.5 9 6.5C10.3807 6.5 11.5 7.61929 11.5 9C11.5 9.69056 11.221 10.3145 10.7678 10.7678C10.3145 11.221 9.69056 11.5 9 11.5C7.61929 11.5 6.5 10.3807 6.5 9Z M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM9 5C6.79086 5 5 6.79086 5 9C5 11.2091 6.79086 13 9 13C9.8332 13 10.6076 12.7447 11.2481 12.3088L12.7197 13.7803C13.0126 14.0732 13.4874 14.0732 13.7803 13.7803C14.0732 13.4874 14.0732 13.0126 13.7803 12.7197L12.3088 11.2481C12.7447 10.6076 13 9.8332 13 9C13 6.79086 11.2091 5 9 5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "magnifying-glass-minus" shape.
+#[cfg(any(feature = "magnifying-glass-minus", heroicon = "magnifying-glass-minus"))]
+pub static MAGNIFYING_GLASS_MINUS: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass-minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.75 8.25C6.33579 8.25 6 8.58579 6 9C6 9.41421 6.33579 9.75 6.75 9.75L11.25 9.75C11.6642 9.75 12 9.41421 12 9C12 8.58579 11.6642 8.25 11.25 8.25L6.75 8.25Z M9 2C5.13401 2 2 5.13401 2 9C2 12.866 5.13401 16 9 16C10.6625 16 12.1906 15.4197 13.3911 14.4517L16.7197 17.7803C17.0126 18.0732 17.4874 18.0732 17.7803 17.7803C18.0732 17.4874 18.0732 17.0126 17.7803 16.7197L14.4517 13.3911C15.4197 12.1906 16 10.6625 16 9C16 5.13401 12.866 2 9 2ZM3.5 9C3.5 5.96243 5.96243 3.5 9 3.5C12.0376 3.5 14.5 5.96243 14.5 9C14.5 10.519 13.8852 11.893 12.8891 12.8891C11.893 13.8852 10.519 14.5 9 14.5C5.96243 14.5 3.5 12.0376 3.5 9Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "magnifying-glass-plus" shape.
+#[cfg(any(feature = "magnifying-glass-plus", heroicon = "magnifying-glass-plus"))]
+pub static MAGNIFYING_GLASS_PLUS: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass-plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 6C9.41421 6 9.75 6.33579 9.75 6.75V8.25H11.25C11.6642 8.25 12 8.58579 12 9C12 9.41421 11.6642 9.75 11.25 9.75L9.75 9.75V11.25C9.75 11.6642 9.41421 12 9 12C8.58579 12 8.25 11.6642 8.25 11.25V9.75L6.75 9.75C6.33579 9.75 6 9.41421 6 9C6 8.58579 6.33579 8.25 6.75 8.25H8.25V6.75C8.25 6.33579 8.58579 6 9 6Z M2 9C2 5.13401 5.13401 2 9 2C12.866 2 16 5.13401 16 9C16 10.6625 15.4197 12.1906 14.4517 13.3911L17.7803 16.7197C18.0732 17.0126 18.0732 17.4874 17.7803 17.7803C17.4874 18.0732 17.0126 18.0732 16.7197 17.7803L13.3911 14.4517C12.1906 15.4197 10.6625 16 9 16C5.13401 16 2 12.866 2 9ZM9 3.5C5.96243 3.5 3.5 5.96243 3.5 9C3.5 12.0376 5.96243 14.5 9 14.5C10.519 14.5 11.893 13.8852 12.8891 12.8891C13.8852 11.893 14.5 10.519 14.5 9C14.5 5.96243 12.0376 3.5 9 3.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "magnifying-glass" shape.
+#[cfg(any(feature = "magnifying-glass", heroicon = "magnifying-glass"))]
+pub static MAGNIFYING_GLASS: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 3.5C5.96243 3.5 3.5 5.96243 3.5 9C3.5 12.0376 5.96243 14.5 9 14.5C10.519 14.5 11.893 13.8852 12.8891 12.8891C13.8852 11.893 14.5 10.519 14.5 9C14.5 5.96243 12.0376 3.5 9 3.5ZM2 9C2 5.13401 5.13401 2 9 2C12.866 2 16 5.13401 16 9C16 10.6625 15.4197 12.1906 14.4517 13.3911L17.7803 16.7197C18.0732 17.0126 18.0732 17.4874 17.7803 17.7803C17.4874 18.0732 17.0126 18.0732 16.7197 17.7803L13.3911 14.4517C12.1906 15.4197 10.6625 16 9 16C5.13401 16 2 12.866 2 9Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "map-pin" shape.
+#[cfg(any(feature = "map-pin", heroicon = "map-pin"))]
+pub static MAP_PIN: super::StaticShape = super::StaticShape::new(
+    "map-pin",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.69008 18.933L9.69288 18.9342C9.89 19.02 10 19 10 19C10 19 10.11 19.02 10.3079 18.9339L10.3099 18.933L10.3157 18.9304L10.3338 18.922C10.3488 18.915 10.3697 18.9052 10.3959 18.8926C10.4484 18.8673 10.5225 18.8306 10.6153 18.7822C10.8008 18.6855 11.0612 18.5419 11.3717 18.3495C11.9912 17.9655 12.8174 17.3826 13.6455 16.5844C15.3022 14.9876 17 12.4925 17 9C17 5.13401 13.866 2 10 2C6.13401 2 3 5.13401 3 9C3 12.4925 4.69783 14.9876 6.35452 16.5844C7.18264 17.3826 8.00877 17.9655 8.62834 18.3495C8.93879 18.5419 9.19922 18.6855 9.38467 18.7822C9.47745 18.8306 9.55163 18.8673 9.60409 18.8926C9.63033 18.9052 9.65116 18.915 9.66619 18.922L9.68435 18.9304L9.69008 18.933ZM10 11.25C11.2426 11.25 12.25 10.2426 12.25 9C12.25 7.75736 11.2426 6.75 10 6.75C8.75736 6.75 7.75 7.75736 7.75 9C7.75 10.2426 8.75736 11.25 10 11.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "map" shape.
+#[cfg(any(feature = "map", heroicon = "map"))]
+pub static MAP: super::StaticShape = super::StaticShape::new(
+    "map",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.15686 2.1755C7.78961 2.02353 7.37706 2.02353 7.00981 2.1755L2.92647 3.86515C2.3657 4.0972 2 4.64429 2 5.25118V16.1278C2 17.1966 3.08594 17.9225 4.07353 17.5139L7.58333 16.0615L11.8431 17.8242C12.2104 17.9762 12.6229 17.9762 12.9902 17.8242L17.0735 16.1345C17.6343 15.9025 18 15.3554 18 14.7485V3.87187C18 2.80308 16.9141 2.07719 15.9265 2.48584L12.4167 3.93818L8.15686 2.1755ZM7.58008 4.99988C7.99429 4.99988 8.33008 5.33566 8.33008 5.74988V12.2499C8.33008 12.6641 7.99429 12.9999 7.58008 12.9999C7.16586 12.9999 6.83008 12.6641 6.83008 12.2499V5.74988C6.83008 5.33566 7.16586 4.99988 7.58008 4.99988ZM13.1699 7.74988C13.1699 7.33566 12.8341 6.99988 12.4199 6.99988C12.0057 6.99988 11.6699 7.33566 11.6699 7.74988V14.2499C11.6699 14.6641 12.0057 14.9999 12.4199 14.9999C12.8341 14.9999 13.1699 14.6641 13.1699 14.2499V7.74988Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "megaphone" shape.
+#[cfg(any(feature = "megaphone", heroicon = "megaphone"))]
+pub static MEGAPHONE: super::StaticShape = super::StaticShape::new(
+    "megaphone",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.9202 3.84536C11.9859 4.84219 9.86368 5.52477 7.62054 5.82595C6.76376 5.94099 5.88902 6.00044 5 6.00044C2.79086 6.00044 1 7.7913 1 10.0004C1 12.0389 2.52477 13.7211 4.49597 13.969C4.78782 15.1067 5.20979 16.2273 5.76704 17.3103C6.1636 18.0809 7.10902 18.3091 7.81763 17.9L8.68366 17.4C9.41014 16.9805 9.62418 16.0789 9.27228 15.3786C9.10619 15.0482 8.95684 14.7133 8.82394 14.3751C10.6243 14.733 12.3353 15.3388 13.9201 16.1555C14.6189 14.2352 15 12.1623 15 10.0004C15 7.83855 14.6189 5.76569 13.9202 3.84536Z M15.2428 3.09699C16.0553 5.24298 16.5 7.56977 16.5 10.0004C16.5 12.4311 16.0553 14.7579 15.2428 16.9039C15.2428 16.9039 15.2427 16.9038 15.2428 16.9039L15.2135 16.9811C15.0652 17.3679 15.2585 17.8016 15.6452 17.95C16.032 18.0983 16.4657 17.905 16.6141 17.5183C16.7002 17.2938 16.7825 17.0674 16.8611 16.8393C17.4152 15.2298 17.7791 13.532 17.9262 11.7733C18.5645 11.4393 19 10.7712 19 10.0004C19 9.22971 18.5645 8.56163 17.9262 8.22758C17.7791 6.46885 17.4152 4.77112 16.8611 3.16156C16.7825 2.93344 16.7002 2.7071 16.6141 2.48262C16.4657 2.09587 16.032 1.90259 15.6452 2.05091C15.2585 2.19924 15.0652 2.633 15.2135 3.01974L15.2428 3.09699C15.2427 3.09702 15.2428 3.09695 15.2428 3.09699Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "microphone" shape.
+#[cfg(any(feature = "microphone", heroicon = "microphone"))]
+pub static MICROPHONE: super::StaticShape = super::StaticShape::new(
+    "microphone",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7 4C7 2.34315 8.34315 1 10 1C11.6569 1 13 2.34315 13 4V10C13 11.6569 11.6569 13 10 13C8.34315 13 7 11.6569 7 10V4Z M5.5 9.64282C5.5 9.22861 5.16421 8.89282 4.75 8.89282C4.33579 8.89282 4 9.22861 4 9.64282V9.99997C4 13.0597 6.29027 15.5845 9.25 15.9535V17.5H7.75C7.33579 17.5 7 17.8358 7 18.25C7 18.6642 7.33579 19 7.75 19H12.25C12.6642 19 13 18.6642 13 18.25C13 17.8358 12.6642 17.5 12.25 17.5H10.75V15.9535C13.7097 15.5845 16 13.0597 16 9.99997V9.64282C16 9.22861 15.6642 8.89282 15.25 8.89282C14.8358 8.89282 14.5 9.22861 14.5 9.64282V9.99997C14.5 12.4852 12.4853 14.5 10 14.5C7.51472 14.5 5.5 12.4852 5.5 9.99997V9.64282Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "minus-circle" shape.
+#[cfg(any(feature = "minus-circle", heroicon = "minus-circle"))]
+pub static MINUS_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "minus-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 18C14.4183 18 18 14.4183 18 10C18 5.58172 14.4183 2 10 2C5.58172 2 2 5.58172 2 10C2 14.4183 5.58172 18 10 18ZM6.75 9.25C6.33579 9.25 6 9.58579 6 10C6 10.4142 6.33579 10.75 6.75 10.75H13.25C13.6642 10.75 14 10.4142 14 10C14 9.58579 13.6642 9.25 13.25 9.25H6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "minus-small" shape.
+#[cfg(any(feature = "minus-small", heroicon = "minus-small"))]
+pub static MINUS_SMALL: super::StaticShape = super::StaticShape::new(
+    "minus-small",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.75 9.25C6.33579 9.25 6 9.58579 6 10C6 10.4142 6.33579 10.75 6.75 10.75L13.25 10.75C13.6642 10.75 14 10.4142 14 10C14 9.58579 13.6642 9.25 13.25 9.25H6.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "minus" shape.
+#[cfg(any(feature = "minus", heroicon = "minus"))]
+pub static MINUS: super::StaticShape = super::StaticShape::new(
+    "minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4 10C4 9.58579 4.33579 9.25 4.75 9.25L15.25 9.25C15.6642 9.25 16 9.58579 16 10C16 10.4142 15.6642 10.75 15.25 10.75L4.75 10.75C4.33579 10.75 4 10.4142 4 10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "moon" shape.
+#[cfg(any(feature = "moon", heroicon = "moon"))]
+pub static MOON: super::StaticShape = super::StaticShape::new(
+    "moon",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.45519 2.00383C7.68518 2.18752 7.78646 2.48878 7.71414 2.77411C7.57443 3.32534 7.5 3.90336 7.5 4.49984C7.5 8.36583 10.634 11.4998 14.5 11.4998C15.6435 11.4998 16.721 11.2262 17.6724 10.7415C17.9347 10.6079 18.2509 10.6401 18.4809 10.8238C18.7109 11.0075 18.8122 11.3088 18.7399 11.5941C17.8069 15.2754 14.4725 17.9998 10.5 17.9998C5.80558 17.9998 2 14.1943 2 9.49984C2 6.19127 3.89048 3.32555 6.64671 1.92156C6.909 1.78795 7.22519 1.82013 7.45519 2.00383Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "musical-note" shape.
+#[cfg(any(feature = "musical-note", heroicon = "musical-note"))]
+pub static MUSICAL_NOTE: super::StaticShape = super::StaticShape::new(
+    "musical-note",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.721 1.59894C17.8974 1.74132 18 1.95589 18 2.18262V13.4723C18 14.5317 17.261 15.4476 16.2255 15.6715L14.1848 16.1127C12.804 16.4113 11.5 15.3591 11.5 13.9464C11.5 12.9032 12.2275 12.0012 13.247 11.7803L15.9088 11.2035C16.2538 11.1287 16.5 10.8235 16.5 10.4705V6.11212L8.5 7.84185V15.5263C8.5 16.5857 7.76097 17.5016 6.7255 17.7255L4.68397 18.1669C3.30357 18.4654 2 17.4136 2 16.0013C2 14.9574 2.72854 14.0552 3.74894 13.8353L6.408 13.2622C6.7534 13.1878 7 12.8824 7 12.5291V4.23668C7 3.88354 7.24634 3.57825 7.5915 3.50362L17.0915 1.44956C17.3131 1.40165 17.5445 1.45656 17.721 1.59894Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
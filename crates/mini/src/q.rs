@@ -0,0 +1,41 @@
+//! The shapes whose names start with "q".
+/// The "qr-code" shape.
+#[cfg(any(feature = "qr-code", heroicon = "qr-code"))]
+pub static QR_CODE: super::StaticShape = super::StaticShape::new(
+    "qr-code",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 2C2.7835 2 2 2.7835 2 3.75V7.25C2 8.2165 2.7835 9 3.75 9H7.25C8.2165 9 9 8.2165 9 7.25V3.75C9 2.7835 8.2165 2 7.25 2H3.75ZM3.5 3.75C3.5 3.61193 3.61193 3.5 3.75 3.5H7.25C7.38807 3.5 7.5 3.61193 7.5 3.75V7.25C7.5 7.38807 7.38807 7.5 7.25 7.5H3.75C3.61193 7.5 3.5 7.38807 3.5 7.25V3.75ZM3.75 11C2.7835 11 2 11.7835 2 12.75V16.25C2 17.2165 2.7835 18 3.75 18H7.25C8.2165 18 9 17.2165 9 16.25V12.75C9 11.7835 8.2165 11 7.25 11H3.75ZM3.5 12.75C3.5 12.6119 3.61193 12.5 3.75 12.5H7.25C7.38807 12.5 7.5 12.6119 7.5 12.75V16.25C7.5 16.3881 7.38807 16.5 7.25 16.5H3.75C3.61193 16.5 3.5 16.3881 3.5 16.25V12.75ZM11 3.75C11 2.7835 11.7835 2 12.75 2H16.25C17.2165 2 18 2.7835 18 3.75V7.25C18 8.2165 17.2165 9 16.25 9H12.75C11.7835 9 11 8.2165 11 7.25V3.75ZM12.75 3.5C12.6119 3.5 12.5 3.61193 12.5 3.75V7.25C12.5 7.38807 12.6119 7.5 12.75 7.5H16.25C16.3881 7.5 16.5 7.38807 16.5 7.25V3.75C16.5 3.61193 16.3881 3.5 16.25 3.5H12.75ZM5.49023 4.5C4.93795 4.5 4.49023 4.94772 4.49023 5.5V5.51C4.49023 6.06228 4.93795 6.51 5.49023 6.51H5.50023C6.05252 6.51 6.50023 6.06228 6.50023 5.51V5.5C6.50023 4.94772 6.05252 4.5 5.50023 4.5H5.49023ZM14.4902 4.5C13.9379 4.5 13.4902 4.94772 13.4902 5.5V5.51C13.4902 6.06228 13.9379 6.51 14.4902 6.51H14.5002C15.0525 6.51 15.5002 6.06228 15.5002 5.51V5.5C15.5002 4.94772 15.0525 4.5 14.5002 4.5H14.4902ZM5.49023 13.5C4.93795 13.5 4.49023 13.9477 4.49023 14.5V14.51C4.49023 15.0623 4.93795 15.51 5.49023 15.51H5.50023C6.05252 15.51 6.50023 15.0623 6.50023 14.51V14.5C6.50023 13.9477 6.05252 13.5 5.50023 13.5H5.49023ZM14.4902 13.5C13.9379 13.5 13.4902 13.9477 13.4902 14.5V14.51C13.4902 15.0623 13.9379 15.51 14.4902 15.51H14.5002C15.0525 15.51 15.5002 15.0623 15.5002 14.51V14.5C15.5002 13.9477 15.0525 13.5 14.5002 13.5H14.4902ZM10.9902 12C10.9902 11.4477 11.4379 11 11.9902 11H12.0002C12.5525 11 13.0002 11.4477 13.0002 12V12.01C13.0002 12.5623 12.5525 13.01 12.0002 13.01H11.9902C11.4379 13.01 10.9902 12.5623 10.9902 12.01V12ZM16.9902 11C16.4379 11 15.9902 11.4477 15.9902 12V12.01C15.9902 12.5623 16.4379 13.01 16.9902 13.01H17.0002C17.5525 13.01 18.0002 12.5623 18.0002 12.01V12C18.0002 11.4477 17.5525 11 17.0002 11H16.9902ZM15.9902 17C15.9902 16.4477 16.4379 16 16.9902 16H17.0002C17.5525 16 18.0002 16.4477 18.0002 17V17.01C18.0002 17.5623 17.5525 18.01 17.0002 18.01H16.9902C16.4379 18.01 15.9902 17.5623 15.9902 17.01V17ZM11.9902 16C11.4379 16 10.9902 16.4477 10.9902 17V17.01C10.9902 17.5623 11.4379 18.01 11.9902 18.01H12.0002C12.5525 18.01 13.0002 17.5623 13.0002 17.01V17C13.0002 16.4477 12.5525 16 12.0002 16H11.9902Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "question-mark-circle" shape.
+#[cfg(any(feature = "question-mark-circle", heroicon = "question-mark-circle"))]
+pub static QUESTION_MARK_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "question-mark-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18 10C18 14.4183 14.4183 18 10 18C5.58172 18 2 14.4183 2 10C2 5.58172 5.58172 2 10 2C14.4183 2 18 5.58172 18 10ZM8.93934 6.93931C8.64645 7.23221 8.17157 7.23221 7.87868 6.93931C7.58579 6.64642 7.58579 6.17155 7.87868 5.87865C9.05025 4.70708 10.9497 4.70708 12.1213 5.87865C13.2929 7.05023 13.2929 8.94972 12.1213 10.1213C11.7288 10.5138 11.2528 10.7756 10.75 10.9051V11.25C10.75 11.6642 10.4142 12 10 12C9.58579 12 9.25 11.6642 9.25 11.25V10.75C9.25 10.0297 9.81995 9.57826 10.3313 9.46322C10.5982 9.40318 10.8516 9.26969 11.0607 9.06063C11.6464 8.47485 11.6464 7.5251 11.0607 6.93931C10.4749 6.35353 9.52513 6.35353 8.93934 6.93931ZM10 15C10.5523 15 11 14.5523 11 14C11 13.4477 10.5523 13 10 13C9.44771 13 9 13.4477 9 14C9 14.5523 9.44771 15 10 15Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "queue-list" shape.
+#[cfg(any(feature = "queue-list", heroicon = "queue-list"))]
+pub static QUEUE_LIST: super::StaticShape = super::StaticShape::new(
+    "queue-list",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.5C2 3.11929 3.11929 2 4.5 2H15.5C16.8807 2 18 3.11929 18 4.5C18 5.88071 16.8807 7 15.5 7H4.5C3.11929 7 2 5.88071 2 4.5Z M2.75 9.08337C2.33579 9.08337 2 9.41916 2 9.83337C2 10.2476 2.33579 10.5834 2.75 10.5834H17.25C17.6642 10.5834 18 10.2476 18 9.83337C18 9.41916 17.6642 9.08337 17.25 9.08337H2.75Z M2.75 12.6633C2.33579 12.6633 2 12.9991 2 13.4133C2 13.8275 2.33579 14.1633 2.75 14.1633H17.25C17.6642 14.1633 18 13.8275 18 13.4133C18 12.9991 17.6642 12.6633 17.25 12.6633H2.75Z M2.75 16.25C2.33579 16.25 2 16.5858 2 17C2 17.4143 2.33579 17.75 2.75 17.75H17.25C17.6642 17.75 18 17.4143 18 17C18 16.5858 17.6642 16.25 17.25 16.25H2.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,95 @@
+//! The shapes whose names start with "r".
+/// The "radio" shape.
+#[cfg(any(feature = "radio", heroicon = "radio"))]
+pub static RADIO: super::StaticShape = super::StaticShape::new(
+    "radio",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.4497 3.47318C17.849 3.36303 18.0834 2.95004 17.9732 2.55074C17.8631 2.15144 17.4501 1.91704 17.0508 2.02719L5.31247 5.26535C4.47281 5.36094 3.64077 5.4819 2.81717 5.62745C1.74546 5.81685 1 6.75598 1 7.81626V15.7502C1 16.9929 2.00736 18.0002 3.25 18.0002H16.75C17.9926 18.0002 19 16.9929 19 15.7502V7.81626C19 6.75598 18.2545 5.81685 17.1828 5.62745C15.417 5.31538 13.6124 5.11637 11.777 5.03807L17.4497 3.47318ZM16 9.50024C16 10.3287 15.3284 11.0002 14.5 11.0002C13.6716 11.0002 13 10.3287 13 9.50024C13 8.67182 13.6716 8.00024 14.5 8.00024C15.3284 8.00024 16 8.67182 16 9.50024ZM14.5 16.0002C15.3284 16.0002 16 15.3287 16 14.5002C16 13.6718 15.3284 13.0002 14.5 13.0002C13.6716 13.0002 13 13.6718 13 14.5002C13 15.3287 13.6716 16.0002 14.5 16.0002ZM5.24023 11.0002C5.24023 10.586 5.57602 10.2502 5.99023 10.2502H6.00023C6.41445 10.2502 6.75023 10.586 6.75023 11.0002V11.0102C6.75023 11.4245 6.41445 11.7602 6.00023 11.7602H5.99023C5.57602 11.7602 5.24023 11.4245 5.24023 11.0102V11.0002ZM7.99023 10.2502C7.57602 10.2502 7.24023 10.586 7.24023 11.0002V11.0102C7.24023 11.4245 7.57602 11.7602 7.99023 11.7602H8.00023C8.41445 11.7602 8.75023 11.4245 8.75023 11.0102V11.0002C8.75023 10.586 8.41445 10.2502 8.00023 10.2502H7.99023ZM6.24023 8.75024C6.24023 8.33603 6.57602 8.00024 6.99023 8.00024H7.00023C7.41445 8.00024 7.75023 8.33603 7.75023 8.75024V8.76024C7.75023 9.17446 7.41445 9.51024 7.00023 9.51024H6.99023C6.57602 9.51024 6.24023 9.17446 6.24023 8.76024V8.75024ZM9.8233 9.16973C9.53041 8.87684 9.05553 8.87684 8.76264 9.16973L8.75557 9.17681C8.46267 9.4697 8.46267 9.94457 8.75557 10.2375L8.76264 10.2445C9.05553 10.5374 9.53041 10.5374 9.8233 10.2445L9.83037 10.2375C10.1233 9.94457 10.1233 9.4697 9.83037 9.17681L9.8233 9.16973ZM10.25 11.2502C10.6642 11.2502 11 11.586 11 12.0002V12.0102C11 12.4245 10.6642 12.7602 10.25 12.7602H10.24C9.82579 12.7602 9.49 12.4245 9.49 12.0102V12.0002C9.49 11.586 9.82579 11.2502 10.24 11.2502H10.25ZM9.83051 14.8337C10.1234 14.5408 10.1234 14.0659 9.83051 13.773L9.82344 13.7659C9.53055 13.4731 9.05567 13.4731 8.76278 13.7659L8.75571 13.773C8.46281 14.0659 8.46281 14.5408 8.75571 14.8337L8.76278 14.8407C9.05567 15.1336 9.53055 15.1336 9.82344 14.8407L9.83051 14.8337ZM6.24023 15.2502C6.24023 14.836 6.57602 14.5002 6.99023 14.5002H7.00023C7.41445 14.5002 7.75023 14.836 7.75023 15.2502V15.2602C7.75023 15.6745 7.41445 16.0102 7.00023 16.0102H6.99023C6.57602 16.0102 6.24023 15.6745 6.24023 15.2602V15.2502ZM5.22711 13.7659C4.93421 13.473 4.45934 13.473 4.16645 13.7659L4.15938 13.773C3.86648 14.0659 3.86648 14.5408 4.15938 14.8337L4.16645 14.8407C4.45934 15.1336 4.93421 15.1336 5.22711 14.8407L5.23418 14.8337C5.52707 14.5408 5.52707 14.0659 5.23418 13.773L5.22711 13.7659ZM3.75 11.2502C4.16421 11.2502 4.5 11.586 4.5 12.0002V12.0102C4.5 12.4245 4.16421 12.7602 3.75 12.7602H3.74C3.32579 12.7602 2.99 12.4245 2.99 12.0102V12.0002C2.99 11.586 3.32579 11.2502 3.74 11.2502H3.75ZM5.23432 10.2375C5.52721 9.94459 5.52721 9.46972 5.23432 9.17682L5.22725 9.16975C4.93435 8.87686 4.45948 8.87686 4.16659 9.16975L4.15952 9.17682C3.86662 9.46972 3.86662 9.94459 4.15952 10.2375L4.16659 10.2446C4.45948 10.5374 4.93435 10.5374 5.22725 10.2446L5.23432 10.2375ZM7.24023 13.0002C7.24023 12.586 7.57602 12.2502 7.99023 12.2502H8.00023C8.41445 12.2502 8.75023 12.586 8.75023 13.0002V13.0102C8.75023 13.4245 8.41445 13.7602 8.00023 13.7602H7.99023C7.57602 13.7602 7.24023 13.4245 7.24023 13.0102V13.0002ZM5.99023 12.2502C5.57602 12.2502 5.24023 12.586 5.24023 13.0002V13.0102C5.24023 13.4245 5.57602 13.7602 5.99023 13.7602H6.00023C6.41445 13.7602 6.75023 13.4245 6.75023 13.0102V13.0002C6.75023 12.586 6.41445 12.2502 6.00023 12.2502H5.99023Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "receipt-percent" shape.
+#[cfg(any(feature = "receipt-percent", heroicon = "receipt-percent"))]
+pub static RECEIPT_PERCENT: super::StaticShape = super::StaticShape::new(
+    "receipt-percent",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.93005 1.31046C6.5916 1.10551 8.28365 1 10 1C11.7163 1 13.4084 1.10551 15.07 1.31046C16.1942 1.44913 17 2.41374 17 3.51661V18.25C17 18.5078 16.8676 18.7475 16.6494 18.8848C16.4312 19.0221 16.1578 19.0377 15.9255 18.9261L13.125 17.5819L10.3245 18.9261C10.1194 19.0246 9.88061 19.0246 9.67545 18.9261L6.875 17.5819L4.07455 18.9261C3.84215 19.0377 3.56875 19.0221 3.35057 18.8848C3.13239 18.7475 3 18.5078 3 18.25V3.51661C3 2.41374 3.80579 1.44913 4.93005 1.31046ZM13.7803 7.28033C14.0732 6.98744 14.0732 6.51256 13.7803 6.21967C13.4874 5.92678 13.0126 5.92678 12.7197 6.21967L6.21967 12.7197C5.92678 13.0126 5.92678 13.4874 6.21967 13.7803C6.51256 14.0732 6.98744 14.0732 7.28033 13.7803L13.7803 7.28033ZM9 8C9 8.55228 8.55228 9 8 9C7.44772 9 7 8.55228 7 8C7 7.44772 7.44772 7 8 7C8.55228 7 9 7.44772 9 8ZM12 13C12.5523 13 13 12.5523 13 12C13 11.4477 12.5523 11 12 11C11.4477 11 11 11.4477 11 12C11 12.5523 11.4477 13 12 13Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "receipt-refund" shape.
+#[cfg(any(feature = "receipt-refund", heroicon = "receipt-refund"))]
+pub static RECEIPT_REFUND: super::StaticShape = super::StaticShape::new(
+    "receipt-refund",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.93005 1.31046C6.5916 1.10551 8.28365 1 10 1C11.7163 1 13.4084 1.10551 15.07 1.31046C16.1942 1.44913 17 2.41374 17 3.51661V18.25C17 18.5078 16.8676 18.7475 16.6494 18.8848C16.4312 19.0221 16.1578 19.0377 15.9255 18.9261L13.125 17.5819L10.3245 18.9261C10.1194 19.0246 9.88061 19.0246 9.67545 18.9261L6.875 17.5819L4.07455 18.9261C3.84215 19.0377 3.56875 19.0221 3.35057 18.8848C3.13239 18.7475 3 18.5078 3 18.25V3.51661C3 2.41374 3.80579 1.44913 4.93005 1.31046ZM9.75172 6.30747C10.0596 6.03038 10.0846 5.55616 9.80747 5.24828C9.53038 4.94039 9.05616 4.91544 8.74828 5.19253L6.24828 7.44253C6.09024 7.58476 6 7.78738 6 8C6 8.21262 6.09024 8.41524 6.24828 8.55747L8.74828 10.8075C9.05616 11.0846 9.53038 11.0596 9.80747 10.7517C10.0846 10.4438 10.0596 9.96962 9.75172 9.69253L8.70447 8.75H10.625C11.6605 8.75 12.5 9.58947 12.5 10.625C12.5 11.6605 11.6605 12.5 10.625 12.5C10.2108 12.5 9.875 12.8358 9.875 13.25C9.875 13.6642 10.2108 14 10.625 14C12.489 14 14 12.489 14 10.625C14 8.76104 12.489 7.25 10.625 7.25H8.70447L9.75172 6.30747Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "rectangle-group" shape.
+#[cfg(any(feature = "rectangle-group", heroicon = "rectangle-group"))]
+pub static RECTANGLE_GROUP: super::StaticShape = super::StaticShape::new(
+    "rectangle-group",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.5 3C1.67157 3 1 3.67157 1 4.5V8.5C1 9.32843 1.67157 10 2.5 10H8.5C9.32843 10 10 9.32843 10 8.5V4.5C10 3.67157 9.32843 3 8.5 3H2.5ZM13.5 5C12.6716 5 12 5.67157 12 6.5V13.5C12 14.3284 12.6716 15 13.5 15H17.5C18.3284 15 19 14.3284 19 13.5V6.5C19 5.67157 18.3284 5 17.5 5H13.5ZM3.5 12C2.67157 12 2 12.6716 2 13.5V15.5C2 16.3284 2.67157 17 3.5 17H9.5C10.3284 17 11 16.3284 11 15.5V13.5C11 12.6716 10.3284 12 9.5 12H3.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "rectangle-stack" shape.
+#[cfg(any(feature = "rectangle-stack", heroicon = "rectangle-stack"))]
+pub static RECTANGLE_STACK: super::StaticShape = super::StaticShape::new(
+    "rectangle-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.12744 3.50197C5.16817 3.50066 5.20906 3.5 5.25011 3.5H14.7501C14.7912 3.5 14.832 3.50066 14.8728 3.50197C14.5645 2.62705 13.7305 2 12.7501 2H7.25011C6.26971 2 5.43576 2.62705 5.12744 3.50197Z M1 10.25C1 9.00736 2.00736 8 3.25 8H16.75C17.9926 8 19 9.00736 19 10.25V15.75C19 16.9926 17.9926 18 16.75 18H3.25C2.00736 18 1 16.9926 1 15.75V10.25Z M3.25011 6.5C3.20906 6.5 3.16817 6.50066 3.12744 6.50197C3.43576 5.62705 4.26971 5 5.25011 5H14.7501C15.7305 5 16.5645 5.62705 16.8728 6.50197C16.832 6.50066 16.7912 6.5 16.7501 6.5H3.25011Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "rocket-launch" shape.
+#[cfg(any(feature = "rocket-launch", heroicon = "rocket-launch"))]
+pub static ROCKET_LAUNCH: super::StaticShape = super::StaticShape::new(
+    "rocket-launch",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.60597 12.9691C4.85946 13.2967 4.79938 13.7678 4.47179 14.0213C3.87925 14.4798 3.5 15.1954 3.5 16C3.5 16.1569 3.51437 16.31 3.54177 16.4582C3.68996 16.4856 3.84307 16.5 4 16.5C4.80458 16.5 5.52023 16.1208 5.97873 15.5282C6.23222 15.2006 6.70328 15.1405 7.03087 15.394C7.35846 15.6475 7.41854 16.1186 7.16505 16.4462C6.4347 17.39 5.2884 18 4 18C3.54003 18 3.09667 17.9221 2.6834 17.778C2.46722 17.7027 2.29728 17.5328 2.22195 17.3166C2.07794 16.9033 2 16.46 2 16C2 14.7116 2.60997 13.5653 3.55383 12.835C3.88142 12.5815 4.35248 12.6415 4.60597 12.9691Z M5.75236 12C6.40714 12.8376 7.16237 13.5929 8 14.2476V18.25C8 18.6642 8.33579 19 8.75 19C11.5114 19 13.75 16.7614 13.75 14C13.75 13.5097 13.6792 13.035 13.5471 12.5861C16.8467 10.2297 19 6.36651 19 2C19 1.91234 18.9991 1.82486 18.9974 1.73759C18.9894 1.33502 18.665 1.01058 18.2624 1.0026C18.1751 1.00087 18.0877 1 18 1C13.6335 1 9.77032 3.15331 7.41387 6.45291C6.96496 6.32078 6.49028 6.25 6 6.25C3.23858 6.25 1 8.48858 1 11.25C1 11.6642 1.33579 12 1.75 12H5.75236ZM13 9C14.1046 9 15 8.10457 15 7C15 5.89543 14.1046 5 13 5C11.8954 5 11 5.89543 11 7C11 8.10457 11.8954 9 13 9Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "rss" shape.
+#[cfg(any(feature = "rss", heroicon = "rss"))]
+pub static RSS: super::StaticShape = super::StaticShape::new(
+    "rss",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 3C3.33579 3 3 3.33579 3 3.75V4.25C3 4.66421 3.33579 5 3.75 5H4C10.0751 5 15 9.92487 15 16V16.25C15 16.6642 15.3358 17 15.75 17H16.25C16.6642 17 17 16.6642 17 16.25V16C17 8.8203 11.1797 3 4 3H3.75Z M3 8.75C3 8.33579 3.33579 8 3.75 8H4C8.41828 8 12 11.5817 12 16V16.25C12 16.6642 11.6642 17 11.25 17H10.75C10.3358 17 10 16.6642 10 16.25V16C10 12.6863 7.31371 10 4 10H3.75C3.33579 10 3 9.66421 3 9.25V8.75Z M7 15C7 16.1046 6.10457 17 5 17C3.89543 17 3 16.1046 3 15C3 13.8954 3.89543 13 5 13C6.10457 13 7 13.8954 7 15Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,299 @@
+//! The shapes whose names start with "s".
+/// The "scale" shape.
+#[cfg(any(feature = "scale", heroicon = "scale"))]
+pub static SCALE: super::StaticShape = super::StaticShape::new(
+    "scale",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 2C10.4143 2 10.75 2.33579 10.75 2.75V3.00836C12.231 3.0414 13.6882 3.17204 15.1154 3.39419C15.8922 3.51509 16.66 3.6631 17.418 3.83721C17.8217 3.92994 18.0737 4.33239 17.981 4.73608C17.8883 5.13978 17.4858 5.39187 17.0821 5.29913C16.7168 5.21521 16.3491 5.13764 15.979 5.06655L17.6814 12.6123C17.7565 12.9452 17.5975 13.2868 17.2944 13.4435C16.6064 13.7994 15.8256 14 15 14C14.1745 14 13.3937 13.7994 12.7057 13.4435C12.4026 13.2868 12.2436 12.9452 12.3187 12.6123L14.0896 4.76296C12.9933 4.62087 11.8791 4.53512 10.75 4.50875V16.0138C12.0446 16.0616 13.3083 16.2324 14.5296 16.5153C14.9331 16.6087 15.1845 17.0116 15.091 17.4151C14.9976 17.8187 14.5947 18.07 14.1912 17.9766C12.8452 17.6649 11.4424 17.5 10 17.5C8.55772 17.5 7.15486 17.6649 5.80893 17.9766C5.40539 18.07 5.00251 17.8187 4.90906 17.4151C4.81561 17.0116 5.06699 16.6087 5.47052 16.5153C6.69183 16.2324 7.95553 16.0616 9.25004 16.0138V4.50875C8.12096 4.53512 7.00679 4.62087 5.91051 4.76296L7.68142 12.6123C7.75652 12.9452 7.59746 13.2868 7.29437 13.4435C6.6064 13.7994 5.82556 14 5.00004 14C4.17453 14 3.39369 13.7994 2.70572 13.4435C2.40263 13.2868 2.24356 12.9452 2.31866 12.6123L4.02108 5.06655C3.65102 5.13764 3.28327 5.21521 2.91796 5.29913C2.51426 5.39187 2.11182 5.13978 2.01908 4.73608C1.92635 4.33239 2.17843 3.92994 2.58213 3.83721C3.34006 3.6631 4.10791 3.51509 4.8847 3.39419C6.31192 3.17204 7.76912 3.0414 9.25004 3.00836V2.75C9.25004 2.33579 9.58583 2 10 2ZM5.00004 7.54309L3.91997 12.3304C4.25962 12.4404 4.62241 12.5 5.00004 12.5C5.37768 12.5 5.74047 12.4404 6.08012 12.3304L5.00004 7.54309ZM15 7.5431L13.92 12.3304C14.2596 12.4404 14.6224 12.5 15 12.5C15.3777 12.5 15.7405 12.4404 16.0801 12.3304L15 7.5431Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "scissors" shape.
+#[cfg(any(feature = "scissors", heroicon = "scissors"))]
+pub static SCISSORS: super::StaticShape = super::StaticShape::new(
+    "scissors",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1.46876 3.7501C0.502265 5.42412 1.07583 7.56469 2.74985 8.53119C4.20093 9.36897 6.00256 9.04958 7.08619 7.85934L7.9695 8.36931C7.99431 8.46193 8.11599 8.4862 8.17843 8.41344C8.32967 8.23719 8.49725 8.07501 8.67914 7.92923C8.96545 7.69975 8.979 7.22009 8.66123 7.03663L7.83619 6.56029C8.32514 5.02673 7.70093 3.30679 6.24985 2.46901C4.57583 1.50251 2.43526 2.07607 1.46876 3.7501ZM3.49985 7.23215C2.54327 6.67986 2.21552 5.45668 2.7678 4.5001C3.32009 3.54351 4.54327 3.21576 5.49985 3.76805C6.45644 4.32033 6.78419 5.54351 6.2319 6.5001C5.67962 7.45668 4.45644 7.78443 3.49985 7.23215Z M9.95556 8.32179C9.17367 8.65167 8.58789 9.32406 8.36825 10.1438L7.96977 11.6309L7.08628 12.141C6.00265 10.9507 4.20097 10.6312 2.74985 11.4691C1.07583 12.4355 0.502265 14.5761 1.46876 16.2501C2.43526 17.9242 4.57583 18.4977 6.24985 17.5312C7.70089 16.6935 8.32511 14.9736 7.83623 13.4401L18.5151 7.27461C18.7778 7.12294 18.9233 6.82795 18.8837 6.5272C18.8441 6.22645 18.6272 5.97916 18.3342 5.90065L17.631 5.71223C17.0403 5.55395 16.4137 5.59708 15.8503 5.8348L9.95556 8.32179ZM2.7678 15.5001C2.21552 14.5436 2.54327 13.3204 3.49985 12.7681C4.45644 12.2158 5.67962 12.5436 6.2319 13.5001C6.78419 14.4567 6.45644 15.6799 5.49985 16.2322C4.54327 16.7845 3.32009 16.4567 2.7678 15.5001Z M12.5201 11.8903C12.1624 12.0968 12.1952 12.6235 12.5758 12.784L15.85 14.1654C16.4134 14.4031 17.04 14.4463 17.6307 14.288L18.3339 14.0996C18.6269 14.0211 18.8438 13.7738 18.8834 13.473C18.923 13.1723 18.7775 12.8773 18.5148 12.7256L15.0441 10.7218C14.8894 10.6325 14.6988 10.6325 14.5441 10.7218L12.5201 11.8903Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "server-stack" shape.
+#[cfg(any(feature = "server-stack", heroicon = "server-stack"))]
+pub static SERVER_STACK: super::StaticShape = super::StaticShape::new(
+    "server-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.46363 3.16188C4.79054 2.45358 5.49945 2 6.27955 2H13.7202C14.5003 2 15.2092 2.45358 15.5361 3.16188L16.69 5.66188C16.7567 5.80652 16.8047 5.9534 16.8352 6.1003C16.5675 6.03476 16.2878 6 15.9999 6H3.99989C3.712 6 3.43223 6.03476 3.16455 6.10031C3.19509 5.9534 3.24303 5.80653 3.30979 5.66188L4.46363 3.16188Z M2 9.5C2 8.39543 2.89543 7.5 4 7.5H16C17.1046 7.5 18 8.39543 18 9.5C18 10.6046 17.1046 11.5 16 11.5H4C2.89543 11.5 2 10.6046 2 9.5ZM15.2402 9.5C15.2402 9.08579 15.576 8.75 15.9902 8.75H16.0002C16.4144 8.75 16.7502 9.08579 16.7502 9.5V9.51C16.7502 9.92421 16.4144 10.26 16.0002 10.26H15.9902C15.576 10.26 15.2402 9.92421 15.2402 9.51V9.5ZM12.9902 8.75C12.576 8.75 12.2402 9.08579 12.2402 9.5V9.51C12.2402 9.92421 12.576 10.26 12.9902 10.26H13.0002C13.4144 10.26 13.7502 9.92421 13.7502 9.51V9.5C13.7502 9.08579 13.4144 8.75 13.0002 8.75H12.9902Z M2 15C2 13.8954 2.89543 13 4 13H16C17.1046 13 18 13.8954 18 15C18 16.1046 17.1046 17 16 17H4C2.89543 17 2 16.1046 2 15ZM15.2402 15C15.2402 14.5858 15.576 14.25 15.9902 14.25H16.0002C16.4144 14.25 16.7502 14.5858 16.7502 15V15.01C16.7502 15.4242 16.4144 15.76 16.0002 15.76H15.9902C15.576 15.76 15.2402 15.4242 15.2402 15.01V15ZM12.9902 14.25C12.576 14.25 12.2402 14.5858 12.2402 15V15.01C12.2402 15.4242 12.576 15.76 12.9902 15.76H13.0002C13.4144 15.76 13.7502 15.4242 13.7502 15.01V15C13.7502 14.5858 13.4144 14.25 13.0002 14.25H12.9902Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "server" shape.
+#[cfg(any(feature = "server", heroicon = "server"))]
+pub static SERVER: super::StaticShape = super::StaticShape::new(
+    "server",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.63196 3.53326C4.84778 2.63402 5.65196 2 6.57674 2H13.4231C14.3479 2 15.1521 2.63401 15.3679 3.53325L17.3441 11.7674C16.9303 11.5951 16.4762 11.5 15.9999 11.5H3.99994C3.52368 11.5 3.06963 11.5951 2.65576 11.7674L4.63196 3.53326Z M4 13C2.89543 13 2 13.8954 2 15C2 16.1046 2.89543 17 4 17H16C17.1046 17 18 16.1046 18 15C18 13.8954 17.1046 13 16 13H4ZM15.2402 15C15.2402 14.5858 15.576 14.25 15.9902 14.25H16.0002C16.4144 14.25 16.7502 14.5858 16.7502 15V15.01C16.7502 15.4242 16.4144 15.76 16.0002 15.76H15.9902C15.576 15.76 15.2402 15.4242 15.2402 15.01V15ZM12.9902 14.25C12.576 14.25 12.2402 14.5858 12.2402 15V15.01C12.2402 15.4242 12.576 15.76 12.9902 15.76H13.0002C13.4144 15.76 13.7502 15.4242 13.7502 15.01V15C13.7502 14.5858 13.4144 14.25 13.0002 14.25H12.9902Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "share" shape.
+#[cfg(any(feature = "share", heroicon = "share"))]
+pub static SHARE: super::StaticShape = super::StaticShape::new(
+    "share",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13 4.5C13 3.11929 14.1193 2 15.5 2C16.8807 2 18 3.11929 18 4.5C18 5.88071 16.8807 7 15.5 7C14.7943 7 14.1569 6.70762 13.7024 6.23739L6.96884 9.60415C6.98935 9.73308 7 9.8653 7 10C7 10.1347 6.98934 10.267 6.96882 10.3959L13.7023 13.7627C14.1569 13.2924 14.7943 13 15.5 13C16.8807 13 18 14.1193 18 15.5C18 16.8807 16.8807 18 15.5 18C14.1193 18 13 16.8807 13 15.5C13 15.3653 13.0107 15.2331 13.0312 15.1041L6.29764 11.7374C5.84307 12.2076 5.20568 12.5 4.5 12.5C3.11929 12.5 2 11.3807 2 10C2 8.61929 3.11929 7.5 4.5 7.5C5.20571 7.5 5.84312 7.79241 6.29769 8.26267L13.0312 4.89593C13.0107 4.76697 13 4.63473 13 4.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "shield-check" shape.
+#[cfg(any(feature = "shield-check", heroicon = "shield-check"))]
+pub static SHIELD_CHECK: super::StaticShape = super::StaticShape::new(
+    "shield-check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.66109 2.23657C9.85709 2.07381 10.1429 2.07381 10.3389 2.23657C12.2777 3.84642 14.7315 4.85747 17.4166 4.98601C17.658 4.99756 17.8641 5.17189 17.8957 5.41143C17.9645 5.93129 18 6.46164 18 7.00028C18 12.1627 14.7401 16.5637 10.1665 18.2572C10.0593 18.2969 9.94102 18.2969 9.83378 18.2572C5.26004 16.5638 2 12.1627 2 7.00016C2 6.46156 2.03548 5.93125 2.10424 5.41144C2.13593 5.17189 2.34202 4.99756 2.58337 4.98601C5.2685 4.85748 7.72231 3.84643 9.66109 2.23657ZM13.8566 8.19113C14.1002 7.85614 14.0261 7.38708 13.6911 7.14345C13.3561 6.89982 12.8871 6.97388 12.6434 7.30887L9.15969 12.099L7.28033 10.2197C6.98744 9.92678 6.51256 9.92678 6.21967 10.2197C5.92678 10.5126 5.92678 10.9874 6.21967 11.2803L8.71967 13.7803C8.87477 13.9354 9.08999 14.0149 9.30867 13.9977C9.52734 13.9805 9.72754 13.8685 9.85655 13.6911L13.8566 8.19113Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "shield-exclamation" shape.
+#[cfg(any(feature = "shield-exclamation", heroicon = "shield-exclamation"))]
+pub static SHIELD_EXCLAMATION: super::StaticShape = super::StaticShape::new(
+    "shield-exclamation",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.3389 2.23681C10.1429 2.07406 9.85709 2.07406 9.66109 2.23681C7.72231 3.84667 5.2685 4.85772 2.58337 4.98625C2.34202 4.99781 2.13593 5.17213 2.10424 5.41168C2.03548 5.9315 2 6.46181 2 7.00041C2 12.163 5.26004 16.5641 9.83378 18.2574C9.94102 18.2971 10.0593 18.2971 10.1665 18.2574C14.7401 16.564 18 12.163 18 7.00053C18 6.46188 17.9645 5.93153 17.8957 5.41168C17.8641 5.17213 17.658 4.99781 17.4166 4.98625C14.7315 4.85772 12.2777 3.84667 10.3389 2.23681ZM10 6.00018C10.4142 6.00018 10.75 6.33597 10.75 6.75018V10.2502C10.75 10.6644 10.4142 11.0002 10 11.0002C9.58579 11.0002 9.25 10.6644 9.25 10.2502L9.25 6.75018C9.25 6.33597 9.58579 6.00018 10 6.00018ZM10 15.0002C10.5523 15.0002 11 14.5525 11 14.0002C11 13.4479 10.5523 13.0002 10 13.0002C9.44772 13.0002 9 13.4479 9 14.0002C9 14.5525 9.44772 15.0002 10 15.0002Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "shopping-bag" shape.
+#[cfg(any(feature = "shopping-bag", heroicon = "shopping-bag"))]
+pub static SHOPPING_BAG: super::StaticShape = super::StaticShape::new(
+    "shopping-bag",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6 5V6H4.66704C3.7593 6 3.00225 6.69407 2.92362 7.5984L2.09753 17.0984C2.00862 18.1209 2.81463 19 3.84095 19H16.1595C17.1858 19 17.9918 18.1209 17.9029 17.0984L17.0768 7.5984C16.9982 6.69407 16.2411 6 15.3334 6H14V5C14 2.79086 12.2091 1 10 1C7.79086 1 6 2.79086 6 5ZM10 2.5C8.61929 2.5 7.5 3.61929 7.5 5V6H12.5V5C12.5 3.61929 11.3807 2.5 10 2.5ZM7.5 10C7.5 11.3807 8.61929 12.5 10 12.5C11.3807 12.5 12.5 11.3807 12.5 10V8.75C12.5 8.33579 12.8358 8 13.25 8C13.6642 8 14 8.33579 14 8.75V10C14 12.2091 12.2091 14 10 14C7.79086 14 6 12.2091 6 10V8.75C6 8.33579 6.33579 8 6.75 8C7.16421 8 7.5 8.33579 7.5 8.75V10Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "shopping-cart" shape.
+#[cfg(any(feature = "shopping-cart", heroicon = "shopping-cart"))]
+pub static SHOPPING_CART: super::StaticShape = super::StaticShape::new(
+    "shopping-cart",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 1.75C1 1.33579 1.33579 1 1.75 1H3.37846C4.25256 1 4.99261 1.64498 5.11205 2.51088L5.17955 3.00024C9.75911 3.01263 14.2281 3.49872 18.5398 4.41236C18.9395 4.49706 19.1979 4.88613 19.1209 5.2874C18.7145 7.40548 18.1717 9.47515 17.502 11.4869C17.4 11.7933 17.1134 12 16.7904 12H6C5.88567 12 5.77351 12.0076 5.66393 12.0223C4.78545 12.14 4.05092 12.7153 3.70796 13.5H17.25C17.6642 13.5 18 13.8358 18 14.25C18 14.6642 17.6642 15 17.25 15H2.75948C2.55068 15 2.35133 14.913 2.2094 14.7598C2.06747 14.6067 1.9958 14.4013 2.01164 14.1931C2.13566 12.5628 3.23526 11.2069 4.72829 10.7066L3.62612 2.71584C3.60906 2.59214 3.50333 2.5 3.37846 2.5H1.75C1.33579 2.5 1 2.16421 1 1.75Z M6 17.5C6 18.3284 5.32843 19 4.5 19C3.67157 19 3 18.3284 3 17.5C3 16.6716 3.67157 16 4.5 16C5.32843 16 6 16.6716 6 17.5Z M15.5 19C16.3284 19 17 18.3284 17 17.5C17 16.6716 16.3284 16 15.5 16C14.6716 16 14 16.6716 14 17.5C14 18.3284 14.6716 19 15.5 19Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "signal-slash" shape.
+#[cfg(any(feature = "signal-slash", heroicon = "signal-slash"))]
+pub static SIGNAL_SLASH: super::StaticShape = super::StaticShape::new(
+    "signal-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.21967 2.21967C2.51256 1.92678 2.98744 1.92678 3.28033 2.21967L10.0626 9.00193C10.565 9.03295 10.967 9.43498 10.9981 9.93741L17.7803 16.7197C18.0732 17.0126 18.0732 17.4874 17.7803 17.7803C17.4874 18.0732 17.0126 18.0732 16.7197 17.7803L9.93741 10.9981C9.43498 10.967 9.03295 10.565 9.00193 10.0626L2.21967 3.28033C1.92678 2.98744 1.92678 2.51256 2.21967 2.21967Z M3.63604 16.364C0.670686 13.3986 0.207189 8.87882 2.24555 5.42753L3.3485 6.53048C1.87267 9.35787 2.32207 12.9287 4.6967 15.3033C4.98959 15.5962 4.98959 16.0711 4.6967 16.364C4.40381 16.6569 3.92893 16.6569 3.63604 16.364Z M6.46447 13.5356C5.08411 12.1552 4.67956 10.1685 5.25082 8.43281L6.51322 9.6952C6.4268 10.6896 6.76411 11.7139 7.52513 12.4749C7.81802 12.7678 7.81802 13.2427 7.52513 13.5356C7.23223 13.8284 6.75736 13.8284 6.46447 13.5356Z M16.364 3.63605C19.3293 6.6014 19.7928 11.1212 17.7545 14.5725L16.6515 13.4695C18.1273 10.6421 17.6779 7.07134 15.3033 4.69671C15.0104 4.40382 15.0104 3.92895 15.3033 3.63605C15.5962 3.34316 16.0711 3.34316 16.364 3.63605Z M13.5355 6.46449C14.9159 7.84484 15.3204 9.83147 14.7492 11.5672L13.4868 10.3048C13.5732 9.31043 13.2359 8.28616 12.4749 7.52515C12.182 7.23225 12.182 6.75738 12.4749 6.46449C12.7678 6.17159 13.2426 6.17159 13.5355 6.46449Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "signal" shape.
+#[cfg(any(feature = "signal", heroicon = "signal"))]
+pub static SIGNAL: super::StaticShape = super::StaticShape::new(
+    "signal",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.364 3.63617C16.0711 3.34328 15.5962 3.34328 15.3033 3.63617C15.0104 3.92907 15.0104 4.40394 15.3033 4.69683C18.2322 7.62577 18.2322 12.3745 15.3033 15.3034C15.0104 15.5963 15.0104 16.0712 15.3033 16.3641C15.5962 16.657 16.0711 16.657 16.364 16.3641C19.8787 12.8494 19.8787 7.15089 16.364 3.63617Z M4.6967 4.69683C4.98959 4.40394 4.98959 3.92907 4.6967 3.63617C4.40381 3.34328 3.92893 3.34328 3.63604 3.63617C0.12132 7.15089 0.12132 12.8494 3.63604 16.3641C3.92893 16.657 4.40381 16.657 4.6967 16.3641C4.98959 16.0712 4.98959 15.5963 4.6967 15.3034C1.76777 12.3745 1.76777 7.62577 4.6967 4.69683Z M12.4749 6.46461C12.7678 6.17171 13.2426 6.17171 13.5355 6.46461C15.4882 8.41723 15.4882 11.5831 13.5355 13.5357C13.2426 13.8286 12.7678 13.8286 12.4749 13.5357C12.182 13.2428 12.182 12.7679 12.4749 12.475C13.8417 11.1082 13.8417 8.8921 12.4749 7.52527C12.182 7.23237 12.182 6.7575 12.4749 6.46461Z M7.52513 6.46461C7.81802 6.7575 7.81802 7.23237 7.52513 7.52527C6.15829 8.8921 6.15829 11.1082 7.52513 12.475C7.81802 12.7679 7.81802 13.2428 7.52513 13.5357C7.23223 13.8286 6.75736 13.8286 6.46447 13.5357C4.51184 11.5831 4.51184 8.41723 6.46447 6.46461C6.75736 6.17171 7.23223 6.17171 7.52513 6.46461Z M11 10.0001C11 10.5524 10.5523 11.0001 10 11.0001C9.44772 11.0001 9 10.5524 9 10.0001C9 9.44783 9.44772 9.00012 10 9.00012C10.5523 9.00012 11 9.44783 11 10.0001Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "sparkles" shape.
+#[cfg(any(feature = "sparkles", heroicon = "sparkles"))]
+pub static SPARKLES: super::StaticShape = super::StaticShape::new(
+    "sparkles",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.9806 1.80388C15.8871 1.33646 15.4767 1 15 1C14.5233 1 14.1129 1.33646 14.0194 1.80388L13.7809 2.99644C13.7017 3.3923 13.3923 3.70174 12.9964 3.78091L11.8039 4.01942C11.3365 4.1129 11 4.52332 11 5C11 5.47668 11.3365 5.8871 11.8039 5.98058L12.9964 6.21909C13.3923 6.29826 13.7017 6.6077 13.7809 7.00356L14.0194 8.19612C14.1129 8.66354 14.5233 9 15 9C15.4767 9 15.8871 8.66354 15.9806 8.19612L16.2191 7.00356C16.2983 6.6077 16.6077 6.29826 17.0036 6.21909L18.1961 5.98058C18.6635 5.8871 19 5.47668 19 5C19 4.52332 18.6635 4.1129 18.1961 4.01942L17.0036 3.78091C16.6077 3.70174 16.2983 3.3923 16.2191 2.99644L15.9806 1.80388Z M6.94868 5.68377C6.81257 5.27543 6.43043 5 6 5C5.56957 5 5.18743 5.27543 5.05132 5.68377L4.36754 7.73509C4.26801 8.03369 4.03369 8.26801 3.73509 8.36754L1.68377 9.05132C1.27543 9.18743 1 9.56957 1 10C1 10.4304 1.27543 10.8126 1.68377 10.9487L3.73509 11.6325C4.03369 11.732 4.26801 11.9663 4.36754 12.2649L5.05132 14.3162C5.18743 14.7246 5.56957 15 6 15C6.43043 15 6.81257 14.7246 6.94868 14.3162L7.63246 12.2649C7.73199 11.9663 7.96631 11.732 8.26491 11.6325L10.3162 10.9487C10.7246 10.8126 11 10.4304 11 10C11 9.56957 10.7246 9.18743 10.3162 9.05132L8.26491 8.36754C7.96631 8.26801 7.73199 8.03369 7.63246 7.73509L6.94868 5.68377Z M13.9487 13.6838C13.8126 13.2754 13.4304 13 13 13C12.5696 13 12.1874 13.2754 12.0513 13.6838L11.8675 14.2351C11.768 14.5337 11.5337 14.768 11.2351 14.8675L10.6838 15.0513C10.2754 15.1874 10 15.5696 10 16C10 16.4304 10.2754 16.8126 10.6838 16.9487L11.2351 17.1325C11.5337 17.232 11.768 17.4663 11.8675 17.7649L12.0513 18.3162C12.1874 18.7246 12.5696 19 13 19C13.4304 19 13.8126 18.7246 13.9487 18.3162L14.1325 17.7649C14.232 17.4663 14.4663 17.232 14.7649 17.1325L15.3162 16.9487C15.7246 16.8126 16 16.4304 16 16C16 15.5696 15.7246 15.1874 15.3162 15.0513L14.7649 14.8675C14.4663 14.768 14.232 14.5337 14.1325 14.2351L13.9487 13.6838Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "speaker-wave" shape.
+#[cfg(any(feature = "speaker-wave", heroicon = "speaker-wave"))]
+pub static SPEAKER_WAVE: super::StaticShape = super::StaticShape::new(
+    "speaker-wave",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 3.75002C10 3.4508 9.82215 3.18021 9.54747 3.06153C9.2728 2.94286 8.95387 2.99879 8.73598 3.20387L4.70257 7.00002H3.16724C2.85725 7.00002 2.5792 7.19074 2.46756 7.47993C2.16534 8.26287 2 9.11302 2 10C2 10.887 2.16534 11.7372 2.46756 12.5201C2.5792 12.8093 2.85725 13 3.16724 13H4.70257L8.73598 16.7962C8.95387 17.0012 9.2728 17.0572 9.54747 16.9385C9.82215 16.8198 10 16.5492 10 16.25V3.75002Z M15.9498 5.05029C15.6569 4.7574 15.182 4.7574 14.8891 5.05029C14.5962 5.34318 14.5962 5.81806 14.8891 6.11095C17.037 8.25883 17.037 11.7412 14.8891 13.8891C14.5962 14.182 14.5962 14.6569 14.8891 14.9498C15.182 15.2427 15.6569 15.2427 15.9498 14.9498C18.6834 12.2161 18.6834 7.78396 15.9498 5.05029Z M13.8287 7.1716C13.5358 6.8787 13.0609 6.8787 12.768 7.1716C12.4751 7.46449 12.4751 7.93936 12.768 8.23226C13.7443 9.20857 13.7443 10.7915 12.768 11.7678C12.4751 12.0607 12.4751 12.5356 12.768 12.8285C13.0609 13.1213 13.5358 13.1213 13.8287 12.8285C15.3908 11.2664 15.3908 8.73369 13.8287 7.1716Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "speaker-x-mark" shape.
+#[cfg(any(feature = "speaker-x-mark", heroicon = "speaker-x-mark"))]
+pub static SPEAKER_X_MARK: super::StaticShape = super::StaticShape::new(
+    "speaker-x-mark",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.54747 3.06153C9.82215 3.18021 10 3.4508 10 3.75002V16.25C10 16.5492 9.82215 16.8198 9.54747 16.9385C9.2728 17.0572 8.95387 17.0012 8.73598 16.7962L4.70257 13H3.16724C2.85725 13 2.5792 12.8093 2.46756 12.5201C2.16534 11.7372 2 10.887 2 10C2 9.11302 2.16534 8.26287 2.46756 7.47993C2.5792 7.19074 2.85725 7.00002 3.16724 7.00002H4.70257L8.73598 3.20387C8.95387 2.99879 9.2728 2.94286 9.54747 3.06153Z M13.2803 7.2197C12.9874 6.92681 12.5126 6.92681 12.2197 7.2197C11.9268 7.51259 11.9268 7.98747 12.2197 8.28036L13.9393 10L12.2197 11.7197C11.9268 12.0126 11.9268 12.4875 12.2197 12.7804C12.5126 13.0733 12.9874 13.0733 13.2803 12.7804L15 11.0607L16.7197 12.7804C17.0126 13.0733 17.4874 13.0733 17.7803 12.7804C18.0732 12.4875 18.0732 12.0126 17.7803 11.7197L16.0607 10L17.7803 8.28036C18.0732 7.98747 18.0732 7.51259 17.7803 7.2197C17.4874 6.92681 17.0126 6.92681 16.7197 7.2197L15 8.93937L13.2803 7.2197Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "square-2-stack" shape.
+#[cfg(any(feature = "square-2-stack", heroicon = "square-2-stack"))]
+pub static SQUARE_2_STACK: super::StaticShape = super::StaticShape::new(
+    "square-2-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.25C2 3.00736 3.00736 2 4.25 2H10.75C11.9926 2 13 3.00736 13 4.25V5.5H9.25C7.17893 5.5 5.5 7.17893 5.5 9.25V13H4.25C3.00736 13 2 11.9926 2 10.75V4.25Z M9.25 7C8.00736 7 7 8.00736 7 9.25V15.75C7 16.9926 8.00736 18 9.25 18H15.75C16.9926 18 18 16.9926 18 15.75V9.25C18 8.00736 16.9926 7 15.75 7H9.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "square-3-stack-3d" shape.
+#[cfg(any(feature = "square-3-stack-3d", heroicon = "square-3-stack-3d"))]
+pub static SQUARE_3_STACK_3D: super::StaticShape = super::StaticShape::new(
+    "square-3-stack-3d",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.19569 12.8694L2.37071 13.353C2.14108 13.4876 2 13.7338 2 14C2 14.2662 2.14108 14.5124 2.37071 14.647L9.62071 18.897C9.85493 19.0343 10.1451 19.0343 10.3793 18.897L17.6293 14.647C17.8589 14.5124 18 14.2662 18 14C18 13.7338 17.8589 13.4876 17.6293 13.353L16.8043 12.8694L11.1379 16.1911C10.4352 16.603 9.56479 16.603 8.86213 16.1911L3.19569 12.8694Z M3.19569 8.86937L2.37071 9.35298C2.14108 9.48759 2 9.73382 2 10C2 10.2662 2.14108 10.5124 2.37071 10.647L9.62071 14.897C9.85493 15.0343 10.1451 15.0343 10.3793 14.897L17.6293 10.647C17.8589 10.5124 18 10.2662 18 10C18 9.73382 17.8589 9.48759 17.6293 9.35298L16.8043 8.86937L11.1379 12.1911C10.4352 12.603 9.56479 12.603 8.86213 12.1911L3.19569 8.86937Z M10.3793 1.10298C10.1451 0.965675 9.85493 0.965675 9.62071 1.10298L2.37071 5.35298C2.14108 5.48759 2 5.73382 2 6C2 6.26618 2.14108 6.51241 2.37071 6.64702L9.62071 10.897C9.85493 11.0343 10.1451 11.0343 10.3793 10.897L17.6293 6.64702C17.8589 6.51241 18 6.26618 18 6C18 5.73382 17.8589 5.48759 17.6293 5.35298L10.3793 1.10298Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "squares-2x2" shape.
+#[cfg(any(feature = "squares-2x2", heroicon = "squares-2x2"))]
+pub static SQUARES_2X2: super::StaticShape = super::StaticShape::new(
+    "squares-2x2",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.25 2C3.00736 2 2 3.00736 2 4.25V6.75C2 7.99264 3.00736 9 4.25 9H6.75C7.99264 9 9 7.99264 9 6.75V4.25C9 3.00736 7.99264 2 6.75 2H4.25ZM4.25 11C3.00736 11 2 12.0074 2 13.25V15.75C2 16.9926 3.00736 18 4.25 18H6.75C7.99264 18 9 16.9926 9 15.75V13.25C9 12.0074 7.99264 11 6.75 11H4.25ZM13.25 2C12.0074 2 11 3.00736 11 4.25V6.75C11 7.99264 12.0074 9 13.25 9H15.75C16.9926 9 18 7.99264 18 6.75V4.25C18 3.00736 16.9926 2 15.75 2H13.25ZM13.25 11C12.0074 11 11 12.0074 11 13.25V15.75C11 16.9926 12.0074 18 13.25 18H15.75C16.9926 18 18 16.9926 18 15.75V13.25C18 12.0074 16.9926 11 15.75 11H13.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "squares-plus" shape.
+#[cfg(any(feature = "squares-plus", heroicon = "squares-plus"))]
+pub static SQUARES_PLUS: super::StaticShape = super::StaticShape::new(
+    "squares-plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 4.25C2 3.00736 3.00736 2 4.25 2H6.75C7.99264 2 9 3.00736 9 4.25V6.75C9 7.99264 7.99264 9 6.75 9H4.25C3.00736 9 2 7.99264 2 6.75V4.25Z M2 13.25C2 12.0074 3.00736 11 4.25 11H6.75C7.99264 11 9 12.0074 9 13.25V15.75C9 16.9926 7.99264 18 6.75 18H4.25C3.00736 18 2 16.9926 2 15.75V13.25Z M11 4.25C11 3.00736 12.0074 2 13.25 2H15.75C16.9926 2 18 3.00736 18 4.25V6.75C18 7.99264 16.9926 9 15.75 9H13.25C12.0074 9 11 7.99264 11 6.75V4.25Z M15.25 11.75C15.25 11.3358 14.9142 11 14.5 11C14.0858 11 13.75 11.3358 13.75 11.75V13.75H11.75C11.3358 13.75 11 14.0858 11 14.5C11 14.9142 11.3358 15.25 11.75 15.25H13.75V17.25C13.75 17.6642 14.0858 18 14.5 18C14.9142 18 15.25 17.6642 15.25 17.25V15.25H17.25C17.6642 15.25 18 14.9142 18 14.5C18 14.0858 17.6642 13.75 17.25 13.75H15.25V11.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "star" shape.
+#[cfg(any(feature = "star", heroicon = "star"))]
+pub static STAR: super::StaticShape = super::StaticShape::new(
+    "star",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.868 2.8837C10.5469 2.11168 9.45329 2.11168 9.13219 2.8837L7.3014 7.28547L2.54932 7.66644C1.71586 7.73326 1.37791 8.77337 2.01291 9.31732L5.63349 12.4187L4.52735 17.056C4.33334 17.8693 5.21812 18.5121 5.93167 18.0763L10.0001 15.5913L14.0686 18.0763C14.7821 18.5121 15.6669 17.8693 15.4729 17.056L14.3667 12.4187L17.9873 9.31732C18.6223 8.77337 18.2844 7.73326 17.4509 7.66644L12.6988 7.28547L10.868 2.8837Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "stop-circle" shape.
+#[cfg(any(feature = "stop-circle", heroicon = "stop-circle"))]
+pub static STOP_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "stop-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2 10C2 5.58172 5.58172 2 10 2C14.4183 2 18 5.58172 18 10C18 14.4183 14.4183 18 10 18C5.58172 18 2 14.4183 2 10ZM7 7.75C7 7.33579 7.33579 7 7.75 7H12.25C12.6642 7 13 7.33579 13 7.75V12.25C13 12.6642 12.6642 13 12.25 13H7.75C7.33579 13 7 12.6642 7 12.25V7.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "stop" shape.
+#[cfg(any(feature = "stop", heroicon = "stop"))]
+pub static STOP: super::StaticShape = super::StaticShape::new(
+    "stop",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.25 3C4.00736 3 3 4.00736 3 5.25V14.75C3 15.9926 4.00736 17 5.25 17H14.75C15.9926 17 17 15.9926 17 14.75V5.25C17 4.00736 15.9926 3 14.75 3H5.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "sun" shape.
+#[cfg(any(feature = "sun", heroicon = "sun"))]
+pub static SUN: super::StaticShape = super::StaticShape::new(
+    "sun",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 2C10.4142 2 10.75 2.33579 10.75 2.75V4.25C10.75 4.66421 10.4142 5 10 5C9.58579 5 9.25 4.66421 9.25 4.25V2.75C9.25 2.33579 9.58579 2 10 2Z M10 15C10.4142 15 10.75 15.3358 10.75 15.75V17.25C10.75 17.6642 10.4142 18 10 18C9.58579 18 9.25 17.6642 9.25 17.25V15.75C9.25 15.3358 9.58579 15 10 15Z M10 7C8.34315 7 7 8.34315 7 10C7 11.6569 8.34315 13 10 13C11.6569 13 13 11.6569 13 10C13 8.34315 11.6569 7 10 7Z M15.6568 5.40386C15.9497 5.11096 15.9497 4.63609 15.6568 4.3432C15.3639 4.0503 14.889 4.0503 14.5961 4.3432L13.5355 5.40386C13.2426 5.69675 13.2426 6.17162 13.5355 6.46452C13.8284 6.75741 14.3032 6.75741 14.5961 6.46452L15.6568 5.40386Z M6.46441 14.5962C6.7573 14.3034 6.7573 13.8285 6.46441 13.5356C6.17151 13.2427 5.69664 13.2427 5.40375 13.5356L4.34309 14.5962C4.05019 14.8891 4.05019 15.364 4.34309 15.6569C4.63598 15.9498 5.11085 15.9498 5.40375 15.6569L6.46441 14.5962Z M18 10C18 10.4142 17.6642 10.75 17.25 10.75H15.75C15.3358 10.75 15 10.4142 15 10C15 9.58579 15.3358 9.25 15.75 9.25H17.25C17.6642 9.25 18 9.58579 18 10Z M5 10C5 10.4142 4.66421 10.75 4.25 10.75H2.75C2.33579 10.75 2 10.4142 2 10C2 9.58579 2.33579 9.25 2.75 9.25H4.25C4.66421 9.25 5 9.58579 5 10Z M14.596 15.6568C14.8889 15.9497 15.3638 15.9497 15.6567 15.6568C15.9496 15.3639 15.9496 14.889 15.6567 14.5961L14.596 13.5355C14.3031 13.2426 13.8283 13.2426 13.5354 13.5355C13.2425 13.8284 13.2425 14.3032 13.5354 14.5961L14.596 15.6568Z M5.40363 6.46441C5.69653 6.7573 6.1714 6.7573 6.46429 6.46441C6.75719 6.17151 6.75719 5.69664 6.46429 5.40375L5.40363 4.34309C5.11074 4.05019 4.63587 4.05019 4.34297 4.34309C4.05008 4.63598 4.05008 5.11085 4.34297 5.40375L5.40363 6.46441Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "swatch" shape.
+#[cfg(any(feature = "swatch", heroicon = "swatch"))]
+pub static SWATCH: super::StaticShape = super::StaticShape::new(
+    "swatch",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.5 2C2.67157 2 2 2.67157 2 3.5V15C2 16.6569 3.34315 18 5 18C6.65685 18 8 16.6569 8 15V3.5C8 2.67157 7.32843 2 6.5 2H3.5ZM15.253 8.98957L9.5 14.7426V6.25731L11.0104 4.74693C11.5962 4.16114 12.5459 4.16114 13.1317 4.74693L15.253 6.86825C15.8388 7.45403 15.8388 8.40378 15.253 8.98957ZM8.36395 18H16.5C17.3284 18 18 17.3284 18 16.5V13.5C18 12.6716 17.3284 12 16.5 12H14.364L8.36395 18ZM5 16C5.55228 16 6 15.5523 6 15C6 14.4477 5.55228 14 5 14C4.44772 14 4 14.4477 4 15C4 15.5523 4.44772 16 5 16Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
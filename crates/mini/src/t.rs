@@ -0,0 +1,97 @@
+//! The shapes whose names start with "t".
+/// The "table-cells" shape.
+#[cfg(any(feature = "table-cells", heroicon = "table-cells"))]
+pub static TABLE_CELLS: super::StaticShape = super::StaticShape::new(
+    "table-cells",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M0.98999 5.23999C0.98999 3.99735 2.00736 3 3.25 3H16.75C17.9926 3 19 4.00736 19 5.25L19.01 14.75C19.01 15.9926 18.0027 17 16.76 17C12.9267 17 7.09334 17 3.26001 17C2.01737 17 1 15.9826 1 14.74L0.98999 5.23999ZM9.25 14.76V14.135C9.25 13.7208 8.91421 13.385 8.5 13.385H3.25C2.83579 13.385 2.5 13.7208 2.5 14.135V14.75C2.5 15.1642 2.83579 15.5 3.25 15.5H8.62283C8.97861 15.4414 9.25 15.1324 9.25 14.76ZM10.75 14.76C10.75 15.1324 11.0214 15.4414 11.3772 15.5H16.75C17.1642 15.5 17.5 15.1642 17.5 14.75V14.135C17.5 13.7208 17.1642 13.385 16.75 13.385H11.5C11.0858 13.385 10.75 13.7208 10.75 14.135V14.76ZM17.5 11.13V10.505C17.5 10.0908 17.1642 9.755 16.75 9.755H11.5C11.0858 9.755 10.75 10.0908 10.75 10.505V11.13C10.75 11.5442 11.0858 11.88 11.5 11.88H16.75C17.1642 11.88 17.5 11.5442 17.5 11.13ZM9.25 11.13V10.505C9.25 10.0908 8.91421 9.755 8.5 9.755H3.25C2.83579 9.755 2.5 10.0908 2.5 10.505V11.13C2.5 11.5442 2.83579 11.88 3.25 11.88H8.5C8.91421 11.88 9.25 11.5442 9.25 11.13ZM17.5 7.5V6.875C17.5 6.46079 17.1642 6.125 16.75 6.125H11.5C11.0858 6.125 10.75 6.46079 10.75 6.875V7.5C10.75 7.91421 11.0858 8.25 11.5 8.25H16.75C17.1642 8.25 17.5 7.91421 17.5 7.5ZM9.25 7.5V6.875C9.25 6.46079 8.91421 6.125 8.5 6.125H3.25C2.83579 6.125 2.5 6.46079 2.5 6.875V7.5C2.5 7.91421 2.83579 8.25 3.25 8.25H8.5C8.91421 8.25 9.25 7.91421 9.25 7.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "tag" shape.
+#[cfg(any(feature = "tag", heroicon = "tag"))]
+pub static TAG: super::StaticShape = super::StaticShape::new(
+    "tag",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.5 3C4.11929 3 3 4.11929 3 5.5V8.37868C3 9.04172 3.26339 9.67761 3.73223 10.1464L10.2322 16.6464C11.2085 17.6228 12.7915 17.6228 13.7678 16.6464L16.6464 13.7678C17.6228 12.7915 17.6228 11.2085 16.6464 10.2322L10.1464 3.73223C9.67761 3.26339 9.04172 3 8.37868 3H5.5ZM6 7C6.55228 7 7 6.55228 7 6C7 5.44772 6.55228 5 6 5C5.44772 5 5 5.44772 5 6C5 6.55228 5.44772 7 6 7Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "ticket" shape.
+#[cfg(any(feature = "ticket", heroicon = "ticket"))]
+pub static TICKET: super::StaticShape = super::StaticShape::new(
+    "ticket",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13 3V4.27083C13 4.68505 13.3358 5.02083 13.75 5.02083C14.1642 5.02083 14.5 4.68505 14.5 4.27083V3H16.75C17.9926 3 19 4.00736 19 5.25V7.87803C19 8.19589 18.7996 8.47923 18.4999 8.58516C17.9163 8.79143 17.5 9.34806 17.5 10C17.5 10.6519 17.9163 11.2086 18.4999 11.4148C18.7996 11.5208 19 11.8041 19 12.122V14.75C19 15.9926 17.9926 17 16.75 17H14.5V15.7292C14.5 15.315 14.1642 14.9792 13.75 14.9792C13.3358 14.9792 13 15.315 13 15.7292V17H3.25C2.00736 17 1 15.9926 1 14.75V12.122C1 11.8041 1.20037 11.5208 1.50007 11.4148C2.08367 11.2086 2.5 10.6519 2.5 10C2.5 9.34806 2.08367 8.79143 1.50007 8.58516C1.20037 8.47923 1 8.19589 1 7.87803V5.25C1 4.00736 2.00736 3 3.25 3H13ZM14.5 7.39583C14.5 6.98162 14.1642 6.64583 13.75 6.64583C13.3358 6.64583 13 6.98162 13 7.39583V8.4375C13 8.85171 13.3358 9.1875 13.75 9.1875C14.1642 9.1875 14.5 8.85171 14.5 8.4375V7.39583ZM14.5 11.5625C14.5 11.1483 14.1642 10.8125 13.75 10.8125C13.3358 10.8125 13 11.1483 13 11.5625V12.6042C13 13.0184 13.3358 13.3542 13.75 13.3542C14.1642 13.3542 14.5 13.0184 14.5 12.6042V11.5625ZM6 10.75C6 10.3358 6.33579 10 6.75 10H10.25C10.6642 10 11 10.3358 11 10.75C11 11.1642 10.6642 11.5 10.25 11.5H6.75C6.33579 11.5 6 11.1642 6 10.75ZM6 13.25C6 12.8358 6.33579 12.5 6.75 12.5H8.25C8.66421 12.5 9 12.8358 9 13.25C9 13.6642 8.66421 14 8.25 14H6.75C6.33579 14 6 13.6642 6 13.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "trash" shape.
+#[cfg(any(feature = "trash", heroicon = "trash"))]
+pub static TRASH: super::StaticShape = super::StaticShape::new(
+    "trash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.75 1C7.23122 1 6 2.23122 6 3.75V4.1927C5.20472 4.26972 4.41602 4.36947 3.63458 4.49129C3.22531 4.5551 2.94525 4.9386 3.00906 5.34787C3.07286 5.75714 3.45637 6.0372 3.86564 5.97339L4.01355 5.95062L4.85504 16.4693C4.96938 17.8985 6.16254 19 7.59629 19H12.4035C13.8372 19 15.0304 17.8985 15.1447 16.4693L15.9862 5.95055L16.1346 5.97339C16.5438 6.0372 16.9274 5.75714 16.9912 5.34787C17.055 4.9386 16.7749 4.5551 16.3656 4.49129C15.5841 4.36946 14.7954 4.2697 14 4.19268V3.75C14 2.23122 12.7688 1 11.25 1H8.75ZM10.0001 4C10.8395 4 11.673 4.02523 12.5 4.07499V3.75C12.5 3.05964 11.9404 2.5 11.25 2.5H8.75C8.05964 2.5 7.5 3.05964 7.5 3.75V4.075C8.32707 4.02524 9.16068 4 10.0001 4ZM8.57948 7.72002C8.56292 7.30614 8.21398 6.98404 7.8001 7.0006C7.38622 7.01716 7.06412 7.36609 7.08068 7.77998L7.38069 15.28C7.39725 15.6939 7.74619 16.016 8.16007 15.9994C8.57395 15.9828 8.89605 15.6339 8.87949 15.22L8.57948 7.72002ZM12.9195 7.77998C12.936 7.36609 12.614 7.01715 12.2001 7.0006C11.7862 6.98404 11.4372 7.30614 11.4207 7.72002L11.1207 15.22C11.1041 15.6339 11.4262 15.9828 11.8401 15.9994C12.254 16.016 12.6029 15.6939 12.6195 15.28L12.9195 7.77998Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "trophy" shape.
+#[cfg(any(feature = "trophy", heroicon = "trophy"))]
+pub static TROPHY: super::StaticShape = super::StaticShape::new(
+    "trophy",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10 1C8.17155 1 6.37729 1.14878 4.62882 1.43504C4.26621 1.4944 4 1.80774 4 2.17518V2.56172C3.17339 2.71855 2.35799 2.90717 1.55514 3.12628C1.23821 3.21277 1.01446 3.49546 1.00306 3.82378C1.00102 3.88231 1 3.94105 1 4C1 6.59485 2.97645 8.72783 5.50636 8.97591C6.27572 9.84484 7.29439 10.4898 8.45156 10.7981C8.35539 11.5844 8.11892 12.3268 7.76796 13H7.5C6.67157 13 6 13.6716 6 14.5V17H5.25C4.55964 17 4 17.5596 4 18.25C4 18.6642 4.33579 19 4.75 19H15.25C15.6642 19 16 18.6642 16 18.25C16 17.5596 15.4404 17 14.75 17H14V14.5C14 13.6716 13.3284 13 12.5 13H12.232C11.8811 12.3268 11.6446 11.5844 11.5484 10.7981C12.7056 10.4898 13.7243 9.84484 14.4936 8.97591C17.0235 8.72783 19 6.59485 19 4C19 3.94103 18.999 3.88229 18.9969 3.82378C18.9855 3.49546 18.7618 3.21277 18.4449 3.12628C17.642 2.90717 16.8266 2.71855 16 2.56172V2.17518C16 1.80774 15.7338 1.4944 15.3712 1.43504C13.6227 1.14878 11.8285 1 10 1ZM2.52524 4.42244C3.01226 4.29976 3.50395 4.18878 4 4.08984V5C4 5.73949 4.13404 6.44825 4.37906 7.10288C3.38067 6.58021 2.66567 5.58968 2.52524 4.42244ZM17.4748 4.42244C17.3343 5.58968 16.6193 6.58021 15.6209 7.10288C15.866 6.44825 16 5.73949 16 5V4.08984C16.496 4.18878 16.9877 4.29976 17.4748 4.42244Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "truck" shape.
+#[cfg(any(feature = "truck", heroicon = "truck"))]
+pub static TRUCK: super::StaticShape = super::StaticShape::new(
+    "truck",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.5 3C5.4488 3 4.40669 3.03958 3.37512 3.11734C2.58952 3.17656 2 3.83452 2 4.60628V10.5H11V4.60628C11 3.83452 10.4105 3.17656 9.62488 3.11734C8.59331 3.03958 7.5512 3 6.5 3Z M2 12V14.5C2 15.3284 2.67157 16 3.5 16H3.54148C3.77952 14.5811 5.0135 13.5 6.5 13.5C7.9865 13.5 9.22048 14.5811 9.45852 16H10.25C10.6642 16 11 15.6642 11 15.25V12H2Z M6.5 18C7.32843 18 8 17.3284 8 16.5C8 15.6716 7.32843 15 6.5 15C5.67157 15 5 15.6716 5 16.5C5 17.3284 5.67157 18 6.5 18Z M13.25 5C12.8358 5 12.5 5.33579 12.5 5.75V14.2639C13.0308 13.7889 13.7316 13.5 14.5 13.5C15.8814 13.5 17.0447 14.4336 17.3933 15.7043C17.7639 15.4289 18.0037 14.9852 17.9883 14.4772C17.8967 11.4639 17.2717 8.58345 16.204 5.92808C15.9743 5.35688 15.4206 5 14.8229 5H13.25Z M14.5 18C15.3284 18 16 17.3284 16 16.5C16 15.6716 15.3284 15 14.5 15C13.6716 15 13 15.6716 13 16.5C13 17.3284 13.6716 18 14.5 18Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "tv" shape.
+#[cfg(any(feature = "tv", heroicon = "tv"))]
+pub static TV: super::StaticShape = super::StaticShape::new(
+    "tv",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4 5H16V12H4V5Z M1 3.5C1 2.67157 1.67157 2 2.5 2H17.5C18.3284 2 19 2.67157 19 3.5V13.5C19 14.3284 18.3284 15 17.5 15H12V16.5H15.25C15.6642 16.5 16 16.8358 16 17.25C16 17.6642 15.6642 18 15.25 18H4.75C4.33579 18 4 17.6642 4 17.25C4 16.8358 4.33579 16.5 4.75 16.5H8V15H2.5C1.67157 15 1 14.3284 1 13.5V3.5ZM17.5 3.5L2.5 3.5V13.5H17.5V3.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,63 @@
+//! The shapes whose names start with "v".
+/// The "variable" shape.
+#[cfg(any(feature = "variable", heroicon = "variable"))]
+pub static VARIABLE: super::StaticShape = super::StaticShape::new(
+    "variable",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.212 2.07905C15.5825 1.894 16.033 2.0444 16.218 2.41498C17.3587 4.6992 18 7.2758 18 10C18 12.7243 17.3587 15.3009 16.218 17.5851C16.033 17.9557 15.5825 18.1061 15.212 17.921C14.8414 17.736 14.691 17.2856 14.876 16.915C15.9151 14.8342 16.5 12.4865 16.5 10C16.5 7.51359 15.9151 5.16592 14.876 3.0851C14.691 2.71452 14.8414 2.2641 15.212 2.07905ZM4.78803 2.07905C5.15861 2.2641 5.30901 2.71452 5.12397 3.0851C4.08491 5.16591 3.5 7.51359 3.5 10C3.5 12.4865 4.08491 14.8342 5.12397 16.915C5.30901 17.2856 5.15861 17.736 4.78803 17.921C4.41745 18.1061 3.96703 17.9557 3.78198 17.5851C2.64135 15.3009 2 12.7243 2 10C2 7.2758 2.64135 4.6992 3.78198 2.41498C3.96703 2.0444 4.41745 1.894 4.78803 2.07905ZM7.07277 5.63347C7.86037 5.10841 8.93143 5.43515 9.29184 6.31042L10.1483 8.39032L11.2942 6.61933C11.9758 5.56592 13.3873 5.27355 14.4313 5.96953L14.666 6.12601C15.0107 6.35577 15.1038 6.82142 14.874 7.16607C14.6443 7.51071 14.1786 7.60384 13.834 7.37408L13.5993 7.21761C13.2513 6.98561 12.7808 7.08307 12.5536 7.43421L10.8431 10.0777L12.0952 13.1185L12.834 12.626C13.1786 12.3962 13.6443 12.4894 13.874 12.834C14.1038 13.1787 14.0107 13.6443 13.666 13.8741L12.9272 14.3666C12.1396 14.8917 11.0686 14.5649 10.7082 13.6897L9.85173 11.6098L8.70579 13.3808C8.02418 14.4342 6.61265 14.7265 5.56869 14.0306L5.33397 13.8741C4.98933 13.6443 4.8962 13.1787 5.12596 12.834C5.35573 12.4894 5.82138 12.3962 6.16603 12.626L6.40074 12.7825C6.74873 13.0145 7.21923 12.917 7.44644 12.5659L9.15693 9.92239L7.90482 6.88155L7.16603 7.37408C6.82138 7.60384 6.35573 7.51071 6.12596 7.16607C5.8962 6.82142 5.98933 6.35577 6.33397 6.126L7.07277 5.63347Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "video-camera-slash" shape.
+#[cfg(any(feature = "video-camera-slash", heroicon = "video-camera-slash"))]
+pub static VIDEO_CAMERA_SLASH: super::StaticShape = super::StaticShape::new(
+    "video-camera-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1 13.75V7.18198L9.81802 16H3.25C2.00736 16 1 14.9926 1 13.75Z M13 6.25V12.818L4.18198 4H10.75C11.9926 4 13 5.00736 13 6.25Z M19 4.75002C19 4.44668 18.8173 4.1732 18.537 4.05711C18.2568 3.94103 17.9342 4.00519 17.7197 4.21969L14.7197 7.21969C14.579 7.36034 14.5 7.55111 14.5 7.75002V12.25C14.5 12.4489 14.579 12.6397 14.7197 12.7804L17.7197 15.7804C17.9342 15.9949 18.2568 16.059 18.537 15.9429C18.8173 15.8268 19 15.5534 19 15.25V4.75002Z M2.28033 4.21967C1.98744 3.92678 1.51256 3.92678 1.21967 4.21967C0.926777 4.51256 0.926777 4.98744 1.21967 5.28033L11.7197 15.7803C12.0126 16.0732 12.4874 16.0732 12.7803 15.7803C13.0732 15.4874 13.0732 15.0126 12.7803 14.7197L2.28033 4.21967Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "video-camera" shape.
+#[cfg(any(feature = "video-camera", heroicon = "video-camera"))]
+pub static VIDEO_CAMERA: super::StaticShape = super::StaticShape::new(
+    "video-camera",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.25 4C2.00736 4 1 5.00736 1 6.25V13.75C1 14.9926 2.00736 16 3.25 16H10.75C11.9926 16 13 14.9926 13 13.75V6.25C13 5.00736 11.9926 4 10.75 4H3.25Z M19 4.75002C19 4.44668 18.8173 4.1732 18.537 4.05711C18.2568 3.94103 17.9342 4.00519 17.7197 4.21969L14.7197 7.21969C14.579 7.36034 14.5 7.55111 14.5 7.75002V12.25C14.5 12.4489 14.579 12.6397 14.7197 12.7804L17.7197 15.7804C17.9342 15.9949 18.2568 16.059 18.537 15.9429C18.8173 15.8268 19 15.5534 19 15.25V4.75002Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "view-columns" shape.
+#[cfg(any(feature = "view-columns", heroicon = "view-columns"))]
+pub static VIEW_COLUMNS: super::StaticShape = super::StaticShape::new(
+    "view-columns",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14 17H16.75C17.9926 17 19 15.9926 19 14.75V5.25C19 4.00736 17.9926 3 16.75 3H14V17Z M12.5 3H7.5V17H12.5V3Z M3.25 3H6V17H3.25C2.00736 17 1 15.9926 1 14.75V5.25C1 4.00736 2.00736 3 3.25 3Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "viewfinder-circle" shape.
+#[cfg(any(feature = "viewfinder-circle", heroicon = "viewfinder-circle"))]
+pub static VIEWFINDER_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "viewfinder-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.25 2C3.00736 2 2 3.00736 2 4.25V6.25C2 6.66421 2.33579 7 2.75 7C3.16421 7 3.5 6.66421 3.5 6.25V4.25C3.5 3.83579 3.83579 3.5 4.25 3.5H6.25C6.66421 3.5 7 3.16421 7 2.75C7 2.33579 6.66421 2 6.25 2H4.25Z M13.75 2C13.3358 2 13 2.33579 13 2.75C13 3.16421 13.3358 3.5 13.75 3.5H15.75C16.1642 3.5 16.5 3.83579 16.5 4.25V6.25C16.5 6.66421 16.8358 7 17.25 7C17.6642 7 18 6.66421 18 6.25V4.25C18 3.00736 16.9926 2 15.75 2H13.75Z M3.5 13.75C3.5 13.3358 3.16421 13 2.75 13C2.33579 13 2 13.3358 2 13.75V15.75C2 16.9926 3.00736 18 4.25 18H6.25C6.66421 18 7 17.6642 7 17.25C7 16.8358 6.66421 16.5 6.25 16.5H4.25C3.83579 16.5 3.5 16.1642 3.5 15.75V13.75Z M18 13.75C18 13.3358 17.6642 13 17.25 13C16.8358 13 16.5 13.3358 16.5 13.75V15.75C16.5 16.1642 16.1642 16.5 15.75 16.5H13.75C13.3358 16.5 13 16.8358 13 17.25C13 17.6642 13.3358 18 13.75 18H15.75C16.9926 18 18 16.9926 18 15.75V13.75Z M7 10C7 8.34315 8.34315 7 10 7C11.6569 7 13 8.34315 13 10C13 11.6569 11.6569 13 10 13C8.34315 13 7 11.6569 7 10Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
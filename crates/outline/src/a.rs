@@ -0,0 +1,583 @@
+//! The shapes whose names start with "a".
+/// The "academic-cap" shape.
+#[cfg(any(feature = "academic-cap", heroicon = "academic-cap"))]
+pub static ACADEMIC_CAP: super::StaticShape = super::StaticShape::new(
+    "academic-cap",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.25933 10.1468C3.98688 12.2308 3.82139 14.3485 3.76853 16.4941C6.66451 17.7032 9.41893 19.1836 12 20.9037C14.5811 19.1836 17.3355 17.7032 20.2315 16.4941C20.1786 14.3485 20.0131 12.2308 19.7407 10.1468M4.25933 10.1468C3.38362 9.85242 2.49729 9.5812 1.60107 9.33382C4.84646 7.05899 8.32741 5.09732 12 3.49268C15.6727 5.09732 19.1536 7.059 22.399 9.33383C21.5028 9.58122 20.6164 9.85245 19.7407 10.1468M4.25933 10.1468C6.94656 11.05 9.5338 12.171 12.0001 13.4888C14.4663 12.171 17.0535 11.0501 19.7407 10.1468M6.75 15.0001C7.16421 15.0001 7.5 14.6643 7.5 14.2501C7.5 13.8359 7.16421 13.5001 6.75 13.5001C6.33579 13.5001 6 13.8359 6 14.2501C6 14.6643 6.33579 15.0001 6.75 15.0001ZM6.75 15.0001V11.3246C8.44147 10.2736 10.1936 9.31107 12 8.44342M4.99264 19.9928C6.16421 18.8212 6.75 17.2857 6.75 15.7501V14.2501",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "adjustments-horizontal" shape.
+#[cfg(any(feature = "adjustments-horizontal", heroicon = "adjustments-horizontal"))]
+pub static ADJUSTMENTS_HORIZONTAL: super::StaticShape = super::StaticShape::new(
+    "adjustments-horizontal",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 6L20.25 6M10.5 6C10.5 6.82843 9.82843 7.5 9 7.5C8.17157 7.5 7.5 6.82843 7.5 6M10.5 6C10.5 5.17157 9.82843 4.5 9 4.5C8.17157 4.5 7.5 5.17157 7.5 6M3.75 6H7.5M10.5 18H20.25M10.5 18C10.5 18.8284 9.82843 19.5 9 19.5C8.17157 19.5 7.5 18.8284 7.5 18M10.5 18C10.5 17.1716 9.82843 16.5 9 16.5C8.17157 16.5 7.5 17.1716 7.5 18M3.75 18L7.5 18M16.5 12L20.25 12M16.5 12C16.5 12.8284 15.8284 13.5 15 13.5C14.1716 13.5 13.5 12.8284 13.5 12M16.5 12C16.5 11.1716 15.8284 10.5 15 10.5C14.1716 10.5 13.5 11.1716 13.5 12M3.75 12H13.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "adjustments-vertical" shape.
+#[cfg(any(feature = "adjustments-vertical", heroicon = "adjustments-vertical"))]
+pub static ADJUSTMENTS_VERTICAL: super::StaticShape = super::StaticShape::new(
+    "adjustments-vertical",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6 13.5L6 3.75M6 13.5C6.82843 13.5 7.5 14.1716 7.5 15C7.5 15.8284 6.82843 16.5 6 16.5M6 13.5C5.17157 13.5 4.5 14.1716 4.5 15C4.5 15.8284 5.17157 16.5 6 16.5M6 20.25L6 16.5M18 13.5V3.75M18 13.5C18.8284 13.5 19.5 14.1716 19.5 15C19.5 15.8284 18.8284 16.5 18 16.5M18 13.5C17.1716 13.5 16.5 14.1716 16.5 15C16.5 15.8284 17.1716 16.5 18 16.5M18 20.25L18 16.5M12 7.5V3.75M12 7.5C12.8284 7.5 13.5 8.17157 13.5 9C13.5 9.82843 12.8284 10.5 12 10.5M12 7.5C11.1716 7.5 10.5 8.17157 10.5 9C10.5 9.82843 11.1716 10.5 12 10.5M12 20.25V10.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "archive-box-arrow-down" shape.
+#[cfg(any(feature = "archive-box-arrow-down", heroicon = "archive-box-arrow-down"))]
+pub static ARCHIVE_BOX_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "archive-box-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M20.25 7.5L19.6246 18.1321C19.5546 19.3214 18.5698 20.25 17.3785 20.25H6.62154C5.43022 20.25 4.44538 19.3214 4.37542 18.1321L3.75 7.5M12 10.5V17.25M12 17.25L9 14.25M12 17.25L15 14.25M3.375 7.5H20.625C21.2463 7.5 21.75 6.99632 21.75 6.375V4.875C21.75 4.25368 21.2463 3.75 20.625 3.75H3.375C2.75368 3.75 2.25 4.25368 2.25 4.875V6.375C2.25 6.99632 2.75368 7.5 3.375 7.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "archive-box-x-mark" shape.
+#[cfg(any(feature = "archive-box-x-mark", heroicon = "archive-box-x-mark"))]
+pub static ARCHIVE_BOX_X_MARK: super::StaticShape = super::StaticShape::new(
+    "archive-box-x-mark",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M20.25 7.5L19.6246 18.1321C19.5546 19.3214 18.5698 20.25 17.3785 20.25H6.62154C5.43022 20.25 4.44538 19.3214 4.37542 18.1321L3.75 7.5M9.75 11.625L12 13.875M12 13.875L14.25 16.125M12 13.875L14.25 11.625M12 13.875L9.75 16.125M3.375 7.5H20.625C21.2463 7.5 21.75 6.99632 21.75 6.375V4.875C21.75 4.25368 21.2463 3.75 20.625 3.75H3.375C2.75368 3.75 2.25 4.25368 2.25 4.875V6.375C2.25 6.99632 2.75368 7.5 3.375 7.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "archive-box" shape.
+#[cfg(any(feature = "archive-box", heroicon = "archive-box"))]
+pub static ARCHIVE_BOX: super::StaticShape = super::StaticShape::new(
+    "archive-box",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M20.25 7.5L19.6246 18.1321C19.5546 19.3214 18.5698 20.25 17.3785 20.25H6.62154C5.43022 20.25 4.44538 19.3214 4.37542 18.1321L3.75 7.5M9.99976 11.25H13.9998M3.375 7.5H20.625C21.2463 7.5 21.75 6.99632 21.75 6.375V4.875C21.75 4.25368 21.2463 3.75 20.625 3.75H3.375C2.75368 3.75 2.25 4.25368 2.25 4.875V6.375C2.25 6.99632 2.75368 7.5 3.375 7.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-circle" shape.
+#[cfg(any(feature = "arrow-down-circle", heroicon = "arrow-down-circle"))]
+pub static ARROW_DOWN_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "arrow-down-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 12.75L12 15.75M12 15.75L15 12.75M12 15.75L12 8.25M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-left" shape.
+#[cfg(any(feature = "arrow-down-left", heroicon = "arrow-down-left"))]
+pub static ARROW_DOWN_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-down-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 4.5L4.5 19.5M4.5 19.5L15.75 19.5M4.5 19.5L4.5 8.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-on-square-stack" shape.
+#[cfg(
+    any(feature = "arrow-down-on-square-stack", heroicon = "arrow-down-on-square-stack")
+)]
+pub static ARROW_DOWN_ON_SQUARE_STACK: super::StaticShape = super::StaticShape::new(
+    "arrow-down-on-square-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.5 7.5H6.75C5.50736 7.5 4.5 8.50736 4.5 9.75V17.25C4.5 18.4926 5.50736 19.5 6.75 19.5H14.25C15.4926 19.5 16.5 18.4926 16.5 17.25V9.75C16.5 8.50736 15.4926 7.5 14.25 7.5H13.5M7.5 11.25L10.5 14.25M10.5 14.25L13.5 11.25M10.5 14.25L10.5 1.5M16.5 10.5H17.25C18.4926 10.5 19.5 11.5074 19.5 12.75V20.25C19.5 21.4926 18.4926 22.5 17.25 22.5H9.75C8.50736 22.5 7.5 21.4926 7.5 20.25V19.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-on-square" shape.
+#[cfg(any(feature = "arrow-down-on-square", heroicon = "arrow-down-on-square"))]
+pub static ARROW_DOWN_ON_SQUARE: super::StaticShape = super::StaticShape::new(
+    "arrow-down-on-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 8.25H7.5C6.25736 8.25 5.25 9.25736 5.25 10.5V19.5C5.25 20.7426 6.25736 21.75 7.5 21.75H16.5C17.7426 21.75 18.75 20.7426 18.75 19.5V10.5C18.75 9.25736 17.7426 8.25 16.5 8.25H15M9 12L12 15M12 15L15 12M12 15L12 2.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-right" shape.
+#[cfg(any(feature = "arrow-down-right", heroicon = "arrow-down-right"))]
+pub static ARROW_DOWN_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-down-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 4.5L19.5 19.5M19.5 19.5V8.25M19.5 19.5H8.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down-tray" shape.
+#[cfg(any(feature = "arrow-down-tray", heroicon = "arrow-down-tray"))]
+pub static ARROW_DOWN_TRAY: super::StaticShape = super::StaticShape::new(
+    "arrow-down-tray",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 16.5V18.75C3 19.9926 4.00736 21 5.25 21H18.75C19.9926 21 21 19.9926 21 18.75V16.5M16.5 12L12 16.5M12 16.5L7.5 12M12 16.5V3",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-down" shape.
+#[cfg(any(feature = "arrow-down", heroicon = "arrow-down"))]
+pub static ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 13.5L12 21M12 21L4.5 13.5M12 21L12 3",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-left-circle" shape.
+#[cfg(any(feature = "arrow-left-circle", heroicon = "arrow-left-circle"))]
+pub static ARROW_LEFT_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "arrow-left-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.25 9L8.25 12M8.25 12L11.25 15M8.25 12H15.75M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-left-on-rectangle" shape.
+#[cfg(any(feature = "arrow-left-on-rectangle", heroicon = "arrow-left-on-rectangle"))]
+pub static ARROW_LEFT_ON_RECTANGLE: super::StaticShape = super::StaticShape::new(
+    "arrow-left-on-rectangle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 9V5.25C15.75 4.00736 14.7426 3 13.5 3L7.5 3C6.25736 3 5.25 4.00736 5.25 5.25L5.25 18.75C5.25 19.9926 6.25736 21 7.5 21H13.5C14.7426 21 15.75 19.9926 15.75 18.75V15M12 9L9 12M9 12L12 15M9 12L21.75 12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-left" shape.
+#[cfg(any(feature = "arrow-left", heroicon = "arrow-left"))]
+pub static ARROW_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 19.5L3 12M3 12L10.5 4.5M3 12H21",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-long-down" shape.
+#[cfg(any(feature = "arrow-long-down", heroicon = "arrow-long-down"))]
+pub static ARROW_LONG_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-long-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 17.25L12 21M12 21L8.25 17.25M12 21L12 3",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-long-left" shape.
+#[cfg(any(feature = "arrow-long-left", heroicon = "arrow-long-left"))]
+pub static ARROW_LONG_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-long-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.75 15.75L3 12M3 12L6.75 8.25M3 12H21",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-long-right" shape.
+#[cfg(any(feature = "arrow-long-right", heroicon = "arrow-long-right"))]
+pub static ARROW_LONG_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-long-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.25 8.25L21 12M21 12L17.25 15.75M21 12H3",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-long-up" shape.
+#[cfg(any(feature = "arrow-long-up", heroicon = "arrow-long-up"))]
+pub static ARROW_LONG_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-long-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.25 6.75L12 3M12 3L15.75 6.75M12 3V21",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-path-rounded-square" shape.
+#[cfg(
+    any(feature = "arrow-path-rounded-square", heroicon = "arrow-path-rounded-square")
+)]
+pub static ARROW_PATH_ROUNDED_SQUARE: super::StaticShape = super::StaticShape::new(
+    "arrow-path-rounded-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 12C19.5 10.7681 19.4536 9.54699 19.3624 8.3384C19.2128 6.35425 17.6458 4.78724 15.6616 4.63757C14.453 4.54641 13.2319 4.5 12 4.5C10.7681 4.5 9.54699 4.54641 8.3384 4.63757C6.35425 4.78724 4.78724 6.35425 4.63757 8.3384C4.62097 8.55852 4.60585 8.77906 4.59222 9M19.5 12L22.5 9M19.5 12L16.5 9M4.5 12C4.5 13.2319 4.54641 14.453 4.63757 15.6616C4.78724 17.6458 6.35425 19.2128 8.3384 19.3624C9.54699 19.4536 10.7681 19.5 12 19.5C13.2319 19.5 14.453 19.4536 15.6616 19.3624C17.6458 19.2128 19.2128 17.6458 19.3624 15.6616C19.379 15.4415 19.3941 15.2209 19.4078 15M4.5 12L7.5 15M4.5 12L1.5 15",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-path" shape.
+#[cfg(any(feature = "arrow-path", heroicon = "arrow-path"))]
+pub static ARROW_PATH: super::StaticShape = super::StaticShape::new(
+    "arrow-path",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.0228 9.34841H21.0154V9.34663M2.98413 19.6444V14.6517M2.98413 14.6517L7.97677 14.6517M2.98413 14.6517L6.16502 17.8347C7.15555 18.8271 8.41261 19.58 9.86436 19.969C14.2654 21.1483 18.7892 18.5364 19.9685 14.1353M4.03073 9.86484C5.21 5.46374 9.73377 2.85194 14.1349 4.03121C15.5866 4.4202 16.8437 5.17312 17.8342 6.1655L21.0154 9.34663M21.0154 4.3558V9.34663",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-right-circle" shape.
+#[cfg(any(feature = "arrow-right-circle", heroicon = "arrow-right-circle"))]
+pub static ARROW_RIGHT_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "arrow-right-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.75 15L15.75 12M15.75 12L12.75 9M15.75 12L8.25 12M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-right-on-rectangle" shape.
+#[cfg(any(feature = "arrow-right-on-rectangle", heroicon = "arrow-right-on-rectangle"))]
+pub static ARROW_RIGHT_ON_RECTANGLE: super::StaticShape = super::StaticShape::new(
+    "arrow-right-on-rectangle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 9V5.25C15.75 4.00736 14.7426 3 13.5 3L7.5 3C6.25736 3 5.25 4.00736 5.25 5.25L5.25 18.75C5.25 19.9926 6.25736 21 7.5 21H13.5C14.7426 21 15.75 19.9926 15.75 18.75V15M18.75 15L21.75 12M21.75 12L18.75 9M21.75 12L9 12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-right" shape.
+#[cfg(any(feature = "arrow-right", heroicon = "arrow-right"))]
+pub static ARROW_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.5 4.5L21 12M21 12L13.5 19.5M21 12H3",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-small-down" shape.
+#[cfg(any(feature = "arrow-small-down", heroicon = "arrow-small-down"))]
+pub static ARROW_SMALL_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-small-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 4.5V19.5M12 19.5L18.75 12.75M12 19.5L5.25 12.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-small-left" shape.
+#[cfg(any(feature = "arrow-small-left", heroicon = "arrow-small-left"))]
+pub static ARROW_SMALL_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-small-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 12L4.5 12M4.5 12L11.25 18.75M4.5 12L11.25 5.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-small-right" shape.
+#[cfg(any(feature = "arrow-small-right", heroicon = "arrow-small-right"))]
+pub static ARROW_SMALL_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-small-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 12L19.5 12M19.5 12L12.75 5.25M19.5 12L12.75 18.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-small-up" shape.
+#[cfg(any(feature = "arrow-small-up", heroicon = "arrow-small-up"))]
+pub static ARROW_SMALL_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-small-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 19.5L12 4.5M12 4.5L5.25 11.25M12 4.5L18.75 11.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-top-right-on-square" shape.
+#[cfg(
+    any(feature = "arrow-top-right-on-square", heroicon = "arrow-top-right-on-square")
+)]
+pub static ARROW_TOP_RIGHT_ON_SQUARE: super::StaticShape = super::StaticShape::new(
+    "arrow-top-right-on-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.5 6H5.25C4.00736 6 3 7.00736 3 8.25V18.75C3 19.9926 4.00736 21 5.25 21H15.75C16.9926 21 18 19.9926 18 18.75V10.5M7.5 16.5L21 3M21 3L15.75 3M21 3V8.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-trending-down" shape.
+#[cfg(any(feature = "arrow-trending-down", heroicon = "arrow-trending-down"))]
+pub static ARROW_TRENDING_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-trending-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 6L9 12.75L13.2862 8.46383C15.3217 10.0166 16.8781 12.23 17.5919 14.8941L18.3684 17.7919M18.3684 17.7919L21.5504 12.2806M18.3684 17.7919L12.857 14.6099",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-trending-up" shape.
+#[cfg(any(feature = "arrow-trending-up", heroicon = "arrow-trending-up"))]
+pub static ARROW_TRENDING_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-trending-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 18.0004L9 11.2504L13.3064 15.5568C14.5101 13.1885 16.5042 11.2027 19.1203 10.038L21.8609 8.81775M21.8609 8.81775L15.9196 6.53711M21.8609 8.81775L19.5802 14.759",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-circle" shape.
+#[cfg(any(feature = "arrow-up-circle", heroicon = "arrow-up-circle"))]
+pub static ARROW_UP_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "arrow-up-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 11.25L12 8.25M12 8.25L9 11.25M12 8.25L12 15.75M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-left" shape.
+#[cfg(any(feature = "arrow-up-left", heroicon = "arrow-up-left"))]
+pub static ARROW_UP_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-up-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 19.5L4.5 4.5M4.5 4.5L4.5 15.75M4.5 4.5L15.75 4.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-on-square-stack" shape.
+#[cfg(any(feature = "arrow-up-on-square-stack", heroicon = "arrow-up-on-square-stack"))]
+pub static ARROW_UP_ON_SQUARE_STACK: super::StaticShape = super::StaticShape::new(
+    "arrow-up-on-square-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.5 7.5H6.75C5.50736 7.5 4.5 8.50736 4.5 9.75V17.25C4.5 18.4926 5.50736 19.5 6.75 19.5H14.25C15.4926 19.5 16.5 18.4926 16.5 17.25V9.75C16.5 8.50736 15.4926 7.5 14.25 7.5H13.5M13.5 4.5L10.5 1.5M10.5 1.5L7.5 4.5M10.5 1.5L10.5 12.75M16.5 10.5H17.25C18.4926 10.5 19.5 11.5074 19.5 12.75V20.25C19.5 21.4926 18.4926 22.5 17.25 22.5H9.75C8.50736 22.5 7.5 21.4926 7.5 20.25V19.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-on-square" shape.
+#[cfg(any(feature = "arrow-up-on-square", heroicon = "arrow-up-on-square"))]
+pub static ARROW_UP_ON_SQUARE: super::StaticShape = super::StaticShape::new(
+    "arrow-up-on-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 8.25H7.5C6.25736 8.25 5.25 9.25736 5.25 10.5V19.5C5.25 20.7426 6.25736 21.75 7.5 21.75H16.5C17.7426 21.75 18.75 20.7426 18.75 19.5V10.5C18.75 9.25736 17.7426 8.25 16.5 8.25H15M15 5.25L12 2.25M12 2.25L9 5.25M12 2.25L12 15",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-right" shape.
+#[cfg(any(feature = "arrow-up-right", heroicon = "arrow-up-right"))]
+pub static ARROW_UP_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-up-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 19.5L19.5 4.5M19.5 4.5L8.25 4.5M19.5 4.5V15.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up-tray" shape.
+#[cfg(any(feature = "arrow-up-tray", heroicon = "arrow-up-tray"))]
+pub static ARROW_UP_TRAY: super::StaticShape = super::StaticShape::new(
+    "arrow-up-tray",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 16.5V18.75C3 19.9926 4.00736 21 5.25 21H18.75C19.9926 21 21 19.9926 21 18.75V16.5M7.5 7.5L12 3M12 3L16.5 7.5M12 3L12 16.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-up" shape.
+#[cfg(any(feature = "arrow-up", heroicon = "arrow-up"))]
+pub static ARROW_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 10.5L12 3M12 3L19.5 10.5M12 3V21",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-uturn-down" shape.
+#[cfg(any(feature = "arrow-uturn-down", heroicon = "arrow-uturn-down"))]
+pub static ARROW_UTURN_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrow-uturn-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 15L9 21M9 21L3 15M9 21V9C9 5.68629 11.6863 3 15 3C18.3137 3 21 5.68629 21 9V12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-uturn-left" shape.
+#[cfg(any(feature = "arrow-uturn-left", heroicon = "arrow-uturn-left"))]
+pub static ARROW_UTURN_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrow-uturn-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 15L3 9M3 9L9 3M3 9H15C18.3137 9 21 11.6863 21 15C21 18.3137 18.3137 21 15 21H12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-uturn-right" shape.
+#[cfg(any(feature = "arrow-uturn-right", heroicon = "arrow-uturn-right"))]
+pub static ARROW_UTURN_RIGHT: super::StaticShape = super::StaticShape::new(
+    "arrow-uturn-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 15L21 9M21 9L15 3M21 9H9C5.68629 9 3 11.6863 3 15C3 18.3137 5.68629 21 9 21H12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrow-uturn-up" shape.
+#[cfg(any(feature = "arrow-uturn-up", heroicon = "arrow-uturn-up"))]
+pub static ARROW_UTURN_UP: super::StaticShape = super::StaticShape::new(
+    "arrow-uturn-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 9L15 3M15 3L21 9M15 3L15 15C15 18.3137 12.3137 21 9 21C5.68629 21 3 18.3137 3 15L3 12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrows-pointing-in" shape.
+#[cfg(any(feature = "arrows-pointing-in", heroicon = "arrows-pointing-in"))]
+pub static ARROWS_POINTING_IN: super::StaticShape = super::StaticShape::new(
+    "arrows-pointing-in",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 9L9 4.5M9 9L4.5 9M9 9L3.75 3.75M9 15L9 19.5M9 15L4.5 15M9 15L3.75 20.25M15 9H19.5M15 9V4.5M15 9L20.25 3.75M15 15H19.5M15 15L15 19.5M15 15L20.25 20.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrows-pointing-out" shape.
+#[cfg(any(feature = "arrows-pointing-out", heroicon = "arrows-pointing-out"))]
+pub static ARROWS_POINTING_OUT: super::StaticShape = super::StaticShape::new(
+    "arrows-pointing-out",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 3.75V8.25M3.75 3.75H8.25M3.75 3.75L9 9M3.75 20.25V15.75M3.75 20.25H8.25M3.75 20.25L9 15M20.25 3.75L15.75 3.75M20.25 3.75V8.25M20.25 3.75L15 9M20.25 20.25H15.75M20.25 20.25V15.75M20.25 20.25L15 15",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrows-right-left" shape.
+#[cfg(any(feature = "arrows-right-left", heroicon = "arrows-right-left"))]
+pub static ARROWS_RIGHT_LEFT: super::StaticShape = super::StaticShape::new(
+    "arrows-right-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.5 21L3 16.5M3 16.5L7.5 12M3 16.5H16.5M16.5 3L21 7.5M21 7.5L16.5 12M21 7.5L7.5 7.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "arrows-up-down" shape.
+#[cfg(any(feature = "arrows-up-down", heroicon = "arrows-up-down"))]
+pub static ARROWS_UP_DOWN: super::StaticShape = super::StaticShape::new(
+    "arrows-up-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 7.5L7.5 3M7.5 3L12 7.5M7.5 3V16.5M21 16.5L16.5 21M16.5 21L12 16.5M16.5 21L16.5 7.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "at-symbol" shape.
+#[cfg(any(feature = "at-symbol", heroicon = "at-symbol"))]
+pub static AT_SYMBOL: super::StaticShape = super::StaticShape::new(
+    "at-symbol",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.5 12C16.5 14.4853 14.4853 16.5 12 16.5C9.51472 16.5 7.5 14.4853 7.5 12C7.5 9.51472 9.51472 7.5 12 7.5C14.4853 7.5 16.5 9.51472 16.5 12ZM16.5 12C16.5 13.6569 17.5074 15 18.75 15C19.9926 15 21 13.6569 21 12C21 9.69671 20.1213 7.3934 18.364 5.63604C14.8492 2.12132 9.15076 2.12132 5.63604 5.63604C2.12132 9.15076 2.12132 14.8492 5.63604 18.364C9.15076 21.8787 14.8492 21.8787 18.364 18.364M16.5 12V8.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
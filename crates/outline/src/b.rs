@@ -0,0 +1,373 @@
+//! The shapes whose names start with "b".
+/// The "backspace" shape.
+#[cfg(any(feature = "backspace", heroicon = "backspace"))]
+pub static BACKSPACE: super::StaticShape = super::StaticShape::new(
+    "backspace",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 9.75L14.25 12M14.25 12L16.5 14.25M14.25 12L16.5 9.75M14.25 12L12 14.25M9.42049 19.1705L3.04549 12.7955C2.60615 12.3562 2.60615 11.6438 3.04549 11.2045L9.42049 4.82951C9.63147 4.61853 9.91762 4.5 10.216 4.5L19.5 4.5C20.7426 4.5 21.75 5.50736 21.75 6.75V17.25C21.75 18.4926 20.7426 19.5 19.5 19.5H10.216C9.91762 19.5 9.63147 19.3815 9.42049 19.1705Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "backward" shape.
+#[cfg(any(feature = "backward", heroicon = "backward"))]
+pub static BACKWARD: super::StaticShape = super::StaticShape::new(
+    "backward",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 16.811C21 17.6748 20.0668 18.2164 19.3168 17.7878L12.2094 13.7264C11.4536 13.2945 11.4536 12.2047 12.2094 11.7728L19.3168 7.71141C20.0668 7.28285 21 7.82439 21 8.68819V16.811Z M11.25 16.811C11.25 17.6748 10.3168 18.2164 9.56684 17.7878L2.45935 13.7264C1.70356 13.2945 1.70356 12.2047 2.45935 11.7728L9.56684 7.71141C10.3168 7.28285 11.25 7.82439 11.25 8.68819L11.25 16.811Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "banknotes" shape.
+#[cfg(any(feature = "banknotes", heroicon = "banknotes"))]
+pub static BANKNOTES: super::StaticShape = super::StaticShape::new(
+    "banknotes",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 18.75C7.71719 18.75 13.0136 19.4812 18.0468 20.8512C18.7738 21.0491 19.5 20.5086 19.5 19.7551V18.75M3.75 4.5V5.25C3.75 5.66421 3.41421 6 3 6H2.25M2.25 6V5.625C2.25 5.00368 2.75368 4.5 3.375 4.5H20.25M2.25 6V15M20.25 4.5V5.25C20.25 5.66421 20.5858 6 21 6H21.75M20.25 4.5H20.625C21.2463 4.5 21.75 5.00368 21.75 5.625V15.375C21.75 15.9963 21.2463 16.5 20.625 16.5H20.25M21.75 15H21C20.5858 15 20.25 15.3358 20.25 15.75V16.5M20.25 16.5H3.75M3.75 16.5H3.375C2.75368 16.5 2.25 15.9963 2.25 15.375V15M3.75 16.5V15.75C3.75 15.3358 3.41421 15 3 15H2.25M15 10.5C15 12.1569 13.6569 13.5 12 13.5C10.3431 13.5 9 12.1569 9 10.5C9 8.84315 10.3431 7.5 12 7.5C13.6569 7.5 15 8.84315 15 10.5ZM18 10.5H18.0075V10.5075H18V10.5ZM6 10.5H6.0075V10.5075H6V10.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-2" shape.
+#[cfg(any(feature = "bars-2", heroicon = "bars-2"))]
+pub static BARS_2: super::StaticShape = super::StaticShape::new(
+    "bars-2",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 9H20.25M3.75 15.75H20.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-3-bottom-left" shape.
+#[cfg(any(feature = "bars-3-bottom-left", heroicon = "bars-3-bottom-left"))]
+pub static BARS_3_BOTTOM_LEFT: super::StaticShape = super::StaticShape::new(
+    "bars-3-bottom-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 6.75H20.25M3.75 12H20.25M3.75 17.25H12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-3-bottom-right" shape.
+#[cfg(any(feature = "bars-3-bottom-right", heroicon = "bars-3-bottom-right"))]
+pub static BARS_3_BOTTOM_RIGHT: super::StaticShape = super::StaticShape::new(
+    "bars-3-bottom-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 6.75H20.25M3.75 12H20.25M12 17.25H20.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-3-center-left" shape.
+#[cfg(any(feature = "bars-3-center-left", heroicon = "bars-3-center-left"))]
+pub static BARS_3_CENTER_LEFT: super::StaticShape = super::StaticShape::new(
+    "bars-3-center-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 6.75H20.25M3.75 12H12M3.75 17.25H20.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-3" shape.
+#[cfg(any(feature = "bars-3", heroicon = "bars-3"))]
+pub static BARS_3: super::StaticShape = super::StaticShape::new(
+    "bars-3",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 6.75H20.25M3.75 12H20.25M3.75 17.25H20.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-4" shape.
+#[cfg(any(feature = "bars-4", heroicon = "bars-4"))]
+pub static BARS_4: super::StaticShape = super::StaticShape::new(
+    "bars-4",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 5.25H20.25M3.75 9.75H20.25M3.75 14.25H20.25M3.75 18.75H20.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-arrow-down" shape.
+#[cfg(any(feature = "bars-arrow-down", heroicon = "bars-arrow-down"))]
+pub static BARS_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "bars-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 4.5H17.25M3 9H12.75M3 13.5H12.75M17.25 9V21M17.25 21L13.5 17.25M17.25 21L21 17.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bars-arrow-up" shape.
+#[cfg(any(feature = "bars-arrow-up", heroicon = "bars-arrow-up"))]
+pub static BARS_ARROW_UP: super::StaticShape = super::StaticShape::new(
+    "bars-arrow-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 4.5H17.25M3 9H12.75M3 13.5H8.25M13.5 12.75L17.25 9M17.25 9L21 12.75M17.25 9V21",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "battery-0" shape.
+#[cfg(any(feature = "battery-0", heroicon = "battery-0"))]
+pub static BATTERY_0: super::StaticShape = super::StaticShape::new(
+    "battery-0",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 10.5H21.375C21.9963 10.5 22.5 11.0037 22.5 11.625V13.875C22.5 14.4963 21.9963 15 21.375 15H21M3.75 18H18.75C19.9926 18 21 16.9926 21 15.75V9.75C21 8.50736 19.9926 7.5 18.75 7.5H3.75C2.50736 7.5 1.5 8.50736 1.5 9.75V15.75C1.5 16.9926 2.50736 18 3.75 18Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "battery-100" shape.
+#[cfg(any(feature = "battery-100", heroicon = "battery-100"))]
+pub static BATTERY_100: super::StaticShape = super::StaticShape::new(
+    "battery-100",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 10.5H21.375C21.9963 10.5 22.5 11.0037 22.5 11.625V13.875C22.5 14.4963 21.9963 15 21.375 15H21M4.5 10.5H18V15H4.5V10.5ZM3.75 18H18.75C19.9926 18 21 16.9926 21 15.75V9.75C21 8.50736 19.9926 7.5 18.75 7.5H3.75C2.50736 7.5 1.5 8.50736 1.5 9.75V15.75C1.5 16.9926 2.50736 18 3.75 18Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "battery-50" shape.
+#[cfg(any(feature = "battery-50", heroicon = "battery-50"))]
+pub static BATTERY_50: super::StaticShape = super::StaticShape::new(
+    "battery-50",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 10.5H21.375C21.9963 10.5 22.5 11.0037 22.5 11.625V13.875C22.5 14.4963 21.9963 15 21.375 15H21M4.5 10.5H11.25V15H4.5V10.5ZM3.75 18H18.75C19.9926 18 21 16.9926 21 15.75V9.75C21 8.50736 19.9926 7.5 18.75 7.5H3.75C2.50736 7.5 1.5 8.50736 1.5 9.75V15.75C1.5 16.9926 2.50736 18 3.75 18Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "beaker" shape.
+#[cfg(any(feature = "beaker", heroicon = "beaker"))]
+pub static BEAKER: super::StaticShape = super::StaticShape::new(
+    "beaker",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.75001 3.10408V8.81802C9.75001 9.41476 9.51295 9.98705 9.091 10.409L5.00001 14.5M9.75001 3.10408C9.49886 3.12743 9.24884 3.15465 9.00001 3.18568M9.75001 3.10408C10.4908 3.03521 11.2413 3 12 3C12.7587 3 13.5093 3.03521 14.25 3.10408M14.25 3.10408V8.81802C14.25 9.41476 14.4871 9.98705 14.909 10.409L19.8 15.3M14.25 3.10408C14.5011 3.12743 14.7512 3.15465 15 3.18568M19.8 15.3L18.2299 15.6925C16.1457 16.2136 13.9216 15.9608 12 15C10.0784 14.0392 7.85435 13.7864 5.7701 14.3075L5.00001 14.5M19.8 15.3L21.2022 16.7022C22.4341 17.9341 21.8527 20.0202 20.1354 20.3134C17.4911 20.7649 14.773 21 12 21C9.227 21 6.50891 20.7649 3.86459 20.3134C2.14728 20.0202 1.56591 17.9341 2.7978 16.7022L5.00001 14.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bell-alert" shape.
+#[cfg(any(feature = "bell-alert", heroicon = "bell-alert"))]
+pub static BELL_ALERT: super::StaticShape = super::StaticShape::new(
+    "bell-alert",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.8569 17.0817C16.7514 16.857 18.5783 16.4116 20.3111 15.7719C18.8743 14.177 17.9998 12.0656 17.9998 9.75V9.04919C17.9999 9.03281 18 9.01641 18 9C18 5.68629 15.3137 3 12 3C8.6863 3 6.00001 5.68629 6.00001 9L5.99982 9.75C5.99982 12.0656 5.12529 14.177 3.68849 15.7719C5.42142 16.4116 7.24845 16.857 9.14315 17.0818M14.8569 17.0817C13.92 17.1928 12.9666 17.25 11.9998 17.25C11.0332 17.25 10.0799 17.1929 9.14315 17.0818M14.8569 17.0817C14.9498 17.3711 15 17.6797 15 18C15 19.6569 13.6569 21 12 21C10.3432 21 9.00001 19.6569 9.00001 18C9.00001 17.6797 9.0502 17.3712 9.14315 17.0818M3.12445 7.5C3.41173 5.78764 4.18254 4.23924 5.29169 3M18.7083 3C19.8175 4.23924 20.5883 5.78764 20.8756 7.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bell-slash" shape.
+#[cfg(any(feature = "bell-slash", heroicon = "bell-slash"))]
+pub static BELL_SLASH: super::StaticShape = super::StaticShape::new(
+    "bell-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.14314 17.0818C10.0799 17.1929 11.0332 17.25 11.9998 17.25C12.3306 17.25 12.6599 17.2433 12.9874 17.2301M9.14314 17.0818C7.24843 16.857 5.4214 16.4116 3.68848 15.7719C5.02539 14.2879 5.87549 12.3567 5.98723 10.2299M9.14314 17.0818C9.05019 17.3712 9 17.6797 9 18C9 19.6569 10.3431 21 12 21C13.2864 21 14.3837 20.1903 14.8101 19.0527M16.7749 16.7749L21 21M16.7749 16.7749C17.9894 16.5298 19.1706 16.1929 20.3111 15.7719C18.8743 14.177 17.9998 12.0656 17.9998 9.75V9.04919L18 9C18 5.68629 15.3137 3 12 3C9.5667 3 7.47171 4.44849 6.53026 6.53026M16.7749 16.7749L6.53026 6.53026M3 3L6.53026 6.53026",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bell-snooze" shape.
+#[cfg(any(feature = "bell-snooze", heroicon = "bell-snooze"))]
+pub static BELL_SNOOZE: super::StaticShape = super::StaticShape::new(
+    "bell-snooze",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.8569 17.0817C16.7514 16.857 18.5783 16.4116 20.3111 15.7719C18.8743 14.177 17.9998 12.0656 17.9998 9.75V9.04919C17.9999 9.03281 18 9.01641 18 9C18 5.68629 15.3137 3 12 3C8.68629 3 6 5.68629 6 9L5.9998 9.75C5.9998 12.0656 5.12527 14.177 3.68848 15.7719C5.4214 16.4116 7.24843 16.857 9.14314 17.0818M14.8569 17.0817C13.92 17.1928 12.9666 17.25 11.9998 17.25C11.0332 17.25 10.0799 17.1929 9.14314 17.0818M14.8569 17.0817C14.9498 17.3711 15 17.6797 15 18C15 19.6569 13.6569 21 12 21C10.3431 21 9 19.6569 9 18C9 17.6797 9.05019 17.3712 9.14314 17.0818M10.5 8.25H13.5L10.5 12.75H13.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bell" shape.
+#[cfg(any(feature = "bell", heroicon = "bell"))]
+pub static BELL: super::StaticShape = super::StaticShape::new(
+    "bell",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.8569 17.0817C16.7514 16.857 18.5783 16.4116 20.3111 15.7719C18.8743 14.177 17.9998 12.0656 17.9998 9.75V9.04919C17.9999 9.03281 18 9.01641 18 9C18 5.68629 15.3137 3 12 3C8.68629 3 6 5.68629 6 9L5.9998 9.75C5.9998 12.0656 5.12527 14.177 3.68848 15.7719C5.4214 16.4116 7.24843 16.857 9.14314 17.0818M14.8569 17.0817C13.92 17.1928 12.9666 17.25 11.9998 17.25C11.0332 17.25 10.0799 17.1929 9.14314 17.0818M14.8569 17.0817C14.9498 17.3711 15 17.6797 15 18C15 19.6569 13.6569 21 12 21C10.3431 21 9 19.6569 9 18C9 17.6797 9.05019 17.3712 9.14314 17.0818",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bolt-slash" shape.
+#[cfg(any(feature = "bolt-slash", heroicon = "bolt-slash"))]
+pub static BOLT_SLASH: super::StaticShape = super::StaticShape::new(
+    "bolt-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.4123 15.6549L9.75 21.75L13.4949 17.7376M9.25736 13.5H3.75L6.40873 10.6514M8.4569 8.4569L14.25 2.25L12 10.5H20.25L15.5431 15.5431M8.4569 8.4569L3 3M8.4569 8.4569L15.5431 15.5431M15.5431 15.5431L21 21",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bolt" shape.
+#[cfg(any(feature = "bolt", heroicon = "bolt"))]
+pub static BOLT: super::StaticShape = super::StaticShape::new(
+    "bolt",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 13.5L14.25 2.25L12 10.5H20.25L9.75 21.75L12 13.5H3.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "book-open" shape.
+#[cfg(any(feature = "book-open", heroicon = "book-open"))]
+pub static BOOK_OPEN: super::StaticShape = super::StaticShape::new(
+    "book-open",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 6.04168C10.4077 4.61656 8.30506 3.75 6 3.75C4.94809 3.75 3.93834 3.93046 3 4.26212V18.5121C3.93834 18.1805 4.94809 18 6 18C8.30506 18 10.4077 18.8666 12 20.2917M12 6.04168C13.5923 4.61656 15.6949 3.75 18 3.75C19.0519 3.75 20.0617 3.93046 21 4.26212V18.5121C20.0617 18.1805 19.0519 18 18 18C15.6949 18 13.5923 18.8666 12 20.2917M12 6.04168V20.2917",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bookmark-slash" shape.
+#[cfg(any(feature = "bookmark-slash", heroicon = "bookmark-slash"))]
+pub static BOOKMARK_SLASH: super::StaticShape = super::StaticShape::new(
+    "bookmark-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 3L4.66365 4.66365M21 21L19.5 19.5M14.0153 18.2576L12 17.25L4.5 21V8.74237M4.66365 4.66365C4.95294 3.94962 5.60087 3.41593 6.40668 3.32241C8.24156 3.10947 10.108 3 12 3C13.892 3 15.7584 3.10947 17.5933 3.32241C18.6939 3.45014 19.5 4.399 19.5 5.50699V19.5M4.66365 4.66365L19.5 19.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bookmark-square" shape.
+#[cfg(any(feature = "bookmark-square", heroicon = "bookmark-square"))]
+pub static BOOKMARK_SQUARE: super::StaticShape = super::StaticShape::new(
+    "bookmark-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.5 3.75V16.5L12 14.25L7.5 16.5V3.75M16.5 3.75H18C19.2426 3.75 20.25 4.75736 20.25 6V18C20.25 19.2426 19.2426 20.25 18 20.25H6C4.75736 20.25 3.75 19.2426 3.75 18V6C3.75 4.75736 4.75736 3.75 6 3.75H7.5M16.5 3.75H7.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bookmark" shape.
+#[cfg(any(feature = "bookmark", heroicon = "bookmark"))]
+pub static BOOKMARK: super::StaticShape = super::StaticShape::new(
+    "bookmark",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.5933 3.32241C18.6939 3.45014 19.5 4.399 19.5 5.50699V21L12 17.25L4.5 21V5.50699C4.5 4.399 5.30608 3.45014 6.40668 3.32241C8.24156 3.10947 10.108 3 12 3C13.892 3 15.7584 3.10947 17.5933 3.32241Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "briefcase" shape.
+#[cfg(any(feature = "briefcase", heroicon = "briefcase"))]
+pub static BRIEFCASE: super::StaticShape = super::StaticShape::new(
+    "briefcase",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M20.25 14.1499V18.4C20.25 19.4944 19.4631 20.4359 18.3782 20.58C16.2915 20.857 14.1624 21 12 21C9.83757 21 7.70854 20.857 5.62185 20.58C4.5369 20.4359 3.75 19.4944 3.75 18.4V14.1499M20.25 14.1499C20.7219 13.7476 21 13.1389 21 12.4889V8.70569C21 7.62475 20.2321 6.69082 19.1631 6.53086C18.0377 6.36247 16.8995 6.23315 15.75 6.14432M20.25 14.1499C20.0564 14.315 19.8302 14.4453 19.5771 14.5294C17.1953 15.3212 14.6477 15.75 12 15.75C9.35229 15.75 6.80469 15.3212 4.42289 14.5294C4.16984 14.4452 3.94361 14.3149 3.75 14.1499M3.75 14.1499C3.27808 13.7476 3 13.1389 3 12.4889V8.70569C3 7.62475 3.7679 6.69082 4.83694 6.53086C5.96233 6.36247 7.10049 6.23315 8.25 6.14432M15.75 6.14432V5.25C15.75 4.00736 14.7426 3 13.5 3H10.5C9.25736 3 8.25 4.00736 8.25 5.25V6.14432M15.75 6.14432C14.5126 6.0487 13.262 6 12 6C10.738 6 9.48744 6.0487 8.25 6.14432 M12 12.75H12.0075V12.7575H12V12.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "bug-ant" shape.
+#[cfg(any(feature = "bug-ant", heroicon = "bug-ant"))]
+pub static BUG_ANT: super::StaticShape = super::StaticShape::new(
+    "bug-ant",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.9997 12.75C13.1482 12.75 14.2778 12.8307 15.3833 12.9867C16.4196 13.1329 17.2493 13.9534 17.2493 15C17.2493 18.7279 14.8988 21.75 11.9993 21.75C9.09977 21.75 6.74927 18.7279 6.74927 15C6.74927 13.9535 7.57879 13.1331 8.61502 12.9868C9.72081 12.8307 10.8508 12.75 11.9997 12.75ZM11.9997 12.75C14.8825 12.75 17.6469 13.2583 20.2075 14.1901C20.083 16.2945 19.6873 18.3259 19.0549 20.25M11.9997 12.75C9.11689 12.75 6.35312 13.2583 3.79248 14.1901C3.91702 16.2945 4.31272 18.3259 4.94512 20.25M11.9997 12.75C13.2423 12.75 14.2498 11.7426 14.2498 10.5C14.2498 10.4652 14.249 10.4306 14.2475 10.3961M11.9997 12.75C10.757 12.75 9.74979 11.7426 9.74979 10.5C9.74979 10.4652 9.75058 10.4306 9.75214 10.3961M12.0002 8.25C12.995 8.25 13.971 8.16929 14.922 8.01406C15.3246 7.94835 15.6628 7.65623 15.7168 7.25196C15.7388 7.08776 15.7502 6.92021 15.7502 6.75C15.7502 6.11844 15.594 5.52335 15.3183 5.00121M12.0002 8.25C11.0053 8.25 10.0293 8.16929 9.0783 8.01406C8.67576 7.94835 8.33754 7.65623 8.28346 7.25196C8.26149 7.08777 8.25015 6.92021 8.25015 6.75C8.25015 6.1175 8.40675 5.52157 8.68327 4.99887M12.0002 8.25C10.7923 8.25 9.80641 9.20171 9.75214 10.3961M12.0002 8.25C13.208 8.25 14.1932 9.20171 14.2475 10.3961M8.68327 4.99887C8.25654 4.71496 7.86824 4.37787 7.52783 3.99707C7.59799 3.36615 7.7986 2.7746 8.10206 2.25M8.68327 4.99887C9.31221 3.81004 10.5616 3 12.0002 3C13.4397 3 14.6897 3.8111 15.3183 5.00121M15.3183 5.00121C15.7445 4.71804 16.1325 4.38184 16.4728 4.00201C16.4031 3.36924 16.2023 2.77597 15.898 2.25M4.92097 6C4.71594 7.08086 4.58339 8.18738 4.52856 9.3143C6.19671 9.86025 7.94538 10.2283 9.75214 10.3961M19.0786 6C19.2836 7.08086 19.4162 8.18738 19.471 9.3143C17.8029 9.86024 16.0542 10.2283 14.2475 10.3961",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "building-library" shape.
+#[cfg(any(feature = "building-library", heroicon = "building-library"))]
+pub static BUILDING_LIBRARY: super::StaticShape = super::StaticShape::new(
+    "building-library",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 21V12.75M15.75 21V12.75M8.25 21V12.75M3 9L12 3L21 9M19.5 21V10.3325C17.0563 9.94906 14.5514 9.75 12 9.75C9.44861 9.75 6.94372 9.94906 4.5 10.3325V21M3 21H21M12 6.75H12.0075V6.7575H12V6.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "building-office-2" shape.
+#[cfg(any(feature = "building-office-2", heroicon = "building-office-2"))]
+pub static BUILDING_OFFICE_2: super::StaticShape = super::StaticShape::new(
+    "building-office-2",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 21H21.75M3.75 3V21M14.25 3V21M20.25 7.5V21M6.75 6.75H7.5M6.75 9.75H7.5M6.75 12.75H7.5M10.5 6.75H11.25M10.5 9.75H11.25M10.5 12.75H11.25M6.75 21V17.625C6.75 17.0037 7.25368 16.5 7.875 16.5H10.125C10.7463 16.5 11.25 17.0037 11.25 17.625V21M3 3H15M14.25 7.5H21M17.25 11.25H17.2575V11.2575H17.25V11.25ZM17.25 14.25H17.2575V14.2575H17.25V14.25ZM17.25 17.25H17.2575V17.2575H17.25V17.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "building-office" shape.
+#[cfg(any(feature = "building-office", heroicon = "building-office"))]
+pub static BUILDING_OFFICE: super::StaticShape = super::StaticShape::new(
+    "building-office",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 21H20.25M4.5 3H19.5M5.25 3V21M18.75 3V21M9 6.75H10.5M9 9.75H10.5M9 12.75H10.5M13.5 6.75H15M13.5 9.75H15M13.5 12.75H15M9 21V17.625C9 17.0037 9.50368 16.5 10.125 16.5H13.875C14.4963 16.5 15 17.0037 15 17.625V21",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "building-storefront" shape.
+#[cfg(any(feature = "building-storefront", heroicon = "building-storefront"))]
+pub static BUILDING_STOREFRONT: super::StaticShape = super::StaticShape::new(
+    "building-storefront",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.5 20.9999V13.4999C13.5 13.0856 13.8358 12.7499 14.25 12.7499H17.25C17.6642 12.7499 18 13.0856 18 13.4999V20.9999M13.5 20.9999H2.36088M13.5 20.9999H18M18 20.9999H21.6391M20.25 20.9999V9.34863M3.75 20.9999V9.34888M3.75 9.34888C4.89729 10.012 6.38977 9.85281 7.37132 8.87127C7.41594 8.82665 7.45886 8.78097 7.50008 8.73432C8.04979 9.35708 8.85402 9.74986 9.75 9.74986C10.646 9.74986 11.4503 9.35704 12 8.73423C12.5497 9.35704 13.354 9.74986 14.25 9.74986C15.1459 9.74986 15.9501 9.35713 16.4998 8.73443C16.541 8.78101 16.5838 8.82662 16.6284 8.87118C17.61 9.85281 19.1027 10.012 20.25 9.34863M3.75 9.34888C3.52788 9.2205 3.31871 9.06129 3.12868 8.87127C1.95711 7.69969 1.95711 5.8002 3.12868 4.62863L4.31797 3.43934C4.59927 3.15804 4.9808 3 5.37863 3H18.6212C19.019 3 19.4005 3.15804 19.6818 3.43934L20.871 4.62854C22.0426 5.80011 22.0426 7.69961 20.871 8.87118C20.6811 9.06113 20.472 9.22028 20.25 9.34863M6.75 17.9999H10.5C10.9142 17.9999 11.25 17.6641 11.25 17.2499V13.4999C11.25 13.0856 10.9142 12.7499 10.5 12.7499H6.75C6.33579 12.7499 6 13.0856 6 13.4999V17.2499C6 17.6641 6.33579 17.9999 6.75 17.9999Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,675 @@
+//! The shapes whose names start with "c".
+/// The "cake" shape.
+#[cfg(any(feature = "cake", heroicon = "cake"))]
+pub static CAKE: super::StaticShape = super::StaticShape::new(
+    "cake",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 8.25006V6.75006M12 8.25006C10.6448 8.25006 9.30281 8.30622 7.97608 8.41633C6.84499 8.51021 6 9.47329 6 10.6083V13.1214M12 8.25006C13.3552 8.25006 14.6972 8.30622 16.0239 8.41633C17.155 8.51021 18 9.47329 18 10.6083V13.1214M15 8.25006V6.75006M9 8.25006V6.75006M21 16.5001L19.5 17.2501C18.5557 17.7222 17.4443 17.7222 16.5 17.2501C15.5557 16.7779 14.4443 16.7779 13.5 17.2501C12.5557 17.7222 11.4443 17.7222 10.5 17.2501C9.55573 16.7779 8.44427 16.7779 7.5 17.2501C6.55573 17.7222 5.44427 17.7222 4.5 17.2501L3 16.5001M18 13.1214C16.0344 12.8763 14.032 12.7501 12 12.7501C9.96804 12.7501 7.96557 12.8763 6 13.1214M18 13.1214C18.3891 13.1699 18.7768 13.2231 19.163 13.2809C20.2321 13.4409 21 14.3748 21 15.4557V20.6251C21 21.2464 20.4963 21.7501 19.875 21.7501H4.125C3.50368 21.7501 3 21.2464 3 20.6251V15.4557C3 14.3748 3.76793 13.4409 4.83697 13.2809C5.22316 13.2231 5.61086 13.1699 6 13.1214M12.2652 3.10989C12.4117 3.25634 12.4117 3.49378 12.2652 3.64022C12.1188 3.78667 11.8813 3.78667 11.7349 3.64022C11.5884 3.49378 11.5884 3.25634 11.7349 3.10989C11.8104 3.03435 12.0001 2.84473 12.0001 2.84473C12.0001 2.84473 12.1943 3.039 12.2652 3.10989ZM9.26522 3.10989C9.41167 3.25634 9.41167 3.49378 9.26522 3.64022C9.11878 3.78667 8.88134 3.78667 8.73489 3.64022C8.58844 3.49378 8.58844 3.25634 8.73489 3.10989C8.81044 3.03435 9.00005 2.84473 9.00005 2.84473C9.00005 2.84473 9.19432 3.039 9.26522 3.10989ZM15.2652 3.10989C15.4117 3.25634 15.4117 3.49378 15.2652 3.64022C15.1188 3.78667 14.8813 3.78667 14.7349 3.64022C14.5884 3.49378 14.5884 3.25634 14.7349 3.10989C14.8104 3.03435 15.0001 2.84473 15.0001 2.84473C15.0001 2.84473 15.1943 3.039 15.2652 3.10989Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "calculator" shape.
+#[cfg(any(feature = "calculator", heroicon = "calculator"))]
+pub static CALCULATOR: super::StaticShape = super::StaticShape::new(
+    "calculator",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 15.75V18M8.25 11.25H8.2575V11.2575H8.25V11.25ZM8.25 13.5H8.2575V13.5075H8.25V13.5ZM8.25 15.75H8.2575V15.7575H8.25V15.75ZM8.25 18H8.2575V18.0075H8.25V18ZM10.7476 11.25H10.7551V11.2575H10.7476V11.25ZM10.7476 13.5H10.7551V13.5075H10.7476V13.5ZM10.7476 15.75H10.7551V15.7575H10.7476V15.75ZM10.7476 18H10.7551V18.0075H10.7476V18ZM13.2524 11.25H13.2599V11.2575H13.2524V11.25ZM13.2524 13.5H13.2599V13.5075H13.2524V13.5ZM13.2524 15.75H13.2599V15.7575H13.2524V15.75ZM13.2524 18H13.2599V18.0075H13.2524V18ZM15.75 11.25H15.7575V11.2575H15.75V11.25ZM15.75 13.5H15.7575V13.5075H15.75V13.5ZM8.25 6H15.75V8.25H8.25V6ZM12 2.25C10.108 2.25 8.24156 2.35947 6.40668 2.57241C5.30608 2.70014 4.5 3.649 4.5 4.75699V19.5C4.5 20.7426 5.50736 21.75 6.75 21.75H17.25C18.4926 21.75 19.5 20.7426 19.5 19.5V4.75699C19.5 3.649 18.6939 2.70014 17.5933 2.57241C15.7584 2.35947 13.892 2.25 12 2.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "calendar-days" shape.
+#[cfg(any(feature = "calendar-days", heroicon = "calendar-days"))]
+pub static CALENDAR_DAYS: super::StaticShape = super::StaticShape::new(
+    "calendar-days",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.75 3V5.25M17.25 3V5.25M3 18.75V7.5C3 6.25736 4.00736 5.25 5.25 5.25H18.75C19.9926 5.25 21 6.25736 21 7.5V18.75M3 18.75C3 19.9926 4.00736 21 5.25 21H18.75C19.9926 21 21 19.9926 21 18.75M3 18.75V11.25C3 10.0074 4.00736 9 5.25 9H18.75C19.9926 9 21 10.0074 21 11.25V18.75M12 12.75H12.0075V12.7575H12V12.75ZM12 15H12.0075V15.0075H12V15ZM12 17.25H12.0075V17.2575H12V17.25ZM9.75 15H9.7575V15.0075H9.75V15ZM9.75 17.25H9.7575V17.2575H9.75V17.25ZM7.5 15H7.5075V15.0075H7.5V15ZM7.5 17.25H7.5075V17.2575H7.5V17.25ZM14.25 12.75H14.2575V12.7575H14.25V12.75ZM14.25 15H14.2575V15.0075H14.25V15ZM14.25 17.25H14.2575V17.2575H14.25V17.25ZM16.5 12.75H16.5075V12.7575H16.5V12.75ZM16.5 15H16.5075V15.0075H16.5V15Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "calendar" shape.
+#[cfg(any(feature = "calendar", heroicon = "calendar"))]
+pub static CALENDAR: super::StaticShape = super::StaticShape::new(
+    "calendar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.75 3V5.25M17.25 3V5.25M3 18.75V7.5C3 6.25736 4.00736 5.25 5.25 5.25H18.75C19.9926 5.25 21 6.25736 21 7.5V18.75M3 18.75C3 19.9926 4.00736 21 5.25 21H18.75C19.9926 21 21 19.9926 21 18.75M3 18.75V11.25C3 10.0074 4.00736 9 5.25 9H18.75C19.9926 9 21 10.0074 21 11.25V18.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "camera" shape.
+#[cfg(any(feature = "camera", heroicon = "camera"))]
+pub static CAMERA: super::StaticShape = super::StaticShape::new(
+    "camera",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.82689 6.1749C6.46581 6.75354 5.86127 7.13398 5.186 7.22994C4.80655 7.28386 4.42853 7.34223 4.05199 7.40497C2.99912 7.58042 2.25 8.50663 2.25 9.57402V18C2.25 19.2426 3.25736 20.25 4.5 20.25H19.5C20.7426 20.25 21.75 19.2426 21.75 18V9.57403C21.75 8.50664 21.0009 7.58043 19.948 7.40498C19.5715 7.34223 19.1934 7.28387 18.814 7.22995C18.1387 7.13398 17.5342 6.75354 17.1731 6.17491L16.3519 4.85889C15.9734 4.25237 15.3294 3.85838 14.6155 3.82005C13.7496 3.77355 12.8775 3.75 12 3.75C11.1225 3.75 10.2504 3.77355 9.3845 3.82005C8.6706 3.85838 8.02658 4.25237 7.64809 4.85889L6.82689 6.1749Z M16.5 12.75C16.5 15.2353 14.4853 17.25 12 17.25C9.51472 17.25 7.5 15.2353 7.5 12.75C7.5 10.2647 9.51472 8.25 12 8.25C14.4853 8.25 16.5 10.2647 16.5 12.75Z M18.75 10.5H18.7575V10.5075H18.75V10.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chart-bar-square" shape.
+#[cfg(any(feature = "chart-bar-square", heroicon = "chart-bar-square"))]
+pub static CHART_BAR_SQUARE: super::StaticShape = super::StaticShape::new(
+    "chart-bar-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.5 14.25V16.5M10.5 12V16.5M13.5 9.75V16.5M16.5 7.5V16.5M6 20.25H18C19.2426 20.25 20.25 19.2426 20.25 18V6C20.25 4.75736 19.2426 3.75 18 3.75H6C4.75736 3.75 3.75 4.75736 3.75 6V18C3.75 19.2426 4.75736 20.25 6 20.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chart-bar" shape.
+#[cfg(any(feature = "chart-bar", heroicon = "chart-bar"))]
+pub static CHART_BAR: super::StaticShape = super::StaticShape::new(
+    "chart-bar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 13.125C3 12.5037 3.50368 12 4.125 12H6.375C6.99632 12 7.5 12.5037 7.5 13.125V19.875C7.5 20.4963 6.99632 21 6.375 21H4.125C3.50368 21 3 20.4963 3 19.875V13.125Z M9.75 8.625C9.75 8.00368 10.2537 7.5 10.875 7.5H13.125C13.7463 7.5 14.25 8.00368 14.25 8.625V19.875C14.25 20.4963 13.7463 21 13.125 21H10.875C10.2537 21 9.75 20.4963 9.75 19.875V8.625Z M16.5 4.125C16.5 3.50368 17.0037 3 17.625 3H19.875C20.4963 3 21 3.50368 21 4.125V19.875C21 20.4963 20.4963 21 19.875 21H17.625C17.0037 21 16.5 20.4963 16.5 19.875V4.125Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chart-pie" shape.
+#[cfg(any(feature = "chart-pie", heroicon = "chart-pie"))]
+pub static CHART_PIE: super::StaticShape = super::StaticShape::new(
+    "chart-pie",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 6C6.35786 6 3 9.35786 3 13.5C3 17.6421 6.35786 21 10.5 21C14.6421 21 18 17.6421 18 13.5H10.5V6Z M13.5 10.5H21C21 6.35786 17.6421 3 13.5 3V10.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-bottom-center-text" shape.
+#[cfg(
+    any(
+        feature = "chat-bubble-bottom-center-text",
+        heroicon = "chat-bubble-bottom-center-text"
+    )
+)]
+pub static CHAT_BUBBLE_BOTTOM_CENTER_TEXT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-bottom-center-text",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.5 8.25H16.5M7.5 11.25H12M2.25 12.7593C2.25 14.3604 3.37341 15.754 4.95746 15.987C6.08596 16.1529 7.22724 16.2796 8.37985 16.3655C8.73004 16.3916 9.05017 16.5753 9.24496 16.8674L12 21L14.755 16.8675C14.9498 16.5753 15.2699 16.3917 15.6201 16.3656C16.7727 16.2796 17.914 16.153 19.0425 15.9871C20.6266 15.7542 21.75 14.3606 21.75 12.7595V6.74056C21.75 5.13946 20.6266 3.74583 19.0425 3.51293C16.744 3.17501 14.3926 3 12.0003 3C9.60776 3 7.25612 3.17504 4.95747 3.51302C3.37342 3.74593 2.25 5.13956 2.25 6.74064V12.7593Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-bottom-center" shape.
+#[cfg(
+    any(feature = "chat-bubble-bottom-center", heroicon = "chat-bubble-bottom-center")
+)]
+pub static CHAT_BUBBLE_BOTTOM_CENTER: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-bottom-center",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 12.7593C2.25 14.3604 3.37341 15.754 4.95746 15.987C6.02548 16.144 7.10495 16.2659 8.19464 16.3513C8.66142 16.388 9.08828 16.6324 9.348 17.022L12 21L14.652 17.0221C14.9117 16.6325 15.3386 16.388 15.8053 16.3514C16.895 16.2659 17.9745 16.1441 19.0425 15.9871C20.6266 15.7542 21.75 14.3606 21.75 12.7595V6.74056C21.75 5.13946 20.6266 3.74583 19.0425 3.51293C16.744 3.17501 14.3926 3 12.0003 3C9.60776 3 7.25612 3.17504 4.95747 3.51302C3.37342 3.74593 2.25 5.13956 2.25 6.74064V12.7593Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-left-ellipsis" shape.
+#[cfg(
+    any(feature = "chat-bubble-left-ellipsis", heroicon = "chat-bubble-left-ellipsis")
+)]
+pub static CHAT_BUBBLE_LEFT_ELLIPSIS: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-left-ellipsis",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.625 9.75C8.625 9.95711 8.45711 10.125 8.25 10.125C8.04289 10.125 7.875 9.95711 7.875 9.75C7.875 9.54289 8.04289 9.375 8.25 9.375C8.45711 9.375 8.625 9.54289 8.625 9.75ZM8.625 9.75H8.25M12.375 9.75C12.375 9.95711 12.2071 10.125 12 10.125C11.7929 10.125 11.625 9.95711 11.625 9.75C11.625 9.54289 11.7929 9.375 12 9.375C12.2071 9.375 12.375 9.54289 12.375 9.75ZM12.375 9.75H12M16.125 9.75C16.125 9.95711 15.9571 10.125 15.75 10.125C15.5429 10.125 15.375 9.95711 15.375 9.75C15.375 9.54289 15.5429 9.375 15.75 9.375C15.9571 9.375 16.125 9.54289 16.125 9.75ZM16.125 9.75H15.75M2.25 12.7593C2.25 14.3604 3.37341 15.754 4.95746 15.987C6.04357 16.1467 7.14151 16.27 8.25 16.3556V21L12.4335 16.8165C12.6402 16.6098 12.9193 16.4923 13.2116 16.485C15.1872 16.4361 17.1331 16.2678 19.0425 15.9871C20.6266 15.7542 21.75 14.3606 21.75 12.7595V6.74056C21.75 5.13946 20.6266 3.74583 19.0425 3.51293C16.744 3.17501 14.3926 3 12.0003 3C9.60776 3 7.25612 3.17504 4.95747 3.51302C3.37342 3.74593 2.25 5.13956 2.25 6.74064V12.7593Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-left-right" shape.
+#[cfg(any(feature = "chat-bubble-left-right", heroicon = "chat-bubble-left-right"))]
+pub static CHAT_BUBBLE_LEFT_RIGHT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-left-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M20.25 8.51104C21.1341 8.79549 21.75 9.6392 21.75 10.6082V14.8938C21.75 16.0304 20.9026 16.9943 19.7697 17.0867C19.4308 17.1144 19.0909 17.1386 18.75 17.1592V20.25L15.75 17.25C14.3963 17.25 13.0556 17.1948 11.7302 17.0866C11.4319 17.0623 11.1534 16.9775 10.9049 16.8451M20.25 8.51104C20.0986 8.46232 19.9393 8.43 19.7739 8.41628C18.4472 8.30616 17.1051 8.25 15.75 8.25C14.3948 8.25 13.0528 8.30616 11.7261 8.41627C10.595 8.51015 9.75 9.47323 9.75 10.6082V14.8937C9.75 15.731 10.2099 16.4746 10.9049 16.8451M20.25 8.51104V6.63731C20.25 5.01589 19.0983 3.61065 17.4903 3.40191C15.4478 3.13676 13.365 3 11.2503 3C9.13533 3 7.05233 3.13678 5.00963 3.40199C3.40173 3.61074 2.25 5.01598 2.25 6.63738V12.8626C2.25 14.484 3.40173 15.8893 5.00964 16.098C5.58661 16.1729 6.16679 16.2376 6.75 16.2918V21L10.9049 16.8451",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-left" shape.
+#[cfg(any(feature = "chat-bubble-left", heroicon = "chat-bubble-left"))]
+pub static CHAT_BUBBLE_LEFT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 12.7593C2.25 14.3604 3.37341 15.754 4.95746 15.987C6.04357 16.1467 7.14151 16.27 8.25 16.3556V21L12.326 16.924C12.6017 16.6483 12.9738 16.4919 13.3635 16.481C15.2869 16.4274 17.1821 16.2606 19.0425 15.9871C20.6266 15.7542 21.75 14.3606 21.75 12.7595V6.74056C21.75 5.13946 20.6266 3.74583 19.0425 3.51293C16.744 3.17501 14.3926 3 12.0003 3C9.60776 3 7.25612 3.17504 4.95747 3.51302C3.37342 3.74593 2.25 5.13956 2.25 6.74064V12.7593Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-oval-left-ellipsis" shape.
+#[cfg(
+    any(
+        feature = "chat-bubble-oval-left-ellipsis",
+        heroicon = "chat-bubble-oval-left-ellipsis"
+    )
+)]
+pub static CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-oval-left-ellipsis",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.625 12C8.625 12.2071 8.45711 12.375 8.25 12.375C8.04289 12.375 7.875 12.2071 7.875 12C7.875 11.7929 8.04289 11.625 8.25 11.625C8.45711 11.625 8.625 11.7929 8.625 12ZM8.625 12H8.25M12.375 12C12.375 12.2071 12.2071 12.375 12 12.375C11.7929 12.375 11.625 12.2071 11.625 12C11.625 11.7929 11.7929 11.625 12 11.625C12.2071 11.625 12.375 11.7929 12.375 12ZM12.375 12H12M16.125 12C16.125 12.2071 15.9571 12.375 15.75 12.375C15.5429 12.375 15.375 12.2071 15.375 12C15.375 11.7929 15.5429 11.625 15.75 11.625C15.9571 11.625 16.125 11.7929 16.125 12ZM16.125 12H15.75M21 12C21 16.5563 16.9706 20.25 12 20.25C11.1125 20.25 10.2551 20.1323 9.44517 19.9129C8.47016 20.5979 7.28201 21 6 21C5.80078 21 5.60376 20.9903 5.40967 20.9713C5.25 20.9558 5.0918 20.9339 4.93579 20.906C5.41932 20.3353 5.76277 19.6427 5.91389 18.8808C6.00454 18.4238 5.7807 17.9799 5.44684 17.6549C3.9297 16.1782 3 14.1886 3 12C3 7.44365 7.02944 3.75 12 3.75C16.9706 3.75 21 7.44365 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-oval-left" shape.
+#[cfg(any(feature = "chat-bubble-oval-left", heroicon = "chat-bubble-oval-left"))]
+pub static CHAT_BUBBLE_OVAL_LEFT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-oval-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 20.25C16.9706 20.25 21 16.5563 21 12C21 7.44365 16.9706 3.75 12 3.75C7.02944 3.75 3 7.44365 3 12C3 14.1036 3.85891 16.0234 5.2728 17.4806C5.70538 17.9265 6.01357 18.5192 5.85933 19.121C5.68829 19.7883 5.368 20.3959 4.93579 20.906C5.0918 20.9339 5.25 20.9558 5.40967 20.9713C5.60376 20.9903 5.80078 21 6 21C7.28201 21 8.47016 20.5979 9.44517 19.9129C10.2551 20.1323 11.1125 20.25 12 20.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "check-badge" shape.
+#[cfg(any(feature = "check-badge", heroicon = "check-badge"))]
+pub static CHECK_BADGE: super::StaticShape = super::StaticShape::new(
+    "check-badge",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 12.75L11.25 15L15 9.75M21 12C21 13.2683 20.3704 14.3895 19.4067 15.0682C19.6081 16.2294 19.2604 17.4672 18.3637 18.3639C17.467 19.2606 16.2292 19.6083 15.068 19.4069C14.3893 20.3705 13.2682 21 12 21C10.7319 21 9.61072 20.3705 8.93204 19.407C7.77066 19.6086 6.53256 19.261 5.6357 18.3641C4.73886 17.4673 4.39125 16.2292 4.59286 15.0678C3.62941 14.3891 3 13.2681 3 12C3 10.7319 3.62946 9.61077 4.59298 8.93208C4.39147 7.77079 4.7391 6.53284 5.63587 5.63607C6.53265 4.73929 7.77063 4.39166 8.93194 4.59319C9.61061 3.62955 10.7318 3 12 3C13.2682 3 14.3893 3.6295 15.068 4.59307C16.2294 4.39145 17.4674 4.73906 18.3643 5.6359C19.2611 6.53274 19.6087 7.77081 19.4071 8.93218C20.3706 9.61087 21 10.7319 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "check-circle" shape.
+#[cfg(any(feature = "check-circle", heroicon = "check-circle"))]
+pub static CHECK_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "check-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 12.75L11.25 15L15 9.75M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "check" shape.
+#[cfg(any(feature = "check", heroicon = "check"))]
+pub static CHECK: super::StaticShape = super::StaticShape::new(
+    "check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 12.75L10.5 18.75L19.5 5.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-down" shape.
+#[cfg(any(feature = "chevron-double-down", heroicon = "chevron-double-down"))]
+pub static CHEVRON_DOUBLE_DOWN: super::StaticShape = super::StaticShape::new(
+    "chevron-double-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 5.25L12 12.75L4.5 5.25M19.5 11.25L12 18.75L4.5 11.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-left" shape.
+#[cfg(any(feature = "chevron-double-left", heroicon = "chevron-double-left"))]
+pub static CHEVRON_DOUBLE_LEFT: super::StaticShape = super::StaticShape::new(
+    "chevron-double-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18.75 19.5L11.25 12L18.75 4.5M12.75 19.5L5.25 12L12.75 4.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-right" shape.
+#[cfg(any(feature = "chevron-double-right", heroicon = "chevron-double-right"))]
+pub static CHEVRON_DOUBLE_RIGHT: super::StaticShape = super::StaticShape::new(
+    "chevron-double-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.25 4.5L18.75 12L11.25 19.5M5.25 4.5L12.75 12L5.25 19.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-up" shape.
+#[cfg(any(feature = "chevron-double-up", heroicon = "chevron-double-up"))]
+pub static CHEVRON_DOUBLE_UP: super::StaticShape = super::StaticShape::new(
+    "chevron-double-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 12.75L12 5.25L19.5 12.75M4.5 18.75L12 11.25L19.5 18.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-down" shape.
+#[cfg(any(feature = "chevron-down", heroicon = "chevron-down"))]
+pub static CHEVRON_DOWN: super::StaticShape = super::StaticShape::new(
+    "chevron-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 8.25L12 15.75L4.5 8.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-left" shape.
+#[cfg(any(feature = "chevron-left", heroicon = "chevron-left"))]
+pub static CHEVRON_LEFT: super::StaticShape = super::StaticShape::new(
+    "chevron-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 19.5L8.25 12L15.75 4.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-right" shape.
+#[cfg(any(feature = "chevron-right", heroicon = "chevron-right"))]
+pub static CHEVRON_RIGHT: super::StaticShape = super::StaticShape::new(
+    "chevron-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.25 4.5L15.75 12L8.25 19.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-up-down" shape.
+#[cfg(any(feature = "chevron-up-down", heroicon = "chevron-up-down"))]
+pub static CHEVRON_UP_DOWN: super::StaticShape = super::StaticShape::new(
+    "chevron-up-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.25 15L12 18.75L15.75 15M8.25 9L12 5.25L15.75 9",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-up" shape.
+#[cfg(any(feature = "chevron-up", heroicon = "chevron-up"))]
+pub static CHEVRON_UP: super::StaticShape = super::StaticShape::new(
+    "chevron-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 15.75L12 8.25L19.5 15.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "circle-stack" shape.
+#[cfg(any(feature = "circle-stack", heroicon = "circle-stack"))]
+pub static CIRCLE_STACK: super::StaticShape = super::StaticShape::new(
+    "circle-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M20.25 6.375C20.25 8.65317 16.5563 10.5 12 10.5C7.44365 10.5 3.75 8.65317 3.75 6.375M20.25 6.375C20.25 4.09683 16.5563 2.25 12 2.25C7.44365 2.25 3.75 4.09683 3.75 6.375M20.25 6.375V17.625C20.25 19.9032 16.5563 21.75 12 21.75C7.44365 21.75 3.75 19.9032 3.75 17.625V6.375M20.25 6.375V10.125M3.75 6.375V10.125M20.25 10.125V13.875C20.25 16.1532 16.5563 18 12 18C7.44365 18 3.75 16.1532 3.75 13.875V10.125M20.25 10.125C20.25 12.4032 16.5563 14.25 12 14.25C7.44365 14.25 3.75 12.4032 3.75 10.125",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard-document-check" shape.
+#[cfg(any(feature = "clipboard-document-check", heroicon = "clipboard-document-check"))]
+pub static CLIPBOARD_DOCUMENT_CHECK: super::StaticShape = super::StaticShape::new(
+    "clipboard-document-check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.3495 3.83619C11.2848 4.04602 11.25 4.26894 11.25 4.5C11.25 4.91421 11.5858 5.25 12 5.25H16.5C16.9142 5.25 17.25 4.91421 17.25 4.5C17.25 4.26894 17.2152 4.04602 17.1505 3.83619M11.3495 3.83619C11.6328 2.91757 12.4884 2.25 13.5 2.25H15C16.0116 2.25 16.8672 2.91757 17.1505 3.83619M11.3495 3.83619C10.9739 3.85858 10.5994 3.88529 10.2261 3.91627C9.09499 4.01015 8.25 4.97324 8.25 6.10822V8.25M17.1505 3.83619C17.5261 3.85858 17.9006 3.88529 18.2739 3.91627C19.405 4.01015 20.25 4.97324 20.25 6.10822V16.5C20.25 17.7426 19.2426 18.75 18 18.75H15.75M8.25 8.25H4.875C4.25368 8.25 3.75 8.75368 3.75 9.375V20.625C3.75 21.2463 4.25368 21.75 4.875 21.75H14.625C15.2463 21.75 15.75 21.2463 15.75 20.625V18.75M8.25 8.25H14.625C15.2463 8.25 15.75 8.75368 15.75 9.375V18.75M7.5 15.75L9 17.25L12 13.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard-document-list" shape.
+#[cfg(any(feature = "clipboard-document-list", heroicon = "clipboard-document-list"))]
+pub static CLIPBOARD_DOCUMENT_LIST: super::StaticShape = super::StaticShape::new(
+    "clipboard-document-list",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 12H12.75M9 15H12.75M9 18H12.75M15.75 18.75H18C19.2426 18.75 20.25 17.7426 20.25 16.5V6.10822C20.25 4.97324 19.405 4.01015 18.2739 3.91627C17.9006 3.88529 17.5261 3.85858 17.1505 3.83619M11.3495 3.83619C11.2848 4.04602 11.25 4.26894 11.25 4.5C11.25 4.91421 11.5858 5.25 12 5.25H16.5C16.9142 5.25 17.25 4.91421 17.25 4.5C17.25 4.26894 17.2152 4.04602 17.1505 3.83619M11.3495 3.83619C11.6328 2.91757 12.4884 2.25 13.5 2.25H15C16.0116 2.25 16.8672 2.91757 17.1505 3.83619M11.3495 3.83619C10.9739 3.85858 10.5994 3.88529 10.2261 3.91627C9.09499 4.01015 8.25 4.97324 8.25 6.10822V8.25M8.25 8.25H4.875C4.25368 8.25 3.75 8.75368 3.75 9.375V20.625C3.75 21.2463 4.25368 21.75 4.875 21.75H14.625C15.2463 21.75 15.75 21.2463 15.75 20.625V9.375C15.75 8.75368 15.2463 8.25 14.625 8.25H8.25ZM6.75 12H6.7575V12.0075H6.75V12ZM6.75 15H6.7575V15.0075H6.75V15ZM6.75 18H6.7575V18.0075H6.75V18Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard-document" shape.
+#[cfg(any(feature = "clipboard-document", heroicon = "clipboard-document"))]
+pub static CLIPBOARD_DOCUMENT: super::StaticShape = super::StaticShape::new(
+    "clipboard-document",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.25 7.5V6.10822C8.25 4.97324 9.09499 4.01015 10.2261 3.91627C10.5994 3.88529 10.9739 3.85858 11.3495 3.83619M15.75 18H18C19.2426 18 20.25 16.9926 20.25 15.75V6.10822C20.25 4.97324 19.405 4.01015 18.2739 3.91627C17.9006 3.88529 17.5261 3.85858 17.1505 3.83619M15.75 18.75V16.875C15.75 15.011 14.239 13.5 12.375 13.5H10.875C10.2537 13.5 9.75 12.9963 9.75 12.375V10.875C9.75 9.01104 8.23896 7.5 6.375 7.5H5.25M17.1505 3.83619C16.8672 2.91757 16.0116 2.25 15 2.25H13.5C12.4884 2.25 11.6328 2.91757 11.3495 3.83619M17.1505 3.83619C17.2152 4.04602 17.25 4.26894 17.25 4.5V5.25H11.25V4.5C11.25 4.26894 11.2848 4.04602 11.3495 3.83619M6.75 7.5H4.875C4.25368 7.5 3.75 8.00368 3.75 8.625V20.625C3.75 21.2463 4.25368 21.75 4.875 21.75H14.625C15.2463 21.75 15.75 21.2463 15.75 20.625V16.5C15.75 11.5294 11.7206 7.5 6.75 7.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard" shape.
+#[cfg(any(feature = "clipboard", heroicon = "clipboard"))]
+pub static CLIPBOARD: super::StaticShape = super::StaticShape::new(
+    "clipboard",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.6657 3.88789C15.3991 2.94272 14.5305 2.25 13.5 2.25H10.5C9.46954 2.25 8.60087 2.94272 8.33426 3.88789M15.6657 3.88789C15.7206 4.0825 15.75 4.28782 15.75 4.5V4.5C15.75 4.91421 15.4142 5.25 15 5.25H9C8.58579 5.25 8.25 4.91421 8.25 4.5V4.5C8.25 4.28782 8.27937 4.0825 8.33426 3.88789M15.6657 3.88789C16.3119 3.93668 16.9545 3.99828 17.5933 4.07241C18.6939 4.20014 19.5 5.149 19.5 6.25699V19.5C19.5 20.7426 18.4926 21.75 17.25 21.75H6.75C5.50736 21.75 4.5 20.7426 4.5 19.5V6.25699C4.5 5.149 5.30608 4.20014 6.40668 4.07241C7.04547 3.99828 7.68808 3.93668 8.33426 3.88789",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clock" shape.
+#[cfg(any(feature = "clock", heroicon = "clock"))]
+pub static CLOCK: super::StaticShape = super::StaticShape::new(
+    "clock",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 6V12H16.5M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cloud-arrow-down" shape.
+#[cfg(any(feature = "cloud-arrow-down", heroicon = "cloud-arrow-down"))]
+pub static CLOUD_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "cloud-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 9.75V16.5M12 16.5L9 13.5M12 16.5L15 13.5M6.75 19.5C4.26472 19.5 2.25 17.4853 2.25 15C2.25 13.0071 3.54555 11.3167 5.3404 10.7252C5.28105 10.4092 5.25 10.0832 5.25 9.75C5.25 6.85051 7.60051 4.5 10.5 4.5C12.9312 4.5 14.9765 6.1526 15.5737 8.39575C15.8654 8.30113 16.1767 8.25 16.5 8.25C18.1569 8.25 19.5 9.59315 19.5 11.25C19.5 11.5981 19.4407 11.9324 19.3316 12.2433C20.7453 12.7805 21.75 14.1479 21.75 15.75C21.75 17.8211 20.0711 19.5 18 19.5H6.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cloud-arrow-up" shape.
+#[cfg(any(feature = "cloud-arrow-up", heroicon = "cloud-arrow-up"))]
+pub static CLOUD_ARROW_UP: super::StaticShape = super::StaticShape::new(
+    "cloud-arrow-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 16.5L12 9.75M12 9.75L15 12.75M12 9.75L9 12.75M6.75 19.5C4.26472 19.5 2.25 17.4853 2.25 15C2.25 13.0071 3.54555 11.3167 5.3404 10.7252C5.28105 10.4092 5.25 10.0832 5.25 9.75C5.25 6.85051 7.60051 4.5 10.5 4.5C12.9312 4.5 14.9765 6.1526 15.5737 8.39575C15.8654 8.30113 16.1767 8.25 16.5 8.25C18.1569 8.25 19.5 9.59315 19.5 11.25C19.5 11.5981 19.4407 11.9324 19.3316 12.2433C20.7453 12.7805 21.75 14.1479 21.75 15.75C21.75 17.8211 20.0711 19.5 18 19.5H6.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cloud" shape.
+#[cfg(any(feature = "cloud", heroicon = "cloud"))]
+pub static CLOUD: super::StaticShape = super::StaticShape::new(
+    "cloud",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 15C2.25 17.4853 4.26472 19.5 6.75 19.5H18C20.0711 19.5 21.75 17.8211 21.75 15.75C21.75 14.1479 20.7453 12.7805 19.3316 12.2433C19.4407 11.9324 19.5 11.5981 19.5 11.25C19.5 9.59315 18.1569 8.25 16.5 8.25C16.1767 8.25 15.8654 8.30113 15.5737 8.39575C14.9765 6.1526 12.9312 4.5 10.5 4.5C7.6005 4.5 5.25 6.85051 5.25 9.75C5.25 10.0832 5.28105 10.4092 5.3404 10.7252C3.54555 11.3167 2.25 13.0071 2.25 15Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "code-bracket-square" shape.
+#[cfg(any(feature = "code-bracket-square", heroicon = "code-bracket-square"))]
+pub static CODE_BRACKET_SQUARE: super::StaticShape = super::StaticShape::new(
+    "code-bracket-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.25 9.75L16.5 12L14.25 14.25M9.75 14.25L7.5 12L9.75 9.75M6 20.25H18C19.2426 20.25 20.25 19.2426 20.25 18V6C20.25 4.75736 19.2426 3.75 18 3.75H6C4.75736 3.75 3.75 4.75736 3.75 6V18C3.75 19.2426 4.75736 20.25 6 20.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "code-bracket" shape.
+#[cfg(any(feature = "code-bracket", heroicon = "code-bracket"))]
+pub static CODE_BRACKET: super::StaticShape = super::StaticShape::new(
+    "code-bracket",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.25 6.75L22.5 12L17.25 17.25M6.75 17.25L1.5 12L6.75 6.75M14.25 3.75L9.75 20.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cog-6-tooth" shape.
+#[cfg(any(feature = "cog-6-tooth", heroicon = "cog-6-tooth"))]
+pub static COG_6_TOOTH: super::StaticShape = super::StaticShape::new(
+    "cog-6-tooth",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.59353 3.94005C9.68394 3.39759 10.1533 3 10.7032 3H13.2972C13.8471 3 14.3165 3.39759 14.4069 3.94005L14.6204 5.2211C14.6827 5.59514 14.9327 5.90671 15.2645 6.09036C15.3386 6.13142 15.412 6.17383 15.4844 6.21757C15.8094 6.41384 16.2048 6.47486 16.5603 6.34166L17.7772 5.88578C18.2922 5.69284 18.8712 5.90051 19.1461 6.37677L20.4431 8.62321C20.7181 9.09948 20.6084 9.70473 20.1839 10.0543L19.1795 10.8811C18.887 11.1219 18.742 11.4937 18.749 11.8725C18.7498 11.9149 18.7502 11.9574 18.7502 12C18.7502 12.0426 18.7498 12.0851 18.749 12.1275C18.742 12.5063 18.887 12.8781 19.1795 13.1189L20.1839 13.9457C20.6084 14.2953 20.7181 14.9005 20.4431 15.3768L19.1461 17.6232C18.8712 18.0995 18.2922 18.3071 17.7772 18.1142L16.5603 17.6583C16.2048 17.5251 15.8094 17.5862 15.4844 17.7824C15.412 17.8262 15.3386 17.8686 15.2645 17.9096C14.9327 18.0933 14.6827 18.4049 14.6204 18.7789L14.4069 20.0599C14.3165 20.6024 13.8471 21 13.2972 21H10.7032C10.1533 21 9.68394 20.6024 9.59353 20.0599L9.38002 18.7789C9.31768 18.4049 9.06771 18.0933 8.73594 17.9096C8.66176 17.8686 8.58844 17.8262 8.51601 17.7824C8.19098 17.5862 7.79565 17.5251 7.44008 17.6583L6.22322 18.1142C5.70822 18.3072 5.12923 18.0995 4.85426 17.6232L3.55728 15.3768C3.28231 14.9005 3.39196 14.2953 3.81654 13.9457L4.82089 13.1189C5.1134 12.8781 5.2584 12.5063 5.25138 12.1275C5.2506 12.0851 5.2502 12.0426 5.2502 12C5.2502 11.9574 5.2506 11.9149 5.25138 11.8725C5.2584 11.4937 5.1134 11.1219 4.82089 10.8811L3.81654 10.0543C3.39196 9.70475 3.28231 9.09949 3.55728 8.62323L4.85426 6.37679C5.12923 5.90052 5.70822 5.69286 6.22321 5.88579L7.44007 6.34167C7.79563 6.47487 8.19096 6.41385 8.516 6.21758C8.58843 6.17384 8.66176 6.13142 8.73594 6.09036C9.06771 5.90671 9.31768 5.59514 9.38002 5.2211L9.59353 3.94005Z M15 11.9999C15 13.6568 13.6568 14.9999 12 14.9999C10.3431 14.9999 8.99997 13.6568 8.99997 11.9999C8.99997 10.3431 10.3431 8.99992 12 8.99992C13.6568 8.99992 15 10.3431 15 11.9999Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cog-8-tooth" shape.
+#[cfg(any(feature = "cog-8-tooth", heroicon = "cog-8-tooth"))]
+pub static COG_8_TOOTH: super::StaticShape = super::StaticShape::new(
+    "cog-8-tooth",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.3427 3.94005C10.4331 3.39759 10.9024 3 11.4523 3H12.5463C13.0963 3 13.5656 3.39759 13.656 3.94005L13.805 4.83386C13.8757 5.25813 14.1886 5.59838 14.5859 5.76332C14.9833 5.92832 15.4396 5.90629 15.7898 5.65617L16.5273 5.12933C16.9749 4.80969 17.5879 4.86042 17.9767 5.24929L18.7503 6.02284C19.1392 6.41171 19.1899 7.02472 18.8702 7.47223L18.3432 8.21007C18.0932 8.56012 18.0711 9.01633 18.2361 9.41363C18.401 9.81078 18.7411 10.1236 19.1653 10.1943L20.0593 10.3433C20.6017 10.4337 20.9993 10.9031 20.9993 11.453V12.547C20.9993 13.0969 20.6017 13.5663 20.0593 13.6567L19.1655 13.8056C18.7412 13.8764 18.4009 14.1893 18.236 14.5865C18.071 14.9839 18.0931 15.4403 18.3432 15.7904L18.8699 16.5278C19.1895 16.9753 19.1388 17.5884 18.7499 17.9772L17.9764 18.7508C17.5875 19.1396 16.9745 19.1904 16.527 18.8707L15.7894 18.3439C15.4393 18.0938 14.9831 18.0718 14.5857 18.2367C14.1886 18.4016 13.8757 18.7418 13.805 19.166L13.656 20.0599C13.5656 20.6024 13.0963 21 12.5463 21H11.4523C10.9024 21 10.4331 20.6024 10.3427 20.0599L10.1937 19.1661C10.123 18.7419 9.81005 18.4016 9.41282 18.2367C9.01541 18.0717 8.55908 18.0937 8.20893 18.3438L7.47131 18.8707C7.0238 19.1904 6.41079 19.1396 6.02192 18.7507L5.24837 17.9772C4.8595 17.5883 4.80877 16.9753 5.12842 16.5278L5.65545 15.79C5.90549 15.4399 5.92754 14.9837 5.76258 14.5864C5.5977 14.1892 5.25752 13.8764 4.83335 13.8057L3.93938 13.6567C3.39692 13.5663 2.99933 13.0969 2.99933 12.547V11.453C2.99933 10.9031 3.39692 10.4337 3.93938 10.3433L4.83319 10.1944C5.25746 10.1236 5.59771 9.81071 5.76265 9.41347C5.92766 9.01605 5.90562 8.5597 5.6555 8.20954L5.12881 7.47216C4.80916 7.02465 4.85989 6.41164 5.24876 6.02277L6.02231 5.24922C6.41118 4.86036 7.02419 4.80962 7.4717 5.12927L8.2093 5.65613C8.55937 5.90618 9.01561 5.92822 9.41293 5.76326C9.8101 5.59837 10.123 5.25819 10.1937 4.834L10.3427 3.94005Z M15 12C15 13.6569 13.6569 15 12 15C10.3432 15 9.00003 13.6569 9.00003 12C9.00003 10.3432 10.3432 9.00002 12 9.00002C13.6569 9.00002 15 10.3432 15 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cog" shape.
+#[cfg(any(feature = "cog", heroicon = "cog"))]
+pub static COG: super::StaticShape = super::StaticShape::new(
+    "cog",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.50073 11.9993C4.50073 16.1414 7.8586 19.4993 12.0007 19.4993C16.1429 19.4993 19.5007 16.1414 19.5007 11.9993M4.50073 11.9993C4.50073 7.85712 7.8586 4.49925 12.0007 4.49925C16.1429 4.49926 19.5007 7.85712 19.5007 11.9993M4.50073 11.9993L3.00073 11.9993M19.5007 11.9993L21.0007 11.9993M19.5007 11.9993L12.0007 11.9993M3.54329 15.0774L4.95283 14.5644M19.0482 9.43411L20.4578 8.92108M5.1062 17.785L6.25527 16.8208M17.7459 7.17897L18.895 6.21479M7.50064 19.7943L8.25064 18.4952M15.7506 5.50484L16.5006 4.2058M10.4378 20.8633L10.6983 19.386M13.303 4.61393L13.5635 3.13672M13.5635 20.8633L13.303 19.3861M10.6983 4.61397L10.4378 3.13676M16.5007 19.7941L15.7507 18.4951M7.50068 4.20565L12.0007 11.9993M18.8952 17.7843L17.7461 16.8202M6.25542 7.17835L5.10635 6.21417M20.458 15.0776L19.0485 14.5646M4.95308 9.43426L3.54354 8.92123M12.0007 11.9993L8.25073 18.4944",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "command-line" shape.
+#[cfg(any(feature = "command-line", heroicon = "command-line"))]
+pub static COMMAND_LINE: super::StaticShape = super::StaticShape::new(
+    "command-line",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.75 7.5L9.75 9.75L6.75 12M11.25 12H14.25M5.25 20.25H18.75C19.9926 20.25 21 19.2426 21 18V6C21 4.75736 19.9926 3.75 18.75 3.75H5.25C4.00736 3.75 3 4.75736 3 6V18C3 19.2426 4.00736 20.25 5.25 20.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "computer-desktop" shape.
+#[cfg(any(feature = "computer-desktop", heroicon = "computer-desktop"))]
+pub static COMPUTER_DESKTOP: super::StaticShape = super::StaticShape::new(
+    "computer-desktop",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 17.25V18.2574C9 19.053 8.68393 19.8161 8.12132 20.3787L7.5 21H16.5L15.8787 20.3787C15.3161 19.8161 15 19.053 15 18.2574V17.25M21 5.25V15C21 16.2426 19.9926 17.25 18.75 17.25H5.25C4.00736 17.25 3 16.2426 3 15V5.25M21 5.25C21 4.00736 19.9926 3 18.75 3H5.25C4.00736 3 3 4.00736 3 5.25M21 5.25V12C21 13.2426 19.9926 14.25 18.75 14.25H5.25C4.00736 14.25 3 13.2426 3 12V5.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cpu-chip" shape.
+#[cfg(any(feature = "cpu-chip", heroicon = "cpu-chip"))]
+pub static CPU_CHIP: super::StaticShape = super::StaticShape::new(
+    "cpu-chip",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.25 3V4.5M4.5 8.25H3M21 8.25H19.5M4.5 12H3M21 12H19.5M4.5 15.75H3M21 15.75H19.5M8.25 19.5V21M12 3V4.5M12 19.5V21M15.75 3V4.5M15.75 19.5V21M6.75 19.5H17.25C18.4926 19.5 19.5 18.4926 19.5 17.25V6.75C19.5 5.50736 18.4926 4.5 17.25 4.5H6.75C5.50736 4.5 4.5 5.50736 4.5 6.75V17.25C4.5 18.4926 5.50736 19.5 6.75 19.5ZM7.5 7.5H16.5V16.5H7.5V7.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "credit-card" shape.
+#[cfg(any(feature = "credit-card", heroicon = "credit-card"))]
+pub static CREDIT_CARD: super::StaticShape = super::StaticShape::new(
+    "credit-card",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 8.25H21.75M2.25 9H21.75M5.25 14.25H11.25M5.25 16.5H8.25M4.5 19.5H19.5C20.7426 19.5 21.75 18.4926 21.75 17.25V6.75C21.75 5.50736 20.7426 4.5 19.5 4.5H4.5C3.25736 4.5 2.25 5.50736 2.25 6.75V17.25C2.25 18.4926 3.25736 19.5 4.5 19.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cube-transparent" shape.
+#[cfg(any(feature = "cube-transparent", heroicon = "cube-transparent"))]
+pub static CUBE_TRANSPARENT: super::StaticShape = super::StaticShape::new(
+    "cube-transparent",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 7.5L18.75 6.1875M21 7.5V9.75M21 7.5L18.75 8.8125M3 7.5L5.25 6.1875M3 7.5L5.25 8.8125M3 7.5V9.75M12 12.75L14.25 11.4375M12 12.75L9.75 11.4375M12 12.75V15M12 21.75L14.25 20.4375M12 21.75V19.5M12 21.75L9.75 20.4375M9.75 3.5625L12 2.25L14.25 3.5625M21 14.25V16.5L18.75 17.8125M5.25 17.8125L3 16.5V14.25",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cube" shape.
+#[cfg(any(feature = "cube", heroicon = "cube"))]
+pub static CUBE: super::StaticShape = super::StaticShape::new(
+    "cube",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 7.5L12 2.25L3 7.5M21 7.5L12 12.75M21 7.5V16.5L12 21.75M3 7.5L12 12.75M3 7.5V16.5L12 21.75M12 12.75V21.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-bangladeshi" shape.
+#[cfg(any(feature = "currency-bangladeshi", heroicon = "currency-bangladeshi"))]
+pub static CURRENCY_BANGLADESHI: super::StaticShape = super::StaticShape::new(
+    "currency-bangladeshi",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.25 7.49997L8.66459 7.29267C9.16327 7.04333 9.75 7.40596 9.75 7.96349V10.5M9.75 10.5H15.75M9.75 10.5H8.25M9.75 10.5V15.9383C9.75 16.2921 9.91144 16.6351 10.2229 16.803C10.7518 17.0882 11.357 17.25 12 17.25C13.8142 17.25 15.3275 15.9617 15.675 14.25C15.7579 13.8414 15.412 13.5 14.995 13.5H14.25M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-dollar" shape.
+#[cfg(any(feature = "currency-dollar", heroicon = "currency-dollar"))]
+pub static CURRENCY_DOLLAR: super::StaticShape = super::StaticShape::new(
+    "currency-dollar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 6V18M9 15.1818L9.87887 15.841C11.0504 16.7197 12.9498 16.7197 14.1214 15.841C15.2929 14.9623 15.2929 13.5377 14.1214 12.659C13.5355 12.2196 12.7677 12 11.9999 12C11.275 12 10.5502 11.7804 9.99709 11.341C8.891 10.4623 8.891 9.03772 9.9971 8.15904C11.1032 7.28036 12.8965 7.28036 14.0026 8.15904L14.4175 8.48863M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-euro" shape.
+#[cfg(any(feature = "currency-euro", heroicon = "currency-euro"))]
+pub static CURRENCY_EURO: super::StaticShape = super::StaticShape::new(
+    "currency-euro",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.25 7.75625C12.667 7.19798 10.8341 7.5519 9.56802 8.81802C7.81066 10.5754 7.81066 13.4246 9.56802 15.182C10.8341 16.4481 12.667 16.802 14.25 16.2437M7.5 10.5H12.75M7.5 13.5H12.75M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-pound" shape.
+#[cfg(any(feature = "currency-pound", heroicon = "currency-pound"))]
+pub static CURRENCY_POUND: super::StaticShape = super::StaticShape::new(
+    "currency-pound",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.1213 7.62877C12.9497 6.45719 11.0503 6.45719 9.87868 7.62877C9.37424 8.13321 9.08699 8.7726 9.01694 9.43073C8.9944 9.64251 9.01512 9.85582 9.04524 10.0667L9.5512 13.6084C9.68065 14.5146 9.5307 15.4386 9.12135 16.2573L9 16.5L10.5385 15.9872C11.0003 15.8332 11.4997 15.8332 11.9615 15.9872L12.6158 16.2053C13.182 16.394 13.7999 16.3501 14.3336 16.0832L15 15.75M8.25 12H12M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-rupee" shape.
+#[cfg(any(feature = "currency-rupee", heroicon = "currency-rupee"))]
+pub static CURRENCY_RUPEE: super::StaticShape = super::StaticShape::new(
+    "currency-rupee",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 8.25L9 8.25M15 11.25H9M12 17.25L9 14.25H10.5C12.1569 14.25 13.5 12.9069 13.5 11.25C13.5 9.59315 12.1569 8.25 10.5 8.25M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-yen" shape.
+#[cfg(any(feature = "currency-yen", heroicon = "currency-yen"))]
+pub static CURRENCY_YEN: super::StaticShape = super::StaticShape::new(
+    "currency-yen",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 7.5L12 12M12 12L15 7.5M12 12V17.25M15 12H9M15 15H9M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cursor-arrow-rays" shape.
+#[cfg(any(feature = "cursor-arrow-rays", heroicon = "cursor-arrow-rays"))]
+pub static CURSOR_ARROW_RAYS: super::StaticShape = super::StaticShape::new(
+    "cursor-arrow-rays",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.0423 21.6718L13.6835 16.6007M13.6835 16.6007L11.1741 18.826L11.7425 9.35623L16.9697 17.2731L13.6835 16.6007ZM12 2.25V4.5M17.8336 4.66637L16.2426 6.25736M20.25 10.5H18M7.75736 14.7426L6.16637 16.3336M6 10.5H3.75M7.75736 6.25736L6.16637 4.66637",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cursor-arrow-ripple" shape.
+#[cfg(any(feature = "cursor-arrow-ripple", heroicon = "cursor-arrow-ripple"))]
+pub static CURSOR_ARROW_RIPPLE: super::StaticShape = super::StaticShape::new(
+    "cursor-arrow-ripple",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.0423 21.6718L13.6835 16.6007M13.6835 16.6007L11.1741 18.826L11.7425 9.35623L16.9697 17.2731L13.6835 16.6007ZM6.16637 16.3336C2.94454 13.1118 2.94454 7.88819 6.16637 4.66637C9.38819 1.44454 14.6118 1.44454 17.8336 4.66637C19.4445 6.27724 20.25 8.38854 20.25 10.4999M8.28769 14.2123C6.23744 12.1621 6.23744 8.83794 8.28769 6.78769C10.3379 4.73744 13.6621 4.73744 15.7123 6.78769C16.7374 7.8128 17.25 9.15637 17.25 10.4999",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
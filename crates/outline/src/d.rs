@@ -0,0 +1,147 @@
+//! The shapes whose names start with "d".
+/// The "device-phone-mobile" shape.
+#[cfg(any(feature = "device-phone-mobile", heroicon = "device-phone-mobile"))]
+pub static DEVICE_PHONE_MOBILE: super::StaticShape = super::StaticShape::new(
+    "device-phone-mobile",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 1.5H8.25C7.00736 1.5 6 2.50736 6 3.75V20.25C6 21.4926 7.00736 22.5 8.25 22.5H15.75C16.9926 22.5 18 21.4926 18 20.25V3.75C18 2.50736 16.9926 1.5 15.75 1.5H13.5M10.5 1.5V3H13.5V1.5M10.5 1.5H13.5M10.5 20.25H13.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "device-tablet" shape.
+#[cfg(any(feature = "device-tablet", heroicon = "device-tablet"))]
+pub static DEVICE_TABLET: super::StaticShape = super::StaticShape::new(
+    "device-tablet",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 19.5H13.5M6.75 21.75H17.25C18.4926 21.75 19.5 20.7426 19.5 19.5V4.5C19.5 3.25736 18.4926 2.25 17.25 2.25H6.75C5.50736 2.25 4.5 3.25736 4.5 4.5V19.5C4.5 20.7426 5.50736 21.75 6.75 21.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-arrow-down" shape.
+#[cfg(any(feature = "document-arrow-down", heroicon = "document-arrow-down"))]
+pub static DOCUMENT_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "document-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 14.25V11.625C19.5 9.76104 17.989 8.25 16.125 8.25H14.625C14.0037 8.25 13.5 7.74632 13.5 7.125V5.625C13.5 3.76104 11.989 2.25 10.125 2.25H8.25M9 14.25L12 17.25M12 17.25L15 14.25M12 17.25L12 11.25M10.5 2.25H5.625C5.00368 2.25 4.5 2.75368 4.5 3.375V20.625C4.5 21.2463 5.00368 21.75 5.625 21.75H18.375C18.9963 21.75 19.5 21.2463 19.5 20.625V11.25C19.5 6.27944 15.4706 2.25 10.5 2.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-arrow-up" shape.
+#[cfg(any(feature = "document-arrow-up", heroicon = "document-arrow-up"))]
+pub static DOCUMENT_ARROW_UP: super::StaticShape = super::StaticShape::new(
+    "document-arrow-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 14.25V11.625C19.5 9.76104 17.989 8.25 16.125 8.25H14.625C14.0037 8.25 13.5 7.74632 13.5 7.125V5.625C13.5 3.76104 11.989 2.25 10.125 2.25H8.25M15 14.25L12 11.25M12 11.25L9 14.25M12 11.25L12 17.25M10.5 2.25H5.625C5.00368 2.25 4.5 2.75368 4.5 3.375V20.625C4.5 21.2463 5.00368 21.75 5.625 21.75H18.375C18.9963 21.75 19.5 21.2463 19.5 20.625V11.25C19.5 6.27944 15.4706 2.25 10.5 2.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-chart-bar" shape.
+#[cfg(any(feature = "document-chart-bar", heroicon = "document-chart-bar"))]
+pub static DOCUMENT_CHART_BAR: super::StaticShape = super::StaticShape::new(
+    "document-chart-bar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 14.25V11.625C19.5 9.76104 17.989 8.25 16.125 8.25H14.625C14.0037 8.25 13.5 7.74632 13.5 7.125V5.625C13.5 3.76104 11.989 2.25 10.125 2.25H8.25M9 16.5V17.25M12 14.25V17.25M15 12V17.25M10.5 2.25H5.625C5.00368 2.25 4.5 2.75368 4.5 3.375V20.625C4.5 21.2463 5.00368 21.75 5.625 21.75H18.375C18.9963 21.75 19.5 21.2463 19.5 20.625V11.25C19.5 6.27944 15.4706 2.25 10.5 2.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-check" shape.
+#[cfg(any(feature = "document-check", heroicon = "document-check"))]
+pub static DOCUMENT_CHECK: super::StaticShape = super::StaticShape::new(
+    "document-check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.125 2.25H5.625C5.00368 2.25 4.5 2.75368 4.5 3.375V20.625C4.5 21.2463 5.00368 21.75 5.625 21.75H18.375C18.9963 21.75 19.5 21.2463 19.5 20.625V11.625M10.125 2.25H10.5C15.4706 2.25 19.5 6.27944 19.5 11.25V11.625M10.125 2.25C11.989 2.25 13.5 3.76104 13.5 5.625V7.125C13.5 7.74632 14.0037 8.25 14.625 8.25H16.125C17.989 8.25 19.5 9.76104 19.5 11.625M9 15L11.25 17.25L15 12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-duplicate" shape.
+#[cfg(any(feature = "document-duplicate", heroicon = "document-duplicate"))]
+pub static DOCUMENT_DUPLICATE: super::StaticShape = super::StaticShape::new(
+    "document-duplicate",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 17.25V20.625C15.75 21.2463 15.2463 21.75 14.625 21.75H4.875C4.25368 21.75 3.75 21.2463 3.75 20.625V7.875C3.75 7.25368 4.25368 6.75 4.875 6.75H6.75C7.26107 6.75 7.76219 6.7926 8.25 6.87444M15.75 17.25H19.125C19.7463 17.25 20.25 16.7463 20.25 16.125V11.25C20.25 6.79051 17.0066 3.08855 12.75 2.37444C12.2622 2.2926 11.7611 2.25 11.25 2.25H9.375C8.75368 2.25 8.25 2.75368 8.25 3.375V6.87444M15.75 17.25H9.375C8.75368 17.25 8.25 16.7463 8.25 16.125V6.87444M20.25 13.5V11.625C20.25 9.76104 18.739 8.25 16.875 8.25H15.375C14.7537 8.25 14.25 7.74632 14.25 7.125V5.625C14.25 3.76104 12.739 2.25 10.875 2.25H9.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-magnifying-glass" shape.
+#[cfg(
+    any(feature = "document-magnifying-glass", heroicon = "document-magnifying-glass")
+)]
+pub static DOCUMENT_MAGNIFYING_GLASS: super::StaticShape = super::StaticShape::new(
+    "document-magnifying-glass",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 14.25V11.625C19.5 9.76104 17.989 8.25 16.125 8.25H14.625C14.0037 8.25 13.5 7.74632 13.5 7.125V5.625C13.5 3.76104 11.989 2.25 10.125 2.25H8.25M13.4812 15.7312L15 17.25M10.5 2.25H5.625C5.00368 2.25 4.5 2.75368 4.5 3.375V19.875C4.5 20.4963 5.00368 21 5.625 21H18.375C18.9963 21 19.5 20.4963 19.5 19.875V11.25C19.5 6.27944 15.4706 2.25 10.5 2.25ZM14.25 13.875C14.25 15.3247 13.0747 16.5 11.625 16.5C10.1753 16.5 9 15.3247 9 13.875C9 12.4253 10.1753 11.25 11.625 11.25C13.0747 11.25 14.25 12.4253 14.25 13.875Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-minus" shape.
+#[cfg(any(feature = "document-minus", heroicon = "document-minus"))]
+pub static DOCUMENT_MINUS: super::StaticShape = super::StaticShape::new(
+    "document-minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 14.25V11.625C19.5 9.76104 17.989 8.25 16.125 8.25H14.625C14.0037 8.25 13.5 7.74632 13.5 7.125V5.625C13.5 3.76104 11.989 2.25 10.125 2.25H8.25M15 14.25H9M10.5 2.25H5.625C5.00368 2.25 4.5 2.75368 4.5 3.375V20.625C4.5 21.2463 5.00368 21.75 5.625 21.75H18.375C18.9963 21.75 19.5 21.2463 19.5 20.625V11.25C19.5 6.27944 15.4706 2.25 10.5 2.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-plus" shape.
+#[cfg(any(feature = "document-plus", heroicon = "document-plus"))]
+pub static DOCUMENT_PLUS: super::StaticShape = super::StaticShape::new(
+    "document-plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 14.25V11.625C19.5 9.76104 17.989 8.25 16.125 8.25H14.625C14.0037 8.25 13.5 7.74632 13.5 7.125V5.625C13.5 3.76104 11.989 2.25 10.125 2.25H8.25M12 11.25V17.25M15 14.25H9M10.5 2.25H5.625C5.00368 2.25 4.5 2.75368 4.5 3.375V20.625C4.5 21.2463 5.00368 21.75 5.625 21.75H18.375C18.9963 21.75 19.5 21.2463 19.5 20.625V11.25C19.5 6.27944 15.4706 2.25 10.5 2.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document-text" shape.
+#[cfg(any(feature = "document-text", heroicon = "document-text"))]
+pub static DOCUMENT_TEXT: super::StaticShape = super::StaticShape::new(
+    "document-text",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 14.25V11.625C19.5 9.76104 17.989 8.25 16.125 8.25H14.625C14.0037 8.25 13.5 7.74632 13.5 7.125V5.625C13.5 3.76104 11.989 2.25 10.125 2.25H8.25M8.25 15H15.75M8.25 18H12M10.5 2.25H5.625C5.00368 2.25 4.5 2.75368 4.5 3.375V20.625C4.5 21.2463 5.00368 21.75 5.625 21.75H18.375C18.9963 21.75 19.5 21.2463 19.5 20.625V11.25C19.5 6.27944 15.4706 2.25 10.5 2.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "document" shape.
+#[cfg(any(feature = "document", heroicon = "document"))]
+pub static DOCUMENT: super::StaticShape = super::StaticShape::new(
+    "document",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 14.25V11.625C19.5 9.76104 17.989 8.25 16.125 8.25H14.625C14.0037 8.25 13.5 7.74632 13.5 7.125V5.625C13.5 3.76104 11.989 2.25 10.125 2.25H8.25M10.5 2.25H5.625C5.00368 2.25 4.5 2.75368 4.5 3.375V20.625C4.5 21.2463 5.00368 21.75 5.625 21.75H18.375C18.9963 21.75 19.5 21.2463 19.5 20.625V11.25C19.5 6.27944 15.4706 2.25 10.5 2.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
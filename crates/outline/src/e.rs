@@ -0,0 +1,123 @@
+//! The shapes whose names start with "e".
+/// The "ellipsis-horizontal-circle" shape.
+#[cfg(
+    any(feature = "ellipsis-horizontal-circle", heroicon = "ellipsis-horizontal-circle")
+)]
+pub static ELLIPSIS_HORIZONTAL_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "ellipsis-horizontal-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.625 12C8.625 12.2071 8.45711 12.375 8.25 12.375C8.04289 12.375 7.875 12.2071 7.875 12C7.875 11.7929 8.04289 11.625 8.25 11.625C8.45711 11.625 8.625 11.7929 8.625 12ZM8.625 12H8.25M12.375 12C12.375 12.2071 12.2071 12.375 12 12.375C11.7929 12.375 11.625 12.2071 11.625 12C11.625 11.7929 11.7929 11.625 12 11.625C12.2071 11.625 12.375 11.7929 12.375 12ZM12.375 12H12M16.125 12C16.125 12.2071 15.9571 12.375 15.75 12.375C15.5429 12.375 15.375 12.2071 15.375 12C15.375 11.7929 15.5429 11.625 15.75 11.625C15.9571 11.625 16.125 11.7929 16.125 12ZM16.125 12H15.75M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "ellipsis-horizontal" shape.
+#[cfg(any(feature = "ellipsis-horizontal", heroicon = "ellipsis-horizontal"))]
+pub static ELLIPSIS_HORIZONTAL: super::StaticShape = super::StaticShape::new(
+    "ellipsis-horizontal",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.75 12C6.75 12.4142 6.41421 12.75 6 12.75C5.58579 12.75 5.25 12.4142 5.25 12C5.25 11.5858 5.58579 11.25 6 11.25C6.41421 11.25 6.75 11.5858 6.75 12Z M12.75 12C12.75 12.4142 12.4142 12.75 12 12.75C11.5858 12.75 11.25 12.4142 11.25 12C11.25 11.5858 11.5858 11.25 12 11.25C12.4142 11.25 12.75 11.5858 12.75 12Z M18.75 12C18.75 12.4142 18.4142 12.75 18 12.75C17.5858 12.75 17.25 12.4142 17.25 12C17.25 11.5858 17.5858 11.25 18 11.25C18.4142 11.25 18.75 11.5858 18.75 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "ellipsis-vertical" shape.
+#[cfg(any(feature = "ellipsis-vertical", heroicon = "ellipsis-vertical"))]
+pub static ELLIPSIS_VERTICAL: super::StaticShape = super::StaticShape::new(
+    "ellipsis-vertical",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 6.75C11.5858 6.75 11.25 6.41421 11.25 6C11.25 5.58579 11.5858 5.25 12 5.25C12.4142 5.25 12.75 5.58579 12.75 6C12.75 6.41421 12.4142 6.75 12 6.75Z M12 12.75C11.5858 12.75 11.25 12.4142 11.25 12C11.25 11.5858 11.5858 11.25 12 11.25C12.4142 11.25 12.75 11.5858 12.75 12C12.75 12.4142 12.4142 12.75 12 12.75Z M12 18.75C11.5858 18.75 11.25 18.4142 11.25 18C11.25 17.5858 11.5858 17.25 12 17.25C12.4142 17.25 12.75 17.5858 12.75 18C12.75 18.4142 12.4142 18.75 12 18.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "envelope-open" shape.
+#[cfg(any(feature = "envelope-open", heroicon = "envelope-open"))]
+pub static ENVELOPE_OPEN: super::StaticShape = super::StaticShape::new(
+    "envelope-open",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21.75 9.00021V9.9063C21.75 10.734 21.2955 11.4949 20.5667 11.8874L14.0893 15.3752M2.25 9.00021V9.9063C2.25 10.734 2.70448 11.4949 3.43328 11.8874L9.91074 15.3752M18.75 17.8849L14.0893 15.3752M14.0893 15.3752L13.0667 14.8246C12.4008 14.466 11.5992 14.466 10.9333 14.8246L9.91074 15.3752M9.91074 15.3752L5.25 17.8849M21.75 19.5002C21.75 20.7429 20.7426 21.7502 19.5 21.7502H4.5C3.25736 21.7502 2.25 20.7429 2.25 19.5002L2.25 8.84412C2.25 8.01639 2.70448 7.25549 3.43328 6.86307L10.9333 2.8246C11.5992 2.46602 12.4008 2.46602 13.0667 2.8246L20.5667 6.86307C21.2955 7.2555 21.75 8.01639 21.75 8.84413V19.5002Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "envelope" shape.
+#[cfg(any(feature = "envelope", heroicon = "envelope"))]
+pub static ENVELOPE: super::StaticShape = super::StaticShape::new(
+    "envelope",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21.75 6.75V17.25C21.75 18.4926 20.7426 19.5 19.5 19.5H4.5C3.25736 19.5 2.25 18.4926 2.25 17.25V6.75M21.75 6.75C21.75 5.50736 20.7426 4.5 19.5 4.5H4.5C3.25736 4.5 2.25 5.50736 2.25 6.75M21.75 6.75V6.99271C21.75 7.77405 21.3447 8.49945 20.6792 8.90894L13.1792 13.5243C12.4561 13.9694 11.5439 13.9694 10.8208 13.5243L3.32078 8.90894C2.65535 8.49945 2.25 7.77405 2.25 6.99271V6.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "exclamation-circle" shape.
+#[cfg(any(feature = "exclamation-circle", heroicon = "exclamation-circle"))]
+pub static EXCLAMATION_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "exclamation-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 9V12.75M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12ZM12 15.75H12.0075V15.7575H12V15.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "exclamation-triangle" shape.
+#[cfg(any(feature = "exclamation-triangle", heroicon = "exclamation-triangle"))]
+pub static EXCLAMATION_TRIANGLE: super::StaticShape = super::StaticShape::new(
+    "exclamation-triangle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.9998 9.00006V12.7501M2.69653 16.1257C1.83114 17.6257 2.91371 19.5001 4.64544 19.5001H19.3541C21.0858 19.5001 22.1684 17.6257 21.303 16.1257L13.9487 3.37819C13.0828 1.87736 10.9167 1.87736 10.0509 3.37819L2.69653 16.1257ZM11.9998 15.7501H12.0073V15.7576H11.9998V15.7501Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "eye-dropper" shape.
+#[cfg(any(feature = "eye-dropper", heroicon = "eye-dropper"))]
+pub static EYE_DROPPER: super::StaticShape = super::StaticShape::new(
+    "eye-dropper",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 11.25L16.5 12.75L17.25 12V8.75798L19.5264 8.14802C20.019 8.01652 20.4847 7.75778 20.8712 7.37132C22.0428 6.19975 22.0428 4.30025 20.8712 3.12868C19.6996 1.95711 17.8001 1.95711 16.6286 3.12868C16.2421 3.51509 15.9832 3.98069 15.8517 4.47324L15.2416 6.74998H12L11.25 7.49998L12.75 8.99999M15 11.25L6.53033 19.7197C6.19077 20.0592 5.73022 20.25 5.25 20.25C4.76978 20.25 4.30924 20.4408 3.96967 20.7803L3 21.75L2.25 21L3.21967 20.0303C3.55923 19.6908 3.75 19.2302 3.75 18.75C3.75 18.2698 3.94077 17.8092 4.28033 17.4697L12.75 8.99999M15 11.25L12.75 8.99999",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "eye-slash" shape.
+#[cfg(any(feature = "eye-slash", heroicon = "eye-slash"))]
+pub static EYE_SLASH: super::StaticShape = super::StaticShape::new(
+    "eye-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.9799 8.22257C3.05679 9.31382 2.35239 10.596 1.93433 12.0015C3.22562 16.338 7.24308 19.5 11.9991 19.5C12.9916 19.5 13.952 19.3623 14.8622 19.1049M6.2276 6.22763C7.88385 5.13558 9.86768 4.5 11.9999 4.5C16.7559 4.5 20.7734 7.66205 22.0647 11.9985C21.3528 14.3919 19.8105 16.4277 17.772 17.772M6.2276 6.22763L2.99997 3M6.2276 6.22763L9.87865 9.87868M17.772 17.772L21 21M17.772 17.772L14.1213 14.1213M14.1213 14.1213C14.6642 13.5784 15 12.8284 15 12C15 10.3431 13.6568 9 12 9C11.1715 9 10.4215 9.33579 9.87865 9.87868M14.1213 14.1213L9.87865 9.87868",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "eye" shape.
+#[cfg(any(feature = "eye", heroicon = "eye"))]
+pub static EYE: super::StaticShape = super::StaticShape::new(
+    "eye",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.03556 12.3224C1.96648 12.1151 1.96642 11.8907 2.03538 11.6834C3.42374 7.50972 7.3608 4.5 12.0008 4.5C16.6387 4.5 20.5742 7.50692 21.9643 11.6776C22.0334 11.8849 22.0335 12.1093 21.9645 12.3166C20.5762 16.4903 16.6391 19.5 11.9991 19.5C7.36121 19.5 3.42565 16.4931 2.03556 12.3224Z M15 12C15 13.6569 13.6569 15 12 15C10.3432 15 9.00001 13.6569 9.00001 12C9.00001 10.3431 10.3432 9 12 9C13.6569 9 15 10.3431 15 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
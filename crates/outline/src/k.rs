@@ -0,0 +1,13 @@
+//! The shapes whose names start with "k".
+/// The "key" shape.
+#[cfg(any(feature = "key", heroicon = "key"))]
+pub static KEY: super::StaticShape = super::StaticShape::new(
+    "key",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 5.25C17.4069 5.25 18.75 6.59315 18.75 8.25M21.75 8.25C21.75 11.5637 19.0637 14.25 15.75 14.25C15.3993 14.25 15.0555 14.2199 14.7213 14.1622C14.1583 14.0649 13.562 14.188 13.158 14.592L10.5 17.25H8.25V19.5H6V21.75H2.25V18.932C2.25 18.3352 2.48705 17.7629 2.90901 17.341L9.408 10.842C9.81202 10.438 9.93512 9.84172 9.83785 9.2787C9.7801 8.94446 9.75 8.60074 9.75 8.25C9.75 4.93629 12.4363 2.25 15.75 2.25C19.0637 2.25 21.75 4.93629 21.75 8.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,157 @@
+//! The shapes whose names start with "m".
+/// The "magnifying-glass-circle" shape.
+#[cfg(any(feature = "magnifying-glass-circle", heroicon = "magnifying-glass-circle"))]
+pub static MAGNIFYING_GLASS_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 15.75L13.2615 13.2615M13.2615 13.2615C13.8722 12.6507 14.25 11.807 14.25 10.875C14.25 9.01104 12.739 7.5 10.875 7.5C9.01104 7.5 7.5 9.01104 7.5 10.875C7.5 12.739 9.01104 14.25 10.875 14.25C11.807 14.25 12.6507 13.8722 13.2615 13.2615ZM21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "magnifying-glass-minus" shape.
+#[cfg(any(feature = "magnifying-glass-minus", heroicon = "magnifying-glass-minus"))]
+pub static MAGNIFYING_GLASS_MINUS: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass-minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 21L15.8033 15.8033M15.8033 15.8033C17.1605 14.4461 18 12.5711 18 10.5C18 6.35786 14.6421 3 10.5 3C6.35786 3 3 6.35786 3 10.5C3 14.6421 6.35786 18 10.5 18C12.5711 18 14.4461 17.1605 15.8033 15.8033ZM13.5 10.5H7.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "magnifying-glass-plus" shape.
+#[cfg(any(feature = "magnifying-glass-plus", heroicon = "magnifying-glass-plus"))]
+pub static MAGNIFYING_GLASS_PLUS: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass-plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 21L15.8033 15.8033M15.8033 15.8033C17.1605 14.4461 18 12.5711 18 10.5C18 6.35786 14.6421 3 10.5 3C6.35786 3 3 6.35786 3 10.5C3 14.6421 6.35786 18 10.5 18C12.5711 18 14.4461 17.1605 15.8033 15.8033ZM10.5 7.5V13.5M13.5 10.5H7.5",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "magnifying-glass" shape.
+#[cfg(any(feature = "magnifying-glass", heroicon = "magnifying-glass"))]
+pub static MAGNIFYING_GLASS: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 21L15.8033 15.8033M15.8033 15.8033C17.1605 14.4461 18 12.5711 18 10.5C18 6.35786 14.6421 3 10.5 3C6.35786 3 3 6.35786 3 10.5C3 14.6421 6.35786 18 10.5 18C12.5711 18 14.4461 17.1605 15.8033 15.8033Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "map-pin" shape.
+#[cfg(any(feature = "map-pin", heroicon = "map-pin"))]
+pub static MAP_PIN: super::StaticShape = super::StaticShape::new(
+    "map-pin",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 10.5C15 12.1569 13.6569 13.5 12 13.5C10.3431 13.5 9 12.1569 9 10.5C9 8.84315 10.3431 7.5 12 7.5C13.6569 7.5 15 8.84315 15 10.5Z M19.5 10.5C19.5 17.6421 12 21.75 12 21.75C12 21.75 4.5 17.6421 4.5 10.5C4.5 6.35786 7.85786 3 12 3C16.1421 3 19.5 6.35786 19.5 10.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "map" shape.
+#[cfg(any(feature = "map", heroicon = "map"))]
+pub static MAP: super::StaticShape = super::StaticShape::new(
+    "map",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 6.75002V15M15 9.00002V17.25M15.5031 20.7485L20.3781 18.311C20.7592 18.1204 21 17.7309 21 17.3047V4.82031C21 3.98401 20.1199 3.44007 19.3719 3.81408L15.5031 5.74847C15.1864 5.90683 14.8136 5.90683 14.4969 5.74847L9.50312 3.25158C9.1864 3.09322 8.8136 3.09322 8.49688 3.25158L3.62188 5.68908C3.24075 5.87965 3 6.26919 3 6.69531V19.1797C3 20.016 3.8801 20.56 4.62811 20.186L8.49688 18.2516C8.8136 18.0932 9.1864 18.0932 9.50312 18.2516L14.4969 20.7485C14.8136 20.9068 15.1864 20.9068 15.5031 20.7485Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "megaphone" shape.
+#[cfg(any(feature = "megaphone", heroicon = "megaphone"))]
+pub static MEGAPHONE: super::StaticShape = super::StaticShape::new(
+    "megaphone",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.3404 15.8398C9.65153 15.7803 8.95431 15.75 8.25 15.75H7.5C5.01472 15.75 3 13.7353 3 11.25C3 8.76472 5.01472 6.75 7.5 6.75H8.25C8.95431 6.75 9.65153 6.71966 10.3404 6.66022M10.3404 15.8398C10.5933 16.8015 10.9237 17.7317 11.3246 18.6234C11.5721 19.1738 11.3842 19.8328 10.8616 20.1345L10.2053 20.5134C9.6539 20.8318 8.9456 20.6306 8.67841 20.0527C8.0518 18.6973 7.56541 17.2639 7.23786 15.771M10.3404 15.8398C9.95517 14.3745 9.75 12.8362 9.75 11.25C9.75 9.66379 9.95518 8.1255 10.3404 6.66022M10.3404 15.8398C13.5 16.1124 16.4845 16.9972 19.1747 18.3749M10.3404 6.66022C13.5 6.3876 16.4845 5.50283 19.1747 4.12509M19.1747 4.12509C19.057 3.74595 18.9302 3.37083 18.7944 3M19.1747 4.12509C19.7097 5.84827 20.0557 7.65462 20.1886 9.51991M19.1747 18.3749C19.057 18.7541 18.9302 19.1292 18.7944 19.5M19.1747 18.3749C19.7097 16.6517 20.0557 14.8454 20.1886 12.9801M20.1886 9.51991C20.6844 9.93264 21 10.5545 21 11.25C21 11.9455 20.6844 12.5674 20.1886 12.9801M20.1886 9.51991C20.2293 10.0913 20.25 10.6682 20.25 11.25C20.25 11.8318 20.2293 12.4087 20.1886 12.9801",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "microphone" shape.
+#[cfg(any(feature = "microphone", heroicon = "microphone"))]
+pub static MICROPHONE: super::StaticShape = super::StaticShape::new(
+    "microphone",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 18.75C15.3137 18.75 18 16.0637 18 12.75V11.25M12 18.75C8.68629 18.75 6 16.0637 6 12.75V11.25M12 18.75V22.5M8.25 22.5H15.75M12 15.75C10.3431 15.75 9 14.4069 9 12.75V4.5C9 2.84315 10.3431 1.5 12 1.5C13.6569 1.5 15 2.84315 15 4.5V12.75C15 14.4069 13.6569 15.75 12 15.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "minus-circle" shape.
+#[cfg(any(feature = "minus-circle", heroicon = "minus-circle"))]
+pub static MINUS_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "minus-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 12H9M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "minus-small" shape.
+#[cfg(any(feature = "minus-small", heroicon = "minus-small"))]
+pub static MINUS_SMALL: super::StaticShape = super::StaticShape::new(
+    "minus-small",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18 12L6 12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "minus" shape.
+#[cfg(any(feature = "minus", heroicon = "minus"))]
+pub static MINUS: super::StaticShape = super::StaticShape::new(
+    "minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 12L4.5 12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "moon" shape.
+#[cfg(any(feature = "moon", heroicon = "moon"))]
+pub static MOON: super::StaticShape = super::StaticShape::new(
+    "moon",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21.7519 15.0019C20.597 15.4839 19.3296 15.75 18 15.75C12.6152 15.75 8.25 11.3848 8.25 5.99999C8.25 4.67039 8.51614 3.40296 8.99806 2.24805C5.47566 3.71785 3 7.19481 3 11.25C3 16.6348 7.36522 21 12.75 21C16.8052 21 20.2821 18.5243 21.7519 15.0019Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "musical-note" shape.
+#[cfg(any(feature = "musical-note", heroicon = "musical-note"))]
+pub static MUSICAL_NOTE: super::StaticShape = super::StaticShape::new(
+    "musical-note",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 9L19.5 6M19.5 12.5528V16.3028C19.5 17.3074 18.834 18.1903 17.8681 18.4663L16.5481 18.8434C15.3964 19.1724 14.25 18.3077 14.25 17.1099C14.25 16.305 14.7836 15.5975 15.5576 15.3764L17.8681 14.7163C18.834 14.4403 19.5 13.5574 19.5 12.5528ZM19.5 12.5528V2.25L9 5.25V15.5528M9 15.5528V19.3028C9 20.3074 8.33405 21.1903 7.36812 21.4663L6.04814 21.8434C4.89645 22.1724 3.75 21.3077 3.75 20.1099C3.75 19.305 4.2836 18.5975 5.05757 18.3764L7.36812 17.7163C8.33405 17.4403 9 16.5574 9 15.5528Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,25 @@
+//! The shapes whose names start with "n".
+/// The "newspaper" shape.
+#[cfg(any(feature = "newspaper", heroicon = "newspaper"))]
+pub static NEWSPAPER: super::StaticShape = super::StaticShape::new(
+    "newspaper",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 7.5H13.5M12 10.5H13.5M6 13.5H13.5M6 16.5H13.5M16.5 7.5H19.875C20.4963 7.5 21 8.00368 21 8.625V18C21 19.2426 19.9926 20.25 18.75 20.25M16.5 7.5V18C16.5 19.2426 17.5074 20.25 18.75 20.25M16.5 7.5V4.875C16.5 4.25368 15.9963 3.75 15.375 3.75H4.125C3.50368 3.75 3 4.25368 3 4.875V18C3 19.2426 4.00736 20.25 5.25 20.25H18.75M6 7.5H9V10.5H6V7.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "no-symbol" shape.
+#[cfg(any(feature = "no-symbol", heroicon = "no-symbol"))]
+pub static NO_SYMBOL: super::StaticShape = super::StaticShape::new(
+    "no-symbol",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18.364 18.364C21.8787 14.8492 21.8787 9.15076 18.364 5.63604C14.8492 2.12132 9.15076 2.12132 5.63604 5.63604M18.364 18.364C14.8492 21.8787 9.15076 21.8787 5.63604 18.364C2.12132 14.8492 2.12132 9.15076 5.63604 5.63604M18.364 18.364L5.63604 5.63604",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,277 @@
+//! The shapes whose names start with "p".
+/// The "paint-brush" shape.
+#[cfg(any(feature = "paint-brush", heroicon = "paint-brush"))]
+pub static PAINT_BRUSH: super::StaticShape = super::StaticShape::new(
+    "paint-brush",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.53086 16.1224C9.08517 15.0243 8.00801 14.25 6.75 14.25C5.09315 14.25 3.75 15.5931 3.75 17.25C3.75 18.4926 2.74262 19.5 1.49998 19.5C1.44928 19.5 1.39898 19.4983 1.34912 19.495C2.12648 20.8428 3.58229 21.75 5.24998 21.75C7.72821 21.75 9.73854 19.7467 9.74993 17.2711C9.74998 17.2641 9.75 17.2571 9.75 17.25C9.75 16.8512 9.67217 16.4705 9.53086 16.1224ZM9.53086 16.1224C10.7252 15.7153 11.8612 15.1705 12.9175 14.5028M7.875 14.4769C8.2823 13.2797 8.8281 12.1411 9.49724 11.0825M12.9175 14.5028C14.798 13.3141 16.4259 11.7362 17.6806 9.85406L21.5566 4.04006C21.6827 3.85093 21.75 3.6287 21.75 3.40139C21.75 2.76549 21.2345 2.25 20.5986 2.25C20.3713 2.25 20.1491 2.31729 19.9599 2.44338L14.1459 6.31937C12.2638 7.57413 10.6859 9.20204 9.49724 11.0825M12.9175 14.5028C12.2396 12.9833 11.0167 11.7604 9.49724 11.0825",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "paper-airplane" shape.
+#[cfg(any(feature = "paper-airplane", heroicon = "paper-airplane"))]
+pub static PAPER_AIRPLANE: super::StaticShape = super::StaticShape::new(
+    "paper-airplane",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.99972 12.0005L3.2688 3.125C9.88393 5.04665 16.0276 8.07649 21.4855 12.0002C16.0276 15.924 9.884 18.9539 3.26889 20.8757L5.99972 12.0005ZM5.99972 12.0005L13.5 12.0005",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "paper-clip" shape.
+#[cfg(any(feature = "paper-clip", heroicon = "paper-clip"))]
+pub static PAPER_CLIP: super::StaticShape = super::StaticShape::new(
+    "paper-clip",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18.375 12.739L10.682 20.432C8.92462 22.1893 6.07538 22.1893 4.31802 20.432C2.56066 18.6746 2.56066 15.8254 4.31802 14.068L15.2573 3.12868C16.4289 1.95711 18.3283 1.95711 19.4999 3.12868C20.6715 4.30025 20.6715 6.19975 19.4999 7.37132L8.55158 18.3197M8.56066 18.3107C8.55764 18.3137 8.55462 18.3167 8.55158 18.3197M14.2498 8.37865L6.43934 16.1893C5.85355 16.7751 5.85355 17.7249 6.43934 18.3107C7.02211 18.8934 7.9651 18.8964 8.55158 18.3197",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "pause-circle" shape.
+#[cfg(any(feature = "pause-circle", heroicon = "pause-circle"))]
+pub static PAUSE_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "pause-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.25 9V15M9.75 15V9M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "pause" shape.
+#[cfg(any(feature = "pause", heroicon = "pause"))]
+pub static PAUSE: super::StaticShape = super::StaticShape::new(
+    "pause",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 5.25L15.75 18.75M8.25 5.25V18.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "pencil-square" shape.
+#[cfg(any(feature = "pencil-square", heroicon = "pencil-square"))]
+pub static PENCIL_SQUARE: super::StaticShape = super::StaticShape::new(
+    "pencil-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.8617 4.48667L18.5492 2.79917C19.2814 2.06694 20.4686 2.06694 21.2008 2.79917C21.9331 3.53141 21.9331 4.71859 21.2008 5.45083L10.5822 16.0695C10.0535 16.5981 9.40144 16.9868 8.68489 17.2002L6 18L6.79978 15.3151C7.01323 14.5986 7.40185 13.9465 7.93052 13.4178L16.8617 4.48667ZM16.8617 4.48667L19.5 7.12499M18 14V18.75C18 19.9926 16.9926 21 15.75 21H5.25C4.00736 21 3 19.9926 3 18.75V8.24999C3 7.00735 4.00736 5.99999 5.25 5.99999H10",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "pencil" shape.
+#[cfg(any(feature = "pencil", heroicon = "pencil"))]
+pub static PENCIL: super::StaticShape = super::StaticShape::new(
+    "pencil",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.8617 4.48667L18.5492 2.79917C19.2814 2.06694 20.4686 2.06694 21.2008 2.79917C21.9331 3.53141 21.9331 4.71859 21.2008 5.45083L6.83218 19.8195C6.30351 20.3481 5.65144 20.7368 4.93489 20.9502L2.25 21.75L3.04978 19.0651C3.26323 18.3486 3.65185 17.6965 4.18052 17.1678L16.8617 4.48667ZM16.8617 4.48667L19.5 7.12499",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "phone-arrow-down-left" shape.
+#[cfg(any(feature = "phone-arrow-down-left", heroicon = "phone-arrow-down-left"))]
+pub static PHONE_ARROW_DOWN_LEFT: super::StaticShape = super::StaticShape::new(
+    "phone-arrow-down-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.25 9.75V5.25M14.25 9.75L18.75 9.75M14.25 9.75L20.25 3.75M17.25 21.75C8.96573 21.75 2.25 15.0343 2.25 6.75V4.5C2.25 3.25736 3.25736 2.25 4.5 2.25H5.87163C6.38785 2.25 6.83783 2.60133 6.96304 3.10215L8.06883 7.52533C8.17861 7.96445 8.01453 8.4266 7.65242 8.69818L6.3588 9.6684C5.98336 9.94998 5.81734 10.437 5.97876 10.8777C7.19015 14.1846 9.81539 16.8098 13.1223 18.0212C13.563 18.1827 14.05 18.0166 14.3316 17.6412L15.3018 16.3476C15.5734 15.9855 16.0355 15.8214 16.4747 15.9312L20.8979 17.037C21.3987 17.1622 21.75 17.6121 21.75 18.1284V19.5C21.75 20.7426 20.7426 21.75 19.5 21.75H17.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "phone-arrow-up-right" shape.
+#[cfg(any(feature = "phone-arrow-up-right", heroicon = "phone-arrow-up-right"))]
+pub static PHONE_ARROW_UP_RIGHT: super::StaticShape = super::StaticShape::new(
+    "phone-arrow-up-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M20.25 3.75V8.25M20.25 3.75H15.75M20.25 3.75L14.25 9.75M17.25 21.75C8.96573 21.75 2.25 15.0343 2.25 6.75V4.5C2.25 3.25736 3.25736 2.25 4.5 2.25H5.87163C6.38785 2.25 6.83783 2.60133 6.96304 3.10215L8.06883 7.52533C8.17861 7.96445 8.01453 8.4266 7.65242 8.69818L6.3588 9.6684C5.98336 9.94998 5.81734 10.437 5.97876 10.8777C7.19015 14.1846 9.81539 16.8098 13.1223 18.0212C13.563 18.1827 14.05 18.0166 14.3316 17.6412L15.3018 16.3476C15.5734 15.9855 16.0355 15.8214 16.4747 15.9312L20.8979 17.037C21.3987 17.1622 21.75 17.6121 21.75 18.1284V19.5C21.75 20.7426 20.7426 21.75 19.5 21.75H17.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "phone-x-mark" shape.
+#[cfg(any(feature = "phone-x-mark", heroicon = "phone-x-mark"))]
+pub static PHONE_X_MARK: super::StaticShape = super::StaticShape::new(
+    "phone-x-mark",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 3.75L18 6M18 6L20.25 8.25M18 6L20.25 3.75M18 6L15.75 8.25M17.25 21.75C8.96573 21.75 2.25 15.0343 2.25 6.75V4.5C2.25 3.25736 3.25736 2.25 4.5 2.25H5.87163C6.38785 2.25 6.83783 2.60133 6.96304 3.10215L8.06883 7.52533C8.17861 7.96445 8.01453 8.4266 7.65242 8.69818L6.3588 9.6684C5.98336 9.94998 5.81734 10.437 5.97876 10.8777C7.19015 14.1846 9.81539 16.8098 13.1223 18.0212C13.563 18.1827 14.05 18.0166 14.3316 17.6412L15.3018 16.3476C15.5734 15.9855 16.0355 15.8214 16.4747 15.9312L20.8979 17.037C21.3987 17.1622 21.75 17.6121 21.75 18.1284V19.5C21.75 20.7426 20.7426 21.75 19.5 21.75H17.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "phone" shape.
+#[cfg(any(feature = "phone", heroicon = "phone"))]
+pub static PHONE: super::StaticShape = super::StaticShape::new(
+    "phone",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 6.75C2.25 15.0343 8.96573 21.75 17.25 21.75H19.5C20.7426 21.75 21.75 20.7426 21.75 19.5V18.1284C21.75 17.6121 21.3987 17.1622 20.8979 17.037L16.4747 15.9312C16.0355 15.8214 15.5734 15.9855 15.3018 16.3476L14.3316 17.6412C14.05 18.0166 13.563 18.1827 13.1223 18.0212C9.81539 16.8098 7.19015 14.1846 5.97876 10.8777C5.81734 10.437 5.98336 9.94998 6.3588 9.6684L7.65242 8.69818C8.01453 8.4266 8.17861 7.96445 8.06883 7.52533L6.96304 3.10215C6.83783 2.60133 6.38785 2.25 5.87163 2.25H4.5C3.25736 2.25 2.25 3.25736 2.25 4.5V6.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "photo" shape.
+#[cfg(any(feature = "photo", heroicon = "photo"))]
+pub static PHOTO: super::StaticShape = super::StaticShape::new(
+    "photo",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 15.75L7.40901 10.591C8.28769 9.71231 9.71231 9.71231 10.591 10.591L15.75 15.75M14.25 14.25L15.659 12.841C16.5377 11.9623 17.9623 11.9623 18.841 12.841L21.75 15.75M3.75 19.5H20.25C21.0784 19.5 21.75 18.8284 21.75 18V6C21.75 5.17157 21.0784 4.5 20.25 4.5H3.75C2.92157 4.5 2.25 5.17157 2.25 6V18C2.25 18.8284 2.92157 19.5 3.75 19.5ZM14.25 8.25H14.2575V8.2575H14.25V8.25ZM14.625 8.25C14.625 8.45711 14.4571 8.625 14.25 8.625C14.0429 8.625 13.875 8.45711 13.875 8.25C13.875 8.04289 14.0429 7.875 14.25 7.875C14.4571 7.875 14.625 8.04289 14.625 8.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "play-circle" shape.
+#[cfg(any(feature = "play-circle", heroicon = "play-circle"))]
+pub static PLAY_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "play-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z M15.9099 11.6722C16.1671 11.8151 16.1671 12.1849 15.9099 12.3278L10.3071 15.4405C10.0572 15.5794 9.75 15.3986 9.75 15.1127V8.88732C9.75 8.60139 10.0572 8.42065 10.3071 8.55951L15.9099 11.6722Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "play-pause" shape.
+#[cfg(any(feature = "play-pause", heroicon = "play-pause"))]
+pub static PLAY_PAUSE: super::StaticShape = super::StaticShape::new(
+    "play-pause",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 7.5L21 18M15 7.5V18M3 16.8114V8.68858C3 7.82478 3.93317 7.28324 4.68316 7.7118L11.7906 11.7732C12.5464 12.2051 12.5464 13.2949 11.7906 13.7268L4.68316 17.7882C3.93317 18.2168 3 17.6752 3 16.8114Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "play" shape.
+#[cfg(any(feature = "play", heroicon = "play"))]
+pub static PLAY: super::StaticShape = super::StaticShape::new(
+    "play",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.25 5.65297C5.25 4.79729 6.1674 4.25486 6.91716 4.66723L18.4577 11.0145C19.2349 11.442 19.2349 12.5586 18.4577 12.986L6.91716 19.3334C6.1674 19.7457 5.25 19.2033 5.25 18.3476V5.65297Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "plus-circle" shape.
+#[cfg(any(feature = "plus-circle", heroicon = "plus-circle"))]
+pub static PLUS_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "plus-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 9V15M15 12H9M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "plus-small" shape.
+#[cfg(any(feature = "plus-small", heroicon = "plus-small"))]
+pub static PLUS_SMALL: super::StaticShape = super::StaticShape::new(
+    "plus-small",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 6V18M18 12L6 12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "plus" shape.
+#[cfg(any(feature = "plus", heroicon = "plus"))]
+pub static PLUS: super::StaticShape = super::StaticShape::new(
+    "plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 4.5V19.5M19.5 12L4.5 12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "power" shape.
+#[cfg(any(feature = "power", heroicon = "power"))]
+pub static POWER: super::StaticShape = super::StaticShape::new(
+    "power",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.63604 5.63604C2.12132 9.15076 2.12132 14.8492 5.63604 18.364C9.15076 21.8787 14.8492 21.8787 18.364 18.364C21.8787 14.8492 21.8787 9.15076 18.364 5.63604M12 3V12",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "presentation-chart-bar" shape.
+#[cfg(any(feature = "presentation-chart-bar", heroicon = "presentation-chart-bar"))]
+pub static PRESENTATION_CHART_BAR: super::StaticShape = super::StaticShape::new(
+    "presentation-chart-bar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 3V14.25C3.75 15.4926 4.75736 16.5 6 16.5H8.25M3.75 3H2.25M3.75 3H20.25M20.25 3H21.75M20.25 3V14.25C20.25 15.4926 19.2426 16.5 18 16.5H15.75M8.25 16.5H15.75M8.25 16.5L7.25 19.5M15.75 16.5L16.75 19.5M16.75 19.5L17.25 21M16.75 19.5H7.25M7.25 19.5L6.75 21M9 11.25V12.75M12 9V12.75M15 6.75V12.75",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "presentation-chart-line" shape.
+#[cfg(any(feature = "presentation-chart-line", heroicon = "presentation-chart-line"))]
+pub static PRESENTATION_CHART_LINE: super::StaticShape = super::StaticShape::new(
+    "presentation-chart-line",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 3V14.25C3.75 15.4926 4.75736 16.5 6 16.5H8.25M3.75 3H2.25M3.75 3H20.25M20.25 3H21.75M20.25 3V14.25C20.25 15.4926 19.2426 16.5 18 16.5H15.75M8.25 16.5H15.75M8.25 16.5L7.25 19.5M15.75 16.5L16.75 19.5M16.75 19.5L17.25 21M16.75 19.5H7.25M7.25 19.5L6.75 21M7.5 12L10.5 9L12.6476 11.1476C13.6542 9.70301 14.9704 8.49023 16.5 7.60539",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "printer" shape.
+#[cfg(any(feature = "printer", heroicon = "printer"))]
+pub static PRINTER: super::StaticShape = super::StaticShape::new(
+    "printer",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.72012 13.8287C6.47944 13.8589 6.23939 13.8911 6 13.9253M6.72012 13.8287C8.44957 13.6118 10.2117 13.5 12 13.5C13.7883 13.5 15.5504 13.6118 17.2799 13.8287M6.72012 13.8287L6.34091 18M17.2799 13.8287C17.5206 13.8589 17.7606 13.8911 18 13.9253M17.2799 13.8287L17.6591 18M17.6591 18L17.8885 20.5231C17.9484 21.182 17.4296 21.75 16.7681 21.75H7.23191C6.57038 21.75 6.05164 21.182 6.11153 20.5231L6.34091 18M17.6591 18H18.75C19.9926 18 21 16.9926 21 15.75V9.45569C21 8.37475 20.2321 7.44082 19.1631 7.28086C18.5293 7.18604 17.8916 7.10361 17.25 7.03381M6.34091 18H5.25C4.00736 18 3 16.9926 3 15.75V9.45569C3 8.37475 3.7679 7.44082 4.83694 7.28086C5.47066 7.18604 6.10843 7.10361 6.75 7.03381M17.25 7.03381C15.5258 6.84625 13.7741 6.75 12 6.75C10.2259 6.75 8.47423 6.84625 6.75 7.03381M17.25 7.03381V3.375C17.25 2.75368 16.7463 2.25 16.125 2.25H7.875C7.25368 2.25 6.75 2.75368 6.75 3.375V7.03381M18 10.5H18.0075V10.5075H18V10.5ZM15 10.5H15.0075V10.5075H15V10.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "puzzle-piece" shape.
+#[cfg(any(feature = "puzzle-piece", heroicon = "puzzle-piece"))]
+pub static PUZZLE_PIECE: super::StaticShape = super::StaticShape::new(
+    "puzzle-piece",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.25 6.08694C14.25 5.73178 14.4361 5.41076 14.6512 5.1282C14.8721 4.8381 15 4.494 15 4.125C15 3.08947 13.9926 2.25 12.75 2.25C11.5074 2.25 10.5 3.08947 10.5 4.125C10.5 4.494 10.6279 4.8381 10.8488 5.1282C11.064 5.41076 11.25 5.73178 11.25 6.08694V6.08694C11.25 6.44822 10.9542 6.73997 10.593 6.72957C9.18939 6.68914 7.80084 6.58845 6.42989 6.43C6.61626 8.04276 6.72269 9.67987 6.74511 11.3373C6.75007 11.7032 6.45293 12 6.08694 12V12C5.73178 12 5.41076 11.814 5.1282 11.5988C4.8381 11.3779 4.494 11.25 4.125 11.25C3.08947 11.25 2.25 12.2574 2.25 13.5C2.25 14.7426 3.08947 15.75 4.125 15.75C4.494 15.75 4.8381 15.6221 5.1282 15.4012C5.41076 15.186 5.73178 15 6.08694 15V15C6.39613 15 6.64157 15.2608 6.6189 15.5691C6.49306 17.2812 6.27742 18.9682 5.97668 20.6256C7.49458 20.8157 9.03451 20.9348 10.5931 20.9797C10.9542 20.9901 11.2501 20.6983 11.2501 20.337V20.337C11.2501 19.9818 11.0641 19.6608 10.8489 19.3782C10.628 19.0881 10.5001 18.744 10.5001 18.375C10.5001 17.3395 11.5075 16.5 12.7501 16.5C13.9928 16.5 15.0001 17.3395 15.0001 18.375C15.0001 18.744 14.8722 19.0881 14.6513 19.3782C14.4362 19.6608 14.2501 19.9818 14.2501 20.337V20.337C14.2501 20.6699 14.5281 20.9357 14.8605 20.9161C16.6992 20.8081 18.5102 20.5965 20.2876 20.2872C20.5571 18.7389 20.7523 17.1652 20.8696 15.5698C20.8923 15.2611 20.6466 15 20.3371 15V15C19.9819 15 19.6609 15.1861 19.3783 15.4013C19.0882 15.6221 18.7441 15.75 18.3751 15.75C17.3396 15.75 16.5001 14.7427 16.5001 13.5C16.5001 12.2574 17.3396 11.25 18.3751 11.25C18.7441 11.25 19.0882 11.378 19.3783 11.5988C19.6609 11.814 19.9819 12 20.3371 12V12C20.7034 12 21.0008 11.703 20.9959 11.3367C20.9713 9.52413 20.8463 7.73572 20.6261 5.97698C18.7403 6.31916 16.816 6.55115 14.8603 6.66605C14.528 6.68557 14.25 6.41979 14.25 6.08694V6.08694Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
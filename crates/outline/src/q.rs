@@ -0,0 +1,37 @@
+//! The shapes whose names start with "q".
+/// The "qr-code" shape.
+#[cfg(any(feature = "qr-code", heroicon = "qr-code"))]
+pub static QR_CODE: super::StaticShape = super::StaticShape::new(
+    "qr-code",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 4.875C3.75 4.25368 4.25368 3.75 4.875 3.75H9.375C9.99632 3.75 10.5 4.25368 10.5 4.875V9.375C10.5 9.99632 9.99632 10.5 9.375 10.5H4.875C4.25368 10.5 3.75 9.99632 3.75 9.375V4.875Z M3.75 14.625C3.75 14.0037 4.25368 13.5 4.875 13.5H9.375C9.99632 13.5 10.5 14.0037 10.5 14.625V19.125C10.5 19.7463 9.99632 20.25 9.375 20.25H4.875C4.25368 20.25 3.75 19.7463 3.75 19.125V14.625Z M13.5 4.875C13.5 4.25368 14.0037 3.75 14.625 3.75H19.125C19.7463 3.75 20.25 4.25368 20.25 4.875V9.375C20.25 9.99632 19.7463 10.5 19.125 10.5H14.625C14.0037 10.5 13.5 9.99632 13.5 9.375V4.875Z M6.75 6.75H7.5V7.5H6.75V6.75Z M6.75 16.5H7.5V17.25H6.75V16.5Z M16.5 6.75H17.25V7.5H16.5V6.75Z M13.5 13.5H14.25V14.25H13.5V13.5Z M13.5 19.5H14.25V20.25H13.5V19.5Z M19.5 13.5H20.25V14.25H19.5V13.5Z M19.5 19.5H20.25V20.25H19.5V19.5Z M16.5 16.5H17.25V17.25H16.5V16.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "question-mark-circle" shape.
+#[cfg(any(feature = "question-mark-circle", heroicon = "question-mark-circle"))]
+pub static QUESTION_MARK_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "question-mark-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.87891 7.51884C11.0505 6.49372 12.95 6.49372 14.1215 7.51884C15.2931 8.54397 15.2931 10.206 14.1215 11.2312C13.9176 11.4096 13.6917 11.5569 13.4513 11.6733C12.7056 12.0341 12.0002 12.6716 12.0002 13.5V14.25M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12ZM12 17.25H12.0075V17.2575H12V17.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "queue-list" shape.
+#[cfg(any(feature = "queue-list", heroicon = "queue-list"))]
+pub static QUEUE_LIST: super::StaticShape = super::StaticShape::new(
+    "queue-list",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 12H20.25M3.75 15.75H20.25M3.75 19.5H20.25M5.625 4.5H18.375C19.4105 4.5 20.25 5.33947 20.25 6.375C20.25 7.41053 19.4105 8.25 18.375 8.25H5.625C4.58947 8.25 3.75 7.41053 3.75 6.375C3.75 5.33947 4.58947 4.5 5.625 4.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
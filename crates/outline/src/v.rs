@@ -0,0 +1,61 @@
+//! The shapes whose names start with "v".
+/// The "variable" shape.
+#[cfg(any(feature = "variable", heroicon = "variable"))]
+pub static VARIABLE: super::StaticShape = super::StaticShape::new(
+    "variable",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.74455 3C3.61952 5.77929 3 8.8173 3 12C3 15.1827 3.61952 18.2207 4.74455 21M19.5 3C20.4673 5.77929 21 8.8173 21 12C21 15.1827 20.4673 18.2207 19.5 21M8.25 8.88462L9.6945 7.99569C10.1061 7.74241 10.6463 7.93879 10.7991 8.39726L13.2009 15.6027C13.3537 16.0612 13.8939 16.2576 14.3055 16.0043L15.75 15.1154M7.5 15.8654L7.71335 15.9556C8.45981 16.2715 9.32536 16.012 9.77495 15.3376L14.225 8.66243C14.6746 7.98804 15.5402 7.72854 16.2867 8.04435L16.5 8.13462",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "video-camera-slash" shape.
+#[cfg(any(feature = "video-camera-slash", heroicon = "video-camera-slash"))]
+pub static VIDEO_CAMERA_SLASH: super::StaticShape = super::StaticShape::new(
+    "video-camera-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 10.5L20.4697 5.78033C20.9421 5.30786 21.75 5.64248 21.75 6.31066V17.6893C21.75 18.3575 20.9421 18.6921 20.4697 18.2197L15.75 13.5M12 18.75H4.5C3.25736 18.75 2.25 17.7426 2.25 16.5V9M15.091 18.091L16.5 19.5M15.091 18.091C15.4982 17.6838 15.75 17.1213 15.75 16.5V7.5C15.75 6.25736 14.7426 5.25 13.5 5.25H4.5C3.87868 5.25 3.31618 5.50184 2.90901 5.90901M15.091 18.091L2.90901 5.90901M1.5 4.5L2.90901 5.90901",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "video-camera" shape.
+#[cfg(any(feature = "video-camera", heroicon = "video-camera"))]
+pub static VIDEO_CAMERA: super::StaticShape = super::StaticShape::new(
+    "video-camera",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 10.5L20.4697 5.78033C20.9421 5.30786 21.75 5.64248 21.75 6.31066V17.6893C21.75 18.3575 20.9421 18.6921 20.4697 18.2197L15.75 13.5M4.5 18.75H13.5C14.7426 18.75 15.75 17.7426 15.75 16.5V7.5C15.75 6.25736 14.7426 5.25 13.5 5.25H4.5C3.25736 5.25 2.25 6.25736 2.25 7.5V16.5C2.25 17.7426 3.25736 18.75 4.5 18.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "view-columns" shape.
+#[cfg(any(feature = "view-columns", heroicon = "view-columns"))]
+pub static VIEW_COLUMNS: super::StaticShape = super::StaticShape::new(
+    "view-columns",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 4.5V19.5M15 4.5V19.5M4.125 19.5H19.875C20.4963 19.5 21 18.9963 21 18.375V5.625C21 5.00368 20.4963 4.5 19.875 4.5H4.125C3.50368 4.5 3 5.00368 3 5.625V18.375C3 18.9963 3.50368 19.5 4.125 19.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "viewfinder-circle" shape.
+#[cfg(any(feature = "viewfinder-circle", heroicon = "viewfinder-circle"))]
+pub static VIEWFINDER_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "viewfinder-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.5 3.75H6C4.75736 3.75 3.75 4.75736 3.75 6V7.5M16.5 3.75H18C19.2426 3.75 20.25 4.75736 20.25 6V7.5M20.25 16.5V18C20.25 19.2426 19.2426 20.25 18 20.25H16.5M7.5 20.25H6C4.75736 20.25 3.75 19.2426 3.75 18V16.5M15 12C15 13.6569 13.6569 15 12 15C10.3431 15 9 13.6569 9 12C9 10.3431 10.3431 9 12 9C13.6569 9 15 10.3431 15 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,61 @@
+//! The shapes whose names start with "w".
+/// The "wallet" shape.
+#[cfg(any(feature = "wallet", heroicon = "wallet"))]
+pub static WALLET: super::StaticShape = super::StaticShape::new(
+    "wallet",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 12C21 10.7574 19.9926 9.75 18.75 9.75H15C15 11.4069 13.6569 12.75 12 12.75C10.3431 12.75 9 11.4069 9 9.75H5.25C4.00736 9.75 3 10.7574 3 12M21 12V18C21 19.2426 19.9926 20.25 18.75 20.25H5.25C4.00736 20.25 3 19.2426 3 18V12M21 12V9M3 12V9M21 9C21 7.75736 19.9926 6.75 18.75 6.75H5.25C4.00736 6.75 3 7.75736 3 9M21 9V6C21 4.75736 19.9926 3.75 18.75 3.75H5.25C4.00736 3.75 3 4.75736 3 6V9",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "wifi" shape.
+#[cfg(any(feature = "wifi", heroicon = "wifi"))]
+pub static WIFI: super::StaticShape = super::StaticShape::new(
+    "wifi",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.28767 15.0378C10.3379 12.9875 13.662 12.9875 15.7123 15.0378M5.10569 11.8558C8.9133 8.04815 15.0867 8.04815 18.8943 11.8558M1.92371 8.67373C7.48868 3.10876 16.5113 3.10876 22.0762 8.67373M12.5303 18.2197L12 18.7501L11.4696 18.2197C11.7625 17.9268 12.2374 17.9268 12.5303 18.2197Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "window" shape.
+#[cfg(any(feature = "window", heroicon = "window"))]
+pub static WINDOW: super::StaticShape = super::StaticShape::new(
+    "window",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 8.25V18C3 19.2426 4.00736 20.25 5.25 20.25H18.75C19.9926 20.25 21 19.2426 21 18V8.25M3 8.25V6C3 4.75736 4.00736 3.75 5.25 3.75H18.75C19.9926 3.75 21 4.75736 21 6V8.25M3 8.25H21M5.25 6H5.2575V6.0075H5.25V6ZM7.5 6H7.5075V6.0075H7.5V6ZM9.75 6H9.7575V6.0075H9.75V6Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "wrench-screwdriver" shape.
+#[cfg(any(feature = "wrench-screwdriver", heroicon = "wrench-screwdriver"))]
+pub static WRENCH_SCREWDRIVER: super::StaticShape = super::StaticShape::new(
+    "wrench-screwdriver",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.4194 15.1694L17.25 21C18.2855 22.0355 19.9645 22.0355 21 21C22.0355 19.9645 22.0355 18.2855 21 17.25L15.1233 11.3733M11.4194 15.1694L13.9155 12.1383C14.2315 11.7546 14.6542 11.5132 15.1233 11.3733M11.4194 15.1694L6.76432 20.8219C6.28037 21.4096 5.55897 21.75 4.79768 21.75C3.39064 21.75 2.25 20.6094 2.25 19.2023C2.25 18.441 2.59044 17.7196 3.1781 17.2357L10.0146 11.6056M15.1233 11.3733C15.6727 11.2094 16.2858 11.1848 16.8659 11.2338C16.9925 11.2445 17.1206 11.25 17.25 11.25C19.7353 11.25 21.75 9.23528 21.75 6.75C21.75 6.08973 21.6078 5.46268 21.3523 4.89779L18.0762 8.17397C16.9605 7.91785 16.0823 7.03963 15.8262 5.92397L19.1024 2.64774C18.5375 2.39223 17.9103 2.25 17.25 2.25C14.7647 2.25 12.75 4.26472 12.75 6.75C12.75 6.87938 12.7555 7.00749 12.7662 7.13411C12.8571 8.20956 12.6948 9.39841 11.8617 10.0845L11.7596 10.1686M10.0146 11.6056L5.90901 7.5H4.5L2.25 3.75L3.75 2.25L7.5 4.5V5.90901L11.7596 10.1686M10.0146 11.6056L11.7596 10.1686M18.375 18.375L15.75 15.75M4.86723 19.125H4.87473V19.1325H4.86723V19.125Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "wrench" shape.
+#[cfg(any(feature = "wrench", heroicon = "wrench"))]
+pub static WRENCH: super::StaticShape = super::StaticShape::new(
+    "wrench",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21.75 6.75C21.75 9.23528 19.7353 11.25 17.25 11.25C17.1206 11.25 16.9925 11.2445 16.8659 11.2338C15.7904 11.1429 14.6016 11.3052 13.9155 12.1383L6.76432 20.8219C6.28037 21.4096 5.55897 21.75 4.79769 21.75C3.39064 21.75 2.25 20.6094 2.25 19.2023C2.25 18.441 2.59044 17.7196 3.1781 17.2357L11.8617 10.0845C12.6948 9.39841 12.8571 8.20956 12.7662 7.13411C12.7555 7.00749 12.75 6.87938 12.75 6.75C12.75 4.26472 14.7647 2.25 17.25 2.25C17.9103 2.25 18.5375 2.39223 19.1024 2.64774L15.8262 5.92397C16.0823 7.03963 16.9605 7.91785 18.0762 8.17397L21.3524 4.89779C21.6078 5.46268 21.75 6.08973 21.75 6.75Z M4.86723 19.125H4.87473V19.1325H4.86723V19.125Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
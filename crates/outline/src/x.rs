@@ -0,0 +1,25 @@
+//! The shapes whose names start with "x".
+/// The "x-circle" shape.
+#[cfg(any(feature = "x-circle", heroicon = "x-circle"))]
+pub static X_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "x-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.75 9.75L14.25 14.25M14.25 9.75L9.75 14.25M21 12C21 16.9706 16.9706 21 12 21C7.02944 21 3 16.9706 3 12C3 7.02944 7.02944 3 12 3C16.9706 3 21 7.02944 21 12Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "x-mark" shape.
+#[cfg(any(feature = "x-mark", heroicon = "x-mark"))]
+pub static X_MARK: super::StaticShape = super::StaticShape::new(
+    "x-mark",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6 18L18 6M6 6L18 18",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
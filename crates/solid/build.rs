@@ -0,0 +1,3 @@
+fn main() {
+    dioxus_heroicons_core::allow_list::configure("solid");
+}
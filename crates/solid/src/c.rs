@@ -0,0 +1,775 @@
+//! The shapes whose names start with "c".
+/// The "cake" shape.
+#[cfg(any(feature = "cake", heroicon = "cake"))]
+pub static CAKE: super::StaticShape = super::StaticShape::new(
+    "cake",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 1.78418L14.2045 2.57969C13.7652 3.01903 13.7652 3.73134 14.2045 4.17068C14.6438 4.61002 15.3562 4.61002 15.7955 4.17068C16.2348 3.73134 16.2348 3.01903 15.7955 2.57969L15 1.78418Z M12 1.78418L11.2045 2.57969C10.7652 3.01903 10.7652 3.73134 11.2045 4.17068C11.6438 4.61002 12.3562 4.61002 12.7955 4.17068C13.2348 3.73134 13.2348 3.01903 12.7955 2.57969L12 1.78418Z M8.99999 1.78418L8.2045 2.57969C7.76516 3.01903 7.76516 3.73134 8.2045 4.17068C8.64384 4.61002 9.35616 4.61002 9.7955 4.17068C10.2348 3.73134 10.2348 3.01903 9.7955 2.57969L8.99999 1.78418Z M9.75 7.54687C10.2483 7.52615 10.7483 7.51229 11.25 7.50536V6.75018C11.25 6.33597 11.5858 6.00018 12 6.00018C12.4142 6.00018 12.75 6.33597 12.75 6.75018V7.50536C13.2517 7.51229 13.7517 7.52615 14.25 7.54687V6.75018C14.25 6.33597 14.5858 6.00018 15 6.00018C15.4142 6.00018 15.75 6.33597 15.75 6.75018V7.63021C15.8524 7.63733 15.9547 7.64474 16.0569 7.65245C17.6071 7.76925 18.75 9.0795 18.75 10.5978V11.6164C16.5333 11.3744 14.2811 11.2502 12 11.2502C9.71886 11.2502 7.46673 11.3744 5.25 11.6164V10.5978C5.25 9.0795 6.39295 7.76925 7.94314 7.65245C8.04534 7.64474 8.14763 7.63733 8.25 7.63021V6.75018C8.25 6.33597 8.58579 6.00018 9 6.00018C9.41421 6.00018 9.75 6.33597 9.75 6.75018V7.54687Z M12 12.7502C9.52847 12.7502 7.09944 12.9342 4.72596 13.2893C3.27191 13.5069 2.25 14.7718 2.25 16.2059V16.5902C3.11853 16.4288 4.02704 16.5502 4.83541 16.9544C5.56854 17.3209 6.43146 17.3209 7.16459 16.9544C8.32001 16.3767 9.67999 16.3767 10.8354 16.9544C11.5685 17.3209 12.4315 17.3209 13.1646 16.9544C14.32 16.3767 15.68 16.3767 16.8354 16.9544C17.5685 17.3209 18.4315 17.3209 19.1646 16.9544C19.973 16.5502 20.8815 16.4288 21.75 16.5902V16.2059C21.75 14.7718 20.7281 13.5069 19.274 13.2893C16.9006 12.9342 14.4715 12.7502 12 12.7502Z M21.75 18.1314C21.1195 17.9418 20.4342 17.9966 19.8354 18.296C18.68 18.8737 17.32 18.8737 16.1646 18.296C15.4315 17.9294 14.5685 17.9294 13.8354 18.296C12.68 18.8737 11.32 18.8737 10.1646 18.296C9.43146 17.9294 8.56854 17.9294 7.83541 18.296C6.67999 18.8737 5.32001 18.8737 4.16459 18.296C3.56583 17.9966 2.88049 17.9418 2.25 18.1314V20.6252C2.25 21.6607 3.08947 22.5002 4.125 22.5002H19.875C20.9105 22.5002 21.75 21.6607 21.75 20.6252V18.1314Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "calculator" shape.
+#[cfg(any(feature = "calculator", heroicon = "calculator"))]
+pub static CALCULATOR: super::StaticShape = super::StaticShape::new(
+    "calculator",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.32022 1.82741C8.18374 1.61114 10.079 1.5 12 1.5C13.921 1.5 15.8163 1.61114 17.6798 1.82741C19.1772 2.00119 20.25 3.28722 20.25 4.75699V19.5C20.25 21.1569 18.9069 22.5 17.25 22.5H6.75C5.09315 22.5 3.75 21.1569 3.75 19.5V4.75699C3.75 3.28722 4.82283 2.00119 6.32022 1.82741ZM7.5 11.25C7.5 10.8358 7.83579 10.5 8.25 10.5H8.2575C8.67171 10.5 9.0075 10.8358 9.0075 11.25V11.2575C9.0075 11.6717 8.67171 12.0075 8.2575 12.0075H8.25C7.83579 12.0075 7.5 11.6717 7.5 11.2575V11.25ZM8.25 12.75C7.83579 12.75 7.5 13.0858 7.5 13.5V13.5075C7.5 13.9217 7.83579 14.2575 8.25 14.2575H8.2575C8.67171 14.2575 9.0075 13.9217 9.0075 13.5075V13.5C9.0075 13.0858 8.67171 12.75 8.2575 12.75H8.25ZM7.5 15.75C7.5 15.3358 7.83579 15 8.25 15H8.2575C8.67171 15 9.0075 15.3358 9.0075 15.75V15.7575C9.0075 16.1717 8.67171 16.5075 8.2575 16.5075H8.25C7.83579 16.5075 7.5 16.1717 7.5 15.7575V15.75ZM8.25 17.25C7.83579 17.25 7.5 17.5858 7.5 18V18.0075C7.5 18.4217 7.83579 18.7575 8.25 18.7575H8.2575C8.67171 18.7575 9.0075 18.4217 9.0075 18.0075V18C9.0075 17.5858 8.67171 17.25 8.2575 17.25H8.25ZM9.99756 11.25C9.99756 10.8358 10.3333 10.5 10.7476 10.5H10.7551C11.1693 10.5 11.5051 10.8358 11.5051 11.25V11.2575C11.5051 11.6717 11.1693 12.0075 10.7551 12.0075H10.7476C10.3333 12.0075 9.99756 11.6717 9.99756 11.2575V11.25ZM10.7476 12.75C10.3333 12.75 9.99756 13.0858 9.99756 13.5V13.5075C9.99756 13.9217 10.3333 14.2575 10.7476 14.2575H10.7551C11.1693 14.2575 11.5051 13.9217 11.5051 13.5075V13.5C11.5051 13.0858 11.1693 12.75 10.7551 12.75H10.7476ZM9.99756 15.75C9.99756 15.3358 10.3333 15 10.7476 15H10.7551C11.1693 15 11.5051 15.3358 11.5051 15.75V15.7575C11.5051 16.1717 11.1693 16.5075 10.7551 16.5075H10.7476C10.3333 16.5075 9.99756 16.1717 9.99756 15.7575V15.75ZM10.7476 17.25C10.3333 17.25 9.99756 17.5858 9.99756 18V18.0075C9.99756 18.4217 10.3333 18.7575 10.7476 18.7575H10.7551C11.1693 18.7575 11.5051 18.4217 11.5051 18.0075V18C11.5051 17.5858 11.1693 17.25 10.7551 17.25H10.7476ZM12.5024 11.25C12.5024 10.8358 12.8382 10.5 13.2524 10.5H13.2599C13.6742 10.5 14.0099 10.8358 14.0099 11.25V11.2575C14.0099 11.6717 13.6742 12.0075 13.2599 12.0075H13.2524C12.8382 12.0075 12.5024 11.6717 12.5024 11.2575V11.25ZM13.2524 12.75C12.8382 12.75 12.5024 13.0858 12.5024 13.5V13.5075C12.5024 13.9217 12.8382 14.2575 13.2524 14.2575H13.2599C13.6742 14.2575 14.0099 13.9217 14.0099 13.5075V13.5C14.0099 13.0858 13.6742 12.75 13.2599 12.75H13.2524ZM12.5024 15.75C12.5024 15.3358 12.8382 15 13.2524 15H13.2599C13.6742 15 14.0099 15.3358 14.0099 15.75V15.7575C14.0099 16.1717 13.6742 16.5075 13.2599 16.5075H13.2524C12.8382 16.5075 12.5024 16.1717 12.5024 15.7575V15.75ZM13.2524 17.25C12.8382 17.25 12.5024 17.5858 12.5024 18V18.0075C12.5024 18.4217 12.8382 18.7575 13.2524 18.7575H13.2599C13.6742 18.7575 14.0099 18.4217 14.0099 18.0075V18C14.0099 17.5858 13.6742 17.25 13.2599 17.25H13.2524ZM15 11.25C15 10.8358 15.3358 10.5 15.75 10.5H15.7575C16.1717 10.5 16.5075 10.8358 16.5075 11.25V11.2575C16.5075 11.6717 16.1717 12.0075 15.7575 12.0075H15.75C15.3358 12.0075 15 11.6717 15 11.2575V11.25ZM15.75 12.75C15.3358 12.75 15 13.0858 15 13.5V13.5075C15 13.9217 15.3358 14.2575 15.75 14.2575H15.7575C16.1717 14.2575 16.5075 13.9217 16.5075 13.5075V13.5C16.5075 13.0858 16.1717 12.75 15.7575 12.75H15.75ZM7.5 6.75C7.5 6.33579 7.83579 6 8.25 6H15.75C16.1642 6 16.5 6.33579 16.5 6.75V7.5C16.5 7.91421 16.1642 8.25 15.75 8.25H8.25C7.83579 8.25 7.5 7.91421 7.5 7.5V6.75ZM16.5 15.75C16.5 15.3358 16.1642 15 15.75 15C15.3358 15 15 15.3358 15 15.75V18C15 18.4142 15.3358 18.75 15.75 18.75C16.1642 18.75 16.5 18.4142 16.5 18V15.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "calendar-days" shape.
+#[cfg(any(feature = "calendar-days", heroicon = "calendar-days"))]
+pub static CALENDAR_DAYS: super::StaticShape = super::StaticShape::new(
+    "calendar-days",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.75 12.75C12.75 13.1642 12.4142 13.5 12 13.5C11.5858 13.5 11.25 13.1642 11.25 12.75C11.25 12.3358 11.5858 12 12 12C12.4142 12 12.75 12.3358 12.75 12.75Z M7.5 15.75C7.91421 15.75 8.25 15.4142 8.25 15C8.25 14.5858 7.91421 14.25 7.5 14.25C7.08579 14.25 6.75 14.5858 6.75 15C6.75 15.4142 7.08579 15.75 7.5 15.75Z M8.25 17.25C8.25 17.6642 7.91421 18 7.5 18C7.08579 18 6.75 17.6642 6.75 17.25C6.75 16.8358 7.08579 16.5 7.5 16.5C7.91421 16.5 8.25 16.8358 8.25 17.25Z M9.75 15.75C10.1642 15.75 10.5 15.4142 10.5 15C10.5 14.5858 10.1642 14.25 9.75 14.25C9.33579 14.25 9 14.5858 9 15C9 15.4142 9.33579 15.75 9.75 15.75Z M10.5 17.25C10.5 17.6642 10.1642 18 9.75 18C9.33579 18 9 17.6642 9 17.25C9 16.8358 9.33579 16.5 9.75 16.5C10.1642 16.5 10.5 16.8358 10.5 17.25Z M12 15.75C12.4142 15.75 12.75 15.4142 12.75 15C12.75 14.5858 12.4142 14.25 12 14.25C11.5858 14.25 11.25 14.5858 11.25 15C11.25 15.4142 11.5858 15.75 12 15.75Z M12.75 17.25C12.75 17.6642 12.4142 18 12 18C11.5858 18 11.25 17.6642 11.25 17.25C11.25 16.8358 11.5858 16.5 12 16.5C12.4142 16.5 12.75 16.8358 12.75 17.25Z M14.25 15.75C14.6642 15.75 15 15.4142 15 15C15 14.5858 14.6642 14.25 14.25 14.25C13.8358 14.25 13.5 14.5858 13.5 15C13.5 15.4142 13.8358 15.75 14.25 15.75Z M15 17.25C15 17.6642 14.6642 18 14.25 18C13.8358 18 13.5 17.6642 13.5 17.25C13.5 16.8358 13.8358 16.5 14.25 16.5C14.6642 16.5 15 16.8358 15 17.25Z M16.5 15.75C16.9142 15.75 17.25 15.4142 17.25 15C17.25 14.5858 16.9142 14.25 16.5 14.25C16.0858 14.25 15.75 14.5858 15.75 15C15.75 15.4142 16.0858 15.75 16.5 15.75Z M15 12.75C15 13.1642 14.6642 13.5 14.25 13.5C13.8358 13.5 13.5 13.1642 13.5 12.75C13.5 12.3358 13.8358 12 14.25 12C14.6642 12 15 12.3358 15 12.75Z M16.5 13.5C16.9142 13.5 17.25 13.1642 17.25 12.75C17.25 12.3358 16.9142 12 16.5 12C16.0858 12 15.75 12.3358 15.75 12.75C15.75 13.1642 16.0858 13.5 16.5 13.5Z M6.75 2.25C7.16421 2.25 7.5 2.58579 7.5 3V4.5H16.5V3C16.5 2.58579 16.8358 2.25 17.25 2.25C17.6642 2.25 18 2.58579 18 3V4.5H18.75C20.4069 4.5 21.75 5.84315 21.75 7.5V18.75C21.75 20.4069 20.4069 21.75 18.75 21.75H5.25C3.59315 21.75 2.25 20.4069 2.25 18.75V7.5C2.25 5.84315 3.59315 4.5 5.25 4.5H6V3C6 2.58579 6.33579 2.25 6.75 2.25ZM20.25 11.25C20.25 10.4216 19.5784 9.75 18.75 9.75H5.25C4.42157 9.75 3.75 10.4216 3.75 11.25V18.75C3.75 19.5784 4.42157 20.25 5.25 20.25H18.75C19.5784 20.25 20.25 19.5784 20.25 18.75V11.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "calendar" shape.
+#[cfg(any(feature = "calendar", heroicon = "calendar"))]
+pub static CALENDAR: super::StaticShape = super::StaticShape::new(
+    "calendar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6.75 2.25C7.16421 2.25 7.5 2.58579 7.5 3V4.5H16.5V3C16.5 2.58579 16.8358 2.25 17.25 2.25C17.6642 2.25 18 2.58579 18 3V4.5H18.75C20.4069 4.5 21.75 5.84315 21.75 7.5V18.75C21.75 20.4069 20.4069 21.75 18.75 21.75H5.25C3.59315 21.75 2.25 20.4069 2.25 18.75V7.5C2.25 5.84315 3.59315 4.5 5.25 4.5H6V3C6 2.58579 6.33579 2.25 6.75 2.25ZM20.25 11.25C20.25 10.4216 19.5784 9.75 18.75 9.75H5.25C4.42157 9.75 3.75 10.4216 3.75 11.25V18.75C3.75 19.5784 4.42157 20.25 5.25 20.25H18.75C19.5784 20.25 20.25 19.5784 20.25 18.75V11.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "camera" shape.
+#[cfg(any(feature = "camera", heroicon = "camera"))]
+pub static CAMERA: super::StaticShape = super::StaticShape::new(
+    "camera",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 9C9.92893 9 8.25 10.6789 8.25 12.75C8.25 14.8211 9.92893 16.5 12 16.5C14.0711 16.5 15.75 14.8211 15.75 12.75C15.75 10.6789 14.0711 9 12 9Z M9.34429 3.07113C10.2236 3.02391 11.109 3 12 3C12.891 3 13.7764 3.02391 14.6557 3.07113C15.6233 3.12309 16.4854 3.65612 16.9882 4.46184L17.8094 5.77786C18.0485 6.16099 18.4544 6.42131 18.9195 6.48741C19.3049 6.54218 19.6888 6.60145 20.0713 6.66518C21.5031 6.90378 22.5 8.15785 22.5 9.57403V18C22.5 19.6569 21.1569 21 19.5 21H4.5C2.84315 21 1.5 19.6569 1.5 18V9.57402C1.5 8.15784 2.49686 6.90377 3.92872 6.66517C4.31116 6.60144 4.6951 6.54217 5.08048 6.4874C5.54556 6.42131 5.95152 6.16099 6.19061 5.77785L7.01181 4.46184C7.5146 3.65612 8.37667 3.12309 9.34429 3.07113ZM6.75 12.75C6.75 9.8505 9.1005 7.5 12 7.5C14.8995 7.5 17.25 9.8505 17.25 12.75C17.25 15.6495 14.8995 18 12 18C9.1005 18 6.75 15.6495 6.75 12.75ZM18.75 11.25C19.1642 11.25 19.5 10.9142 19.5 10.5C19.5 10.0858 19.1642 9.75 18.75 9.75C18.3358 9.75 18 10.0858 18 10.5C18 10.9142 18.3358 11.25 18.75 11.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chart-bar-square" shape.
+#[cfg(any(feature = "chart-bar-square", heroicon = "chart-bar-square"))]
+pub static CHART_BAR_SQUARE: super::StaticShape = super::StaticShape::new(
+    "chart-bar-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 6C3 4.34315 4.34315 3 6 3H18C19.6569 3 21 4.34315 21 6V18C21 19.6569 19.6569 21 18 21H6C4.34315 21 3 19.6569 3 18V6ZM7.5 13.5C7.91421 13.5 8.25 13.8358 8.25 14.25V16.5C8.25 16.9142 7.91421 17.25 7.5 17.25C7.08579 17.25 6.75 16.9142 6.75 16.5V14.25C6.75 13.8358 7.08579 13.5 7.5 13.5ZM11.25 12C11.25 11.5858 10.9142 11.25 10.5 11.25C10.0858 11.25 9.75 11.5858 9.75 12V16.5C9.75 16.9142 10.0858 17.25 10.5 17.25C10.9142 17.25 11.25 16.9142 11.25 16.5V12ZM13.5 9C13.9142 9 14.25 9.33579 14.25 9.75V16.5C14.25 16.9142 13.9142 17.25 13.5 17.25C13.0858 17.25 12.75 16.9142 12.75 16.5V9.75C12.75 9.33579 13.0858 9 13.5 9ZM17.25 7.5C17.25 7.08579 16.9142 6.75 16.5 6.75C16.0858 6.75 15.75 7.08579 15.75 7.5V16.5C15.75 16.9142 16.0858 17.25 16.5 17.25C16.9142 17.25 17.25 16.9142 17.25 16.5V7.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chart-bar" shape.
+#[cfg(any(feature = "chart-bar", heroicon = "chart-bar"))]
+pub static CHART_BAR: super::StaticShape = super::StaticShape::new(
+    "chart-bar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M18.375 2.25C17.3395 2.25 16.5 3.08947 16.5 4.125V19.875C16.5 20.9105 17.3395 21.75 18.375 21.75H19.125C20.1605 21.75 21 20.9105 21 19.875V4.125C21 3.08947 20.1605 2.25 19.125 2.25H18.375Z M9.75 8.625C9.75 7.58947 10.5895 6.75 11.625 6.75H12.375C13.4105 6.75 14.25 7.58947 14.25 8.625V19.875C14.25 20.9105 13.4105 21.75 12.375 21.75H11.625C10.5895 21.75 9.75 20.9105 9.75 19.875V8.625Z M3 13.125C3 12.0895 3.83947 11.25 4.875 11.25H5.625C6.66053 11.25 7.5 12.0895 7.5 13.125V19.875C7.5 20.9105 6.66053 21.75 5.625 21.75H4.875C3.83947 21.75 3 20.9105 3 19.875V13.125Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chart-pie" shape.
+#[cfg(any(feature = "chart-pie", heroicon = "chart-pie"))]
+pub static CHART_PIE: super::StaticShape = super::StaticShape::new(
+    "chart-pie",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 13.5C2.25 8.94365 5.94365 5.25 10.5 5.25C10.9142 5.25 11.25 5.58579 11.25 6V12.75H18C18.4142 12.75 18.75 13.0858 18.75 13.5C18.75 18.0563 15.0563 21.75 10.5 21.75C5.94365 21.75 2.25 18.0563 2.25 13.5Z M12.75 3C12.75 2.58579 13.0858 2.25 13.5 2.25C18.0563 2.25 21.75 5.94365 21.75 10.5C21.75 10.9142 21.4142 11.25 21 11.25H13.5C13.0858 11.25 12.75 10.9142 12.75 10.5V3Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-bottom-center-text" shape.
+#[cfg(
+    any(
+        feature = "chat-bubble-bottom-center-text",
+        heroicon = "chat-bubble-bottom-center-text"
+    )
+)]
+pub static CHAT_BUBBLE_BOTTOM_CENTER_TEXT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-bottom-center-text",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.84836 2.771C7.18302 2.42773 9.57113 2.25 12.0003 2.25C14.4292 2.25 16.8171 2.4277 19.1516 2.77091C21.1299 3.06177 22.5 4.79445 22.5 6.74056V12.7595C22.5 14.7056 21.1299 16.4382 19.1516 16.7291C18.0054 16.8976 16.8464 17.0262 15.6759 17.1135C15.5514 17.1228 15.4431 17.1875 15.379 17.2835L12.624 21.416C12.4849 21.6247 12.2508 21.75 12 21.75C11.7492 21.75 11.5151 21.6247 11.376 21.416L8.62092 17.2835C8.5569 17.1874 8.44853 17.1227 8.32408 17.1134C7.15358 17.0261 5.99452 16.8975 4.84836 16.729C2.87004 16.4381 1.5 14.7054 1.5 12.7593V6.74064C1.5 4.79455 2.87004 3.06188 4.84836 2.771ZM6.75 8.25C6.75 7.83579 7.08579 7.5 7.5 7.5H16.5C16.9142 7.5 17.25 7.83579 17.25 8.25C17.25 8.66421 16.9142 9 16.5 9H7.5C7.08579 9 6.75 8.66421 6.75 8.25ZM7.5 10.5C7.08579 10.5 6.75 10.8358 6.75 11.25C6.75 11.6642 7.08579 12 7.5 12H12C12.4142 12 12.75 11.6642 12.75 11.25C12.75 10.8358 12.4142 10.5 12 10.5H7.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-bottom-center" shape.
+#[cfg(
+    any(feature = "chat-bubble-bottom-center", heroicon = "chat-bubble-bottom-center")
+)]
+pub static CHAT_BUBBLE_BOTTOM_CENTER: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-bottom-center",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.84836 2.771C7.18302 2.42773 9.57113 2.25 12.0003 2.25C14.4292 2.25 16.8171 2.4277 19.1516 2.77091C21.1299 3.06177 22.5 4.79445 22.5 6.74056V12.7595C22.5 14.7056 21.1299 16.4382 19.1516 16.7291C18.0054 16.8976 16.8464 17.0262 15.6759 17.1135C15.5514 17.1228 15.4431 17.1875 15.379 17.2835L12.624 21.416C12.4849 21.6247 12.2508 21.75 12 21.75C11.7492 21.75 11.5151 21.6247 11.376 21.416L8.62092 17.2835C8.5569 17.1874 8.44853 17.1227 8.32408 17.1134C7.15358 17.0261 5.99452 16.8975 4.84836 16.729C2.87004 16.4381 1.5 14.7054 1.5 12.7593V6.74064C1.5 4.79455 2.87004 3.06188 4.84836 2.771Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-left-ellipsis" shape.
+#[cfg(
+    any(feature = "chat-bubble-left-ellipsis", heroicon = "chat-bubble-left-ellipsis")
+)]
+pub static CHAT_BUBBLE_LEFT_ELLIPSIS: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-left-ellipsis",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.0003 2.25C9.57113 2.25 7.18302 2.42773 4.84836 2.771C2.87004 3.06188 1.5 4.79455 1.5 6.74064V12.7593C1.5 14.7054 2.87004 16.4381 4.84836 16.729C5.72491 16.8579 6.60901 16.9634 7.5 17.045V21C7.5 21.3033 7.68273 21.5768 7.96299 21.6929C8.24324 21.809 8.56583 21.7448 8.78033 21.5303L12.9638 17.3468C13.0318 17.2788 13.1266 17.2374 13.2302 17.2348C15.2361 17.1851 17.2123 17.0142 19.1516 16.7291C21.1299 16.4382 22.5 14.7056 22.5 12.7595V6.74056C22.5 4.79445 21.1299 3.06177 19.1516 2.77091C16.8171 2.4277 14.4292 2.25 12.0003 2.25ZM8.25 8.625C7.62868 8.625 7.125 9.12868 7.125 9.75C7.125 10.3713 7.62868 10.875 8.25 10.875C8.87132 10.875 9.375 10.3713 9.375 9.75C9.375 9.12868 8.87132 8.625 8.25 8.625ZM10.875 9.75C10.875 9.12868 11.3787 8.625 12 8.625C12.6213 8.625 13.125 9.12868 13.125 9.75C13.125 10.3713 12.6213 10.875 12 10.875C11.3787 10.875 10.875 10.3713 10.875 9.75ZM15.75 8.625C15.1287 8.625 14.625 9.12868 14.625 9.75C14.625 10.3713 15.1287 10.875 15.75 10.875C16.3713 10.875 16.875 10.3713 16.875 9.75C16.875 9.12868 16.3713 8.625 15.75 8.625Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-left-right" shape.
+#[cfg(any(feature = "chat-bubble-left-right", heroicon = "chat-bubble-left-right"))]
+pub static CHAT_BUBBLE_LEFT_RIGHT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-left-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.91307 2.65823C6.9877 2.38888 9.10296 2.25 11.2503 2.25C13.3974 2.25 15.5124 2.38885 17.5869 2.65815C19.5091 2.90769 20.8783 4.51937 20.9923 6.38495C20.6665 6.27614 20.3212 6.20396 19.96 6.17399C18.5715 6.05874 17.1673 6 15.75 6C14.3326 6 12.9285 6.05874 11.54 6.17398C9.1817 6.36971 7.5 8.36467 7.5 10.6082V14.8937C7.5 16.5844 8.45468 18.1326 9.9328 18.8779L7.28033 21.5303C7.06583 21.7448 6.74324 21.809 6.46299 21.6929C6.18273 21.5768 6 21.3033 6 21V16.9705C5.63649 16.9316 5.27417 16.8887 4.91308 16.8418C2.90466 16.581 1.5 14.8333 1.5 12.8626V6.63738C1.5 4.66672 2.90466 2.91899 4.91307 2.65823Z M15.75 7.5C14.3741 7.5 13.0114 7.55702 11.6641 7.66884C10.1248 7.7966 9 9.10282 9 10.6082V14.8937C9 16.4014 10.128 17.7083 11.6692 17.8341C12.9131 17.9357 14.17 17.9912 15.4384 17.999L18.2197 20.7803C18.4342 20.9948 18.7568 21.059 19.037 20.9429C19.3173 20.8268 19.5 20.5533 19.5 20.25V17.8601C19.6103 17.8518 19.7206 17.8432 19.8307 17.8342C21.372 17.7085 22.5 16.4015 22.5 14.8938V10.6082C22.5 9.10283 21.3752 7.79661 19.836 7.66885C18.4886 7.55702 17.1259 7.5 15.75 7.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-left" shape.
+#[cfg(any(feature = "chat-bubble-left", heroicon = "chat-bubble-left"))]
+pub static CHAT_BUBBLE_LEFT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.84836 2.771C7.18302 2.42773 9.57113 2.25 12.0003 2.25C14.4292 2.25 16.8171 2.4277 19.1516 2.77091C21.1299 3.06177 22.5 4.79445 22.5 6.74056V12.7595C22.5 14.7056 21.1299 16.4382 19.1516 16.7291C17.2123 17.0142 15.2361 17.1851 13.2302 17.2348C13.1266 17.2374 13.0318 17.2788 12.9638 17.3468L8.78033 21.5303C8.56583 21.7448 8.24324 21.809 7.96299 21.6929C7.68273 21.5768 7.5 21.3033 7.5 21V17.045C6.60901 16.9634 5.72491 16.8579 4.84836 16.729C2.87004 16.4381 1.5 14.7054 1.5 12.7593V6.74064C1.5 4.79455 2.87004 3.06188 4.84836 2.771Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-oval-left-ellipsis" shape.
+#[cfg(
+    any(
+        feature = "chat-bubble-oval-left-ellipsis",
+        heroicon = "chat-bubble-oval-left-ellipsis"
+    )
+)]
+pub static CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-oval-left-ellipsis",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.80365 21.6442C4.9793 21.6757 5.15732 21.7003 5.33691 21.7178C5.55516 21.7391 5.77647 21.75 6 21.75C7.3153 21.75 8.54447 21.3731 9.58317 20.7213C10.3569 20.9034 11.1668 21 12 21C17.322 21 21.75 17.0307 21.75 12C21.75 6.96934 17.322 3 12 3C6.67799 3 2.25 6.96934 2.25 12C2.25 14.4086 3.2746 16.5871 4.92371 18.1923C5.15571 18.4182 5.20107 18.6196 5.17822 18.7349C5.05254 19.3685 4.76687 19.9451 4.36357 20.4211C4.19016 20.6258 4.13927 20.9075 4.23008 21.1599C4.3209 21.4123 4.5396 21.597 4.80365 21.6442ZM8.25 10.875C7.62868 10.875 7.125 11.3787 7.125 12C7.125 12.6213 7.62868 13.125 8.25 13.125C8.87132 13.125 9.375 12.6213 9.375 12C9.375 11.3787 8.87132 10.875 8.25 10.875ZM10.875 12C10.875 11.3787 11.3787 10.875 12 10.875C12.6213 10.875 13.125 11.3787 13.125 12C13.125 12.6213 12.6213 13.125 12 13.125C11.3787 13.125 10.875 12.6213 10.875 12ZM15.75 10.875C15.1287 10.875 14.625 11.3787 14.625 12C14.625 12.6213 15.1287 13.125 15.75 13.125C16.3713 13.125 16.875 12.6213 16.875 12C16.875 11.3787 16.3713 10.875 15.75 10.875Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chat-bubble-oval-left" shape.
+#[cfg(any(feature = "chat-bubble-oval-left", heroicon = "chat-bubble-oval-left"))]
+pub static CHAT_BUBBLE_OVAL_LEFT: super::StaticShape = super::StaticShape::new(
+    "chat-bubble-oval-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.33691 21.7178C5.15732 21.7003 4.9793 21.6757 4.80365 21.6442C4.5396 21.597 4.3209 21.4123 4.23008 21.1599C4.13927 20.9075 4.19016 20.6258 4.36357 20.4211C4.76687 19.9451 5.05254 19.3685 5.17822 18.7349C5.20107 18.6196 5.15571 18.4182 4.92371 18.1923C3.2746 16.5871 2.25 14.4086 2.25 12C2.25 6.96934 6.67799 3 12 3C17.322 3 21.75 6.96934 21.75 12C21.75 17.0307 17.322 21 12 21C11.1668 21 10.3569 20.9034 9.58317 20.7213C8.54447 21.3731 7.3153 21.75 6 21.75C5.77647 21.75 5.55516 21.7391 5.33691 21.7178Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "check-badge" shape.
+#[cfg(any(feature = "check-badge", heroicon = "check-badge"))]
+pub static CHECK_BADGE: super::StaticShape = super::StaticShape::new(
+    "check-badge",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.60288 3.79876C9.42705 2.85093 10.6433 2.25 12 2.25C13.3566 2.25 14.5728 2.85087 15.397 3.79861C16.6501 3.71106 17.9352 4.14616 18.8946 5.10557C19.854 6.06498 20.2891 7.35009 20.2016 8.60319C21.1492 9.42735 21.75 10.6435 21.75 12C21.75 13.3568 21.149 14.5731 20.2011 15.3973C20.2884 16.6502 19.8533 17.935 18.8941 18.8943C17.9348 19.8535 16.65 20.2886 15.3971 20.2013C14.5729 21.1491 13.3567 21.75 12 21.75C10.6434 21.75 9.4272 21.1491 8.60304 20.2014C7.34992 20.289 6.0648 19.8539 5.10537 18.8945C4.14596 17.935 3.71086 16.6499 3.79841 15.3968C2.85079 14.5726 2.25 13.3565 2.25 12C2.25 10.6434 2.85085 9.42723 3.79855 8.60306C3.7111 7.35005 4.14621 6.06507 5.10554 5.10574C6.06488 4.1464 7.34987 3.71129 8.60288 3.79876ZM15.6103 10.1859C15.8511 9.84887 15.773 9.38046 15.4359 9.1397C15.0989 8.89894 14.6305 8.97701 14.3897 9.31407L11.1543 13.8436L9.53033 12.2197C9.23744 11.9268 8.76256 11.9268 8.46967 12.2197C8.17678 12.5126 8.17678 12.9874 8.46967 13.2803L10.7197 15.5303C10.8756 15.6862 11.0921 15.7656 11.3119 15.7474C11.5316 15.7293 11.7322 15.6153 11.8603 15.4359L15.6103 10.1859Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "check-circle" shape.
+#[cfg(any(feature = "check-circle", heroicon = "check-circle"))]
+pub static CHECK_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "check-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 12C2.25 6.61522 6.61522 2.25 12 2.25C17.3848 2.25 21.75 6.61522 21.75 12C21.75 17.3848 17.3848 21.75 12 21.75C6.61522 21.75 2.25 17.3848 2.25 12ZM15.6103 10.1859C15.8511 9.84887 15.773 9.38046 15.4359 9.1397C15.0989 8.89894 14.6305 8.97701 14.3897 9.31407L11.1543 13.8436L9.53033 12.2197C9.23744 11.9268 8.76256 11.9268 8.46967 12.2197C8.17678 12.5126 8.17678 12.9874 8.46967 13.2803L10.7197 15.5303C10.8756 15.6862 11.0921 15.7656 11.3119 15.7474C11.5316 15.7293 11.7322 15.6153 11.8603 15.4359L15.6103 10.1859Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "check" shape.
+#[cfg(any(feature = "check", heroicon = "check"))]
+pub static CHECK: super::StaticShape = super::StaticShape::new(
+    "check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.916 4.62604C20.2607 4.85581 20.3538 5.32146 20.124 5.6661L11.124 19.1661C10.9994 19.3531 10.7975 19.4743 10.5739 19.4964C10.3503 19.5186 10.1286 19.4393 9.96967 19.2804L3.96967 13.2804C3.67678 12.9875 3.67678 12.5126 3.96967 12.2197C4.26256 11.9269 4.73744 11.9269 5.03033 12.2197L10.3834 17.5729L18.876 4.83405C19.1057 4.48941 19.5714 4.39628 19.916 4.62604Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-down" shape.
+#[cfg(any(feature = "chevron-double-down", heroicon = "chevron-double-down"))]
+pub static CHEVRON_DOUBLE_DOWN: super::StaticShape = super::StaticShape::new(
+    "chevron-double-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M20.0303 4.71967C20.3232 5.01256 20.3232 5.48744 20.0303 5.78033L12.5303 13.2803C12.2374 13.5732 11.7626 13.5732 11.4697 13.2803L3.96967 5.78033C3.67678 5.48744 3.67678 5.01256 3.96967 4.71967C4.26256 4.42678 4.73744 4.42678 5.03033 4.71967L12 11.6893L18.9697 4.71967C19.2626 4.42678 19.7374 4.42678 20.0303 4.71967ZM20.0303 10.7197C20.3232 11.0126 20.3232 11.4874 20.0303 11.7803L12.5303 19.2803C12.2374 19.5732 11.7626 19.5732 11.4697 19.2803L3.96967 11.7803C3.67678 11.4874 3.67678 11.0126 3.96967 10.7197C4.26256 10.4268 4.73744 10.4268 5.03033 10.7197L12 17.6893L18.9697 10.7197C19.2626 10.4268 19.7374 10.4268 20.0303 10.7197Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-left" shape.
+#[cfg(any(feature = "chevron-double-left", heroicon = "chevron-double-left"))]
+pub static CHEVRON_DOUBLE_LEFT: super::StaticShape = super::StaticShape::new(
+    "chevron-double-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.2803 3.96967C13.5732 4.26256 13.5732 4.73744 13.2803 5.03033L6.31066 12L13.2803 18.9697C13.5732 19.2626 13.5732 19.7374 13.2803 20.0303C12.9874 20.3232 12.5126 20.3232 12.2197 20.0303L4.71967 12.5303C4.42678 12.2374 4.42678 11.7626 4.71967 11.4697L12.2197 3.96967C12.5126 3.67678 12.9874 3.67678 13.2803 3.96967ZM19.2803 3.96967C19.5732 4.26256 19.5732 4.73744 19.2803 5.03033L12.3107 12L19.2803 18.9697C19.5732 19.2626 19.5732 19.7374 19.2803 20.0303C18.9874 20.3232 18.5126 20.3232 18.2197 20.0303L10.7197 12.5303C10.4268 12.2374 10.4268 11.7626 10.7197 11.4697L18.2197 3.96967C18.5126 3.67678 18.9874 3.67678 19.2803 3.96967Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-right" shape.
+#[cfg(any(feature = "chevron-double-right", heroicon = "chevron-double-right"))]
+pub static CHEVRON_DOUBLE_RIGHT: super::StaticShape = super::StaticShape::new(
+    "chevron-double-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.71967 3.96967C5.01256 3.67678 5.48744 3.67678 5.78033 3.96967L13.2803 11.4697C13.5732 11.7626 13.5732 12.2374 13.2803 12.5303L5.78033 20.0303C5.48744 20.3232 5.01256 20.3232 4.71967 20.0303C4.42678 19.7374 4.42678 19.2626 4.71967 18.9697L11.6893 12L4.71967 5.03033C4.42678 4.73744 4.42678 4.26256 4.71967 3.96967ZM10.7197 3.96967C11.0126 3.67678 11.4874 3.67678 11.7803 3.96967L19.2803 11.4697C19.5732 11.7626 19.5732 12.2374 19.2803 12.5303L11.7803 20.0303C11.4874 20.3232 11.0126 20.3232 10.7197 20.0303C10.4268 19.7374 10.4268 19.2626 10.7197 18.9697L17.6893 12L10.7197 5.03033C10.4268 4.73744 10.4268 4.26256 10.7197 3.96967Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-double-up" shape.
+#[cfg(any(feature = "chevron-double-up", heroicon = "chevron-double-up"))]
+pub static CHEVRON_DOUBLE_UP: super::StaticShape = super::StaticShape::new(
+    "chevron-double-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.4697 4.71967C11.7626 4.42678 12.2374 4.42678 12.5303 4.71967L20.0303 12.2197C20.3232 12.5126 20.3232 12.9874 20.0303 13.2803C19.7374 13.5732 19.2626 13.5732 18.9697 13.2803L12 6.31066L5.03033 13.2803C4.73744 13.5732 4.26256 13.5732 3.96967 13.2803C3.67678 12.9874 3.67678 12.5126 3.96967 12.2197L11.4697 4.71967ZM12 12.3107L5.03033 19.2803C4.73744 19.5732 4.26256 19.5732 3.96967 19.2803C3.67678 18.9874 3.67678 18.5126 3.96967 18.2197L11.4697 10.7197C11.7626 10.4268 12.2374 10.4268 12.5303 10.7197L20.0303 18.2197C20.3232 18.5126 20.3232 18.9874 20.0303 19.2803C19.7374 19.5732 19.2626 19.5732 18.9697 19.2803L12 12.3107Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-down" shape.
+#[cfg(any(feature = "chevron-down", heroicon = "chevron-down"))]
+pub static CHEVRON_DOWN: super::StaticShape = super::StaticShape::new(
+    "chevron-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.5303 16.2803C12.2374 16.5732 11.7626 16.5732 11.4697 16.2803L3.96967 8.78033C3.67678 8.48744 3.67678 8.01256 3.96967 7.71967C4.26256 7.42678 4.73744 7.42678 5.03033 7.71967L12 14.6893L18.9697 7.71967C19.2626 7.42678 19.7374 7.42678 20.0303 7.71967C20.3232 8.01256 20.3232 8.48744 20.0303 8.78033L12.5303 16.2803Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-left" shape.
+#[cfg(any(feature = "chevron-left", heroicon = "chevron-left"))]
+pub static CHEVRON_LEFT: super::StaticShape = super::StaticShape::new(
+    "chevron-left",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.71967 12.5303C7.42678 12.2374 7.42678 11.7626 7.71967 11.4697L15.2197 3.96967C15.5126 3.67678 15.9874 3.67678 16.2803 3.96967C16.5732 4.26256 16.5732 4.73744 16.2803 5.03033L9.31066 12L16.2803 18.9697C16.5732 19.2626 16.5732 19.7374 16.2803 20.0303C15.9874 20.3232 15.5126 20.3232 15.2197 20.0303L7.71967 12.5303Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-right" shape.
+#[cfg(any(feature = "chevron-right", heroicon = "chevron-right"))]
+pub static CHEVRON_RIGHT: super::StaticShape = super::StaticShape::new(
+    "chevron-right",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.2803 11.4697C16.5732 11.7626 16.5732 12.2374 16.2803 12.5303L8.78033 20.0303C8.48744 20.3232 8.01256 20.3232 7.71967 20.0303C7.42678 19.7374 7.42678 19.2626 7.71967 18.9697L14.6893 12L7.71967 5.03033C7.42678 4.73744 7.42678 4.26256 7.71967 3.96967C8.01256 3.67678 8.48744 3.67678 8.78033 3.96967L16.2803 11.4697Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-up-down" shape.
+#[cfg(any(feature = "chevron-up-down", heroicon = "chevron-up-down"))]
+pub static CHEVRON_UP_DOWN: super::StaticShape = super::StaticShape::new(
+    "chevron-up-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.4697 4.71967C11.7626 4.42678 12.2374 4.42678 12.5303 4.71967L16.2803 8.46967C16.5732 8.76256 16.5732 9.23744 16.2803 9.53033C15.9874 9.82322 15.5126 9.82322 15.2197 9.53033L12 6.31066L8.78033 9.53033C8.48744 9.82322 8.01256 9.82322 7.71967 9.53033C7.42678 9.23744 7.42678 8.76256 7.71967 8.46967L11.4697 4.71967ZM7.71967 14.4697C8.01256 14.1768 8.48744 14.1768 8.78033 14.4697L12 17.6893L15.2197 14.4697C15.5126 14.1768 15.9874 14.1768 16.2803 14.4697C16.5732 14.7626 16.5732 15.2374 16.2803 15.5303L12.5303 19.2803C12.2374 19.5732 11.7626 19.5732 11.4697 19.2803L7.71967 15.5303C7.42678 15.2374 7.42678 14.7626 7.71967 14.4697Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "chevron-up" shape.
+#[cfg(any(feature = "chevron-up", heroicon = "chevron-up"))]
+pub static CHEVRON_UP: super::StaticShape = super::StaticShape::new(
+    "chevron-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.4697 7.71967C11.7626 7.42678 12.2374 7.42678 12.5303 7.71967L20.0303 15.2197C20.3232 15.5126 20.3232 15.9874 20.0303 16.2803C19.7374 16.5732 19.2626 16.5732 18.9697 16.2803L12 9.31066L5.03033 16.2803C4.73744 16.5732 4.26256 16.5732 3.96967 16.2803C3.67678 15.9874 3.67678 15.5126 3.96967 15.2197L11.4697 7.71967Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "circle-stack" shape.
+#[cfg(any(feature = "circle-stack", heroicon = "circle-stack"))]
+pub static CIRCLE_STACK: super::StaticShape = super::StaticShape::new(
+    "circle-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M21 6.375C21 9.06739 16.9706 11.25 12 11.25C7.02944 11.25 3 9.06739 3 6.375C3 3.68261 7.02944 1.5 12 1.5C16.9706 1.5 21 3.68261 21 6.375Z M12 12.75C14.6852 12.75 17.1905 12.1637 19.0784 11.1411C19.7684 10.7673 20.4248 10.3043 20.9747 9.75674C20.9915 9.87831 21 10.0011 21 10.125C21 12.8174 16.9706 15 12 15C7.02944 15 3 12.8174 3 10.125C3 10.0011 3.00853 9.8783 3.02529 9.75674C3.57523 10.3043 4.23162 10.7673 4.92161 11.1411C6.80949 12.1637 9.31481 12.75 12 12.75Z M12 16.5C14.6852 16.5 17.1905 15.9137 19.0784 14.8911C19.7684 14.5173 20.4248 14.0543 20.9747 13.5067C20.9915 13.6283 21 13.7511 21 13.875C21 16.5674 16.9706 18.75 12 18.75C7.02944 18.75 3 16.5674 3 13.875C3 13.7511 3.00853 13.6283 3.02529 13.5067C3.57523 14.0543 4.23162 14.5173 4.92161 14.8911C6.80949 15.9137 9.31481 16.5 12 16.5Z M12 20.25C14.6852 20.25 17.1905 19.6637 19.0784 18.6411C19.7684 18.2673 20.4248 17.8043 20.9747 17.2567C20.9915 17.3783 21 17.5011 21 17.625C21 20.3174 16.9706 22.5 12 22.5C7.02944 22.5 3 20.3174 3 17.625C3 17.5011 3.00853 17.3783 3.02529 17.2567C3.57523 17.8043 4.23162 18.2673 4.92161 18.6411C6.80949 19.6637 9.31481 20.25 12 20.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard-document-check" shape.
+#[cfg(any(feature = "clipboard-document-check", heroicon = "clipboard-document-check"))]
+pub static CLIPBOARD_DOCUMENT_CHECK: super::StaticShape = super::StaticShape::new(
+    "clipboard-document-check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.50183 6H11.2477L11.25 6H14.6299C16.4915 6.00268 17.9999 7.51269 17.9999 9.375V18.75C19.6567 18.75 20.9999 17.4068 20.9999 15.75V6.10821C20.9999 4.60282 19.8751 3.2966 18.3358 3.16884C18.1121 3.15027 17.8879 3.13321 17.6632 3.11767C17.1633 2.15647 16.1583 1.5 15 1.5H13.5C12.3417 1.5 11.3367 2.15647 10.8368 3.11765C10.6121 3.13319 10.3878 3.15026 10.1639 3.16884C8.66165 3.29353 7.55421 4.54069 7.50183 6ZM13.5 3C12.6716 3 12 3.67157 12 4.5H16.5C16.5 3.67157 15.8284 3 15 3H13.5Z M3 9.375C3 8.33947 3.83947 7.5 4.875 7.5H14.625C15.6605 7.5 16.5 8.33947 16.5 9.375V20.625C16.5 21.6605 15.6605 22.5 14.625 22.5H4.875C3.83947 22.5 3 21.6605 3 20.625V9.375ZM12.5857 13.9685C12.8444 13.6451 12.792 13.1731 12.4685 12.9143C12.1451 12.6556 11.6731 12.708 11.4143 13.0315L8.93781 16.1272L8.03033 15.2197C7.73744 14.9268 7.26256 14.9268 6.96967 15.2197C6.67678 15.5126 6.67678 15.9874 6.96967 16.2803L8.46967 17.7803C8.62052 17.9312 8.82847 18.0107 9.04148 17.9989C9.25448 17.9871 9.45238 17.8851 9.58565 17.7185L12.5857 13.9685Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard-document-list" shape.
+#[cfg(any(feature = "clipboard-document-list", heroicon = "clipboard-document-list"))]
+pub static CLIPBOARD_DOCUMENT_LIST: super::StaticShape = super::StaticShape::new(
+    "clipboard-document-list",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.50183 6H11.2477L11.25 6H14.6299C16.4915 6.00268 17.9999 7.51269 17.9999 9.375V18.75C19.6567 18.75 20.9999 17.4068 20.9999 15.75V6.10821C20.9999 4.60282 19.8751 3.2966 18.3358 3.16884C18.1121 3.15027 17.8879 3.13321 17.6632 3.11767C17.1633 2.15647 16.1583 1.5 15 1.5H13.5C12.3417 1.5 11.3367 2.15647 10.8368 3.11765C10.6121 3.13319 10.3878 3.15026 10.1639 3.16884C8.66165 3.29353 7.55421 4.54069 7.50183 6ZM13.5 3C12.6716 3 12 3.67157 12 4.5H16.5C16.5 3.67157 15.8284 3 15 3H13.5Z M3 9.375C3 8.33947 3.83947 7.5 4.875 7.5H14.625C15.6605 7.5 16.5 8.33947 16.5 9.375V20.625C16.5 21.6605 15.6605 22.5 14.625 22.5H4.875C3.83947 22.5 3 21.6605 3 20.625V9.375ZM6 12C6 11.5858 6.33579 11.25 6.75 11.25H6.7575C7.17171 11.25 7.5075 11.5858 7.5075 12V12.0075C7.5075 12.4217 7.17171 12.7575 6.7575 12.7575H6.75C6.33579 12.7575 6 12.4217 6 12.0075V12ZM8.25 12C8.25 11.5858 8.58579 11.25 9 11.25H12.75C13.1642 11.25 13.5 11.5858 13.5 12C13.5 12.4142 13.1642 12.75 12.75 12.75H9C8.58579 12.75 8.25 12.4142 8.25 12ZM6 15C6 14.5858 6.33579 14.25 6.75 14.25H6.7575C7.17171 14.25 7.5075 14.5858 7.5075 15V15.0075C7.5075 15.4217 7.17171 15.7575 6.7575 15.7575H6.75C6.33579 15.7575 6 15.4217 6 15.0075V15ZM8.25 15C8.25 14.5858 8.58579 14.25 9 14.25H12.75C13.1642 14.25 13.5 14.5858 13.5 15C13.5 15.4142 13.1642 15.75 12.75 15.75H9C8.58579 15.75 8.25 15.4142 8.25 15ZM6 18C6 17.5858 6.33579 17.25 6.75 17.25H6.7575C7.17171 17.25 7.5075 17.5858 7.5075 18V18.0075C7.5075 18.4217 7.17171 18.7575 6.7575 18.7575H6.75C6.33579 18.7575 6 18.4217 6 18.0075V18ZM8.25 18C8.25 17.5858 8.58579 17.25 9 17.25H12.75C13.1642 17.25 13.5 17.5858 13.5 18C13.5 18.4142 13.1642 18.75 12.75 18.75H9C8.58579 18.75 8.25 18.4142 8.25 18Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard-document" shape.
+#[cfg(any(feature = "clipboard-document", heroicon = "clipboard-document"))]
+pub static CLIPBOARD_DOCUMENT: super::StaticShape = super::StaticShape::new(
+    "clipboard-document",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.6633 3.11765C17.8879 3.13319 18.1122 3.15026 18.336 3.16883C19.8753 3.29659 21 4.60281 21 6.10821V15.75C21 17.4068 19.6569 18.75 18 18.75V16.5C18 10.5781 13.4244 5.72484 7.61572 5.28281C7.93989 4.15127 8.91565 3.27245 10.1641 3.16883C10.3879 3.15026 10.6121 3.13319 10.8368 3.11765C11.3367 2.15647 12.3417 1.5 13.5 1.5H15C16.1584 1.5 17.1634 2.15647 17.6633 3.11765ZM12 4.5C12 3.67157 12.6716 3 13.5 3H15C15.8285 3 16.5 3.67157 16.5 4.5H12Z M3 8.625C3 7.58947 3.83947 6.75 4.875 6.75H5.25C7.32107 6.75 9 8.42893 9 10.5V12.375C9 13.4105 9.83947 14.25 10.875 14.25H12.75C14.8211 14.25 16.5 15.9289 16.5 18V20.625C16.5 21.6605 15.6605 22.5 14.625 22.5H4.875C3.83947 22.5 3 21.6605 3 20.625V8.625Z M10.5 10.5C10.5 9.18695 10.018 7.98648 9.22119 7.0659C12.6201 7.95377 15.2962 10.6299 16.1841 14.0288C15.2635 13.232 14.0631 12.75 12.75 12.75H10.875C10.6679 12.75 10.5 12.5821 10.5 12.375V10.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clipboard" shape.
+#[cfg(any(feature = "clipboard", heroicon = "clipboard"))]
+pub static CLIPBOARD: super::StaticShape = super::StaticShape::new(
+    "clipboard",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 3C9.81411 3 9.23394 3.46099 9.0561 4.09149C9.01971 4.2205 9 4.35733 9 4.5H15C15 4.35733 14.9803 4.2205 14.9439 4.09149C14.7661 3.46099 14.1859 3 13.5 3H10.5ZM7.80654 3.17789C8.29511 2.18436 9.31692 1.5 10.5 1.5H13.5C14.6831 1.5 15.7049 2.18436 16.1935 3.17789C16.6911 3.22029 17.1865 3.27017 17.6798 3.32741C19.1772 3.50119 20.25 4.78722 20.25 6.25699V19.5C20.25 21.1569 18.9069 22.5 17.25 22.5H6.75C5.09315 22.5 3.75 21.1569 3.75 19.5V6.25699C3.75 4.78722 4.82283 3.50119 6.32022 3.32741C6.81347 3.27017 7.30894 3.22029 7.80654 3.17789Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "clock" shape.
+#[cfg(any(feature = "clock", heroicon = "clock"))]
+pub static CLOCK: super::StaticShape = super::StaticShape::new(
+    "clock",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM12.75 6C12.75 5.58579 12.4142 5.25 12 5.25C11.5858 5.25 11.25 5.58579 11.25 6V12C11.25 12.4142 11.5858 12.75 12 12.75H16.5C16.9142 12.75 17.25 12.4142 17.25 12C17.25 11.5858 16.9142 11.25 16.5 11.25H12.75V6Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cloud-arrow-down" shape.
+#[cfg(any(feature = "cloud-arrow-down", heroicon = "cloud-arrow-down"))]
+pub static CLOUD_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "cloud-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 3.75C7.18629 3.75 4.5 6.43629 4.5 9.75C4.5 9.91685 4.50683 10.0822 4.52024 10.2459C2.73627 11.084 1.5 12.8968 1.5 15C1.5 17.8995 3.85051 20.25 6.75 20.25H18C20.4853 20.25 22.5 18.2353 22.5 15.75C22.5 14.0653 21.5744 12.5981 20.2058 11.827C20.2349 11.6386 20.25 11.4459 20.25 11.25C20.25 9.17893 18.5711 7.5 16.5 7.5C16.3559 7.5 16.2135 7.50816 16.0733 7.52408C15.1893 5.31282 13.028 3.75 10.5 3.75ZM12.75 9.75C12.75 9.33579 12.4142 9 12 9C11.5858 9 11.25 9.33579 11.25 9.75V14.6893L9.53033 12.9697C9.23744 12.6768 8.76256 12.6768 8.46967 12.9697C8.17678 13.2626 8.17678 13.7374 8.46967 14.0303L11.4697 17.0303C11.7626 17.3232 12.2374 17.3232 12.5303 17.0303L15.5303 14.0303C15.8232 13.7374 15.8232 13.2626 15.5303 12.9697C15.2374 12.6768 14.7626 12.6768 14.4697 12.9697L12.75 14.6893V9.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cloud-arrow-up" shape.
+#[cfg(any(feature = "cloud-arrow-up", heroicon = "cloud-arrow-up"))]
+pub static CLOUD_ARROW_UP: super::StaticShape = super::StaticShape::new(
+    "cloud-arrow-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 3.75C7.18629 3.75 4.5 6.43629 4.5 9.75C4.5 9.91685 4.50683 10.0822 4.52024 10.2459C2.73627 11.084 1.5 12.8968 1.5 15C1.5 17.8995 3.85051 20.25 6.75 20.25H18C20.4853 20.25 22.5 18.2353 22.5 15.75C22.5 14.0653 21.5744 12.5981 20.2058 11.827C20.2349 11.6386 20.25 11.4459 20.25 11.25C20.25 9.17893 18.5711 7.5 16.5 7.5C16.3559 7.5 16.2135 7.50816 16.0733 7.52408C15.1893 5.31282 13.028 3.75 10.5 3.75ZM12.5303 9.21967C12.2374 8.92678 11.7626 8.92678 11.4697 9.21967L8.46967 12.2197C8.17678 12.5126 8.17678 12.9874 8.46967 13.2803C8.76256 13.5732 9.23744 13.5732 9.53033 13.2803L11.25 11.5607L11.25 16.5C11.25 16.9142 11.5858 17.25 12 17.25C12.4142 17.25 12.75 16.9142 12.75 16.5V11.5607L14.4697 13.2803C14.7626 13.5732 15.2374 13.5732 15.5303 13.2803C15.8232 12.9874 15.8232 12.5126 15.5303 12.2197L12.5303 9.21967Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cloud" shape.
+#[cfg(any(feature = "cloud", heroicon = "cloud"))]
+pub static CLOUD: super::StaticShape = super::StaticShape::new(
+    "cloud",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 9.75C4.5 6.43629 7.18629 3.75 10.5 3.75C13.028 3.75 15.1893 5.31282 16.0733 7.52408C16.2135 7.50816 16.3559 7.5 16.5 7.5C18.5711 7.5 20.25 9.17893 20.25 11.25C20.25 11.4459 20.2349 11.6386 20.2058 11.827C21.5744 12.5981 22.5 14.0653 22.5 15.75C22.5 18.2353 20.4853 20.25 18 20.25H6.75C3.85051 20.25 1.5 17.8995 1.5 15C1.5 12.8968 2.73627 11.084 4.52024 10.2459C4.50683 10.0822 4.5 9.91686 4.5 9.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "code-bracket-square" shape.
+#[cfg(any(feature = "code-bracket-square", heroicon = "code-bracket-square"))]
+pub static CODE_BRACKET_SQUARE: super::StaticShape = super::StaticShape::new(
+    "code-bracket-square",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 6C3 4.34315 4.34315 3 6 3H18C19.6569 3 21 4.34315 21 6V18C21 19.6569 19.6569 21 18 21H6C4.34315 21 3 19.6569 3 18V6ZM17.25 12C17.25 12.1989 17.171 12.3897 17.0303 12.5303L14.7803 14.7803C14.4874 15.0732 14.0126 15.0732 13.7197 14.7803C13.4268 14.4874 13.4268 14.0126 13.7197 13.7197L15.4393 12L13.7197 10.2803C13.4268 9.98744 13.4268 9.51256 13.7197 9.21967C14.0126 8.92678 14.4874 8.92678 14.7803 9.21967L17.0303 11.4697C17.171 11.6103 17.25 11.8011 17.25 12ZM6.96967 11.4697C6.82902 11.6103 6.75 11.8011 6.75 12C6.75 12.1989 6.82902 12.3897 6.96967 12.5303L9.21967 14.7803C9.51256 15.0732 9.98744 15.0732 10.2803 14.7803C10.5732 14.4874 10.5732 14.0126 10.2803 13.7197L8.56066 12L10.2803 10.2803C10.5732 9.98744 10.5732 9.51256 10.2803 9.21967C9.98744 8.92678 9.51256 8.92678 9.21967 9.21967L6.96967 11.4697Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "code-bracket" shape.
+#[cfg(any(feature = "code-bracket", heroicon = "code-bracket"))]
+pub static CODE_BRACKET: super::StaticShape = super::StaticShape::new(
+    "code-bracket",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M14.4473 3.02662C14.847 3.1356 15.0826 3.54791 14.9736 3.94753L10.4736 20.4475C10.3646 20.8471 9.95228 21.0827 9.55266 20.9738C9.15304 20.8648 8.91744 20.4525 9.02643 20.0529L13.5264 3.55285C13.6354 3.15323 14.0477 2.91763 14.4473 3.02662ZM16.7197 6.21986C17.0126 5.92696 17.4874 5.92696 17.7803 6.21986L23.0303 11.4699C23.3232 11.7628 23.3232 12.2376 23.0303 12.5305L17.7803 17.7805C17.4874 18.0734 17.0126 18.0734 16.7197 17.7805C16.4268 17.4876 16.4268 17.0128 16.7197 16.7199L21.4393 12.0002L16.7197 7.28052C16.4268 6.98762 16.4268 6.51275 16.7197 6.21986ZM7.28033 6.21986C7.57322 6.51275 7.57322 6.98763 7.28033 7.28052L2.56066 12.0002L7.28033 16.7199C7.57322 17.0128 7.57322 17.4876 7.28033 17.7805C6.98744 18.0734 6.51256 18.0734 6.21967 17.7805L0.96967 12.5305C0.676777 12.2376 0.676777 11.7628 0.96967 11.4699L6.21967 6.21986C6.51256 5.92697 6.98744 5.92697 7.28033 6.21986Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cog-6-tooth" shape.
+#[cfg(any(feature = "cog-6-tooth", heroicon = "cog-6-tooth"))]
+pub static COG_6_TOOTH: super::StaticShape = super::StaticShape::new(
+    "cog-6-tooth",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.0779 2.25C10.1613 2.25 9.37909 2.91265 9.22841 3.81675L9.04974 4.88873C9.02959 5.00964 8.93542 5.1498 8.75311 5.23747C8.40905 5.40292 8.07967 5.5938 7.7674 5.8076C7.60091 5.92159 7.43259 5.9332 7.31769 5.89015L6.29851 5.50833C5.44019 5.18678 4.4752 5.53289 4.01692 6.32666L3.09493 7.92358C2.63665 8.71736 2.8194 9.72611 3.52704 10.3087L4.36756 11.0006C4.46219 11.0785 4.53629 11.2298 4.52119 11.4307C4.50706 11.6188 4.49988 11.8086 4.49988 12C4.49988 12.1915 4.50707 12.3814 4.52121 12.5695C4.53632 12.7704 4.46221 12.9217 4.36758 12.9996L3.52704 13.6916C2.8194 14.2741 2.63665 15.2829 3.09493 16.0767L4.01692 17.6736C4.4752 18.4674 5.44019 18.8135 6.29851 18.4919L7.31791 18.11C7.43281 18.067 7.60113 18.0786 7.76761 18.1925C8.07982 18.4063 8.40913 18.5971 8.75311 18.7625C8.93542 18.8502 9.02959 18.9904 9.04974 19.1113L9.22841 20.1832C9.37909 21.0874 10.1613 21.75 11.0779 21.75H12.9219C13.8384 21.75 14.6207 21.0874 14.7713 20.1832L14.95 19.1113C14.9702 18.9904 15.0643 18.8502 15.2466 18.7625C15.5907 18.5971 15.9201 18.4062 16.2324 18.1924C16.3988 18.0784 16.5672 18.0668 16.6821 18.1098L17.7012 18.4917C18.5596 18.8132 19.5246 18.4671 19.9828 17.6733L20.9048 16.0764C21.3631 15.2826 21.1804 14.2739 20.4727 13.6913L19.6322 12.9994C19.5376 12.9215 19.4635 12.7702 19.4786 12.5693C19.4927 12.3812 19.4999 12.1914 19.4999 12C19.4999 11.8085 19.4927 11.6186 19.4785 11.4305C19.4634 11.2296 19.5375 11.0783 19.6322 11.0004L20.4727 10.3084C21.1804 9.72587 21.3631 8.71711 20.9048 7.92334L19.9828 6.32642C19.5246 5.53264 18.5596 5.18654 17.7012 5.50809L16.6818 5.89C16.5669 5.93304 16.3986 5.92144 16.2321 5.80746C15.9199 5.59371 15.5906 5.40289 15.2466 5.23747C15.0643 5.1498 14.9702 5.00964 14.95 4.88873L14.7713 3.81675C14.6207 2.91265 13.8384 2.25 12.9219 2.25H11.0779ZM12 15.75C14.0711 15.75 15.75 14.0711 15.75 12C15.75 9.92893 14.0711 8.25 12 8.25C9.92893 8.25 8.25 9.92893 8.25 12C8.25 14.0711 9.92893 15.75 12 15.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cog-8-tooth" shape.
+#[cfg(any(feature = "cog-8-tooth", heroicon = "cog-8-tooth"))]
+pub static COG_8_TOOTH: super::StaticShape = super::StaticShape::new(
+    "cog-8-tooth",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.828 2.25C10.9115 2.25 10.1292 2.91265 9.97854 3.81675L9.88699 4.36602C9.84017 4.64695 9.63707 4.87407 9.37035 4.97395C9.20811 5.0347 9.04853 5.1009 8.89184 5.17235C8.63259 5.29056 8.32822 5.27363 8.09636 5.10801L7.64299 4.78418C6.89714 4.25143 5.87546 4.33599 5.22734 4.9841L4.98413 5.22732C4.33601 5.87544 4.25145 6.89712 4.7842 7.64297L5.10803 8.09633C5.27364 8.32819 5.29057 8.63256 5.17236 8.89182C5.10091 9.04851 5.0347 9.20809 4.97395 9.37034C4.87408 9.63706 4.64695 9.84016 4.36602 9.88698L3.81675 9.97852C2.91265 10.1292 2.25 10.9114 2.25 11.828V12.172C2.25 13.0885 2.91265 13.8708 3.81675 14.0215L4.36602 14.113C4.64695 14.1598 4.87407 14.3629 4.97395 14.6297C5.03469 14.7919 5.1009 14.9515 5.17234 15.1082C5.29056 15.3674 5.27362 15.6718 5.10801 15.9036L4.78416 16.357C4.25141 17.1029 4.33597 18.1246 4.98408 18.7727L5.2273 19.0159C5.87541 19.664 6.8971 19.7486 7.64295 19.2158L8.09633 18.892C8.32819 18.7264 8.63256 18.7094 8.89182 18.8276C9.04851 18.8991 9.20809 18.9653 9.37034 19.026C9.63706 19.1259 9.84016 19.353 9.88698 19.634L9.97852 20.1832C10.1292 21.0873 10.9114 21.75 11.828 21.75H12.172C13.0885 21.75 13.8708 21.0874 14.0215 20.1832L14.113 19.634C14.1598 19.3531 14.3629 19.1259 14.6297 19.0261C14.7919 18.9653 14.9515 18.8991 15.1082 18.8277C15.3674 18.7094 15.6718 18.7264 15.9036 18.892L16.357 19.2158C17.1029 19.7486 18.1245 19.664 18.7727 19.0159L19.0159 18.7727C19.664 18.1246 19.7485 17.1029 19.2158 16.357L18.892 15.9037C18.7264 15.6718 18.7094 15.3674 18.8276 15.1082C18.8991 14.9515 18.9653 14.7919 19.026 14.6297C19.1259 14.3629 19.353 14.1598 19.634 14.113L20.1832 14.0215C21.0873 13.8708 21.75 13.0886 21.75 12.172V11.828C21.75 10.9115 21.0874 10.1292 20.1832 9.97854L19.634 9.88699C19.3531 9.84017 19.1259 9.63707 19.0261 9.37035C18.9653 9.20811 18.8991 9.04854 18.8277 8.89185C18.7094 8.63259 18.7264 8.32822 18.892 8.09636L19.2158 7.64297C19.7486 6.89712 19.664 5.87544 19.0159 5.22732L18.7727 4.9841C18.1246 4.33599 17.1029 4.25143 16.3571 4.78418L15.9037 5.10802C15.6718 5.27364 15.3674 5.29057 15.1082 5.17236C14.9515 5.10091 14.7919 5.0347 14.6297 4.97395C14.3629 4.87408 14.1598 4.64695 14.113 4.36602L14.0215 3.81675C13.8708 2.91265 13.0886 2.25 12.172 2.25H11.828ZM12 15.75C14.0711 15.75 15.75 14.0711 15.75 12C15.75 9.92893 14.0711 8.25 12 8.25C9.92893 8.25 8.25 9.92893 8.25 12C8.25 14.0711 9.92893 15.75 12 15.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cog" shape.
+#[cfg(any(feature = "cog", heroicon = "cog"))]
+pub static COG: super::StaticShape = super::StaticShape::new(
+    "cog",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.0039 10.4069C17.1423 10.8419 16.7882 11.2494 16.3317 11.2494H12.8667C12.5988 11.2494 12.3512 11.1064 12.2172 10.8744L10.4845 7.87349C10.2561 7.47789 10.4321 6.96735 10.8784 6.87021C11.2398 6.79157 11.6151 6.75015 12 6.75015C14.344 6.75015 16.3293 8.28635 17.0039 10.4069Z M8.11938 8.46409C8.42728 8.12639 8.95767 8.22903 9.18619 8.62478L10.9182 11.6244C11.0522 11.8564 11.0522 12.1424 10.9182 12.3744L9.18577 15.375C8.95726 15.7708 8.42679 15.8735 8.11891 15.5357C7.26853 14.6027 6.75 13.362 6.75 12.0001C6.75 10.6381 7.26872 9.39712 8.11938 8.46409Z M10.8778 17.1299C10.4315 17.0327 10.2555 16.5223 10.4839 16.1267L12.2172 13.1244C12.3512 12.8923 12.5988 12.7494 12.8668 12.7494H16.3322C16.7886 12.7494 17.1426 13.1567 17.0044 13.5917C16.3304 15.7131 14.3447 17.2501 12 17.2501C11.6148 17.2501 11.2393 17.2087 10.8778 17.1299Z M21.0007 12.7494C21.4149 12.7494 21.7507 12.4136 21.7507 11.9994C21.7507 11.5852 21.4149 11.2494 21.0007 11.2494H20.2171C20.1753 10.7852 20.095 10.3322 19.9795 9.89341L20.7143 9.62597C21.1035 9.4843 21.3042 9.05392 21.1625 8.66469C21.0209 8.27545 20.5905 8.07476 20.2012 8.21643L19.4664 8.4839C19.2695 8.06647 19.0386 7.66823 18.7773 7.29273L19.3771 6.78945C19.6944 6.5232 19.7358 6.05013 19.4695 5.73282C19.2033 5.41552 18.7302 5.37413 18.4129 5.64038L17.8126 6.14409C17.4869 5.82081 17.1344 5.52452 16.7588 5.25884L17.1502 4.58093C17.3573 4.22221 17.2344 3.76351 16.8756 3.55641C16.5169 3.3493 16.0582 3.47221 15.8511 3.83093L15.4604 4.50766C15.0465 4.31618 14.614 4.15801 14.1664 4.03656L14.3021 3.26708C14.374 2.85916 14.1016 2.47017 13.6937 2.39824C13.2858 2.32631 12.8968 2.59869 12.8249 3.00661L12.6889 3.77767C12.462 3.75894 12.2325 3.74938 12.0007 3.74938C11.769 3.74938 11.5394 3.75895 11.3124 3.77773L11.1764 3.00665C11.1045 2.59873 10.7155 2.32635 10.3076 2.39828C9.89966 2.47021 9.62728 2.8592 9.69921 3.26712L9.83492 4.0368C9.38749 4.15824 8.95506 4.31639 8.54113 4.50783L8.15018 3.83075C7.94306 3.47204 7.48436 3.34915 7.12565 3.55627C6.76694 3.76339 6.64405 4.22209 6.85117 4.5808L7.24273 5.25893C6.86722 5.52447 6.51483 5.82066 6.18921 6.14386L5.58845 5.63976C5.27114 5.37351 4.79807 5.4149 4.53182 5.7322C4.26557 6.04951 4.30696 6.52257 4.62426 6.78883L5.22448 7.29247C4.96309 7.66809 4.73209 8.06649 4.53509 8.48412L3.80006 8.21659C3.41082 8.07492 2.98044 8.27561 2.83877 8.66484C2.6971 9.05407 2.89779 9.48446 3.28703 9.62612L4.02196 9.89362C3.90647 10.3323 3.8262 10.7853 3.78437 11.2494H3.00073C2.58652 11.2494 2.25073 11.5852 2.25073 11.9994C2.25073 12.4136 2.58652 12.7494 3.00073 12.7494H3.78437C3.82619 13.2135 3.90644 13.6665 4.02192 14.1052L3.28678 14.3728C2.89755 14.5145 2.69685 14.9448 2.83852 15.3341C2.98019 15.7233 3.41058 15.924 3.79981 15.7823L4.53502 15.5147C4.73205 15.9324 4.9631 16.331 5.22461 16.7067L4.62411 17.2106C4.30681 17.4768 4.26542 17.9499 4.53167 18.2672C4.79792 18.5845 5.27099 18.6259 5.58829 18.3596L6.18941 17.8552C6.51507 18.1784 6.86754 18.4747 7.2432 18.7403L6.85112 19.4194C6.64401 19.7781 6.76692 20.2368 7.12564 20.4439C7.48436 20.651 7.94305 20.5281 8.15016 20.1694L8.54161 19.4914C8.9554 19.6827 9.38772 19.8408 9.83514 19.9622L9.6992 20.7332C9.62727 21.1411 9.89965 21.5301 10.3076 21.602C10.7155 21.6739 11.1045 21.4015 11.1764 20.9936L11.3126 20.2211C11.5395 20.2398 11.769 20.2494 12.0007 20.2494C12.2324 20.2494 12.4618 20.2398 12.6887 20.2211L12.8249 20.9937C12.8968 21.4016 13.2858 21.674 13.6937 21.602C14.1016 21.5301 14.374 21.1411 14.3021 20.7332L14.1662 19.9623C14.6136 19.8409 15.046 19.6828 15.4598 19.4914L15.8512 20.1692C16.0583 20.528 16.517 20.6509 16.8757 20.4438C17.2344 20.2366 17.3573 19.778 17.1502 19.4192L16.7582 18.7403C17.134 18.4746 17.4866 18.1783 17.8123 17.8549L18.4131 18.359C18.7304 18.6253 19.2034 18.5839 19.4697 18.2666C19.7359 17.9493 19.6946 17.4762 19.3772 17.2099L18.7771 16.7063C19.0385 16.3308 19.2694 15.9324 19.4664 15.5149L20.2015 15.7825C20.5907 15.9242 21.0211 15.7235 21.1628 15.3342C21.3044 14.945 21.1038 14.5146 20.7145 14.3729L19.9795 14.1054C20.095 13.6666 20.1753 13.2135 20.2171 12.7494H21.0007ZM18.3435 9.69075C18.3374 9.67412 18.332 9.65741 18.3272 9.64066C18.0593 8.92262 17.6728 8.26243 17.1913 7.68399C17.1846 7.67656 17.1779 7.66895 17.1714 7.66119C17.165 7.65358 17.1588 7.64588 17.1528 7.6381C16.6625 7.05954 16.0766 6.56465 15.4194 6.17787C15.4046 6.17061 15.3901 6.16281 15.3756 6.15448C15.3608 6.14593 15.3464 6.13695 15.3324 6.12756C14.6882 5.76124 13.9778 5.49788 13.2236 5.35987C13.2067 5.35805 13.1897 5.35566 13.1728 5.35266C13.1563 5.34976 13.1401 5.34635 13.1241 5.34244C12.7588 5.28123 12.3835 5.24938 12.0007 5.24938C11.6183 5.24938 11.2435 5.28112 10.8788 5.34208C10.8623 5.34616 10.8455 5.34971 10.8285 5.3527C10.8109 5.3558 10.7934 5.35826 10.7759 5.3601C10.005 5.5013 9.28044 5.77342 8.62548 6.15241C7.94757 6.54468 7.34445 7.05116 6.84231 7.64545C6.83827 7.65052 6.83415 7.65556 6.82995 7.66057C6.82783 7.6631 6.82568 7.66562 6.82353 7.66813C6.82127 7.67075 6.819 7.67335 6.81671 7.67593C6.33198 8.2565 5.94308 8.91961 5.67409 9.64102C5.66929 9.65771 5.66388 9.67434 5.65785 9.6909C5.65182 9.70747 5.64527 9.72369 5.63822 9.73956C5.38727 10.4462 5.25073 11.2069 5.25073 11.9994C5.25073 12.7937 5.38795 13.556 5.63997 14.2639C5.64626 14.2783 5.65215 14.293 5.6576 14.308C5.66305 14.323 5.66799 14.338 5.67243 14.3531C6.21998 15.8246 7.26613 17.0536 8.60555 17.8346C8.60934 17.8367 8.61311 17.8387 8.61688 17.8408C8.6198 17.8425 8.62272 17.8441 8.62564 17.8458C8.63265 17.8499 8.63958 17.854 8.64641 17.8583C9.3043 18.2357 10.0318 18.5056 10.8049 18.6438C10.8128 18.6449 10.8207 18.6462 10.8285 18.6476C10.8358 18.6489 10.8431 18.6503 10.8504 18.6517C11.2242 18.7159 11.6086 18.7494 12.0007 18.7494C12.3929 18.7494 12.7773 18.7159 13.1512 18.6517C13.1584 18.6503 13.1655 18.6489 13.1728 18.6476C13.1805 18.6462 13.1883 18.645 13.1961 18.6439C13.9675 18.506 14.6935 18.2371 15.3503 17.861C15.3586 17.8557 15.3671 17.8506 15.3757 17.8457C15.3839 17.8409 15.3922 17.8363 15.4006 17.8319C16.07 17.4409 16.666 16.9379 17.163 16.3486L17.1716 16.3382L17.1808 16.3274C17.6679 15.745 18.0587 15.0792 18.3285 14.3546C18.333 14.339 18.3381 14.3236 18.3437 14.3082C18.3493 14.2927 18.3554 14.2776 18.3619 14.2627C18.6137 13.5552 18.7507 12.7933 18.7507 11.9994C18.7507 11.2068 18.6141 10.4462 18.3632 9.73969C18.3561 9.72373 18.3495 9.70741 18.3435 9.69075Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "command-line" shape.
+#[cfg(any(feature = "command-line", heroicon = "command-line"))]
+pub static COMMAND_LINE: super::StaticShape = super::StaticShape::new(
+    "command-line",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 6C2.25 4.34315 3.59315 3 5.25 3H18.75C20.4069 3 21.75 4.34315 21.75 6V18C21.75 19.6569 20.4069 21 18.75 21H5.25C3.59315 21 2.25 19.6569 2.25 18V6ZM6.21967 6.96967C6.51256 6.67678 6.98744 6.67678 7.28033 6.96967L9.53033 9.21967C9.82322 9.51256 9.82322 9.98744 9.53033 10.2803L7.28033 12.5303C6.98744 12.8232 6.51256 12.8232 6.21967 12.5303C5.92678 12.2374 5.92678 11.7626 6.21967 11.4697L7.93934 9.75L6.21967 8.03033C5.92678 7.73744 5.92678 7.26256 6.21967 6.96967ZM10.5 11.25C10.0858 11.25 9.75 11.5858 9.75 12C9.75 12.4142 10.0858 12.75 10.5 12.75H13.5C13.9142 12.75 14.25 12.4142 14.25 12C14.25 11.5858 13.9142 11.25 13.5 11.25H10.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "computer-desktop" shape.
+#[cfg(any(feature = "computer-desktop", heroicon = "computer-desktop"))]
+pub static COMPUTER_DESKTOP: super::StaticShape = super::StaticShape::new(
+    "computer-desktop",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 5.25C2.25 3.59315 3.59315 2.25 5.25 2.25H18.75C20.4069 2.25 21.75 3.59315 21.75 5.25V15C21.75 16.6569 20.4069 18 18.75 18H15.75V18.2574C15.75 18.8541 15.9871 19.4264 16.409 19.8483L17.0303 20.4697C17.2448 20.6842 17.309 21.0068 17.1929 21.287C17.0768 21.5673 16.8033 21.75 16.5 21.75H7.5C7.19665 21.75 6.92318 21.5673 6.80709 21.287C6.691 21.0068 6.75517 20.6842 6.96967 20.4697L7.59099 19.8483C8.01295 19.4264 8.25 18.8541 8.25 18.2574V18H5.25C3.59315 18 2.25 16.6569 2.25 15V5.25ZM3.75 5.25V12.75C3.75 13.5784 4.42157 14.25 5.25 14.25H18.75C19.5784 14.25 20.25 13.5784 20.25 12.75V5.25C20.25 4.42157 19.5784 3.75 18.75 3.75H5.25C4.42157 3.75 3.75 4.42157 3.75 5.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cpu-chip" shape.
+#[cfg(any(feature = "cpu-chip", heroicon = "cpu-chip"))]
+pub static CPU_CHIP: super::StaticShape = super::StaticShape::new(
+    "cpu-chip",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.5 7.5H7.5V16.5H16.5V7.5Z M8.25 2.25C8.66421 2.25 9 2.58579 9 3V3.75H11.25V3C11.25 2.58579 11.5858 2.25 12 2.25C12.4142 2.25 12.75 2.58579 12.75 3V3.75H15V3C15 2.58579 15.3358 2.25 15.75 2.25C16.1642 2.25 16.5 2.58579 16.5 3V3.75H17.25C18.9069 3.75 20.25 5.09315 20.25 6.75V7.5H21C21.4142 7.5 21.75 7.83579 21.75 8.25C21.75 8.66421 21.4142 9 21 9H20.25V11.25H21C21.4142 11.25 21.75 11.5858 21.75 12C21.75 12.4142 21.4142 12.75 21 12.75H20.25V15H21C21.4142 15 21.75 15.3358 21.75 15.75C21.75 16.1642 21.4142 16.5 21 16.5H20.25V17.25C20.25 18.9069 18.9069 20.25 17.25 20.25H16.5V21C16.5 21.4142 16.1642 21.75 15.75 21.75C15.3358 21.75 15 21.4142 15 21V20.25H12.75V21C12.75 21.4142 12.4142 21.75 12 21.75C11.5858 21.75 11.25 21.4142 11.25 21V20.25H9V21C9 21.4142 8.66421 21.75 8.25 21.75C7.83579 21.75 7.5 21.4142 7.5 21V20.25H6.75C5.09315 20.25 3.75 18.9069 3.75 17.25V16.5H3C2.58579 16.5 2.25 16.1642 2.25 15.75C2.25 15.3358 2.58579 15 3 15H3.75V12.75H3C2.58579 12.75 2.25 12.4142 2.25 12C2.25 11.5858 2.58579 11.25 3 11.25H3.75V9H3C2.58579 9 2.25 8.66421 2.25 8.25C2.25 7.83579 2.58579 7.5 3 7.5H3.75V6.75C3.75 5.09315 5.09315 3.75 6.75 3.75H7.5V3C7.5 2.58579 7.83579 2.25 8.25 2.25ZM6 6.75C6 6.33579 6.33579 6 6.75 6H17.25C17.6642 6 18 6.33579 18 6.75V17.25C18 17.6642 17.6642 18 17.25 18H6.75C6.33579 18 6 17.6642 6 17.25V6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "credit-card" shape.
+#[cfg(any(feature = "credit-card", heroicon = "credit-card"))]
+pub static CREDIT_CARD: super::StaticShape = super::StaticShape::new(
+    "credit-card",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 3.75C2.84315 3.75 1.5 5.09315 1.5 6.75V7.5H22.5V6.75C22.5 5.09315 21.1569 3.75 19.5 3.75H4.5Z M22.5 9.75H1.5V17.25C1.5 18.9069 2.84315 20.25 4.5 20.25H19.5C21.1569 20.25 22.5 18.9069 22.5 17.25V9.75ZM4.5 13.5C4.5 13.0858 4.83579 12.75 5.25 12.75H11.25C11.6642 12.75 12 13.0858 12 13.5C12 13.9142 11.6642 14.25 11.25 14.25H5.25C4.83579 14.25 4.5 13.9142 4.5 13.5ZM5.25 15.75C4.83579 15.75 4.5 16.0858 4.5 16.5C4.5 16.9142 4.83579 17.25 5.25 17.25H8.25C8.66421 17.25 9 16.9142 9 16.5C9 16.0858 8.66421 15.75 8.25 15.75H5.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cube-transparent" shape.
+#[cfg(any(feature = "cube-transparent", heroicon = "cube-transparent"))]
+pub static CUBE_TRANSPARENT: super::StaticShape = super::StaticShape::new(
+    "cube-transparent",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.6221 1.60217C11.8556 1.46594 12.1444 1.46594 12.3779 1.60217L14.6279 2.91467C14.9857 3.12338 15.1065 3.58261 14.8978 3.9404C14.6891 4.29819 14.2299 4.41904 13.8721 4.21033L12 3.11828L10.1279 4.21033C9.77011 4.41904 9.31088 4.29819 9.10217 3.9404C8.89346 3.58261 9.01431 3.12338 9.3721 2.91467L11.6221 1.60217ZM5.89783 5.8096C6.10654 6.16739 5.98569 6.62662 5.6279 6.83533L4.48848 7.5L5.6279 8.16467C5.98569 8.37338 6.10654 8.83261 5.89783 9.1904C5.68912 9.54819 5.22989 9.66904 4.8721 9.46033L3.75 8.80578V9.75C3.75 10.1642 3.41421 10.5 3 10.5C2.58579 10.5 2.25 10.1642 2.25 9.75V7.5C2.25 7.23325 2.39168 6.98657 2.6221 6.85217L4.8721 5.53967C5.22989 5.33096 5.68912 5.45181 5.89783 5.8096ZM18.1022 5.8096C18.3109 5.45181 18.7701 5.33096 19.1279 5.53967L21.3779 6.85217C21.6083 6.98657 21.75 7.23325 21.75 7.5V9.75C21.75 10.1642 21.4142 10.5 21 10.5C20.5858 10.5 20.25 10.1642 20.25 9.75V8.80578L19.1279 9.46033C18.7701 9.66904 18.3109 9.54819 18.1022 9.1904C17.8935 8.83261 18.0143 8.37338 18.3721 8.16467L19.5115 7.5L18.3721 6.83533C18.0143 6.62662 17.8935 6.16739 18.1022 5.8096ZM9.10217 11.0596C9.31088 10.7018 9.77011 10.581 10.1279 10.7897L12 11.8817L13.8721 10.7897C14.2299 10.581 14.6891 10.7018 14.8978 11.0596C15.1065 11.4174 14.9857 11.8766 14.6279 12.0853L12.75 13.1808V15C12.75 15.4142 12.4142 15.75 12 15.75C11.5858 15.75 11.25 15.4142 11.25 15V13.1808L9.3721 12.0853C9.01431 11.8766 8.89346 11.4174 9.10217 11.0596ZM3 13.5C3.41421 13.5 3.75 13.8358 3.75 14.25V16.0692L5.6279 17.1647C5.98569 17.3734 6.10654 17.8326 5.89783 18.1904C5.68912 18.5482 5.22989 18.669 4.8721 18.4603L2.6221 17.1478C2.39168 17.0134 2.25 16.7668 2.25 16.5V14.25C2.25 13.8358 2.58579 13.5 3 13.5ZM21 13.5C21.4142 13.5 21.75 13.8358 21.75 14.25V16.5C21.75 16.7668 21.6083 17.0134 21.3779 17.1478L19.1279 18.4603C18.7701 18.669 18.3109 18.5482 18.1022 18.1904C17.8935 17.8326 18.0143 17.3734 18.3721 17.1647L20.25 16.0692V14.25C20.25 13.8358 20.5858 13.5 21 13.5ZM12 18.75C12.4142 18.75 12.75 19.0858 12.75 19.5V20.4442L13.8721 19.7897C14.2299 19.581 14.6891 19.7018 14.8978 20.0596C15.1065 20.4174 14.9857 20.8766 14.6279 21.0853L12.3779 22.3978C12.1444 22.5341 11.8556 22.5341 11.6221 22.3978L9.3721 21.0853C9.01431 20.8766 8.89346 20.4174 9.10217 20.0596C9.31088 19.7018 9.77011 19.581 10.1279 19.7897L11.25 20.4442V19.5C11.25 19.0858 11.5858 18.75 12 18.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cube" shape.
+#[cfg(any(feature = "cube", heroicon = "cube"))]
+pub static CUBE: super::StaticShape = super::StaticShape::new(
+    "cube",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.3779 1.60217C12.1444 1.46594 11.8556 1.46594 11.6221 1.60217L3 6.63172L12 11.8817L21 6.63172L12.3779 1.60217Z M21.75 7.93078L12.75 13.1808V22.1808L21.3779 17.1478C21.6083 17.0134 21.75 16.7668 21.75 16.5V7.93078Z M11.25 22.1808V13.1808L2.25 7.93078V16.5C2.25 16.7668 2.39168 17.0134 2.6221 17.1478L11.25 22.1808Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-bangladeshi" shape.
+#[cfg(any(feature = "currency-bangladeshi", heroicon = "currency-bangladeshi"))]
+pub static CURRENCY_BANGLADESHI: super::StaticShape = super::StaticShape::new(
+    "currency-bangladeshi",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75ZM10.5002 7.96345C10.5002 6.84838 9.3267 6.12314 8.32934 6.62181L7.91475 6.82911C7.54427 7.01435 7.3941 7.46485 7.57934 7.83534C7.76459 8.20582 8.21509 8.35599 8.58557 8.17075L9.00016 7.96345V9.74993H8.25016C7.83595 9.74993 7.50016 10.0857 7.50016 10.4999C7.50016 10.9141 7.83595 11.2499 8.25016 11.2499H9.00016V15.9382C9.00016 16.5014 9.25961 17.1356 9.8671 17.4631C10.5027 17.8058 11.2299 18 12.0002 18C14.1781 18 15.9932 16.4535 16.4102 14.3992C16.6086 13.4217 15.7739 12.75 14.9952 12.75H14.2502C13.836 12.75 13.5002 13.0857 13.5002 13.5C13.5002 13.9142 13.836 14.25 14.2502 14.25H14.9058C14.5731 15.544 13.3975 16.5 12.0002 16.5C11.4844 16.5 11.0012 16.3705 10.579 16.1428C10.5674 16.1366 10.5508 16.1239 10.5338 16.0916C10.5155 16.0567 10.5002 16.0036 10.5002 15.9382V11.2499H15.7502C16.1644 11.2499 16.5002 10.9141 16.5002 10.4999C16.5002 10.0857 16.1644 9.74993 15.7502 9.74993H10.5002V7.96345Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-dollar" shape.
+#[cfg(any(feature = "currency-dollar", heroicon = "currency-dollar"))]
+pub static CURRENCY_DOLLAR: super::StaticShape = super::StaticShape::new(
+    "currency-dollar",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.4636 8.74626C10.6908 8.56577 10.9607 8.43451 11.25 8.35249V11.1474C10.9552 11.0637 10.686 10.9304 10.4636 10.7537C10.0699 10.441 9.91752 10.073 9.91752 9.75C9.91752 9.42705 10.0699 9.05904 10.4636 8.74626Z M12.75 15.6616V12.8383C13.0972 12.9228 13.4138 13.0658 13.6713 13.259C14.0978 13.5788 14.25 13.9448 14.25 14.25C14.25 14.5551 14.0978 14.9211 13.6713 15.241C13.4138 15.4342 13.0972 15.5772 12.75 15.6616Z M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM12.75 6C12.75 5.58579 12.4142 5.25 12 5.25C11.5858 5.25 11.25 5.58579 11.25 6V6.81563C10.6231 6.92669 10.0253 7.17873 9.53058 7.57176C8.81822 8.13765 8.41752 8.9213 8.41752 9.75C8.41752 10.5787 8.81822 11.3623 9.53058 11.9282C10.033 12.3274 10.6327 12.575 11.25 12.6843V15.6616C10.9028 15.5771 10.5864 15.4341 10.3289 15.241L9.45001 14.5818C9.11865 14.3333 8.64854 14.4004 8.40001 14.7318C8.15147 15.0631 8.21862 15.5332 8.54999 15.7818L9.42886 16.441C9.96206 16.8409 10.5979 17.0856 11.25 17.1903V18C11.25 18.4142 11.5858 18.75 12 18.75C12.4142 18.75 12.75 18.4142 12.75 18V17.1904C13.4021 17.0856 14.0381 16.8409 14.5714 16.441C15.3164 15.8821 15.75 15.0965 15.75 14.25C15.75 13.4034 15.3164 12.6178 14.5714 12.059C14.0381 11.659 13.4021 11.4143 12.75 11.3096V8.35257C13.0392 8.4346 13.309 8.56583 13.5361 8.74626L13.951 9.07585C14.2753 9.3335 14.7471 9.27944 15.0048 8.95511C15.2624 8.63078 15.2084 8.15899 14.884 7.90135L14.4691 7.57176C13.9745 7.17879 13.3768 6.92677 12.75 6.81567V6Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-euro" shape.
+#[cfg(any(feature = "currency-euro", heroicon = "currency-euro"))]
+pub static CURRENCY_EURO: super::StaticShape = super::StaticShape::new(
+    "currency-euro",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM10.0983 9.34835C11.1527 8.29405 12.6796 7.99768 14.0006 8.46355C14.3912 8.60132 14.8195 8.39633 14.9573 8.0057C15.0951 7.61507 14.8901 7.18671 14.4994 7.04895C12.6545 6.39828 10.5156 6.80976 9.03769 8.28769C8.60004 8.72534 8.25581 9.22104 8.005 9.75H7.5C7.08579 9.75 6.75 10.0858 6.75 10.5C6.75 10.9142 7.08579 11.25 7.5 11.25H7.55353C7.48216 11.7472 7.48216 12.2528 7.55353 12.75H7.5C7.08579 12.75 6.75 13.0858 6.75 13.5C6.75 13.9142 7.08579 14.25 7.5 14.25H8.005C8.25581 14.779 8.60004 15.2747 9.03769 15.7123C10.5156 17.1902 12.6545 17.6017 14.4994 16.9511C14.8901 16.8133 15.0951 16.3849 14.9573 15.9943C14.8195 15.6037 14.3912 15.3987 14.0006 15.5364C12.6796 16.0023 11.1527 15.706 10.0983 14.6517C9.97095 14.5243 9.85464 14.39 9.74941 14.25H12.75C13.1642 14.25 13.5 13.9142 13.5 13.5C13.5 13.0858 13.1642 12.75 12.75 12.75H9.07535C8.97488 12.2554 8.97488 11.7446 9.07535 11.25H12.75C13.1642 11.25 13.5 10.9142 13.5 10.5C13.5 10.0858 13.1642 9.75 12.75 9.75H9.74941C9.85464 9.61003 9.97095 9.47575 10.0983 9.34835Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-pound" shape.
+#[cfg(any(feature = "currency-pound", heroicon = "currency-pound"))]
+pub static CURRENCY_POUND: super::StaticShape = super::StaticShape::new(
+    "currency-pound",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM9.76273 9.51002C9.81529 9.01621 10.0302 8.53785 10.409 8.15901C11.2877 7.28033 12.7123 7.28033 13.591 8.15901C13.8839 8.4519 14.3588 8.4519 14.6517 8.15901C14.9445 7.86612 14.9445 7.39124 14.6517 7.09835C13.1872 5.63388 10.8128 5.63388 9.34835 7.09835C8.7183 7.7284 8.3587 8.52881 8.27116 9.35126C8.23849 9.6582 8.27044 9.94628 8.30277 10.1726L8.45668 11.25H8.25C7.83579 11.25 7.5 11.5858 7.5 12C7.5 12.4142 7.83579 12.75 8.25 12.75H8.67097L8.80874 13.7144C8.91661 14.4695 8.79165 15.2396 8.45053 15.9218L8.32918 16.1645C8.19352 16.4358 8.23466 16.7621 8.43341 16.9913C8.63216 17.2205 8.94939 17.3074 9.23717 17.2114L10.7757 16.6986C11.0836 16.596 11.4164 16.596 11.7243 16.6986L12.3787 16.9167C13.1335 17.1683 13.9573 17.1098 14.669 16.7539L15.3354 16.4207C15.7059 16.2355 15.8561 15.785 15.6708 15.4145C15.4856 15.044 15.0351 14.8939 14.6646 15.0791L13.9982 15.4123C13.6424 15.5902 13.2304 15.6195 12.853 15.4937L12.1987 15.2756C11.5829 15.0703 10.9171 15.0703 10.3013 15.2756L10.2401 15.296C10.3601 14.7089 10.3794 14.1022 10.2937 13.5023L10.1862 12.75H12C12.4142 12.75 12.75 12.4142 12.75 12C12.75 11.5858 12.4142 11.25 12 11.25H9.97191L9.7877 9.9605C9.7598 9.76518 9.75032 9.62664 9.76273 9.51002Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-rupee" shape.
+#[cfg(any(feature = "currency-rupee", heroicon = "currency-rupee"))]
+pub static CURRENCY_RUPEE: super::StaticShape = super::StaticShape::new(
+    "currency-rupee",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM9 7.5C8.58579 7.5 8.25 7.83579 8.25 8.25C8.25 8.66421 8.58579 9 9 9H10.5C11.4797 9 12.3131 9.62611 12.622 10.5H9C8.58579 10.5 8.25 10.8358 8.25 11.25C8.25 11.6642 8.58579 12 9 12H12.622C12.3131 12.8739 11.4797 13.5 10.5 13.5H9C8.69665 13.5 8.42318 13.6827 8.30709 13.963C8.191 14.2432 8.25517 14.5658 8.46967 14.7803L11.4697 17.7803C11.7626 18.0732 12.2374 18.0732 12.5303 17.7803C12.8232 17.4874 12.8232 17.0126 12.5303 16.7197L10.7989 14.9883C12.4785 14.8558 13.8468 13.6168 14.175 12H15C15.4142 12 15.75 11.6642 15.75 11.25C15.75 10.8358 15.4142 10.5 15 10.5H14.175C14.0625 9.94584 13.8278 9.43606 13.5003 9H15C15.4142 9 15.75 8.66421 15.75 8.25C15.75 7.83579 15.4142 7.5 15 7.5H9Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "currency-yen" shape.
+#[cfg(any(feature = "currency-yen", heroicon = "currency-yen"))]
+pub static CURRENCY_YEN: super::StaticShape = super::StaticShape::new(
+    "currency-yen",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM9.62404 7.08397C9.39427 6.73933 8.92862 6.6462 8.58398 6.87596C8.23933 7.10573 8.1462 7.57138 8.37596 7.91603L10.5986 11.25H9C8.58579 11.25 8.25 11.5858 8.25 12C8.25 12.4142 8.58579 12.75 9 12.75H11.25V14.25H9C8.58579 14.25 8.25 14.5858 8.25 15C8.25 15.4142 8.58579 15.75 9 15.75H11.25V17.25C11.25 17.6642 11.5858 18 12 18C12.4142 18 12.75 17.6642 12.75 17.25V15.75H15C15.4142 15.75 15.75 15.4142 15.75 15C15.75 14.5858 15.4142 14.25 15 14.25H12.75V12.75H15C15.4142 12.75 15.75 12.4142 15.75 12C15.75 11.5858 15.4142 11.25 15 11.25H13.4014L15.624 7.91603C15.8538 7.57138 15.7607 7.10573 15.416 6.87596C15.0714 6.6462 14.6057 6.73933 14.376 7.08397L12 10.6479L9.62404 7.08397Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cursor-arrow-rays" shape.
+#[cfg(any(feature = "cursor-arrow-rays", heroicon = "cursor-arrow-rays"))]
+pub static CURSOR_ARROW_RAYS: super::StaticShape = super::StaticShape::new(
+    "cursor-arrow-rays",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 1.5C12.4142 1.5 12.75 1.83579 12.75 2.25V4.5C12.75 4.91421 12.4142 5.25 12 5.25C11.5858 5.25 11.25 4.91421 11.25 4.5V2.25C11.25 1.83579 11.5858 1.5 12 1.5ZM5.63604 4.13604C5.92893 3.84315 6.40381 3.84315 6.6967 4.13604L8.28769 5.72703C8.58058 6.01992 8.58058 6.4948 8.28769 6.78769C7.9948 7.08058 7.51992 7.08058 7.22703 6.78769L5.63604 5.1967C5.34315 4.90381 5.34315 4.42893 5.63604 4.13604ZM18.364 4.13604C18.6569 4.42893 18.6569 4.90381 18.364 5.1967L16.773 6.78769C16.4801 7.08058 16.0052 7.08058 15.7123 6.78769C15.4194 6.4948 15.4194 6.01992 15.7123 5.72703L17.3033 4.13604C17.5962 3.84315 18.0711 3.84315 18.364 4.13604ZM11.5484 8.63179C11.8602 8.54824 12.1905 8.67359 12.3684 8.94299L17.5955 16.8599C17.7627 17.113 17.7609 17.4419 17.591 17.6932C17.421 17.9445 17.1165 18.0687 16.8193 18.0079L14.722 17.5787L15.7668 21.4777C15.874 21.8778 15.6365 22.289 15.2364 22.3963C14.8363 22.5035 14.4251 22.266 14.3179 21.8659L13.2732 17.967L11.6717 19.3872C11.4447 19.5884 11.1189 19.6332 10.8461 19.5005C10.5733 19.3678 10.4073 19.0839 10.4254 18.7811L10.9939 9.3113C11.0132 8.98905 11.2366 8.71534 11.5484 8.63179ZM3 10.5C3 10.0858 3.33579 9.75 3.75 9.75H6C6.41421 9.75 6.75 10.0858 6.75 10.5C6.75 10.9142 6.41421 11.25 6 11.25H3.75C3.33579 11.25 3 10.9142 3 10.5ZM17.25 10.5C17.25 10.0858 17.5858 9.75 18 9.75H20.25C20.6642 9.75 21 10.0858 21 10.5C21 10.9142 20.6642 11.25 20.25 11.25H18C17.5858 11.25 17.25 10.9142 17.25 10.5ZM8.28769 14.2123C8.58058 14.5052 8.58058 14.9801 8.28769 15.273L6.6967 16.864C6.40381 17.1569 5.92893 17.1569 5.63604 16.864C5.34315 16.5711 5.34315 16.0962 5.63604 15.8033L7.22703 14.2123C7.51992 13.9194 7.9948 13.9194 8.28769 14.2123Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "cursor-arrow-ripple" shape.
+#[cfg(any(feature = "cursor-arrow-ripple", heroicon = "cursor-arrow-ripple"))]
+pub static CURSOR_ARROW_RIPPLE: super::StaticShape = super::StaticShape::new(
+    "cursor-arrow-ripple",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M17.3033 5.1967C14.3744 2.26777 9.62563 2.26777 6.6967 5.1967C3.76777 8.12563 3.76777 12.8744 6.6967 15.8033C6.98959 16.0962 6.98959 16.5711 6.6967 16.864C6.40381 17.1569 5.92893 17.1569 5.63604 16.864C2.12132 13.3492 2.12132 7.65076 5.63604 4.13604C9.15076 0.62132 14.8492 0.62132 18.364 4.13604C20.1211 5.89321 21 8.19775 21 10.4998C21 10.9141 20.6642 11.2498 20.25 11.2499C19.8358 11.2499 19.5 10.9141 19.5 10.4999C19.5 8.57933 18.7679 6.66128 17.3033 5.1967ZM15.182 7.31802C13.4246 5.56066 10.5754 5.56066 8.81802 7.31802C7.06066 9.07538 7.06066 11.9246 8.81802 13.682C9.11091 13.9749 9.11091 14.4497 8.81802 14.7426C8.52513 15.0355 8.05025 15.0355 7.75736 14.7426C5.41421 12.3995 5.41421 8.60051 7.75736 6.25736C10.1005 3.91421 13.8995 3.91421 16.2426 6.25736C17.414 7.42877 18 8.96558 18 10.4999C18 10.9141 17.6642 11.2499 17.25 11.2499C16.8358 11.2499 16.5 10.9142 16.5 10.4999C16.5 9.34715 16.0608 8.19683 15.182 7.31802ZM11.5484 8.63179C11.8602 8.54824 12.1905 8.67359 12.3684 8.94299L17.5955 16.8599C17.7627 17.113 17.7609 17.4419 17.591 17.6932C17.421 17.9445 17.1165 18.0687 16.8193 18.0079L14.722 17.5787L15.7668 21.4777C15.874 21.8778 15.6365 22.289 15.2364 22.3963C14.8363 22.5035 14.4251 22.266 14.3179 21.8659L13.2732 17.967L11.6717 19.3872C11.4447 19.5884 11.1189 19.6332 10.8461 19.5005C10.5733 19.3678 10.4073 19.0839 10.4254 18.7811L10.9939 9.3113C11.0132 8.98905 11.2366 8.71534 11.5484 8.63179Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,177 @@
+//! The shapes whose names start with "f".
+/// The "face-frown" shape.
+#[cfg(any(feature = "face-frown", heroicon = "face-frown"))]
+pub static FACE_FROWN: super::StaticShape = super::StaticShape::new(
+    "face-frown",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM9.375 8.25C8.83433 8.25 8.54674 8.66881 8.43901 8.88426C8.30886 9.14457 8.25 9.45171 8.25 9.75C8.25 10.0483 8.30886 10.3554 8.43901 10.6157C8.54674 10.8312 8.83433 11.25 9.375 11.25C9.91567 11.25 10.2033 10.8312 10.311 10.6157C10.4411 10.3554 10.5 10.0483 10.5 9.75C10.5 9.45171 10.4411 9.14457 10.311 8.88426C10.2033 8.66881 9.91567 8.25 9.375 8.25ZM13.689 8.88426C13.7967 8.66881 14.0843 8.25 14.625 8.25C15.1657 8.25 15.4533 8.66881 15.561 8.88426C15.6911 9.14457 15.75 9.45171 15.75 9.75C15.75 10.0483 15.6911 10.3554 15.561 10.6157C15.4533 10.8312 15.1657 11.25 14.625 11.25C14.0843 11.25 13.7967 10.8312 13.689 10.6157C13.5589 10.3554 13.5 10.0483 13.5 9.75C13.5 9.45171 13.5589 9.14457 13.689 8.88426ZM9.34869 16.8482C9.0558 17.1411 8.58092 17.1411 8.28803 16.8482C7.99514 16.5553 7.99514 16.0805 8.28803 15.7876C9.31761 14.758 10.6699 14.2453 12.0184 14.2499C13.3548 14.2545 14.6923 14.7672 15.7126 15.7876C16.0055 16.0805 16.0055 16.5553 15.7126 16.8482C15.4198 17.1411 14.9449 17.1411 14.652 16.8482C13.9229 16.1191 12.9698 15.7532 12.0132 15.7499C11.048 15.7466 10.0843 16.1126 9.34869 16.8482Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "face-smile" shape.
+#[cfg(any(feature = "face-smile", heroicon = "face-smile"))]
+pub static FACE_SMILE: super::StaticShape = super::StaticShape::new(
+    "face-smile",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM9.375 8.25C8.83433 8.25 8.54674 8.66881 8.43901 8.88426C8.30886 9.14457 8.25 9.45171 8.25 9.75C8.25 10.0483 8.30886 10.3554 8.43901 10.6157C8.54674 10.8312 8.83433 11.25 9.375 11.25C9.91567 11.25 10.2033 10.8312 10.311 10.6157C10.4411 10.3554 10.5 10.0483 10.5 9.75C10.5 9.45171 10.4411 9.14457 10.311 8.88426C10.2033 8.66881 9.91567 8.25 9.375 8.25ZM13.689 8.88426C13.7967 8.66881 14.0843 8.25 14.625 8.25C15.1657 8.25 15.4533 8.66881 15.561 8.88426C15.6911 9.14457 15.75 9.45171 15.75 9.75C15.75 10.0483 15.6911 10.3554 15.561 10.6157C15.4533 10.8312 15.1657 11.25 14.625 11.25C14.0843 11.25 13.7967 10.8312 13.689 10.6157C13.5589 10.3554 13.5 10.0483 13.5 9.75C13.5 9.45171 13.5589 9.14457 13.689 8.88426ZM15.7123 15.7123C16.0052 15.4194 16.0052 14.9446 15.7123 14.6517C15.4194 14.3588 14.9445 14.3588 14.6517 14.6517C13.1872 16.1161 10.8128 16.1161 9.34835 14.6517C9.05546 14.3588 8.58058 14.3588 8.28769 14.6517C7.9948 14.9446 7.9948 15.4194 8.28769 15.7123C10.3379 17.7626 13.6621 17.7626 15.7123 15.7123Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "film" shape.
+#[cfg(any(feature = "film", heroicon = "film"))]
+pub static FILM: super::StaticShape = super::StaticShape::new(
+    "film",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1.5 5.625C1.5 4.58947 2.33947 3.75 3.375 3.75H20.625C21.6605 3.75 22.5 4.58947 22.5 5.625V18.375C22.5 19.4105 21.6605 20.25 20.625 20.25H3.375C2.33947 20.25 1.5 19.4105 1.5 18.375V5.625ZM3 5.625V7.125C3 7.33211 3.16789 7.5 3.375 7.5H4.875C5.08211 7.5 5.25 7.33211 5.25 7.125V5.625C5.25 5.41789 5.08211 5.25 4.875 5.25H3.375C3.16789 5.25 3 5.41789 3 5.625ZM19.125 5.25C18.9179 5.25 18.75 5.41789 18.75 5.625V7.125C18.75 7.33211 18.9179 7.5 19.125 7.5H20.625C20.8321 7.5 21 7.33211 21 7.125V5.625C21 5.41789 20.8321 5.25 20.625 5.25H19.125ZM21 9.375C21 9.16789 20.8321 9 20.625 9H19.125C18.9179 9 18.75 9.16789 18.75 9.375V10.875C18.75 11.0821 18.9179 11.25 19.125 11.25H20.625C20.8321 11.25 21 11.0821 21 10.875V9.375ZM21 13.125C21 12.9179 20.8321 12.75 20.625 12.75H19.125C18.9179 12.75 18.75 12.9179 18.75 13.125V14.625C18.75 14.8321 18.9179 15 19.125 15H20.625C20.8321 15 21 14.8321 21 14.625V13.125ZM21 16.875C21 16.6679 20.8321 16.5 20.625 16.5H19.125C18.9179 16.5 18.75 16.6679 18.75 16.875V18.375C18.75 18.5821 18.9179 18.75 19.125 18.75H20.625C20.8321 18.75 21 18.5821 21 18.375V16.875ZM4.875 18.75C5.08211 18.75 5.25 18.5821 5.25 18.375V16.875C5.25 16.6679 5.08211 16.5 4.875 16.5H3.375C3.16789 16.5 3 16.6679 3 16.875V18.375C3 18.5821 3.16789 18.75 3.375 18.75H4.875ZM3.375 15H4.875C5.08211 15 5.25 14.8321 5.25 14.625V13.125C5.25 12.9179 5.08211 12.75 4.875 12.75H3.375C3.16789 12.75 3 12.9179 3 13.125V14.625C3 14.8321 3.16789 15 3.375 15ZM3.375 11.25H4.875C5.08211 11.25 5.25 11.0821 5.25 10.875V9.375C5.25 9.16789 5.08211 9 4.875 9H3.375C3.16789 9 3 9.16789 3 9.375V10.875C3 11.0821 3.16789 11.25 3.375 11.25ZM7.5 11.25C7.08579 11.25 6.75 11.5858 6.75 12C6.75 12.4142 7.08579 12.75 7.5 12.75H16.5C16.9142 12.75 17.25 12.4142 17.25 12C17.25 11.5858 16.9142 11.25 16.5 11.25H7.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "finger-print" shape.
+#[cfg(any(feature = "finger-print", heroicon = "finger-print"))]
+pub static FINGER_PRINT: super::StaticShape = super::StaticShape::new(
+    "finger-print",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 3.75C10.6231 3.75 9.34467 4.16143 8.27803 4.86788C7.93269 5.09661 7.46733 5.00207 7.2386 4.65674C7.00988 4.3114 7.10441 3.84603 7.44975 3.61731C8.75441 2.7532 10.3196 2.25 12 2.25C16.5563 2.25 20.25 5.94365 20.25 10.5C20.25 13.5153 19.6759 16.3983 18.6299 19.0442C18.4776 19.4294 18.0419 19.6182 17.6567 19.466C17.2714 19.3137 17.0826 18.8779 17.2349 18.4927C18.2127 16.0196 18.75 13.3235 18.75 10.5C18.75 6.77208 15.7279 3.75 12 3.75ZM6.15671 5.73863C6.50205 5.96735 6.59658 6.43272 6.36786 6.77806C5.6614 7.84469 5.24998 9.12316 5.24998 10.5C5.24998 12.1132 4.78653 13.6204 3.98459 14.893C3.76375 15.2434 3.30065 15.3485 2.95021 15.1276C2.59978 14.9068 2.49472 14.4437 2.71556 14.0933C3.37092 13.0533 3.74998 11.8222 3.74998 10.5C3.74998 8.81959 4.25318 7.25443 5.11728 5.94977C5.34601 5.60444 5.81137 5.5099 6.15671 5.73863ZM12 7.5C10.3431 7.5 8.99998 8.84315 8.99998 10.5C8.99998 13.5997 7.82401 16.4266 5.89462 18.5556C5.61647 18.8626 5.14217 18.8859 4.83524 18.6077C4.5283 18.3296 4.50497 17.8553 4.78312 17.5484C6.47217 15.6845 7.49998 13.2132 7.49998 10.5C7.49998 8.01472 9.51469 6 12 6C14.4853 6 16.5 8.01472 16.5 10.5C16.5 11.0472 16.4777 11.5897 16.4334 12.1264C16.3993 12.5392 16.037 12.8462 15.6242 12.8121C15.2114 12.778 14.9044 12.4157 14.9385 12.0029C14.9794 11.5078 15 11.0066 15 10.5C15 8.84315 13.6568 7.5 12 7.5ZM12.0003 9.75C12.4145 9.75 12.7503 10.0858 12.7503 10.5C12.7503 14.4082 11.3259 17.9855 8.96884 20.7384C8.69945 21.053 8.22599 21.0897 7.91135 20.8203C7.59671 20.5509 7.56004 20.0775 7.82943 19.7628C9.96257 17.2715 11.2503 14.0371 11.2503 10.5C11.2503 10.0858 11.5861 9.75 12.0003 9.75ZM15.2387 14.9331C15.637 15.0467 15.8678 15.4617 15.7542 15.8601C15.1843 17.8576 14.3041 19.7239 13.1693 21.4035C12.9374 21.7467 12.4712 21.837 12.128 21.6051C11.7848 21.3732 11.6945 20.907 11.9264 20.5637C12.974 19.0132 13.7861 17.291 14.3117 15.4485C14.4254 15.0502 14.8404 14.8194 15.2387 14.9331Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "fire" shape.
+#[cfg(any(feature = "fire", heroicon = "fire"))]
+pub static FIRE: super::StaticShape = super::StaticShape::new(
+    "fire",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.9633 2.28603C12.8416 2.12274 12.6586 2.01599 12.4565 1.99035C12.2545 1.9647 12.0506 2.02235 11.8919 2.15005C10.0218 3.65487 8.7174 5.83801 8.35322 8.32662C7.69665 7.85065 7.11999 7.27076 6.6476 6.61105C6.51764 6.42957 6.3136 6.3154 6.09095 6.29959C5.8683 6.28377 5.65017 6.36795 5.49587 6.52925C3.95047 8.14466 3 10.3371 3 12.7499C3 17.7205 7.02944 21.7499 12 21.7499C16.9706 21.7499 21 17.7205 21 12.7499C21 9.089 18.8143 5.94023 15.6798 4.5343C14.5706 3.99281 13.6547 3.21308 12.9633 2.28603ZM15.75 14.2503C15.75 16.3214 14.0711 18.0003 12 18.0003C9.92893 18.0003 8.25 16.3214 8.25 14.2503C8.25 13.841 8.31559 13.4469 8.43682 13.0782C9.06529 13.5428 9.78769 13.8876 10.5703 14.0789C10.7862 12.6781 11.4866 11.4372 12.4949 10.5327C14.3321 10.7749 15.75 12.347 15.75 14.2503Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "flag" shape.
+#[cfg(any(feature = "flag", heroicon = "flag"))]
+pub static FLAG: super::StaticShape = super::StaticShape::new(
+    "flag",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 2.25C3.41421 2.25 3.75 2.58579 3.75 3V3.53942L5.58819 3.07987C7.84613 2.51539 10.2315 2.77724 12.3132 3.8181L12.421 3.87196C14.1472 4.73507 16.1214 4.96567 18.0001 4.52363L21.1096 3.79196C21.3465 3.73622 21.5958 3.79888 21.7781 3.96005C21.9605 4.12121 22.0533 4.36083 22.0271 4.60278C21.844 6.29313 21.75 8.01046 21.75 9.75C21.75 11.504 21.8455 13.2355 22.0317 14.9395C22.0728 15.3161 21.8266 15.6642 21.4579 15.751L18.3436 16.4837C16.1234 17.0062 13.7902 16.7336 11.7501 15.7136L11.6424 15.6597C9.88097 14.779 7.86256 14.5574 5.95199 15.0351L3.75 15.5856V21C3.75 21.4142 3.41421 21.75 3 21.75C2.58579 21.75 2.25 21.4142 2.25 21V3C2.25 2.58579 2.58579 2.25 3 2.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder-arrow-down" shape.
+#[cfg(any(feature = "folder-arrow-down", heroicon = "folder-arrow-down"))]
+pub static FOLDER_ARROW_DOWN: super::StaticShape = super::StaticShape::new(
+    "folder-arrow-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 21C21.1569 21 22.5 19.6569 22.5 18V9C22.5 7.34315 21.1569 6 19.5 6H14.1213C13.9224 6 13.7316 5.92098 13.591 5.78033L11.4697 3.65901C11.0477 3.23705 10.4754 3 9.87868 3H4.5C2.84315 3 1.5 4.34315 1.5 6V18C1.5 19.6569 2.84315 21 4.5 21H19.5ZM12.75 10.5C12.75 10.0858 12.4142 9.75 12 9.75C11.5858 9.75 11.25 10.0858 11.25 10.5L11.25 14.6893L9.53033 12.9697C9.23744 12.6768 8.76256 12.6768 8.46967 12.9697C8.17678 13.2626 8.17678 13.7374 8.46967 14.0303L11.4697 17.0303C11.6103 17.171 11.8011 17.25 12 17.25C12.1989 17.25 12.3897 17.171 12.5303 17.0303L15.5303 14.0303C15.8232 13.7374 15.8232 13.2626 15.5303 12.9697C15.2374 12.6768 14.7626 12.6768 14.4697 12.9697L12.75 14.6893L12.75 10.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder-minus" shape.
+#[cfg(any(feature = "folder-minus", heroicon = "folder-minus"))]
+pub static FOLDER_MINUS: super::StaticShape = super::StaticShape::new(
+    "folder-minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 21C21.1569 21 22.5 19.6569 22.5 18V9C22.5 7.34315 21.1569 6 19.5 6H14.1213C13.9224 6 13.7316 5.92098 13.591 5.78033L11.4697 3.65901C11.0477 3.23705 10.4754 3 9.87868 3H4.5C2.84315 3 1.5 4.34315 1.5 6V18C1.5 19.6569 2.84315 21 4.5 21H19.5ZM9 12.75C8.58579 12.75 8.25 13.0858 8.25 13.5C8.25 13.9142 8.58579 14.25 9 14.25H15C15.4142 14.25 15.75 13.9142 15.75 13.5C15.75 13.0858 15.4142 12.75 15 12.75H9Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder-open" shape.
+#[cfg(any(feature = "folder-open", heroicon = "folder-open"))]
+pub static FOLDER_OPEN: super::StaticShape = super::StaticShape::new(
+    "folder-open",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.9057 9C20.2877 9 20.6549 9.05664 21 9.16156V9C21 7.34315 19.6569 6 18 6H14.1213C13.9224 6 13.7316 5.92098 13.591 5.78033L11.4697 3.65901C11.0477 3.23705 10.4754 3 9.87868 3H6C4.34315 3 3 4.34315 3 6V9.16152C3.34508 9.05663 3.71223 9 4.09421 9H19.9057Z M4.0943 10.5C2.72506 10.5 1.67327 11.7127 1.86691 13.0682L2.72405 19.0682C2.8824 20.1767 3.83173 21 4.95144 21H19.0486C20.1683 21 21.1176 20.1767 21.276 19.0682L22.1331 13.0682C22.3268 11.7127 21.275 10.5 19.9058 10.5H4.0943Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder-plus" shape.
+#[cfg(any(feature = "folder-plus", heroicon = "folder-plus"))]
+pub static FOLDER_PLUS: super::StaticShape = super::StaticShape::new(
+    "folder-plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 21C21.1569 21 22.5 19.6569 22.5 18V9C22.5 7.34315 21.1569 6 19.5 6H14.1213C13.9224 6 13.7316 5.92098 13.591 5.78033L11.4697 3.65901C11.0477 3.23705 10.4754 3 9.87868 3H4.5C2.84315 3 1.5 4.34315 1.5 6V18C1.5 19.6569 2.84315 21 4.5 21H19.5ZM12.75 10.5C12.75 10.0858 12.4142 9.75 12 9.75C11.5858 9.75 11.25 10.0858 11.25 10.5V12.75H9C8.58579 12.75 8.25 13.0858 8.25 13.5C8.25 13.9142 8.58579 14.25 9 14.25H11.25V16.5C11.25 16.9142 11.5858 17.25 12 17.25C12.4142 17.25 12.75 16.9142 12.75 16.5V14.25H15C15.4142 14.25 15.75 13.9142 15.75 13.5C15.75 13.0858 15.4142 12.75 15 12.75H12.75V10.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "folder" shape.
+#[cfg(any(feature = "folder", heroicon = "folder"))]
+pub static FOLDER: super::StaticShape = super::StaticShape::new(
+    "folder",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.5 21C21.1569 21 22.5 19.6569 22.5 18V13.5C22.5 11.8431 21.1569 10.5 19.5 10.5H4.5C2.84315 10.5 1.5 11.8431 1.5 13.5V18C1.5 19.6569 2.84315 21 4.5 21H19.5Z M1.5 10.1458V6C1.5 4.34315 2.84315 3 4.5 3H9.87868C10.4754 3 11.0477 3.23705 11.4697 3.65901L13.591 5.78033C13.7316 5.92098 13.9224 6 14.1213 6H19.5C21.1569 6 22.5 7.34315 22.5 9V10.1458C21.7039 9.43328 20.6525 9 19.5 9H4.5C3.34747 9 2.29613 9.43328 1.5 10.1458Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "forward" shape.
+#[cfg(any(feature = "forward", heroicon = "forward"))]
+pub static FORWARD: super::StaticShape = super::StaticShape::new(
+    "forward",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.05526 7.06036C3.80528 6.34609 2.25 7.24865 2.25 8.68832V16.8112C2.25 18.2508 3.80528 19.1534 5.05526 18.4391L12 14.4707V16.8112C12 18.2508 13.5553 19.1534 14.8053 18.4391L21.9128 14.3777C23.1724 13.6579 23.1724 11.8416 21.9128 11.1218L14.8053 7.06036C13.5553 6.34609 12 7.24865 12 8.68832V11.0288L5.05526 7.06036Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "funnel" shape.
+#[cfg(any(feature = "funnel", heroicon = "funnel"))]
+pub static FUNNEL: super::StaticShape = super::StaticShape::new(
+    "funnel",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.79154 2.93825C6.46066 2.48562 9.20314 2.25 12.0001 2.25C14.7969 2.25 17.5394 2.48561 20.2085 2.93822C21.1108 3.09125 21.75 3.87676 21.75 4.77402V5.81802C21.75 6.61367 21.4339 7.37673 20.8713 7.93934L14.6893 14.1213C14.408 14.4026 14.25 14.7842 14.25 15.182V18.1094C14.25 19.2457 13.608 20.2845 12.5916 20.7927L10.8354 21.6708C10.6029 21.7871 10.3268 21.7746 10.1057 21.638C9.88459 21.5013 9.75 21.2599 9.75 21V15.182C9.75 14.7842 9.59196 14.4026 9.31066 14.1213L3.12868 7.93934C2.56607 7.37673 2.25 6.61367 2.25 5.81802V4.77404C2.25 3.87678 2.88917 3.09127 3.79154 2.93825Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
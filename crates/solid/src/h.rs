@@ -0,0 +1,89 @@
+//! The shapes whose names start with "h".
+/// The "hand-raised" shape.
+#[cfg(any(feature = "hand-raised", heroicon = "hand-raised"))]
+pub static HAND_RAISED: super::StaticShape = super::StaticShape::new(
+    "hand-raised",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 1.875C10.5 1.25368 11.0037 0.75 11.625 0.75C12.2463 0.75 12.75 1.25368 12.75 1.875V10.0938C13.2674 10.2561 13.7708 10.4757 14.25 10.7527V3.375C14.25 2.75368 14.7537 2.25 15.375 2.25C15.9963 2.25 16.5 2.75368 16.5 3.375V14.3122C15.0821 14.5501 13.8891 15.451 13.2506 16.6852C14.4554 16.0866 15.8134 15.75 17.25 15.75C17.6642 15.75 18 15.4142 18 15V12.75L18 12.7336C18.0042 11.8771 18.3339 11.0181 18.9885 10.3635C19.4278 9.92417 20.1402 9.92417 20.5795 10.3635C21.0188 10.8028 21.0188 11.5152 20.5795 11.9545C20.361 12.173 20.2514 12.4567 20.25 12.7445L20.25 12.75L20.25 15.75H20.2454C20.1863 17.2558 19.5623 18.6877 18.4926 19.7574L16.7574 21.4926C15.6321 22.6179 14.106 23.25 12.5147 23.25H10.5C6.35786 23.25 3 19.8921 3 15.75V6.375C3 5.75368 3.50368 5.25 4.125 5.25C4.74632 5.25 5.25 5.75368 5.25 6.375V11.8939C5.71078 11.4421 6.2154 11.0617 6.75 10.7527V3.375C6.75 2.75368 7.25368 2.25 7.875 2.25C8.49632 2.25 9 2.75368 9 3.375V9.90069C9.49455 9.80023 9.99728 9.75 10.5 9.75V1.875Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "hand-thumb-down" shape.
+#[cfg(any(feature = "hand-thumb-down", heroicon = "hand-thumb-down"))]
+pub static HAND_THUMB_DOWN: super::StaticShape = super::StaticShape::new(
+    "hand-thumb-down",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.7303 5.25L16.7647 5.25C17.5455 6.43343 18 7.85114 18 9.375C18 10.8989 17.5455 12.3166 16.7647 13.5L16.6174 13.5C15.8111 13.5 15.0835 13.9458 14.5859 14.5803C13.8127 15.5662 12.8383 16.3866 11.7245 16.9798C11.0023 17.3644 10.3757 17.9357 10.0719 18.6954C9.85923 19.2269 9.75 19.7941 9.75 20.3666L9.75 21C9.75 21.4142 9.41421 21.75 9 21.75C7.75736 21.75 6.75 20.7426 6.75 19.5C6.75 18.3484 7.00956 17.2574 7.47337 16.2823C7.73895 15.724 7.36638 15 6.74809 15L3.62227 15C2.59563 15 1.6767 14.306 1.56801 13.2851C1.52305 12.8629 1.5 12.4341 1.5 12C1.5 9.15238 2.49188 6.53642 4.149 4.47878C4.5366 3.99749 5.13581 3.75 5.75377 3.75L9.76975 3.75C10.2534 3.75 10.7339 3.82798 11.1928 3.98093L14.3072 5.01908C14.7661 5.17203 15.2466 5.25 15.7303 5.25Z M21.6685 13.7729C22.2052 12.4111 22.5 10.9275 22.5 9.37501C22.5 8.15493 22.3179 6.97738 21.9794 5.86805C21.7201 5.01802 20.8958 4.5 20.0071 4.5L19.0993 4.5C18.6538 4.5 18.3786 4.99827 18.5758 5.3978C19.1675 6.59709 19.5 7.94722 19.5 9.375C19.5 11.0832 19.0241 12.6802 18.1977 14.0408C17.9527 14.4441 18.226 15 18.6979 15L19.7506 15C20.5827 15 21.3635 14.547 21.6685 13.7729Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "hand-thumb-up" shape.
+#[cfg(any(feature = "hand-thumb-up", heroicon = "hand-thumb-up"))]
+pub static HAND_THUMB_UP: super::StaticShape = super::StaticShape::new(
+    "hand-thumb-up",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.49281 18.75C7.06823 18.75 6.67296 18.5135 6.51759 18.1184C6.18349 17.2687 6 16.3433 6 15.375C6 13.6259 6.59874 12.0167 7.60244 10.741C7.75335 10.5493 7.97456 10.4321 8.20214 10.344C8.67496 10.1609 9.09254 9.82968 9.4141 9.41967C10.1873 8.43384 11.1617 7.6134 12.2755 7.02021C12.9977 6.63563 13.6243 6.06428 13.9281 5.30464C14.1408 4.7731 14.25 4.20587 14.25 3.63338V3C14.25 2.58579 14.5858 2.25 15 2.25C16.2426 2.25 17.25 3.25736 17.25 4.5C17.25 5.65163 16.9904 6.74263 16.5266 7.71771C16.261 8.27604 16.6336 9 17.2519 9H20.3777C21.4044 9 22.3233 9.69399 22.432 10.7149C22.4769 11.1371 22.5 11.5658 22.5 12C22.5 14.8476 21.5081 17.4636 19.851 19.5212C19.4634 20.0025 18.8642 20.25 18.2462 20.25H14.2302C13.7466 20.25 13.2661 20.172 12.8072 20.0191L9.69278 18.9809C9.23393 18.828 8.75342 18.75 8.26975 18.75H7.49281Z M2.33149 10.9771C1.79481 12.3389 1.5 13.8225 1.5 15.375C1.5 16.5951 1.68208 17.7726 2.02056 18.882C2.27991 19.732 3.10418 20.25 3.99289 20.25H4.90067C5.3462 20.25 5.62137 19.7517 5.42423 19.3522C4.83248 18.1529 4.5 16.8028 4.5 15.375C4.5 13.6668 4.97588 12.0698 5.8023 10.7093C6.0473 10.3059 5.77404 9.75 5.30212 9.75H4.24936C3.41733 9.75 2.63655 10.203 2.33149 10.9771Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "hashtag" shape.
+#[cfg(any(feature = "hashtag", heroicon = "hashtag"))]
+pub static HASHTAG: super::StaticShape = super::StaticShape::new(
+    "hashtag",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.0974 1.51471C11.5035 1.59594 11.767 1.99106 11.6857 2.39723L10.6651 7.50015H15.1351L16.2146 2.10306C16.2958 1.69689 16.6909 1.43348 17.0971 1.51471C17.5033 1.59594 17.7667 1.99106 17.6854 2.39723L16.6649 7.50015H20.25C20.6642 7.50015 21 7.83593 21 8.25015C21 8.66436 20.6642 9.00015 20.25 9.00015H16.3649L15.1649 15.0001H18.75C19.1642 15.0001 19.5 15.3359 19.5 15.7501C19.5 16.1644 19.1642 16.5001 18.75 16.5001H14.8649L13.7854 21.8972C13.7042 22.3034 13.3091 22.5668 12.9029 22.4856C12.4967 22.4043 12.2333 22.0092 12.3146 21.6031L13.3351 16.5001H8.86515L7.78573 21.8972C7.70449 22.3034 7.30938 22.5668 6.90321 22.4856C6.49704 22.4043 6.23362 22.0092 6.31486 21.6031L7.33544 16.5001H3.75C3.33579 16.5001 3 16.1644 3 15.7501C3 15.3359 3.33579 15.0001 3.75 15.0001H7.63544L8.83544 9.00015H5.25C4.83579 9.00015 4.5 8.66436 4.5 8.25015C4.5 7.83593 4.83579 7.50015 5.25 7.50015H9.13544L10.2149 2.10306C10.2961 1.69689 10.6912 1.43348 11.0974 1.51471ZM10.3651 9.00015L9.16515 15.0001H13.6351L14.8351 9.00015H10.3651Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "heart" shape.
+#[cfg(any(feature = "heart", heroicon = "heart"))]
+pub static HEART: super::StaticShape = super::StaticShape::new(
+    "heart",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.645 20.9107L11.6384 20.9071L11.6158 20.8949C11.5965 20.8844 11.5689 20.8693 11.5336 20.8496C11.4629 20.8101 11.3612 20.7524 11.233 20.6769C10.9765 20.5261 10.6132 20.3039 10.1785 20.015C9.31074 19.4381 8.15122 18.5901 6.9886 17.5063C4.68781 15.3615 2.25 12.1751 2.25 8.25C2.25 5.32194 4.7136 3 7.6875 3C9.43638 3 11.0023 3.79909 12 5.0516C12.9977 3.79909 14.5636 3 16.3125 3C19.2864 3 21.75 5.32194 21.75 8.25C21.75 12.1751 19.3122 15.3615 17.0114 17.5063C15.8488 18.5901 14.6893 19.4381 13.8215 20.015C13.3868 20.3039 13.0235 20.5261 12.767 20.6769C12.6388 20.7524 12.5371 20.8101 12.4664 20.8496C12.4311 20.8693 12.4035 20.8844 12.3842 20.8949L12.3616 20.9071L12.355 20.9107L12.3523 20.9121C12.1323 21.0289 11.8677 21.0289 11.6477 20.9121L11.645 20.9107Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "home-modern" shape.
+#[cfg(any(feature = "home-modern", heroicon = "home-modern"))]
+pub static HOME_MODERN: super::StaticShape = super::StaticShape::new(
+    "home-modern",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.0061 3.70505C19.3954 3.56349 19.5962 3.13317 19.4547 2.7439C19.3131 2.35462 18.8828 2.1538 18.4935 2.29536L6 6.83846V3.00021C6 2.58599 5.66421 2.25021 5.25 2.25021H3.75C3.33579 2.25021 3 2.58599 3 3.00021V7.92937L1.99353 8.29536C1.60426 8.43691 1.40344 8.86724 1.54499 9.25651C1.68655 9.64579 2.11687 9.8466 2.50615 9.70505L19.0061 3.70505Z M3.01896 11.1147L18 5.66708V9.08858L22.0061 10.5454C22.3954 10.6869 22.5962 11.1172 22.4547 11.5065C22.3131 11.8958 21.8828 12.0966 21.4935 11.955L20.9998 11.7755V20.2502H21.75C22.1642 20.2502 22.5 20.586 22.5 21.0002C22.5 21.4144 22.1642 21.7502 21.75 21.7502H2.25C1.83579 21.7502 1.5 21.4144 1.5 21.0002C1.5 20.586 1.83579 20.2502 2.25 20.2502H3V11.1215L3.01896 11.1147ZM18 20.2502V10.6847L19.4998 11.2301V20.2502H18ZM9 14.2502C8.58579 14.2502 8.25 14.586 8.25 15.0002V19.5002C8.25 19.9144 8.58579 20.2502 9 20.2502H12C12.4142 20.2502 12.75 19.9144 12.75 19.5002V15.0002C12.75 14.586 12.4142 14.2502 12 14.2502H9Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "home" shape.
+#[cfg(any(feature = "home", heroicon = "home"))]
+pub static HOME: super::StaticShape = super::StaticShape::new(
+    "home",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.4697 3.84076C11.7626 3.54787 12.2374 3.54787 12.5303 3.84076L21.2197 12.5301C21.5126 12.823 21.9874 12.823 22.2803 12.5301C22.5732 12.2372 22.5732 11.7623 22.2803 11.4694L13.591 2.7801C12.7123 1.90142 11.2877 1.90142 10.409 2.7801L1.71967 11.4694C1.42678 11.7623 1.42678 12.2372 1.71967 12.5301C2.01256 12.823 2.48744 12.823 2.78033 12.5301L11.4697 3.84076Z M12 5.43176L20.159 13.5908C20.1887 13.6205 20.2191 13.6492 20.25 13.6769V19.8748C20.25 20.9103 19.4105 21.7498 18.375 21.7498H15C14.5858 21.7498 14.25 21.414 14.25 20.9998V16.4998C14.25 16.0856 13.9142 15.7498 13.5 15.7498H10.5C10.0858 15.7498 9.75 16.0856 9.75 16.4998V20.9998C9.75 21.414 9.41421 21.7498 9 21.7498H5.625C4.58947 21.7498 3.75 20.9103 3.75 19.8748V13.6769C3.78093 13.6492 3.81127 13.6205 3.84099 13.5908L12 5.43176Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,179 @@
+//! The shapes whose names start with "m".
+/// The "magnifying-glass-circle" shape.
+#[cfg(any(feature = "magnifying-glass-circle", heroicon = "magnifying-glass-circle"))]
+pub static MAGNIFYING_GLASS_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.25 10.875C8.25 9.42525 9.42525 8.25 10.875 8.25C12.3247 8.25 13.5 9.42525 13.5 10.875C13.5 11.6001 13.207 12.2553 12.7312 12.7312C12.2553 13.207 11.6001 13.5 10.875 13.5C9.42525 13.5 8.25 12.3247 8.25 10.875Z M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM10.875 6.75C8.59683 6.75 6.75 8.59683 6.75 10.875C6.75 13.1532 8.59683 15 10.875 15C11.7428 15 12.5487 14.7315 13.2131 14.2737L15.2197 16.2803C15.5126 16.5732 15.9874 16.5732 16.2803 16.2803C16.5732 15.9874 16.5732 15.5126 16.2803 15.2197L14.2737 13.2131C14.7315 12.5487 15 11.7428 15 10.875C15 8.59683 13.1532 6.75 10.875 6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "magnifying-glass-minus" shape.
+#[cfg(any(feature = "magnifying-glass-minus", heroicon = "magnifying-glass-minus"))]
+pub static MAGNIFYING_GLASS_MINUS: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass-minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 3.75C6.77208 3.75 3.75 6.77208 3.75 10.5C3.75 14.2279 6.77208 17.25 10.5 17.25C12.3642 17.25 14.0506 16.4953 15.273 15.273C16.4953 14.0506 17.25 12.3642 17.25 10.5C17.25 6.77208 14.2279 3.75 10.5 3.75ZM2.25 10.5C2.25 5.94365 5.94365 2.25 10.5 2.25C15.0563 2.25 18.75 5.94365 18.75 10.5C18.75 12.5078 18.032 14.3491 16.8399 15.7793L21.5303 20.4697C21.8232 20.7626 21.8232 21.2374 21.5303 21.5303C21.2374 21.8232 20.7626 21.8232 20.4697 21.5303L15.7793 16.8399C14.3491 18.032 12.5078 18.75 10.5 18.75C5.94365 18.75 2.25 15.0563 2.25 10.5ZM6.75 10.5C6.75 10.0858 7.08579 9.75 7.5 9.75H13.5C13.9142 9.75 14.25 10.0858 14.25 10.5C14.25 10.9142 13.9142 11.25 13.5 11.25H7.5C7.08579 11.25 6.75 10.9142 6.75 10.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "magnifying-glass-plus" shape.
+#[cfg(any(feature = "magnifying-glass-plus", heroicon = "magnifying-glass-plus"))]
+pub static MAGNIFYING_GLASS_PLUS: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass-plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 3.75C6.77208 3.75 3.75 6.77208 3.75 10.5C3.75 14.2279 6.77208 17.25 10.5 17.25C12.3642 17.25 14.0506 16.4953 15.273 15.273C16.4953 14.0506 17.25 12.3642 17.25 10.5C17.25 6.77208 14.2279 3.75 10.5 3.75ZM2.25 10.5C2.25 5.94365 5.94365 2.25 10.5 2.25C15.0563 2.25 18.75 5.94365 18.75 10.5C18.75 12.5078 18.032 14.3491 16.8399 15.7793L21.5303 20.4697C21.8232 20.7626 21.8232 21.2374 21.5303 21.5303C21.2374 21.8232 20.7626 21.8232 20.4697 21.5303L15.7793 16.8399C14.3491 18.032 12.5078 18.75 10.5 18.75C5.94365 18.75 2.25 15.0563 2.25 10.5ZM10.5 6.75C10.9142 6.75 11.25 7.08579 11.25 7.5V9.75H13.5C13.9142 9.75 14.25 10.0858 14.25 10.5C14.25 10.9142 13.9142 11.25 13.5 11.25H11.25V13.5C11.25 13.9142 10.9142 14.25 10.5 14.25C10.0858 14.25 9.75 13.9142 9.75 13.5V11.25H7.5C7.08579 11.25 6.75 10.9142 6.75 10.5C6.75 10.0858 7.08579 9.75 7.5 9.75H9.75V7.5C9.75 7.08579 10.0858 6.75 10.5 6.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "magnifying-glass" shape.
+#[cfg(any(feature = "magnifying-glass", heroicon = "magnifying-glass"))]
+pub static MAGNIFYING_GLASS: super::StaticShape = super::StaticShape::new(
+    "magnifying-glass",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.5 3.75C6.77208 3.75 3.75 6.77208 3.75 10.5C3.75 14.2279 6.77208 17.25 10.5 17.25C12.3642 17.25 14.0506 16.4953 15.273 15.273C16.4953 14.0506 17.25 12.3642 17.25 10.5C17.25 6.77208 14.2279 3.75 10.5 3.75ZM2.25 10.5C2.25 5.94365 5.94365 2.25 10.5 2.25C15.0563 2.25 18.75 5.94365 18.75 10.5C18.75 12.5078 18.032 14.3491 16.8399 15.7793L21.5303 20.4697C21.8232 20.7626 21.8232 21.2374 21.5303 21.5303C21.2374 21.8232 20.7626 21.8232 20.4697 21.5303L15.7793 16.8399C14.3491 18.032 12.5078 18.75 10.5 18.75C5.94365 18.75 2.25 15.0563 2.25 10.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "map-pin" shape.
+#[cfg(any(feature = "map-pin", heroicon = "map-pin"))]
+pub static MAP_PIN: super::StaticShape = super::StaticShape::new(
+    "map-pin",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.5397 22.351C11.57 22.3686 11.5937 22.3821 11.6105 22.3915L11.6384 22.4071C11.8613 22.5294 12.1378 22.5285 12.3608 22.4075L12.3895 22.3915C12.4063 22.3821 12.43 22.3686 12.4603 22.351C12.5207 22.316 12.607 22.265 12.7155 22.1982C12.9325 22.0646 13.2388 21.8676 13.6046 21.6091C14.3351 21.0931 15.3097 20.3274 16.2865 19.3273C18.2307 17.3368 20.25 14.3462 20.25 10.5C20.25 5.94365 16.5563 2.25 12 2.25C7.44365 2.25 3.75 5.94365 3.75 10.5C3.75 14.3462 5.76932 17.3368 7.71346 19.3273C8.69025 20.3274 9.66491 21.0931 10.3954 21.6091C10.7612 21.8676 11.0675 22.0646 11.2845 22.1982C11.393 22.265 11.4793 22.316 11.5397 22.351ZM12 13.5C13.6569 13.5 15 12.1569 15 10.5C15 8.84315 13.6569 7.5 12 7.5C10.3431 7.5 9 8.84315 9 10.5C9 12.1569 10.3431 13.5 12 13.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "map" shape.
+#[cfg(any(feature = "map", heroicon = "map"))]
+pub static MAP: super::StaticShape = super::StaticShape::new(
+    "map",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.16147 2.58076C8.68934 2.31683 9.31066 2.31683 9.83853 2.58076L14.8323 5.07765C14.9379 5.13043 15.0621 5.13043 15.1677 5.07765L19.0365 3.14326C20.2832 2.51992 21.75 3.42647 21.75 4.82031V17.3047C21.75 18.0149 21.3487 18.6642 20.7135 18.9818L15.8385 21.4193C15.3107 21.6832 14.6893 21.6832 14.1615 21.4193L9.16771 18.9224C9.06213 18.8696 8.93787 18.8696 8.8323 18.9224L4.96353 20.8568C3.71683 21.4801 2.25 20.5736 2.25 19.1797V6.69531C2.25 5.98512 2.65125 5.33587 3.28647 5.01826L8.16147 2.58076ZM9 6.00002C9.41421 6.00002 9.75 6.3358 9.75 6.75002V15C9.75 15.4142 9.41421 15.75 9 15.75C8.58579 15.75 8.25 15.4142 8.25 15V6.75002C8.25 6.3358 8.58579 6.00002 9 6.00002ZM15.75 9.00002C15.75 8.5858 15.4142 8.25002 15 8.25002C14.5858 8.25002 14.25 8.5858 14.25 9.00002V17.25C14.25 17.6642 14.5858 18 15 18C15.4142 18 15.75 17.6642 15.75 17.25V9.00002Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "megaphone" shape.
+#[cfg(any(feature = "megaphone", heroicon = "megaphone"))]
+pub static MEGAPHONE: super::StaticShape = super::StaticShape::new(
+    "megaphone",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.8812 4.34562C14.81 5.17419 12.5917 5.71339 10.276 5.9132C9.60847 5.97079 8.93276 6.0002 8.25 6.0002H7.5C4.60051 6.0002 2.25 8.35071 2.25 11.2502C2.25 13.8498 4.13945 16.0081 6.61997 16.4268C6.95424 17.7958 7.41805 19.114 7.99764 20.3676C8.46171 21.3714 9.67181 21.6876 10.5803 21.1631L11.2366 20.7842C12.1167 20.2761 12.4023 19.1915 12.0087 18.3161C11.7738 17.7937 11.5642 17.2576 11.3814 16.7092C13.2988 16.9672 15.1419 17.459 16.8812 18.1548C17.6069 15.9854 18 13.6637 18 11.2502C18 8.83666 17.6069 6.51496 16.8812 4.34562Z M18.2606 3.7409C19.0641 6.0966 19.5 8.62249 19.5 11.2502C19.5 13.8779 19.0641 16.4038 18.2606 18.7595V18.7595C18.2054 18.9212 18.1487 19.0823 18.0901 19.2424C17.9477 19.6314 18.1476 20.0621 18.5366 20.2045C18.9256 20.3469 19.3563 20.147 19.4987 19.758C19.6387 19.3755 19.7696 18.9886 19.891 18.5975C20.4147 16.9107 20.7627 15.147 20.914 13.328C21.431 12.7895 21.75 12.0569 21.75 11.2502C21.75 10.4436 21.431 9.71091 20.914 9.17246C20.7627 7.35338 20.4147 5.58966 19.891 3.90292C19.7696 3.51183 19.6387 3.1249 19.4987 2.7424C19.3563 2.35342 18.9256 2.15352 18.5366 2.29591C18.1476 2.43829 17.9477 2.86904 18.0901 3.25801C18.1487 3.41813 18.2055 3.57916 18.2606 3.7409V3.7409Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "microphone" shape.
+#[cfg(any(feature = "microphone", heroicon = "microphone"))]
+pub static MICROPHONE: super::StaticShape = super::StaticShape::new(
+    "microphone",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.25 4.5C8.25 2.42893 9.92893 0.75 12 0.75C14.0711 0.75 15.75 2.42893 15.75 4.5V12.75C15.75 14.8211 14.0711 16.5 12 16.5C9.92893 16.5 8.25 14.8211 8.25 12.75V4.5Z M6 10.5C6.41421 10.5 6.75 10.8358 6.75 11.25V12.75C6.75 15.6495 9.1005 18 12 18C14.8995 18 17.25 15.6495 17.25 12.75V11.25C17.25 10.8358 17.5858 10.5 18 10.5C18.4142 10.5 18.75 10.8358 18.75 11.25V12.75C18.75 16.2244 16.125 19.0857 12.75 19.4588V21.75H15.75C16.1642 21.75 16.5 22.0858 16.5 22.5C16.5 22.9142 16.1642 23.25 15.75 23.25H8.25C7.83579 23.25 7.5 22.9142 7.5 22.5C7.5 22.0858 7.83579 21.75 8.25 21.75H11.25V19.4588C7.87504 19.0857 5.25 16.2244 5.25 12.75V11.25C5.25 10.8358 5.58579 10.5 6 10.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "minus-circle" shape.
+#[cfg(any(feature = "minus-circle", heroicon = "minus-circle"))]
+pub static MINUS_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "minus-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C6.61522 2.25 2.25 6.61522 2.25 12C2.25 17.3848 6.61522 21.75 12 21.75C17.3848 21.75 21.75 17.3848 21.75 12C21.75 6.61522 17.3848 2.25 12 2.25ZM15 12.75C15.4142 12.75 15.75 12.4142 15.75 12C15.75 11.5858 15.4142 11.25 15 11.25H9C8.58579 11.25 8.25 11.5858 8.25 12C8.25 12.4142 8.58579 12.75 9 12.75H15Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "minus-small" shape.
+#[cfg(any(feature = "minus-small", heroicon = "minus-small"))]
+pub static MINUS_SMALL: super::StaticShape = super::StaticShape::new(
+    "minus-small",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.25 12C5.25 11.5858 5.58579 11.25 6 11.25L18 11.25C18.4142 11.25 18.75 11.5858 18.75 12C18.75 12.4142 18.4142 12.75 18 12.75L6 12.75C5.58579 12.75 5.25 12.4142 5.25 12Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "minus" shape.
+#[cfg(any(feature = "minus", heroicon = "minus"))]
+pub static MINUS: super::StaticShape = super::StaticShape::new(
+    "minus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 12C3.75 11.5858 4.08579 11.25 4.5 11.25L19.5 11.25C19.9142 11.25 20.25 11.5858 20.25 12C20.25 12.4142 19.9142 12.75 19.5 12.75L4.5 12.75C4.08579 12.75 3.75 12.4142 3.75 12Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "moon" shape.
+#[cfg(any(feature = "moon", heroicon = "moon"))]
+pub static MOON: super::StaticShape = super::StaticShape::new(
+    "moon",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.52839 1.71774C9.74339 1.93274 9.80731 2.25628 9.69021 2.53689C9.2458 3.60192 9 4.77131 9 6.00001C9 10.9706 13.0294 15 18 15C19.2287 15 20.3981 14.7542 21.4631 14.3098C21.7437 14.1927 22.0673 14.2566 22.2823 14.4716C22.4973 14.6866 22.5612 15.0102 22.4441 15.2908C20.8618 19.0827 17.1183 21.75 12.75 21.75C6.95101 21.75 2.25 17.049 2.25 11.25C2.25 6.88172 4.91735 3.13817 8.70924 1.55591C8.98985 1.43882 9.31338 1.50274 9.52839 1.71774Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "musical-note" shape.
+#[cfg(any(feature = "musical-note", heroicon = "musical-note"))]
+pub static MUSICAL_NOTE: super::StaticShape = super::StaticShape::new(
+    "musical-note",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.9516 1.65124C20.1395 1.79297 20.25 2.01466 20.25 2.25001V5.98344C20.2503 5.99476 20.2503 6.00606 20.25 6.01732V16.3028C20.25 17.6423 19.3621 18.8194 18.0742 19.1874L16.7542 19.5645C15.1234 20.0305 13.5 18.806 13.5 17.1099C13.5 15.9701 14.2556 14.9684 15.3515 14.6553L17.6621 13.9951C18.306 13.8111 18.75 13.2225 18.75 12.5528V6.9943L9.75 9.56573V19.3028C9.75 20.6423 8.86207 21.8194 7.57416 22.1874L6.25418 22.5645C4.62337 23.0305 3 21.806 3 20.1099C3 18.9701 3.75559 17.9684 4.85153 17.6553L7.16208 16.9951C7.80603 16.8111 8.25 16.2225 8.25 15.5528V9.01659C8.24974 9.00526 8.24974 8.99395 8.25 8.98268V5.25001C8.25 4.91515 8.47198 4.62086 8.79396 4.52886L19.294 1.52886C19.5202 1.46421 19.7638 1.50952 19.9516 1.65124Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,97 @@
+//! The shapes whose names start with "r".
+/// The "radio" shape.
+#[cfg(any(feature = "radio", heroicon = "radio"))]
+pub static RADIO: super::StaticShape = super::StaticShape::new(
+    "radio",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M20.4321 4.10278C20.8339 4.00232 21.0782 3.59512 20.9778 3.19328C20.8773 2.79143 20.4701 2.54711 20.0683 2.64757L4.12873 6.63246C4.06201 6.64329 3.99534 6.65426 3.92872 6.66536C2.49686 6.90396 1.5 8.15803 1.5 9.57421V18.7502C1.5 20.407 2.84315 21.7502 4.5 21.7502H19.5C21.1569 21.7502 22.5 20.407 22.5 18.7502V9.57421C22.5 8.15803 21.5031 6.90396 20.0713 6.66536C17.7057 6.27116 15.2828 6.04736 12.8157 6.00687L20.4321 4.10278ZM10.8471 12.663C11.14 12.9559 11.14 13.4307 10.8471 13.7236L10.8418 13.7289C10.5489 14.0218 10.074 14.0218 9.78115 13.7289L9.77584 13.7236C9.48295 13.4307 9.48295 12.9559 9.77584 12.663L9.78115 12.6577C10.074 12.3648 10.5489 12.3648 10.8418 12.6577L10.8471 12.663ZM9.78144 15.85C10.0743 16.1429 10.5492 16.1429 10.8421 15.85L10.8474 15.8447C11.1403 15.5518 11.1403 15.0769 10.8474 14.784L10.8421 14.7787C10.5492 14.4858 10.0743 14.4858 9.78144 14.7787L9.77613 14.784C9.48324 15.0769 9.48324 15.5518 9.77613 15.8447L9.78144 15.85ZM8.72602 14.7842C9.01891 15.0771 9.01891 15.552 8.72602 15.8449L8.72071 15.8502C8.42782 16.1431 7.95295 16.1431 7.66005 15.8502L7.65475 15.8449C7.36186 15.552 7.36186 15.0771 7.65475 14.7842L7.66005 14.7789C7.95295 14.486 8.42782 14.486 8.72071 14.7789L8.72602 14.7842ZM7.66034 13.7287C7.95324 14.0216 8.42811 14.0216 8.721 13.7287L8.72631 13.7234C9.0192 13.4305 9.0192 12.9557 8.72631 12.6628L8.721 12.6575C8.42811 12.3646 7.95324 12.3646 7.66034 12.6575L7.65504 12.6628C7.36215 12.9557 7.36215 13.4305 7.65504 13.7234L7.66034 13.7287ZM9.25488 9.75018C9.6691 9.75018 10.0049 10.086 10.0049 10.5002V10.5077C10.0049 10.9219 9.6691 11.2577 9.25488 11.2577H9.24738C8.83317 11.2577 8.49738 10.9219 8.49738 10.5077V10.5002C8.49738 10.086 8.83317 9.75018 9.24738 9.75018H9.25488ZM12.879 13.0298C13.2378 12.8227 13.3607 12.364 13.1536 12.0053L13.1498 11.9988C12.9427 11.6401 12.484 11.5171 12.1253 11.7243L12.1188 11.728C11.7601 11.9351 11.6372 12.3938 11.8443 12.7525L11.848 12.759C12.0551 13.1177 12.5138 13.2406 12.8725 13.0335L12.879 13.0298ZM11.4996 18.156C11.1409 18.3631 10.6822 18.2402 10.4751 17.8815L10.4713 17.875C10.2642 17.5163 10.3871 17.0576 10.7459 16.8505L10.7524 16.8467C11.1111 16.6396 11.5698 16.7625 11.7769 17.1212L11.7806 17.1277C11.9877 17.4865 11.8648 17.9451 11.5061 18.1523L11.4996 18.156ZM11.781 11.38C11.9881 11.0213 11.8652 10.5626 11.5065 10.3555L11.5 10.3518C11.1413 10.1447 10.6826 10.2676 10.4755 10.6263L10.4717 10.6328C10.2646 10.9915 10.3875 11.4502 10.7462 11.6573L10.7527 11.6611C11.1115 11.8682 11.5701 11.7453 11.7773 11.3865L11.781 11.38ZM13.1499 16.5089C12.9428 16.8676 12.4841 16.9906 12.1254 16.7834L12.1189 16.7797C11.7602 16.5726 11.6373 16.1139 11.8444 15.7552L11.8481 15.7487C12.0552 15.39 12.5139 15.2671 12.8726 15.4742L12.8791 15.4779C13.2379 15.685 13.3608 16.1437 13.1537 16.5024L13.1499 16.5089ZM13.0049 15.0075C13.4191 15.0075 13.7549 14.6717 13.7549 14.2575V14.25C13.7549 13.8358 13.4191 13.5 13.0049 13.5H12.9974C12.5832 13.5 12.2474 13.8358 12.2474 14.25V14.2575C12.2474 14.6717 12.5832 15.0075 12.9974 15.0075H13.0049ZM9.25488 17.2502C9.6691 17.2502 10.0049 17.586 10.0049 18.0002V18.0077C10.0049 18.4219 9.6691 18.7577 9.25488 18.7577H9.24738C8.83317 18.7577 8.49738 18.4219 8.49738 18.0077V18.0002C8.49738 17.586 8.83317 17.2502 9.24738 17.2502H9.25488ZM6.38393 16.7798C6.74265 16.5727 6.86555 16.114 6.65845 15.7553L6.6547 15.7488C6.44759 15.3901 5.9889 15.2671 5.63018 15.4743L5.62368 15.478C5.26496 15.6851 5.14206 16.1438 5.34916 16.5025L5.35291 16.509C5.56002 16.8677 6.01871 16.9906 6.37743 16.7835L6.38393 16.7798ZM7.74962 11.6609C7.3909 11.868 6.9322 11.7451 6.7251 11.3864L6.72135 11.3799C6.51424 11.0212 6.63715 10.5625 6.99587 10.3554L7.00236 10.3516C7.36108 10.1445 7.81977 10.2674 8.02688 10.6261L8.03063 10.6326C8.23774 10.9913 8.11483 11.45 7.75611 11.6571L7.74962 11.6609ZM8.03101 17.8752C8.23811 17.5164 8.11521 17.0578 7.75649 16.8506L7.74999 16.8469C7.39127 16.6398 6.93258 16.7627 6.72547 17.1214L6.72172 17.1279C6.51462 17.4866 6.63752 17.9453 6.99624 18.1524L7.00274 18.1562C7.36146 18.3633 7.82015 18.2404 8.02726 17.8817L8.03101 17.8752ZM6.65479 12.7589C6.44768 13.1176 5.98899 13.2406 5.63027 13.0334L5.62377 13.0297C5.26505 12.8226 5.14215 12.3639 5.34925 12.0052L5.353 11.9987C5.56011 11.64 6.0188 11.5171 6.37752 11.7242L6.38402 11.7279C6.74274 11.935 6.86564 12.3937 6.65854 12.7524L6.65479 12.7589ZM5.50488 15.0075C5.9191 15.0075 6.25488 14.6717 6.25488 14.2575V14.25C6.25488 13.8358 5.9191 13.5 5.50488 13.5H5.49738C5.08317 13.5 4.74738 13.8358 4.74738 14.25V14.2575C4.74738 14.6717 5.08317 15.0075 5.49738 15.0075H5.50488ZM17.25 10.5002C18.0784 10.5002 18.75 11.1718 18.75 12.0002C18.75 12.8286 18.0784 13.5002 17.25 13.5002C16.4216 13.5002 15.75 12.8286 15.75 12.0002C15.75 11.1718 16.4216 10.5002 17.25 10.5002ZM18.75 16.5002C18.75 15.6718 18.0784 15.0002 17.25 15.0002C16.4216 15.0002 15.75 15.6718 15.75 16.5002C15.75 17.3286 16.4216 18.0002 17.25 18.0002C18.0784 18.0002 18.75 17.3286 18.75 16.5002Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "receipt-percent" shape.
+#[cfg(any(feature = "receipt-percent", heroicon = "receipt-percent"))]
+pub static RECEIPT_PERCENT: super::StaticShape = super::StaticShape::new(
+    "receipt-percent",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 1.5C10.079 1.5 8.18374 1.61114 6.32022 1.82741C4.82283 2.00119 3.75 3.28722 3.75 4.75699V21.75C3.75 21.9989 3.87345 22.2315 4.07953 22.3711C4.28561 22.5106 4.54748 22.5388 4.77854 22.4464L8.25 21.0578L11.7215 22.4464C11.9003 22.5179 12.0997 22.5179 12.2785 22.4464L15.75 21.0578L19.2215 22.4464C19.4525 22.5388 19.7144 22.5106 19.9205 22.3711C20.1266 22.2315 20.25 21.9989 20.25 21.75V4.75699C20.25 3.28722 19.1772 2.00119 17.6798 1.82741C15.8163 1.61114 13.921 1.5 12 1.5ZM15.5303 8.78033C15.8232 8.48744 15.8232 8.01256 15.5303 7.71967C15.2374 7.42678 14.7626 7.42678 14.4697 7.71967L8.46967 13.7197C8.17678 14.0126 8.17678 14.4874 8.46967 14.7803C8.76256 15.0732 9.23744 15.0732 9.53033 14.7803L15.5303 8.78033ZM8.625 9C8.625 8.37868 9.12868 7.875 9.75 7.875C10.3713 7.875 10.875 8.37868 10.875 9C10.875 9.62132 10.3713 10.125 9.75 10.125C9.12868 10.125 8.625 9.62132 8.625 9ZM14.25 12.375C13.6287 12.375 13.125 12.8787 13.125 13.5C13.125 14.1213 13.6287 14.625 14.25 14.625C14.8713 14.625 15.375 14.1213 15.375 13.5C15.375 12.8787 14.8713 12.375 14.25 12.375Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "receipt-refund" shape.
+#[cfg(any(feature = "receipt-refund", heroicon = "receipt-refund"))]
+pub static RECEIPT_REFUND: super::StaticShape = super::StaticShape::new(
+    "receipt-refund",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 1.5C10.079 1.5 8.18374 1.61114 6.32022 1.82741C4.82283 2.00119 3.75 3.28722 3.75 4.75699V21.75C3.75 21.9989 3.87345 22.2315 4.07953 22.3711C4.28561 22.5106 4.54748 22.5388 4.77854 22.4464L8.25 21.0578L11.7215 22.4464C11.9003 22.5179 12.0997 22.5179 12.2785 22.4464L15.75 21.0578L19.2215 22.4464C19.4525 22.5388 19.7144 22.5106 19.9205 22.3711C20.1266 22.2315 20.25 21.9989 20.25 21.75V4.75699C20.25 3.28722 19.1772 2.00119 17.6798 1.82741C15.8163 1.61114 13.921 1.5 12 1.5ZM11.0303 8.03033C11.3232 7.73744 11.3232 7.26256 11.0303 6.96967C10.7374 6.67678 10.2626 6.67678 9.96967 6.96967L7.71967 9.21967C7.42678 9.51256 7.42678 9.98744 7.71967 10.2803L9.96967 12.5303C10.2626 12.8232 10.7374 12.8232 11.0303 12.5303C11.3232 12.2374 11.3232 11.7626 11.0303 11.4697L10.0607 10.5H13.125C14.1605 10.5 15 11.3395 15 12.375C15 13.4105 14.1605 14.25 13.125 14.25H12C11.5858 14.25 11.25 14.5858 11.25 15C11.25 15.4142 11.5858 15.75 12 15.75H13.125C14.989 15.75 16.5 14.239 16.5 12.375C16.5 10.511 14.989 9 13.125 9H10.0607L11.0303 8.03033Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "rectangle-group" shape.
+#[cfg(any(feature = "rectangle-group", heroicon = "rectangle-group"))]
+pub static RECTANGLE_GROUP: super::StaticShape = super::StaticShape::new(
+    "rectangle-group",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M1.5 7.125C1.5 6.08947 2.33947 5.25 3.375 5.25H9.375C10.4105 5.25 11.25 6.08947 11.25 7.125V10.875C11.25 11.9105 10.4105 12.75 9.375 12.75H3.375C2.33947 12.75 1.5 11.9105 1.5 10.875V7.125ZM13.5 8.625C13.5 7.58947 14.3395 6.75 15.375 6.75H20.625C21.6605 6.75 22.5 7.58947 22.5 8.625V16.875C22.5 17.9105 21.6605 18.75 20.625 18.75H15.375C14.3395 18.75 13.5 17.9105 13.5 16.875V8.625ZM3 16.125C3 15.0895 3.83947 14.25 4.875 14.25H10.125C11.1605 14.25 12 15.0895 12 16.125V18.375C12 19.4105 11.1605 20.25 10.125 20.25H4.875C3.83947 20.25 3 19.4105 3 18.375V16.125Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "rectangle-stack" shape.
+#[cfg(any(feature = "rectangle-stack", heroicon = "rectangle-stack"))]
+pub static RECTANGLE_STACK: super::StaticShape = super::StaticShape::new(
+    "rectangle-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.56641 4.65724C5.9435 4.55472 6.34029 4.5 6.74986 4.5H17.2499C17.6594 4.5 18.0562 4.55472 18.4333 4.65724C17.9406 3.67454 16.924 3 15.7499 3H8.24986C7.0757 3 6.0591 3.67454 5.56641 4.65724Z M2.25 12C2.25 10.3431 3.59315 9 5.25 9H18.75C20.4069 9 21.75 10.3431 21.75 12V18C21.75 19.6569 20.4069 21 18.75 21H5.25C3.59315 21 2.25 19.6569 2.25 18V12Z M5.24986 7.5C4.84029 7.5 4.4435 7.55472 4.06641 7.65724C4.5591 6.67454 5.5757 6 6.74986 6H17.2499C18.424 6 19.4406 6.67454 19.9333 7.65724C19.5562 7.55472 19.1594 7.5 18.7499 7.5H5.24986Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "rocket-launch" shape.
+#[cfg(any(feature = "rocket-launch", heroicon = "rocket-launch"))]
+pub static ROCKET_LAUNCH: super::StaticShape = super::StaticShape::new(
+    "rocket-launch",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9.315 7.58365C12.1956 3.88296 16.6946 1.50021 21.75 1.5C21.9489 1.49999 22.1397 1.57901 22.2803 1.71966C22.421 1.86031 22.5 2.05108 22.5 2.25C22.5 7.30564 20.1173 11.805 16.4165 14.6859C16.4715 15.0329 16.5 15.3883 16.5 15.75C16.5 19.4779 13.4779 22.5 9.75 22.5C9.33579 22.5 9 22.1642 9 21.75V17.6185C8.99075 17.6118 8.98163 17.6049 8.97264 17.5978C8.02063 16.8429 7.15799 15.9803 6.40312 15.0282C6.39577 15.019 6.38866 15.0096 6.38179 15H2.25C1.83579 15 1.5 14.6642 1.5 14.25C1.5 10.5221 4.52208 7.5 8.25 7.5C8.61198 7.5 8.96772 7.52856 9.315 7.58365ZM15 6.75C13.7574 6.75 12.75 7.75736 12.75 9C12.75 10.2426 13.7574 11.25 15 11.25C16.2426 11.25 17.25 10.2426 17.25 9C17.25 7.75736 16.2426 6.75 15 6.75Z M5.26036 17.2418C5.59237 16.9942 5.66074 16.5242 5.41306 16.1922C5.16539 15.8602 4.69546 15.7918 4.36345 16.0395C3.08209 16.9954 2.25 18.5256 2.25 20.2499C2.25 20.5255 2.27129 20.7966 2.31246 21.0615C2.36259 21.3842 2.61574 21.6373 2.93842 21.6875C3.20336 21.7286 3.47445 21.7499 3.75 21.7499C5.47434 21.7499 7.00452 20.9178 7.9604 19.6365C8.20808 19.3045 8.13971 18.8345 7.8077 18.5869C7.47569 18.3392 7.00576 18.4075 6.75809 18.7396C6.07313 19.6577 4.98081 20.2499 3.75 20.2499C3.75 19.0191 4.34218 17.9268 5.26036 17.2418Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "rss" shape.
+#[cfg(any(feature = "rss", heroicon = "rss"))]
+pub static RSS: super::StaticShape = super::StaticShape::new(
+    "rss",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.75 4.5C3.75 4.08579 4.08579 3.75 4.5 3.75H5.25C13.5343 3.75 20.25 10.4657 20.25 18.75V19.5C20.25 19.9142 19.9142 20.25 19.5 20.25H18.75C18.3358 20.25 18 19.9142 18 19.5V18.75C18 11.7084 12.2916 6 5.25 6H4.5C4.08579 6 3.75 5.66421 3.75 5.25V4.5ZM3.75 11.25C3.75 10.8358 4.08579 10.5 4.5 10.5H5.25C9.80635 10.5 13.5 14.1937 13.5 18.75V19.5C13.5 19.9142 13.1642 20.25 12.75 20.25H12C11.5858 20.25 11.25 19.9142 11.25 19.5V18.75C11.25 15.4363 8.56371 12.75 5.25 12.75H4.5C4.08579 12.75 3.75 12.4142 3.75 12V11.25ZM3.75 18.75C3.75 17.9216 4.42157 17.25 5.25 17.25C6.07843 17.25 6.75 17.9216 6.75 18.75C6.75 19.5784 6.07843 20.25 5.25 20.25C4.42157 20.25 3.75 19.5784 3.75 18.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
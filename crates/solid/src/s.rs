@@ -0,0 +1,309 @@
+//! The shapes whose names start with "s".
+/// The "scale" shape.
+#[cfg(any(feature = "scale", heroicon = "scale"))]
+pub static SCALE: super::StaticShape = super::StaticShape::new(
+    "scale",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C12.4142 2.25 12.75 2.58579 12.75 3V3.75565C14.8183 3.78685 16.8558 3.94691 18.8546 4.22821C19.8808 4.37264 20.8968 4.54903 21.9016 4.75634C22.2784 4.83409 22.5352 5.18431 22.4962 5.56704C22.4571 5.94977 22.1348 6.24087 21.75 6.24087H19.8324L22.306 16.3654C22.3872 16.6979 22.2331 17.0431 21.9313 17.2046C20.9827 17.7124 19.8988 18 18.75 18C17.6012 18 16.5174 17.7124 15.5687 17.2046C15.267 17.0431 15.1129 16.6979 15.1941 16.3654L17.6677 6.24087H12.75V19.5217C14.0425 19.5968 15.2843 19.8645 16.4466 20.2971C16.7867 20.4237 16.9874 20.7757 16.9231 21.1329C16.8588 21.4901 16.5479 21.75 16.185 21.75H7.81465C7.4517 21.75 7.14083 21.4901 7.07652 21.1329C7.0122 20.7757 7.2129 20.4237 7.55305 20.2971C8.71543 19.8645 9.9574 19.5967 11.25 19.5217V6.24087H6.33238L8.80598 16.3654C8.88721 16.6979 8.73307 17.0431 8.43134 17.2046C7.48271 17.7124 6.39885 18 5.25004 18C4.10124 18 3.01738 17.7124 2.06875 17.2046C1.76702 17.0431 1.61288 16.6979 1.69411 16.3654L4.16771 6.24087H2.25004C1.86532 6.24087 1.54299 5.94977 1.50392 5.56704C1.46485 5.18431 1.7217 4.83409 2.09849 4.75634C3.10325 4.54903 4.11928 4.37264 5.14552 4.22821C7.14429 3.94691 9.18169 3.78686 11.25 3.75565V3C11.25 2.58579 11.5858 2.25 12 2.25ZM16.878 15.7934L18.75 8.13096L20.6221 15.7934H16.878ZM7.12211 15.7934L5.25004 8.13096L3.37797 15.7934H7.12211Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "scissors" shape.
+#[cfg(any(feature = "scissors", heroicon = "scissors"))]
+pub static SCISSORS: super::StaticShape = super::StaticShape::new(
+    "scissors",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M8.12833 9.15465C6.98189 10.5304 4.97858 10.9236 3.37489 9.99773C1.5813 8.9622 0.966765 6.66874 2.0023 4.87514C3.03783 3.08154 5.3313 2.46701 7.12489 3.50254C8.66319 4.39068 9.33419 6.20412 8.84075 7.83392L9.97714 8.49001C10.1638 8.59775 10.295 8.78056 10.3374 8.99182C10.3799 9.20309 10.3294 9.4224 10.1989 9.59385L10.1933 9.6012C9.94809 9.92324 9.49126 9.99309 9.16144 9.75845C9.12519 9.73266 9.08749 9.70843 9.04842 9.68586L8.12833 9.15465ZM3.30134 5.62514C3.92266 4.54898 5.29874 4.18026 6.37489 4.80158C7.3896 5.38742 7.77536 6.6443 7.29525 7.68806C7.27427 7.71504 7.25489 7.74377 7.23732 7.7742C7.21056 7.82057 7.1893 7.8686 7.17336 7.91755C6.54071 8.96089 5.18679 9.31178 4.12489 8.6987C3.04874 8.07737 2.68002 6.7013 3.30134 5.62514Z M13.3484 8.27167C12.3744 8.56954 11.5401 9.1424 10.9215 9.88959C10.4538 10.4545 10.109 11.1196 9.91984 11.842C9.81853 12.2288 9.76198 12.6318 9.75505 13.0438C9.74669 13.5407 9.47822 13.9968 9.04782 14.2453L8.08883 14.799C6.93631 13.4611 4.96032 13.0872 3.37489 14.0025C1.5813 15.0381 0.966765 17.3315 2.0023 19.1251C3.03783 20.9187 5.3313 21.5333 7.12489 20.4977C8.68172 19.5989 9.35023 17.7523 8.8224 16.1075L22.5507 8.18151C22.8134 8.02983 22.9588 7.73485 22.9193 7.43409C22.8797 7.13334 22.6628 6.88606 22.3698 6.80754L21.5674 6.59253C20.6169 6.33787 19.6141 6.35542 18.6731 6.64319L13.3484 8.27167ZM4.12489 15.3016C3.04874 15.9229 2.68002 17.299 3.30134 18.3751C3.92266 19.4513 5.29874 19.82 6.37489 19.1987C7.45105 18.5774 7.81977 17.2013 7.19845 16.1251C6.57713 15.049 5.20105 14.6803 4.12489 15.3016ZM12 12.75C12.4142 12.75 12.75 12.4142 12.75 12C12.75 11.5858 12.4142 11.25 12 11.25C11.5858 11.25 11.25 11.5858 11.25 12C11.25 12.4142 11.5858 12.75 12 12.75Z M16.3718 12.615C16.6038 12.481 16.8897 12.481 17.1218 12.615L22.5513 15.7497C22.814 15.9014 22.9594 16.1963 22.9198 16.4971C22.8803 16.7979 22.6634 17.0451 22.3704 17.1236L21.5679 17.3387C20.6175 17.5933 19.6147 17.5758 18.6737 17.288L13.5271 15.714C13.2415 15.6267 13.035 15.3783 13.0012 15.0816C12.9674 14.785 13.1128 14.4965 13.3714 14.3473L16.3718 12.615Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "server-stack" shape.
+#[cfg(any(feature = "server-stack", heroicon = "server-stack"))]
+pub static SERVER_STACK: super::StaticShape = super::StaticShape::new(
+    "server-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.50701 4.04763C6.07695 3.38269 6.909 3 7.78478 3H16.2151C17.0908 3 17.9229 3.38269 18.4928 4.04763L20.2147 6.05645C19.9819 6.0193 19.7432 6 19.4999 6H4.49992C4.25668 6 4.01794 6.0193 3.78516 6.05645L5.50701 4.04763Z M1.5 10.5C1.5 8.84315 2.84315 7.5 4.5 7.5H19.5C21.1569 7.5 22.5 8.84315 22.5 10.5C22.5 12.1569 21.1569 13.5 19.5 13.5H4.5C2.84315 13.5 1.5 12.1569 1.5 10.5ZM16.5 10.5C16.5 10.9142 16.1642 11.25 15.75 11.25C15.3358 11.25 15 10.9142 15 10.5C15 10.0858 15.3358 9.75 15.75 9.75C16.1642 9.75 16.5 10.0858 16.5 10.5ZM18.75 11.25C19.1642 11.25 19.5 10.9142 19.5 10.5C19.5 10.0858 19.1642 9.75 18.75 9.75C18.3358 9.75 18 10.0858 18 10.5C18 10.9142 18.3358 11.25 18.75 11.25Z M4.5 15C2.84315 15 1.5 16.3431 1.5 18C1.5 19.6569 2.84315 21 4.5 21H19.5C21.1569 21 22.5 19.6569 22.5 18C22.5 16.3431 21.1569 15 19.5 15H4.5ZM15.75 18.75C16.1642 18.75 16.5 18.4142 16.5 18C16.5 17.5858 16.1642 17.25 15.75 17.25C15.3358 17.25 15 17.5858 15 18C15 18.4142 15.3358 18.75 15.75 18.75ZM19.5 18C19.5 18.4142 19.1642 18.75 18.75 18.75C18.3358 18.75 18 18.4142 18 18C18 17.5858 18.3358 17.25 18.75 17.25C19.1642 17.25 19.5 17.5858 19.5 18Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "server" shape.
+#[cfg(any(feature = "server", heroicon = "server"))]
+pub static SERVER: super::StaticShape = super::StaticShape::new(
+    "server",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.07993 5.22701C4.43013 3.91375 5.61948 3 6.97863 3H17.0214C18.3805 3 19.5699 3.91375 19.9201 5.22701L22.0338 13.1535C21.1346 12.4318 19.9927 12 18.75 12H5.25C4.00727 12 2.86538 12.4318 1.96619 13.1535L4.07993 5.22701Z M5.25 13.5C3.17893 13.5 1.5 15.1789 1.5 17.25C1.5 19.3211 3.17893 21 5.25 21H18.75C20.8211 21 22.5 19.3211 22.5 17.25C22.5 15.1789 20.8211 13.5 18.75 13.5H5.25ZM15.75 18C16.1642 18 16.5 17.6642 16.5 17.25C16.5 16.8358 16.1642 16.5 15.75 16.5C15.3358 16.5 15 16.8358 15 17.25C15 17.6642 15.3358 18 15.75 18ZM19.5 17.25C19.5 17.6642 19.1642 18 18.75 18C18.3358 18 18 17.6642 18 17.25C18 16.8358 18.3358 16.5 18.75 16.5C19.1642 16.5 19.5 16.8358 19.5 17.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "share" shape.
+#[cfg(any(feature = "share", heroicon = "share"))]
+pub static SHARE: super::StaticShape = super::StaticShape::new(
+    "share",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15.75 4.5C15.75 2.84315 17.0931 1.5 18.75 1.5C20.4069 1.5 21.75 2.84315 21.75 4.5C21.75 6.15685 20.4069 7.5 18.75 7.5C17.8933 7.5 17.1212 7.14074 16.5751 6.56624L8.15392 11.2447C8.21665 11.4863 8.25 11.7395 8.25 12C8.25 12.2605 8.21665 12.5137 8.15392 12.7553L16.5751 17.4338C17.1212 16.8593 17.8933 16.5 18.75 16.5C20.4069 16.5 21.75 17.8431 21.75 19.5C21.75 21.1569 20.4069 22.5 18.75 22.5C17.0931 22.5 15.75 21.1569 15.75 19.5C15.75 19.2395 15.7833 18.9863 15.8461 18.7447L7.42488 14.0662C6.87885 14.6407 6.10667 15 5.25 15C3.59315 15 2.25 13.6569 2.25 12C2.25 10.3431 3.59315 9 5.25 9C6.10667 9 6.87885 9.35926 7.42488 9.93377L15.8461 5.25532C15.7833 5.01367 15.75 4.76045 15.75 4.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "shield-check" shape.
+#[cfg(any(feature = "shield-check", heroicon = "shield-check"))]
+pub static SHIELD_CHECK: super::StaticShape = super::StaticShape::new(
+    "shield-check",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12.5157 2.1698C12.2265 1.89587 11.7735 1.89587 11.4843 2.1698C9.46752 4.07977 6.74624 5.25011 3.75 5.25011C3.70233 5.25011 3.65473 5.24981 3.60721 5.24922C3.27984 5.24515 2.98767 5.4539 2.88541 5.76491C2.47287 7.01968 2.25 8.35963 2.25 9.75015C2.25 15.6922 6.31406 20.6831 11.8131 22.0984C11.9357 22.13 12.0643 22.13 12.1869 22.0984C17.6859 20.6831 21.75 15.6922 21.75 9.75015C21.75 8.35963 21.5271 7.01968 21.1146 5.76491C21.0123 5.4539 20.7202 5.24515 20.3928 5.24922C20.3453 5.24981 20.2977 5.25011 20.25 5.25011C17.2538 5.25011 14.5325 4.07977 12.5157 2.1698ZM15.6103 10.1859C15.8511 9.84887 15.773 9.38046 15.4359 9.1397C15.0989 8.89894 14.6305 8.97701 14.3897 9.31407L11.1543 13.8436L9.53033 12.2197C9.23744 11.9268 8.76256 11.9268 8.46967 12.2197C8.17678 12.5126 8.17678 12.9874 8.46967 13.2803L10.7197 15.5303C10.8756 15.6862 11.0921 15.7656 11.3119 15.7474C11.5316 15.7293 11.7322 15.6153 11.8603 15.4359L15.6103 10.1859Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "shield-exclamation" shape.
+#[cfg(any(feature = "shield-exclamation", heroicon = "shield-exclamation"))]
+pub static SHIELD_EXCLAMATION: super::StaticShape = super::StaticShape::new(
+    "shield-exclamation",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.4843 2.1698C11.7735 1.89587 12.2265 1.89587 12.5157 2.1698C14.5325 4.07977 17.2538 5.25011 20.25 5.25011C20.2977 5.25011 20.3453 5.24981 20.3928 5.24922C20.7202 5.24515 21.0123 5.4539 21.1146 5.76491C21.5271 7.01968 21.75 8.35963 21.75 9.75015C21.75 15.6922 17.6859 20.6831 12.1869 22.0984C12.0643 22.13 11.9357 22.13 11.8131 22.0984C6.31406 20.6831 2.25 15.6922 2.25 9.75015C2.25 8.35963 2.47287 7.01968 2.88541 5.76491C2.98767 5.4539 3.27984 5.24515 3.60721 5.24922C3.65473 5.24981 3.70233 5.25011 3.75 5.25011C6.74624 5.25011 9.46752 4.07977 11.4843 2.1698ZM12 8.25009C12.4142 8.25009 12.75 8.58588 12.75 9.00009V12.7501C12.75 13.1643 12.4142 13.5001 12 13.5001C11.5858 13.5001 11.25 13.1643 11.25 12.7501V9.00009C11.25 8.58588 11.5858 8.25009 12 8.25009ZM12 15.0001C11.5858 15.0001 11.25 15.3359 11.25 15.7501V15.7576C11.25 16.1718 11.5858 16.5076 12 16.5076H12.0075C12.4217 16.5076 12.7575 16.1718 12.7575 15.7576V15.7501C12.7575 15.3359 12.4217 15.0001 12.0075 15.0001H12Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "shopping-bag" shape.
+#[cfg(any(feature = "shopping-bag", heroicon = "shopping-bag"))]
+pub static SHOPPING_BAG: super::StaticShape = super::StaticShape::new(
+    "shopping-bag",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M7.5 6V6.75H5.51287C4.55332 6.75 3.74862 7.47444 3.64817 8.42872L2.38502 20.4287C2.26848 21.5358 3.13652 22.5 4.24971 22.5H19.7504C20.8636 22.5 21.7317 21.5358 21.6151 20.4287L20.352 8.42872C20.2515 7.47444 19.4468 6.75 18.4873 6.75H16.5V6C16.5 3.51472 14.4853 1.5 12 1.5C9.51472 1.5 7.5 3.51472 7.5 6ZM12 3C10.3431 3 9 4.34315 9 6V6.75H15V6C15 4.34315 13.6569 3 12 3ZM9 11.25C9 12.9069 10.3431 14.25 12 14.25C13.6569 14.25 15 12.9069 15 11.25V10.5C15 10.0858 15.3358 9.75 15.75 9.75C16.1642 9.75 16.5 10.0858 16.5 10.5V11.25C16.5 13.7353 14.4853 15.75 12 15.75C9.51472 15.75 7.5 13.7353 7.5 11.25V10.5C7.5 10.0858 7.83579 9.75 8.25 9.75C8.66421 9.75 9 10.0858 9 10.5V11.25Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "shopping-cart" shape.
+#[cfg(any(feature = "shopping-cart", heroicon = "shopping-cart"))]
+pub static SHOPPING_CART: super::StaticShape = super::StaticShape::new(
+    "shopping-cart",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 2.25C1.83579 2.25 1.5 2.58579 1.5 3C1.5 3.41421 1.83579 3.75 2.25 3.75H3.63568C3.80558 3.75 3.95425 3.86422 3.99803 4.02838L6.55576 13.6199C4.94178 14.0385 3.75 15.5051 3.75 17.25C3.75 17.6642 4.08579 18 4.5 18H20.25C20.6642 18 21 17.6642 21 17.25C21 16.8358 20.6642 16.5 20.25 16.5H5.37803C5.68691 15.6261 6.52034 15 7.5 15H18.7183C19.0051 15 19.2668 14.8364 19.3925 14.5785C20.5277 12.249 21.5183 9.83603 22.3527 7.35126C22.4191 7.15357 22.4002 6.93716 22.3005 6.75399C22.2008 6.57082 22.0294 6.43743 21.8273 6.38583C17.0055 5.15442 11.9536 4.5 6.75 4.5C6.39217 4.5 6.03505 4.5031 5.67868 4.50926L5.44738 3.64188C5.2285 2.82109 4.48515 2.25 3.63568 2.25H2.25Z M3.75 20.25C3.75 19.4216 4.42157 18.75 5.25 18.75C6.07843 18.75 6.75 19.4216 6.75 20.25C6.75 21.0784 6.07843 21.75 5.25 21.75C4.42157 21.75 3.75 21.0784 3.75 20.25Z M16.5 20.25C16.5 19.4216 17.1716 18.75 18 18.75C18.8284 18.75 19.5 19.4216 19.5 20.25C19.5 21.0784 18.8284 21.75 18 21.75C17.1716 21.75 16.5 21.0784 16.5 20.25Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "signal-slash" shape.
+#[cfg(any(feature = "signal-slash", heroicon = "signal-slash"))]
+pub static SIGNAL_SLASH: super::StaticShape = super::StaticShape::new(
+    "signal-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.46968 2.46967C2.76257 2.17678 3.23744 2.17678 3.53034 2.46967L11.9374 10.8767C11.9581 10.8756 11.979 10.875 12 10.875C12.6213 10.875 13.125 11.3787 13.125 12C13.125 12.021 13.1244 12.0419 13.1233 12.0626L14.5848 13.5242C15.2643 12.3737 15.1098 10.8671 14.1213 9.87868C13.8284 9.58579 13.8284 9.11091 14.1213 8.81802C14.4142 8.52513 14.8891 8.52513 15.182 8.81802C16.7587 10.3947 16.9208 12.8503 15.6683 14.6077L16.7402 15.6795C18.572 13.3257 18.4062 9.9209 16.2426 7.75736C15.9498 7.46447 15.9498 6.98959 16.2426 6.6967C16.5355 6.40381 17.0104 6.40381 17.3033 6.6967C20.0533 9.44665 20.2213 13.8008 17.8075 16.7468L18.8725 17.8118C21.8696 14.2758 21.7001 8.97217 18.364 5.63604C18.0711 5.34315 18.0711 4.86827 18.364 4.57538C18.6569 4.28249 19.1317 4.28249 19.4246 4.57538C23.3468 8.49757 23.5174 14.7507 19.9363 18.8756L21.5303 20.4697C21.8232 20.7626 21.8232 21.2374 21.5303 21.5303C21.2374 21.8232 20.7626 21.8232 20.4697 21.5303L18.3644 19.4251L16.2426 17.3033L16.242 17.3027L11.5366 12.5972C11.4896 12.5603 11.4472 12.5179 11.4103 12.4709L2.46968 3.53033C2.17678 3.23744 2.17678 2.76256 2.46968 2.46967ZM3.65854 6.89187C4.02729 7.08054 4.17326 7.53242 3.98459 7.90117C2.25352 11.2844 2.80534 15.5333 5.63605 18.364C5.92894 18.6569 5.92894 19.1317 5.63605 19.4246C5.34315 19.7175 4.86828 19.7175 4.57539 19.4246C1.27115 16.1204 0.630437 11.1635 2.64924 7.21792C2.83791 6.84917 3.28979 6.70319 3.65854 6.89187ZM5.84076 9.13355C6.23339 9.2655 6.44473 9.69075 6.31278 10.0834C5.6094 12.1765 6.09233 14.5776 7.75737 16.2426C8.05026 16.5355 8.05026 17.0104 7.75737 17.3033C7.46447 17.5962 6.9896 17.5962 6.69671 17.3033C4.61367 15.2203 4.01314 12.2177 4.89092 9.60558C5.02286 9.21294 5.44812 9.00161 5.84076 9.13355ZM8.182 11.7875C8.5921 11.7293 8.97176 12.0145 9.02999 12.4246C9.11816 13.0455 9.40043 13.6431 9.87869 14.1213C10.1716 14.4142 10.1716 14.8891 9.87869 15.182C9.58579 15.4749 9.11092 15.4749 8.81803 15.182C8.10217 14.4661 7.67717 13.5671 7.54489 12.6355C7.48666 12.2254 7.7719 11.8458 8.182 11.7875Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "signal" shape.
+#[cfg(any(feature = "signal", heroicon = "signal"))]
+pub static SIGNAL: super::StaticShape = super::StaticShape::new(
+    "signal",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M5.63604 4.57514C5.92893 4.86803 5.92893 5.34291 5.63604 5.6358C2.12132 9.15052 2.12132 14.849 5.63604 18.3637C5.92893 18.6566 5.92893 19.1315 5.63604 19.4244C5.34315 19.7173 4.86827 19.7173 4.57538 19.4244C0.474874 15.3239 0.474873 8.67564 4.57538 4.57514C4.86827 4.28225 5.34315 4.28225 5.63604 4.57514ZM18.364 4.57514C18.6569 4.28225 19.1317 4.28225 19.4246 4.57514C23.5251 8.67564 23.5251 15.3239 19.4246 19.4244C19.1317 19.7173 18.6569 19.7173 18.364 19.4244C18.0711 19.1315 18.0711 18.6566 18.364 18.3637C21.8787 14.849 21.8787 9.15052 18.364 5.6358C18.0711 5.34291 18.0711 4.86803 18.364 4.57514ZM7.75736 6.69646C8.05025 6.98935 8.05025 7.46423 7.75736 7.75712C5.41421 10.1003 5.41421 13.8993 7.75736 16.2424C8.05025 16.5353 8.05025 17.0102 7.75736 17.3031C7.46447 17.596 6.98959 17.596 6.6967 17.3031C3.76777 14.3741 3.76777 9.62539 6.6967 6.69646C6.98959 6.40357 7.46447 6.40357 7.75736 6.69646ZM16.2426 6.69646C16.5355 6.40357 17.0104 6.40357 17.3033 6.69646C20.2322 9.62539 20.2322 14.3741 17.3033 17.3031C17.0104 17.596 16.5355 17.596 16.2426 17.3031C15.9497 17.0102 15.9497 16.5353 16.2426 16.2424C18.5858 13.8993 18.5858 10.1003 16.2426 7.75712C15.9497 7.46423 15.9497 6.98935 16.2426 6.69646ZM9.87868 8.81778C10.1716 9.11067 10.1716 9.58555 9.87868 9.87844C8.70711 11.05 8.70711 12.9495 9.87868 14.1211C10.1716 14.414 10.1716 14.8888 9.87868 15.1817C9.58579 15.4746 9.11091 15.4746 8.81802 15.1817C7.06066 13.4244 7.06066 10.5751 8.81802 8.81778C9.11091 8.52489 9.58579 8.52489 9.87868 8.81778ZM14.1213 8.81778C14.4142 8.52489 14.8891 8.52489 15.182 8.81778C16.9393 10.5751 16.9393 13.4244 15.182 15.1817C14.8891 15.4746 14.4142 15.4746 14.1213 15.1817C13.8284 14.8888 13.8284 14.414 14.1213 14.1211C15.2929 12.9495 15.2929 11.05 14.1213 9.87844C13.8284 9.58555 13.8284 9.11067 14.1213 8.81778ZM10.875 11.9998C10.875 11.3784 11.3787 10.8748 12 10.8748C12.6213 10.8748 13.125 11.3784 13.125 11.9998C13.125 12.6211 12.6213 13.1248 12 13.1248C11.3787 13.1248 10.875 12.6211 10.875 11.9998Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "sparkles" shape.
+#[cfg(any(feature = "sparkles", heroicon = "sparkles"))]
+pub static SPARKLES: super::StaticShape = super::StaticShape::new(
+    "sparkles",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M9 4.5C9.33486 4.5 9.62915 4.72198 9.72114 5.04396L10.5343 7.89015C10.8903 9.13593 11.8641 10.1097 13.1099 10.4657L15.956 11.2789C16.278 11.3709 16.5 11.6651 16.5 12C16.5 12.3349 16.278 12.6291 15.956 12.7211L13.1099 13.5343C11.8641 13.8903 10.8903 14.8641 10.5343 16.1099L9.72114 18.956C9.62915 19.278 9.33486 19.5 9 19.5C8.66514 19.5 8.37085 19.278 8.27886 18.956L7.46566 16.1099C7.10972 14.8641 6.13593 13.8903 4.89015 13.5343L2.04396 12.7211C1.72198 12.6291 1.5 12.3349 1.5 12C1.5 11.6651 1.72198 11.3709 2.04396 11.2789L4.89015 10.4657C6.13593 10.1097 7.10972 9.13593 7.46566 7.89015L8.27886 5.04396C8.37085 4.72198 8.66514 4.5 9 4.5Z M18 1.5C18.3442 1.5 18.6441 1.73422 18.7276 2.0681L18.9865 3.10356C19.2216 4.04406 19.9559 4.7784 20.8964 5.01353L21.9319 5.27239C22.2658 5.35586 22.5 5.65585 22.5 6C22.5 6.34415 22.2658 6.64414 21.9319 6.72761L20.8964 6.98647C19.9559 7.2216 19.2216 7.95594 18.9865 8.89644L18.7276 9.9319C18.6441 10.2658 18.3442 10.5 18 10.5C17.6558 10.5 17.3559 10.2658 17.2724 9.9319L17.0135 8.89644C16.7784 7.95594 16.0441 7.2216 15.1036 6.98647L14.0681 6.72761C13.7342 6.64414 13.5 6.34415 13.5 6C13.5 5.65585 13.7342 5.35586 14.0681 5.27239L15.1036 5.01353C16.0441 4.7784 16.7784 4.04406 17.0135 3.10356L17.2724 2.0681C17.3559 1.73422 17.6558 1.5 18 1.5Z M16.5 15C16.8228 15 17.1094 15.2066 17.2115 15.5128L17.6058 16.6956C17.7551 17.1435 18.1065 17.4949 18.5544 17.6442L19.7372 18.0385C20.0434 18.1406 20.25 18.4272 20.25 18.75C20.25 19.0728 20.0434 19.3594 19.7372 19.4615L18.5544 19.8558C18.1065 20.0051 17.7551 20.3565 17.6058 20.8044L17.2115 21.9872C17.1094 22.2934 16.8228 22.5 16.5 22.5C16.1772 22.5 15.8906 22.2934 15.7885 21.9872L15.3942 20.8044C15.2449 20.3565 14.8935 20.0051 14.4456 19.8558L13.2628 19.4615C12.9566 19.3594 12.75 19.0728 12.75 18.75C12.75 18.4272 12.9566 18.1406 13.2628 18.0385L14.4456 17.6442C14.8935 17.4949 15.2449 17.1435 15.3942 16.6956L15.7885 15.5128C15.8906 15.2066 16.1772 15 16.5 15Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "speaker-wave" shape.
+#[cfg(any(feature = "speaker-wave", heroicon = "speaker-wave"))]
+pub static SPEAKER_WAVE: super::StaticShape = super::StaticShape::new(
+    "speaker-wave",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.5 4.06063C13.5 2.72427 11.8843 2.05501 10.9393 2.99996L6.43934 7.49997H4.50905C3.36772 7.49997 2.19106 8.16441 1.8493 9.40502C1.62147 10.2321 1.5 11.1024 1.5 12C1.5 12.8975 1.62147 13.7678 1.8493 14.5949C2.19106 15.8355 3.36772 16.5 4.50905 16.5H6.43934L10.9393 21C11.8843 21.9449 13.5 21.2757 13.5 19.9393V4.06063Z M18.5837 5.10561C18.8766 4.81272 19.3514 4.81272 19.6443 5.10561C23.452 8.91322 23.452 15.0866 19.6443 18.8942C19.3514 19.1871 18.8766 19.1871 18.5837 18.8942C18.2908 18.6013 18.2908 18.1264 18.5837 17.8335C21.8055 14.6117 21.8055 9.38809 18.5837 6.16627C18.2908 5.87338 18.2908 5.3985 18.5837 5.10561Z M15.9323 7.75734C16.2252 7.46445 16.7001 7.46445 16.993 7.75734C19.3361 10.1005 19.3361 13.8995 16.993 16.2426C16.7001 16.5355 16.2252 16.5355 15.9323 16.2426C15.6394 15.9497 15.6394 15.4749 15.9323 15.182C17.6897 13.4246 17.6897 10.5754 15.9323 8.818C15.6394 8.52511 15.6394 8.05024 15.9323 7.75734Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "speaker-x-mark" shape.
+#[cfg(any(feature = "speaker-x-mark", heroicon = "speaker-x-mark"))]
+pub static SPEAKER_X_MARK: super::StaticShape = super::StaticShape::new(
+    "speaker-x-mark",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M13.5 4.06063C13.5 2.72427 11.8843 2.05501 10.9393 2.99996L6.43934 7.49997H4.50905C3.36772 7.49997 2.19106 8.1644 1.8493 9.40502C1.62147 10.2321 1.5 11.1024 1.5 12C1.5 12.8975 1.62147 13.7678 1.8493 14.5949C2.19106 15.8355 3.36772 16.5 4.50905 16.5H6.43934L10.9393 21C11.8843 21.9449 13.5 21.2757 13.5 19.9393V4.06063Z M17.7803 9.21962C17.4874 8.92673 17.0126 8.92673 16.7197 9.21962C16.4268 9.51252 16.4268 9.98739 16.7197 10.2803L18.4393 12L16.7197 13.7196C16.4268 14.0125 16.4268 14.4874 16.7197 14.7803C17.0126 15.0732 17.4874 15.0732 17.7803 14.7803L19.5 13.0606L21.2197 14.7803C21.5126 15.0732 21.9874 15.0732 22.2803 14.7803C22.5732 14.4874 22.5732 14.0125 22.2803 13.7196L20.5607 12L22.2803 10.2803C22.5732 9.98739 22.5732 9.51252 22.2803 9.21962C21.9874 8.92673 21.5126 8.92673 21.2197 9.21962L19.5 10.9393L17.7803 9.21962Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "square-2-stack" shape.
+#[cfg(any(feature = "square-2-stack", heroicon = "square-2-stack"))]
+pub static SQUARE_2_STACK: super::StaticShape = super::StaticShape::new(
+    "square-2-stack",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M16.5 6C16.5 4.34315 15.1569 3 13.5 3H6C4.34315 3 3 4.34315 3 6V13.5C3 15.1569 4.34315 16.5 6 16.5V10.5C6 8.01472 8.01472 6 10.5 6H16.5Z M18 7.5C19.6569 7.5 21 8.84315 21 10.5V18C21 19.6569 19.6569 21 18 21H10.5C8.84315 21 7.5 19.6569 7.5 18V10.5C7.5 8.84315 8.84315 7.5 10.5 7.5H18Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "square-3-stack-3d" shape.
+#[cfg(any(feature = "square-3-stack-3d", heroicon = "square-3-stack-3d"))]
+pub static SQUARE_3_STACK_3D: super::StaticShape = super::StaticShape::new(
+    "square-3-stack-3d",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M11.6444 1.58965C11.8664 1.47012 12.1336 1.47012 12.3556 1.58965L22.1056 6.83965C22.3485 6.97046 22.5 7.22409 22.5 7.5C22.5 7.77591 22.3485 8.02954 22.1056 8.16035L12.3556 13.4104C12.1336 13.5299 11.8664 13.5299 11.6444 13.4104L1.89443 8.16035C1.65149 8.02954 1.5 7.77591 1.5 7.5C1.5 7.22409 1.65149 6.97046 1.89443 6.83965L11.6444 1.58965Z M3.26468 10.6018L10.9333 14.731C11.5992 15.0896 12.4008 15.0896 13.0667 14.7311L20.7353 10.6018L22.1056 11.3396C22.3485 11.4704 22.5 11.7241 22.5 12C22.5 12.2759 22.3485 12.5295 22.1056 12.6603L12.3556 17.9103C12.1336 18.0299 11.8664 18.0299 11.6444 17.9103L1.89443 12.6603C1.65149 12.5295 1.5 12.2759 1.5 12C1.5 11.7241 1.65149 11.4704 1.89443 11.3396L3.26468 10.6018Z M10.9333 19.231L3.26468 15.1018L1.89443 15.8396C1.65149 15.9704 1.5 16.2241 1.5 16.5C1.5 16.7759 1.65149 17.0295 1.89443 17.1603L11.6444 22.4103C11.8664 22.5299 12.1336 22.5299 12.3556 22.4103L22.1056 17.1603C22.3485 17.0295 22.5 16.7759 22.5 16.5C22.5 16.2241 22.3485 15.9704 22.1056 15.8396L20.7353 15.1018L13.0667 19.2311C12.4008 19.5896 11.5992 19.5896 10.9333 19.231Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "squares-2x2" shape.
+#[cfg(any(feature = "squares-2x2", heroicon = "squares-2x2"))]
+pub static SQUARES_2X2: super::StaticShape = super::StaticShape::new(
+    "squares-2x2",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3 6C3 4.34315 4.34315 3 6 3H8.25C9.90685 3 11.25 4.34315 11.25 6V8.25C11.25 9.90685 9.90685 11.25 8.25 11.25H6C4.34315 11.25 3 9.90685 3 8.25V6ZM12.75 6C12.75 4.34315 14.0931 3 15.75 3H18C19.6569 3 21 4.34315 21 6V8.25C21 9.90685 19.6569 11.25 18 11.25H15.75C14.0931 11.25 12.75 9.90685 12.75 8.25V6ZM3 15.75C3 14.0931 4.34315 12.75 6 12.75H8.25C9.90685 12.75 11.25 14.0931 11.25 15.75V18C11.25 19.6569 9.90685 21 8.25 21H6C4.34315 21 3 19.6569 3 18V15.75ZM12.75 15.75C12.75 14.0931 14.0931 12.75 15.75 12.75H18C19.6569 12.75 21 14.0931 21 15.75V18C21 19.6569 19.6569 21 18 21H15.75C14.0931 21 12.75 19.6569 12.75 18V15.75Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "squares-plus" shape.
+#[cfg(any(feature = "squares-plus", heroicon = "squares-plus"))]
+pub static SQUARES_PLUS: super::StaticShape = super::StaticShape::new(
+    "squares-plus",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6 3C4.34315 3 3 4.34315 3 6V8.25C3 9.90685 4.34315 11.25 6 11.25H8.25C9.90685 11.25 11.25 9.90685 11.25 8.25V6C11.25 4.34315 9.90685 3 8.25 3H6Z M15.75 3C14.0931 3 12.75 4.34315 12.75 6V8.25C12.75 9.90685 14.0931 11.25 15.75 11.25H18C19.6569 11.25 21 9.90685 21 8.25V6C21 4.34315 19.6569 3 18 3H15.75Z M6 12.75C4.34315 12.75 3 14.0931 3 15.75V18C3 19.6569 4.34315 21 6 21H8.25C9.90685 21 11.25 19.6569 11.25 18V15.75C11.25 14.0931 9.90685 12.75 8.25 12.75H6Z M17.625 13.5C17.625 13.0858 17.2892 12.75 16.875 12.75C16.4608 12.75 16.125 13.0858 16.125 13.5V16.125H13.5C13.0858 16.125 12.75 16.4608 12.75 16.875C12.75 17.2892 13.0858 17.625 13.5 17.625H16.125V20.25C16.125 20.6642 16.4608 21 16.875 21C17.2892 21 17.625 20.6642 17.625 20.25V17.625H20.25C20.6642 17.625 21 17.2892 21 16.875C21 16.4608 20.6642 16.125 20.25 16.125H17.625V13.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "star" shape.
+#[cfg(any(feature = "star", heroicon = "star"))]
+pub static STAR: super::StaticShape = super::StaticShape::new(
+    "star",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M10.7881 3.2108C11.2364 2.13286 12.7635 2.13286 13.2118 3.2108L15.2938 8.21652L20.6979 8.64976C21.8616 8.74306 22.3335 10.1953 21.4469 10.9549L17.3295 14.4818L18.5874 19.7553C18.8583 20.8909 17.6229 21.7884 16.6266 21.1799L11.9999 18.354L7.37329 21.1799C6.37697 21.7884 5.14158 20.8909 5.41246 19.7553L6.67038 14.4818L2.55303 10.9549C1.66639 10.1953 2.13826 8.74306 3.302 8.64976L8.70609 8.21652L10.7881 3.2108Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "stop-circle" shape.
+#[cfg(any(feature = "stop-circle", heroicon = "stop-circle"))]
+pub static STOP_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "stop-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 12C2.25 6.61522 6.61522 2.25 12 2.25C17.3848 2.25 21.75 6.61522 21.75 12C21.75 17.3848 17.3848 21.75 12 21.75C6.61522 21.75 2.25 17.3848 2.25 12ZM8.25 9.5625C8.25 8.83763 8.83763 8.25 9.5625 8.25H14.4375C15.1624 8.25 15.75 8.83763 15.75 9.5625V14.4375C15.75 15.1624 15.1624 15.75 14.4375 15.75H9.5625C8.83763 15.75 8.25 15.1624 8.25 14.4375V9.5625Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "stop" shape.
+#[cfg(any(feature = "stop", heroicon = "stop"))]
+pub static STOP: super::StaticShape = super::StaticShape::new(
+    "stop",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 7.5C4.5 5.84315 5.84315 4.5 7.5 4.5H16.5C18.1569 4.5 19.5 5.84315 19.5 7.5V16.5C19.5 18.1569 18.1569 19.5 16.5 19.5H7.5C5.84315 19.5 4.5 18.1569 4.5 16.5V7.5Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "sun" shape.
+#[cfg(any(feature = "sun", heroicon = "sun"))]
+pub static SUN: super::StaticShape = super::StaticShape::new(
+    "sun",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M12 2.25C12.4142 2.25 12.75 2.58579 12.75 3V5.25C12.75 5.66421 12.4142 6 12 6C11.5858 6 11.25 5.66421 11.25 5.25V3C11.25 2.58579 11.5858 2.25 12 2.25Z M7.5 12C7.5 9.51472 9.51472 7.5 12 7.5C14.4853 7.5 16.5 9.51472 16.5 12C16.5 14.4853 14.4853 16.5 12 16.5C9.51472 16.5 7.5 14.4853 7.5 12Z M18.8943 6.16634C19.1872 5.87344 19.1872 5.39857 18.8943 5.10568C18.6014 4.81278 18.1266 4.81278 17.8337 5.10568L16.2427 6.69667C15.9498 6.98956 15.9498 7.46443 16.2427 7.75733C16.5356 8.05022 17.0105 8.05022 17.3034 7.75733L18.8943 6.16634Z M21.75 12C21.75 12.4142 21.4142 12.75 21 12.75H18.75C18.3358 12.75 18 12.4142 18 12C18 11.5858 18.3358 11.25 18.75 11.25H21C21.4142 11.25 21.75 11.5858 21.75 12Z M17.8336 18.8943C18.1265 19.1871 18.6013 19.1871 18.8942 18.8943C19.1871 18.6014 19.1871 18.1265 18.8942 17.8336L17.3032 16.2426C17.0103 15.9497 16.5355 15.9497 16.2426 16.2426C15.9497 16.5355 15.9497 17.0104 16.2426 17.3033L17.8336 18.8943Z M12 18C12.4142 18 12.75 18.3358 12.75 18.75V21C12.75 21.4142 12.4142 21.75 12 21.75C11.5858 21.75 11.25 21.4142 11.25 21V18.75C11.25 18.3358 11.5858 18 12 18Z M7.7575 17.3033C8.0504 17.0104 8.0504 16.5355 7.7575 16.2426C7.46461 15.9497 6.98974 15.9497 6.69684 16.2426L5.10585 17.8336C4.81296 18.1265 4.81296 18.6014 5.10585 18.8943C5.39875 19.1872 5.87362 19.1872 6.16651 18.8943L7.7575 17.3033Z M6 12C6 12.4142 5.66421 12.75 5.25 12.75H3C2.58579 12.75 2.25 12.4142 2.25 12C2.25 11.5858 2.58579 11.25 3 11.25H5.25C5.66421 11.25 6 11.5858 6 12Z M6.69673 7.75732C6.98962 8.05021 7.4645 8.05021 7.75739 7.75732C8.05028 7.46443 8.05028 6.98955 7.75739 6.69666L6.1664 5.10567C5.87351 4.81278 5.39863 4.81278 5.10574 5.10567C4.81285 5.39856 4.81285 5.87344 5.10574 6.16633L6.69673 7.75732Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "swatch" shape.
+#[cfg(any(feature = "swatch", heroicon = "swatch"))]
+pub static SWATCH: super::StaticShape = super::StaticShape::new(
+    "swatch",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M2.25 4.125C2.25 3.08947 3.08947 2.25 4.125 2.25H9.375C10.4105 2.25 11.25 3.08947 11.25 4.125V17.25C11.25 19.7353 9.23528 21.75 6.75 21.75C4.26472 21.75 2.25 19.7353 2.25 17.25V4.125ZM6.75 18.375C7.37132 18.375 7.875 17.8713 7.875 17.25C7.875 16.6287 7.37132 16.125 6.75 16.125C6.12868 16.125 5.625 16.6287 5.625 17.25C5.625 17.8713 6.12868 18.375 6.75 18.375Z M10.719 21.75H19.8751C20.9106 21.75 21.7501 20.9105 21.7501 19.875V14.625C21.7501 13.5895 20.9106 12.75 19.8751 12.75H19.7353L10.9927 21.4926C10.9035 21.5818 10.8122 21.6676 10.719 21.75Z M12.7383 17.6255L19.2125 11.1512C19.9448 10.419 19.9448 9.23179 19.2125 8.49955L15.5002 4.78724C14.768 4.05501 13.5808 4.05501 12.8486 4.78724L12.7498 4.88598V17.25C12.7498 17.3761 12.7459 17.5013 12.7383 17.6255Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
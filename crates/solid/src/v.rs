@@ -0,0 +1,63 @@
+//! The shapes whose names start with "v".
+/// The "variable" shape.
+#[cfg(any(feature = "variable", heroicon = "variable"))]
+pub static VARIABLE: super::StaticShape = super::StaticShape::new(
+    "variable",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M19.2535 2.29164C19.6447 2.15548 20.0722 2.36223 20.2083 2.75343C21.2033 5.61217 21.75 8.73352 21.75 12C21.75 15.2664 21.2033 18.3877 20.2083 21.2465C20.0722 21.6377 19.6447 21.8444 19.2535 21.7083C18.8623 21.5721 18.6555 21.1446 18.7917 20.7534C19.7313 18.0536 20.25 15.0989 20.25 12C20.25 8.901 19.7313 5.94634 18.7917 3.24649C18.6555 2.85529 18.8623 2.42779 19.2535 2.29164ZM5.02596 2.30476C5.40991 2.46018 5.59517 2.89742 5.43975 3.28137C4.3503 5.97278 3.75 8.91534 3.75 12C3.75 15.0846 4.3503 18.0271 5.43975 20.7185C5.59517 21.1025 5.40991 21.5397 5.02596 21.6952C4.64201 21.8506 4.20477 21.6653 4.04935 21.2814C2.88874 18.4142 2.25 15.2807 2.25 12C2.25 8.71918 2.88874 5.58573 4.04935 2.71855C4.20477 2.3346 4.64201 2.14934 5.02596 2.30476ZM9.30143 7.35691C10.1246 6.85034 11.205 7.24311 11.5106 8.16005L12.2266 10.308L13.601 8.24636C14.2504 7.27224 15.5007 6.89742 16.5789 7.35359L16.7922 7.44385C17.1737 7.60524 17.3521 8.04533 17.1907 8.4268C17.0293 8.80828 16.5892 8.98669 16.2078 8.8253L15.9944 8.73504C15.5797 8.55959 15.0989 8.70375 14.8491 9.07841L12.8275 12.1108L13.9124 15.3655L15.3569 14.4766C15.7097 14.2595 16.1717 14.3695 16.3887 14.7223C16.6058 15.075 16.4958 15.537 16.1431 15.7541L14.6986 16.643C13.8754 17.1496 12.795 16.7568 12.4894 15.8399L11.7734 13.6919L10.399 15.7536C9.74958 16.7277 8.49934 17.1025 7.42112 16.6463L7.20777 16.5561C6.82629 16.3947 6.64788 15.9546 6.80927 15.5731C6.97067 15.1916 7.41075 15.0132 7.79223 15.1746L8.00558 15.2649C8.42028 15.4403 8.90114 15.2962 9.15091 14.9215L11.1725 11.8891L10.0876 8.63439L8.64307 9.52332C8.2903 9.74041 7.82834 9.63042 7.61126 9.27765C7.39417 8.92488 7.50416 8.46292 7.85693 8.24583L9.30143 7.35691Z",
+            clip_rule: Some("evenodd"),
+            fill_rule: Some("evenodd"),
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "video-camera-slash" shape.
+#[cfg(any(feature = "video-camera-slash", heroicon = "video-camera-slash"))]
+pub static VIDEO_CAMERA_SLASH: super::StaticShape = super::StaticShape::new(
+    "video-camera-slash",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M3.53033 2.46967C3.23744 2.17678 2.76256 2.17678 2.46967 2.46967C2.17678 2.76256 2.17678 3.23744 2.46967 3.53033L20.4697 21.5303C20.7626 21.8232 21.2374 21.8232 21.5303 21.5303C21.8232 21.2374 21.8232 20.7626 21.5303 20.4697L3.53033 2.46967Z M22.5 17.6893C22.5 18.1614 22.2984 18.5502 21.996 18.814L17.25 14.068V7.93931L19.9393 5.24996C20.8843 4.30501 22.5 4.97427 22.5 6.31063V17.6893Z M15.75 7.5V12.568L7.68198 4.5H12.75C14.4069 4.5 15.75 5.84315 15.75 7.5Z M1.5 7.5C1.5 6.71787 1.79931 6.00564 2.28963 5.47161L15.1363 18.3183C14.5882 19.0366 13.7232 19.5 12.75 19.5H4.5C2.84315 19.5 1.5 18.1569 1.5 16.5V7.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "video-camera" shape.
+#[cfg(any(feature = "video-camera", heroicon = "video-camera"))]
+pub static VIDEO_CAMERA: super::StaticShape = super::StaticShape::new(
+    "video-camera",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M4.5 4.5C2.84315 4.5 1.5 5.84315 1.5 7.5V16.5C1.5 18.1569 2.84315 19.5 4.5 19.5H12.75C14.4069 19.5 15.75 18.1569 15.75 16.5V7.5C15.75 5.84315 14.4069 4.5 12.75 4.5H4.5Z M19.9393 18.75L17.25 16.0606V7.93931L19.9393 5.24996C20.8843 4.30501 22.5 4.97427 22.5 6.31063V17.6893C22.5 19.0257 20.8843 19.6949 19.9393 18.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "view-columns" shape.
+#[cfg(any(feature = "view-columns", heroicon = "view-columns"))]
+pub static VIEW_COLUMNS: super::StaticShape = super::StaticShape::new(
+    "view-columns",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M15 3.75H9V20.25H15V3.75Z M16.5 20.25H19.875C20.9105 20.25 21.75 19.4105 21.75 18.375V5.625C21.75 4.58947 20.9105 3.75 19.875 3.75H16.5V20.25Z M4.125 3.75H7.5V20.25H4.125C3.08947 20.25 2.25 19.4105 2.25 18.375V5.625C2.25 4.58947 3.08947 3.75 4.125 3.75Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
+/// The "viewfinder-circle" shape.
+#[cfg(any(feature = "viewfinder-circle", heroicon = "viewfinder-circle"))]
+pub static VIEWFINDER_CIRCLE: super::StaticShape = super::StaticShape::new(
+    "viewfinder-circle",
+    super::VIEW_BOX,
+    &[
+        super::PathData {
+            d: "M6 3C4.34315 3 3 4.34315 3 6V7.5C3 7.91421 3.33579 8.25 3.75 8.25C4.16421 8.25 4.5 7.91421 4.5 7.5V6C4.5 5.17157 5.17157 4.5 6 4.5H7.5C7.91421 4.5 8.25 4.16421 8.25 3.75C8.25 3.33579 7.91421 3 7.5 3H6Z M16.5 3C16.0858 3 15.75 3.33579 15.75 3.75C15.75 4.16421 16.0858 4.5 16.5 4.5H18C18.8284 4.5 19.5 5.17157 19.5 6V7.5C19.5 7.91421 19.8358 8.25 20.25 8.25C20.6642 8.25 21 7.91421 21 7.5V6C21 4.34315 19.6569 3 18 3H16.5Z M12 8.25C9.92893 8.25 8.25 9.92893 8.25 12C8.25 14.0711 9.92893 15.75 12 15.75C14.0711 15.75 15.75 14.0711 15.75 12C15.75 9.92893 14.0711 8.25 12 8.25Z M4.5 16.5C4.5 16.0858 4.16421 15.75 3.75 15.75C3.33579 15.75 3 16.0858 3 16.5V18C3 19.6569 4.34315 21 6 21H7.5C7.91421 21 8.25 20.6642 8.25 20.25C8.25 19.8358 7.91421 19.5 7.5 19.5H6C5.17157 19.5 4.5 18.8284 4.5 18V16.5Z M21 16.5C21 16.0858 20.6642 15.75 20.25 15.75C19.8358 15.75 19.5 16.0858 19.5 16.5V18C19.5 18.8284 18.8284 19.5 18 19.5H16.5C16.0858 19.5 15.75 19.8358 15.75 20.25C15.75 20.6642 16.0858 21 16.5 21H18C19.6569 21 21 19.6569 21 18V16.5Z",
+            ..super::PathData::EMPTY
+        },
+    ],
+);
@@ -0,0 +1,1511 @@
+//! A gallery of every heroicons shape in every style, with its name and a snippet of code that draws
+//! it, which is copied to the clipboard when the shape is clicked. This renders the gallery to an
+//! HTML file with `dioxus-ssr`, so it can be opened in any browser:
+//!
+//! ```sh
+//! cargo run --example gallery -- gallery.html
+//! ```
+//!
+//! This file was written by the `gen` crate, so don't edit it by hand.
+
+use dioxus::prelude::*;
+use dioxus_heroicons::{meta, mini, outline, solid, Icon, StaticShape};
+use std::{env, fs};
+
+/// Each style's name, and each of its shapes with a snippet that draws it.
+static STYLES: &[(&str, &[(&StaticShape, &str)])] = &[
+    (
+        "mini",
+        &[
+            (&mini::ACADEMIC_CAP, "Icon { icon: mini::Shape::AcademicCap }"),
+            (
+                &mini::ADJUSTMENTS_HORIZONTAL,
+                "Icon { icon: mini::Shape::AdjustmentsHorizontal }",
+            ),
+            (
+                &mini::ADJUSTMENTS_VERTICAL,
+                "Icon { icon: mini::Shape::AdjustmentsVertical }",
+            ),
+            (
+                &mini::ARCHIVE_BOX_ARROW_DOWN,
+                "Icon { icon: mini::Shape::ArchiveBoxArrowDown }",
+            ),
+            (&mini::ARCHIVE_BOX_X_MARK, "Icon { icon: mini::Shape::ArchiveBoxXMark }"),
+            (&mini::ARCHIVE_BOX, "Icon { icon: mini::Shape::ArchiveBox }"),
+            (&mini::ARROW_DOWN_CIRCLE, "Icon { icon: mini::Shape::ArrowDownCircle }"),
+            (&mini::ARROW_DOWN_LEFT, "Icon { icon: mini::Shape::ArrowDownLeft }"),
+            (
+                &mini::ARROW_DOWN_ON_SQUARE_STACK,
+                "Icon { icon: mini::Shape::ArrowDownOnSquareStack }",
+            ),
+            (
+                &mini::ARROW_DOWN_ON_SQUARE,
+                "Icon { icon: mini::Shape::ArrowDownOnSquare }",
+            ),
+            (&mini::ARROW_DOWN_RIGHT, "Icon { icon: mini::Shape::ArrowDownRight }"),
+            (&mini::ARROW_DOWN_TRAY, "Icon { icon: mini::Shape::ArrowDownTray }"),
+            (&mini::ARROW_DOWN, "Icon { icon: mini::Shape::ArrowDown }"),
+            (&mini::ARROW_LEFT_CIRCLE, "Icon { icon: mini::Shape::ArrowLeftCircle }"),
+            (
+                &mini::ARROW_LEFT_ON_RECTANGLE,
+                "Icon { icon: mini::Shape::ArrowLeftOnRectangle }",
+            ),
+            (&mini::ARROW_LEFT, "Icon { icon: mini::Shape::ArrowLeft }"),
+            (&mini::ARROW_LONG_DOWN, "Icon { icon: mini::Shape::ArrowLongDown }"),
+            (&mini::ARROW_LONG_LEFT, "Icon { icon: mini::Shape::ArrowLongLeft }"),
+            (&mini::ARROW_LONG_RIGHT, "Icon { icon: mini::Shape::ArrowLongRight }"),
+            (&mini::ARROW_LONG_UP, "Icon { icon: mini::Shape::ArrowLongUp }"),
+            (
+                &mini::ARROW_PATH_ROUNDED_SQUARE,
+                "Icon { icon: mini::Shape::ArrowPathRoundedSquare }",
+            ),
+            (&mini::ARROW_PATH, "Icon { icon: mini::Shape::ArrowPath }"),
+            (&mini::ARROW_RIGHT_CIRCLE, "Icon { icon: mini::Shape::ArrowRightCircle }"),
+            (
+                &mini::ARROW_RIGHT_ON_RECTANGLE,
+                "Icon { icon: mini::Shape::ArrowRightOnRectangle }",
+            ),
+            (&mini::ARROW_RIGHT, "Icon { icon: mini::Shape::ArrowRight }"),
+            (&mini::ARROW_SMALL_DOWN, "Icon { icon: mini::Shape::ArrowSmallDown }"),
+            (&mini::ARROW_SMALL_LEFT, "Icon { icon: mini::Shape::ArrowSmallLeft }"),
+            (&mini::ARROW_SMALL_RIGHT, "Icon { icon: mini::Shape::ArrowSmallRight }"),
+            (&mini::ARROW_SMALL_UP, "Icon { icon: mini::Shape::ArrowSmallUp }"),
+            (
+                &mini::ARROW_TOP_RIGHT_ON_SQUARE,
+                "Icon { icon: mini::Shape::ArrowTopRightOnSquare }",
+            ),
+            (
+                &mini::ARROW_TRENDING_DOWN,
+                "Icon { icon: mini::Shape::ArrowTrendingDown }",
+            ),
+            (&mini::ARROW_TRENDING_UP, "Icon { icon: mini::Shape::ArrowTrendingUp }"),
+            (&mini::ARROW_UP_CIRCLE, "Icon { icon: mini::Shape::ArrowUpCircle }"),
+            (&mini::ARROW_UP_LEFT, "Icon { icon: mini::Shape::ArrowUpLeft }"),
+            (
+                &mini::ARROW_UP_ON_SQUARE_STACK,
+                "Icon { icon: mini::Shape::ArrowUpOnSquareStack }",
+            ),
+            (&mini::ARROW_UP_ON_SQUARE, "Icon { icon: mini::Shape::ArrowUpOnSquare }"),
+            (&mini::ARROW_UP_RIGHT, "Icon { icon: mini::Shape::ArrowUpRight }"),
+            (&mini::ARROW_UP_TRAY, "Icon { icon: mini::Shape::ArrowUpTray }"),
+            (&mini::ARROW_UP, "Icon { icon: mini::Shape::ArrowUp }"),
+            (&mini::ARROW_UTURN_DOWN, "Icon { icon: mini::Shape::ArrowUturnDown }"),
+            (&mini::ARROW_UTURN_LEFT, "Icon { icon: mini::Shape::ArrowUturnLeft }"),
+            (&mini::ARROW_UTURN_RIGHT, "Icon { icon: mini::Shape::ArrowUturnRight }"),
+            (&mini::ARROW_UTURN_UP, "Icon { icon: mini::Shape::ArrowUturnUp }"),
+            (&mini::ARROWS_POINTING_IN, "Icon { icon: mini::Shape::ArrowsPointingIn }"),
+            (
+                &mini::ARROWS_POINTING_OUT,
+                "Icon { icon: mini::Shape::ArrowsPointingOut }",
+            ),
+            (&mini::ARROWS_RIGHT_LEFT, "Icon { icon: mini::Shape::ArrowsRightLeft }"),
+            (&mini::ARROWS_UP_DOWN, "Icon { icon: mini::Shape::ArrowsUpDown }"),
+            (&mini::AT_SYMBOL, "Icon { icon: mini::Shape::AtSymbol }"),
+            (&mini::BACKSPACE, "Icon { icon: mini::Shape::Backspace }"),
+            (&mini::BACKWARD, "Icon { icon: mini::Shape::Backward }"),
+            (&mini::BANKNOTES, "Icon { icon: mini::Shape::Banknotes }"),
+            (&mini::BARS_2, "Icon { icon: mini::Shape::Bars2 }"),
+            (&mini::BARS_3_BOTTOM_LEFT, "Icon { icon: mini::Shape::Bars3BottomLeft }"),
+            (&mini::BARS_3_BOTTOM_RIGHT, "Icon { icon: mini::Shape::Bars3BottomRight }"),
+            (&mini::BARS_3_CENTER_LEFT, "Icon { icon: mini::Shape::Bars3CenterLeft }"),
+            (&mini::BARS_3, "Icon { icon: mini::Shape::Bars3 }"),
+            (&mini::BARS_4, "Icon { icon: mini::Shape::Bars4 }"),
+            (&mini::BARS_ARROW_DOWN, "Icon { icon: mini::Shape::BarsArrowDown }"),
+            (&mini::BARS_ARROW_UP, "Icon { icon: mini::Shape::BarsArrowUp }"),
+            (&mini::BATTERY_0, "Icon { icon: mini::Shape::Battery0 }"),
+            (&mini::BATTERY_100, "Icon { icon: mini::Shape::Battery100 }"),
+            (&mini::BATTERY_50, "Icon { icon: mini::Shape::Battery50 }"),
+            (&mini::BEAKER, "Icon { icon: mini::Shape::Beaker }"),
+            (&mini::BELL_ALERT, "Icon { icon: mini::Shape::BellAlert }"),
+            (&mini::BELL_SLASH, "Icon { icon: mini::Shape::BellSlash }"),
+            (&mini::BELL_SNOOZE, "Icon { icon: mini::Shape::BellSnooze }"),
+            (&mini::BELL, "Icon { icon: mini::Shape::Bell }"),
+            (&mini::BOLT_SLASH, "Icon { icon: mini::Shape::BoltSlash }"),
+            (&mini::BOLT, "Icon { icon: mini::Shape::Bolt }"),
+            (&mini::BOOK_OPEN, "Icon { icon: mini::Shape::BookOpen }"),
+            (&mini::BOOKMARK_SLASH, "Icon { icon: mini::Shape::BookmarkSlash }"),
+            (&mini::BOOKMARK_SQUARE, "Icon { icon: mini::Shape::BookmarkSquare }"),
+            (&mini::BOOKMARK, "Icon { icon: mini::Shape::Bookmark }"),
+            (&mini::BRIEFCASE, "Icon { icon: mini::Shape::Briefcase }"),
+            (&mini::BUG_ANT, "Icon { icon: mini::Shape::BugAnt }"),
+            (&mini::BUILDING_LIBRARY, "Icon { icon: mini::Shape::BuildingLibrary }"),
+            (&mini::BUILDING_OFFICE_2, "Icon { icon: mini::Shape::BuildingOffice2 }"),
+            (&mini::BUILDING_OFFICE, "Icon { icon: mini::Shape::BuildingOffice }"),
+            (
+                &mini::BUILDING_STOREFRONT,
+                "Icon { icon: mini::Shape::BuildingStorefront }",
+            ),
+            (&mini::CAKE, "Icon { icon: mini::Shape::Cake }"),
+            (&mini::CALCULATOR, "Icon { icon: mini::Shape::Calculator }"),
+            (&mini::CALENDAR_DAYS, "Icon { icon: mini::Shape::CalendarDays }"),
+            (&mini::CALENDAR, "Icon { icon: mini::Shape::Calendar }"),
+            (&mini::CAMERA, "Icon { icon: mini::Shape::Camera }"),
+            (&mini::CHART_BAR_SQUARE, "Icon { icon: mini::Shape::ChartBarSquare }"),
+            (&mini::CHART_BAR, "Icon { icon: mini::Shape::ChartBar }"),
+            (&mini::CHART_PIE, "Icon { icon: mini::Shape::ChartPie }"),
+            (
+                &mini::CHAT_BUBBLE_BOTTOM_CENTER_TEXT,
+                "Icon { icon: mini::Shape::ChatBubbleBottomCenterText }",
+            ),
+            (
+                &mini::CHAT_BUBBLE_BOTTOM_CENTER,
+                "Icon { icon: mini::Shape::ChatBubbleBottomCenter }",
+            ),
+            (
+                &mini::CHAT_BUBBLE_LEFT_ELLIPSIS,
+                "Icon { icon: mini::Shape::ChatBubbleLeftEllipsis }",
+            ),
+            (
+                &mini::CHAT_BUBBLE_LEFT_RIGHT,
+                "Icon { icon: mini::Shape::ChatBubbleLeftRight }",
+            ),
+            (&mini::CHAT_BUBBLE_LEFT, "Icon { icon: mini::Shape::ChatBubbleLeft }"),
+            (
+                &mini::CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS,
+                "Icon { icon: mini::Shape::ChatBubbleOvalLeftEllipsis }",
+            ),
+            (
+                &mini::CHAT_BUBBLE_OVAL_LEFT,
+                "Icon { icon: mini::Shape::ChatBubbleOvalLeft }",
+            ),
+            (&mini::CHECK_BADGE, "Icon { icon: mini::Shape::CheckBadge }"),
+            (&mini::CHECK_CIRCLE, "Icon { icon: mini::Shape::CheckCircle }"),
+            (&mini::CHECK, "Icon { icon: mini::Shape::Check }"),
+            (
+                &mini::CHEVRON_DOUBLE_DOWN,
+                "Icon { icon: mini::Shape::ChevronDoubleDown }",
+            ),
+            (
+                &mini::CHEVRON_DOUBLE_LEFT,
+                "Icon { icon: mini::Shape::ChevronDoubleLeft }",
+            ),
+            (
+                &mini::CHEVRON_DOUBLE_RIGHT,
+                "Icon { icon: mini::Shape::ChevronDoubleRight }",
+            ),
+            (&mini::CHEVRON_DOUBLE_UP, "Icon { icon: mini::Shape::ChevronDoubleUp }"),
+            (&mini::CHEVRON_DOWN, "Icon { icon: mini::Shape::ChevronDown }"),
+            (&mini::CHEVRON_LEFT, "Icon { icon: mini::Shape::ChevronLeft }"),
+            (&mini::CHEVRON_RIGHT, "Icon { icon: mini::Shape::ChevronRight }"),
+            (&mini::CHEVRON_UP_DOWN, "Icon { icon: mini::Shape::ChevronUpDown }"),
+            (&mini::CHEVRON_UP, "Icon { icon: mini::Shape::ChevronUp }"),
+            (&mini::CIRCLE_STACK, "Icon { icon: mini::Shape::CircleStack }"),
+            (
+                &mini::CLIPBOARD_DOCUMENT_CHECK,
+                "Icon { icon: mini::Shape::ClipboardDocumentCheck }",
+            ),
+            (
+                &mini::CLIPBOARD_DOCUMENT_LIST,
+                "Icon { icon: mini::Shape::ClipboardDocumentList }",
+            ),
+            (&mini::CLIPBOARD_DOCUMENT, "Icon { icon: mini::Shape::ClipboardDocument }"),
+            (&mini::CLIPBOARD, "Icon { icon: mini::Shape::Clipboard }"),
+            (&mini::CLOCK, "Icon { icon: mini::Shape::Clock }"),
+            (&mini::CLOUD_ARROW_DOWN, "Icon { icon: mini::Shape::CloudArrowDown }"),
+            (&mini::CLOUD_ARROW_UP, "Icon { icon: mini::Shape::CloudArrowUp }"),
+            (&mini::CLOUD, "Icon { icon: mini::Shape::Cloud }"),
+            (
+                &mini::CODE_BRACKET_SQUARE,
+                "Icon { icon: mini::Shape::CodeBracketSquare }",
+            ),
+            (&mini::CODE_BRACKET, "Icon { icon: mini::Shape::CodeBracket }"),
+            (&mini::COG_6_TOOTH, "Icon { icon: mini::Shape::Cog6Tooth }"),
+            (&mini::COG_8_TOOTH, "Icon { icon: mini::Shape::Cog8Tooth }"),
+            (&mini::COG, "Icon { icon: mini::Shape::Cog }"),
+            (&mini::COMMAND_LINE, "Icon { icon: mini::Shape::CommandLine }"),
+            (&mini::COMPUTER_DESKTOP, "Icon { icon: mini::Shape::ComputerDesktop }"),
+            (&mini::CPU_CHIP, "Icon { icon: mini::Shape::CpuChip }"),
+            (&mini::CREDIT_CARD, "Icon { icon: mini::Shape::CreditCard }"),
+            (&mini::CUBE_TRANSPARENT, "Icon { icon: mini::Shape::CubeTransparent }"),
+            (&mini::CUBE, "Icon { icon: mini::Shape::Cube }"),
+            (
+                &mini::CURRENCY_BANGLADESHI,
+                "Icon { icon: mini::Shape::CurrencyBangladeshi }",
+            ),
+            (&mini::CURRENCY_DOLLAR, "Icon { icon: mini::Shape::CurrencyDollar }"),
+            (&mini::CURRENCY_EURO, "Icon { icon: mini::Shape::CurrencyEuro }"),
+            (&mini::CURRENCY_POUND, "Icon { icon: mini::Shape::CurrencyPound }"),
+            (&mini::CURRENCY_RUPEE, "Icon { icon: mini::Shape::CurrencyRupee }"),
+            (&mini::CURRENCY_YEN, "Icon { icon: mini::Shape::CurrencyYen }"),
+            (&mini::CURSOR_ARROW_RAYS, "Icon { icon: mini::Shape::CursorArrowRays }"),
+            (
+                &mini::CURSOR_ARROW_RIPPLE,
+                "Icon { icon: mini::Shape::CursorArrowRipple }",
+            ),
+            (
+                &mini::DEVICE_PHONE_MOBILE,
+                "Icon { icon: mini::Shape::DevicePhoneMobile }",
+            ),
+            (&mini::DEVICE_TABLET, "Icon { icon: mini::Shape::DeviceTablet }"),
+            (
+                &mini::DOCUMENT_ARROW_DOWN,
+                "Icon { icon: mini::Shape::DocumentArrowDown }",
+            ),
+            (&mini::DOCUMENT_ARROW_UP, "Icon { icon: mini::Shape::DocumentArrowUp }"),
+            (&mini::DOCUMENT_CHART_BAR, "Icon { icon: mini::Shape::DocumentChartBar }"),
+            (&mini::DOCUMENT_CHECK, "Icon { icon: mini::Shape::DocumentCheck }"),
+            (&mini::DOCUMENT_DUPLICATE, "Icon { icon: mini::Shape::DocumentDuplicate }"),
+            (
+                &mini::DOCUMENT_MAGNIFYING_GLASS,
+                "Icon { icon: mini::Shape::DocumentMagnifyingGlass }",
+            ),
+            (&mini::DOCUMENT_MINUS, "Icon { icon: mini::Shape::DocumentMinus }"),
+            (&mini::DOCUMENT_PLUS, "Icon { icon: mini::Shape::DocumentPlus }"),
+            (&mini::DOCUMENT_TEXT, "Icon { icon: mini::Shape::DocumentText }"),
+            (&mini::DOCUMENT, "Icon { icon: mini::Shape::Document }"),
+            (
+                &mini::ELLIPSIS_HORIZONTAL_CIRCLE,
+                "Icon { icon: mini::Shape::EllipsisHorizontalCircle }",
+            ),
+            (
+                &mini::ELLIPSIS_HORIZONTAL,
+                "Icon { icon: mini::Shape::EllipsisHorizontal }",
+            ),
+            (&mini::ELLIPSIS_VERTICAL, "Icon { icon: mini::Shape::EllipsisVertical }"),
+            (&mini::ENVELOPE_OPEN, "Icon { icon: mini::Shape::EnvelopeOpen }"),
+            (&mini::ENVELOPE, "Icon { icon: mini::Shape::Envelope }"),
+            (&mini::EXCLAMATION_CIRCLE, "Icon { icon: mini::Shape::ExclamationCircle }"),
+            (
+                &mini::EXCLAMATION_TRIANGLE,
+                "Icon { icon: mini::Shape::ExclamationTriangle }",
+            ),
+            (&mini::EYE_DROPPER, "Icon { icon: mini::Shape::EyeDropper }"),
+            (&mini::EYE_SLASH, "Icon { icon: mini::Shape::EyeSlash }"),
+            (&mini::EYE, "Icon { icon: mini::Shape::Eye }"),
+            (&mini::FACE_FROWN, "Icon { icon: mini::Shape::FaceFrown }"),
+            (&mini::FACE_SMILE, "Icon { icon: mini::Shape::FaceSmile }"),
+            (&mini::FILM, "Icon { icon: mini::Shape::Film }"),
+            (&mini::FINGER_PRINT, "Icon { icon: mini::Shape::FingerPrint }"),
+            (&mini::FIRE, "Icon { icon: mini::Shape::Fire }"),
+            (&mini::FLAG, "Icon { icon: mini::Shape::Flag }"),
+            (&mini::FOLDER_ARROW_DOWN, "Icon { icon: mini::Shape::FolderArrowDown }"),
+            (&mini::FOLDER_MINUS, "Icon { icon: mini::Shape::FolderMinus }"),
+            (&mini::FOLDER_OPEN, "Icon { icon: mini::Shape::FolderOpen }"),
+            (&mini::FOLDER_PLUS, "Icon { icon: mini::Shape::FolderPlus }"),
+            (&mini::FOLDER, "Icon { icon: mini::Shape::Folder }"),
+            (&mini::FORWARD, "Icon { icon: mini::Shape::Forward }"),
+            (&mini::FUNNEL, "Icon { icon: mini::Shape::Funnel }"),
+            (&mini::GIF, "Icon { icon: mini::Shape::Gif }"),
+            (&mini::GIFT_TOP, "Icon { icon: mini::Shape::GiftTop }"),
+            (&mini::GIFT, "Icon { icon: mini::Shape::Gift }"),
+            (&mini::GLOBE_ALT, "Icon { icon: mini::Shape::GlobeAlt }"),
+            (&mini::GLOBE_AMERICAS, "Icon { icon: mini::Shape::GlobeAmericas }"),
+            (
+                &mini::GLOBE_ASIA_AUSTRALIA,
+                "Icon { icon: mini::Shape::GlobeAsiaAustralia }",
+            ),
+            (
+                &mini::GLOBE_EUROPE_AFRICA,
+                "Icon { icon: mini::Shape::GlobeEuropeAfrica }",
+            ),
+            (&mini::HAND_RAISED, "Icon { icon: mini::Shape::HandRaised }"),
+            (&mini::HAND_THUMB_DOWN, "Icon { icon: mini::Shape::HandThumbDown }"),
+            (&mini::HAND_THUMB_UP, "Icon { icon: mini::Shape::HandThumbUp }"),
+            (&mini::HASHTAG, "Icon { icon: mini::Shape::Hashtag }"),
+            (&mini::HEART, "Icon { icon: mini::Shape::Heart }"),
+            (&mini::HOME_MODERN, "Icon { icon: mini::Shape::HomeModern }"),
+            (&mini::HOME, "Icon { icon: mini::Shape::Home }"),
+            (&mini::IDENTIFICATION, "Icon { icon: mini::Shape::Identification }"),
+            (&mini::INBOX_ARROW_DOWN, "Icon { icon: mini::Shape::InboxArrowDown }"),
+            (&mini::INBOX_STACK, "Icon { icon: mini::Shape::InboxStack }"),
+            (&mini::INBOX, "Icon { icon: mini::Shape::Inbox }"),
+            (&mini::INFORMATION_CIRCLE, "Icon { icon: mini::Shape::InformationCircle }"),
+            (&mini::KEY, "Icon { icon: mini::Shape::Key }"),
+            (&mini::LANGUAGE, "Icon { icon: mini::Shape::Language }"),
+            (&mini::LIFEBUOY, "Icon { icon: mini::Shape::Lifebuoy }"),
+            (&mini::LIGHT_BULB, "Icon { icon: mini::Shape::LightBulb }"),
+            (&mini::LINK, "Icon { icon: mini::Shape::Link }"),
+            (&mini::LIST_BULLET, "Icon { icon: mini::Shape::ListBullet }"),
+            (&mini::LOCK_CLOSED, "Icon { icon: mini::Shape::LockClosed }"),
+            (&mini::LOCK_OPEN, "Icon { icon: mini::Shape::LockOpen }"),
+            (
+                &mini::MAGNIFYING_GLASS_CIRCLE,
+                "Icon { icon: mini::Shape::MagnifyingGlassCircle }",
+            ),
+            (
+                &mini::MAGNIFYING_GLASS_MINUS,
+                "Icon { icon: mini::Shape::MagnifyingGlassMinus }",
+            ),
+            (
+                &mini::MAGNIFYING_GLASS_PLUS,
+                "Icon { icon: mini::Shape::MagnifyingGlassPlus }",
+            ),
+            (&mini::MAGNIFYING_GLASS, "Icon { icon: mini::Shape::MagnifyingGlass }"),
+            (&mini::MAP_PIN, "Icon { icon: mini::Shape::MapPin }"),
+            (&mini::MAP, "Icon { icon: mini::Shape::Map }"),
+            (&mini::MEGAPHONE, "Icon { icon: mini::Shape::Megaphone }"),
+            (&mini::MICROPHONE, "Icon { icon: mini::Shape::Microphone }"),
+            (&mini::MINUS_CIRCLE, "Icon { icon: mini::Shape::MinusCircle }"),
+            (&mini::MINUS_SMALL, "Icon { icon: mini::Shape::MinusSmall }"),
+            (&mini::MINUS, "Icon { icon: mini::Shape::Minus }"),
+            (&mini::MOON, "Icon { icon: mini::Shape::Moon }"),
+            (&mini::MUSICAL_NOTE, "Icon { icon: mini::Shape::MusicalNote }"),
+            (&mini::NEWSPAPER, "Icon { icon: mini::Shape::Newspaper }"),
+            (&mini::NO_SYMBOL, "Icon { icon: mini::Shape::NoSymbol }"),
+            (&mini::PAINT_BRUSH, "Icon { icon: mini::Shape::PaintBrush }"),
+            (&mini::PAPER_AIRPLANE, "Icon { icon: mini::Shape::PaperAirplane }"),
+            (&mini::PAPER_CLIP, "Icon { icon: mini::Shape::PaperClip }"),
+            (&mini::PAUSE_CIRCLE, "Icon { icon: mini::Shape::PauseCircle }"),
+            (&mini::PAUSE, "Icon { icon: mini::Shape::Pause }"),
+            (&mini::PENCIL_SQUARE, "Icon { icon: mini::Shape::PencilSquare }"),
+            (&mini::PENCIL, "Icon { icon: mini::Shape::Pencil }"),
+            (
+                &mini::PHONE_ARROW_DOWN_LEFT,
+                "Icon { icon: mini::Shape::PhoneArrowDownLeft }",
+            ),
+            (
+                &mini::PHONE_ARROW_UP_RIGHT,
+                "Icon { icon: mini::Shape::PhoneArrowUpRight }",
+            ),
+            (&mini::PHONE_X_MARK, "Icon { icon: mini::Shape::PhoneXMark }"),
+            (&mini::PHONE, "Icon { icon: mini::Shape::Phone }"),
+            (&mini::PHOTO, "Icon { icon: mini::Shape::Photo }"),
+            (&mini::PLAY_CIRCLE, "Icon { icon: mini::Shape::PlayCircle }"),
+            (&mini::PLAY_PAUSE, "Icon { icon: mini::Shape::PlayPause }"),
+            (&mini::PLAY, "Icon { icon: mini::Shape::Play }"),
+            (&mini::PLUS_CIRCLE, "Icon { icon: mini::Shape::PlusCircle }"),
+            (&mini::PLUS_SMALL, "Icon { icon: mini::Shape::PlusSmall }"),
+            (&mini::PLUS, "Icon { icon: mini::Shape::Plus }"),
+            (&mini::POWER, "Icon { icon: mini::Shape::Power }"),
+            (
+                &mini::PRESENTATION_CHART_BAR,
+                "Icon { icon: mini::Shape::PresentationChartBar }",
+            ),
+            (
+                &mini::PRESENTATION_CHART_LINE,
+                "Icon { icon: mini::Shape::PresentationChartLine }",
+            ),
+            (&mini::PRINTER, "Icon { icon: mini::Shape::Printer }"),
+            (&mini::PUZZLE_PIECE, "Icon { icon: mini::Shape::PuzzlePiece }"),
+            (&mini::QR_CODE, "Icon { icon: mini::Shape::QrCode }"),
+            (
+                &mini::QUESTION_MARK_CIRCLE,
+                "Icon { icon: mini::Shape::QuestionMarkCircle }",
+            ),
+            (&mini::QUEUE_LIST, "Icon { icon: mini::Shape::QueueList }"),
+            (&mini::RADIO, "Icon { icon: mini::Shape::Radio }"),
+            (&mini::RECEIPT_PERCENT, "Icon { icon: mini::Shape::ReceiptPercent }"),
+            (&mini::RECEIPT_REFUND, "Icon { icon: mini::Shape::ReceiptRefund }"),
+            (&mini::RECTANGLE_GROUP, "Icon { icon: mini::Shape::RectangleGroup }"),
+            (&mini::RECTANGLE_STACK, "Icon { icon: mini::Shape::RectangleStack }"),
+            (&mini::ROCKET_LAUNCH, "Icon { icon: mini::Shape::RocketLaunch }"),
+            (&mini::RSS, "Icon { icon: mini::Shape::Rss }"),
+            (&mini::SCALE, "Icon { icon: mini::Shape::Scale }"),
+            (&mini::SCISSORS, "Icon { icon: mini::Shape::Scissors }"),
+            (&mini::SERVER_STACK, "Icon { icon: mini::Shape::ServerStack }"),
+            (&mini::SERVER, "Icon { icon: mini::Shape::Server }"),
+            (&mini::SHARE, "Icon { icon: mini::Shape::Share }"),
+            (&mini::SHIELD_CHECK, "Icon { icon: mini::Shape::ShieldCheck }"),
+            (&mini::SHIELD_EXCLAMATION, "Icon { icon: mini::Shape::ShieldExclamation }"),
+            (&mini::SHOPPING_BAG, "Icon { icon: mini::Shape::ShoppingBag }"),
+            (&mini::SHOPPING_CART, "Icon { icon: mini::Shape::ShoppingCart }"),
+            (&mini::SIGNAL_SLASH, "Icon { icon: mini::Shape::SignalSlash }"),
+            (&mini::SIGNAL, "Icon { icon: mini::Shape::Signal }"),
+            (&mini::SPARKLES, "Icon { icon: mini::Shape::Sparkles }"),
+            (&mini::SPEAKER_WAVE, "Icon { icon: mini::Shape::SpeakerWave }"),
+            (&mini::SPEAKER_X_MARK, "Icon { icon: mini::Shape::SpeakerXMark }"),
+            (&mini::SQUARE_2_STACK, "Icon { icon: mini::Shape::Square2Stack }"),
+            (&mini::SQUARE_3_STACK_3D, "Icon { icon: mini::Shape::Square3Stack3d }"),
+            (&mini::SQUARES_2X2, "Icon { icon: mini::Shape::Squares2x2 }"),
+            (&mini::SQUARES_PLUS, "Icon { icon: mini::Shape::SquaresPlus }"),
+            (&mini::STAR, "Icon { icon: mini::Shape::Star }"),
+            (&mini::STOP_CIRCLE, "Icon { icon: mini::Shape::StopCircle }"),
+            (&mini::STOP, "Icon { icon: mini::Shape::Stop }"),
+            (&mini::SUN, "Icon { icon: mini::Shape::Sun }"),
+            (&mini::SWATCH, "Icon { icon: mini::Shape::Swatch }"),
+            (&mini::TABLE_CELLS, "Icon { icon: mini::Shape::TableCells }"),
+            (&mini::TAG, "Icon { icon: mini::Shape::Tag }"),
+            (&mini::TICKET, "Icon { icon: mini::Shape::Ticket }"),
+            (&mini::TRASH, "Icon { icon: mini::Shape::Trash }"),
+            (&mini::TROPHY, "Icon { icon: mini::Shape::Trophy }"),
+            (&mini::TRUCK, "Icon { icon: mini::Shape::Truck }"),
+            (&mini::TV, "Icon { icon: mini::Shape::Tv }"),
+            (&mini::USER_CIRCLE, "Icon { icon: mini::Shape::UserCircle }"),
+            (&mini::USER_GROUP, "Icon { icon: mini::Shape::UserGroup }"),
+            (&mini::USER_MINUS, "Icon { icon: mini::Shape::UserMinus }"),
+            (&mini::USER_PLUS, "Icon { icon: mini::Shape::UserPlus }"),
+            (&mini::USER, "Icon { icon: mini::Shape::User }"),
+            (&mini::USERS, "Icon { icon: mini::Shape::Users }"),
+            (&mini::VARIABLE, "Icon { icon: mini::Shape::Variable }"),
+            (&mini::VIDEO_CAMERA_SLASH, "Icon { icon: mini::Shape::VideoCameraSlash }"),
+            (&mini::VIDEO_CAMERA, "Icon { icon: mini::Shape::VideoCamera }"),
+            (&mini::VIEW_COLUMNS, "Icon { icon: mini::Shape::ViewColumns }"),
+            (&mini::VIEWFINDER_CIRCLE, "Icon { icon: mini::Shape::ViewfinderCircle }"),
+            (&mini::WALLET, "Icon { icon: mini::Shape::Wallet }"),
+            (&mini::WIFI, "Icon { icon: mini::Shape::Wifi }"),
+            (&mini::WINDOW, "Icon { icon: mini::Shape::Window }"),
+            (&mini::WRENCH_SCREWDRIVER, "Icon { icon: mini::Shape::WrenchScrewdriver }"),
+            (&mini::WRENCH, "Icon { icon: mini::Shape::Wrench }"),
+            (&mini::X_CIRCLE, "Icon { icon: mini::Shape::XCircle }"),
+            (&mini::X_MARK, "Icon { icon: mini::Shape::XMark }"),
+        ],
+    ),
+    (
+        "outline",
+        &[
+            (&outline::ACADEMIC_CAP, "Icon { icon: outline::Shape::AcademicCap }"),
+            (
+                &outline::ADJUSTMENTS_HORIZONTAL,
+                "Icon { icon: outline::Shape::AdjustmentsHorizontal }",
+            ),
+            (
+                &outline::ADJUSTMENTS_VERTICAL,
+                "Icon { icon: outline::Shape::AdjustmentsVertical }",
+            ),
+            (
+                &outline::ARCHIVE_BOX_ARROW_DOWN,
+                "Icon { icon: outline::Shape::ArchiveBoxArrowDown }",
+            ),
+            (
+                &outline::ARCHIVE_BOX_X_MARK,
+                "Icon { icon: outline::Shape::ArchiveBoxXMark }",
+            ),
+            (&outline::ARCHIVE_BOX, "Icon { icon: outline::Shape::ArchiveBox }"),
+            (
+                &outline::ARROW_DOWN_CIRCLE,
+                "Icon { icon: outline::Shape::ArrowDownCircle }",
+            ),
+            (&outline::ARROW_DOWN_LEFT, "Icon { icon: outline::Shape::ArrowDownLeft }"),
+            (
+                &outline::ARROW_DOWN_ON_SQUARE_STACK,
+                "Icon { icon: outline::Shape::ArrowDownOnSquareStack }",
+            ),
+            (
+                &outline::ARROW_DOWN_ON_SQUARE,
+                "Icon { icon: outline::Shape::ArrowDownOnSquare }",
+            ),
+            (
+                &outline::ARROW_DOWN_RIGHT,
+                "Icon { icon: outline::Shape::ArrowDownRight }",
+            ),
+            (&outline::ARROW_DOWN_TRAY, "Icon { icon: outline::Shape::ArrowDownTray }"),
+            (&outline::ARROW_DOWN, "Icon { icon: outline::Shape::ArrowDown }"),
+            (
+                &outline::ARROW_LEFT_CIRCLE,
+                "Icon { icon: outline::Shape::ArrowLeftCircle }",
+            ),
+            (
+                &outline::ARROW_LEFT_ON_RECTANGLE,
+                "Icon { icon: outline::Shape::ArrowLeftOnRectangle }",
+            ),
+            (&outline::ARROW_LEFT, "Icon { icon: outline::Shape::ArrowLeft }"),
+            (&outline::ARROW_LONG_DOWN, "Icon { icon: outline::Shape::ArrowLongDown }"),
+            (&outline::ARROW_LONG_LEFT, "Icon { icon: outline::Shape::ArrowLongLeft }"),
+            (
+                &outline::ARROW_LONG_RIGHT,
+                "Icon { icon: outline::Shape::ArrowLongRight }",
+            ),
+            (&outline::ARROW_LONG_UP, "Icon { icon: outline::Shape::ArrowLongUp }"),
+            (
+                &outline::ARROW_PATH_ROUNDED_SQUARE,
+                "Icon { icon: outline::Shape::ArrowPathRoundedSquare }",
+            ),
+            (&outline::ARROW_PATH, "Icon { icon: outline::Shape::ArrowPath }"),
+            (
+                &outline::ARROW_RIGHT_CIRCLE,
+                "Icon { icon: outline::Shape::ArrowRightCircle }",
+            ),
+            (
+                &outline::ARROW_RIGHT_ON_RECTANGLE,
+                "Icon { icon: outline::Shape::ArrowRightOnRectangle }",
+            ),
+            (&outline::ARROW_RIGHT, "Icon { icon: outline::Shape::ArrowRight }"),
+            (
+                &outline::ARROW_SMALL_DOWN,
+                "Icon { icon: outline::Shape::ArrowSmallDown }",
+            ),
+            (
+                &outline::ARROW_SMALL_LEFT,
+                "Icon { icon: outline::Shape::ArrowSmallLeft }",
+            ),
+            (
+                &outline::ARROW_SMALL_RIGHT,
+                "Icon { icon: outline::Shape::ArrowSmallRight }",
+            ),
+            (&outline::ARROW_SMALL_UP, "Icon { icon: outline::Shape::ArrowSmallUp }"),
+            (
+                &outline::ARROW_TOP_RIGHT_ON_SQUARE,
+                "Icon { icon: outline::Shape::ArrowTopRightOnSquare }",
+            ),
+            (
+                &outline::ARROW_TRENDING_DOWN,
+                "Icon { icon: outline::Shape::ArrowTrendingDown }",
+            ),
+            (
+                &outline::ARROW_TRENDING_UP,
+                "Icon { icon: outline::Shape::ArrowTrendingUp }",
+            ),
+            (&outline::ARROW_UP_CIRCLE, "Icon { icon: outline::Shape::ArrowUpCircle }"),
+            (&outline::ARROW_UP_LEFT, "Icon { icon: outline::Shape::ArrowUpLeft }"),
+            (
+                &outline::ARROW_UP_ON_SQUARE_STACK,
+                "Icon { icon: outline::Shape::ArrowUpOnSquareStack }",
+            ),
+            (
+                &outline::ARROW_UP_ON_SQUARE,
+                "Icon { icon: outline::Shape::ArrowUpOnSquare }",
+            ),
+            (&outline::ARROW_UP_RIGHT, "Icon { icon: outline::Shape::ArrowUpRight }"),
+            (&outline::ARROW_UP_TRAY, "Icon { icon: outline::Shape::ArrowUpTray }"),
+            (&outline::ARROW_UP, "Icon { icon: outline::Shape::ArrowUp }"),
+            (
+                &outline::ARROW_UTURN_DOWN,
+                "Icon { icon: outline::Shape::ArrowUturnDown }",
+            ),
+            (
+                &outline::ARROW_UTURN_LEFT,
+                "Icon { icon: outline::Shape::ArrowUturnLeft }",
+            ),
+            (
+                &outline::ARROW_UTURN_RIGHT,
+                "Icon { icon: outline::Shape::ArrowUturnRight }",
+            ),
+            (&outline::ARROW_UTURN_UP, "Icon { icon: outline::Shape::ArrowUturnUp }"),
+            (
+                &outline::ARROWS_POINTING_IN,
+                "Icon { icon: outline::Shape::ArrowsPointingIn }",
+            ),
+            (
+                &outline::ARROWS_POINTING_OUT,
+                "Icon { icon: outline::Shape::ArrowsPointingOut }",
+            ),
+            (
+                &outline::ARROWS_RIGHT_LEFT,
+                "Icon { icon: outline::Shape::ArrowsRightLeft }",
+            ),
+            (&outline::ARROWS_UP_DOWN, "Icon { icon: outline::Shape::ArrowsUpDown }"),
+            (&outline::AT_SYMBOL, "Icon { icon: outline::Shape::AtSymbol }"),
+            (&outline::BACKSPACE, "Icon { icon: outline::Shape::Backspace }"),
+            (&outline::BACKWARD, "Icon { icon: outline::Shape::Backward }"),
+            (&outline::BANKNOTES, "Icon { icon: outline::Shape::Banknotes }"),
+            (&outline::BARS_2, "Icon { icon: outline::Shape::Bars2 }"),
+            (
+                &outline::BARS_3_BOTTOM_LEFT,
+                "Icon { icon: outline::Shape::Bars3BottomLeft }",
+            ),
+            (
+                &outline::BARS_3_BOTTOM_RIGHT,
+                "Icon { icon: outline::Shape::Bars3BottomRight }",
+            ),
+            (
+                &outline::BARS_3_CENTER_LEFT,
+                "Icon { icon: outline::Shape::Bars3CenterLeft }",
+            ),
+            (&outline::BARS_3, "Icon { icon: outline::Shape::Bars3 }"),
+            (&outline::BARS_4, "Icon { icon: outline::Shape::Bars4 }"),
+            (&outline::BARS_ARROW_DOWN, "Icon { icon: outline::Shape::BarsArrowDown }"),
+            (&outline::BARS_ARROW_UP, "Icon { icon: outline::Shape::BarsArrowUp }"),
+            (&outline::BATTERY_0, "Icon { icon: outline::Shape::Battery0 }"),
+            (&outline::BATTERY_100, "Icon { icon: outline::Shape::Battery100 }"),
+            (&outline::BATTERY_50, "Icon { icon: outline::Shape::Battery50 }"),
+            (&outline::BEAKER, "Icon { icon: outline::Shape::Beaker }"),
+            (&outline::BELL_ALERT, "Icon { icon: outline::Shape::BellAlert }"),
+            (&outline::BELL_SLASH, "Icon { icon: outline::Shape::BellSlash }"),
+            (&outline::BELL_SNOOZE, "Icon { icon: outline::Shape::BellSnooze }"),
+            (&outline::BELL, "Icon { icon: outline::Shape::Bell }"),
+            (&outline::BOLT_SLASH, "Icon { icon: outline::Shape::BoltSlash }"),
+            (&outline::BOLT, "Icon { icon: outline::Shape::Bolt }"),
+            (&outline::BOOK_OPEN, "Icon { icon: outline::Shape::BookOpen }"),
+            (&outline::BOOKMARK_SLASH, "Icon { icon: outline::Shape::BookmarkSlash }"),
+            (&outline::BOOKMARK_SQUARE, "Icon { icon: outline::Shape::BookmarkSquare }"),
+            (&outline::BOOKMARK, "Icon { icon: outline::Shape::Bookmark }"),
+            (&outline::BRIEFCASE, "Icon { icon: outline::Shape::Briefcase }"),
+            (&outline::BUG_ANT, "Icon { icon: outline::Shape::BugAnt }"),
+            (
+                &outline::BUILDING_LIBRARY,
+                "Icon { icon: outline::Shape::BuildingLibrary }",
+            ),
+            (
+                &outline::BUILDING_OFFICE_2,
+                "Icon { icon: outline::Shape::BuildingOffice2 }",
+            ),
+            (&outline::BUILDING_OFFICE, "Icon { icon: outline::Shape::BuildingOffice }"),
+            (
+                &outline::BUILDING_STOREFRONT,
+                "Icon { icon: outline::Shape::BuildingStorefront }",
+            ),
+            (&outline::CAKE, "Icon { icon: outline::Shape::Cake }"),
+            (&outline::CALCULATOR, "Icon { icon: outline::Shape::Calculator }"),
+            (&outline::CALENDAR_DAYS, "Icon { icon: outline::Shape::CalendarDays }"),
+            (&outline::CALENDAR, "Icon { icon: outline::Shape::Calendar }"),
+            (&outline::CAMERA, "Icon { icon: outline::Shape::Camera }"),
+            (
+                &outline::CHART_BAR_SQUARE,
+                "Icon { icon: outline::Shape::ChartBarSquare }",
+            ),
+            (&outline::CHART_BAR, "Icon { icon: outline::Shape::ChartBar }"),
+            (&outline::CHART_PIE, "Icon { icon: outline::Shape::ChartPie }"),
+            (
+                &outline::CHAT_BUBBLE_BOTTOM_CENTER_TEXT,
+                "Icon { icon: outline::Shape::ChatBubbleBottomCenterText }",
+            ),
+            (
+                &outline::CHAT_BUBBLE_BOTTOM_CENTER,
+                "Icon { icon: outline::Shape::ChatBubbleBottomCenter }",
+            ),
+            (
+                &outline::CHAT_BUBBLE_LEFT_ELLIPSIS,
+                "Icon { icon: outline::Shape::ChatBubbleLeftEllipsis }",
+            ),
+            (
+                &outline::CHAT_BUBBLE_LEFT_RIGHT,
+                "Icon { icon: outline::Shape::ChatBubbleLeftRight }",
+            ),
+            (
+                &outline::CHAT_BUBBLE_LEFT,
+                "Icon { icon: outline::Shape::ChatBubbleLeft }",
+            ),
+            (
+                &outline::CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS,
+                "Icon { icon: outline::Shape::ChatBubbleOvalLeftEllipsis }",
+            ),
+            (
+                &outline::CHAT_BUBBLE_OVAL_LEFT,
+                "Icon { icon: outline::Shape::ChatBubbleOvalLeft }",
+            ),
+            (&outline::CHECK_BADGE, "Icon { icon: outline::Shape::CheckBadge }"),
+            (&outline::CHECK_CIRCLE, "Icon { icon: outline::Shape::CheckCircle }"),
+            (&outline::CHECK, "Icon { icon: outline::Shape::Check }"),
+            (
+                &outline::CHEVRON_DOUBLE_DOWN,
+                "Icon { icon: outline::Shape::ChevronDoubleDown }",
+            ),
+            (
+                &outline::CHEVRON_DOUBLE_LEFT,
+                "Icon { icon: outline::Shape::ChevronDoubleLeft }",
+            ),
+            (
+                &outline::CHEVRON_DOUBLE_RIGHT,
+                "Icon { icon: outline::Shape::ChevronDoubleRight }",
+            ),
+            (
+                &outline::CHEVRON_DOUBLE_UP,
+                "Icon { icon: outline::Shape::ChevronDoubleUp }",
+            ),
+            (&outline::CHEVRON_DOWN, "Icon { icon: outline::Shape::ChevronDown }"),
+            (&outline::CHEVRON_LEFT, "Icon { icon: outline::Shape::ChevronLeft }"),
+            (&outline::CHEVRON_RIGHT, "Icon { icon: outline::Shape::ChevronRight }"),
+            (&outline::CHEVRON_UP_DOWN, "Icon { icon: outline::Shape::ChevronUpDown }"),
+            (&outline::CHEVRON_UP, "Icon { icon: outline::Shape::ChevronUp }"),
+            (&outline::CIRCLE_STACK, "Icon { icon: outline::Shape::CircleStack }"),
+            (
+                &outline::CLIPBOARD_DOCUMENT_CHECK,
+                "Icon { icon: outline::Shape::ClipboardDocumentCheck }",
+            ),
+            (
+                &outline::CLIPBOARD_DOCUMENT_LIST,
+                "Icon { icon: outline::Shape::ClipboardDocumentList }",
+            ),
+            (
+                &outline::CLIPBOARD_DOCUMENT,
+                "Icon { icon: outline::Shape::ClipboardDocument }",
+            ),
+            (&outline::CLIPBOARD, "Icon { icon: outline::Shape::Clipboard }"),
+            (&outline::CLOCK, "Icon { icon: outline::Shape::Clock }"),
+            (
+                &outline::CLOUD_ARROW_DOWN,
+                "Icon { icon: outline::Shape::CloudArrowDown }",
+            ),
+            (&outline::CLOUD_ARROW_UP, "Icon { icon: outline::Shape::CloudArrowUp }"),
+            (&outline::CLOUD, "Icon { icon: outline::Shape::Cloud }"),
+            (
+                &outline::CODE_BRACKET_SQUARE,
+                "Icon { icon: outline::Shape::CodeBracketSquare }",
+            ),
+            (&outline::CODE_BRACKET, "Icon { icon: outline::Shape::CodeBracket }"),
+            (&outline::COG_6_TOOTH, "Icon { icon: outline::Shape::Cog6Tooth }"),
+            (&outline::COG_8_TOOTH, "Icon { icon: outline::Shape::Cog8Tooth }"),
+            (&outline::COG, "Icon { icon: outline::Shape::Cog }"),
+            (&outline::COMMAND_LINE, "Icon { icon: outline::Shape::CommandLine }"),
+            (
+                &outline::COMPUTER_DESKTOP,
+                "Icon { icon: outline::Shape::ComputerDesktop }",
+            ),
+            (&outline::CPU_CHIP, "Icon { icon: outline::Shape::CpuChip }"),
+            (&outline::CREDIT_CARD, "Icon { icon: outline::Shape::CreditCard }"),
+            (
+                &outline::CUBE_TRANSPARENT,
+                "Icon { icon: outline::Shape::CubeTransparent }",
+            ),
+            (&outline::CUBE, "Icon { icon: outline::Shape::Cube }"),
+            (
+                &outline::CURRENCY_BANGLADESHI,
+                "Icon { icon: outline::Shape::CurrencyBangladeshi }",
+            ),
+            (&outline::CURRENCY_DOLLAR, "Icon { icon: outline::Shape::CurrencyDollar }"),
+            (&outline::CURRENCY_EURO, "Icon { icon: outline::Shape::CurrencyEuro }"),
+            (&outline::CURRENCY_POUND, "Icon { icon: outline::Shape::CurrencyPound }"),
+            (&outline::CURRENCY_RUPEE, "Icon { icon: outline::Shape::CurrencyRupee }"),
+            (&outline::CURRENCY_YEN, "Icon { icon: outline::Shape::CurrencyYen }"),
+            (
+                &outline::CURSOR_ARROW_RAYS,
+                "Icon { icon: outline::Shape::CursorArrowRays }",
+            ),
+            (
+                &outline::CURSOR_ARROW_RIPPLE,
+                "Icon { icon: outline::Shape::CursorArrowRipple }",
+            ),
+            (
+                &outline::DEVICE_PHONE_MOBILE,
+                "Icon { icon: outline::Shape::DevicePhoneMobile }",
+            ),
+            (&outline::DEVICE_TABLET, "Icon { icon: outline::Shape::DeviceTablet }"),
+            (
+                &outline::DOCUMENT_ARROW_DOWN,
+                "Icon { icon: outline::Shape::DocumentArrowDown }",
+            ),
+            (
+                &outline::DOCUMENT_ARROW_UP,
+                "Icon { icon: outline::Shape::DocumentArrowUp }",
+            ),
+            (
+                &outline::DOCUMENT_CHART_BAR,
+                "Icon { icon: outline::Shape::DocumentChartBar }",
+            ),
+            (&outline::DOCUMENT_CHECK, "Icon { icon: outline::Shape::DocumentCheck }"),
+            (
+                &outline::DOCUMENT_DUPLICATE,
+                "Icon { icon: outline::Shape::DocumentDuplicate }",
+            ),
+            (
+                &outline::DOCUMENT_MAGNIFYING_GLASS,
+                "Icon { icon: outline::Shape::DocumentMagnifyingGlass }",
+            ),
+            (&outline::DOCUMENT_MINUS, "Icon { icon: outline::Shape::DocumentMinus }"),
+            (&outline::DOCUMENT_PLUS, "Icon { icon: outline::Shape::DocumentPlus }"),
+            (&outline::DOCUMENT_TEXT, "Icon { icon: outline::Shape::DocumentText }"),
+            (&outline::DOCUMENT, "Icon { icon: outline::Shape::Document }"),
+            (
+                &outline::ELLIPSIS_HORIZONTAL_CIRCLE,
+                "Icon { icon: outline::Shape::EllipsisHorizontalCircle }",
+            ),
+            (
+                &outline::ELLIPSIS_HORIZONTAL,
+                "Icon { icon: outline::Shape::EllipsisHorizontal }",
+            ),
+            (
+                &outline::ELLIPSIS_VERTICAL,
+                "Icon { icon: outline::Shape::EllipsisVertical }",
+            ),
+            (&outline::ENVELOPE_OPEN, "Icon { icon: outline::Shape::EnvelopeOpen }"),
+            (&outline::ENVELOPE, "Icon { icon: outline::Shape::Envelope }"),
+            (
+                &outline::EXCLAMATION_CIRCLE,
+                "Icon { icon: outline::Shape::ExclamationCircle }",
+            ),
+            (
+                &outline::EXCLAMATION_TRIANGLE,
+                "Icon { icon: outline::Shape::ExclamationTriangle }",
+            ),
+            (&outline::EYE_DROPPER, "Icon { icon: outline::Shape::EyeDropper }"),
+            (&outline::EYE_SLASH, "Icon { icon: outline::Shape::EyeSlash }"),
+            (&outline::EYE, "Icon { icon: outline::Shape::Eye }"),
+            (&outline::FACE_FROWN, "Icon { icon: outline::Shape::FaceFrown }"),
+            (&outline::FACE_SMILE, "Icon { icon: outline::Shape::FaceSmile }"),
+            (&outline::FILM, "Icon { icon: outline::Shape::Film }"),
+            (&outline::FINGER_PRINT, "Icon { icon: outline::Shape::FingerPrint }"),
+            (&outline::FIRE, "Icon { icon: outline::Shape::Fire }"),
+            (&outline::FLAG, "Icon { icon: outline::Shape::Flag }"),
+            (
+                &outline::FOLDER_ARROW_DOWN,
+                "Icon { icon: outline::Shape::FolderArrowDown }",
+            ),
+            (&outline::FOLDER_MINUS, "Icon { icon: outline::Shape::FolderMinus }"),
+            (&outline::FOLDER_OPEN, "Icon { icon: outline::Shape::FolderOpen }"),
+            (&outline::FOLDER_PLUS, "Icon { icon: outline::Shape::FolderPlus }"),
+            (&outline::FOLDER, "Icon { icon: outline::Shape::Folder }"),
+            (&outline::FORWARD, "Icon { icon: outline::Shape::Forward }"),
+            (&outline::FUNNEL, "Icon { icon: outline::Shape::Funnel }"),
+            (&outline::GIF, "Icon { icon: outline::Shape::Gif }"),
+            (&outline::GIFT_TOP, "Icon { icon: outline::Shape::GiftTop }"),
+            (&outline::GIFT, "Icon { icon: outline::Shape::Gift }"),
+            (&outline::GLOBE_ALT, "Icon { icon: outline::Shape::GlobeAlt }"),
+            (&outline::GLOBE_AMERICAS, "Icon { icon: outline::Shape::GlobeAmericas }"),
+            (
+                &outline::GLOBE_ASIA_AUSTRALIA,
+                "Icon { icon: outline::Shape::GlobeAsiaAustralia }",
+            ),
+            (
+                &outline::GLOBE_EUROPE_AFRICA,
+                "Icon { icon: outline::Shape::GlobeEuropeAfrica }",
+            ),
+            (&outline::HAND_RAISED, "Icon { icon: outline::Shape::HandRaised }"),
+            (&outline::HAND_THUMB_DOWN, "Icon { icon: outline::Shape::HandThumbDown }"),
+            (&outline::HAND_THUMB_UP, "Icon { icon: outline::Shape::HandThumbUp }"),
+            (&outline::HASHTAG, "Icon { icon: outline::Shape::Hashtag }"),
+            (&outline::HEART, "Icon { icon: outline::Shape::Heart }"),
+            (&outline::HOME_MODERN, "Icon { icon: outline::Shape::HomeModern }"),
+            (&outline::HOME, "Icon { icon: outline::Shape::Home }"),
+            (&outline::IDENTIFICATION, "Icon { icon: outline::Shape::Identification }"),
+            (
+                &outline::INBOX_ARROW_DOWN,
+                "Icon { icon: outline::Shape::InboxArrowDown }",
+            ),
+            (&outline::INBOX_STACK, "Icon { icon: outline::Shape::InboxStack }"),
+            (&outline::INBOX, "Icon { icon: outline::Shape::Inbox }"),
+            (
+                &outline::INFORMATION_CIRCLE,
+                "Icon { icon: outline::Shape::InformationCircle }",
+            ),
+            (&outline::KEY, "Icon { icon: outline::Shape::Key }"),
+            (&outline::LANGUAGE, "Icon { icon: outline::Shape::Language }"),
+            (&outline::LIFEBUOY, "Icon { icon: outline::Shape::Lifebuoy }"),
+            (&outline::LIGHT_BULB, "Icon { icon: outline::Shape::LightBulb }"),
+            (&outline::LINK, "Icon { icon: outline::Shape::Link }"),
+            (&outline::LIST_BULLET, "Icon { icon: outline::Shape::ListBullet }"),
+            (&outline::LOCK_CLOSED, "Icon { icon: outline::Shape::LockClosed }"),
+            (&outline::LOCK_OPEN, "Icon { icon: outline::Shape::LockOpen }"),
+            (
+                &outline::MAGNIFYING_GLASS_CIRCLE,
+                "Icon { icon: outline::Shape::MagnifyingGlassCircle }",
+            ),
+            (
+                &outline::MAGNIFYING_GLASS_MINUS,
+                "Icon { icon: outline::Shape::MagnifyingGlassMinus }",
+            ),
+            (
+                &outline::MAGNIFYING_GLASS_PLUS,
+                "Icon { icon: outline::Shape::MagnifyingGlassPlus }",
+            ),
+            (
+                &outline::MAGNIFYING_GLASS,
+                "Icon { icon: outline::Shape::MagnifyingGlass }",
+            ),
+            (&outline::MAP_PIN, "Icon { icon: outline::Shape::MapPin }"),
+            (&outline::MAP, "Icon { icon: outline::Shape::Map }"),
+            (&outline::MEGAPHONE, "Icon { icon: outline::Shape::Megaphone }"),
+            (&outline::MICROPHONE, "Icon { icon: outline::Shape::Microphone }"),
+            (&outline::MINUS_CIRCLE, "Icon { icon: outline::Shape::MinusCircle }"),
+            (&outline::MINUS_SMALL, "Icon { icon: outline::Shape::MinusSmall }"),
+            (&outline::MINUS, "Icon { icon: outline::Shape::Minus }"),
+            (&outline::MOON, "Icon { icon: outline::Shape::Moon }"),
+            (&outline::MUSICAL_NOTE, "Icon { icon: outline::Shape::MusicalNote }"),
+            (&outline::NEWSPAPER, "Icon { icon: outline::Shape::Newspaper }"),
+            (&outline::NO_SYMBOL, "Icon { icon: outline::Shape::NoSymbol }"),
+            (&outline::PAINT_BRUSH, "Icon { icon: outline::Shape::PaintBrush }"),
+            (&outline::PAPER_AIRPLANE, "Icon { icon: outline::Shape::PaperAirplane }"),
+            (&outline::PAPER_CLIP, "Icon { icon: outline::Shape::PaperClip }"),
+            (&outline::PAUSE_CIRCLE, "Icon { icon: outline::Shape::PauseCircle }"),
+            (&outline::PAUSE, "Icon { icon: outline::Shape::Pause }"),
+            (&outline::PENCIL_SQUARE, "Icon { icon: outline::Shape::PencilSquare }"),
+            (&outline::PENCIL, "Icon { icon: outline::Shape::Pencil }"),
+            (
+                &outline::PHONE_ARROW_DOWN_LEFT,
+                "Icon { icon: outline::Shape::PhoneArrowDownLeft }",
+            ),
+            (
+                &outline::PHONE_ARROW_UP_RIGHT,
+                "Icon { icon: outline::Shape::PhoneArrowUpRight }",
+            ),
+            (&outline::PHONE_X_MARK, "Icon { icon: outline::Shape::PhoneXMark }"),
+            (&outline::PHONE, "Icon { icon: outline::Shape::Phone }"),
+            (&outline::PHOTO, "Icon { icon: outline::Shape::Photo }"),
+            (&outline::PLAY_CIRCLE, "Icon { icon: outline::Shape::PlayCircle }"),
+            (&outline::PLAY_PAUSE, "Icon { icon: outline::Shape::PlayPause }"),
+            (&outline::PLAY, "Icon { icon: outline::Shape::Play }"),
+            (&outline::PLUS_CIRCLE, "Icon { icon: outline::Shape::PlusCircle }"),
+            (&outline::PLUS_SMALL, "Icon { icon: outline::Shape::PlusSmall }"),
+            (&outline::PLUS, "Icon { icon: outline::Shape::Plus }"),
+            (&outline::POWER, "Icon { icon: outline::Shape::Power }"),
+            (
+                &outline::PRESENTATION_CHART_BAR,
+                "Icon { icon: outline::Shape::PresentationChartBar }",
+            ),
+            (
+                &outline::PRESENTATION_CHART_LINE,
+                "Icon { icon: outline::Shape::PresentationChartLine }",
+            ),
+            (&outline::PRINTER, "Icon { icon: outline::Shape::Printer }"),
+            (&outline::PUZZLE_PIECE, "Icon { icon: outline::Shape::PuzzlePiece }"),
+            (&outline::QR_CODE, "Icon { icon: outline::Shape::QrCode }"),
+            (
+                &outline::QUESTION_MARK_CIRCLE,
+                "Icon { icon: outline::Shape::QuestionMarkCircle }",
+            ),
+            (&outline::QUEUE_LIST, "Icon { icon: outline::Shape::QueueList }"),
+            (&outline::RADIO, "Icon { icon: outline::Shape::Radio }"),
+            (&outline::RECEIPT_PERCENT, "Icon { icon: outline::Shape::ReceiptPercent }"),
+            (&outline::RECEIPT_REFUND, "Icon { icon: outline::Shape::ReceiptRefund }"),
+            (&outline::RECTANGLE_GROUP, "Icon { icon: outline::Shape::RectangleGroup }"),
+            (&outline::RECTANGLE_STACK, "Icon { icon: outline::Shape::RectangleStack }"),
+            (&outline::ROCKET_LAUNCH, "Icon { icon: outline::Shape::RocketLaunch }"),
+            (&outline::RSS, "Icon { icon: outline::Shape::Rss }"),
+            (&outline::SCALE, "Icon { icon: outline::Shape::Scale }"),
+            (&outline::SCISSORS, "Icon { icon: outline::Shape::Scissors }"),
+            (&outline::SERVER_STACK, "Icon { icon: outline::Shape::ServerStack }"),
+            (&outline::SERVER, "Icon { icon: outline::Shape::Server }"),
+            (&outline::SHARE, "Icon { icon: outline::Shape::Share }"),
+            (&outline::SHIELD_CHECK, "Icon { icon: outline::Shape::ShieldCheck }"),
+            (
+                &outline::SHIELD_EXCLAMATION,
+                "Icon { icon: outline::Shape::ShieldExclamation }",
+            ),
+            (&outline::SHOPPING_BAG, "Icon { icon: outline::Shape::ShoppingBag }"),
+            (&outline::SHOPPING_CART, "Icon { icon: outline::Shape::ShoppingCart }"),
+            (&outline::SIGNAL_SLASH, "Icon { icon: outline::Shape::SignalSlash }"),
+            (&outline::SIGNAL, "Icon { icon: outline::Shape::Signal }"),
+            (&outline::SPARKLES, "Icon { icon: outline::Shape::Sparkles }"),
+            (&outline::SPEAKER_WAVE, "Icon { icon: outline::Shape::SpeakerWave }"),
+            (&outline::SPEAKER_X_MARK, "Icon { icon: outline::Shape::SpeakerXMark }"),
+            (&outline::SQUARE_2_STACK, "Icon { icon: outline::Shape::Square2Stack }"),
+            (
+                &outline::SQUARE_3_STACK_3D,
+                "Icon { icon: outline::Shape::Square3Stack3d }",
+            ),
+            (&outline::SQUARES_2X2, "Icon { icon: outline::Shape::Squares2x2 }"),
+            (&outline::SQUARES_PLUS, "Icon { icon: outline::Shape::SquaresPlus }"),
+            (&outline::STAR, "Icon { icon: outline::Shape::Star }"),
+            (&outline::STOP_CIRCLE, "Icon { icon: outline::Shape::StopCircle }"),
+            (&outline::STOP, "Icon { icon: outline::Shape::Stop }"),
+            (&outline::SUN, "Icon { icon: outline::Shape::Sun }"),
+            (&outline::SWATCH, "Icon { icon: outline::Shape::Swatch }"),
+            (&outline::TABLE_CELLS, "Icon { icon: outline::Shape::TableCells }"),
+            (&outline::TAG, "Icon { icon: outline::Shape::Tag }"),
+            (&outline::TICKET, "Icon { icon: outline::Shape::Ticket }"),
+            (&outline::TRASH, "Icon { icon: outline::Shape::Trash }"),
+            (&outline::TROPHY, "Icon { icon: outline::Shape::Trophy }"),
+            (&outline::TRUCK, "Icon { icon: outline::Shape::Truck }"),
+            (&outline::TV, "Icon { icon: outline::Shape::Tv }"),
+            (&outline::USER_CIRCLE, "Icon { icon: outline::Shape::UserCircle }"),
+            (&outline::USER_GROUP, "Icon { icon: outline::Shape::UserGroup }"),
+            (&outline::USER_MINUS, "Icon { icon: outline::Shape::UserMinus }"),
+            (&outline::USER_PLUS, "Icon { icon: outline::Shape::UserPlus }"),
+            (&outline::USER, "Icon { icon: outline::Shape::User }"),
+            (&outline::USERS, "Icon { icon: outline::Shape::Users }"),
+            (&outline::VARIABLE, "Icon { icon: outline::Shape::Variable }"),
+            (
+                &outline::VIDEO_CAMERA_SLASH,
+                "Icon { icon: outline::Shape::VideoCameraSlash }",
+            ),
+            (&outline::VIDEO_CAMERA, "Icon { icon: outline::Shape::VideoCamera }"),
+            (&outline::VIEW_COLUMNS, "Icon { icon: outline::Shape::ViewColumns }"),
+            (
+                &outline::VIEWFINDER_CIRCLE,
+                "Icon { icon: outline::Shape::ViewfinderCircle }",
+            ),
+            (&outline::WALLET, "Icon { icon: outline::Shape::Wallet }"),
+            (&outline::WIFI, "Icon { icon: outline::Shape::Wifi }"),
+            (&outline::WINDOW, "Icon { icon: outline::Shape::Window }"),
+            (
+                &outline::WRENCH_SCREWDRIVER,
+                "Icon { icon: outline::Shape::WrenchScrewdriver }",
+            ),
+            (&outline::WRENCH, "Icon { icon: outline::Shape::Wrench }"),
+            (&outline::X_CIRCLE, "Icon { icon: outline::Shape::XCircle }"),
+            (&outline::X_MARK, "Icon { icon: outline::Shape::XMark }"),
+        ],
+    ),
+    (
+        "solid",
+        &[
+            (&solid::ACADEMIC_CAP, "Icon { icon: solid::Shape::AcademicCap }"),
+            (
+                &solid::ADJUSTMENTS_HORIZONTAL,
+                "Icon { icon: solid::Shape::AdjustmentsHorizontal }",
+            ),
+            (
+                &solid::ADJUSTMENTS_VERTICAL,
+                "Icon { icon: solid::Shape::AdjustmentsVertical }",
+            ),
+            (
+                &solid::ARCHIVE_BOX_ARROW_DOWN,
+                "Icon { icon: solid::Shape::ArchiveBoxArrowDown }",
+            ),
+            (&solid::ARCHIVE_BOX_X_MARK, "Icon { icon: solid::Shape::ArchiveBoxXMark }"),
+            (&solid::ARCHIVE_BOX, "Icon { icon: solid::Shape::ArchiveBox }"),
+            (&solid::ARROW_DOWN_CIRCLE, "Icon { icon: solid::Shape::ArrowDownCircle }"),
+            (&solid::ARROW_DOWN_LEFT, "Icon { icon: solid::Shape::ArrowDownLeft }"),
+            (
+                &solid::ARROW_DOWN_ON_SQUARE_STACK,
+                "Icon { icon: solid::Shape::ArrowDownOnSquareStack }",
+            ),
+            (
+                &solid::ARROW_DOWN_ON_SQUARE,
+                "Icon { icon: solid::Shape::ArrowDownOnSquare }",
+            ),
+            (&solid::ARROW_DOWN_RIGHT, "Icon { icon: solid::Shape::ArrowDownRight }"),
+            (&solid::ARROW_DOWN_TRAY, "Icon { icon: solid::Shape::ArrowDownTray }"),
+            (&solid::ARROW_DOWN, "Icon { icon: solid::Shape::ArrowDown }"),
+            (&solid::ARROW_LEFT_CIRCLE, "Icon { icon: solid::Shape::ArrowLeftCircle }"),
+            (
+                &solid::ARROW_LEFT_ON_RECTANGLE,
+                "Icon { icon: solid::Shape::ArrowLeftOnRectangle }",
+            ),
+            (&solid::ARROW_LEFT, "Icon { icon: solid::Shape::ArrowLeft }"),
+            (&solid::ARROW_LONG_DOWN, "Icon { icon: solid::Shape::ArrowLongDown }"),
+            (&solid::ARROW_LONG_LEFT, "Icon { icon: solid::Shape::ArrowLongLeft }"),
+            (&solid::ARROW_LONG_RIGHT, "Icon { icon: solid::Shape::ArrowLongRight }"),
+            (&solid::ARROW_LONG_UP, "Icon { icon: solid::Shape::ArrowLongUp }"),
+            (
+                &solid::ARROW_PATH_ROUNDED_SQUARE,
+                "Icon { icon: solid::Shape::ArrowPathRoundedSquare }",
+            ),
+            (&solid::ARROW_PATH, "Icon { icon: solid::Shape::ArrowPath }"),
+            (
+                &solid::ARROW_RIGHT_CIRCLE,
+                "Icon { icon: solid::Shape::ArrowRightCircle }",
+            ),
+            (
+                &solid::ARROW_RIGHT_ON_RECTANGLE,
+                "Icon { icon: solid::Shape::ArrowRightOnRectangle }",
+            ),
+            (&solid::ARROW_RIGHT, "Icon { icon: solid::Shape::ArrowRight }"),
+            (&solid::ARROW_SMALL_DOWN, "Icon { icon: solid::Shape::ArrowSmallDown }"),
+            (&solid::ARROW_SMALL_LEFT, "Icon { icon: solid::Shape::ArrowSmallLeft }"),
+            (&solid::ARROW_SMALL_RIGHT, "Icon { icon: solid::Shape::ArrowSmallRight }"),
+            (&solid::ARROW_SMALL_UP, "Icon { icon: solid::Shape::ArrowSmallUp }"),
+            (
+                &solid::ARROW_TOP_RIGHT_ON_SQUARE,
+                "Icon { icon: solid::Shape::ArrowTopRightOnSquare }",
+            ),
+            (
+                &solid::ARROW_TRENDING_DOWN,
+                "Icon { icon: solid::Shape::ArrowTrendingDown }",
+            ),
+            (&solid::ARROW_TRENDING_UP, "Icon { icon: solid::Shape::ArrowTrendingUp }"),
+            (&solid::ARROW_UP_CIRCLE, "Icon { icon: solid::Shape::ArrowUpCircle }"),
+            (&solid::ARROW_UP_LEFT, "Icon { icon: solid::Shape::ArrowUpLeft }"),
+            (
+                &solid::ARROW_UP_ON_SQUARE_STACK,
+                "Icon { icon: solid::Shape::ArrowUpOnSquareStack }",
+            ),
+            (&solid::ARROW_UP_ON_SQUARE, "Icon { icon: solid::Shape::ArrowUpOnSquare }"),
+            (&solid::ARROW_UP_RIGHT, "Icon { icon: solid::Shape::ArrowUpRight }"),
+            (&solid::ARROW_UP_TRAY, "Icon { icon: solid::Shape::ArrowUpTray }"),
+            (&solid::ARROW_UP, "Icon { icon: solid::Shape::ArrowUp }"),
+            (&solid::ARROW_UTURN_DOWN, "Icon { icon: solid::Shape::ArrowUturnDown }"),
+            (&solid::ARROW_UTURN_LEFT, "Icon { icon: solid::Shape::ArrowUturnLeft }"),
+            (&solid::ARROW_UTURN_RIGHT, "Icon { icon: solid::Shape::ArrowUturnRight }"),
+            (&solid::ARROW_UTURN_UP, "Icon { icon: solid::Shape::ArrowUturnUp }"),
+            (
+                &solid::ARROWS_POINTING_IN,
+                "Icon { icon: solid::Shape::ArrowsPointingIn }",
+            ),
+            (
+                &solid::ARROWS_POINTING_OUT,
+                "Icon { icon: solid::Shape::ArrowsPointingOut }",
+            ),
+            (&solid::ARROWS_RIGHT_LEFT, "Icon { icon: solid::Shape::ArrowsRightLeft }"),
+            (&solid::ARROWS_UP_DOWN, "Icon { icon: solid::Shape::ArrowsUpDown }"),
+            (&solid::AT_SYMBOL, "Icon { icon: solid::Shape::AtSymbol }"),
+            (&solid::BACKSPACE, "Icon { icon: solid::Shape::Backspace }"),
+            (&solid::BACKWARD, "Icon { icon: solid::Shape::Backward }"),
+            (&solid::BANKNOTES, "Icon { icon: solid::Shape::Banknotes }"),
+            (&solid::BARS_2, "Icon { icon: solid::Shape::Bars2 }"),
+            (&solid::BARS_3_BOTTOM_LEFT, "Icon { icon: solid::Shape::Bars3BottomLeft }"),
+            (
+                &solid::BARS_3_BOTTOM_RIGHT,
+                "Icon { icon: solid::Shape::Bars3BottomRight }",
+            ),
+            (&solid::BARS_3_CENTER_LEFT, "Icon { icon: solid::Shape::Bars3CenterLeft }"),
+            (&solid::BARS_3, "Icon { icon: solid::Shape::Bars3 }"),
+            (&solid::BARS_4, "Icon { icon: solid::Shape::Bars4 }"),
+            (&solid::BARS_ARROW_DOWN, "Icon { icon: solid::Shape::BarsArrowDown }"),
+            (&solid::BARS_ARROW_UP, "Icon { icon: solid::Shape::BarsArrowUp }"),
+            (&solid::BATTERY_0, "Icon { icon: solid::Shape::Battery0 }"),
+            (&solid::BATTERY_100, "Icon { icon: solid::Shape::Battery100 }"),
+            (&solid::BATTERY_50, "Icon { icon: solid::Shape::Battery50 }"),
+            (&solid::BEAKER, "Icon { icon: solid::Shape::Beaker }"),
+            (&solid::BELL_ALERT, "Icon { icon: solid::Shape::BellAlert }"),
+            (&solid::BELL_SLASH, "Icon { icon: solid::Shape::BellSlash }"),
+            (&solid::BELL_SNOOZE, "Icon { icon: solid::Shape::BellSnooze }"),
+            (&solid::BELL, "Icon { icon: solid::Shape::Bell }"),
+            (&solid::BOLT_SLASH, "Icon { icon: solid::Shape::BoltSlash }"),
+            (&solid::BOLT, "Icon { icon: solid::Shape::Bolt }"),
+            (&solid::BOOK_OPEN, "Icon { icon: solid::Shape::BookOpen }"),
+            (&solid::BOOKMARK_SLASH, "Icon { icon: solid::Shape::BookmarkSlash }"),
+            (&solid::BOOKMARK_SQUARE, "Icon { icon: solid::Shape::BookmarkSquare }"),
+            (&solid::BOOKMARK, "Icon { icon: solid::Shape::Bookmark }"),
+            (&solid::BRIEFCASE, "Icon { icon: solid::Shape::Briefcase }"),
+            (&solid::BUG_ANT, "Icon { icon: solid::Shape::BugAnt }"),
+            (&solid::BUILDING_LIBRARY, "Icon { icon: solid::Shape::BuildingLibrary }"),
+            (&solid::BUILDING_OFFICE_2, "Icon { icon: solid::Shape::BuildingOffice2 }"),
+            (&solid::BUILDING_OFFICE, "Icon { icon: solid::Shape::BuildingOffice }"),
+            (
+                &solid::BUILDING_STOREFRONT,
+                "Icon { icon: solid::Shape::BuildingStorefront }",
+            ),
+            (&solid::CAKE, "Icon { icon: solid::Shape::Cake }"),
+            (&solid::CALCULATOR, "Icon { icon: solid::Shape::Calculator }"),
+            (&solid::CALENDAR_DAYS, "Icon { icon: solid::Shape::CalendarDays }"),
+            (&solid::CALENDAR, "Icon { icon: solid::Shape::Calendar }"),
+            (&solid::CAMERA, "Icon { icon: solid::Shape::Camera }"),
+            (&solid::CHART_BAR_SQUARE, "Icon { icon: solid::Shape::ChartBarSquare }"),
+            (&solid::CHART_BAR, "Icon { icon: solid::Shape::ChartBar }"),
+            (&solid::CHART_PIE, "Icon { icon: solid::Shape::ChartPie }"),
+            (
+                &solid::CHAT_BUBBLE_BOTTOM_CENTER_TEXT,
+                "Icon { icon: solid::Shape::ChatBubbleBottomCenterText }",
+            ),
+            (
+                &solid::CHAT_BUBBLE_BOTTOM_CENTER,
+                "Icon { icon: solid::Shape::ChatBubbleBottomCenter }",
+            ),
+            (
+                &solid::CHAT_BUBBLE_LEFT_ELLIPSIS,
+                "Icon { icon: solid::Shape::ChatBubbleLeftEllipsis }",
+            ),
+            (
+                &solid::CHAT_BUBBLE_LEFT_RIGHT,
+                "Icon { icon: solid::Shape::ChatBubbleLeftRight }",
+            ),
+            (&solid::CHAT_BUBBLE_LEFT, "Icon { icon: solid::Shape::ChatBubbleLeft }"),
+            (
+                &solid::CHAT_BUBBLE_OVAL_LEFT_ELLIPSIS,
+                "Icon { icon: solid::Shape::ChatBubbleOvalLeftEllipsis }",
+            ),
+            (
+                &solid::CHAT_BUBBLE_OVAL_LEFT,
+                "Icon { icon: solid::Shape::ChatBubbleOvalLeft }",
+            ),
+            (&solid::CHECK_BADGE, "Icon { icon: solid::Shape::CheckBadge }"),
+            (&solid::CHECK_CIRCLE, "Icon { icon: solid::Shape::CheckCircle }"),
+            (&solid::CHECK, "Icon { icon: solid::Shape::Check }"),
+            (
+                &solid::CHEVRON_DOUBLE_DOWN,
+                "Icon { icon: solid::Shape::ChevronDoubleDown }",
+            ),
+            (
+                &solid::CHEVRON_DOUBLE_LEFT,
+                "Icon { icon: solid::Shape::ChevronDoubleLeft }",
+            ),
+            (
+                &solid::CHEVRON_DOUBLE_RIGHT,
+                "Icon { icon: solid::Shape::ChevronDoubleRight }",
+            ),
+            (&solid::CHEVRON_DOUBLE_UP, "Icon { icon: solid::Shape::ChevronDoubleUp }"),
+            (&solid::CHEVRON_DOWN, "Icon { icon: solid::Shape::ChevronDown }"),
+            (&solid::CHEVRON_LEFT, "Icon { icon: solid::Shape::ChevronLeft }"),
+            (&solid::CHEVRON_RIGHT, "Icon { icon: solid::Shape::ChevronRight }"),
+            (&solid::CHEVRON_UP_DOWN, "Icon { icon: solid::Shape::ChevronUpDown }"),
+            (&solid::CHEVRON_UP, "Icon { icon: solid::Shape::ChevronUp }"),
+            (&solid::CIRCLE_STACK, "Icon { icon: solid::Shape::CircleStack }"),
+            (
+                &solid::CLIPBOARD_DOCUMENT_CHECK,
+                "Icon { icon: solid::Shape::ClipboardDocumentCheck }",
+            ),
+            (
+                &solid::CLIPBOARD_DOCUMENT_LIST,
+                "Icon { icon: solid::Shape::ClipboardDocumentList }",
+            ),
+            (
+                &solid::CLIPBOARD_DOCUMENT,
+                "Icon { icon: solid::Shape::ClipboardDocument }",
+            ),
+            (&solid::CLIPBOARD, "Icon { icon: solid::Shape::Clipboard }"),
+            (&solid::CLOCK, "Icon { icon: solid::Shape::Clock }"),
+            (&solid::CLOUD_ARROW_DOWN, "Icon { icon: solid::Shape::CloudArrowDown }"),
+            (&solid::CLOUD_ARROW_UP, "Icon { icon: solid::Shape::CloudArrowUp }"),
+            (&solid::CLOUD, "Icon { icon: solid::Shape::Cloud }"),
+            (
+                &solid::CODE_BRACKET_SQUARE,
+                "Icon { icon: solid::Shape::CodeBracketSquare }",
+            ),
+            (&solid::CODE_BRACKET, "Icon { icon: solid::Shape::CodeBracket }"),
+            (&solid::COG_6_TOOTH, "Icon { icon: solid::Shape::Cog6Tooth }"),
+            (&solid::COG_8_TOOTH, "Icon { icon: solid::Shape::Cog8Tooth }"),
+            (&solid::COG, "Icon { icon: solid::Shape::Cog }"),
+            (&solid::COMMAND_LINE, "Icon { icon: solid::Shape::CommandLine }"),
+            (&solid::COMPUTER_DESKTOP, "Icon { icon: solid::Shape::ComputerDesktop }"),
+            (&solid::CPU_CHIP, "Icon { icon: solid::Shape::CpuChip }"),
+            (&solid::CREDIT_CARD, "Icon { icon: solid::Shape::CreditCard }"),
+            (&solid::CUBE_TRANSPARENT, "Icon { icon: solid::Shape::CubeTransparent }"),
+            (&solid::CUBE, "Icon { icon: solid::Shape::Cube }"),
+            (
+                &solid::CURRENCY_BANGLADESHI,
+                "Icon { icon: solid::Shape::CurrencyBangladeshi }",
+            ),
+            (&solid::CURRENCY_DOLLAR, "Icon { icon: solid::Shape::CurrencyDollar }"),
+            (&solid::CURRENCY_EURO, "Icon { icon: solid::Shape::CurrencyEuro }"),
+            (&solid::CURRENCY_POUND, "Icon { icon: solid::Shape::CurrencyPound }"),
+            (&solid::CURRENCY_RUPEE, "Icon { icon: solid::Shape::CurrencyRupee }"),
+            (&solid::CURRENCY_YEN, "Icon { icon: solid::Shape::CurrencyYen }"),
+            (&solid::CURSOR_ARROW_RAYS, "Icon { icon: solid::Shape::CursorArrowRays }"),
+            (
+                &solid::CURSOR_ARROW_RIPPLE,
+                "Icon { icon: solid::Shape::CursorArrowRipple }",
+            ),
+            (
+                &solid::DEVICE_PHONE_MOBILE,
+                "Icon { icon: solid::Shape::DevicePhoneMobile }",
+            ),
+            (&solid::DEVICE_TABLET, "Icon { icon: solid::Shape::DeviceTablet }"),
+            (
+                &solid::DOCUMENT_ARROW_DOWN,
+                "Icon { icon: solid::Shape::DocumentArrowDown }",
+            ),
+            (&solid::DOCUMENT_ARROW_UP, "Icon { icon: solid::Shape::DocumentArrowUp }"),
+            (
+                &solid::DOCUMENT_CHART_BAR,
+                "Icon { icon: solid::Shape::DocumentChartBar }",
+            ),
+            (&solid::DOCUMENT_CHECK, "Icon { icon: solid::Shape::DocumentCheck }"),
+            (
+                &solid::DOCUMENT_DUPLICATE,
+                "Icon { icon: solid::Shape::DocumentDuplicate }",
+            ),
+            (
+                &solid::DOCUMENT_MAGNIFYING_GLASS,
+                "Icon { icon: solid::Shape::DocumentMagnifyingGlass }",
+            ),
+            (&solid::DOCUMENT_MINUS, "Icon { icon: solid::Shape::DocumentMinus }"),
+            (&solid::DOCUMENT_PLUS, "Icon { icon: solid::Shape::DocumentPlus }"),
+            (&solid::DOCUMENT_TEXT, "Icon { icon: solid::Shape::DocumentText }"),
+            (&solid::DOCUMENT, "Icon { icon: solid::Shape::Document }"),
+            (
+                &solid::ELLIPSIS_HORIZONTAL_CIRCLE,
+                "Icon { icon: solid::Shape::EllipsisHorizontalCircle }",
+            ),
+            (
+                &solid::ELLIPSIS_HORIZONTAL,
+                "Icon { icon: solid::Shape::EllipsisHorizontal }",
+            ),
+            (&solid::ELLIPSIS_VERTICAL, "Icon { icon: solid::Shape::EllipsisVertical }"),
+            (&solid::ENVELOPE_OPEN, "Icon { icon: solid::Shape::EnvelopeOpen }"),
+            (&solid::ENVELOPE, "Icon { icon: solid::Shape::Envelope }"),
+            (
+                &solid::EXCLAMATION_CIRCLE,
+                "Icon { icon: solid::Shape::ExclamationCircle }",
+            ),
+            (
+                &solid::EXCLAMATION_TRIANGLE,
+                "Icon { icon: solid::Shape::ExclamationTriangle }",
+            ),
+            (&solid::EYE_DROPPER, "Icon { icon: solid::Shape::EyeDropper }"),
+            (&solid::EYE_SLASH, "Icon { icon: solid::Shape::EyeSlash }"),
+            (&solid::EYE, "Icon { icon: solid::Shape::Eye }"),
+            (&solid::FACE_FROWN, "Icon { icon: solid::Shape::FaceFrown }"),
+            (&solid::FACE_SMILE, "Icon { icon: solid::Shape::FaceSmile }"),
+            (&solid::FILM, "Icon { icon: solid::Shape::Film }"),
+            (&solid::FINGER_PRINT, "Icon { icon: solid::Shape::FingerPrint }"),
+            (&solid::FIRE, "Icon { icon: solid::Shape::Fire }"),
+            (&solid::FLAG, "Icon { icon: solid::Shape::Flag }"),
+            (&solid::FOLDER_ARROW_DOWN, "Icon { icon: solid::Shape::FolderArrowDown }"),
+            (&solid::FOLDER_MINUS, "Icon { icon: solid::Shape::FolderMinus }"),
+            (&solid::FOLDER_OPEN, "Icon { icon: solid::Shape::FolderOpen }"),
+            (&solid::FOLDER_PLUS, "Icon { icon: solid::Shape::FolderPlus }"),
+            (&solid::FOLDER, "Icon { icon: solid::Shape::Folder }"),
+            (&solid::FORWARD, "Icon { icon: solid::Shape::Forward }"),
+            (&solid::FUNNEL, "Icon { icon: solid::Shape::Funnel }"),
+            (&solid::GIF, "Icon { icon: solid::Shape::Gif }"),
+            (&solid::GIFT_TOP, "Icon { icon: solid::Shape::GiftTop }"),
+            (&solid::GIFT, "Icon { icon: solid::Shape::Gift }"),
+            (&solid::GLOBE_ALT, "Icon { icon: solid::Shape::GlobeAlt }"),
+            (&solid::GLOBE_AMERICAS, "Icon { icon: solid::Shape::GlobeAmericas }"),
+            (
+                &solid::GLOBE_ASIA_AUSTRALIA,
+                "Icon { icon: solid::Shape::GlobeAsiaAustralia }",
+            ),
+            (
+                &solid::GLOBE_EUROPE_AFRICA,
+                "Icon { icon: solid::Shape::GlobeEuropeAfrica }",
+            ),
+            (&solid::HAND_RAISED, "Icon { icon: solid::Shape::HandRaised }"),
+            (&solid::HAND_THUMB_DOWN, "Icon { icon: solid::Shape::HandThumbDown }"),
+            (&solid::HAND_THUMB_UP, "Icon { icon: solid::Shape::HandThumbUp }"),
+            (&solid::HASHTAG, "Icon { icon: solid::Shape::Hashtag }"),
+            (&solid::HEART, "Icon { icon: solid::Shape::Heart }"),
+            (&solid::HOME_MODERN, "Icon { icon: solid::Shape::HomeModern }"),
+            (&solid::HOME, "Icon { icon: solid::Shape::Home }"),
+            (&solid::IDENTIFICATION, "Icon { icon: solid::Shape::Identification }"),
+            (&solid::INBOX_ARROW_DOWN, "Icon { icon: solid::Shape::InboxArrowDown }"),
+            (&solid::INBOX_STACK, "Icon { icon: solid::Shape::InboxStack }"),
+            (&solid::INBOX, "Icon { icon: solid::Shape::Inbox }"),
+            (
+                &solid::INFORMATION_CIRCLE,
+                "Icon { icon: solid::Shape::InformationCircle }",
+            ),
+            (&solid::KEY, "Icon { icon: solid::Shape::Key }"),
+            (&solid::LANGUAGE, "Icon { icon: solid::Shape::Language }"),
+            (&solid::LIFEBUOY, "Icon { icon: solid::Shape::Lifebuoy }"),
+            (&solid::LIGHT_BULB, "Icon { icon: solid::Shape::LightBulb }"),
+            (&solid::LINK, "Icon { icon: solid::Shape::Link }"),
+            (&solid::LIST_BULLET, "Icon { icon: solid::Shape::ListBullet }"),
+            (&solid::LOCK_CLOSED, "Icon { icon: solid::Shape::LockClosed }"),
+            (&solid::LOCK_OPEN, "Icon { icon: solid::Shape::LockOpen }"),
+            (
+                &solid::MAGNIFYING_GLASS_CIRCLE,
+                "Icon { icon: solid::Shape::MagnifyingGlassCircle }",
+            ),
+            (
+                &solid::MAGNIFYING_GLASS_MINUS,
+                "Icon { icon: solid::Shape::MagnifyingGlassMinus }",
+            ),
+            (
+                &solid::MAGNIFYING_GLASS_PLUS,
+                "Icon { icon: solid::Shape::MagnifyingGlassPlus }",
+            ),
+            (&solid::MAGNIFYING_GLASS, "Icon { icon: solid::Shape::MagnifyingGlass }"),
+            (&solid::MAP_PIN, "Icon { icon: solid::Shape::MapPin }"),
+            (&solid::MAP, "Icon { icon: solid::Shape::Map }"),
+            (&solid::MEGAPHONE, "Icon { icon: solid::Shape::Megaphone }"),
+            (&solid::MICROPHONE, "Icon { icon: solid::Shape::Microphone }"),
+            (&solid::MINUS_CIRCLE, "Icon { icon: solid::Shape::MinusCircle }"),
+            (&solid::MINUS_SMALL, "Icon { icon: solid::Shape::MinusSmall }"),
+            (&solid::MINUS, "Icon { icon: solid::Shape::Minus }"),
+            (&solid::MOON, "Icon { icon: solid::Shape::Moon }"),
+            (&solid::MUSICAL_NOTE, "Icon { icon: solid::Shape::MusicalNote }"),
+            (&solid::NEWSPAPER, "Icon { icon: solid::Shape::Newspaper }"),
+            (&solid::NO_SYMBOL, "Icon { icon: solid::Shape::NoSymbol }"),
+            (&solid::PAINT_BRUSH, "Icon { icon: solid::Shape::PaintBrush }"),
+            (&solid::PAPER_AIRPLANE, "Icon { icon: solid::Shape::PaperAirplane }"),
+            (&solid::PAPER_CLIP, "Icon { icon: solid::Shape::PaperClip }"),
+            (&solid::PAUSE_CIRCLE, "Icon { icon: solid::Shape::PauseCircle }"),
+            (&solid::PAUSE, "Icon { icon: solid::Shape::Pause }"),
+            (&solid::PENCIL_SQUARE, "Icon { icon: solid::Shape::PencilSquare }"),
+            (&solid::PENCIL, "Icon { icon: solid::Shape::Pencil }"),
+            (
+                &solid::PHONE_ARROW_DOWN_LEFT,
+                "Icon { icon: solid::Shape::PhoneArrowDownLeft }",
+            ),
+            (
+                &solid::PHONE_ARROW_UP_RIGHT,
+                "Icon { icon: solid::Shape::PhoneArrowUpRight }",
+            ),
+            (&solid::PHONE_X_MARK, "Icon { icon: solid::Shape::PhoneXMark }"),
+            (&solid::PHONE, "Icon { icon: solid::Shape::Phone }"),
+            (&solid::PHOTO, "Icon { icon: solid::Shape::Photo }"),
+            (&solid::PLAY_CIRCLE, "Icon { icon: solid::Shape::PlayCircle }"),
+            (&solid::PLAY_PAUSE, "Icon { icon: solid::Shape::PlayPause }"),
+            (&solid::PLAY, "Icon { icon: solid::Shape::Play }"),
+            (&solid::PLUS_CIRCLE, "Icon { icon: solid::Shape::PlusCircle }"),
+            (&solid::PLUS_SMALL, "Icon { icon: solid::Shape::PlusSmall }"),
+            (&solid::PLUS, "Icon { icon: solid::Shape::Plus }"),
+            (&solid::POWER, "Icon { icon: solid::Shape::Power }"),
+            (
+                &solid::PRESENTATION_CHART_BAR,
+                "Icon { icon: solid::Shape::PresentationChartBar }",
+            ),
+            (
+                &solid::PRESENTATION_CHART_LINE,
+                "Icon { icon: solid::Shape::PresentationChartLine }",
+            ),
+            (&solid::PRINTER, "Icon { icon: solid::Shape::Printer }"),
+            (&solid::PUZZLE_PIECE, "Icon { icon: solid::Shape::PuzzlePiece }"),
+            (&solid::QR_CODE, "Icon { icon: solid::Shape::QrCode }"),
+            (
+                &solid::QUESTION_MARK_CIRCLE,
+                "Icon { icon: solid::Shape::QuestionMarkCircle }",
+            ),
+            (&solid::QUEUE_LIST, "Icon { icon: solid::Shape::QueueList }"),
+            (&solid::RADIO, "Icon { icon: solid::Shape::Radio }"),
+            (&solid::RECEIPT_PERCENT, "Icon { icon: solid::Shape::ReceiptPercent }"),
+            (&solid::RECEIPT_REFUND, "Icon { icon: solid::Shape::ReceiptRefund }"),
+            (&solid::RECTANGLE_GROUP, "Icon { icon: solid::Shape::RectangleGroup }"),
+            (&solid::RECTANGLE_STACK, "Icon { icon: solid::Shape::RectangleStack }"),
+            (&solid::ROCKET_LAUNCH, "Icon { icon: solid::Shape::RocketLaunch }"),
+            (&solid::RSS, "Icon { icon: solid::Shape::Rss }"),
+            (&solid::SCALE, "Icon { icon: solid::Shape::Scale }"),
+            (&solid::SCISSORS, "Icon { icon: solid::Shape::Scissors }"),
+            (&solid::SERVER_STACK, "Icon { icon: solid::Shape::ServerStack }"),
+            (&solid::SERVER, "Icon { icon: solid::Shape::Server }"),
+            (&solid::SHARE, "Icon { icon: solid::Shape::Share }"),
+            (&solid::SHIELD_CHECK, "Icon { icon: solid::Shape::ShieldCheck }"),
+            (
+                &solid::SHIELD_EXCLAMATION,
+                "Icon { icon: solid::Shape::ShieldExclamation }",
+            ),
+            (&solid::SHOPPING_BAG, "Icon { icon: solid::Shape::ShoppingBag }"),
+            (&solid::SHOPPING_CART, "Icon { icon: solid::Shape::ShoppingCart }"),
+            (&solid::SIGNAL_SLASH, "Icon { icon: solid::Shape::SignalSlash }"),
+            (&solid::SIGNAL, "Icon { icon: solid::Shape::Signal }"),
+            (&solid::SPARKLES, "Icon { icon: solid::Shape::Sparkles }"),
+            (&solid::SPEAKER_WAVE, "Icon { icon: solid::Shape::SpeakerWave }"),
+            (&solid::SPEAKER_X_MARK, "Icon { icon: solid::Shape::SpeakerXMark }"),
+            (&solid::SQUARE_2_STACK, "Icon { icon: solid::Shape::Square2Stack }"),
+            (&solid::SQUARE_3_STACK_3D, "Icon { icon: solid::Shape::Square3Stack3d }"),
+            (&solid::SQUARES_2X2, "Icon { icon: solid::Shape::Squares2x2 }"),
+            (&solid::SQUARES_PLUS, "Icon { icon: solid::Shape::SquaresPlus }"),
+            (&solid::STAR, "Icon { icon: solid::Shape::Star }"),
+            (&solid::STOP_CIRCLE, "Icon { icon: solid::Shape::StopCircle }"),
+            (&solid::STOP, "Icon { icon: solid::Shape::Stop }"),
+            (&solid::SUN, "Icon { icon: solid::Shape::Sun }"),
+            (&solid::SWATCH, "Icon { icon: solid::Shape::Swatch }"),
+            (&solid::TABLE_CELLS, "Icon { icon: solid::Shape::TableCells }"),
+            (&solid::TAG, "Icon { icon: solid::Shape::Tag }"),
+            (&solid::TICKET, "Icon { icon: solid::Shape::Ticket }"),
+            (&solid::TRASH, "Icon { icon: solid::Shape::Trash }"),
+            (&solid::TROPHY, "Icon { icon: solid::Shape::Trophy }"),
+            (&solid::TRUCK, "Icon { icon: solid::Shape::Truck }"),
+            (&solid::TV, "Icon { icon: solid::Shape::Tv }"),
+            (&solid::USER_CIRCLE, "Icon { icon: solid::Shape::UserCircle }"),
+            (&solid::USER_GROUP, "Icon { icon: solid::Shape::UserGroup }"),
+            (&solid::USER_MINUS, "Icon { icon: solid::Shape::UserMinus }"),
+            (&solid::USER_PLUS, "Icon { icon: solid::Shape::UserPlus }"),
+            (&solid::USER, "Icon { icon: solid::Shape::User }"),
+            (&solid::USERS, "Icon { icon: solid::Shape::Users }"),
+            (&solid::VARIABLE, "Icon { icon: solid::Shape::Variable }"),
+            (
+                &solid::VIDEO_CAMERA_SLASH,
+                "Icon { icon: solid::Shape::VideoCameraSlash }",
+            ),
+            (&solid::VIDEO_CAMERA, "Icon { icon: solid::Shape::VideoCamera }"),
+            (&solid::VIEW_COLUMNS, "Icon { icon: solid::Shape::ViewColumns }"),
+            (&solid::VIEWFINDER_CIRCLE, "Icon { icon: solid::Shape::ViewfinderCircle }"),
+            (&solid::WALLET, "Icon { icon: solid::Shape::Wallet }"),
+            (&solid::WIFI, "Icon { icon: solid::Shape::Wifi }"),
+            (&solid::WINDOW, "Icon { icon: solid::Shape::Window }"),
+            (
+                &solid::WRENCH_SCREWDRIVER,
+                "Icon { icon: solid::Shape::WrenchScrewdriver }",
+            ),
+            (&solid::WRENCH, "Icon { icon: solid::Shape::Wrench }"),
+            (&solid::X_CIRCLE, "Icon { icon: solid::Shape::XCircle }"),
+            (&solid::X_MARK, "Icon { icon: solid::Shape::XMark }"),
+        ],
+    ),
+];
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2rem; }
+.shapes { display: grid; grid-template-columns: repeat(auto-fill, minmax(9rem, 1fr)); gap: 0.5rem; }
+.shape { display: flex; flex-direction: column; align-items: center; gap: 0.5rem; padding: 1rem 0.5rem; border: 1px solid #E5E7EB; border-radius: 0.5rem; background: none; cursor: pointer; font-size: 0.75rem; }
+.shape:hover { background: #F3F4F6; }
+";
+
+/// Static HTML can't run Dioxus event handlers, so the clipboard is written by a plain script.
+const SCRIPT: &str = "
+for (const button of document.querySelectorAll('.shape')) {
+    button.addEventListener('click', () => navigator.clipboard.writeText(button.dataset.snippet));
+}
+";
+
+#[allow(non_snake_case)]
+#[component]
+fn Gallery() -> Element {
+    rsx! {
+        h1 { "heroicons {meta::HEROICONS_VERSION}" }
+        for (style, shapes) in STYLES {
+            h2 { key: "{style}", "{style}" }
+            div {
+                class: "shapes",
+                for (shape, snippet) in shapes.iter() {
+                    button {
+                        key: "{style}-{shape.name()}",
+                        class: "shape",
+                        title: "Copy {snippet}",
+                        "data-snippet": *snippet,
+                        Icon { icon: **shape, size: 24 }
+                        "{shape.name()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn main() {
+    let file = env::args().nth(1).unwrap_or_else(|| "gallery.html".to_string());
+    let mut dom = VirtualDom::new(Gallery);
+    dom.rebuild_in_place();
+    let html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<title>heroicons {}</title>\n<style>{}</style>\n</head>\n\
+         <body>\n{}\n<script>{}</script>\n</body>\n</html>\n",
+        meta::HEROICONS_VERSION,
+        STYLE,
+        dioxus_ssr::render(&dom),
+        SCRIPT,
+    );
+    fs::write(&file, html).unwrap_or_else(|e| panic!("could not write {}: {}", file, e));
+    println!("wrote the gallery to {file}");
+}
@@ -0,0 +1,159 @@
+use crate::{
+    files::{self, TempDir},
+    generate,
+    semver::print_changes,
+    styles::styles_file,
+    Options, Selection,
+};
+use std::{
+    collections::BTreeSet,
+    io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use walkdir::WalkDir;
+
+// Regenerates the crate's generated files in a copy of the crate and compares them to the real
+// ones, printing a diff for each file that's out of sync. Returns true if they all match.
+pub fn check(heroicons: &Path, crate_dir: &Path, options: &Options) -> io::Result<bool> {
+    let work_dir = regenerate(heroicons, crate_dir, options)?;
+    let in_sync = print_diff(crate_dir, work_dir.path(), options)?;
+    if in_sync {
+        println!("the generated code is up to date");
+    } else {
+        println!("the generated code is out of date, so run gen to regenerate it");
+    }
+    Ok(in_sync)
+}
+
+// Regenerates the crate's generated files in a copy of the crate and prints which shapes would be
+// added, removed, or changed, and a diff of each file that would change, without writing anything
+// to the crate.
+pub fn dry_run(heroicons: &Path, crate_dir: &Path, options: &Options) -> io::Result<()> {
+    let work_dir = regenerate(heroicons, crate_dir, options)?;
+    print_changes(crate_dir, work_dir.path(), options)?;
+    if print_diff(crate_dir, work_dir.path(), options)? {
+        println!("regenerating the crate would not change any files");
+    } else {
+        println!("this was a dry run, so the crate was not changed");
+    }
+    Ok(())
+}
+
+// Prints a diff for each generated file that's different in the work dir, or a line saying that it
+// would be added or removed. Returns true if none of them are different.
+fn print_diff(crate_dir: &Path, work_dir: &Path, options: &Options) -> io::Result<bool> {
+    let styles = options.style_dirs.styles();
+    let mut files = updated_in_place(&styles);
+    files.push(Path::new("src").join("meta.rs"));
+    files.push(Path::new("tests").join("shape_snapshots.rs"));
+    files.push(Path::new("examples").join("gallery.rs"));
+    files.push(styles_file());
+    for style in styles {
+        let src = Path::new("crates").join(style).join("src");
+        let mut style_files = relative_files(&crate_dir.join(&src))?;
+        style_files.extend(relative_files(&work_dir.join(&src))?);
+        files.extend(style_files.into_iter().map(|f| src.join(f)));
+    }
+
+    let mut in_sync = true;
+    for file in &files {
+        let committed = crate_dir.join(file);
+        let generated = work_dir.join(file);
+        match (committed.exists(), generated.exists()) {
+            (true, false) => println!("{} should not exist", file.display()),
+            (false, true) => println!("{} is missing", file.display()),
+            _ if comparable(&committed)? == comparable(&generated)? => continue,
+            _ => {
+                Command::new("git")
+                    .args(["--no-pager", "diff", "--no-index", "--"])
+                    .args([&committed, &generated])
+                    .status()
+                    .map_err(|e| {
+                        io::Error::new(e.kind(), format!("could not run git diff: {e}"))
+                    })?;
+            }
+        }
+        in_sync = false;
+    }
+    Ok(in_sync)
+}
+
+// Regenerates the crate's generated files in a copy of the crate in a temporary directory, and
+// returns that directory, which is removed when it's dropped.
+pub fn regenerate(heroicons: &Path, crate_dir: &Path, options: &Options) -> io::Result<TempDir> {
+    let temp_dir = TempDir::new("dioxus-heroicons-gen-check")?;
+    let work_dir = temp_dir.path();
+
+    // The generator reads the crate's code to find the shapes that its components use, and updates
+    // the manifests and discriminants in place, so those need to be copied along with any existing
+    // generated code.
+    copy_dir(&crate_dir.join("src"), &work_dir.join("src"))?;
+    for file in updated_in_place(&options.style_dirs.styles()) {
+        if crate_dir.join(&file).exists() {
+            copy_file(&crate_dir.join(&file), &work_dir.join(&file))?;
+        }
+    }
+
+    generate(
+        heroicons,
+        work_dir,
+        options,
+        &Selection::all(&options.style_dirs),
+    )?;
+    Ok(temp_dir)
+}
+
+// Returns the paths of the files that the generator updates rather than replaces, relative to the
+// crate.
+fn updated_in_place(styles: &[&str]) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from("Cargo.toml"),
+        Path::new("gen").join("discriminants.json"),
+    ];
+    for style in styles {
+        files.push(Path::new("crates").join(style).join("Cargo.toml"));
+    }
+    files
+}
+
+// Returns a file's content, minus the time in the meta module, which is different every time the
+// code is generated unless `SOURCE_DATE_EPOCH` is set.
+fn comparable(file: &Path) -> io::Result<String> {
+    Ok(files::read(file)?
+        .lines()
+        .filter(|l| !l.starts_with("pub const GENERATED_AT"))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+// Returns the paths of the files in a directory, relative to it. A directory that doesn't exist
+// has no files, since a style crate's `src` might not have been generated yet.
+pub fn relative_files(dir: &Path) -> io::Result<BTreeSet<PathBuf>> {
+    let mut files = BTreeSet::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+    for entry in WalkDir::new(dir) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            files.insert(entry.path().strip_prefix(dir).unwrap().to_path_buf());
+        }
+    }
+    Ok(files)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    for file in relative_files(from)? {
+        copy_file(&from.join(&file), &to.join(&file))?;
+    }
+    Ok(())
+}
+
+// Copies a file, creating the directory that it's copied to if it doesn't exist.
+fn copy_file(from: &Path, to: &Path) -> io::Result<()> {
+    if let Some(dir) = to.parent() {
+        files::create_dir_all(dir)?;
+    }
+    files::copy(from, to)
+}
@@ -0,0 +1,27 @@
+use crate::files;
+use std::{env, io, path::PathBuf, process::Command};
+
+const HEROICONS_REPO: &str = "https://github.com/tailwindlabs/heroicons.git";
+
+// Shallow-clones the heroicons repo at a tag, like "v2.0.13", into a temporary directory and returns
+// its path. Any earlier clone of the same tag is removed first, so the result is always exactly what
+// the tag points to.
+pub fn fetch_heroicons(tag: &str) -> io::Result<PathBuf> {
+    let dir = env::temp_dir().join(format!("dioxus-heroicons-gen-{tag}"));
+    if dir.exists() {
+        files::remove_dir_all(&dir)?;
+    }
+    info!("cloning heroicons {} from {}", tag, HEROICONS_REPO);
+    let status = Command::new("git")
+        .args(["clone", "--quiet", "--depth", "1", "--branch", tag])
+        .arg(HEROICONS_REPO)
+        .arg(&dir)
+        .status()
+        .map_err(|e| io::Error::new(e.kind(), format!("could not run git: {e}")))?;
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "could not clone heroicons at {tag}"
+        )));
+    }
+    Ok(dir)
+}
@@ -0,0 +1,75 @@
+use serde::de::DeserializeOwned;
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+// These wrap the `fs` functions that the generator uses, adding the path to each error, since the
+// errors from `fs` don't say which file they're about.
+
+pub fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map_err(|e| with_path(&e, path))
+}
+
+pub fn write(path: &Path, content: impl AsRef<[u8]>) -> io::Result<()> {
+    fs::write(path, content).map_err(|e| with_path(&e, path))
+}
+
+pub fn copy(from: &Path, to: &Path) -> io::Result<()> {
+    fs::copy(from, to)
+        .map(|_| ())
+        .map_err(|e| with_path(&e, from))
+}
+
+pub fn create_dir_all(path: &Path) -> io::Result<()> {
+    fs::create_dir_all(path).map_err(|e| with_path(&e, path))
+}
+
+pub fn remove_dir_all(path: &Path) -> io::Result<()> {
+    fs::remove_dir_all(path).map_err(|e| with_path(&e, path))
+}
+
+// A directory in the system's temporary directory that only this process uses, so that two runs of
+// the generator at once don't write over each other's files. It's removed when this is dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    // Creates an empty directory whose name starts with `prefix`.
+    pub fn new(prefix: &str) -> io::Result<Self> {
+        let path = env::temp_dir().join(format!("{prefix}-{}", process::id()));
+        if path.exists() {
+            remove_dir_all(&path)?;
+        }
+        create_dir_all(&path)?;
+        Ok(Self(path))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        // There's nothing useful to do if this fails, and the system cleans up its temporary
+        // directory eventually anyway.
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+// Reads a JSON file, like the tags and renames files.
+pub fn read_json<T: DeserializeOwned>(path: &Path) -> io::Result<T> {
+    serde_json::from_str(&read(path)?)
+        .map_err(|e| invalid_data(format!("{} is not valid: {e}", path.display())))
+}
+
+// Returns an error for a file or other input that's not what it should be.
+pub fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+// Adds a path to an error from a function that doesn't have a wrapper here.
+pub fn with_path(e: &io::Error, path: &Path) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+}
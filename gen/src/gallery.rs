@@ -0,0 +1,118 @@
+use crate::files;
+use heroicons_codegen::Icon;
+use std::{fmt::Write, io, path::Path};
+
+const GALLERY_TEMPLATE: &str = r#"//! A gallery of every heroicons shape in every style, with its name and a snippet of code that draws
+//! it, which is copied to the clipboard when the shape is clicked. This renders the gallery to an
+//! HTML file with `dioxus-ssr`, so it can be opened in any browser:
+//!
+//! ```sh
+//! cargo run --example gallery -- gallery.html
+//! ```
+//!
+//! This file was written by the `gen` crate, so don't edit it by hand.
+
+use dioxus::prelude::*;
+use dioxus_heroicons::{meta, mini, outline, solid, Icon, StaticShape};
+use std::{env, fs};
+
+{STYLES}
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2rem; }
+.shapes { display: grid; grid-template-columns: repeat(auto-fill, minmax(9rem, 1fr)); gap: 0.5rem; }
+.shape { display: flex; flex-direction: column; align-items: center; gap: 0.5rem; padding: 1rem 0.5rem; border: 1px solid #E5E7EB; border-radius: 0.5rem; background: none; cursor: pointer; font-size: 0.75rem; }
+.shape:hover { background: #F3F4F6; }
+";
+
+/// Static HTML can't run Dioxus event handlers, so the clipboard is written by a plain script.
+const SCRIPT: &str = "
+for (const button of document.querySelectorAll('.shape')) {
+    button.addEventListener('click', () => navigator.clipboard.writeText(button.dataset.snippet));
+}
+";
+
+#[allow(non_snake_case)]
+#[component]
+fn Gallery() -> Element {
+    rsx! {
+        h1 { "heroicons {meta::HEROICONS_VERSION}" }
+        for (style, shapes) in STYLES {
+            h2 { key: "{style}", "{style}" }
+            div {
+                class: "shapes",
+                for (shape, snippet) in shapes.iter() {
+                    button {
+                        key: "{style}-{shape.name()}",
+                        class: "shape",
+                        title: "Copy {snippet}",
+                        "data-snippet": *snippet,
+                        Icon { icon: **shape, size: 24 }
+                        "{shape.name()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn main() {
+    let file = env::args().nth(1).unwrap_or_else(|| "gallery.html".to_string());
+    let mut dom = VirtualDom::new(Gallery);
+    dom.rebuild_in_place();
+    let html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<title>heroicons {}</title>\n<style>{}</style>\n</head>\n\
+         <body>\n{}\n<script>{}</script>\n</body>\n</html>\n",
+        meta::HEROICONS_VERSION,
+        STYLE,
+        dioxus_ssr::render(&dom),
+        SCRIPT,
+    );
+    fs::write(&file, html).unwrap_or_else(|e| panic!("could not write {}: {}", file, e));
+    println!("wrote the gallery to {file}");
+}
+"#;
+
+const STYLES_TEMPLATE: &str = r"
+/// Each style's name, and each of its shapes with a snippet that draws it.
+static STYLES: &[(&str, &[(&StaticShape, &str)])] = &[
+{STYLES}];
+";
+
+const STYLE_TEMPLATE: &str = r#"("{STYLE}", &[
+{SHAPES}]),
+"#;
+
+// Writes an example app that renders every shape in every style, with a snippet of the code for
+// each one. The shapes are listed here, rather than taken from each style's `Shape::ALL`, so that
+// the snippets have the variant names. Only the list of shapes is formatted with prettyplease, since
+// it would put all of the `rsx!` on a few long lines.
+pub fn write_gallery(styles: &[(&str, &[Icon])], file: &Path) -> io::Result<()> {
+    let styles = styles
+        .iter()
+        .map(|(style, icons)| {
+            let mut shapes = String::new();
+            for icon in *icons {
+                // Writing to a `String` can't fail.
+                let _ = writeln!(
+                    shapes,
+                    "(&{style}::{}, \"Icon {{ icon: {style}::Shape::{} }}\"),",
+                    icon.const_name(),
+                    icon.variant,
+                );
+            }
+            STYLE_TEMPLATE
+                .replace("{STYLE}", style)
+                .replace("{SHAPES}", &shapes)
+        })
+        .collect::<String>();
+    if let Some(dir) = file.parent() {
+        files::create_dir_all(dir)?;
+    }
+    // The generated code always parses, unless there's a bug in the template.
+    let code = syn::parse_file(&STYLES_TEMPLATE.replace("{STYLES}", &styles))
+        .expect("the gallery's shapes are valid Rust");
+    files::write(
+        file,
+        GALLERY_TEMPLATE.replace("{STYLES}", &prettyplease::unparse(&code)),
+    )
+}
@@ -0,0 +1,40 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// How much gen prints, from the least to the most. Warnings and errors are always printed, and so is
+// the output of the options and subcommands that are run to get a report, like --check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+// Returns true if messages for the given verbosity should be printed.
+pub fn enabled(verbosity: Verbosity) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= verbosity as u8
+}
+
+// Prints a line about what gen is doing, unless --quiet was passed.
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Verbosity::Normal) {
+            println!($($arg)*);
+        }
+    };
+}
+
+// Prints a line of detail that's only useful when reviewing a regeneration, if --verbose was
+// passed.
+macro_rules! detail {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Verbosity::Verbose) {
+            println!($($arg)*);
+        }
+    };
+}
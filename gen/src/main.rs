@@ -1,184 +1,791 @@
+#[macro_use]
+mod log;
+
+mod check;
+mod fetch;
+mod files;
+mod gallery;
+mod meta;
+mod semver;
+mod size_report;
+mod snapshots;
+mod sprite;
+mod stats;
+mod styles;
+
+use check::{check, dry_run};
 use clap::Parser;
-use heck::ToUpperCamelCase;
+use fetch::fetch_heroicons;
+use files::invalid_data;
+use gallery::write_gallery;
+use heroicons_codegen::{
+    icon_features, parse_icons_lenient, Generator, Icon, Layout, FEATURES_END, FEATURES_START,
+    RENAMES_START,
+};
 use itertools::Itertools;
-use scraper::{Html, Selector};
-use std::{fs, path::PathBuf, process::Command};
+use log::{set_verbosity, Verbosity};
+use meta::{heroicons_version, write_meta};
+use semver::semver_report;
+use size_report::{size_report, SizeReportArgs};
+use snapshots::write_snapshots;
+use sprite::{write_sprite, SpriteArgs};
+use stats::print_stats;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    io,
+    path::{Path, PathBuf},
+    process,
+};
+use styles::{styles_file, write_styles, StyleDirArgs, StyleDirs};
 use walkdir::WalkDir;
 
 #[derive(Debug, Parser)]
 #[clap(author, version, about, long_about = None)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
+    #[clap(subcommand)]
+    command: Option<Subcommand>,
     /// Path to the heroicons repo
+    #[clap(long, required_unless_present = "tag", conflicts_with = "tag")]
+    heroicons: Option<PathBuf>,
+    /// A heroicons release tag, like "v2.0.13", to shallow-clone from GitHub and generate from,
+    /// instead of using a local checkout with --heroicons
+    #[clap(long)]
+    tag: Option<String>,
+    /// Path to the dioxus-heroicons crate. The style crates are written to its `crates` directory,
+    /// and the icon features are written to its Cargo.toml and theirs.
+    #[clap(long = "crate", default_value = ".")]
+    crate_dir: PathBuf,
+    /// Path to a JSON file of tags for the icons, like `{"trash": ["garbage", "delete"]}`, which
+    /// become doc aliases for their shapes and are returned by their `tags` methods. This defaults
+    /// to the crate's `gen/tags.json`.
+    #[clap(long)]
+    tags: Option<PathBuf>,
+    /// Path to the search tags that heroicons uses for its website, saved as a JSON file in the
+    /// same format as --tags. These are merged with the tags from --tags, which only need to list
+    /// the tags that heroicons doesn't have.
+    #[clap(long)]
+    heroicons_tags: Option<PathBuf>,
+    /// Path to a JSON file of the icons that heroicons renamed, from old name to new name, like
+    /// `{"arrow-left-on-rectangle": "arrow-left-start-on-rectangle"}`. The old names become
+    /// deprecated aliases for the new shapes, and features that enable them. This defaults to the
+    /// crate's `gen/renames.json`.
+    #[clap(long)]
+    renames: Option<PathBuf>,
+    /// Path to a JSON file of icons that are or aren't directional, like `{"list-bullet": true}`,
+    /// which overrides the guess from their names. Icons with "left" or "right" in their names are
+    /// directional unless this says otherwise. This defaults to the crate's `gen/directional.json`.
+    #[clap(long)]
+    directional: Option<PathBuf>,
+    /// The version of heroicons, like "2.0.13". This defaults to the version in the heroicons
+    /// repo's package.json.
     #[clap(long)]
-    heroicons: PathBuf,
-    /// Path to the directory where files will be written
+    heroicons_version: Option<String>,
+    /// Minify the shapes' path data, rounding its numbers to this many decimal places and dropping
+    /// the separators and command letters that it doesn't need. The heroicons SVGs have up to 5
+    /// decimal places, so fewer than that changes the shapes slightly, but 2 or 3 is still far more
+    /// precise than any screen can show. The path data isn't minified by default.
+    #[clap(long, value_name = "DECIMALS")]
+    minify: Option<u32>,
+    /// Skip the SVG files that can't be parsed, printing a warning for each one, instead of
+    /// stopping without writing anything. Either way, every invalid file is reported.
+    #[clap(long)]
+    skip_invalid: bool,
+    /// How to split each style crate's code into files. With "letter", the statics for the shapes
+    /// go in one file per initial letter, like `a.rs`, and with "icon" they go in one file per
+    /// shape, like `arrow_left.rs`, so that regenerating a shape only changes its own file. With
+    /// "file", everything goes in the `lib.rs`.
+    #[clap(long, value_parser = ["file", "letter", "icon"], default_value = "letter")]
+    layout: String,
+    #[clap(flatten)]
+    mode: ModeArgs,
+    /// The styles to regenerate, separated by commas, like "solid,outline". The other style crates
+    /// are left alone. This defaults to all of the styles in --style-dirs.
+    #[clap(long, value_delimiter = ',')]
+    styles: Vec<String>,
+    /// Only regenerate the files with icons whose names match one of these patterns, separated by
+    /// commas, like "arrow-*". A `*` matches any number of characters and a `?` matches one.
+    #[clap(long, value_delimiter = ',')]
+    filter: Vec<String>,
+    /// Print more detail about the icons, like the names of the ones with more than one path
+    #[clap(short, long, conflicts_with = "quiet")]
+    verbose: bool,
+    /// Only print warnings and errors, and the output of --check, --dry-run, and --semver-report
     #[clap(short, long)]
-    to: PathBuf,
+    quiet: bool,
+    #[clap(flatten)]
+    style_dirs: StyleDirArgs,
+}
+
+// The options that regenerate the code somewhere else and report on it, instead of writing it to
+// the crate. Only one of them can be passed, and they can't be combined with a partial
+// regeneration.
+#[derive(Debug, clap::Args)]
+#[group(id = "mode", multiple = false, conflicts_with_all = ["styles", "filter"])]
+struct ModeArgs {
+    /// Regenerate the code in a temporary directory and compare it to the crate's generated files
+    /// instead of overwriting them. This prints a diff and exits with an error if they differ.
+    #[clap(long)]
+    check: bool,
+    /// Regenerate the code in a temporary directory, like --check, and report which shapes would
+    /// be added, renamed, removed, or changed, and whether that needs a breaking release. Removed
+    /// shapes and changes to a shape's paths or view box are breaking, while new shapes and shapes
+    /// that are in the renames file are not.
+    #[clap(long)]
+    semver_report: bool,
+    /// Regenerate the code in a temporary directory, like --check, and print which shapes would be
+    /// added, removed, or changed, and a diff of each file that would change, without writing
+    /// anything. This is useful for seeing what a new version of heroicons would change.
+    #[clap(long)]
+    dry_run: bool,
 }
 
-#[derive(Debug)]
-struct Icon {
-    name: String,
-    viewbox: String,
-    path: String,
-    clip_rule: Option<String>,
-    fill_rule: Option<String>,
+// What gen does with the generated code.
+#[derive(Clone, Copy, Debug)]
+enum Mode {
+    Write,
+    Check,
+    DryRun,
+    SemverReport,
 }
 
-fn style_to_dir(style: &str) -> &str {
-    match style {
-        "outline" => "24/outline",
-        "solid" => "24/solid",
-        "mini" => "20/solid",
-        _ => panic!("unknown style"),
+impl ModeArgs {
+    fn mode(&self) -> Mode {
+        if self.check {
+            Mode::Check
+        } else if self.dry_run {
+            Mode::DryRun
+        } else if self.semver_report {
+            Mode::SemverReport
+        } else {
+            Mode::Write
+        }
+    }
+}
+
+// Where the generator gets its tags, renames, and heroicons version from, and how it handles the
+// icons.
+struct Options {
+    tags_files: Vec<PathBuf>,
+    renames_file: PathBuf,
+    directional_file: PathBuf,
+    version: String,
+    minify: Option<u32>,
+    skip_invalid: bool,
+    layout: Layout,
+    style_dirs: StyleDirs,
+}
+
+// The style crates and icons to regenerate. The manifest features and the meta module are always
+// regenerated, since they need every icon.
+struct Selection {
+    styles: Vec<String>,
+    filter: Vec<String>,
+}
+
+impl Selection {
+    fn all(style_dirs: &StyleDirs) -> Self {
+        Self {
+            styles: style_dirs
+                .styles()
+                .into_iter()
+                .map(ToString::to_string)
+                .collect(),
+            filter: vec![],
+        }
+    }
+
+    fn includes(&self, icon: &Icon) -> bool {
+        self.filter.is_empty() || self.filter.iter().any(|f| glob_match(f, &icon.name))
     }
 }
 
+// Returns true if a name matches a pattern in which `*` matches any number of characters and `?`
+// matches one.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    match pattern.chars().next() {
+        None => name.is_empty(),
+        Some('*') => {
+            let rest = &pattern[1..];
+            name.char_indices()
+                .map(|(i, _)| i)
+                .chain(std::iter::once(name.len()))
+                .any(|i| glob_match(rest, &name[i..]))
+        }
+        Some(p) => match name.chars().next() {
+            Some(n) if p == '?' || p == n => {
+                glob_match(&pattern[p.len_utf8()..], &name[n.len_utf8()..])
+            }
+            _ => false,
+        },
+    }
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum Subcommand {
+    /// Builds the crate with different styles and icons enabled, and reports how much each style
+    /// and icon adds to the size of a binary
+    SizeReport(SizeReportArgs),
+    /// Writes an SVG sprite sheet with a `<symbol>` for each of the selected shapes in a style,
+    /// and a Rust module with a constant for each symbol's `id`, so that an app can serve its icons
+    /// as a static file and show them with `UseIcon`
+    Sprite(SpriteArgs),
+}
+
 fn main() {
     let args = Args::parse();
+    match run(args) {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+// Runs the command, returning false if it ran but failed, like a check that found differences.
+fn run(args: Args) -> io::Result<bool> {
+    set_verbosity(if args.verbose {
+        Verbosity::Verbose
+    } else if args.quiet {
+        Verbosity::Quiet
+    } else {
+        Verbosity::Normal
+    });
+    match args.command {
+        Some(Subcommand::SizeReport(args)) => {
+            size_report(&args)?;
+            return Ok(true);
+        }
+        Some(Subcommand::Sprite(args)) => {
+            write_sprite(&args)?;
+            return Ok(true);
+        }
+        None => (),
+    }
 
-    let mut src_dir = args.heroicons.clone();
-    src_dir.push("src");
+    let heroicons = match args.tag {
+        Some(tag) => fetch_heroicons(&tag)?,
+        None => args.heroicons.unwrap(),
+    };
+    let crate_dir = args.crate_dir;
+    let mut tags_files = args.heroicons_tags.into_iter().collect::<Vec<_>>();
+    tags_files.push(
+        args.tags
+            .unwrap_or_else(|| crate_dir.join("gen").join("tags.json")),
+    );
+    let version = match args.heroicons_version {
+        Some(v) => v,
+        None => heroicons_version(&heroicons)?,
+    };
+    let options = Options {
+        tags_files,
+        renames_file: args
+            .renames
+            .unwrap_or_else(|| crate_dir.join("gen").join("renames.json")),
+        directional_file: args
+            .directional
+            .unwrap_or_else(|| crate_dir.join("gen").join("directional.json")),
+        version,
+        minify: args.minify,
+        skip_invalid: args.skip_invalid,
+        layout: match args.layout.as_str() {
+            "file" => Layout::File,
+            "icon" => Layout::Icon,
+            _ => Layout::Letter,
+        },
+        style_dirs: args.style_dirs.resolve(&crate_dir)?,
+    };
+    match args.mode.mode() {
+        Mode::Check => check(&heroicons, &crate_dir, &options),
+        Mode::DryRun => {
+            dry_run(&heroicons, &crate_dir, &options)?;
+            Ok(true)
+        }
+        Mode::SemverReport => {
+            semver_report(&heroicons, &crate_dir, &options)?;
+            Ok(true)
+        }
+        Mode::Write => {
+            let mut selection = Selection::all(&options.style_dirs);
+            if let Some(style) = args.styles.iter().find(|s| !selection.styles.contains(s)) {
+                return Err(invalid_data(format!(
+                    "there is no directory for the {style} style"
+                )));
+            }
+            if !args.styles.is_empty() {
+                selection.styles = args.styles;
+            }
+            selection.filter = args.filter;
+            generate(&heroicons, &crate_dir, &options, &selection)?;
+            Ok(true)
+        }
+    }
+}
+
+fn generate(
+    heroicons: &Path,
+    crate_dir: &Path,
+    options: &Options,
+    selection: &Selection,
+) -> io::Result<()> {
+    let used = shapes_used_by_crate(&crate_dir.join("src"), &options.style_dirs.styles())?;
+    let tags = read_tags(&options.tags_files)?;
+    let mut untagged = tags.keys().cloned().collect::<BTreeSet<_>>();
+    let renames: BTreeMap<String, String> = files::read_json(&options.renames_file)?;
+    let directional_overrides: BTreeMap<String, bool> =
+        files::read_json(&options.directional_file)?;
+    let discriminants_file = crate_dir.join("gen").join("discriminants.json");
+    let mut discriminants = read_discriminants(&discriminants_file)?;
+    // Each icon feature in the main crate maps to the features for that icon in the style crates.
+    let mut features = BTreeMap::new();
+    let mut components = vec![];
+    let mut counts = vec![];
+    let mut all_icons = vec![];
+    // The styles that each icon is in.
+    let mut variants = BTreeMap::new();
+    for (style, src_dir, icons) in parse_styles(heroicons, options)? {
+        let directional = directional(&icons, &directional_overrides);
+        let generator = Generator::new(icons)
+            .types_path("dioxus_heroicons_core")
+            .feature_gates(true)
+            .allow_list(true)
+            .dioxus_impls(false)
+            .tags(tags.clone())
+            .renames(renames.clone())
+            .directional(directional)
+            .minify(options.minify)
+            .layout(options.layout)
+            .discriminants(discriminants.remove(style).unwrap_or_default());
+        discriminants.insert(style.to_string(), generator.all_discriminants());
+        let icons = generator.icons();
+        counts.push((style, icons.len()));
+        all_icons.push((style, src_dir, icons.to_vec()));
+        for icon in icons {
+            untagged.remove(&icon.name);
+            variants
+                .entry(icon.name.clone())
+                .or_insert_with(Vec::new)
+                .push(style);
+            features
+                .entry(icon.name.clone())
+                .or_insert_with(Vec::new)
+                .push(format!("{}?/{}", style_crate(style), icon.name));
+            // Shapes that the crate's own components use are always enabled along with the
+            // components.
+            if used.contains(&format!("{}::Shape::{}", style, icon.variant)) {
+                components.push(format!("{}/{}", style_crate(style), icon.name));
+            }
+        }
+
+        write_style_crate(style, &generator, &renames, crate_dir, selection)?;
+    }
 
-    for style in &["outline", "solid", "mini"] {
-        let mut src_dir = src_dir.clone();
-        src_dir.push(style_to_dir(style));
+    for name in untagged {
+        eprintln!("warning: {name} has tags but is not an icon");
+    }
+
+    for deps in features.values_mut() {
+        deps.sort();
+    }
+    components.sort();
+    components.insert(0, "dep:dioxus".to_string());
+    let names = features.keys().cloned().collect();
+    write_features(
+        &features,
+        Some(&components),
+        &renamed(&renames, &names),
+        &crate_dir.join("Cargo.toml"),
+    )?;
+    files::write(
+        &discriminants_file,
+        serde_json::to_string_pretty(&discriminants)? + "\n",
+    )?;
+    write_styles(&options.style_dirs.styles(), &crate_dir.join(styles_file()))?;
+    counts.sort_unstable();
+    write_meta(
+        &options.version,
+        &counts,
+        &variants,
+        &crate_dir.join("src").join("meta.rs"),
+    )?;
+    all_icons.sort_by_key(|(style, _, _)| *style);
+    print_stats(
+        &all_icons
+            .iter()
+            .map(|(style, _, icons)| (*style, icons.as_slice()))
+            .collect::<Vec<_>>(),
+    );
+    write_gallery(
+        &all_icons
+            .iter()
+            .map(|(style, _, icons)| (*style, icons.as_slice()))
+            .collect::<Vec<_>>(),
+        &crate_dir.join("examples").join("gallery.rs"),
+    )?;
+    let snapshots = crate_dir.join("tests").join("shape_snapshots.rs");
+    write_snapshots(
+        &all_icons
+            .iter()
+            .map(|(style, dir, icons)| (*style, dir.as_path(), icons.as_slice()))
+            .collect::<Vec<_>>(),
+        options.minify,
+        &snapshots,
+    )
+}
 
-        let icons = make_icons(&src_dir);
+// Parses each style's SVG files, returning the style, the directory its files are in, and its icons.
+// Nothing is written until every file has been parsed, so that all of the invalid files are
+// reported at once, and so that the crate isn't left half-generated.
+fn parse_styles<'a>(
+    heroicons: &Path,
+    options: &'a Options,
+) -> io::Result<Vec<(&'a str, PathBuf, Vec<Icon>)>> {
+    let mut invalid = vec![];
+    let mut parsed = vec![];
+    for style in options.style_dirs.styles() {
+        let src_dir = options.style_dirs.dir(heroicons, style)?;
+        let (icons, errors) = parse_icons_lenient(&src_dir);
+        invalid.extend(errors);
+        parsed.push((style, src_dir, icons));
+    }
+    if !invalid.is_empty() {
+        if !options.skip_invalid {
+            return Err(invalid_data(format!(
+                "{} SVG files could not be parsed, so nothing was generated. Fix them, or pass \
+                 --skip-invalid to generate the code without them.\n  {}",
+                invalid.len(),
+                invalid.iter().join("\n  "),
+            )));
+        }
+        for e in invalid {
+            eprintln!("warning: skipping {e}");
+        }
+    }
+    Ok(parsed)
+}
 
-        let mut to = args.to.clone();
-        to.push(&format!("{}.rs", style));
+// Writes a style crate's code and icon features, if the style is selected. Every style is parsed,
+// since the main crate's features need all of their icons, but only the selected ones are written,
+// and only the files for the icons that the filter matches.
+fn write_style_crate(
+    style: &str,
+    generator: &Generator,
+    renames: &BTreeMap<String, String>,
+    crate_dir: &Path,
+    selection: &Selection,
+) -> io::Result<()> {
+    if !selection.styles.iter().any(|s| s == style) {
+        return Ok(());
+    }
+    let icons = generator.icons();
+    let style_dir = crate_dir.join("crates").join(style);
+    print_summary(style, icons, renames, &style_dir.join("Cargo.toml"))?;
+    let header = STYLE_HEADER.trim_start().replace("{STYLE}", style);
+    if selection.filter.is_empty() {
+        generator.write_split(style_dir.join("src"), &header)
+    } else {
+        generator.write_split_matching(style_dir.join("src"), &header, |i| selection.includes(i))
+    }?;
+    let names = icons
+        .iter()
+        .map(|i| i.name.clone())
+        .collect::<BTreeSet<_>>();
+    write_features(
+        &names.iter().map(|n| (n.clone(), vec![])).collect(),
+        None,
+        &renamed(renames, &names),
+        &style_dir.join("Cargo.toml"),
+    )
+}
 
-        write_icons_file(&icons, &to);
+// Prints how many icons a style has, and which ones were added or removed since the style crate was
+// last generated, going by the icon features in its manifest. An icon that was removed might have
+// been renamed, so this warns about each one that isn't in the renames file.
+fn print_summary(
+    style: &str,
+    icons: &[Icon],
+    renames: &BTreeMap<String, String>,
+    manifest: &Path,
+) -> io::Result<()> {
+    let old = icon_features(&files::read(manifest)?);
+    let new = icons
+        .iter()
+        .map(|i| i.name.clone())
+        .collect::<BTreeSet<_>>();
+    let added = new.difference(&old).collect::<Vec<_>>();
+    let removed = old.difference(&new).collect::<Vec<_>>();
+    info!(
+        "{}: {} icons, {} added, {} removed",
+        style,
+        icons.len(),
+        added.len(),
+        removed.len(),
+    );
+    if !added.is_empty() {
+        info!("  added: {}", added.iter().join(", "));
+    }
+    if !removed.is_empty() {
+        info!("  removed: {}", removed.iter().join(", "));
+    }
+    for name in removed.iter().filter(|n| !renames.contains_key(**n)) {
+        eprintln!(
+            "warning: {name} was removed from {style}, so if heroicons renamed it, add it to the \
+             renames file"
+        );
     }
+    Ok(())
 }
 
-fn make_icons(src_dir: &PathBuf) -> Vec<Icon> {
-    let mut icons: Vec<Icon> = vec![];
+// Returns the renames whose new icon is one of the given names and whose old icon isn't, since
+// those are the ones that need a feature for the old name.
+fn renamed(
+    renames: &BTreeMap<String, String>,
+    names: &BTreeSet<String>,
+) -> BTreeMap<String, String> {
+    renames
+        .iter()
+        .filter(|(old, new)| names.contains(*new) && !names.contains(*old))
+        .map(|(old, new)| (old.clone(), new.clone()))
+        .collect()
+}
 
-    let svg_sel = Selector::parse("svg").unwrap();
-    let path_sel = Selector::parse("path").unwrap();
-    for entry in WalkDir::new(src_dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().is_file() && e.file_name().to_string_lossy().ends_with(".svg"))
-        .sorted_by(|a, b| Ord::cmp(a.file_name(), b.file_name()))
-    {
-        let name = entry
-            .file_name()
-            .to_str()
-            .unwrap()
-            .trim_end_matches(".svg")
-            .to_upper_camel_case();
-
-        let content = fs::read_to_string(entry.path()).unwrap();
-        let frag = Html::parse_fragment(&content);
-        let svg = frag.select(&svg_sel).next().unwrap();
-
-        icons.push(Icon {
-            name,
-            viewbox: svg.value().attr("viewBox").unwrap().to_string(),
-            path: svg
-                .select(&path_sel)
-                .map(|e| e.value().attr("d").unwrap().to_string())
-                .collect::<Vec<_>>()
-                .join(" "),
-            clip_rule: svg
-                .select(&path_sel)
-                .find_map(|e| e.value().attr("clip-rule"))
-                .map(|r| r.to_string()),
-            fill_rule: svg
-                .select(&path_sel)
-                .find_map(|e| e.value().attr("fill-rule"))
-                .map(|r| r.to_string()),
-        });
+// Reads the tags from each file and merges them, so an icon's tags are the ones from every file
+// that lists it, in the order of the files.
+fn read_tags(files: &[PathBuf]) -> io::Result<BTreeMap<String, Vec<String>>> {
+    let mut tags = BTreeMap::new();
+    for file in files {
+        let file_tags: BTreeMap<String, Vec<String>> = files::read_json(file)?;
+        for (name, t) in file_tags {
+            tags.entry(name).or_insert_with(Vec::new).extend(t);
+        }
     }
+    Ok(tags)
+}
 
+// Returns the names of the icons that point or read in a direction, and so should be mirrored in a
+// right-to-left layout. An icon with "left" or "right" as one of the words in its name, like
+// `arrow-left` or `bars-3-bottom-right`, is directional, unless the overrides say it isn't, and
+// the overrides can also add icons whose names don't say which way they point, like
+// `paper-airplane`.
+fn directional(icons: &[Icon], overrides: &BTreeMap<String, bool>) -> BTreeSet<String> {
     icons
+        .iter()
+        .filter(|i| {
+            overrides
+                .get(&i.name)
+                .copied()
+                .unwrap_or_else(|| i.name.split('-').any(|w| w == "left" || w == "right"))
+        })
+        .map(|i| i.name.clone())
+        .collect()
 }
 
-const TEMPLATE: &str = r#"
-use dioxus::prelude::*;
-
-pub(crate) const VIEW_BOX: &str = "{VIEWBOX}";
+// Reads the discriminants of each style's icons, by style and then by icon name, which are kept in a
+// file so that they stay the same when the icons change. A file that doesn't exist yet has none.
+fn read_discriminants(file: &Path) -> io::Result<BTreeMap<String, BTreeMap<String, u32>>> {
+    if file.exists() {
+        files::read_json(file)
+    } else {
+        Ok(BTreeMap::new())
+    }
+}
 
-/// All available icon shapes
-///
-/// See the enum variants for the shape names. These names are always the
-/// CamelCase version of the original heroicon name. So for example,
-/// "arrow-narrow-left" becomes `ArrowNarrowLeft`.
-#[allow(dead_code)]
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub enum Shape {
-    {NAMES}
+fn style_crate(style: &str) -> String {
+    format!("dioxus-heroicons-{style}")
 }
 
-impl crate::IconShape for Shape {
-    fn view_box(&self) -> &str {
-        VIEW_BOX
+// Finds every `style::Shape::Name` in the crate's own code.
+fn shapes_used_by_crate(src_dir: &Path, styles: &[&str]) -> io::Result<HashSet<String>> {
+    let mut used = HashSet::new();
+    for entry in WalkDir::new(src_dir) {
+        let entry = entry?;
+        if !entry.file_type().is_file() || !entry.file_name().to_string_lossy().ends_with(".rs") {
+            continue;
+        }
+        let content = files::read(entry.path())?;
+        for style in styles {
+            let prefix = format!("{style}::Shape::");
+            for (i, _) in content.match_indices(&prefix) {
+                let name = content[i + prefix.len()..]
+                    .chars()
+                    .take_while(char::is_ascii_alphanumeric)
+                    .collect::<String>();
+                used.insert(format!("{prefix}{name}"));
+            }
+        }
     }
+    Ok(used)
+}
+
+// The docs at the top of each style crate's lib.rs.
+const STYLE_HEADER: &str = r"
+//! The {STYLE} [heroicons](https://heroicons.com/) shapes.
+//!
+//! This crate has a [`Shape`] enum with all of the {STYLE} shapes, and a [`StaticShape`] static
+//! for each shape. It's re-exported as the `{STYLE}` module of
+//! [`dioxus-heroicons`](https://docs.rs/dioxus-heroicons), which has the Dioxus components that
+//! draw these shapes, so most apps should depend on that crate instead of this one.
+//!
+//! There is one feature for each icon, named after its heroicons name, like `arrow-left`. The
+//! `all-icons` feature, which is on by default, enables all of them.
+//!
+//! This crate was written by the `gen` crate in the dioxus-heroicons repo, so don't edit it by hand.
+";
+
+// Replaces everything between the start and end markers in a manifest with one feature per icon,
+// plus an `all-icons` feature that enables all of them. If `components` is given, this also writes
+// a `components` feature with those dependencies, since it has to enable the shapes that the
+// components use in the style crates. Each of the `renames` gets a feature with its old name that
+// enables the feature with its new name.
+fn write_features(
+    features: &BTreeMap<String, Vec<String>>,
+    components: Option<&[String]>,
+    renames: &BTreeMap<String, String>,
+    manifest: &Path,
+) -> io::Result<()> {
+    let content = files::read(manifest)?;
+    let marker = |marker: &str| {
+        content.find(marker).ok_or_else(|| {
+            invalid_data(format!(
+                "{} does not have a \"{}\" line to mark where the icon features go",
+                manifest.display(),
+                marker,
+            ))
+        })
+    };
+    let (start, end) = (marker(FEATURES_START)?, marker(FEATURES_END)?);
 
-    #[allow(clippy::too_many_lines)]
-    fn path(&self) -> Element {
-        match self {
-            {PATHS}
+    let mut generated = vec![FEATURES_START.to_string()];
+    if let Some(components) = components {
+        generated.push("components = [".to_string());
+        generated.extend(components.iter().map(|c| format!("    \"{c}\",")));
+        generated.push("]".to_string());
+    }
+    generated.push("all-icons = [".to_string());
+    generated.extend(features.keys().map(|f| format!("    \"{f}\",")));
+    generated.push("]".to_string());
+    generated.extend(features.iter().map(|(f, deps)| {
+        let deps = deps.iter().map(|d| format!("\"{d}\"")).collect::<Vec<_>>();
+        let line = format!("{} = [{}]", f, deps.join(", "));
+        // This wraps long features the same way that taplo does.
+        if line.len() <= 100 {
+            line
+        } else {
+            format!(
+                "{} = [\n{}\n]",
+                f,
+                deps.iter().map(|d| format!("    {d},")).join("\n"),
+            )
         }
+    }));
+    if !renames.is_empty() {
+        generated.push(RENAMES_START.to_string());
+        generated.extend(
+            renames
+                .iter()
+                .map(|(old, new)| format!("{old} = [\"{new}\"]")),
+        );
     }
+
+    let content = format!(
+        "{}{}\n{}",
+        &content[..start],
+        generated.join("\n"),
+        &content[end..],
+    );
+    files::write(manifest, content)
 }
-"#;
 
-const PATH_TEMPLATE: &str = r#"
-Shape::{NAME} => rsx! {
-    path {
-        {ATTRS}
-    },
-},"#;
+#[cfg(test)]
+mod test {
+    use super::*;
+    use files::TempDir;
 
-fn write_icons_file(icons: &[Icon], to: &PathBuf) {
-    let names = icons
-        .iter()
-        .map(|i| i.name.as_str())
-        .collect::<Vec<_>>()
-        .join(",\n");
+    #[test]
+    fn glob_matches() {
+        assert!(glob_match("arrow-*", "arrow-left"));
+        assert!(glob_match("arrow-*", "arrow-"));
+        assert!(glob_match("*-left", "arrow-left"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("a*b*c", "abbbc"));
+        assert!(glob_match("arrow-?p", "arrow-up"));
+        assert!(glob_match("???", "cog"));
+        assert!(glob_match("", ""));
 
-    let paths = icons
-        .iter()
-        .map(|i| {
-            let attrs = &[
-                attr("d", Some(i.path.as_ref()), false),
-                attr("clip_rule", i.clip_rule.as_deref(), true),
-                attr("fill_rule", i.fill_rule.as_deref(), true),
-            ]
-            .iter()
-            .filter_map(|a| a.as_deref())
-            .collect::<Vec<_>>()
-            .join("\n");
-            PATH_TEMPLATE
-                .replace("{NAME}", &i.name)
-                .replace("{ATTRS}", attrs)
-        })
-        .collect::<Vec<_>>()
-        .join("");
-
-    let code = TEMPLATE
-        .replace("{VIEWBOX}", &icons[0].viewbox)
-        .replace("{NAMES}", &names)
-        .replace("{PATHS}", &paths);
-
-    fs::write(to, code).unwrap();
-    Command::new("rustfmt").arg(to).output().unwrap();
-}
-
-// rustfmt gets confused about indentation in rsx! blocks and will indent the
-// first attribute properly, but not the following, so we have to indent all
-// but the first attribute manually.
-fn attr(name: &str, value: Option<&str>, indent: bool) -> Option<String> {
-    value.map(|v| {
-        format!(
-            r#"{}{}: "{}","#,
-            if indent { "        " } else { "" },
-            name,
-            v,
+        // A pattern has to match the whole name, not just part of it.
+        assert!(!glob_match("arrow", "arrow-left"));
+        assert!(!glob_match("left", "arrow-left"));
+        assert!(!glob_match("*-right", "arrow-left"));
+        assert!(!glob_match("arrow-?", "arrow-up"));
+        assert!(!glob_match("??", "cog"));
+        assert!(!glob_match("?", ""));
+        assert!(!glob_match("", "cog"));
+    }
+
+    #[test]
+    fn writes_features() {
+        let dir = TempDir::new("gen-test-features").unwrap();
+        let manifest = dir.path().join("Cargo.toml");
+        files::write(
+            &manifest,
+            format!(
+                "[features]\ndefault = [\"all-icons\"]\n{FEATURES_START}\nold = []\n{FEATURES_END}\nother = []\n"
+            ),
         )
-    })
+        .unwrap();
+
+        let long = (0..10)
+            .map(|i| format!("dependency-{i}"))
+            .collect::<Vec<_>>();
+        let features = vec![
+            ("arrow-left".to_string(), vec![]),
+            ("cog".to_string(), vec!["dep:cog".to_string()]),
+            ("long".to_string(), long),
+        ]
+        .into_iter()
+        .collect();
+        let components = ["arrow-left".to_string()];
+        let renames = vec![("old-cog".to_string(), "cog".to_string())]
+            .into_iter()
+            .collect();
+        write_features(&features, Some(&components), &renames, &manifest).unwrap();
+
+        let content = files::read(&manifest).unwrap();
+        let long_deps = (0..10)
+            .map(|i| format!("    \"dependency-{i}\","))
+            .join("\n");
+        assert_eq!(
+            content,
+            format!(
+                "[features]\ndefault = [\"all-icons\"]\n{FEATURES_START}\ncomponents = [\n    \"arrow-left\",\n]\n\
+                 all-icons = [\n    \"arrow-left\",\n    \"cog\",\n    \"long\",\n]\narrow-left = []\n\
+                 cog = [\"dep:cog\"]\nlong = [\n{long_deps}\n]\n{RENAMES_START}\nold-cog = [\"cog\"]\n\
+                 {FEATURES_END}\nother = []\n"
+            ),
+        );
+        assert_eq!(
+            icon_features(&content),
+            ["arrow-left", "cog", "long"]
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        );
+    }
+
+    #[test]
+    fn write_features_without_markers() {
+        let dir = TempDir::new("gen-test-no-markers").unwrap();
+        let manifest = dir.path().join("Cargo.toml");
+        files::write(&manifest, format!("[features]\n{FEATURES_START}\n")).unwrap();
+        let err = write_features(&BTreeMap::new(), None, &BTreeMap::new(), &manifest).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{} does not have a \"{FEATURES_END}\" line to mark where the icon features go",
+                manifest.display(),
+            ),
+        );
+    }
 }
@@ -0,0 +1,180 @@
+use crate::files::{self, invalid_data};
+use itertools::Itertools;
+use std::{
+    collections::BTreeMap,
+    env, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const META_TEMPLATE: &str = r#"//! Information about the heroicons that this crate's shapes were generated from, for showing where
+//! the icons came from or checking that an app was built with the icons it expects.
+//!
+//! This module was written by the `gen` crate, so don't edit it by hand.
+
+use crate::ShapeStyle::{self, {STYLE_VARIANTS}};
+
+/// The version of heroicons that the shapes come from, like "2.0.13".
+pub const HEROICONS_VERSION: &str = "{VERSION}";
+
+/// When the shapes were generated, as an RFC 3339 timestamp in UTC.
+pub const GENERATED_AT: &str = "{GENERATED_AT}";
+{COUNTS}
+/// The styles that each icon is in, by its heroicons name, sorted by name. Not every icon is in
+/// every style, so code that switches between styles can use this to find the ones that it can
+/// switch to. This lists every icon, including those whose features are off.
+pub const VARIANTS: &[(&str, &[ShapeStyle])] = &[
+{VARIANTS}];
+
+/// Returns the styles that heroicons has an icon in, in the order of [`ShapeStyle`], or an empty
+/// slice if it doesn't have an icon with that name.
+#[must_use]
+pub fn variants(name: &str) -> &'static [ShapeStyle] {
+    VARIANTS
+        .binary_search_by(|(n, _)| (*n).cmp(name))
+        .map_or(&[], |i| VARIANTS[i].1)
+}
+
+/// Returns true if heroicons has an icon in a style. The shape might still not be compiled in, if
+/// its style or icon feature is off.
+#[must_use]
+pub fn available_in(name: &str, style: ShapeStyle) -> bool {
+    variants(name).contains(&style)
+}
+
+/// Returns `style` if heroicons has an icon in it, or else the first style that has the icon, so
+/// that an app which lets users pick a style can fall back to another one for an icon that's
+/// missing from theirs. Returns `None` if there's no icon with that name.
+#[must_use]
+pub fn style_or_fallback(name: &str, style: ShapeStyle) -> Option<ShapeStyle> {
+    let variants = variants(name);
+    if variants.contains(&style) {
+        Some(style)
+    } else {
+        variants.first().copied()
+    }
+}
+"#;
+
+const COUNT_TEMPLATE: &str = r"
+/// The number of shapes in the `{STYLE}` style, including any whose features are off. Compare this
+/// to the length of `{STYLE}::Shape::ALL` to see how many of them are enabled.
+pub const {CONST}_ICON_COUNT: usize = {COUNT};
+";
+
+// Returns the heroicons version from the `version` in the repo's `package.json`.
+pub fn heroicons_version(heroicons: &Path) -> io::Result<String> {
+    let file = heroicons.join("package.json");
+    let package: serde_json::Value = files::read_json(&file).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!(
+                "could not read the heroicons version, so pass --heroicons-version instead: {e}",
+            ),
+        )
+    })?;
+    package["version"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| invalid_data(format!("{} does not have a version", file.display())))
+}
+
+// Writes the `meta` module with the heroicons version, the time, the number of icons in each style,
+// and the styles that each icon is in.
+pub fn write_meta(
+    version: &str,
+    counts: &[(&str, usize)],
+    variants: &BTreeMap<String, Vec<&str>>,
+    file: &Path,
+) -> io::Result<()> {
+    let style_variants = counts
+        .iter()
+        .map(|(style, _)| style_variant(style))
+        .join(", ");
+    let counts = counts
+        .iter()
+        .map(|(style, count)| {
+            COUNT_TEMPLATE
+                .replace("{STYLE}", style)
+                .replace("{CONST}", &style.to_uppercase())
+                .replace("{COUNT}", &count.to_string())
+        })
+        .collect::<Vec<_>>()
+        .concat();
+    let code = META_TEMPLATE
+        .replace("{VERSION}", version)
+        .replace("{GENERATED_AT}", &timestamp())
+        .replace("{COUNTS}", &counts)
+        .replace("{STYLE_VARIANTS}", &style_variants)
+        .replace("{VARIANTS}", &variants_code(variants));
+    files::write(file, code)
+}
+
+fn variants_code(variants: &BTreeMap<String, Vec<&str>>) -> String {
+    variants
+        .iter()
+        .map(|(name, styles)| {
+            // The styles are sorted by name, which is also the order of the `ShapeStyle` variants.
+            let styles = styles.iter().sorted().map(|s| style_variant(s));
+            format!("    (\"{}\", &[{}]),\n", name, styles.format(", "))
+        })
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+// Returns the current time as an RFC 3339 timestamp in UTC, or the time in `SOURCE_DATE_EPOCH` if
+// it's set, so that the output can be reproduced.
+fn timestamp() -> String {
+    let secs = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs())
+        });
+    rfc3339(secs)
+}
+
+// Formats a number of seconds since the Unix epoch as an RFC 3339 timestamp in UTC.
+fn rfc3339(secs: u64) -> String {
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+
+    // This converts days since the epoch to a date, using the algorithm from
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days. The days are never
+    // negative, so its signed arithmetic isn't needed.
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60,
+    )
+}
+
+// Returns the name of a style's `ShapeStyle` variant, like `Mini`.
+fn style_variant(style: &str) -> String {
+    format!("{}{}", style[..1].to_uppercase(), &style[1..])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(rfc3339(0), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc3339(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(rfc3339(1_672_531_199), "2022-12-31T23:59:59Z");
+        assert_eq!(rfc3339(1_709_210_096), "2024-02-29T12:34:56Z");
+    }
+}
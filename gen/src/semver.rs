@@ -0,0 +1,287 @@
+use crate::{
+    check::{regenerate, relative_files},
+    files, Options,
+};
+use itertools::Itertools;
+use std::{cmp::Reverse, collections::BTreeMap, fmt, io, path::Path};
+
+// What kind of release a regeneration needs, from the least to the most disruptive, so the kind
+// for several changes is the greatest of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bump {
+    None,
+    Additive,
+    Breaking,
+}
+
+impl fmt::Display for Bump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Bump::None => "no changes",
+            Bump::Additive => "additive",
+            Bump::Breaking => "breaking",
+        })
+    }
+}
+
+// How a style's shapes would change if the crate were regenerated.
+#[derive(Debug, Default)]
+struct StyleChanges {
+    added: Vec<String>,
+    // Shapes that were removed, but whose old names stay as deprecated aliases for their new ones.
+    renamed: Vec<(String, String)>,
+    removed: Vec<String>,
+    // Shapes whose view box or paths changed.
+    changed: Vec<String>,
+}
+
+impl StyleChanges {
+    fn bump(&self) -> Bump {
+        if !self.removed.is_empty() || !self.changed.is_empty() {
+            Bump::Breaking
+        } else if !self.added.is_empty() || !self.renamed.is_empty() {
+            Bump::Additive
+        } else {
+            Bump::None
+        }
+    }
+}
+
+// Regenerates the crate in a temporary directory, like `check`, and prints how each style's shapes
+// would change, classifying each change as additive or breaking. New shapes are additive, and so
+// are renamed ones that are in the renames file, since their old names still work. Removed shapes
+// and shapes that would be drawn differently are breaking, since code that uses them would stop
+// compiling or would draw something else.
+pub fn semver_report(heroicons: &Path, crate_dir: &Path, options: &Options) -> io::Result<()> {
+    let work_dir = regenerate(heroicons, crate_dir, options)?;
+    match print_changes(crate_dir, work_dir.path(), options)? {
+        Bump::None => println!("regenerating the crate would not change any shapes"),
+        Bump::Additive => println!(
+            "regenerating the crate only adds shapes, so it needs a release that adds features"
+        ),
+        Bump::Breaking => println!(
+            "regenerating the crate removes or changes shapes, so it needs a breaking release"
+        ),
+    }
+    Ok(())
+}
+
+// Prints how each style's shapes differ between the crate and a copy of it that was regenerated in
+// the work dir, and returns the kind of release that the differences need.
+pub fn print_changes(crate_dir: &Path, work_dir: &Path, options: &Options) -> io::Result<Bump> {
+    let renames: BTreeMap<String, String> = files::read_json(&options.renames_file)?;
+    let mut bump = Bump::None;
+    for style in options.style_dirs.styles() {
+        let src = Path::new("crates").join(style).join("src");
+        let changes = compare(
+            &shapes(&crate_dir.join(&src))?,
+            &shapes(&work_dir.join(&src))?,
+            &renames,
+        );
+        bump = bump.max(changes.bump());
+        println!("{}: {}", style, changes.bump());
+        for (kind, names) in [
+            ("added", &changes.added),
+            ("removed", &changes.removed),
+            ("changed", &changes.changed),
+        ] {
+            if !names.is_empty() {
+                println!("  {}: {}", kind, names.join(", "));
+            }
+        }
+        if !changes.renamed.is_empty() {
+            println!(
+                "  renamed: {}",
+                changes
+                    .renamed
+                    .iter()
+                    .map(|(old, new)| format!("{old} -> {new}"))
+                    .join(", "),
+            );
+        }
+    }
+    Ok(bump)
+}
+
+fn compare(
+    old: &BTreeMap<String, String>,
+    new: &BTreeMap<String, String>,
+    renames: &BTreeMap<String, String>,
+) -> StyleChanges {
+    let mut changes = StyleChanges::default();
+    for (name, code) in new {
+        match old.get(name) {
+            None => changes.added.push(name.clone()),
+            Some(old_code) if old_code != code => changes.changed.push(name.clone()),
+            Some(_) => (),
+        }
+    }
+    for name in old.keys().filter(|n| !new.contains_key(*n)) {
+        match renames.get(name) {
+            Some(new_name) if new.contains_key(new_name) => {
+                changes.renamed.push((name.clone(), new_name.clone()));
+            }
+            _ => changes.removed.push(name.clone()),
+        }
+    }
+    changes
+}
+
+// Returns the arguments to `StaticShape::new` for each shape's static in a style crate's `src`
+// directory, which are its name, view box, and paths, by its heroicons name. Each static starts
+// with a `/// The "name" shape.` doc comment and ends with `);`, and can be in any of the files,
+// depending on the crate's layout. Its docs and attributes are left out, since changing them
+// doesn't change how the shape is drawn. The `super::` paths are dropped and the shapes that use
+// the crate's `VIEW_BOX` const or one of its shared path data consts get its value in their code
+// instead, so that only a change to a shape's paths or to a const shows up as a change to the
+// shape, not a change to the layout.
+fn shapes(src_dir: &Path) -> io::Result<BTreeMap<String, String>> {
+    const START: &str = "/// The \"";
+    const ARGS: &str = "StaticShape::new(";
+    const END: &str = "\n);\n";
+    let mut shapes = BTreeMap::new();
+    let mut consts = vec![];
+    for file in relative_files(src_dir)? {
+        let content = files::read(&src_dir.join(&file))?;
+        if file == Path::new("lib.rs") {
+            consts = content
+                .lines()
+                .filter_map(|l| {
+                    l.strip_prefix("pub const ")
+                        .or_else(|| l.strip_prefix("const "))
+                })
+                .filter_map(|l| l.split_once(": &str = "))
+                .map(|(name, value)| (name.to_string(), value.trim_end_matches(';').to_string()))
+                .collect();
+        }
+        for code in content.split(START).skip(1) {
+            // Renamed shapes' deprecated consts have docs that start the same way.
+            if let Some((name, rest)) = code.split_once('"') {
+                if rest.starts_with(" shape.\n") {
+                    let code = code.find(END).map_or(code, |i| &code[..i + END.len()]);
+                    let code = code.find(ARGS).map_or(code, |i| &code[i + ARGS.len()..]);
+                    shapes.insert(name.to_string(), code.replace("super::", ""));
+                }
+            }
+        }
+    }
+    // Longer names go first, so that a name isn't replaced inside another one that starts with it.
+    consts.sort_by_key(|(name, _)| Reverse(name.len()));
+    for code in shapes.values_mut() {
+        for (name, value) in &consts {
+            *code = code.replace(name.as_str(), value);
+        }
+    }
+    Ok(shapes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::files::TempDir;
+
+    // The code for a shape's static, like the generator writes it.
+    fn shape(name: &str, d: &str, cfg: &str) -> String {
+        format!(
+            "/// The \"{name}\" shape.\n{cfg}pub static {}: super::StaticShape = super::StaticShape::new(\n    \
+             \"{name}\",\n    super::VIEW_BOX,\n    &[\n        super::PathData {{\n            d: \"{d}\",\n            \
+             ..super::PathData::EMPTY\n        }},\n    ],\n);\n",
+            name.to_uppercase().replace('-', "_"),
+        )
+    }
+
+    // Writes a style crate's `src` with a `lib.rs` that has the shapes' tags and an `a.rs` with the
+    // shapes, and returns their code by name.
+    fn crate_shapes(
+        dir: &TempDir,
+        name: &str,
+        tags: &str,
+        shapes: &[String],
+    ) -> BTreeMap<String, String> {
+        let src = dir.path().join(name);
+        files::create_dir_all(&src).unwrap();
+        files::write(
+            &src.join("lib.rs"),
+            format!(
+                "pub const VIEW_BOX: &str = \"0 0 24 24\";\nstatic ENTRIES: &[Option<Entry>] = &[{tags}];\n"
+            ),
+        )
+        .unwrap();
+        files::write(&src.join("a.rs"), shapes.concat()).unwrap();
+        super::shapes(&src).unwrap()
+    }
+
+    fn bump(
+        old: &BTreeMap<String, String>,
+        new: &BTreeMap<String, String>,
+        renames: &[(&str, &str)],
+    ) -> Bump {
+        let renames = renames
+            .iter()
+            .map(|(old, new)| (old.to_string(), new.to_string()))
+            .collect();
+        compare(old, new, &renames).bump()
+    }
+
+    #[test]
+    fn compare_shapes() {
+        let dir = TempDir::new("gen-test-semver").unwrap();
+        let arrow = shape("arrow", "M0 0L1 1", "");
+        let cog = shape("cog", "M2 2L3 3", "");
+        let old = crate_shapes(&dir, "old", "", &[arrow.clone(), cog.clone()]);
+        assert_eq!(
+            old["arrow"],
+            "\n    \"arrow\",\n    \"0 0 24 24\",\n    &[\n        PathData {\n            \
+             d: \"M0 0L1 1\",\n            ..PathData::EMPTY\n        },\n    ],\n);\n",
+        );
+
+        let added = crate_shapes(
+            &dir,
+            "added",
+            "",
+            &[arrow.clone(), cog.clone(), shape("bell", "M4 4", "")],
+        );
+        let changes = compare(&old, &added, &BTreeMap::new());
+        assert_eq!(changes.added, ["bell"]);
+        assert_eq!(changes.bump(), Bump::Additive);
+
+        let removed = crate_shapes(&dir, "removed", "", std::slice::from_ref(&arrow));
+        let changes = compare(&old, &removed, &BTreeMap::new());
+        assert_eq!(changes.removed, ["cog"]);
+        assert_eq!(changes.bump(), Bump::Breaking);
+
+        // A rename is breaking unless the old name stays as an alias for the new one.
+        let renamed = crate_shapes(
+            &dir,
+            "renamed",
+            "",
+            &[arrow.clone(), shape("gear", "M2 2L3 3", "")],
+        );
+        assert_eq!(bump(&old, &renamed, &[]), Bump::Breaking);
+        assert_eq!(bump(&old, &renamed, &[("cog", "gear")]), Bump::Additive);
+
+        let redrawn = crate_shapes(
+            &dir,
+            "redrawn",
+            "",
+            &[arrow.clone(), shape("cog", "M2 2L3 4", "")],
+        );
+        let changes = compare(&old, &redrawn, &BTreeMap::new());
+        assert_eq!(changes.changed, ["cog"]);
+        assert_eq!(changes.bump(), Bump::Breaking);
+
+        // Changing a shape's tags, docs, or attributes doesn't change how it's drawn.
+        let retagged = crate_shapes(
+            &dir,
+            "retagged",
+            "Some((Shape::Cog, &COG, &[\"settings\"], false))",
+            &[
+                arrow.replace("shape.\n", "shape.\n/// An arrow.\n"),
+                shape("cog", "M2 2L3 3", "#[cfg(feature = \"cog\")]\n"),
+            ],
+        );
+        let changes = compare(&old, &retagged, &BTreeMap::new());
+        assert!(changes.changed.is_empty());
+        assert_eq!(changes.bump(), Bump::None);
+    }
+}
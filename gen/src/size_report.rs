@@ -0,0 +1,290 @@
+use crate::{
+    files::{self, TempDir},
+    styles::StyleDirArgs,
+};
+use heroicons_codegen::{parse_icons, Icon};
+use itertools::Itertools;
+use std::{
+    convert::TryFrom,
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+#[derive(Debug, clap::Args)]
+pub struct SizeReportArgs {
+    /// Path to the heroicons repo
+    #[clap(long)]
+    heroicons: PathBuf,
+    /// Path to the dioxus-heroicons crate to measure
+    #[clap(long = "crate", default_value = ".")]
+    crate_dir: PathBuf,
+    /// The target to build for, like wasm32-unknown-unknown. This defaults to the host.
+    #[clap(long)]
+    target: Option<String>,
+    /// The number of icons to list, largest first
+    #[clap(long, default_value_t = 20)]
+    top: usize,
+    #[clap(flatten)]
+    style_dirs: StyleDirArgs,
+}
+
+// The program that each build compiles. It prints every enabled shape, so none of them can be
+// optimized away.
+const MAIN: &str = r"
+fn main() {
+{STYLES}}
+";
+
+// The loop in `MAIN` that prints one style's shapes.
+const STYLE_LOOP: &str = r#"    for s in dioxus_heroicons::{STYLE}::Shape::ALL {
+        println!("{} {} {:?}", s.name(), s.view_box(), s.path_data());
+    }
+"#;
+
+const MANIFEST: &str = r#"
+[package]
+name = "size-report"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[dependencies]
+dioxus-heroicons = { path = "{CRATE}", default-features = false, features = [{STYLES}] }
+{STYLE_DEPENDENCY}
+
+[profile.release]
+opt-level = "s"
+lto = true
+codegen-units = 1
+panic = "abort"
+strip = true
+
+[workspace]
+"#;
+
+// One style's measurements. Each style is built three times: without any icons (the baseline,
+// shared by all styles), with just its first icon, and with all of its icons. The difference between
+// the last two is split across the icons by the size of their path data, and whatever the first
+// icon doesn't account for is the style's fixed cost, like its tables and methods.
+struct StyleSize<'a> {
+    style: &'a str,
+    icons: Vec<Icon>,
+    total: i64,
+    fixed: i64,
+    per_byte: f64,
+}
+
+pub fn size_report(args: &SizeReportArgs) -> io::Result<()> {
+    let crate_dir =
+        fs::canonicalize(&args.crate_dir).map_err(|e| files::with_path(&e, &args.crate_dir))?;
+    let style_dirs = args.style_dirs.resolve(&crate_dir)?;
+    let temp_dir = TempDir::new("dioxus-heroicons-size-report")?;
+    let work_dir = temp_dir.path();
+    files::create_dir_all(&work_dir.join("src"))?;
+    let styles = style_dirs.styles();
+    let main = styles
+        .iter()
+        .map(|s| STYLE_LOOP.replace("{STYLE}", s))
+        .collect::<String>();
+    files::write(
+        &work_dir.join("src").join("main.rs"),
+        MAIN.trim_start().replace("{STYLES}", &main),
+    )?;
+    let build = |style: &str, features: &[&str]| {
+        build_size(
+            work_dir,
+            &crate_dir,
+            args.target.as_deref(),
+            &styles,
+            style,
+            features,
+        )
+    };
+
+    let baseline = build(styles[0], &[])?;
+    let mut sizes = vec![];
+    for &style in &styles {
+        let dir = style_dirs.dir(&args.heroicons, style)?;
+        let icons = parse_icons(&dir)?;
+        let first = icons.first().ok_or_else(|| {
+            files::invalid_data(format!("{} does not have any icons", dir.display()))
+        })?;
+        let single = build(style, &[&first.name])? - baseline;
+        let total = build(style, &["all-icons"])? - baseline;
+        let all_bytes = icons.iter().map(icon_bytes).sum::<usize>();
+        let per_byte = per_byte(total - single, all_bytes - icon_bytes(first));
+        let fixed = single - estimate(icon_bytes(first), per_byte);
+        sizes.push(StyleSize {
+            style,
+            icons,
+            total,
+            fixed,
+            per_byte,
+        });
+    }
+
+    println!(
+        "Sizes for {}, built with opt-level \"s\" and LTO",
+        args.target.as_deref().unwrap_or("the host target"),
+    );
+    println!();
+    println!(
+        "  baseline without any shapes: {} bytes",
+        separated(baseline)
+    );
+    println!();
+    println!(
+        "  {:<8} {:>6} {:>12} {:>10} {:>9}",
+        "style", "icons", "total", "fixed", "per icon",
+    );
+    for s in &sizes {
+        println!(
+            "  {:<8} {:>6} {:>12} {:>10} {:>9}",
+            s.style,
+            s.icons.len(),
+            signed(s.total),
+            signed(s.fixed),
+            signed((s.total - s.fixed) / i64::try_from(s.icons.len()).unwrap_or(i64::MAX)),
+        );
+    }
+
+    let mut icons = sizes
+        .iter()
+        .flat_map(|s| {
+            s.icons.iter().map(move |i| {
+                (
+                    s.style,
+                    i.name.as_str(),
+                    estimate(icon_bytes(i), s.per_byte),
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    icons.sort_by(|a, b| b.2.cmp(&a.2).then(a.1.cmp(b.1)));
+    println!();
+    println!(
+        "The {} largest icons, estimated from the size of their path data:",
+        args.top.min(icons.len()),
+    );
+    println!();
+    for (style, name, size) in icons.iter().take(args.top) {
+        println!("  {:<8} {:<32} {:>8}", style, name, signed(*size));
+    }
+    Ok(())
+}
+
+// Builds the size-report program with the given features of a style crate and returns the size of
+// the binary in bytes. The other style crates are built without any icons.
+fn build_size(
+    work_dir: &Path,
+    crate_dir: &Path,
+    target: Option<&str>,
+    styles: &[&str],
+    style: &str,
+    features: &[&str],
+) -> io::Result<i64> {
+    let features = features
+        .iter()
+        .map(|f| format!(r#""{f}""#))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let style_dependency = format!(
+        "dioxus-heroicons-{style} = {{ path = \"{{CRATE}}/crates/{style}\", \
+         default-features = false, features = [{features}] }}",
+    );
+    let manifest = MANIFEST
+        .trim_start()
+        .replace("{STYLE_DEPENDENCY}", &style_dependency)
+        .replace(
+            "{STYLES}",
+            &styles.iter().map(|s| format!(r#""{s}""#)).join(", "),
+        )
+        .replace("{CRATE}", &crate_dir.to_string_lossy());
+    files::write(&work_dir.join("Cargo.toml"), manifest)?;
+
+    let mut cmd = Command::new("cargo");
+    cmd.current_dir(work_dir).args(["build", "--release"]);
+    if let Some(target) = target {
+        cmd.args(["--target", target]);
+    }
+    let output = cmd
+        .output()
+        .map_err(|e| io::Error::new(e.kind(), format!("could not run cargo: {e}")))?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "building {} with features [{}] failed:\n{}",
+            style,
+            features,
+            String::from_utf8_lossy(&output.stderr),
+        )));
+    }
+
+    let mut binary = work_dir.join("target");
+    if let Some(target) = target {
+        binary.push(target);
+    }
+    binary.push("release");
+    binary.push(format!("size-report{}", binary_suffix(target)));
+    let metadata = fs::metadata(&binary).map_err(|e| files::with_path(&e, &binary))?;
+    i64::try_from(metadata.len())
+        .map_err(|_| files::invalid_data(format!("{} is too large to measure", binary.display())))
+}
+
+fn binary_suffix(target: Option<&str>) -> &'static str {
+    match target {
+        Some(t) if t.starts_with("wasm32") => ".wasm",
+        Some(t) if t.contains("windows") => ".exe",
+        Some(_) => "",
+        None => env::consts::EXE_SUFFIX,
+    }
+}
+
+// The number of bytes of data that an icon adds: its name, and its paths and their attributes.
+fn icon_bytes(icon: &Icon) -> usize {
+    icon.name.len()
+        + icon
+            .paths
+            .iter()
+            .map(|p| p.d.len() + p.attributes().iter().map(|(_, v)| v.len()).sum::<usize>())
+            .sum::<usize>()
+}
+
+// Returns the size per byte of path data. When a style only has one icon there are no other bytes
+// to split the size across, so this is 0 and the whole size counts as the style's fixed cost.
+//
+// The sizes and byte counts are far below 2^52, so converting them to `f64` doesn't lose anything.
+#[allow(clippy::cast_precision_loss)]
+fn per_byte(size: i64, bytes: usize) -> f64 {
+    if bytes == 0 {
+        0.0
+    } else {
+        size as f64 / bytes as f64
+    }
+}
+
+// Estimates the size that `bytes` of path data adds to the binary.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn estimate(bytes: usize, per_byte: f64) -> i64 {
+    (bytes as f64 * per_byte).round() as i64
+}
+
+fn signed(n: i64) -> String {
+    if n < 0 {
+        format!("-{}", separated(-n))
+    } else {
+        format!("+{}", separated(n))
+    }
+}
+
+fn separated(n: i64) -> String {
+    let digits = n.to_string();
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
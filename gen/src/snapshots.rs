@@ -0,0 +1,130 @@
+use crate::files::{self, invalid_data};
+use heroicons_codegen::{minify_path_data, Icon};
+use itertools::Itertools;
+use scraper::{Html, Selector};
+use std::{io, path::Path};
+
+const SNAPSHOTS_TEMPLATE: &str = r#"//! Renders every shape and compares it to the markup of the heroicons SVG that it was generated
+//! from, so that a change to the generator that drops or changes an attribute makes these fail.
+//!
+//! This file was written by the `gen` crate, so don't edit it by hand.
+
+#![cfg(all(feature = "all-icons", feature = "components"))]
+
+use dioxus_heroicons::{mini, outline, solid, IconShape};
+use html_compare_rs::HtmlComparer;
+
+/// Checks a shape's view box and the paths that it renders, both with `path` and `path_html`,
+/// against the markup from its SVG.
+fn check<S: IconShape>(shape: &S, view_box: &str, paths: &str) {
+    assert_eq!(shape.view_box(), view_box, "the view box for {shape:?}");
+    let compare = |method: &str, html: &str| {
+        if let Err(e) = HtmlComparer::new().compare(paths, html) {
+            panic!("the {} for {:?} doesn't match its SVG: {}", method, shape, e);
+        }
+    };
+    compare("path", &dioxus_ssr::render_element(shape.path()));
+    compare("path_html", shape.path_html().unwrap());
+}
+{TESTS}"#;
+
+const TEST_TEMPLATE: &str = r"
+#[test]
+fn {STYLE}_snapshots() {
+    for (shape, view_box, paths) in {CONST} {
+        check(shape, view_box, paths);
+    }
+}
+
+/// Each {STYLE} shape, with the view box and paths from its SVG.
+const {CONST}: &[({STYLE}::Shape, &str, &str)] = &[
+{SHAPES}];
+";
+
+// Writes a test file that compares every shape in each style to the markup of its source SVG. The
+// markup is read from the SVGs again, rather than taken from the parsed icons, so that it's
+// independent of the generator. Icons with shapes other than `<path>` are left out, since the
+// generator converts those to paths, and they wouldn't match their source. When the generator
+// minifies the path data, the `d` attributes are minified the same way.
+pub fn write_snapshots(
+    svg_dirs: &[(&str, &Path, &[Icon])],
+    minify: Option<u32>,
+    file: &Path,
+) -> io::Result<()> {
+    let svg_sel = Selector::parse("svg").unwrap();
+    let shape_sel = Selector::parse("svg *").unwrap();
+    let tests = svg_dirs
+        .iter()
+        .map(|(style, dir, icons)| {
+            let shapes = icons
+                .iter()
+                .map(|icon| {
+                    let svg_file = dir.join(format!("{}.svg", icon.name));
+                    let content = files::read(&svg_file)?;
+                    let frag = Html::parse_fragment(&content);
+                    let (svg, view_box) = frag
+                        .select(&svg_sel)
+                        .next()
+                        .and_then(|svg| Some((svg, svg.value().attr("viewBox")?)))
+                        .ok_or_else(|| {
+                            invalid_data(format!(
+                                "{} does not have an <svg> with a viewBox",
+                                svg_file.display(),
+                            ))
+                        })?;
+                    let mut paths = vec![];
+                    for e in svg.select(&shape_sel) {
+                        if e.value().name() != "path" {
+                            eprintln!(
+                                "warning: {} {} has a <{}>, so it has no snapshot test",
+                                style,
+                                icon.name,
+                                e.value().name(),
+                            );
+                            return Ok(None);
+                        }
+                        // The parser doesn't keep the attributes in order, so they're sorted to
+                        // keep the file the same each time it's generated.
+                        let attrs = e
+                            .value()
+                            .attrs()
+                            .sorted_by_key(|(name, _)| (*name != "d", *name))
+                            .map(|(name, value)| {
+                                let value = match minify {
+                                    Some(precision) if name == "d" => {
+                                        minify_path_data(value, precision)
+                                            .unwrap_or_else(|| value.to_string())
+                                    }
+                                    _ => value.to_string(),
+                                };
+                                format!(r#" {name}="{value}""#)
+                            })
+                            .join("");
+                        paths.push(format!("<path{attrs}></path>"));
+                    }
+                    Ok(Some(format!(
+                        "    ({}::Shape::{}, \"{}\", r#\"{}\"#),\n",
+                        style,
+                        icon.variant,
+                        view_box,
+                        paths.concat(),
+                    )))
+                })
+                .collect::<io::Result<Vec<_>>>()?
+                .into_iter()
+                .flatten()
+                .collect::<String>();
+            Ok(TEST_TEMPLATE
+                .replace("{CONST}", &style.to_uppercase())
+                .replace("{STYLE}", style)
+                .replace("{SHAPES}", &shapes))
+        })
+        .collect::<io::Result<String>>()?;
+    if let Some(dir) = file.parent() {
+        files::create_dir_all(dir)?;
+    }
+    // The generated code always parses, unless there's a bug in the template.
+    let code = syn::parse_file(&SNAPSHOTS_TEMPLATE.replace("{TESTS}", &tests))
+        .expect("the snapshot tests are valid Rust");
+    files::write(file, prettyplease::unparse(&code))
+}
@@ -0,0 +1,70 @@
+use crate::{files::invalid_data, glob_match, styles::StyleDirArgs};
+use heroicons_codegen::{parse_icons, Generator};
+use std::{io, path::PathBuf};
+
+#[derive(Debug, clap::Args)]
+pub struct SpriteArgs {
+    /// Path to the heroicons repo
+    #[clap(long)]
+    heroicons: PathBuf,
+    /// Path to the dioxus-heroicons crate, which has the directory for each style in its
+    /// `gen/style-dirs.json`
+    #[clap(long = "crate", default_value = ".")]
+    crate_dir: PathBuf,
+    /// The style of the shapes in the sprite sheet, which is one of the styles in --style-dirs
+    #[clap(long)]
+    style: String,
+    /// Only put the shapes whose names match one of these patterns in the sprite sheet, separated
+    /// by commas, like "arrow-*,x-mark". A `*` matches any number of characters and a `?` matches
+    /// one. This defaults to all of the shapes in the style.
+    #[clap(long, value_delimiter = ',')]
+    filter: Vec<String>,
+    /// The prefix for each symbol's `id`, which must match the `prefix` of the `UseIcon`s that
+    /// show them
+    #[clap(long, default_value = "heroicon")]
+    prefix: String,
+    /// Minify the shapes' path data, rounding its numbers to this many decimal places, like the
+    /// --minify option for generating the crate
+    #[clap(long, value_name = "DECIMALS")]
+    minify: Option<u32>,
+    /// Where to write the sprite sheet
+    #[clap(long, default_value = "heroicons-sprite.svg")]
+    svg: PathBuf,
+    /// Where to write the Rust module with a constant for each symbol's `id`
+    #[clap(long, default_value = "sprite_ids.rs")]
+    rust: PathBuf,
+    #[clap(flatten)]
+    style_dirs: StyleDirArgs,
+}
+
+// Writes a sprite sheet with a `<symbol>` for each of the selected shapes in a style, and a Rust
+// module with their `id`s, for an app to serve as a static file and show with `UseIcon`.
+pub fn write_sprite(args: &SpriteArgs) -> io::Result<()> {
+    let dir = args
+        .style_dirs
+        .resolve(&args.crate_dir)?
+        .dir(&args.heroicons, &args.style)?;
+    let icons = parse_icons(&dir)?
+        .into_iter()
+        .filter(|i| args.filter.is_empty() || args.filter.iter().any(|f| glob_match(f, &i.name)))
+        .collect::<Vec<_>>();
+    if icons.is_empty() {
+        return Err(invalid_data(format!(
+            "none of the {} icons in {} match the filter",
+            args.style,
+            dir.display(),
+        )));
+    }
+    let count = icons.len();
+    Generator::new(icons)
+        .minify(args.minify)
+        .write_sprite(&args.prefix, &args.svg, &args.rust)?;
+    info!(
+        "wrote {} {} shapes to {} and their ids to {}",
+        count,
+        args.style,
+        args.svg.display(),
+        args.rust.display(),
+    );
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use heroicons_codegen::Icon;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+// Prints a summary of the icons in each style: how many there are, how many have more than one
+// path, how many use each path attribute, and how many are duplicates of another icon. It also
+// warns about the icons that look wrong, like ones with empty path data, an unusual view box, or
+// the same paths as another icon, since those are usually mistakes in heroicons or the parser.
+pub fn print_stats(styles: &[(&str, &[Icon])]) {
+    info!("summary:");
+    for &(style, icons) in styles {
+        let multi_path = icons
+            .iter()
+            .filter(|i| i.paths.len() > 1)
+            .map(|i| i.name.as_str())
+            .collect::<Vec<_>>();
+        let duplicates = duplicates(icons);
+        info!(
+            "  {}: {} icons, {} with more than one path, {} duplicates",
+            style,
+            icons.len(),
+            multi_path.len(),
+            duplicates.iter().map(|d| d.len() - 1).sum::<usize>(),
+        );
+        let attributes = attribute_counts(icons);
+        info!(
+            "    attributes: {}",
+            if attributes.is_empty() {
+                "none".to_string()
+            } else {
+                attributes
+                    .iter()
+                    .map(|(name, count)| format!("{name} {count}"))
+                    .join(", ")
+            },
+        );
+        if !multi_path.is_empty() {
+            detail!("    more than one path: {}", multi_path.join(", "));
+        }
+
+        for names in &duplicates {
+            eprintln!(
+                "warning: the {} icons {} have the same paths",
+                style,
+                names.join(", "),
+            );
+        }
+        for icon in icons {
+            if icon.paths.iter().any(|p| p.d.trim().is_empty()) {
+                eprintln!("warning: {} {} has a path with no data", style, icon.name);
+            }
+        }
+        let view_boxes = icons.iter().map(|i| i.view_box.as_str()).counts();
+        if let Some((common, _)) = view_boxes.iter().max_by_key(|(_, count)| **count) {
+            for icon in icons.iter().filter(|i| i.view_box != *common) {
+                eprintln!(
+                    "warning: {} {} has a viewBox of \"{}\", but most {} icons have \"{}\"",
+                    style, icon.name, icon.view_box, style, common,
+                );
+            }
+        }
+    }
+}
+
+// Returns how many icons have at least one path with each attribute, by the attribute's SVG name.
+fn attribute_counts(icons: &[Icon]) -> BTreeMap<&'static str, usize> {
+    let mut counts = BTreeMap::new();
+    for icon in icons {
+        for name in icon
+            .paths
+            .iter()
+            .flat_map(|p| p.attributes())
+            .map(|(name, _)| name)
+            .unique()
+        {
+            *counts.entry(name).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+// Returns the names of each group of icons that have exactly the same paths.
+fn duplicates(icons: &[Icon]) -> Vec<Vec<&str>> {
+    let mut groups: Vec<Vec<&Icon>> = vec![];
+    for icon in icons {
+        match groups.iter_mut().find(|g| g[0].paths == icon.paths) {
+            Some(group) => group.push(icon),
+            None => groups.push(vec![icon]),
+        }
+    }
+    groups
+        .into_iter()
+        .filter(|g| g.len() > 1)
+        .map(|g| g.iter().map(|i| i.name.as_str()).collect())
+        .collect()
+}
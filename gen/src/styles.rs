@@ -0,0 +1,223 @@
+use crate::files;
+use itertools::Itertools;
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+const STYLES_TEMPLATE: &str = r"//! The names of the heroicons styles that there are crates for.
+//!
+//! This module was written by the `gen` crate from its `style-dirs.json`, so don't edit it by hand.
+
+/// The name of each style, like `mini`, in alphabetical order.
+pub const STYLES: &[&str] = &[{STYLES}];
+";
+
+#[derive(Debug, clap::Args)]
+pub struct StyleDirArgs {
+    /// Path to a JSON file with the directory under the heroicons repo's `src` that has each
+    /// style's SVG files, like `{"mini": "20/solid"}`. The styles in this file are the ones that
+    /// are generated, and each one needs a crate in the crate's `crates` directory. This defaults
+    /// to the crate's `gen/style-dirs.json`.
+    #[clap(long)]
+    style_dirs: Option<PathBuf>,
+    /// The directory for a style, like "mini=20/solid", which overrides the one in --style-dirs,
+    /// or adds a style that isn't in it. Separate several with commas.
+    #[clap(long, value_delimiter = ',', value_name = "STYLE=DIR", value_parser = parse_style_dir)]
+    style_dir: Vec<(String, String)>,
+}
+
+impl StyleDirArgs {
+    // Returns the directory for each style, from the file and then the overrides. A style without
+    // a crate is an error, since there's nowhere to write it.
+    pub fn resolve(&self, crate_dir: &Path) -> io::Result<StyleDirs> {
+        let file = self
+            .style_dirs
+            .clone()
+            .unwrap_or_else(|| crate_dir.join("gen").join("style-dirs.json"));
+        let mut dirs: BTreeMap<String, String> = files::read_json(&file)?;
+        let mut overridden = vec![];
+        for (style, dir) in &self.style_dir {
+            if overridden.contains(&style) {
+                return Err(files::invalid_data(format!(
+                    "--style-dir has more than one directory for the {style} style",
+                )));
+            }
+            overridden.push(style);
+            dirs.insert(style.clone(), dir.clone());
+        }
+        if dirs.is_empty() {
+            return Err(files::invalid_data(format!(
+                "{} does not have any styles",
+                file.display(),
+            )));
+        }
+        if let Some(style) = dirs
+            .keys()
+            .find(|s| !crate_dir.join("crates").join(s).join("Cargo.toml").exists())
+        {
+            return Err(files::invalid_data(format!(
+                "there is a directory for the {style} style, but there is no crate for it",
+            )));
+        }
+        Ok(StyleDirs(dirs))
+    }
+}
+
+// The directory under the heroicons repo's `src` that has each style's SVG files. These are all of
+// the styles that are generated.
+#[derive(Debug)]
+pub struct StyleDirs(BTreeMap<String, String>);
+
+impl StyleDirs {
+    // Returns the names of the styles, in alphabetical order.
+    pub fn styles(&self) -> Vec<&str> {
+        self.0.keys().map(String::as_str).collect()
+    }
+
+    // Returns the directory with a style's SVG files in a heroicons repo.
+    pub fn dir(&self, heroicons: &Path, style: &str) -> io::Result<PathBuf> {
+        self.0
+            .get(style)
+            .map(|dir| heroicons.join("src").join(dir))
+            .ok_or_else(|| {
+                files::invalid_data(format!("there is no directory for the {style} style"))
+            })
+    }
+}
+
+// Returns the path of the module with the names of the styles, relative to the crate. It's in the
+// core crate, since the style crates' build scripts use it to read the allow-list.
+pub fn styles_file() -> PathBuf {
+    Path::new("crates")
+        .join("core")
+        .join("src")
+        .join("styles.rs")
+}
+
+// Writes the module with the names of the styles.
+pub fn write_styles(styles: &[&str], file: &Path) -> io::Result<()> {
+    let styles = styles.iter().map(|s| format!("\"{s}\"")).join(", ");
+    if let Some(dir) = file.parent() {
+        files::create_dir_all(dir)?;
+    }
+    files::write(file, STYLES_TEMPLATE.replace("{STYLES}", &styles))
+}
+
+fn parse_style_dir(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((style, dir)) if !style.is_empty() && !dir.is_empty() => {
+            Ok((style.to_string(), dir.to_string()))
+        }
+        _ => Err(format!(
+            "{value} should be a style and a directory, like \"mini=20/solid\""
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::files::TempDir;
+
+    // Makes a crate dir with a crate for each style and a `gen/style-dirs.json` with `json`.
+    fn crate_dir(prefix: &str, styles: &[&str], json: &str) -> TempDir {
+        let dir = TempDir::new(prefix).unwrap();
+        for style in styles {
+            let style_dir = dir.path().join("crates").join(style);
+            files::create_dir_all(&style_dir).unwrap();
+            files::write(&style_dir.join("Cargo.toml"), "").unwrap();
+        }
+        files::create_dir_all(&dir.path().join("gen")).unwrap();
+        files::write(&dir.path().join("gen").join("style-dirs.json"), json).unwrap();
+        dir
+    }
+
+    fn args(style_dir: &[(&str, &str)]) -> StyleDirArgs {
+        StyleDirArgs {
+            style_dirs: None,
+            style_dir: style_dir
+                .iter()
+                .map(|(s, d)| (s.to_string(), d.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parse_style_dirs() {
+        assert_eq!(
+            parse_style_dir("mini=20/solid"),
+            Ok(("mini".to_string(), "20/solid".to_string())),
+        );
+        assert_eq!(
+            parse_style_dir("mini=a=b"),
+            Ok(("mini".to_string(), "a=b".to_string())),
+        );
+        for value in ["mini", "=20/solid", "mini=", ""] {
+            assert_eq!(
+                parse_style_dir(value),
+                Err(format!(
+                    "{value} should be a style and a directory, like \"mini=20/solid\""
+                )),
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_style_dirs() {
+        let dir = crate_dir(
+            "gen-test-resolve",
+            &["mini", "solid"],
+            r#"{"solid": "24/solid", "mini": "20/solid"}"#,
+        );
+        let dirs = args(&[("mini", "20/other")]).resolve(dir.path()).unwrap();
+        assert_eq!(dirs.styles(), ["mini", "solid"]);
+        let heroicons = Path::new("heroicons");
+        assert_eq!(
+            dirs.dir(heroicons, "mini").unwrap(),
+            heroicons.join("src").join("20/other"),
+        );
+        assert_eq!(
+            dirs.dir(heroicons, "outline").unwrap_err().to_string(),
+            "there is no directory for the outline style",
+        );
+    }
+
+    #[test]
+    fn resolve_duplicate_style() {
+        let dir = crate_dir("gen-test-duplicate", &["mini"], r#"{"mini": "20/solid"}"#);
+        let err = args(&[("mini", "a"), ("mini", "b")])
+            .resolve(dir.path())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "--style-dir has more than one directory for the mini style",
+        );
+    }
+
+    #[test]
+    fn resolve_style_without_crate() {
+        let dir = crate_dir("gen-test-no-crate", &["mini"], r#"{"mini": "20/solid"}"#);
+        let err = args(&[("micro", "16/solid")])
+            .resolve(dir.path())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "there is a directory for the micro style, but there is no crate for it",
+        );
+    }
+
+    #[test]
+    fn resolve_without_styles() {
+        let dir = crate_dir("gen-test-no-styles", &[], "{}");
+        let err = args(&[]).resolve(dir.path()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{} does not have any styles",
+                dir.path().join("gen").join("style-dirs.json").display(),
+            ),
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    solid,
+    theme::{join_classes, use_icon_theme},
+    Icon,
+};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`Avatar`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct AvatarProps {
+    /// The URL of the image. If this is not set, or the image fails to load, the avatar shows
+    /// `fallback_icon` instead. Changing this to a new URL tries to load the image again.
+    #[props(default, strip_option)]
+    pub src: Option<String>,
+    /// The accessible label for the avatar, which is usually the name of the person it represents.
+    pub alt: String,
+    /// The icon to show when there is no image. This defaults to [`solid::Shape::User`].
+    #[props(default = solid::Shape::User)]
+    pub fallback_icon: solid::Shape,
+    /// An optional background color for the fallback, like "#DBEAFE". This overrides the
+    /// background from the `avatar_class` in the [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub background: Option<String>,
+    /// The fill color for the fallback icon. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+    /// An optional class for the outer `<span>`. This is added after the `avatar_class` from the
+    /// [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The width and height of the avatar. This defaults to 32 pixels. The fallback icon is 60% of
+    /// this size.
+    #[props(default = 32)]
+    pub size: u32,
+}
+
+/// Renders an avatar image, falling back to a user icon when there is no image.
+///
+/// The avatar is a `<span>` with `role="img"` and an `aria-label` from `alt`, so it has the same
+/// accessible name whether it shows the image or the icon. The round shape and the default colors
+/// come from the `avatar_class` in the [`IconTheme`](crate::IconTheme).
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span role="img" aria-label="..." class="..." style="...">
+///   <img src="..." alt="" style="...">
+/// </span>
+/// ```
+///
+/// See the [`AvatarProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn Avatar(props: AvatarProps) -> Element {
+    let theme = use_icon_theme();
+    // This is the URL that failed to load, if any, so a new `src` gets a fresh try.
+    let mut failed = use_signal(|| None::<String>);
+    let class = join_classes([Some(theme.avatar_class.as_str()), props.class.as_deref()]);
+    let size = props.size;
+    let background = props
+        .background
+        .map(|b| format!(" background-color: {b};"))
+        .unwrap_or_default();
+    let src = props.src.filter(|src| failed.read().as_ref() != Some(src));
+    rsx! {
+        span {
+            role: "img",
+            aria_label: props.alt,
+            class: if let Some(class) = class { class },
+            style: "display: inline-flex; align-items: center; justify-content: center; overflow: hidden; width: {size}px; height: {size}px;{background}",
+            if let Some(src) = src {
+                img {
+                    src: src.clone(),
+                    alt: "",
+                    style: "width: 100%; height: 100%; object-fit: cover;",
+                    onerror: move |_| failed.set(Some(src.clone())),
+                },
+            } else {
+                Icon {
+                    size: size * 3 / 5,
+                    fill: props.fill,
+                    icon: props.fallback_icon,
+                },
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::assert_rsx_eq, IconShape};
+
+    const AVATAR_CLASS: &str = "rounded-full bg-gray-200 text-gray-500";
+
+    #[test]
+    fn avatar_image() {
+        assert_rsx_eq(
+            rsx! {
+                Avatar {
+                    src: "/ada.png",
+                    alt: "Ada Lovelace",
+                },
+            },
+            rsx! {
+                span {
+                    role: "img",
+                    aria_label: "Ada Lovelace",
+                    class: AVATAR_CLASS,
+                    style: "display: inline-flex; align-items: center; justify-content: center; overflow: hidden; width: 32px; height: 32px;",
+                    img {
+                        src: "/ada.png",
+                        alt: "",
+                        style: "width: 100%; height: 100%; object-fit: cover;",
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn avatar_fallback() {
+        assert_rsx_eq(
+            rsx! {
+                Avatar {
+                    alt: "Ada Lovelace",
+                    fallback_icon: solid::Shape::UserCircle,
+                    background: "#DBEAFE",
+                    size: 40,
+                },
+            },
+            rsx! {
+                span {
+                    role: "img",
+                    aria_label: "Ada Lovelace",
+                    class: AVATAR_CLASS,
+                    style: "display: inline-flex; align-items: center; justify-content: center; overflow: hidden; width: 40px; height: 40px; background-color: #DBEAFE;",
+                    svg {
+                        height: 24,
+                        width: 24,
+                        view_box: solid::VIEW_BOX,
+                        fill: "currentColor",
+                        { solid::Shape::UserCircle.path() },
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{outline, solid, state_toggle::StateToggle};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`BookmarkToggle`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct BookmarkToggleProps {
+    /// Whether the item is bookmarked. If this is set then the toggle is controlled, and it will
+    /// only change state when you change this value, usually in response to `onchange`. If this is
+    /// not set then the toggle keeps track of its own state.
+    #[props(default, strip_option)]
+    pub bookmarked: Option<bool>,
+    /// The initial state of the toggle when `bookmarked` is not set. This defaults to false.
+    #[props(default = false)]
+    pub default_bookmarked: bool,
+    /// A signal to bind the state to. If this is set then the toggle shows the signal's value, and
+    /// clicking it sets the signal to the new state before calling `onchange`. This takes
+    /// precedence over `bookmarked`.
+    #[props(default, strip_option)]
+    pub bind: Option<Signal<bool>>,
+    /// An optional handler that is called with the new state when the toggle is clicked.
+    #[props(default, strip_option)]
+    pub onchange: Option<EventHandler<bool>>,
+    /// Set this to true while a change is being saved. See
+    /// [`FavoriteToggleProps`](crate::FavoriteToggleProps) for details. This defaults to false.
+    #[props(default = false)]
+    pub pending: bool,
+    /// The accessible label for the toggle. This is also used as its title. This defaults to
+    /// "Bookmark".
+    #[props(default = "Bookmark".to_string())]
+    pub label: String,
+    /// The message that is announced to screen readers when the item is bookmarked. This defaults
+    /// to "Bookmarked".
+    #[props(default = "Bookmarked".to_string())]
+    pub bookmarked_message: String,
+    /// The message that is announced to screen readers when the bookmark is removed. This defaults
+    /// to "Bookmark removed".
+    #[props(default = "Bookmark removed".to_string())]
+    pub removed_message: String,
+    /// An optional class for the button.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color for the solid bookmark. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+    /// If this is true then the bookmark briefly grows when it's turned on. This defaults to true.
+    #[props(default = true)]
+    pub pop: bool,
+}
+
+/// Renders a bookmark toggle.
+///
+/// The toggle shows a [`solid::Shape::Bookmark`] when the item is bookmarked and an
+/// [`outline::Shape::BookmarkSlash`] when it's not. Its label stays the same and its state is
+/// exposed with `aria-pressed`. When it's clicked, a live region also announces
+/// `bookmarked_message` or `removed_message`.
+///
+/// Like [`FavoriteToggle`](crate::FavoriteToggle), this can be controlled or uncontrolled, and
+/// supports optimistic updates with `pending`.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button title="Bookmark" aria-pressed="false">
+///   <span style="...">
+///     <svg ...>
+///   </span>
+///   <span style="...">Bookmark</span>
+/// </button>
+/// <span role="status" style="..."></span>
+/// ```
+///
+/// See the [`BookmarkToggleProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn BookmarkToggle(props: BookmarkToggleProps) -> Element {
+    rsx! {
+        StateToggle {
+            icon_on: solid::Shape::Bookmark,
+            icon_off: outline::Shape::BookmarkSlash,
+            fill_on: props.fill,
+            on: props.bookmarked,
+            default_on: props.default_bookmarked,
+            bind: props.bind,
+            onchange: props.onchange,
+            pending: props.pending,
+            label: props.label,
+            class: props.class,
+            size: props.size,
+            pop: props.pop,
+            announcements: Some((props.bookmarked_message, props.removed_message)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn bookmark_toggle_default_bookmarked() {
+        assert_rsx_eq(
+            rsx! {
+                BookmarkToggle {
+                    default_bookmarked: true,
+                },
+            },
+            rsx! {
+                button {
+                    r#type: "button",
+                    class: FOCUS_CLASS,
+                    title: "Bookmark",
+                    aria_pressed: "true",
+                    span {
+                        style: "display: inline-flex; transition: transform 150ms; transform: scale(1);",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: solid::VIEW_BOX,
+                            fill: "currentColor",
+                            { solid::Shape::Bookmark.path() },
+                        },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Bookmark"
+                    },
+                },
+                span {
+                    role: "status",
+                    style: SR_ONLY_STYLE,
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn bookmark_toggle_off() {
+        assert_rsx_eq(
+            rsx! {
+                BookmarkToggle {
+                    bookmarked: false,
+                    label: "Save for later",
+                },
+            },
+            rsx! {
+                button {
+                    r#type: "button",
+                    class: FOCUS_CLASS,
+                    title: "Save for later",
+                    aria_pressed: "false",
+                    span {
+                        style: "display: inline-flex; transition: transform 150ms; transform: scale(1);",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: outline::VIEW_BOX,
+                            fill: "currentColor",
+                            { outline::Shape::BookmarkSlash.path() },
+                        },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Save for later"
+                    },
+                },
+                span {
+                    role: "status",
+                    style: SR_ONLY_STYLE,
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{mini, Icon};
+use dioxus::prelude::*;
+
+/// One item in a [`Breadcrumbs`] trail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BreadcrumbItem {
+    /// The text for the item.
+    pub label: String,
+    /// The URL the item links to, if any.
+    pub href: Option<String>,
+}
+
+impl BreadcrumbItem {
+    /// Creates a new item that isn't a link.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            href: None,
+        }
+    }
+
+    /// Creates a new item that links to `href`.
+    pub fn link(label: impl Into<String>, href: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            href: Some(href.into()),
+        }
+    }
+}
+
+/// The separator between the items in a [`Breadcrumbs`] trail.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BreadcrumbSeparator {
+    /// A [`mini::Shape::ChevronRight`].
+    #[default]
+    Chevron,
+    /// A "/". The heroicons set doesn't have a slash, so this is text rather than an icon.
+    Slash,
+}
+
+/// The properties for the [`Breadcrumbs`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct BreadcrumbsProps {
+    /// The items in the trail, from the top level down to the current page.
+    pub items: Vec<BreadcrumbItem>,
+    /// The separator between items. This defaults to [`BreadcrumbSeparator::Chevron`].
+    #[props(default)]
+    pub separator: BreadcrumbSeparator,
+    /// The accessible label for the `<nav>`. This defaults to "Breadcrumb".
+    #[props(default = "Breadcrumb".to_string())]
+    pub label: String,
+    /// An optional class for the `<nav>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for each item's `<a>` or `<span>`.
+    #[props(default, strip_option)]
+    pub item_class: Option<String>,
+    /// An optional class for each separator's `<span>`.
+    #[props(default, strip_option)]
+    pub separator_class: Option<String>,
+    /// The size of the separator icons. This defaults to 16 pixels.
+    #[props(default = 16)]
+    pub size: u32,
+}
+
+/// Renders a breadcrumb trail.
+///
+/// The items are in an ordered list inside a `<nav>`. Items with an `href` are links, and the rest
+/// are plain text. The last item is the current page, so it has `aria-current="page"`. The
+/// separators are hidden from screen readers.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <nav aria-label="Breadcrumb">
+///   <ol style="...">
+///     <li style="...">
+///       <a href="/">Home</a>
+///     </li>
+///     <li style="...">
+///       <span aria-hidden="true" style="display: inline-flex;">
+///         <svg ...>
+///       </span>
+///       <span aria-current="page">Settings</span>
+///     </li>
+///   </ol>
+/// </nav>
+/// ```
+///
+/// See the [`BreadcrumbsProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn Breadcrumbs(props: BreadcrumbsProps) -> Element {
+    let last = props.items.len().saturating_sub(1);
+    let separator = props.separator;
+    let size = props.size;
+    rsx! {
+        nav {
+            aria_label: props.label,
+            class: if let Some(class) = props.class { class },
+            ol {
+                style: "display: flex; flex-wrap: wrap; align-items: center; gap: 8px; list-style: none; margin: 0; padding: 0;",
+                for (i, item) in props.items.into_iter().enumerate() {
+                    li {
+                        key: "{i}",
+                        style: "display: inline-flex; align-items: center; gap: 8px;",
+                        if i > 0 {
+                            span {
+                                aria_hidden: true,
+                                class: props.separator_class.clone(),
+                                style: "display: inline-flex;",
+                                match separator {
+                                    BreadcrumbSeparator::Chevron => rsx! {
+                                        Icon {
+                                            size,
+                                            icon: mini::Shape::ChevronRight,
+                                        }
+                                    },
+                                    BreadcrumbSeparator::Slash => rsx! { "/" },
+                                }
+                            },
+                        }
+                        if let Some(href) = item.href {
+                            a {
+                                href,
+                                class: props.item_class.clone(),
+                                aria_current: if i == last { Some("page") } else { None },
+                                "{item.label}"
+                            },
+                        } else {
+                            span {
+                                class: props.item_class.clone(),
+                                aria_current: if i == last { Some("page") } else { None },
+                                "{item.label}"
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::assert_rsx_eq, IconShape};
+
+    const OL_STYLE: &str = "display: flex; flex-wrap: wrap; align-items: center; gap: 8px; list-style: none; margin: 0; padding: 0;";
+    const LI_STYLE: &str = "display: inline-flex; align-items: center; gap: 8px;";
+
+    #[test]
+    fn breadcrumbs_chevron() {
+        let items = vec![
+            BreadcrumbItem::link("Home", "/"),
+            BreadcrumbItem::new("Settings"),
+        ];
+        assert_rsx_eq(
+            rsx! {
+                Breadcrumbs {
+                    items,
+                },
+            },
+            rsx! {
+                nav {
+                    aria_label: "Breadcrumb",
+                    ol {
+                        style: OL_STYLE,
+                        li {
+                            style: LI_STYLE,
+                            a {
+                                href: "/",
+                                "Home"
+                            },
+                        },
+                        li {
+                            style: LI_STYLE,
+                            span {
+                                aria_hidden: true,
+                                style: "display: inline-flex;",
+                                svg {
+                                    height: 16,
+                                    width: 16,
+                                    view_box: mini::VIEW_BOX,
+                                    fill: "currentColor",
+                                    { mini::Shape::ChevronRight.path() },
+                                },
+                            },
+                            span {
+                                aria_current: "page",
+                                "Settings"
+                            },
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn breadcrumbs_slash() {
+        let items = vec![
+            BreadcrumbItem::link("Docs", "/docs"),
+            BreadcrumbItem::link("Icons", "/docs/icons"),
+        ];
+        assert_rsx_eq(
+            rsx! {
+                Breadcrumbs {
+                    items,
+                    separator: BreadcrumbSeparator::Slash,
+                    item_class: "underline",
+                },
+            },
+            rsx! {
+                nav {
+                    aria_label: "Breadcrumb",
+                    ol {
+                        style: OL_STYLE,
+                        li {
+                            style: LI_STYLE,
+                            a {
+                                href: "/docs",
+                                class: "underline",
+                                "Docs"
+                            },
+                        },
+                        li {
+                            style: LI_STYLE,
+                            span {
+                                aria_hidden: true,
+                                style: "display: inline-flex;",
+                                "/"
+                            },
+                            a {
+                                href: "/docs/icons",
+                                class: "underline",
+                                aria_current: "page",
+                                "Icons"
+                            },
+                        },
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{
+    mini,
+    theme::{join_classes, use_icon_theme},
+    IconButton, Status, StatusIcon,
+};
+use dioxus::prelude::*;
+
+/// The properties for the [`Callout`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct CalloutProps {
+    /// The status of the callout, which determines its icon and its ARIA role.
+    pub status: Status,
+    /// An optional title, which is shown above the body.
+    #[props(default, strip_option)]
+    pub title: Option<String>,
+    /// An optional handler for the dismiss button. If this is not set then there is no dismiss
+    /// button.
+    #[props(default, strip_option)]
+    pub ondismiss: Option<EventHandler<MouseEvent>>,
+    /// The accessible label for the dismiss button. This defaults to "Dismiss".
+    #[props(default = "Dismiss".to_string())]
+    pub dismiss_label: String,
+    /// An optional class for the outer `<div>`. This is added after the `callout_class` from the
+    /// [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for the title's `<div>`.
+    #[props(default, strip_option)]
+    pub title_class: Option<String>,
+    /// An optional class for the dismiss button.
+    #[props(default, strip_option)]
+    pub dismiss_class: Option<String>,
+    /// The size of the status icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The body of the callout.
+    pub children: Element,
+}
+
+/// Renders an alert box with the [`StatusIcon`] for its status, an optional title, a body, and an
+/// optional dismiss button.
+///
+/// A callout with a [`Status::Error`] or [`Status::Warning`] has `role="alert"`, so screen readers
+/// announce it right away. Any other status has `role="status"`, which is announced politely.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <div role="alert" class="..." style="display: flex; ...">
+///   <span role="img" aria-label="Error" class="...">
+///     <svg ...>
+///   </span>
+///   <div style="flex: 1;">
+///     <div>Title goes here</div>
+///     <div>Child elements go here</div>
+///   </div>
+///   <button title="Dismiss">
+///     <svg ...>
+///     <span style="...">Dismiss</span>
+///   </button>
+/// </div>
+/// ```
+///
+/// See the [`CalloutProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn Callout(props: CalloutProps) -> Element {
+    let theme = use_icon_theme();
+    let status = props.status;
+    let class = join_classes([Some(theme.callout_class.as_str()), props.class.as_deref()]);
+    rsx! {
+        div {
+            role: match status {
+                Status::Error | Status::Warning => "alert",
+                _ => "status",
+            },
+            class: if let Some(class) = class { class },
+            style: "display: flex; align-items: flex-start; gap: 12px;",
+            StatusIcon {
+                status,
+                size: props.size,
+            },
+            div {
+                style: "flex: 1;",
+                if let Some(title) = props.title {
+                    div {
+                        class: if let Some(title_class) = props.title_class { title_class },
+                        { title }
+                    },
+                }
+                div {
+                    { props.children }
+                },
+            },
+            if let Some(ondismiss) = props.ondismiss {
+                IconButton {
+                    onclick: ondismiss,
+                    class: props.dismiss_class,
+                    title: props.dismiss_label.clone(),
+                    size: 16,
+                    icon: mini::Shape::XMark,
+                    sr_only: props.dismiss_label,
+                },
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn callout_info() {
+        assert_rsx_eq(
+            rsx! {
+                Callout {
+                    status: Status::Info,
+                    "Your changes were saved."
+                },
+            },
+            rsx! {
+                div {
+                    role: "status",
+                    class: "rounded-md border border-gray-200 p-4",
+                    style: "display: flex; align-items: flex-start; gap: 12px;",
+                    span {
+                        role: "img",
+                        aria_label: "Info",
+                        class: "text-blue-600",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::InformationCircle.path() },
+                        },
+                    },
+                    div {
+                        style: "flex: 1;",
+                        div {
+                            "Your changes were saved."
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn callout_error_with_title_and_dismiss() {
+        #[allow(non_snake_case)]
+        fn Error() -> Element {
+            rsx! {
+                Callout {
+                    status: Status::Error,
+                    title: "Upload failed",
+                    title_class: "font-semibold",
+                    ondismiss: |_| {},
+                    class: "my-2",
+                    "The file is too large."
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Error {},
+            },
+            rsx! {
+                div {
+                    role: "alert",
+                    class: "rounded-md border border-gray-200 p-4 my-2",
+                    style: "display: flex; align-items: flex-start; gap: 12px;",
+                    span {
+                        role: "img",
+                        aria_label: "Error",
+                        class: "text-red-600",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::XCircle.path() },
+                        },
+                    },
+                    div {
+                        style: "flex: 1;",
+                        div {
+                            class: "font-semibold",
+                            "Upload failed"
+                        },
+                        div {
+                            "The file is too large."
+                        },
+                    },
+                    button {
+                        class: FOCUS_CLASS,
+                        title: "Dismiss",
+                        svg {
+                            height: 16,
+                            width: 16,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::XMark.path() },
+                        },
+                        span {
+                            style: SR_ONLY_STYLE,
+                            "Dismiss"
+                        },
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    mini,
+    theme::{join_classes, use_icon_theme},
+    IconButton,
+};
+use dioxus::prelude::*;
+
+/// The properties for the [`Chip`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct ChipProps {
+    /// An optional leading icon, which is usually an [`Icon`](crate::Icon).
+    #[props(default = VNode::empty())]
+    pub icon: Element,
+    /// An optional handler for the dismiss button. If this is not set then there is no dismiss
+    /// button.
+    #[props(default, strip_option)]
+    pub ondismiss: Option<EventHandler<MouseEvent>>,
+    /// The accessible label for the dismiss button. This defaults to "Remove".
+    #[props(default = "Remove".to_string())]
+    pub dismiss_label: String,
+    /// An optional class for the outer `<span>`. This is added after the `chip_class` from the
+    /// [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for the dismiss button.
+    #[props(default, strip_option)]
+    pub dismiss_class: Option<String>,
+    /// The size of the dismiss icon. This defaults to 12 pixels.
+    #[props(default = 12)]
+    pub dismiss_size: u32,
+    /// The label for the chip, which can be plain text or any other elements.
+    pub children: Element,
+}
+
+/// Renders a pill with an optional leading icon and an optional dismiss button, for filters and
+/// tags.
+///
+/// The dismiss button is an [`IconButton`] with a [`mini::Shape::XMark`] that calls `ondismiss`
+/// when it's clicked. For example:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_heroicons::{mini, Chip, Icon};
+///
+/// fn Filter() -> Element {
+///     let mut shown = use_signal(|| true);
+///     rsx! {
+///         if shown() {
+///             Chip {
+///                 icon: rsx! { Icon { icon: mini::Shape::Tag, size: 16 } },
+///                 ondismiss: move |_| shown.set(false),
+///                 "rust"
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span class="..." style="display: inline-flex; ...">
+///   <svg ...>
+///   <span>Child elements go here</span>
+///   <button title="Remove">
+///     <svg ...>
+///     <span style="...">Remove</span>
+///   </button>
+/// </span>
+/// ```
+///
+/// See the [`ChipProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn Chip(props: ChipProps) -> Element {
+    let theme = use_icon_theme();
+    let class = join_classes([Some(theme.chip_class.as_str()), props.class.as_deref()]);
+    rsx! {
+        span {
+            class: if let Some(class) = class { class },
+            style: "display: inline-flex; align-items: center; gap: 4px;",
+            { props.icon }
+            span {
+                { props.children }
+            },
+            if let Some(ondismiss) = props.ondismiss {
+                IconButton {
+                    onclick: ondismiss,
+                    class: props.dismiss_class,
+                    title: props.dismiss_label.clone(),
+                    size: props.dismiss_size,
+                    icon: mini::Shape::XMark,
+                    sr_only: props.dismiss_label,
+                },
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        Icon, IconShape, SR_ONLY_STYLE,
+    };
+
+    const CHIP_CLASS: &str = "rounded-full bg-gray-100 px-2 py-0.5 text-sm text-gray-700";
+    const CHIP_STYLE: &str = "display: inline-flex; align-items: center; gap: 4px;";
+
+    #[test]
+    fn chip_plain() {
+        assert_rsx_eq(
+            rsx! {
+                Chip {
+                    "rust"
+                },
+            },
+            rsx! {
+                span {
+                    class: CHIP_CLASS,
+                    style: CHIP_STYLE,
+                    span {
+                        "rust"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn chip_with_icon_and_dismiss() {
+        #[allow(non_snake_case)]
+        fn Tag() -> Element {
+            rsx! {
+                Chip {
+                    icon: rsx! {
+                        Icon {
+                            icon: mini::Shape::Tag,
+                            size: 16,
+                        }
+                    },
+                    ondismiss: |_| {},
+                    dismiss_label: "Remove rust",
+                    "rust"
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Tag {},
+            },
+            rsx! {
+                span {
+                    class: CHIP_CLASS,
+                    style: CHIP_STYLE,
+                    svg {
+                        height: 16,
+                        width: 16,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::Tag.path() },
+                    },
+                    span {
+                        "rust"
+                    },
+                    button {
+                        class: FOCUS_CLASS,
+                        title: "Remove rust",
+                        svg {
+                            height: 12,
+                            width: 12,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::XMark.path() },
+                        },
+                        span {
+                            style: SR_ONLY_STYLE,
+                            "Remove rust"
+                        },
+                    },
+                },
+            },
+        );
+    }
+}
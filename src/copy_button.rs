@@ -0,0 +1,157 @@
+use crate::{outline, timer::sleep, ButtonVariant, IconButton};
+use dioxus::prelude::*;
+
+/// The properties for the [`CopyButton`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct CopyButtonProps {
+    /// The text to copy to the clipboard.
+    pub text: String,
+    /// How long the button shows a check after the text is copied, in milliseconds. This defaults
+    /// to 2000.
+    #[props(default = 2000)]
+    pub reset_ms: u32,
+    /// An optional handler that is called after the text is copied.
+    #[props(default, strip_option)]
+    pub oncopy: Option<EventHandler<()>>,
+    /// An optional handler that is called with an error message if the text can't be copied.
+    #[props(default, strip_option)]
+    pub onerror: Option<EventHandler<String>>,
+    /// The accessible label for the button. This is also used as the button's title. This defaults
+    /// to "Copy".
+    #[props(default = "Copy".to_string())]
+    pub label: String,
+    /// The accessible label for the button after the text is copied. This defaults to "Copied".
+    #[props(default = "Copied".to_string())]
+    pub copied_label: String,
+    /// An optional class for the button.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional visual variant for the button.
+    #[props(default, strip_option)]
+    pub variant: Option<ButtonVariant>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+}
+
+/// Renders an [`IconButton`] that copies some text to the clipboard.
+///
+/// The button shows a [`outline::Shape::ClipboardDocument`]. After the text is copied, it shows a
+/// [`outline::Shape::Check`] and the `copied_label` for `reset_ms` milliseconds.
+///
+/// Copying uses the browser's Clipboard API, so it needs the `wasm` feature. Without that feature,
+/// every click calls `onerror`.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button title="Copy">
+///   <svg ...>
+///   <span style="...">Copy</span>
+/// </button>
+/// ```
+///
+/// See the [`CopyButtonProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn CopyButton(props: CopyButtonProps) -> Element {
+    let mut copied = use_signal(|| false);
+    // Each copy gets a new generation, so an old timer doesn't reset the icon of a newer copy.
+    let mut generation = use_signal(|| 0_u32);
+    let text = props.text;
+    let reset_ms = props.reset_ms;
+    let oncopy = props.oncopy;
+    let onerror = props.onerror;
+    let label = if copied() {
+        props.copied_label
+    } else {
+        props.label
+    };
+    rsx! {
+        IconButton {
+            onclick: move |_| {
+                let text = text.clone();
+                spawn(async move {
+                    match write_clipboard(&text).await {
+                        Ok(()) => {
+                            let this_generation = generation() + 1;
+                            generation.set(this_generation);
+                            copied.set(true);
+                            if let Some(oc) = oncopy {
+                                oc.call(());
+                            }
+                            sleep(reset_ms).await;
+                            if generation() == this_generation {
+                                copied.set(false);
+                            }
+                        }
+                        Err(e) => {
+                            if let Some(oe) = onerror {
+                                oe.call(e);
+                            }
+                        }
+                    }
+                });
+            },
+            class: props.class,
+            variant: props.variant,
+            title: label.clone(),
+            size: props.size,
+            icon: if copied() { outline::Shape::Check } else { outline::Shape::ClipboardDocument },
+            sr_only: label,
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+async fn write_clipboard(text: &str) -> Result<(), String> {
+    let window = web_sys::window().ok_or_else(|| "there is no browser window".to_string())?;
+    let promise = window.navigator().clipboard().write_text(text);
+    wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("could not write to the clipboard: {e:?}"))
+}
+
+#[cfg(not(feature = "wasm"))]
+#[allow(clippy::unused_async)]
+async fn write_clipboard(_text: &str) -> Result<(), String> {
+    Err("copying to the clipboard requires the wasm feature".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn copy_button() {
+        assert_rsx_eq(
+            rsx! {
+                CopyButton {
+                    text: "cargo add dioxus-heroicons",
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    title: "Copy",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::ClipboardDocument.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Copy"
+                    },
+                },
+            },
+        );
+    }
+}
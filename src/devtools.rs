@@ -0,0 +1,74 @@
+pub use crate::ShapeStyle;
+use crate::StaticShape;
+
+/// One of the shapes compiled into the app, along with its style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShapeEntry {
+    /// The shape's style.
+    pub style: ShapeStyle,
+    /// The shape itself, with its name, view box, and path data. With the `components` feature,
+    /// this can be passed to [`Icon`](crate::Icon) to preview it.
+    pub shape: StaticShape,
+}
+
+/// Returns every shape that's compiled into the app, in every enabled style. The shapes are sorted
+/// by style, and then in the order of each style's `Shape::ALL`.
+#[must_use]
+pub fn shapes() -> Vec<ShapeEntry> {
+    #[allow(unused_mut)]
+    let mut shapes = vec![];
+    #[cfg(any(feature = "components", feature = "mini"))]
+    shapes.extend(crate::mini::Shape::ALL.iter().map(|s| ShapeEntry {
+        style: ShapeStyle::Mini,
+        shape: s.static_shape(),
+    }));
+    #[cfg(any(feature = "components", feature = "outline"))]
+    shapes.extend(crate::outline::Shape::ALL.iter().map(|s| ShapeEntry {
+        style: ShapeStyle::Outline,
+        shape: s.static_shape(),
+    }));
+    #[cfg(any(feature = "components", feature = "solid"))]
+    shapes.extend(crate::solid::Shape::ALL.iter().map(|s| ShapeEntry {
+        style: ShapeStyle::Solid,
+        shape: s.static_shape(),
+    }));
+    shapes
+}
+
+/// Returns the compiled-in shape with the given style and heroicons name, like "arrow-left", or
+/// `None` if that shape's style or icon feature is off.
+#[must_use]
+pub fn find(style: ShapeStyle, name: &str) -> Option<ShapeEntry> {
+    shapes()
+        .into_iter()
+        .find(|e| e.style == style && e.shape.name() == name)
+}
+
+#[cfg(all(test, feature = "components", feature = "all-icons"))]
+mod test {
+    use super::*;
+    use crate::{meta, mini, outline};
+
+    #[test]
+    fn registry() {
+        let shapes = shapes();
+        assert_eq!(
+            shapes.len(),
+            meta::MINI_ICON_COUNT + meta::OUTLINE_ICON_COUNT + meta::SOLID_ICON_COUNT,
+        );
+        assert_eq!(shapes[0].style, ShapeStyle::Mini);
+        assert_eq!(shapes.last().unwrap().style, ShapeStyle::Solid);
+
+        let entry = find(ShapeStyle::Outline, "arrow-left").unwrap();
+        assert_eq!(entry.shape, outline::ARROW_LEFT);
+        assert_eq!(entry.style.name(), "outline");
+        assert_eq!(
+            find(ShapeStyle::Mini, "x-circle")
+                .unwrap()
+                .shape
+                .path_data(),
+            mini::X_CIRCLE.path_data(),
+        );
+        assert_eq!(find(ShapeStyle::Solid, "not-an-icon"), None);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{mini, Icon};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`DisclosureChevron`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct DisclosureChevronProps {
+    /// Whether the section this chevron belongs to is open.
+    pub open: bool,
+    /// How far the chevron rotates when it's open, in degrees. This defaults to 90, which turns the
+    /// right-pointing chevron so it points down.
+    #[props(default = 90)]
+    pub rotation: i32,
+    /// How long the rotation takes, in milliseconds. Set this to 0 to turn the animation off. This
+    /// defaults to 150.
+    #[props(default = 150)]
+    pub transition_ms: u32,
+    /// An optional class for the `<span>` that wraps the icon.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color to use for the icon. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+}
+
+/// Renders a [`mini::Shape::ChevronRight`] that rotates when `open` is true, for accordions and
+/// tree rows.
+///
+/// The chevron is decorative, so it has `aria-hidden="true"`. Put `aria-expanded` on the button
+/// that opens and closes the section instead.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span aria-hidden="true" style="display: inline-flex; ... transform: rotate(90deg);">
+///   <svg ...>
+/// </span>
+/// ```
+///
+/// See the [`DisclosureChevronProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn DisclosureChevron(props: DisclosureChevronProps) -> Element {
+    let angle = if props.open { props.rotation } else { 0 };
+    rsx! {
+        span {
+            aria_hidden: true,
+            class: if let Some(class) = props.class { class },
+            style: "display: inline-flex; transition: transform {props.transition_ms}ms; transform: rotate({angle}deg);",
+            Icon {
+                size: props.size,
+                fill: props.fill,
+                icon: mini::Shape::ChevronRight,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::assert_rsx_eq, IconShape};
+
+    #[test]
+    fn disclosure_chevron_closed() {
+        assert_rsx_eq(
+            rsx! {
+                DisclosureChevron {
+                    open: false,
+                },
+            },
+            rsx! {
+                span {
+                    aria_hidden: "true",
+                    style: "display: inline-flex; transition: transform 150ms; transform: rotate(0deg);",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::ChevronRight.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn disclosure_chevron_open() {
+        assert_rsx_eq(
+            rsx! {
+                DisclosureChevron {
+                    open: true,
+                    rotation: -90,
+                    transition_ms: 0,
+                    class: "text-gray-500",
+                },
+            },
+            rsx! {
+                span {
+                    aria_hidden: "true",
+                    class: "text-gray-500",
+                    style: "display: inline-flex; transition: transform 0ms; transform: rotate(-90deg);",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::ChevronRight.path() },
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{
+    mini,
+    theme::{join_classes, use_icon_theme},
+    Icon, SR_ONLY_STYLE,
+};
+use dioxus::prelude::*;
+
+/// The icon for a [`DragHandle`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DragHandleIcon {
+    /// Two horizontal bars, [`mini::Shape::Bars2`].
+    #[default]
+    Bars,
+    /// Three vertical dots, [`mini::Shape::EllipsisVertical`].
+    Dots,
+}
+
+impl DragHandleIcon {
+    fn shape(self) -> mini::Shape {
+        match self {
+            DragHandleIcon::Bars => mini::Shape::Bars2,
+            DragHandleIcon::Dots => mini::Shape::EllipsisVertical,
+        }
+    }
+}
+
+/// The properties for the [`DragHandle`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct DragHandleProps {
+    /// The icon to show. This defaults to [`DragHandleIcon::Bars`].
+    #[props(default)]
+    pub icon: DragHandleIcon,
+    /// The accessible label for the handle. This defaults to "Drag to reorder".
+    #[props(default = "Drag to reorder".to_string())]
+    pub label: String,
+    /// If this is true then the handle can't be dragged or focused. This defaults to false.
+    #[props(default = false)]
+    pub disabled: bool,
+    /// If this is true then the handle has `draggable="true"`, for sortable lists that use the
+    /// native HTML drag and drop events. Set this to false for libraries that use pointer events
+    /// instead. This defaults to true.
+    #[props(default = true)]
+    pub draggable: bool,
+    /// An optional handler for the native `dragstart` event.
+    #[props(default, strip_option)]
+    pub ondragstart: Option<EventHandler<DragEvent>>,
+    /// An optional handler for the native `dragend` event.
+    #[props(default, strip_option)]
+    pub ondragend: Option<EventHandler<DragEvent>>,
+    /// An optional handler for the `pointerdown` event.
+    #[props(default, strip_option)]
+    pub onpointerdown: Option<EventHandler<PointerEvent>>,
+    /// An optional handler for the `pointermove` event.
+    #[props(default, strip_option)]
+    pub onpointermove: Option<EventHandler<PointerEvent>>,
+    /// An optional handler for the `pointerup` event.
+    #[props(default, strip_option)]
+    pub onpointerup: Option<EventHandler<PointerEvent>>,
+    /// An optional handler for the `keydown` event, for moving items with the keyboard.
+    #[props(default, strip_option)]
+    pub onkeydown: Option<EventHandler<KeyboardEvent>>,
+    /// An optional class for the `<button>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+}
+
+/// Renders a handle for dragging an item in a sortable list.
+///
+/// The handle is a `<button>` with `cursor: grab` and `touch-action: none`, so that dragging it on
+/// a touch screen moves the item instead of scrolling the page. None of the events are handled by
+/// the handle itself, so it works with either native drag and drop or a pointer events based
+/// library. Since it's a button, it can be focused, and `onkeydown` can move the item with the
+/// arrow keys for people who can't drag.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button type="button" title="Drag to reorder" draggable="true" style="cursor: grab; ...">
+///   <svg ...>
+///   <span style="...">Drag to reorder</span>
+/// </button>
+/// ```
+///
+/// See the [`DragHandleProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn DragHandle(props: DragHandleProps) -> Element {
+    let theme = use_icon_theme();
+    let class = join_classes([Some(theme.focus_class.as_str()), props.class.as_deref()]);
+    let disabled = props.disabled;
+    let (ondragstart, ondragend) = (props.ondragstart, props.ondragend);
+    let (onpointerdown, onpointermove, onpointerup) =
+        (props.onpointerdown, props.onpointermove, props.onpointerup);
+    let onkeydown = props.onkeydown;
+    let label = props.label;
+    rsx! {
+        button {
+            r#type: "button",
+            class: if let Some(class) = class { class },
+            title: label.as_str(),
+            disabled,
+            draggable: props.draggable && !disabled,
+            style: if disabled { "cursor: not-allowed;" } else { "cursor: grab; touch-action: none;" },
+            ondragstart: move |evt| if let Some(h) = ondragstart {
+                h.call(evt);
+            },
+            ondragend: move |evt| if let Some(h) = ondragend {
+                h.call(evt);
+            },
+            onpointerdown: move |evt| if let Some(h) = onpointerdown {
+                h.call(evt);
+            },
+            onpointermove: move |evt| if let Some(h) = onpointermove {
+                h.call(evt);
+            },
+            onpointerup: move |evt| if let Some(h) = onpointerup {
+                h.call(evt);
+            },
+            onkeydown: move |evt| if let Some(h) = onkeydown {
+                h.call(evt);
+            },
+            Icon {
+                size: props.size,
+                disabled,
+                icon: props.icon.shape(),
+            },
+            span {
+                style: SR_ONLY_STYLE,
+                "{label}"
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, DISABLED_FILL_COLOR,
+    };
+
+    #[test]
+    fn drag_handle_default() {
+        assert_rsx_eq(
+            rsx! {
+                DragHandle {},
+            },
+            rsx! {
+                button {
+                    r#type: "button",
+                    class: FOCUS_CLASS,
+                    title: "Drag to reorder",
+                    disabled: false,
+                    draggable: true,
+                    style: "cursor: grab; touch-action: none;",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::Bars2.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Drag to reorder"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn drag_handle_dots_disabled() {
+        assert_rsx_eq(
+            rsx! {
+                DragHandle {
+                    icon: DragHandleIcon::Dots,
+                    disabled: true,
+                    label: "Move task",
+                    size: 16,
+                },
+            },
+            rsx! {
+                button {
+                    r#type: "button",
+                    class: FOCUS_CLASS,
+                    title: "Move task",
+                    disabled: true,
+                    draggable: false,
+                    style: "cursor: not-allowed;",
+                    svg {
+                        height: 16,
+                        width: 16,
+                        view_box: mini::VIEW_BOX,
+                        fill: DISABLED_FILL_COLOR,
+                        { mini::Shape::EllipsisVertical.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Move task"
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{
+    theme::{join_classes, use_icon_theme},
+    use_unique_id, ButtonVariant, IconButton, IconShape,
+};
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// Which edge of a [`DropdownIconButton`] its panel is aligned with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropdownAlign {
+    /// The panel's left edge is aligned with the button's left edge.
+    #[default]
+    Start,
+    /// The panel's right edge is aligned with the button's right edge.
+    End,
+}
+
+impl DropdownAlign {
+    /// Returns the inline style that positions the panel below the button.
+    fn style(self) -> &'static str {
+        match self {
+            DropdownAlign::Start => "position: absolute; top: 100%; left: 0; z-index: 20;",
+            DropdownAlign::End => "position: absolute; top: 100%; right: 0; z-index: 20;",
+        }
+    }
+}
+
+/// The inline style for the invisible backdrop that closes an open panel when it's clicked.
+const BACKDROP_STYLE: &str = "position: fixed; inset: 0; z-index: 10;";
+
+/// The properties for the [`DropdownIconButton`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct DropdownIconButtonProps<S: IconShape + 'static> {
+    /// The icon shape for the button.
+    pub icon: S,
+    /// The accessible label for the button. This is also used as the button's title.
+    pub label: String,
+    /// If this is true then the panel is open when the component is first rendered. This defaults
+    /// to false.
+    #[props(default = false)]
+    pub default_open: bool,
+    /// An optional handler that is called with the new state whenever the panel opens or closes.
+    #[props(default, strip_option)]
+    pub ontoggle: Option<EventHandler<bool>>,
+    /// Which edge of the button the panel is aligned with. This defaults to
+    /// [`DropdownAlign::Start`].
+    #[props(default)]
+    pub align: DropdownAlign,
+    /// An optional `id` for the panel, which the button refers to with `aria-controls` while the
+    /// panel is open. If this is not set then a unique `id` is generated.
+    #[props(default, strip_option)]
+    pub panel_id: Option<String>,
+    /// An optional class for the outer `<div>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for the button.
+    #[props(default, strip_option)]
+    pub button_class: Option<String>,
+    /// An optional visual variant for the button.
+    #[props(default, strip_option)]
+    pub variant: Option<ButtonVariant>,
+    /// An optional class for the panel. This is added after the `dropdown_class` from the
+    /// [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub panel_class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// If this is true then the button is disabled. This defaults to false.
+    #[props(default = false)]
+    pub disabled: bool,
+    /// The contents of the panel.
+    pub children: Element,
+}
+
+/// Renders an [`IconButton`] that opens and closes a popup panel.
+///
+/// The button has `aria-haspopup` and `aria-expanded` attributes, and while the panel is open, an
+/// `aria-controls` attribute that points at it. The panel is only rendered while it's open, so
+/// the button never refers to an element that isn't there. The panel closes when the button is clicked again, when `Escape` is pressed, which
+/// also moves the focus back to the button, and when the user clicks anywhere outside of it. Clicks
+/// outside are caught by an invisible backdrop behind the panel, so this works without any
+/// JavaScript.
+///
+/// This component will generate HTML like this when the panel is open:
+///
+/// ```html
+/// <div style="position: relative; display: inline-block;">
+///   <button title="..." aria-haspopup="true" aria-expanded="true" aria-controls="dropdown-0">
+///     <svg ...>
+///     <span style="...">Label goes here</span>
+///   </button>
+///   <div aria-hidden="true" style="position: fixed; ..."></div>
+///   <div id="dropdown-0" class="..." style="position: absolute; ...">
+///     Child elements go here
+///   </div>
+/// </div>
+/// ```
+///
+/// See the [`DropdownIconButtonProps`] field documentation for details on the properties it
+/// accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn DropdownIconButton<S: IconShape>(props: DropdownIconButtonProps<S>) -> Element {
+    let theme = use_icon_theme();
+    let mut open = use_signal(|| props.default_open);
+    let mut button = use_signal(|| None::<Rc<MountedData>>);
+    let generated_id = use_unique_id("dropdown");
+    let panel_id = props.panel_id.unwrap_or(generated_id);
+    let ontoggle = props.ontoggle;
+    let mut set_open = move |new: bool| {
+        if open() == new {
+            return;
+        }
+        open.set(new);
+        if let Some(ot) = ontoggle {
+            ot.call(new);
+        }
+    };
+    let panel_class = join_classes([
+        Some(theme.dropdown_class.as_str()),
+        props.panel_class.as_deref(),
+    ]);
+
+    rsx! {
+        div {
+            class: if let Some(class) = props.class { class },
+            style: "position: relative; display: inline-block;",
+            onkeydown: move |evt| {
+                if evt.key() != Key::Escape || !open() {
+                    return;
+                }
+                evt.prevent_default();
+                set_open(false);
+                if let Some(button) = button() {
+                    spawn(async move {
+                        let _ = button.set_focus(true).await;
+                    });
+                }
+            },
+            IconButton {
+                onclick: move |_| set_open(!open()),
+                onmounted: move |evt: MountedEvent| button.set(Some(evt.data())),
+                class: props.button_class,
+                variant: props.variant,
+                title: props.label.clone(),
+                size: props.size,
+                disabled: props.disabled,
+                icon: props.icon,
+                sr_only: props.label,
+                aria_haspopup: "true",
+                aria_expanded: open(),
+                aria_controls: if open() { Some(panel_id.clone()) } else { None },
+            },
+            if open() {
+                div {
+                    aria_hidden: true,
+                    style: BACKDROP_STYLE,
+                    onclick: move |_| set_open(false),
+                },
+                div {
+                    id: panel_id,
+                    class: if let Some(panel_class) = panel_class { panel_class },
+                    style: props.align.style(),
+                    { props.children }
+                },
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        outline,
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn dropdown_icon_button_closed() {
+        assert_rsx_eq(
+            rsx! {
+                DropdownIconButton {
+                    icon: outline::Shape::EllipsisVertical,
+                    label: "More",
+                    panel_id: "more-menu",
+                    "Menu"
+                },
+            },
+            rsx! {
+                div {
+                    style: "position: relative; display: inline-block;",
+                    button {
+                        class: FOCUS_CLASS,
+                        title: "More",
+                        aria_haspopup: "true",
+                        aria_expanded: "false",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: outline::VIEW_BOX,
+                            fill: "currentColor",
+                            { outline::Shape::EllipsisVertical.path() },
+                        },
+                        span {
+                            style: SR_ONLY_STYLE,
+                            "More"
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn dropdown_icon_button_open() {
+        assert_rsx_eq(
+            rsx! {
+                DropdownIconButton {
+                    icon: outline::Shape::EllipsisVertical,
+                    label: "More",
+                    panel_id: "more-menu",
+                    default_open: true,
+                    align: DropdownAlign::End,
+                    "Menu"
+                },
+            },
+            rsx! {
+                div {
+                    style: "position: relative; display: inline-block;",
+                    button {
+                        class: FOCUS_CLASS,
+                        title: "More",
+                        aria_haspopup: "true",
+                        aria_expanded: "true",
+                        aria_controls: "more-menu",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: outline::VIEW_BOX,
+                            fill: "currentColor",
+                            { outline::Shape::EllipsisVertical.path() },
+                        },
+                        span {
+                            style: SR_ONLY_STYLE,
+                            "More"
+                        },
+                    },
+                    div {
+                        aria_hidden: "true",
+                        style: BACKDROP_STYLE,
+                    },
+                    div {
+                        id: "more-menu",
+                        class: "mt-1 rounded-md border border-gray-200 bg-white p-1 shadow-lg",
+                        style: "position: absolute; top: 100%; right: 0; z-index: 20;",
+                        "Menu"
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    theme::{join_classes, use_icon_theme},
+    Icon, IconShape,
+};
+use dioxus::prelude::*;
+
+/// The properties for the [`EmptyState`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct EmptyStateProps<S: IconShape + 'static> {
+    /// The icon shape to use.
+    pub icon: S,
+    /// The heading, like "No results".
+    pub heading: String,
+    /// An optional description, shown below the heading.
+    #[props(default, strip_option)]
+    pub description: Option<String>,
+    /// An optional action, which is usually an [`IconButton`](crate::IconButton), shown below the
+    /// description.
+    #[props(default = VNode::empty())]
+    pub action: Element,
+    /// An optional class for the outer `<div>`. This is added after the `empty_state_class` from
+    /// the [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for the icon. This is added after the `empty_state_icon_class` from the
+    /// [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub icon_class: Option<String>,
+    /// An optional class for the heading.
+    #[props(default, strip_option)]
+    pub heading_class: Option<String>,
+    /// An optional class for the description.
+    #[props(default, strip_option)]
+    pub description_class: Option<String>,
+    /// The size of the icon. This defaults to 48 pixels.
+    #[props(default = 48)]
+    pub size: u32,
+}
+
+/// Renders a placeholder for an empty list or search result, with a large muted icon, a heading,
+/// an optional description, and an optional action.
+///
+/// The spacing and the icon's muted color come from the `empty_state_class` and
+/// `empty_state_icon_class` in the [`IconTheme`](crate::IconTheme). The icon is filled with
+/// "currentColor", so its color comes from its class.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <div class="..." style="display: flex; flex-direction: column; ...">
+///   <svg class="..." ...>
+///   <h3>Heading goes here</h3>
+///   <p>Description goes here</p>
+///   <div>
+///     Action goes here
+///   </div>
+/// </div>
+/// ```
+///
+/// See the [`EmptyStateProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn EmptyState<S: IconShape>(props: EmptyStateProps<S>) -> Element {
+    let theme = use_icon_theme();
+    let class = join_classes([
+        Some(theme.empty_state_class.as_str()),
+        props.class.as_deref(),
+    ]);
+    let icon_class = join_classes([
+        Some(theme.empty_state_icon_class.as_str()),
+        props.icon_class.as_deref(),
+    ]);
+    rsx! {
+        div {
+            class: if let Some(class) = class { class },
+            style: "display: flex; flex-direction: column; align-items: center; gap: 8px; text-align: center;",
+            Icon {
+                class: icon_class,
+                size: props.size,
+                icon: props.icon,
+            },
+            h3 {
+                class: if let Some(heading_class) = props.heading_class { heading_class },
+                { props.heading }
+            },
+            if let Some(description) = props.description {
+                p {
+                    class: if let Some(description_class) = props.description_class { description_class },
+                    { description }
+                },
+            }
+            if props.action != VNode::empty() {
+                div {
+                    { props.action }
+                },
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{outline, test::assert_rsx_eq};
+
+    const STYLE: &str =
+        "display: flex; flex-direction: column; align-items: center; gap: 8px; text-align: center;";
+
+    #[test]
+    fn empty_state_minimal() {
+        assert_rsx_eq(
+            rsx! {
+                EmptyState {
+                    icon: outline::Shape::Inbox,
+                    heading: "No messages",
+                },
+            },
+            rsx! {
+                div {
+                    class: "py-12",
+                    style: STYLE,
+                    svg {
+                        class: "text-gray-400",
+                        height: 48,
+                        width: 48,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Inbox.path() },
+                    },
+                    h3 {
+                        "No messages"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn empty_state_full() {
+        assert_rsx_eq(
+            rsx! {
+                EmptyState {
+                    icon: outline::Shape::MagnifyingGlass,
+                    heading: "No results",
+                    description: "Try a different search.",
+                    action: rsx! { button { "Clear search" } },
+                    class: "my-4",
+                    icon_class: "h-12",
+                    heading_class: "font-semibold",
+                    description_class: "text-sm",
+                },
+            },
+            rsx! {
+                div {
+                    class: "py-12 my-4",
+                    style: STYLE,
+                    svg {
+                        class: "text-gray-400 h-12",
+                        height: 48,
+                        width: 48,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::MagnifyingGlass.path() },
+                    },
+                    h3 {
+                        class: "font-semibold",
+                        "No results"
+                    },
+                    p {
+                        class: "text-sm",
+                        "Try a different search."
+                    },
+                    div {
+                        button {
+                            "Clear search"
+                        },
+                    },
+                },
+            },
+        );
+    }
+}
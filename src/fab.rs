@@ -0,0 +1,203 @@
+use crate::{
+    theme::{join_classes, use_icon_theme},
+    ButtonSize, IconButton, IconShape,
+};
+use dioxus::prelude::*;
+
+/// Where a [`Fab`] is placed on the screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FabPosition {
+    /// Fixed to the bottom right corner of the viewport.
+    #[default]
+    BottomRight,
+    /// Fixed to the bottom left corner of the viewport.
+    BottomLeft,
+    /// Fixed to the top right corner of the viewport.
+    TopRight,
+    /// Fixed to the top left corner of the viewport.
+    TopLeft,
+    /// Not fixed at all, so the button is laid out like any other element.
+    Inline,
+}
+
+impl FabPosition {
+    /// Returns the inline style that places the button, if it's fixed.
+    fn style(self) -> Option<&'static str> {
+        match self {
+            FabPosition::BottomRight => {
+                Some("position: fixed; bottom: 16px; right: 16px; z-index: 30;")
+            }
+            FabPosition::BottomLeft => {
+                Some("position: fixed; bottom: 16px; left: 16px; z-index: 30;")
+            }
+            FabPosition::TopRight => Some("position: fixed; top: 16px; right: 16px; z-index: 30;"),
+            FabPosition::TopLeft => Some("position: fixed; top: 16px; left: 16px; z-index: 30;"),
+            FabPosition::Inline => None,
+        }
+    }
+}
+
+/// Returns the padding around the icon for each size, in pixels.
+fn padding(size: ButtonSize) -> u32 {
+    match size {
+        ButtonSize::Sm => 8,
+        ButtonSize::Md => 12,
+        ButtonSize::Lg => 16,
+    }
+}
+
+/// The properties for the [`Fab`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct FabProps<S: IconShape + 'static> {
+    /// The icon shape to use.
+    pub icon: S,
+    /// The label for the button. When `extended` is false, this is the button's title and is only
+    /// visible to screen readers. When it's true, this is shown next to the icon.
+    pub label: String,
+    /// An optional handler for the button's `onclick` event.
+    #[props(default, strip_option)]
+    pub onclick: Option<EventHandler<MouseEvent>>,
+    /// If this is true then the label is shown next to the icon. This defaults to false.
+    #[props(default = false)]
+    pub extended: bool,
+    /// Where the button is placed. This defaults to [`FabPosition::BottomRight`].
+    #[props(default)]
+    pub position: FabPosition,
+    /// The preset size for the button, which sets the size of the icon and the padding around it.
+    /// This defaults to [`ButtonSize::Lg`], which makes a 56 pixel button.
+    #[props(default = ButtonSize::Lg)]
+    pub size: ButtonSize,
+    /// An optional class for the button. This is added after the `fab_class` from the
+    /// [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// If this is true then the button is disabled. This defaults to false.
+    #[props(default = false)]
+    pub disabled: bool,
+}
+
+/// Renders a floating action button, a circular, elevated [`IconButton`] for the main action on a
+/// screen.
+///
+/// By default the button is fixed to the bottom right corner of the viewport. Set `position` to
+/// [`FabPosition::Inline`] to lay it out with the rest of the page instead. The shape, color, and
+/// shadow come from the `fab_class` in the [`IconTheme`](crate::IconTheme).
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button class="..." title="Compose" style="position: fixed; ...">
+///   <svg ...>
+///   <span style="...">Compose</span>
+/// </button>
+/// ```
+///
+/// See the [`FabProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn Fab<S: IconShape>(props: FabProps<S>) -> Element {
+    let theme = use_icon_theme();
+    let class = join_classes([Some(theme.fab_class.as_str()), props.class.as_deref()]);
+    let extended = props.extended;
+    let style = format!(
+        "{}display: inline-flex; align-items: center; gap: 8px; padding: {}px;",
+        props
+            .position
+            .style()
+            .map(|s| format!("{s} "))
+            .unwrap_or_default(),
+        padding(props.size),
+    );
+    let label = props.label;
+    let children = if extended {
+        rsx! { "{label}" }
+    } else {
+        VNode::empty()
+    };
+    rsx! {
+        IconButton {
+            onclick: props.onclick,
+            class,
+            title: if extended { None } else { Some(label.clone()) },
+            size: props.size.icon_size(),
+            disabled: props.disabled,
+            icon: props.icon,
+            sr_only: if extended { None } else { Some(label.clone()) },
+            style,
+            children,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        outline,
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        SR_ONLY_STYLE,
+    };
+
+    const FAB_CLASS: &str = "rounded-full bg-blue-600 text-white shadow-lg hover:bg-blue-700";
+
+    #[test]
+    fn fab_default() {
+        assert_rsx_eq(
+            rsx! {
+                Fab {
+                    icon: outline::Shape::Pencil,
+                    label: "Compose",
+                },
+            },
+            rsx! {
+                button {
+                    class: "{FOCUS_CLASS} {FAB_CLASS}",
+                    title: "Compose",
+                    style: "position: fixed; bottom: 16px; right: 16px; z-index: 30; display: inline-flex; align-items: center; gap: 8px; padding: 16px;",
+                    svg {
+                        height: 24,
+                        width: 24,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Pencil.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Compose"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn fab_extended_inline() {
+        assert_rsx_eq(
+            rsx! {
+                Fab {
+                    icon: outline::Shape::Pencil,
+                    label: "Compose",
+                    extended: true,
+                    position: FabPosition::Inline,
+                    size: ButtonSize::Sm,
+                },
+            },
+            rsx! {
+                button {
+                    class: "{FOCUS_CLASS} {FAB_CLASS}",
+                    style: "display: inline-flex; align-items: center; gap: 8px; padding: 8px;",
+                    svg {
+                        height: 16,
+                        width: 16,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Pencil.path() },
+                    },
+                    span {
+                        "Compose"
+                    },
+                },
+            },
+        );
+    }
+}
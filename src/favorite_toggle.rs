@@ -0,0 +1,163 @@
+use crate::{outline, solid, state_toggle::StateToggle};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`FavoriteToggle`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct FavoriteToggleProps {
+    /// Whether the item is a favorite. If this is set then the toggle is controlled, and it will
+    /// only change state when you change this value, usually in response to `onchange`. If this is
+    /// not set then the toggle keeps track of its own state.
+    #[props(default, strip_option)]
+    pub favorited: Option<bool>,
+    /// The initial state of the toggle when `favorited` is not set. This defaults to false.
+    #[props(default = false)]
+    pub default_favorited: bool,
+    /// A signal to bind the state to. If this is set then the toggle shows the signal's value, and
+    /// clicking it sets the signal to the new state before calling `onchange`. This takes
+    /// precedence over `favorited`.
+    #[props(default, strip_option)]
+    pub bind: Option<Signal<bool>>,
+    /// An optional handler that is called with the new state when the toggle is clicked.
+    #[props(default, strip_option)]
+    pub onchange: Option<EventHandler<bool>>,
+    /// Set this to true while a change is being saved. The toggle keeps showing the new state, has
+    /// `aria-busy="true"`, and ignores clicks until this is false again. If saving fails, set
+    /// `favorited` back to its old value. This defaults to false.
+    #[props(default = false)]
+    pub pending: bool,
+    /// The accessible label for the toggle. This is also used as its title. This defaults to
+    /// "Favorite".
+    #[props(default = "Favorite".to_string())]
+    pub label: String,
+    /// An optional class for the button.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color for the solid heart. This defaults to "#DC2626", which is "red 600" from
+    /// tailwindcss.
+    #[props(into, default = "#DC2626")]
+    pub fill: Cow<'static, str>,
+    /// If this is true then the heart briefly grows when it's favorited. This defaults to true.
+    #[props(default = true)]
+    pub pop: bool,
+}
+
+/// Renders a heart toggle for marking something as a favorite.
+///
+/// The toggle shows an [`outline::Shape::Heart`] when it's off and a [`solid::Shape::Heart`] when
+/// it's on. Its label stays the same, and its state is exposed with `aria-pressed`.
+///
+/// For optimistic updates, change `favorited` as soon as `onchange` is called, set `pending` while
+/// the change is saved, and then either clear `pending` or, if saving failed, change `favorited`
+/// back.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button title="Favorite" aria-pressed="false">
+///   <span style="display: inline-flex; transition: transform 150ms; transform: scale(1);">
+///     <svg ...>
+///   </span>
+///   <span style="...">Favorite</span>
+/// </button>
+/// ```
+///
+/// See the [`FavoriteToggleProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn FavoriteToggle(props: FavoriteToggleProps) -> Element {
+    rsx! {
+        StateToggle {
+            icon_on: solid::Shape::Heart,
+            icon_off: outline::Shape::Heart,
+            fill_on: props.fill,
+            on: props.favorited,
+            default_on: props.default_favorited,
+            bind: props.bind,
+            onchange: props.onchange,
+            pending: props.pending,
+            label: props.label,
+            class: props.class,
+            size: props.size,
+            pop: props.pop,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn favorite_toggle_off() {
+        assert_rsx_eq(
+            rsx! {
+                FavoriteToggle {},
+            },
+            rsx! {
+                button {
+                    r#type: "button",
+                    class: FOCUS_CLASS,
+                    title: "Favorite",
+                    aria_pressed: "false",
+                    span {
+                        style: "display: inline-flex; transition: transform 150ms; transform: scale(1);",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: outline::VIEW_BOX,
+                            fill: "currentColor",
+                            { outline::Shape::Heart.path() },
+                        },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Favorite"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn favorite_toggle_pending() {
+        assert_rsx_eq(
+            rsx! {
+                FavoriteToggle {
+                    favorited: true,
+                    pending: true,
+                },
+            },
+            rsx! {
+                button {
+                    r#type: "button",
+                    class: FOCUS_CLASS,
+                    title: "Favorite",
+                    aria_pressed: "true",
+                    aria_busy: "true",
+                    span {
+                        style: "display: inline-flex; transition: transform 150ms; transform: scale(1);",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: solid::VIEW_BOX,
+                            fill: "#DC2626",
+                            { solid::Shape::Heart.path() },
+                        },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Favorite"
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{outline, Icon};
+use dioxus::prelude::*;
+
+/// A broad kind of file, for picking an icon.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FileType {
+    /// A text document, like a PDF, a Word document, or a Markdown file.
+    Text,
+    /// An image.
+    Image,
+    /// An archive, like a zip file or a tarball.
+    Archive,
+    /// Source code or structured data, like a Rust file or a JSON file.
+    Code,
+    /// A spreadsheet, including CSV files.
+    Spreadsheet,
+    /// Anything else.
+    #[default]
+    Other,
+}
+
+impl FileType {
+    /// Returns the file type for an extension, like "pdf" or ".pdf". The match is case-insensitive,
+    /// and unknown extensions are [`FileType::Other`].
+    #[must_use]
+    pub fn from_extension(extension: &str) -> Self {
+        let extension = extension.trim_start_matches('.').to_ascii_lowercase();
+        match extension.as_str() {
+            "pdf" | "txt" | "md" | "rtf" | "doc" | "docx" | "odt" | "pages" => FileType::Text,
+            "png" | "jpg" | "jpeg" | "gif" | "webp" | "avif" | "svg" | "bmp" | "ico" | "tif"
+            | "tiff" | "heic" => FileType::Image,
+            "zip" | "tar" | "gz" | "tgz" | "bz2" | "xz" | "zst" | "7z" | "rar" => FileType::Archive,
+            "rs" | "js" | "mjs" | "ts" | "jsx" | "tsx" | "py" | "rb" | "go" | "java" | "kt"
+            | "swift" | "c" | "h" | "cpp" | "hpp" | "cs" | "php" | "sh" | "html" | "css"
+            | "scss" | "json" | "toml" | "yaml" | "yml" | "xml" => FileType::Code,
+            "csv" | "tsv" | "xls" | "xlsx" | "ods" | "numbers" => FileType::Spreadsheet,
+            _ => FileType::Other,
+        }
+    }
+
+    /// Returns the file type for a file name or path, like "report.pdf", based on its extension.
+    /// Names without an extension, like "Makefile" or ".bashrc", are [`FileType::Other`].
+    #[must_use]
+    pub fn from_file_name(name: &str) -> Self {
+        let name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+        match name.rsplit_once('.') {
+            Some((stem, extension)) if !stem.is_empty() => Self::from_extension(extension),
+            _ => FileType::Other,
+        }
+    }
+
+    /// Returns the file type for a MIME type, like "application/pdf" or "image/png". Any parameters
+    /// after a `;` are ignored, and unknown MIME types are [`FileType::Other`].
+    #[must_use]
+    pub fn from_mime(mime: &str) -> Self {
+        let mime = mime
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        if mime.starts_with("image/") {
+            return FileType::Image;
+        }
+        match mime.as_str() {
+            "application/pdf"
+            | "text/plain"
+            | "text/markdown"
+            | "application/rtf"
+            | "application/msword"
+            | "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+            | "application/vnd.oasis.opendocument.text" => FileType::Text,
+            "application/zip"
+            | "application/gzip"
+            | "application/x-tar"
+            | "application/x-bzip2"
+            | "application/x-xz"
+            | "application/zstd"
+            | "application/x-7z-compressed"
+            | "application/vnd.rar" => FileType::Archive,
+            "text/csv"
+            | "text/tab-separated-values"
+            | "application/vnd.ms-excel"
+            | "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            | "application/vnd.oasis.opendocument.spreadsheet" => FileType::Spreadsheet,
+            "text/html"
+            | "text/css"
+            | "text/javascript"
+            | "application/javascript"
+            | "application/json"
+            | "application/xml"
+            | "text/xml"
+            | "application/toml"
+            | "application/yaml"
+            | "application/x-sh" => FileType::Code,
+            m if m.starts_with("text/x-") => FileType::Code,
+            _ => FileType::Other,
+        }
+    }
+
+    /// Returns the icon shape for this file type. [`FileType::Other`] is a plain
+    /// [`outline::Shape::Document`].
+    #[must_use]
+    pub fn shape(self) -> outline::Shape {
+        match self {
+            FileType::Text => outline::Shape::DocumentText,
+            FileType::Image => outline::Shape::Photo,
+            FileType::Archive => outline::Shape::ArchiveBox,
+            FileType::Code => outline::Shape::CodeBracket,
+            FileType::Spreadsheet => outline::Shape::TableCells,
+            FileType::Other => outline::Shape::Document,
+        }
+    }
+}
+
+/// The properties for the [`FileTypeIcon`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct FileTypeIconProps {
+    /// The file's name or path, which is used to pick the icon by its extension.
+    #[props(default, strip_option)]
+    pub file_name: Option<String>,
+    /// The file's MIME type. If this is set and is a known type then it's used instead of the
+    /// `file_name`.
+    #[props(default, strip_option)]
+    pub mime: Option<String>,
+    /// An optional class for the `<svg>` element.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 24 pixels.
+    #[props(default = 24)]
+    pub size: u32,
+}
+
+/// Renders the icon for a file, based on its MIME type or the extension in its name.
+///
+/// See [`FileType`] for the mapping from extensions and MIME types to icons. Files that don't match
+/// anything get a plain [`outline::Shape::Document`].
+///
+/// See the [`FileTypeIconProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn FileTypeIcon(props: FileTypeIconProps) -> Element {
+    let file_type = props
+        .mime
+        .as_deref()
+        .map(FileType::from_mime)
+        .filter(|t| *t != FileType::Other)
+        .or_else(|| props.file_name.as_deref().map(FileType::from_file_name))
+        .unwrap_or_default();
+    rsx! {
+        Icon {
+            class: props.class,
+            size: props.size,
+            icon: file_type.shape(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::assert_rsx_eq, IconShape};
+
+    #[test]
+    fn file_type_icon() {
+        assert_rsx_eq(
+            rsx! {
+                FileTypeIcon {
+                    file_name: "notes.txt",
+                    mime: "image/png",
+                },
+                FileTypeIcon {
+                    file_name: "data/Report.XLSX",
+                    mime: "application/octet-stream",
+                    size: 16,
+                },
+            },
+            rsx! {
+                svg {
+                    height: 24,
+                    width: 24,
+                    view_box: outline::VIEW_BOX,
+                    fill: "currentColor",
+                    { outline::Shape::Photo.path() },
+                },
+                svg {
+                    height: 16,
+                    width: 16,
+                    view_box: outline::VIEW_BOX,
+                    fill: "currentColor",
+                    { outline::Shape::TableCells.path() },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn file_types() {
+        assert_eq!(FileType::from_extension(".PDF"), FileType::Text);
+        assert_eq!(FileType::from_extension("tgz"), FileType::Archive);
+        assert_eq!(FileType::from_file_name("src/main.rs"), FileType::Code);
+        assert_eq!(
+            FileType::from_file_name("archive.tar.gz"),
+            FileType::Archive
+        );
+        assert_eq!(FileType::from_file_name(".bashrc"), FileType::Other);
+        assert_eq!(FileType::from_file_name("Makefile"), FileType::Other);
+        assert_eq!(
+            FileType::from_mime("text/csv; charset=utf-8"),
+            FileType::Spreadsheet,
+        );
+        assert_eq!(FileType::from_mime("text/x-python"), FileType::Code);
+        assert_eq!(FileType::from_mime("IMAGE/JPEG"), FileType::Image);
+        assert_eq!(FileType::from_mime("video/mp4"), FileType::Other);
+    }
+}
@@ -0,0 +1,181 @@
+//! Bridges between this crate's [`IconShape`] trait and the `IconShape` trait from
+//! [`dioxus-free-icons`](https://docs.rs/dioxus-free-icons), for apps that use both crates.
+//!
+//! Neither crate's shapes can implement the other crate's trait directly, so each direction has a
+//! wrapper. Wrap a heroicons shape in a [`HeroiconShape`] to pass it to a `dioxus-free-icons`
+//! component:
+//!
+//! ```rust,ignore
+//! rsx! {
+//!     dioxus_free_icons::Icon {
+//!         icon: HeroiconShape(outline::Shape::ArrowLeft),
+//!     }
+//! }
+//! ```
+//!
+//! And wrap a `dioxus-free-icons` shape in a [`FreeIconShape`] to pass it to one of this crate's
+//! components:
+//!
+//! ```rust,ignore
+//! rsx! {
+//!     IconButton {
+//!         icon: FreeIconShape(FaRust),
+//!         title: "Rust",
+//!     }
+//! }
+//! ```
+
+use crate::IconShape;
+use dioxus::prelude::*;
+
+const XMLNS: &str = "http://www.w3.org/2000/svg";
+
+/// A heroicons shape, like [`outline::Shape::ArrowLeft`](crate::outline::Shape::ArrowLeft), that
+/// implements `dioxus_free_icons::IconShape`, so it can be drawn by a `dioxus-free-icons`
+/// component. The shape is filled with that component's `fill` color, like it is by this crate's
+/// [`Icon`](crate::Icon).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HeroiconShape<S>(pub S);
+
+impl<S: IconShape> dioxus_free_icons::IconShape for HeroiconShape<S> {
+    fn view_box(&self) -> &str {
+        self.0.view_box()
+    }
+
+    fn xmlns(&self) -> &str {
+        XMLNS
+    }
+
+    fn child_elements(&self) -> Element {
+        self.0.path()
+    }
+
+    fn fill_and_stroke<'a>(&self, user_color: &'a str) -> (&'a str, &'a str, &'a str) {
+        (user_color, "none", "0")
+    }
+}
+
+/// A `dioxus-free-icons` shape, like `FaRust`, that implements this crate's [`IconShape`], so it
+/// can be drawn by this crate's components.
+///
+/// The shape's own fill, stroke, and line joins are set on a `<g>` around its elements. A filled
+/// shape inherits the `fill` of the [`Icon`](crate::Icon), but since the `Icon` doesn't set a
+/// stroke color, a stroked shape, like the Feather and Lucide shapes, is drawn in `currentColor`,
+/// so set its color with the CSS `color` property instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FreeIconShape<T>(pub T);
+
+impl<T: dioxus_free_icons::IconShape + Clone + PartialEq + std::fmt::Debug> IconShape
+    for FreeIconShape<T>
+{
+    fn view_box(&self) -> &str {
+        self.0.view_box()
+    }
+
+    fn path(&self) -> Element {
+        let (fill, stroke, stroke_width) = self.0.fill_and_stroke("inherit");
+        let stroke = if stroke == "inherit" {
+            "currentColor"
+        } else {
+            stroke
+        };
+        rsx! {
+            g {
+                fill,
+                stroke,
+                stroke_width,
+                stroke_linecap: self.0.stroke_linecap(),
+                stroke_linejoin: self.0.stroke_linejoin(),
+                {self.0.child_elements()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{outline, test::assert_rsx_eq, Icon};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Stroked;
+
+    impl dioxus_free_icons::IconShape for Stroked {
+        fn view_box(&self) -> &'static str {
+            "0 0 24 24"
+        }
+
+        fn xmlns(&self) -> &'static str {
+            XMLNS
+        }
+
+        fn child_elements(&self) -> Element {
+            rsx! {
+                path { d: "M0 0L24 24" }
+            }
+        }
+
+        fn fill_and_stroke<'a>(&self, user_color: &'a str) -> (&'a str, &'a str, &'a str) {
+            ("none", user_color, "2")
+        }
+
+        fn stroke_linecap(&self) -> &'static str {
+            "round"
+        }
+    }
+
+    #[test]
+    fn heroicon_shape() {
+        let shape = HeroiconShape(outline::Shape::ArrowLeft);
+        assert_rsx_eq(
+            rsx! {
+                dioxus_free_icons::Icon {
+                    icon: shape,
+                    fill: "red",
+                },
+            },
+            rsx! {
+                svg {
+                    class: "",
+                    height: "20",
+                    width: "20",
+                    view_box: "0 0 24 24",
+                    xmlns: XMLNS,
+                    fill: "red",
+                    stroke: "none",
+                    stroke_width: "0",
+                    stroke_linecap: "butt",
+                    stroke_linejoin: "miter",
+                    {outline::Shape::ArrowLeft.path()}
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn free_icon_shape() {
+        assert_rsx_eq(
+            rsx! {
+                Icon {
+                    icon: FreeIconShape(Stroked),
+                },
+            },
+            rsx! {
+                svg {
+                    height: 20,
+                    width: 20,
+                    view_box: "0 0 24 24",
+                    fill: "currentColor",
+                    g {
+                        fill: "none",
+                        stroke: "currentColor",
+                        stroke_width: "2",
+                        stroke_linecap: "round",
+                        stroke_linejoin: "miter",
+                        path { d: "M0 0L24 24" }
+                    }
+                }
+            },
+        );
+    }
+}
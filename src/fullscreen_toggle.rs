@@ -0,0 +1,253 @@
+use crate::{outline, ToggleIconButton};
+use dioxus::prelude::*;
+
+/// The properties for the [`FullscreenToggle`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct FullscreenToggleProps {
+    /// Whether the target is in full screen mode. If this is set then the toggle is controlled, and
+    /// it will only change state when you change this value, usually in response to `onchange`. If
+    /// this is not set then the toggle keeps track of its own state.
+    #[props(default, strip_option)]
+    pub fullscreen: Option<bool>,
+    /// A signal to bind the state to. If this is set then the toggle shows the signal's value, and
+    /// it sets the signal to the new state before calling `onchange`. If the Fullscreen API fails
+    /// then the signal isn't changed. This takes precedence over `fullscreen`.
+    #[props(default, strip_option)]
+    pub bind: Option<Signal<bool>>,
+    /// An optional handler that is called with the new state when the toggle is clicked, or when
+    /// the browser enters or exits full screen mode if there's a `target_id`.
+    #[props(default, strip_option)]
+    pub onchange: Option<EventHandler<bool>>,
+    /// The `id` of the element to show in full screen mode. If this is set and the `wasm` feature
+    /// is enabled, clicking the toggle calls the browser's Fullscreen API for this element.
+    #[props(default, strip_option)]
+    pub target_id: Option<String>,
+    /// An optional handler that is called with an error message if the Fullscreen API fails or the
+    /// browser refuses to show the target in full screen mode.
+    #[props(default, strip_option)]
+    pub onerror: Option<EventHandler<String>>,
+    /// The accessible label for the toggle. This is also used as its title. This defaults to "Full
+    /// screen".
+    #[props(default = "Full screen".to_string())]
+    pub label: String,
+    /// An optional class for the button.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+}
+
+/// Renders a button that switches between full screen and normal modes.
+///
+/// The button shows an [`outline::Shape::ArrowsPointingOut`] normally and an
+/// [`outline::Shape::ArrowsPointingIn`] in full screen mode. It's a [`ToggleIconButton`], so its
+/// state is exposed with `aria-pressed`.
+///
+/// With the `wasm` feature and a `target_id`, the toggle also enters and exits full screen mode
+/// with the browser's Fullscreen API. The browser may do that after a delay, or refuse to, so the
+/// toggle's state only changes when the browser sends a `fullscreenchange` event, and `onerror` is
+/// called when it sends a `fullscreenerror` event. This also means that when the browser exits
+/// full screen mode on its own, like when the user presses `Escape`, the toggle updates its state
+/// and calls `onchange`.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button title="Full screen" aria-pressed="false" type="button">
+///   <svg ...>
+///   <span style="...">Full screen</span>
+/// </button>
+/// ```
+///
+/// See the [`FullscreenToggleProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn FullscreenToggle(props: FullscreenToggleProps) -> Element {
+    // The state is kept here instead of in the `ToggleIconButton`, so that with a target it only
+    // changes when the browser reports a change.
+    let mut uncontrolled = use_signal(|| false);
+    let target_id = props.target_id;
+    let onchange = props.onchange;
+    let onerror = props.onerror;
+    let bind = props.bind;
+    let controlled = bind.map(|b| b()).or(props.fullscreen);
+    let fullscreen = controlled.unwrap_or(uncontrolled());
+    let update = use_callback(move |new: bool| {
+        if new == fullscreen {
+            return;
+        }
+        if let Some(mut bind) = bind {
+            bind.set(new);
+        } else if controlled.is_none() {
+            uncontrolled.set(new);
+        }
+        if let Some(oc) = onchange {
+            oc.call(new);
+        }
+    });
+    use_fullscreen_events(target_id.as_deref(), update, onerror);
+    rsx! {
+        ToggleIconButton {
+            icon_on: outline::Shape::ArrowsPointingIn,
+            icon_off: outline::Shape::ArrowsPointingOut,
+            pressed: fullscreen,
+            onchange: move |new: bool| {
+                let Some(target_id) = &target_id else {
+                    update.call(new);
+                    return;
+                };
+                match set_fullscreen(target_id, new) {
+                    // The state changes when the browser says that it did.
+                    Ok(true) => (),
+                    Ok(false) => update.call(new),
+                    Err(e) => {
+                        if let Some(oe) = onerror {
+                            oe.call(e);
+                        }
+                    }
+                }
+            },
+            class: props.class,
+            title: props.label.clone(),
+            size: props.size,
+            sr_only: props.label,
+            r#type: "button",
+        }
+    }
+}
+
+// Asks the browser to show the target in full screen mode, or to exit it. Returns true if the
+// browser will send a `fullscreenchange` or `fullscreenerror` event when it's done, and false if
+// there's nothing for it to do, like exiting when nothing is in full screen mode.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+fn set_fullscreen(target_id: &str, fullscreen: bool) -> Result<bool, String> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| "there is no browser document".to_string())?;
+    if fullscreen {
+        let element = document
+            .get_element_by_id(target_id)
+            .ok_or_else(|| format!("there is no element with the id {target_id}"))?;
+        element
+            .request_fullscreen()
+            .map_err(|e| format!("could not enter full screen mode: {e:?}"))?;
+        Ok(true)
+    } else if document.fullscreen_element().is_some() {
+        document.exit_fullscreen();
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+#[allow(clippy::unnecessary_wraps)]
+fn set_fullscreen(_target_id: &str, _fullscreen: bool) -> Result<bool, String> {
+    Ok(false)
+}
+
+// Listens for `fullscreenchange` events on the document and calls `onchange` with whether the
+// target is now in full screen mode, and for `fullscreenerror` events on the target and calls
+// `onerror`. The listeners are replaced when the target changes and removed when the component is
+// unmounted.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+fn use_fullscreen_events(
+    target_id: Option<&str>,
+    onchange: Callback<bool>,
+    onerror: Option<EventHandler<String>>,
+) {
+    use crate::listener::Listener;
+    use std::{cell::RefCell, rc::Rc};
+    use wasm_bindgen::JsCast;
+
+    let target_id = target_id.map(str::to_string);
+    let listeners = use_hook(|| Rc::new(RefCell::new(Vec::<Listener>::new())));
+    {
+        let listeners = Rc::clone(&listeners);
+        use_effect(use_reactive((&target_id,), move |(target_id,)| {
+            let mut listeners = listeners.borrow_mut();
+            listeners.clear();
+            let Some(target_id) = target_id else {
+                return;
+            };
+            let id = target_id.clone();
+            listeners.extend(Listener::add(
+                "fullscreenchange",
+                move |document, _: web_sys::Event| {
+                    onchange.call(document.fullscreen_element().is_some_and(|e| e.id() == id));
+                },
+            ));
+            listeners.extend(Listener::add(
+                "fullscreenerror",
+                move |_, evt: web_sys::Event| {
+                    let is_target = evt
+                        .target()
+                        .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+                        .is_some_and(|e| e.id() == target_id);
+                    if let (true, Some(oe)) = (is_target, onerror) {
+                        oe.call("the browser could not enter full screen mode".to_string());
+                    }
+                },
+            ));
+        }));
+    }
+    use_drop(move || {
+        listeners.borrow_mut().clear();
+    });
+}
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+fn use_fullscreen_events(
+    _target_id: Option<&str>,
+    _onchange: Callback<bool>,
+    _onerror: Option<EventHandler<String>>,
+) {
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn fullscreen_toggle() {
+        #[allow(non_snake_case)]
+        fn Toggle() -> Element {
+            rsx! {
+                FullscreenToggle {
+                    fullscreen: true,
+                    target_id: "video",
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Toggle {},
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    title: "Full screen",
+                    aria_pressed: "true",
+                    r#type: "button",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::ArrowsPointingIn.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Full screen"
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use crate::{
+    mini,
+    theme::{join_classes, use_icon_theme},
+    Icon, SR_ONLY_STYLE,
+};
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// The properties for the [`IconCheckbox`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconCheckboxProps {
+    /// The checked state of the checkbox. If this is set then the checkbox is controlled, and it
+    /// will only change state when you change this value, usually in response to `onchange`. If
+    /// this is not set then the checkbox keeps track of its own state.
+    #[props(default, strip_option)]
+    pub checked: Option<bool>,
+    /// The initial checked state of the checkbox when `checked` is not set. This defaults to false.
+    #[props(default = false)]
+    pub default_checked: bool,
+    /// A signal to bind the checked state to. If this is set then the checkbox shows the signal's
+    /// value, and changing it sets the signal to the new state before calling `onchange`. This
+    /// takes precedence over `checked`.
+    #[props(default, strip_option)]
+    pub bind: Option<Signal<bool>>,
+    /// If this is true then the checkbox is shown with a [`mini::Shape::Minus`], regardless of
+    /// whether it's checked. With the `wasm` feature this also sets the native input's
+    /// `indeterminate` property, so screen readers announce it as mixed. This defaults to false.
+    #[props(default = false)]
+    pub indeterminate: bool,
+    /// If this is true then the checkbox is disabled. This defaults to false.
+    #[props(default = false)]
+    pub disabled: bool,
+    /// An optional handler that is called with the new checked state when the checkbox changes.
+    #[props(default, strip_option)]
+    pub onchange: Option<EventHandler<bool>>,
+    /// An optional `name` for the native `<input>`.
+    #[props(default, strip_option)]
+    pub name: Option<String>,
+    /// An optional `value` for the native `<input>`.
+    #[props(default, strip_option)]
+    pub value: Option<String>,
+    /// An optional class for the outer `<label>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the box. This defaults to 16 pixels.
+    #[props(default = 16)]
+    pub size: u32,
+    /// The label for the checkbox, which can be plain text or any other elements.
+    pub children: Element,
+}
+
+/// Renders a checkbox drawn with heroicons.
+///
+/// The checkbox is a native `<input type="checkbox">` that is visually hidden, so it works with
+/// forms, the keyboard, and screen readers. Next to it is a box that shows a
+/// [`mini::Shape::Check`] when it's checked. The box's classes come from the `checkbox_class` and
+/// `checkbox_checked_class` in the [`IconTheme`](crate::IconTheme). The input has the `peer` class,
+/// so the box can show a focus ring with tailwindcss's `peer-focus-visible` variant.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <label style="...">
+///   <input type="checkbox" class="peer" style="...">
+///   <span aria-hidden="true" class="..." style="...">
+///     <svg ...>
+///   </span>
+///   <span>
+///     Child elements go here
+///   </span>
+/// </label>
+/// ```
+///
+/// See the [`IconCheckboxProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconCheckbox(props: IconCheckboxProps) -> Element {
+    let theme = use_icon_theme();
+    let mut uncontrolled = use_signal(|| props.default_checked);
+    let bind = props.bind;
+    let controlled = bind.map(|b| b()).or(props.checked);
+    let checked = controlled.unwrap_or(uncontrolled());
+    let onchange = props.onchange;
+    let marked = checked || props.indeterminate;
+    let box_class = join_classes([
+        Some(theme.checkbox_class.as_str()),
+        marked.then_some(theme.checkbox_checked_class.as_str()),
+    ]);
+    let size = props.size;
+    // The indeterminate state is only a DOM property, not an attribute, so it's set on the mounted
+    // input whenever it or the `indeterminate` prop changes.
+    let mut input = use_signal(|| None::<Rc<MountedData>>);
+    use_effect(use_reactive(
+        (&props.indeterminate,),
+        move |(indeterminate,)| {
+            if let Some(input) = input() {
+                set_indeterminate(&input, indeterminate);
+            }
+        },
+    ));
+    rsx! {
+        label {
+            class: if let Some(class) = props.class { class },
+            style: "position: relative; display: inline-flex; align-items: center; gap: 8px;",
+            input {
+                r#type: "checkbox",
+                class: "peer",
+                style: SR_ONLY_STYLE,
+                name: if let Some(name) = props.name { name },
+                value: if let Some(value) = props.value { value },
+                checked,
+                disabled: props.disabled,
+                onmounted: move |evt: MountedEvent| input.set(Some(evt.data())),
+                onchange: move |evt| {
+                    let new = evt.checked();
+                    if let Some(mut bind) = bind {
+                        bind.set(new);
+                    } else if controlled.is_none() {
+                        uncontrolled.set(new);
+                    }
+                    if let Some(oc) = onchange {
+                        oc.call(new);
+                    }
+                },
+            },
+            span {
+                aria_hidden: true,
+                class: if let Some(box_class) = box_class { box_class },
+                style: "display: inline-flex; align-items: center; justify-content: center; width: {size}px; height: {size}px;",
+                if props.indeterminate {
+                    Icon {
+                        size,
+                        icon: mini::Shape::Minus,
+                    },
+                } else if checked {
+                    Icon {
+                        size,
+                        icon: mini::Shape::Check,
+                    },
+                }
+            },
+            if props.children != VNode::empty() {
+                span {
+                    { props.children }
+                },
+            }
+        },
+    }
+}
+
+#[cfg(feature = "wasm")]
+fn set_indeterminate(input: &MountedData, indeterminate: bool) {
+    use dioxus_web::WebEventExt;
+    use wasm_bindgen::JsCast;
+
+    if let Some(input) = input.as_web_event().dyn_ref::<web_sys::HtmlInputElement>() {
+        input.set_indeterminate(indeterminate);
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+fn set_indeterminate(_input: &MountedData, _indeterminate: bool) {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::assert_rsx_eq, IconShape};
+
+    const BOX_STYLE: &str = "display: inline-flex; align-items: center; justify-content: center; width: 16px; height: 16px;";
+    const BOX_CLASS: &str = "rounded border border-gray-300 bg-white text-white peer-focus-visible:ring-2 peer-focus-visible:ring-blue-500";
+
+    #[test]
+    fn icon_checkbox_unchecked() {
+        assert_rsx_eq(
+            rsx! {
+                IconCheckbox {
+                    name: "agree",
+                    "I agree"
+                },
+            },
+            rsx! {
+                label {
+                    style: "position: relative; display: inline-flex; align-items: center; gap: 8px;",
+                    input {
+                        r#type: "checkbox",
+                        class: "peer",
+                        style: SR_ONLY_STYLE,
+                        name: "agree",
+                        checked: false,
+                        disabled: false,
+                    },
+                    span {
+                        aria_hidden: true,
+                        class: BOX_CLASS,
+                        style: BOX_STYLE,
+                    },
+                    span {
+                        "I agree"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_checkbox_checked() {
+        assert_rsx_eq(
+            rsx! {
+                IconCheckbox {
+                    checked: true,
+                    disabled: true,
+                },
+            },
+            rsx! {
+                label {
+                    style: "position: relative; display: inline-flex; align-items: center; gap: 8px;",
+                    input {
+                        r#type: "checkbox",
+                        class: "peer",
+                        style: SR_ONLY_STYLE,
+                        checked: true,
+                        disabled: true,
+                    },
+                    span {
+                        aria_hidden: true,
+                        class: "{BOX_CLASS} border-blue-600 bg-blue-600",
+                        style: BOX_STYLE,
+                        svg {
+                            height: 16,
+                            width: 16,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::Check.path() },
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_checkbox_indeterminate() {
+        assert_rsx_eq(
+            rsx! {
+                IconCheckbox {
+                    indeterminate: true,
+                },
+            },
+            rsx! {
+                label {
+                    style: "position: relative; display: inline-flex; align-items: center; gap: 8px;",
+                    input {
+                        r#type: "checkbox",
+                        class: "peer",
+                        style: SR_ONLY_STYLE,
+                        checked: false,
+                        disabled: false,
+                    },
+                    span {
+                        aria_hidden: true,
+                        class: "{BOX_CLASS} border-blue-600 bg-blue-600",
+                        style: BOX_STYLE,
+                        svg {
+                            height: 16,
+                            width: 16,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::Minus.path() },
+                        },
+                    },
+                },
+            },
+        );
+    }
+}
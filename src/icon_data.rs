@@ -0,0 +1,110 @@
+use crate::{IconShape, PathData};
+use dioxus::prelude::*;
+use std::{any::Any, fmt, rc::Rc};
+
+/// An icon shape of any type. The generic components like [`Icon`](crate::Icon) and
+/// [`IconButton`](crate::IconButton) convert their shape into this and then do all their rendering
+/// with it, so the code that renders them is only compiled once, instead of once for each shape
+/// type an app uses.
+#[derive(Clone)]
+pub(crate) struct IconData(Rc<dyn AnyShape>);
+
+impl IconData {
+    /// Wraps a shape. If the shape is already an `IconData` then it's just cloned, so converting
+    /// twice doesn't add another layer of indirection.
+    pub(crate) fn new<S: IconShape + 'static>(shape: S) -> Self {
+        match (&shape as &dyn Any).downcast_ref::<Self>() {
+            Some(data) => data.clone(),
+            None => Self(Rc::new(shape)),
+        }
+    }
+}
+
+impl PartialEq for IconData {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_any(other.0.as_any())
+    }
+}
+
+impl fmt::Debug for IconData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_debug(f)
+    }
+}
+
+impl IconShape for IconData {
+    fn view_box(&self) -> &str {
+        self.0.view_box()
+    }
+
+    fn path(&self) -> Element {
+        self.0.path()
+    }
+
+    fn path_html(&self) -> Option<&'static str> {
+        self.0.path_html()
+    }
+
+    fn paths(&self) -> Option<&'static [PathData]> {
+        self.0.paths()
+    }
+}
+
+/// The object-safe parts of [`IconShape`], plus what's needed to compare and debug a shape without
+/// knowing its type.
+trait AnyShape {
+    fn view_box(&self) -> &str;
+    fn path(&self) -> Element;
+    fn path_html(&self) -> Option<&'static str>;
+    fn paths(&self) -> Option<&'static [PathData]>;
+    fn as_any(&self) -> &dyn Any;
+    fn eq_any(&self, other: &dyn Any) -> bool;
+    fn fmt_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl<S: IconShape + 'static> AnyShape for S {
+    fn view_box(&self) -> &str {
+        IconShape::view_box(self)
+    }
+
+    fn path(&self) -> Element {
+        IconShape::path(self)
+    }
+
+    fn path_html(&self) -> Option<&'static str> {
+        IconShape::path_html(self)
+    }
+
+    fn paths(&self) -> Option<&'static [PathData]> {
+        IconShape::paths(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn eq_any(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<S>() == Some(self)
+    }
+
+    fn fmt_debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mini, outline};
+
+    #[test]
+    fn icon_data() {
+        let data = IconData::new(outline::Shape::ArrowLeft);
+        assert_eq!(data, IconData::new(outline::Shape::ArrowLeft));
+        assert_ne!(data, IconData::new(outline::Shape::ArrowRight));
+        assert_ne!(data, IconData::new(mini::Shape::ArrowLeft));
+        assert_eq!(IconShape::view_box(&data), outline::VIEW_BOX);
+        assert_eq!(format!("{data:?}"), "ArrowLeft");
+        assert!(Rc::ptr_eq(&IconData::new(data.clone()).0, &data.0));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    theme::{join_classes, use_icon_theme},
+    BadgePlacement, Icon, IconProps, IconShape, DISABLED_FILL_COLOR, DOT_COLOR,
+};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`IconLink`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconLinkProps<S: IconShape + 'static> {
+    /// The URL the link points to.
+    pub href: String,
+    /// An optional `target` for the link, like "_blank".
+    #[props(default, strip_option)]
+    pub target: Option<String>,
+    /// An optional `rel` for the link, like "noopener noreferrer".
+    #[props(default, strip_option)]
+    pub rel: Option<String>,
+    /// An optional `download` attribute for the link. If this is set to an empty string, the
+    /// attribute is rendered without a filename.
+    #[props(default, strip_option)]
+    pub download: Option<String>,
+    /// An optional class for the *link itself*.
+    #[props(default, strip_option)]
+    pub class: Option<Cow<'static, str>>,
+    /// An optional title for the link element.
+    #[props(default, strip_option)]
+    pub title: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color to use for the icon. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+    /// The icon shape to use.
+    pub icon: S,
+    /// An optional class for the `<span>` that is part of this component.
+    #[props(default, strip_option)]
+    pub span_class: Option<Cow<'static, str>>,
+    /// An optional class that will be passed to the [`Icon`].
+    #[props(default, strip_option)]
+    pub icon_class: Option<Cow<'static, str>>,
+    /// An optional class to use for the link's focus indicator instead of the `focus_class` from
+    /// the [`IconTheme`](crate::IconTheme). Set this to an empty string to remove the focus
+    /// indicator classes.
+    #[props(default, strip_option)]
+    pub focus_class: Option<Cow<'static, str>>,
+    /// Any other attributes for the *link itself*.
+    #[props(extends = GlobalAttributes, extends = a)]
+    pub attributes: Vec<Attribute>,
+    /// These are the child elements of the `IconLink` component.
+    pub children: Element,
+}
+
+/// Renders an `<a>` containing an SVG icon.
+///
+/// This is the counterpart to [`IconButton`](crate::IconButton) for actions that navigate
+/// somewhere. This component will generate HTML like this:
+///
+/// ```html
+/// <a href="...">
+///   <svg ...>
+///   <span>
+///     Child elements go here
+///   </span>
+/// </a>
+/// ```
+///
+/// See the [`IconLinkProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconLink<S: IconShape>(props: IconLinkProps<S>) -> Element {
+    let theme = use_icon_theme();
+    let class = join_classes([
+        Some(props.focus_class.as_deref().unwrap_or(&theme.focus_class)),
+        props.class.as_deref(),
+    ]);
+    rsx! {
+        a {
+            href: props.href,
+            target: if let Some(target) = props.target { target },
+            rel: if let Some(rel) = props.rel { rel },
+            download: if let Some(download) = props.download { download },
+            class: if let Some(class) = class { class },
+            title: if let Some(title) = props.title { title },
+            ..props.attributes,
+            Icon {
+                ..IconProps {
+                    class: props.icon_class,
+                    size: props.size,
+                    fill: props.fill,
+                    icon: props.icon.clone(),
+                    disabled: false,
+                    disabled_fill: DISABLED_FILL_COLOR.into(),
+                    dot: false,
+                    dot_color: DOT_COLOR.into(),
+                    dot_placement: BadgePlacement::default(),
+                    prerender: false,
+                },
+            },
+            if props.children != VNode::empty() {
+                span {
+                    class: if let Some(span_class) = props.span_class.as_deref() { span_class },
+                    { props.children }
+                },
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        outline,
+        test::{assert_rsx_eq, FOCUS_CLASS},
+    };
+
+    #[test]
+    fn icon_link_default() {
+        assert_rsx_eq(
+            rsx! {
+                IconLink {
+                    href: "/home",
+                    icon: outline::Shape::Home,
+                },
+            },
+            rsx! {
+                a {
+                    href: "/home",
+                    class: FOCUS_CLASS,
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Home.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_link_with_props() {
+        assert_rsx_eq(
+            rsx! {
+                IconLink {
+                    href: "/report.pdf",
+                    target: "_blank",
+                    rel: "noopener",
+                    download: "report.pdf",
+                    class: "some-link",
+                    icon: outline::Shape::ArrowDownTray,
+                    "Download"
+                },
+            },
+            rsx! {
+                a {
+                    href: "/report.pdf",
+                    target: "_blank",
+                    rel: "noopener",
+                    download: "report.pdf",
+                    class: "{FOCUS_CLASS} some-link",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::ArrowDownTray.path() },
+                    },
+                    span {
+                        "Download"
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{IconButton, IconSet};
+use dioxus::prelude::*;
+
+/// The properties for the [`IconPicker`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconPickerProps<S: IconSet> {
+    /// The handler that is called with a shape when it's clicked.
+    pub onselect: EventHandler<S>,
+    /// The currently selected shape, if any. Its button has `aria-pressed` set to true.
+    #[props(default, strip_option)]
+    pub selected: Option<S>,
+    /// The shapes to pick from. This defaults to all the shapes in the set, from
+    /// [`IconSet::all`].
+    #[props(default, strip_option)]
+    pub shapes: Option<Vec<S>>,
+    /// An optional class for the picker's outer `<div>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for the search `<input>`.
+    #[props(default, strip_option)]
+    pub input_class: Option<String>,
+    /// An optional class for each shape's button.
+    #[props(default, strip_option)]
+    pub button_class: Option<String>,
+    /// The placeholder text for the search input. This defaults to "Search icons".
+    #[props(default = "Search icons".to_string())]
+    pub placeholder: String,
+    /// The size of the icons. This defaults to 24 pixels.
+    #[props(default = 24)]
+    pub size: u32,
+}
+
+/// Renders a searchable grid of icon shapes.
+///
+/// The search uses [`IconSet::matches`], which matches against each shape's heroicons name and its
+/// tags, so searching for "arrow left" finds `ArrowLeft`, `ArrowLeftCircle`, and so on, and
+/// searching for "download" finds `ArrowDownTray`. Pick the set of shapes with the type parameter,
+/// for example:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_heroicons::{outline, IconPicker};
+///
+/// fn Settings() -> Element {
+///     let mut icon = use_signal(|| outline::Shape::Star);
+///     rsx! {
+///         IconPicker::<outline::Shape> {
+///             selected: icon(),
+///             onselect: move |shape| icon.set(shape),
+///         }
+///     }
+/// }
+/// ```
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <div>
+///   <input type="search" placeholder="Search icons" aria-label="Search icons">
+///   <div style="display: grid; ...">
+///     <button title="academic-cap" aria-pressed="false">
+///       <svg ...>
+///       <span style="...">academic-cap</span>
+///     </button>
+///     ...
+///   </div>
+/// </div>
+/// ```
+///
+/// See the [`IconPickerProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconPicker<S: IconSet>(props: IconPickerProps<S>) -> Element {
+    let mut query = use_signal(String::new);
+    let onselect = props.onselect;
+    let shapes = props.shapes.unwrap_or_else(|| S::all().to_vec());
+    let matching = shapes
+        .into_iter()
+        .filter(|s| s.matches(&query.read()))
+        .collect::<Vec<_>>();
+    rsx! {
+        div {
+            class: if let Some(class) = props.class { class },
+            input {
+                r#type: "search",
+                class: if let Some(input_class) = props.input_class { input_class },
+                placeholder: props.placeholder.clone(),
+                aria_label: props.placeholder,
+                value: query(),
+                oninput: move |evt| query.set(evt.value()),
+            },
+            div {
+                style: "display: grid; grid-template-columns: repeat(auto-fill, minmax({props.size * 2}px, 1fr));",
+                for shape in matching {
+                    IconButton {
+                        key: "{shape.name()}",
+                        onclick: move |_| onselect.call(shape),
+                        class: props.button_class.clone(),
+                        title: shape.name(),
+                        size: props.size,
+                        icon: shape,
+                        sr_only: shape.name(),
+                        aria_pressed: props.selected == Some(shape),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        outline,
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn icon_picker() {
+        #[allow(non_snake_case)]
+        fn Picker() -> Element {
+            rsx! {
+                IconPicker::<outline::Shape> {
+                    onselect: |_| {},
+                    selected: outline::Shape::Star,
+                    shapes: vec![outline::Shape::Sparkles, outline::Shape::Star],
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Picker {},
+            },
+            rsx! {
+                div {
+                    input {
+                        r#type: "search",
+                        placeholder: "Search icons",
+                        aria_label: "Search icons",
+                        value: "",
+                    },
+                    div {
+                        style: "display: grid; grid-template-columns: repeat(auto-fill, minmax(48px, 1fr));",
+                        button {
+                            class: FOCUS_CLASS,
+                            title: "sparkles",
+                            aria_pressed: "false",
+                            svg {
+                                height: 24,
+                                width: 24,
+                                view_box: outline::VIEW_BOX,
+                                fill: "currentColor",
+                                { outline::Shape::Sparkles.path() },
+                            },
+                            span {
+                                style: SR_ONLY_STYLE,
+                                "sparkles"
+                            },
+                        },
+                        button {
+                            class: FOCUS_CLASS,
+                            title: "star",
+                            aria_pressed: "true",
+                            svg {
+                                height: 24,
+                                width: 24,
+                                view_box: outline::VIEW_BOX,
+                                fill: "currentColor",
+                                { outline::Shape::Star.path() },
+                            },
+                            span {
+                                style: SR_ONLY_STYLE,
+                                "star"
+                            },
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn matching() {
+        let shape = outline::Shape::ArrowDownTray;
+        assert!(shape.matches("arrow down"));
+        assert!(shape.matches(" Down-Tray "));
+        assert!(shape.matches(""));
+        assert!(shape.matches("Download"));
+        assert!(!shape.matches("left"));
+    }
+
+    #[test]
+    fn all_shapes() {
+        assert_eq!(outline::Shape::all()[0], outline::Shape::AcademicCap);
+        assert_eq!(outline::Shape::Squares2x2.name(), "squares-2x2");
+        assert_eq!(outline::Shape::all().len(), crate::mini::Shape::all().len());
+    }
+}
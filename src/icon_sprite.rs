@@ -0,0 +1,355 @@
+use crate::IconSet;
+use dioxus::prelude::*;
+use std::borrow::Cow;
+use std::{fs, io, path::Path};
+
+/// The inline style for the sprite's `<svg>`. This hides it without using `display: none`, which
+/// stops some browsers from rendering the symbols it contains.
+const SPRITE_STYLE: &str = "position: absolute; width: 0; height: 0; overflow: hidden;";
+
+/// The properties for the [`IconSprite`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconSpriteProps<S: IconSet> {
+    /// The shapes to define symbols for. Each shape should only be listed once.
+    pub shapes: Vec<S>,
+    /// The prefix for each symbol's `id`. The `id` is the prefix, a hyphen, and the shape's
+    /// heroicons name, like "heroicon-arrow-left". If a page has sprites for more than one style,
+    /// give each one a different prefix. This defaults to "heroicon".
+    #[props(default = "heroicon".to_string())]
+    pub prefix: String,
+}
+
+/// Renders a hidden SVG sprite with a `<symbol>` for each of the given shapes.
+///
+/// Render this once per page, then use a [`UseIcon`] with the same `prefix` for each icon. Each
+/// [`UseIcon`] is an `<svg>` containing a single `<use>` element that points at a symbol, so a
+/// page that shows the same icons many times, like a table or a file tree, only contains each
+/// icon's path data once. For example:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_heroicons::{outline, IconSprite, UseIcon};
+///
+/// fn FileTree(files: Vec<String>) -> Element {
+///     rsx! {
+///         IconSprite::<outline::Shape> {
+///             shapes: vec![outline::Shape::Document, outline::Shape::Folder],
+///         },
+///         for file in files {
+///             div {
+///                 UseIcon { icon: outline::Shape::Document },
+///                 "{file}"
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <svg aria-hidden="true" style="position: absolute; ...">
+///   <symbol id="heroicon-document" viewBox="0 0 24 24">
+///     <path ...>
+///   </symbol>
+///   ...
+/// </svg>
+/// ```
+///
+/// See the [`IconSpriteProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconSprite<S: IconSet>(props: IconSpriteProps<S>) -> Element {
+    rsx! {
+        svg {
+            "aria-hidden": "true",
+            style: SPRITE_STYLE,
+            for shape in props.shapes {
+                symbol {
+                    key: "{shape.name()}",
+                    id: symbol_id(&props.prefix, shape),
+                    view_box: "{shape.view_box()}",
+                    { shape.path() }
+                }
+            }
+        }
+    }
+}
+
+/// The properties for the [`UseIcon`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct UseIconProps<S: IconSet> {
+    /// The icon shape to use. This must be one of the shapes in an [`IconSprite`] with the same
+    /// `prefix`.
+    pub icon: S,
+    /// The prefix of the [`IconSprite`] that defines the shape. This defaults to "heroicon".
+    #[props(default = "heroicon".to_string())]
+    pub prefix: String,
+    /// The URL of an external sprite sheet file, like one written by [`write_sprite_sheet`]. This
+    /// can also be an `Asset` from Dioxus' `asset!` macro. If this is not set then the symbol must
+    /// be in an [`IconSprite`] on the same page.
+    #[props(default, strip_option, into)]
+    pub sprite_url: Option<String>,
+    /// An optional class for the `<svg>` element.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the `<svg>` element. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The color to use for filling the icon. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+}
+
+/// Renders an icon that refers to a symbol in an [`IconSprite`], or in an external sprite sheet
+/// written by [`write_sprite_sheet`].
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <svg height="20" width="20" viewBox="0 0 24 24" fill="currentColor">
+///   <use href="#heroicon-document"></use>
+/// </svg>
+/// ```
+///
+/// See the [`UseIconProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn UseIcon<S: IconSet>(props: UseIconProps<S>) -> Element {
+    let href = format!(
+        "{}#{}",
+        props.sprite_url.unwrap_or_default(),
+        symbol_id(&props.prefix, props.icon),
+    );
+    rsx! {
+        svg {
+            class: if let Some(class) = props.class { class },
+            height: "{props.size}",
+            width: "{props.size}",
+            view_box: "{props.icon.view_box()}",
+            fill: "{props.fill}",
+            r#use {
+                href,
+            }
+        }
+    }
+}
+
+/// Writes an SVG sprite sheet file with a `<symbol>` for each of the given shapes, along with a
+/// Rust file that has a constant for each symbol's `id`.
+///
+/// This is meant to be called from a `build.rs`, so that an app can serve the sprite sheet as a
+/// cacheable static file instead of putting the icons in its HTML. Use a [`UseIcon`] with its
+/// `sprite_url` set to the sheet's URL to show an icon from it. The `id`s are made from the
+/// `prefix` in the same way as for an [`IconSprite`].
+///
+/// The Rust file has a constant for each shape, named after its heroicons name, like
+/// `ARROW_LEFT`, and a `SYMBOL_IDS` constant with all of them. For example, in `build.rs`:
+///
+/// ```rust,no_run
+/// use dioxus_heroicons::{outline, write_sprite_sheet};
+/// use std::{env, path::PathBuf};
+///
+/// let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+/// write_sprite_sheet(
+///     &[outline::Shape::Document, outline::Shape::Folder],
+///     "heroicon",
+///     "assets/icons.svg",
+///     out_dir.join("icons.rs"),
+/// )
+/// .unwrap();
+/// ```
+///
+/// And then in the app, `include!(concat!(env!("OUT_DIR"), "/icons.rs"));` defines the constants.
+///
+/// If the sprite sheet is written to the app's `assets` directory, Dioxus' asset system can bundle
+/// it, so that its URL is fingerprinted and it's served on the web, desktop, and fullstack alike.
+/// Pass the `Asset` to a [`UseIcon`] as its `sprite_url`:
+///
+/// ```rust,ignore
+/// const SPRITES: Asset = asset!("/assets/icons.svg");
+///
+/// rsx! {
+///     UseIcon {
+///         icon: outline::Shape::Folder,
+///         sprite_url: SPRITES,
+///     }
+/// }
+/// ```
+///
+/// # Errors
+///
+/// This returns an error if either file can't be written, or if one of the shapes doesn't have a
+/// [`path_html`](crate::IconShape::path_html).
+pub fn write_sprite_sheet<S: IconSet>(
+    shapes: &[S],
+    prefix: &str,
+    svg_file: impl AsRef<Path>,
+    rust_file: impl AsRef<Path>,
+) -> io::Result<()> {
+    fs::write(svg_file, sprite_sheet_svg(shapes, prefix)?)?;
+    fs::write(rust_file, sprite_sheet_rust(shapes, prefix))
+}
+
+/// Returns the SVG for a sprite sheet file.
+fn sprite_sheet_svg<S: IconSet>(shapes: &[S], prefix: &str) -> io::Result<String> {
+    let symbols = shapes
+        .iter()
+        .map(|shape| {
+            let path = shape.path_html().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("the {} shape has no path HTML", shape.name()),
+                )
+            })?;
+            Ok(format!(
+                r#"<symbol id="{}" viewBox="{}">{path}</symbol>"#,
+                symbol_id(prefix, *shape),
+                shape.view_box(),
+            ))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    Ok(format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\">\n{}\n</svg>\n",
+        symbols.join("\n"),
+    ))
+}
+
+/// Returns the Rust code with the constants for a sprite sheet's symbol `id`s.
+fn sprite_sheet_rust<S: IconSet>(shapes: &[S], prefix: &str) -> String {
+    let names = shapes
+        .iter()
+        .map(|shape| shape.name().replace('-', "_").to_uppercase())
+        .collect::<Vec<_>>();
+    let consts = shapes
+        .iter()
+        .zip(&names)
+        .map(|(shape, name)| {
+            format!(
+                "/// The `id` of the symbol for the \"{}\" shape.\n\
+                 pub const {name}: &str = \"{}\";\n",
+                shape.name(),
+                symbol_id(prefix, *shape),
+            )
+        })
+        .collect::<Vec<_>>()
+        .concat();
+    format!(
+        "// This file was written by dioxus_heroicons::write_sprite_sheet.\n\n{consts}\n\
+         /// The `id`s of all the symbols in the sprite sheet.\n\
+         pub const SYMBOL_IDS: &[&str] = &[{}];\n",
+        names.join(", "),
+    )
+}
+
+/// Returns the `id` of the symbol for a shape in an [`IconSprite`].
+fn symbol_id<S: IconSet>(prefix: &str, shape: S) -> String {
+    format!("{prefix}-{}", shape.name())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mini, outline, test::assert_rsx_eq, IconShape};
+
+    #[test]
+    fn icon_sprite() {
+        assert_rsx_eq(
+            rsx! {
+                IconSprite::<outline::Shape> {
+                    shapes: vec![outline::Shape::Document, outline::Shape::Folder],
+                    prefix: "outline",
+                },
+            },
+            rsx! {
+                svg {
+                    "aria-hidden": "true",
+                    style: SPRITE_STYLE,
+                    symbol {
+                        id: "outline-document",
+                        view_box: outline::VIEW_BOX,
+                        { outline::Shape::Document.path() },
+                    },
+                    symbol {
+                        id: "outline-folder",
+                        view_box: outline::VIEW_BOX,
+                        { outline::Shape::Folder.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn use_icon_external() {
+        assert_rsx_eq(
+            rsx! {
+                UseIcon {
+                    icon: outline::Shape::Folder,
+                    sprite_url: "/assets/icons.svg",
+                },
+            },
+            rsx! {
+                svg {
+                    height: 20,
+                    width: 20,
+                    view_box: outline::VIEW_BOX,
+                    fill: "currentColor",
+                    r#use {
+                        href: "/assets/icons.svg#heroicon-folder",
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn sprite_sheet() {
+        let shapes = [mini::Shape::Folder, mini::Shape::ArrowLeft];
+        let svg = sprite_sheet_svg(&shapes, "mini").unwrap();
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\">\n<symbol id=\"mini-folder\""));
+        assert!(svg.contains(&format!(
+            r#"<symbol id="mini-arrow-left" viewBox="0 0 20 20">{}</symbol>"#,
+            mini::Shape::ArrowLeft.path_html().unwrap(),
+        )));
+        assert_eq!(
+            sprite_sheet_rust(&shapes, "mini"),
+            "// This file was written by dioxus_heroicons::write_sprite_sheet.
+
+/// The `id` of the symbol for the \"folder\" shape.
+pub const FOLDER: &str = \"mini-folder\";
+/// The `id` of the symbol for the \"arrow-left\" shape.
+pub const ARROW_LEFT: &str = \"mini-arrow-left\";
+
+/// The `id`s of all the symbols in the sprite sheet.
+pub const SYMBOL_IDS: &[&str] = &[FOLDER, ARROW_LEFT];
+",
+        );
+    }
+
+    #[test]
+    fn use_icon() {
+        assert_rsx_eq(
+            rsx! {
+                UseIcon {
+                    icon: mini::Shape::Folder,
+                    class: "foo",
+                    size: 16,
+                },
+            },
+            rsx! {
+                svg {
+                    class: "foo",
+                    height: 16,
+                    width: 16,
+                    view_box: mini::VIEW_BOX,
+                    fill: "currentColor",
+                    r#use {
+                        href: "#heroicon-folder",
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::IconShape;
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`IconStack`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconStackProps {
+    /// An optional class for the outer `<svg>` element.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the outer `<svg>` element. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The layers of the stack. These should be [`IconLayer`] components. Later layers are drawn on
+    /// top of earlier ones.
+    pub children: Element,
+}
+
+/// Renders several icons layered on top of each other in the same box.
+///
+/// Each layer is an [`IconLayer`], which is rendered as a nested `<svg>`. The size and position of
+/// each layer are percentages of the stack's size, so a stack can be resized without changing its
+/// layers. For example, this renders a document with a small plus in its bottom right corner:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_heroicons::{mini, outline, IconLayer, IconStack};
+///
+/// fn NewDocument() -> Element {
+///     rsx! {
+///         IconStack {
+///             size: 24,
+///             IconLayer { icon: outline::Shape::Document },
+///             IconLayer { icon: mini::Shape::Plus, size: 50, x: 50, y: 50 },
+///         }
+///     }
+/// }
+/// ```
+///
+/// See the [`IconStackProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconStack(props: IconStackProps) -> Element {
+    rsx! {
+        svg {
+            class: if let Some(class) = props.class { class },
+            height: format_args!("{}", props.size),
+            width: format_args!("{}", props.size),
+            view_box: "0 0 100 100",
+            { props.children }
+        }
+    }
+}
+
+/// The properties for the [`IconLayer`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconLayerProps<S: IconShape + 'static> {
+    /// The icon shape to use.
+    pub icon: S,
+    /// The size of the layer as a percentage of the [`IconStack`]'s size. This defaults to 100.
+    #[props(default = 100)]
+    pub size: u32,
+    /// The offset of the layer from the left edge of the stack, as a percentage of the stack's
+    /// size. This defaults to 0.
+    #[props(default = 0)]
+    pub x: u32,
+    /// The offset of the layer from the top edge of the stack, as a percentage of the stack's size.
+    /// This defaults to 0.
+    #[props(default = 0)]
+    pub y: u32,
+    /// The fill color to use for this layer. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+    /// An optional class for this layer's `<svg>` element.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+}
+
+/// Renders one layer of an [`IconStack`].
+///
+/// This must be a child of an [`IconStack`].
+///
+/// See the [`IconLayerProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconLayer<S: IconShape>(props: IconLayerProps<S>) -> Element {
+    rsx! {
+        svg {
+            class: if let Some(class) = props.class { class },
+            x: format_args!("{}", props.x),
+            y: format_args!("{}", props.y),
+            height: format_args!("{}", props.size),
+            width: format_args!("{}", props.size),
+            view_box: format_args!("{}", props.icon.view_box()),
+            fill: "{props.fill}",
+            { props.icon.path() }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mini, outline, test::assert_rsx_eq};
+
+    #[test]
+    fn icon_stack() {
+        assert_rsx_eq(
+            rsx! {
+                IconStack {
+                    size: 24,
+                    IconLayer {
+                        icon: outline::Shape::Document,
+                    },
+                    IconLayer {
+                        icon: mini::Shape::Plus,
+                        size: 50,
+                        x: 50,
+                        y: 50,
+                        fill: "green",
+                    },
+                },
+            },
+            rsx! {
+                svg {
+                    height: 24,
+                    width: 24,
+                    view_box: "0 0 100 100",
+                    svg {
+                        x: 0,
+                        y: 0,
+                        height: 100,
+                        width: 100,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Document.path() },
+                    },
+                    svg {
+                        x: 50,
+                        y: 50,
+                        height: 50,
+                        width: 50,
+                        view_box: mini::VIEW_BOX,
+                        fill: "green",
+                        { mini::Shape::Plus.path() },
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{Icon, IconShape};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`IconText`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconTextProps<S: IconShape + 'static> {
+    /// The icon shape to use.
+    pub icon: S,
+    /// An optional class for the outer `<span>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color to use for the icon. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+    /// The gap between the icon and the label, in pixels. This defaults to 8.
+    #[props(default = 8)]
+    pub gap: u32,
+    /// An optional class that will be passed to the [`Icon`].
+    #[props(default, strip_option)]
+    pub icon_class: Option<String>,
+    /// An optional class for the `<span>` that contains the label.
+    #[props(default, strip_option)]
+    pub span_class: Option<String>,
+    /// The label, which can be plain text or any other elements.
+    pub children: Element,
+}
+
+/// Renders an icon next to a label.
+///
+/// The icon and label are vertically centered and separated by `gap` pixels. This is handy in
+/// menus, stats, and list rows. This component will generate HTML like this:
+///
+/// ```html
+/// <span style="display: inline-flex; align-items: center; gap: 8px;">
+///   <svg ...>
+///   <span>
+///     Child elements go here
+///   </span>
+/// </span>
+/// ```
+///
+/// See the [`IconTextProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconText<S: IconShape>(props: IconTextProps<S>) -> Element {
+    rsx! {
+        span {
+            class: if let Some(class) = props.class { class },
+            style: "display: inline-flex; align-items: center; gap: {props.gap}px;",
+            Icon {
+                class: props.icon_class,
+                size: props.size,
+                fill: props.fill,
+                icon: props.icon.clone(),
+            },
+            span {
+                class: if let Some(span_class) = props.span_class { span_class },
+                { props.children }
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{outline, test::assert_rsx_eq};
+
+    #[test]
+    fn icon_text_default() {
+        assert_rsx_eq(
+            rsx! {
+                IconText {
+                    icon: outline::Shape::Users,
+                    "42 members"
+                },
+            },
+            rsx! {
+                span {
+                    style: "display: inline-flex; align-items: center; gap: 8px;",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Users.path() },
+                    },
+                    span {
+                        "42 members"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_text_with_props() {
+        assert_rsx_eq(
+            rsx! {
+                IconText {
+                    icon: outline::Shape::Users,
+                    class: "stat",
+                    size: 16,
+                    gap: 4,
+                    span_class: "label",
+                    "42 members"
+                },
+            },
+            rsx! {
+                span {
+                    class: "stat",
+                    style: "display: inline-flex; align-items: center; gap: 4px;",
+                    svg {
+                        height: 16,
+                        width: 16,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Users.path() },
+                    },
+                    span {
+                        class: "label",
+                        "42 members"
+                    },
+                },
+            },
+        );
+    }
+}
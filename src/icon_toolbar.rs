@@ -0,0 +1,255 @@
+use crate::{IconButton, IconShape};
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// One button in an [`IconToolbar`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToolbarItem<S: IconShape> {
+    /// The icon shape for the button.
+    pub icon: S,
+    /// The accessible label for the button. This is also used as the button's title.
+    pub label: String,
+    /// If this is true then the button is disabled and is skipped by arrow-key navigation.
+    pub disabled: bool,
+}
+
+impl<S: IconShape> ToolbarItem<S> {
+    /// Creates a new enabled item.
+    pub fn new(icon: S, label: impl Into<String>) -> Self {
+        Self {
+            icon,
+            label: label.into(),
+            disabled: false,
+        }
+    }
+}
+
+/// The properties for the [`IconToolbar`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconToolbarProps<S: IconShape + 'static> {
+    /// The buttons in the toolbar.
+    pub items: Vec<ToolbarItem<S>>,
+    /// The accessible label for the toolbar as a whole.
+    pub label: String,
+    /// If this is true then the toolbar is a radio group, where exactly one button is checked at a
+    /// time. This defaults to false.
+    #[props(default = false)]
+    pub radio: bool,
+    /// The index of the checked button when `radio` is true.
+    #[props(default, strip_option)]
+    pub selected: Option<usize>,
+    /// An optional handler that is called with the index of a button when it's clicked. When
+    /// `radio` is true, this is also called when the arrow keys move to a new button.
+    #[props(default, strip_option)]
+    pub onselect: Option<EventHandler<usize>>,
+    /// An optional class for the toolbar's `<div>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for each button in the toolbar.
+    #[props(default, strip_option)]
+    pub button_class: Option<String>,
+    /// The size of the icons. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+}
+
+/// Renders a row of [`IconButton`]s, optionally as a radio group.
+///
+/// The toolbar uses a roving `tabindex`, so it's a single tab stop, and the arrow keys move between
+/// its buttons. `Home` and `End` move to the first and last buttons. In radio mode, the buttons have
+/// `role="radio"` and `aria-checked`, and moving to a button with the arrow keys also selects it.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <div role="toolbar" aria-label="...">
+///   <button title="..." tabindex="0">
+///     <svg ...>
+///     <span style="...">Label goes here</span>
+///   </button>
+///   ...
+/// </div>
+/// ```
+///
+/// See the [`IconToolbarProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconToolbar<S: IconShape>(props: IconToolbarProps<S>) -> Element {
+    let mut focused = use_signal(|| props.selected.unwrap_or(0));
+    let mut buttons = use_signal(Vec::<Option<Rc<MountedData>>>::new);
+    let radio = props.radio;
+    let onselect = props.onselect;
+    let enabled = props.items.iter().map(|i| !i.disabled).collect::<Vec<_>>();
+    // If the focused button was removed or disabled, fall back to the first enabled one.
+    let current = if enabled.get(focused()).copied().unwrap_or(false) {
+        focused()
+    } else {
+        enabled.iter().position(|e| *e).unwrap_or(0)
+    };
+
+    let onkeydown = move |evt: KeyboardEvent| {
+        let Some(next) = next_index(&enabled, current, &evt.key()) else {
+            return;
+        };
+        evt.prevent_default();
+        focused.set(next);
+        if let Some(Some(button)) = buttons.peek().get(next).cloned() {
+            spawn(async move {
+                let _ = button.set_focus(true).await;
+            });
+        }
+        if radio {
+            if let Some(os) = onselect {
+                os.call(next);
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            role: if radio { "radiogroup" } else { "toolbar" },
+            aria_label: props.label,
+            class: if let Some(class) = props.class { class },
+            onkeydown,
+            for (i, item) in props.items.into_iter().enumerate() {
+                IconButton {
+                    key: "{i}",
+                    onclick: move |_| {
+                        focused.set(i);
+                        if let Some(os) = onselect {
+                            os.call(i);
+                        }
+                    },
+                    onmounted: move |evt: MountedEvent| {
+                        let mut buttons = buttons.write();
+                        if buttons.len() <= i {
+                            buttons.resize(i + 1, None);
+                        }
+                        buttons[i] = Some(evt.data());
+                    },
+                    class: props.button_class.clone(),
+                    title: item.label.clone(),
+                    size: props.size,
+                    disabled: item.disabled,
+                    icon: item.icon,
+                    sr_only: item.label,
+                    tabindex: if i == current { "0" } else { "-1" },
+                    role: if radio { Some("radio") } else { None },
+                    aria_checked: if radio { Some(props.selected == Some(i)) } else { None },
+                }
+            }
+        }
+    }
+}
+
+/// Returns the index of the enabled item that a key press moves to, or `None` if the key doesn't
+/// move anywhere.
+fn next_index(enabled: &[bool], current: usize, key: &Key) -> Option<usize> {
+    let len = enabled.len();
+    if len == 0 || !enabled.iter().any(|e| *e) {
+        return None;
+    }
+    let step = |from: usize, forward: bool| {
+        let mut i = from;
+        loop {
+            i = if forward {
+                (i + 1) % len
+            } else {
+                (i + len - 1) % len
+            };
+            if enabled[i] {
+                return i;
+            }
+        }
+    };
+    match key {
+        Key::ArrowRight | Key::ArrowDown => Some(step(current, true)),
+        Key::ArrowLeft | Key::ArrowUp => Some(step(current, false)),
+        Key::Home => Some(step(len - 1, true)),
+        Key::End => Some(step(0, false)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        outline,
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn icon_toolbar_radio() {
+        let items = vec![
+            ToolbarItem::new(outline::Shape::Bars3BottomLeft, "Align left"),
+            ToolbarItem::new(outline::Shape::Bars3, "Align center"),
+        ];
+        assert_rsx_eq(
+            rsx! {
+                IconToolbar {
+                    items,
+                    label: "Alignment",
+                    radio: true,
+                    selected: 1,
+                },
+            },
+            rsx! {
+                div {
+                    role: "radiogroup",
+                    aria_label: "Alignment",
+                    button {
+                        class: FOCUS_CLASS,
+                        title: "Align left",
+                        tabindex: "-1",
+                        role: "radio",
+                        aria_checked: "false",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: outline::VIEW_BOX,
+                            fill: "currentColor",
+                            { outline::Shape::Bars3BottomLeft.path() },
+                        },
+                        span {
+                            style: SR_ONLY_STYLE,
+                            "Align left"
+                        },
+                    },
+                    button {
+                        class: FOCUS_CLASS,
+                        title: "Align center",
+                        tabindex: "0",
+                        role: "radio",
+                        aria_checked: "true",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: outline::VIEW_BOX,
+                            fill: "currentColor",
+                            { outline::Shape::Bars3.path() },
+                        },
+                        span {
+                            style: SR_ONLY_STYLE,
+                            "Align center"
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn next_index_skips_disabled() {
+        let enabled = [true, false, true, true];
+        assert_eq!(next_index(&enabled, 0, &Key::ArrowRight), Some(2));
+        assert_eq!(next_index(&enabled, 2, &Key::ArrowLeft), Some(0));
+        assert_eq!(next_index(&enabled, 0, &Key::ArrowLeft), Some(3));
+        assert_eq!(next_index(&enabled, 3, &Key::ArrowDown), Some(0));
+        assert_eq!(next_index(&enabled, 2, &Key::Home), Some(0));
+        assert_eq!(next_index(&enabled, 0, &Key::End), Some(3));
+        assert_eq!(next_index(&enabled, 0, &Key::Enter), None);
+        assert_eq!(next_index(&[false, false], 0, &Key::ArrowRight), None);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{Icon, IconShape};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// How an [`IconTransition`] animates between its icons.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransitionEffect {
+    /// The icons fade in and out.
+    Fade,
+    /// The icons fade in and out while growing and shrinking.
+    #[default]
+    Scale,
+    /// The icons fade in and out while rotating a quarter turn.
+    Rotate,
+}
+
+impl TransitionEffect {
+    /// Returns the CSS `transform` for an icon that is shown or hidden.
+    fn transform(self, shown: bool) -> &'static str {
+        match (self, shown) {
+            (TransitionEffect::Fade, _) => "none",
+            (TransitionEffect::Scale, true) => "scale(1)",
+            (TransitionEffect::Scale, false) => "scale(0.5)",
+            (TransitionEffect::Rotate, true) => "rotate(0deg)",
+            (TransitionEffect::Rotate, false) => "rotate(90deg)",
+        }
+    }
+}
+
+/// The properties for the [`IconTransition`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconTransitionProps<S: IconShape + 'static> {
+    /// The icon shape that is shown when `active` is true.
+    pub icon_on: S,
+    /// The icon shape that is shown when `active` is false.
+    pub icon_off: S,
+    /// Which icon is shown. Changing this animates to the other icon.
+    pub active: bool,
+    /// How the icons are animated. This defaults to [`TransitionEffect::Scale`].
+    #[props(default)]
+    pub effect: TransitionEffect,
+    /// How long the animation takes, in milliseconds. Set this to 0 to turn the animation off.
+    /// This defaults to 200.
+    #[props(default = 200)]
+    pub transition_ms: u32,
+    /// An optional class for the outer `<span>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icons. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color to use for the icons. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+}
+
+/// Renders two icons in the same place and animates between them when `active` changes, for
+/// swaps like play and pause, or a hamburger and a close button.
+///
+/// Both icons are always rendered, layered on top of each other, and the hidden one is faded out
+/// with the `effect`'s transform applied. The animation is a CSS transition, so it doesn't need
+/// any CSS classes.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span style="position: relative; ...">
+///   <span style="position: absolute; ... opacity: 1; ...">
+///     <svg ...>
+///   </span>
+///   <span style="position: absolute; ... opacity: 0; ...">
+///     <svg ...>
+///   </span>
+/// </span>
+/// ```
+///
+/// See the [`IconTransitionProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconTransition<S: IconShape>(props: IconTransitionProps<S>) -> Element {
+    let size = props.size;
+    let transition_ms = props.transition_ms;
+    let active = props.active;
+    let effect = props.effect;
+    let layer_style = |shown: bool| {
+        format!(
+            "position: absolute; top: 0; left: 0; transition: opacity {ms}ms, transform {ms}ms; \
+             opacity: {opacity}; transform: {transform};",
+            ms = transition_ms,
+            opacity = u8::from(shown),
+            transform = effect.transform(shown),
+        )
+    };
+    rsx! {
+        span {
+            class: if let Some(class) = props.class { class },
+            style: "position: relative; display: inline-block; width: {size}px; height: {size}px;",
+            span {
+                style: layer_style(!active),
+                Icon {
+                    size,
+                    fill: props.fill.clone(),
+                    icon: props.icon_off,
+                },
+            },
+            span {
+                style: layer_style(active),
+                Icon {
+                    size,
+                    fill: props.fill,
+                    icon: props.icon_on,
+                },
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{solid, test::assert_rsx_eq};
+
+    #[test]
+    fn icon_transition_scale() {
+        assert_rsx_eq(
+            rsx! {
+                IconTransition {
+                    icon_on: solid::Shape::Pause,
+                    icon_off: solid::Shape::Play,
+                    active: true,
+                    transition_ms: 100,
+                },
+            },
+            rsx! {
+                span {
+                    style: "position: relative; display: inline-block; width: 20px; height: 20px;",
+                    span {
+                        style: "position: absolute; top: 0; left: 0; transition: opacity 100ms, transform 100ms; opacity: 0; transform: scale(0.5);",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: solid::VIEW_BOX,
+                            fill: "currentColor",
+                            { solid::Shape::Play.path() },
+                        },
+                    },
+                    span {
+                        style: "position: absolute; top: 0; left: 0; transition: opacity 100ms, transform 100ms; opacity: 1; transform: scale(1);",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: solid::VIEW_BOX,
+                            fill: "currentColor",
+                            { solid::Shape::Pause.path() },
+                        },
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{
+    theme::{join_classes, use_icon_theme},
+    Icon, IconShape,
+};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// Where a badge is placed relative to the icon it's attached to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BadgePlacement {
+    /// The top right corner.
+    #[default]
+    TopRight,
+    /// The top left corner.
+    TopLeft,
+    /// The bottom right corner.
+    BottomRight,
+    /// The bottom left corner.
+    BottomLeft,
+}
+
+impl BadgePlacement {
+    /// Returns the inline style that positions a badge at this corner. The badge's container must
+    /// be positioned, e.g. with `position: relative`.
+    pub(crate) fn style(self) -> &'static str {
+        match self {
+            BadgePlacement::TopRight => {
+                "position: absolute; top: 0; right: 0; transform: translate(50%, -50%);"
+            }
+            BadgePlacement::TopLeft => {
+                "position: absolute; top: 0; left: 0; transform: translate(-50%, -50%);"
+            }
+            BadgePlacement::BottomRight => {
+                "position: absolute; bottom: 0; right: 0; transform: translate(50%, 50%);"
+            }
+            BadgePlacement::BottomLeft => {
+                "position: absolute; bottom: 0; left: 0; transform: translate(-50%, 50%);"
+            }
+        }
+    }
+}
+
+/// The semantic color of a badge.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BadgeColor {
+    /// Something that needs attention, like unread messages. This is red.
+    #[default]
+    Danger,
+    /// Something neutral but highlighted. This is blue.
+    Primary,
+    /// Something that worked. This is green.
+    Success,
+    /// Something that may need attention. This is yellow.
+    Warning,
+    /// Something unimportant. This is gray.
+    Neutral,
+}
+
+impl BadgeColor {
+    /// Returns the background color for this badge color, as a hex color.
+    #[must_use]
+    pub fn hex(self) -> &'static str {
+        match self {
+            // red 600
+            BadgeColor::Danger => "#DC2626",
+            // blue 600
+            BadgeColor::Primary => "#2563EB",
+            // green 600
+            BadgeColor::Success => "#16A34A",
+            // yellow 500
+            BadgeColor::Warning => "#EAB308",
+            // gray 500
+            BadgeColor::Neutral => "#6B7280",
+        }
+    }
+}
+
+/// Returns the text for a badge count. If the count is more than `max` then this is `max` followed
+/// by a "+", like "99+".
+fn format_count(count: u32, max: Option<u32>) -> String {
+    match max {
+        Some(max) if count > max => format!("{max}+"),
+        _ => count.to_string(),
+    }
+}
+
+/// The inline style for the element that a badge is positioned relative to.
+pub(crate) const BADGE_CONTAINER_STYLE: &str = "position: relative; display: inline-block;";
+
+/// Returns the inline style for a small round dot at the given corner, for the `dot` on an
+/// [`Icon`](crate::Icon).
+pub(crate) fn dot_style(placement: BadgePlacement, color: &str) -> String {
+    format!(
+        "{} width: 8px; height: 8px; border-radius: 9999px; background-color: {color};",
+        placement.style(),
+    )
+}
+
+/// The properties for the [`IconWithBadge`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconWithBadgeProps<S: IconShape + 'static> {
+    /// The icon shape to use.
+    pub icon: S,
+    /// The count to show in the badge. If this is not set then the badge is a dot.
+    #[props(default, strip_option)]
+    pub count: Option<u32>,
+    /// If this is true then a badge is shown when `count` is 0. Otherwise the badge is hidden. This
+    /// defaults to false.
+    #[props(default = false)]
+    pub show_zero: bool,
+    /// The largest count to show. If `count` is more than this then the badge shows this followed
+    /// by a "+", like "99+". If this is not set then the count is always shown as is.
+    #[props(default, strip_option)]
+    pub max: Option<u32>,
+    /// The color of the badge. If this is set then it's used as the badge's background color,
+    /// overriding any background color from the classes. Otherwise the color comes from the
+    /// `badge_class` in the [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub color: Option<BadgeColor>,
+    /// If this is true then the badge pulses to draw attention to it, using the
+    /// `badge_pulse_class` from the [`IconTheme`](crate::IconTheme). This defaults to false.
+    #[props(default = false)]
+    pub pulse: bool,
+    /// Where the badge is placed. This defaults to [`BadgePlacement::TopRight`].
+    #[props(default)]
+    pub placement: BadgePlacement,
+    /// An optional class for the outer `<span>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for the badge. This is added after the `badge_class` from the
+    /// [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub badge_class: Option<String>,
+    /// An optional accessible label for the badge, like "3 unread messages".
+    #[props(default, strip_option)]
+    pub label: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color to use for the icon. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+    /// An optional class that will be passed to the [`Icon`].
+    #[props(default, strip_option)]
+    pub icon_class: Option<String>,
+}
+
+/// Renders an icon with a badge, either a count or a dot, over one of its corners.
+///
+/// Set `max` to cap the count, so a count of 120 with a `max` of 99 is shown as "99+". The badge
+/// can be given a semantic [`BadgeColor`], and a dot badge for unread items can `pulse`.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span style="position: relative; display: inline-block;">
+///   <svg ...>
+///   <span class="..." style="position: absolute; ...">3</span>
+/// </span>
+/// ```
+///
+/// See the [`IconWithBadgeProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconWithBadge<S: IconShape>(props: IconWithBadgeProps<S>) -> Element {
+    let theme = use_icon_theme();
+    let badge_class = join_classes([
+        Some(theme.badge_class.as_str()),
+        props.pulse.then_some(theme.badge_pulse_class.as_str()),
+        props.badge_class.as_deref(),
+    ]);
+    let show_badge = props.show_zero || props.count != Some(0);
+    let size_style = if props.count.is_some() {
+        ""
+    } else {
+        " width: 8px; height: 8px;"
+    };
+    let color_style = props
+        .color
+        .map(|color| format!(" background-color: {};", color.hex()))
+        .unwrap_or_default();
+    let badge_style = format!("{}{size_style}{color_style}", props.placement.style());
+    let text = props.count.map(|count| format_count(count, props.max));
+    rsx! {
+        span {
+            class: if let Some(class) = props.class { class },
+            style: BADGE_CONTAINER_STYLE,
+            Icon {
+                class: props.icon_class,
+                size: props.size,
+                fill: props.fill,
+                icon: props.icon.clone(),
+            },
+            if show_badge {
+                span {
+                    class: if let Some(badge_class) = badge_class { badge_class },
+                    style: badge_style,
+                    aria_label: if let Some(label) = props.label { label },
+                    if let Some(text) = text {
+                        "{text}"
+                    }
+                },
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{outline, test::assert_rsx_eq};
+
+    const BADGE_CLASS: &str = "rounded-full bg-red-600 px-1 text-xs leading-4 text-white";
+
+    #[test]
+    fn icon_with_badge_count() {
+        assert_rsx_eq(
+            rsx! {
+                IconWithBadge {
+                    icon: outline::Shape::Bell,
+                    count: 3,
+                },
+            },
+            rsx! {
+                span {
+                    style: BADGE_CONTAINER_STYLE,
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Bell.path() },
+                    },
+                    span {
+                        class: BADGE_CLASS,
+                        style: BadgePlacement::TopRight.style(),
+                        "3"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_with_badge_dot() {
+        assert_rsx_eq(
+            rsx! {
+                IconWithBadge {
+                    icon: outline::Shape::Bell,
+                    placement: BadgePlacement::BottomLeft,
+                    label: "New notifications",
+                },
+            },
+            rsx! {
+                span {
+                    style: BADGE_CONTAINER_STYLE,
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Bell.path() },
+                    },
+                    span {
+                        class: BADGE_CLASS,
+                        style: "{BadgePlacement::BottomLeft.style()} width: 8px; height: 8px;",
+                        aria_label: "New notifications",
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_with_badge_max_color_pulse() {
+        assert_rsx_eq(
+            rsx! {
+                IconWithBadge {
+                    icon: outline::Shape::Bell,
+                    count: 120,
+                    max: 99,
+                    color: BadgeColor::Primary,
+                    pulse: true,
+                },
+            },
+            rsx! {
+                span {
+                    style: BADGE_CONTAINER_STYLE,
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Bell.path() },
+                    },
+                    span {
+                        class: "{BADGE_CLASS} animate-pulse",
+                        style: "{BadgePlacement::TopRight.style()} background-color: #2563EB;",
+                        "99+"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn count_formatting() {
+        assert_eq!(format_count(5, None), "5");
+        assert_eq!(format_count(99, Some(99)), "99");
+        assert_eq!(format_count(100, Some(99)), "99+");
+        assert_eq!(format_count(1000, None), "1000");
+    }
+
+    #[test]
+    fn icon_with_badge_zero() {
+        assert_rsx_eq(
+            rsx! {
+                IconWithBadge {
+                    icon: outline::Shape::Bell,
+                    count: 0,
+                },
+            },
+            rsx! {
+                span {
+                    style: BADGE_CONTAINER_STYLE,
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Bell.path() },
+                    },
+                },
+            },
+        );
+    }
+}
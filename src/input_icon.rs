@@ -0,0 +1,206 @@
+use crate::{mini, Icon, IconButton, IconShape};
+use dioxus::prelude::*;
+
+/// The gap between the edge of the input and an icon, in pixels.
+const INSET: u32 = 8;
+
+/// The properties for the [`InputIcon`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct InputIconProps<S: IconShape + 'static> {
+    /// The icon shape to show at the start of the input, like [`mini::Shape::MagnifyingGlass`].
+    pub icon: S,
+    /// An optional handler for the clear button. If this is set then there is a
+    /// [`mini::Shape::XMark`] button at the end of the input while `show_clear` is true.
+    #[props(default, strip_option)]
+    pub onclear: Option<EventHandler<MouseEvent>>,
+    /// If this is false then the clear button is hidden, for example while the input is empty.
+    /// This defaults to true.
+    #[props(default = true)]
+    pub show_clear: bool,
+    /// The accessible label for the clear button. This defaults to "Clear".
+    #[props(default = "Clear".to_string())]
+    pub clear_label: String,
+    /// An optional class for the outer `<span>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for the leading icon.
+    #[props(default, strip_option)]
+    pub icon_class: Option<String>,
+    /// An optional class for the clear button.
+    #[props(default, strip_option)]
+    pub clear_class: Option<String>,
+    /// The size of the icons. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The input to put the icons in.
+    pub children: Element,
+}
+
+/// Renders icons inside a text input, with a leading icon and an optional trailing clear button.
+///
+/// The icons are positioned absolutely over the input, so the input needs padding to keep its
+/// text clear of them. Use [`input_icon_padding`] to get that padding as an inline style. The
+/// leading icon doesn't catch clicks, so clicking it focuses the input. For example:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_heroicons::{input_icon_padding, mini, InputIcon};
+///
+/// fn Search() -> Element {
+///     let mut query = use_signal(String::new);
+///     rsx! {
+///         InputIcon {
+///             icon: mini::Shape::MagnifyingGlass,
+///             onclear: move |_| query.set(String::new()),
+///             show_clear: !query.read().is_empty(),
+///             input {
+///                 r#type: "search",
+///                 style: input_icon_padding(20, true, true),
+///                 value: query(),
+///                 oninput: move |evt| query.set(evt.value()),
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span style="position: relative; display: inline-block;">
+///   <span aria-hidden="true" style="...">
+///     <svg ...>
+///   </span>
+///   <input ...>
+///   <button title="Clear" type="button" style="...">...</button>
+/// </span>
+/// ```
+///
+/// See the [`InputIconProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn InputIcon<S: IconShape>(props: InputIconProps<S>) -> Element {
+    let size = props.size;
+    let onclear = props.onclear.filter(|_| props.show_clear);
+    rsx! {
+        span {
+            class: if let Some(class) = props.class { class },
+            style: "position: relative; display: inline-block;",
+            span {
+                aria_hidden: true,
+                style: "position: absolute; top: 50%; left: {INSET}px; transform: translateY(-50%); display: inline-flex; pointer-events: none;",
+                Icon {
+                    class: props.icon_class,
+                    size,
+                    icon: props.icon,
+                },
+            },
+            { props.children },
+            if let Some(onclear) = onclear {
+                IconButton {
+                    onclick: move |evt| onclear.call(evt),
+                    class: props.clear_class,
+                    title: props.clear_label.clone(),
+                    size,
+                    icon: mini::Shape::XMark,
+                    sr_only: props.clear_label,
+                    r#type: "button",
+                    style: "position: absolute; top: 50%; right: {INSET}px; transform: translateY(-50%); display: inline-flex;",
+                },
+            }
+        }
+    }
+}
+
+/// Returns an inline style with the padding an input needs to keep its text clear of the icons in
+/// an [`InputIcon`]. Pass the same `size` as the [`InputIcon`], and whether it has a leading icon
+/// and a clear button.
+#[must_use]
+pub fn input_icon_padding(size: u32, leading: bool, trailing: bool) -> String {
+    let padding = size + INSET * 2;
+    let mut style = Vec::new();
+    if leading {
+        style.push(format!("padding-left: {padding}px;"));
+    }
+    if trailing {
+        style.push(format!("padding-right: {padding}px;"));
+    }
+    style.join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn input_icon_with_clear() {
+        #[allow(non_snake_case)]
+        fn Search() -> Element {
+            rsx! {
+                InputIcon {
+                    icon: mini::Shape::MagnifyingGlass,
+                    onclear: |_| {},
+                    input {
+                        r#type: "search",
+                    },
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Search {},
+            },
+            rsx! {
+                span {
+                    style: "position: relative; display: inline-block;",
+                    span {
+                        aria_hidden: true,
+                        style: "position: absolute; top: 50%; left: 8px; transform: translateY(-50%); display: inline-flex; pointer-events: none;",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::MagnifyingGlass.path() },
+                        },
+                    },
+                    input {
+                        r#type: "search",
+                    },
+                    button {
+                        class: FOCUS_CLASS,
+                        title: "Clear",
+                        r#type: "button",
+                        style: "position: absolute; top: 50%; right: 8px; transform: translateY(-50%); display: inline-flex;",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::XMark.path() },
+                        },
+                        span {
+                            style: SR_ONLY_STYLE,
+                            "Clear"
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn padding() {
+        assert_eq!(
+            input_icon_padding(20, true, true),
+            "padding-left: 36px; padding-right: 36px;",
+        );
+        assert_eq!(input_icon_padding(16, true, false), "padding-left: 32px;");
+        assert_eq!(input_icon_padding(16, false, false), "");
+    }
+}
@@ -1,7 +1,56 @@
 //! Dioxus components for [heroicons](https://heroicons.com/)
 //!
-//! This library provides two components. The [`Icon`] component produces the SVG for a heroicon. The
-//! [`IconButton`] component wraps the icon with an HTML `button`.
+//! This library provides these components:
+//!
+//! - [`Icon`] produces the SVG for a heroicon.
+//! - [`Avatar`] shows a user's image, falling back to a user icon when there is no image.
+//! - [`BookmarkToggle`] is a bookmark toggle that announces its changes to screen readers.
+//! - [`Breadcrumbs`] is a breadcrumb trail with chevron or slash separators.
+//! - [`Callout`] is an alert box with a [`StatusIcon`], a title, a body, and an optional dismiss
+//!   button.
+//! - [`Chip`] is a pill for tags and filters, with an optional leading icon and dismiss button.
+//! - [`CopyButton`] copies some text to the clipboard and briefly shows a check.
+//! - [`DisclosureChevron`] is a chevron that rotates when a section opens, for accordions and trees.
+//! - [`DragHandle`] is a handle for dragging items in sortable lists.
+//! - [`DropdownIconButton`] is an [`IconButton`] that opens and closes a popup panel.
+//! - [`EmptyState`] is a placeholder for empty lists, with a large muted icon, a heading, a
+//!   description, and an optional action.
+//! - [`Fab`] is a floating action button, with an optional extended mode that shows its label.
+//! - [`FavoriteToggle`] is a heart toggle with support for optimistic updates.
+//! - [`FileTypeIcon`] shows a document icon for a file's extension or MIME type.
+//! - [`FullscreenToggle`] switches between full screen and normal modes, optionally with the
+//!   browser's Fullscreen API.
+//! - [`IconButton`] wraps the icon with an HTML `button`.
+//! - [`IconCheckbox`] is a checkbox drawn with heroicons, backed by a native checkbox.
+//! - [`IconLink`] wraps the icon with an HTML `a`, for actions that navigate somewhere.
+//! - [`IconPicker`] is a searchable grid of all the shapes in an [`IconSet`].
+//! - [`IconSprite`] renders a hidden SVG sprite of symbols, which [`UseIcon`] refers to, so
+//!   repeated icons only send their path data once.
+//! - [`IconStack`] layers several icons on top of each other, using [`IconLayer`] children.
+//! - [`IconText`] lays out an icon next to a text label.
+//! - [`IconToolbar`] is a row of [`IconButton`]s with arrow-key navigation and an optional radio
+//!   mode.
+//! - [`IconTransition`] animates between two icons, for swaps like play and pause.
+//! - [`IconWithBadge`] shows an icon with a count or dot badge over one of its corners.
+//! - [`InputIcon`] puts a leading icon and an optional clear button inside a text input.
+//! - [`MenuToggle`] is a hamburger button that turns into a close button when its menu is open.
+//! - [`PaginationNav`] is a row of buttons for moving between pages of results.
+//! - [`PasswordVisibilityToggle`] is an eye toggle for showing and hiding a password.
+//! - [`PlayPauseButton`] is a play/pause button for a media player, with an optional stop button.
+//! - [`ProgressRing`] is a circular progress indicator with an optional icon in its center.
+//! - [`Rating`] is a star rating, which can be read-only or changed with the mouse and keyboard.
+//! - [`SplitButton`] is a primary action button with an attached chevron button that opens a panel
+//!   of secondary actions.
+//! - [`SortIndicator`] shows the chevron for a table column's [`SortDirection`].
+//! - [`Spinner`] is a spinning icon that shows that something is loading.
+//! - [`StatusIcon`] shows the conventional icon for a [`Status`], like success or error.
+//! - [`Stepper`] is a numeric stepper with minus and plus buttons that repeat while they're held.
+//! - [`ThemeToggle`] switches between a light and dark [`ColorScheme`] with a sun and moon icon.
+//! - [`ToastIcon`] shows the icon for a toast or other notification's [`ToastLevel`].
+//! - [`ToggleIconButton`] is an [`IconButton`] that switches between two icons when it's pressed.
+//! - [`Tooltip`] shows a tooltip while an element is hovered or focused.
+//! - [`UseIcon`] renders an icon from an [`IconSprite`].
+//! - [`VolumeButton`] is a mute button that shows the right speaker icon for a volume level.
 //!
 //! In your own components, you can call them like this:
 //!
@@ -43,44 +92,599 @@
 //!
 //! Check out <https://jkelleyrtp.github.io/icon-chooser/> for an icon chooser that shows you all the
 //! solid icons and lets you copy the relevant component code to the clipboard.
+//!
+//! Each style has a cargo feature, `mini`, `outline`, and `solid`, and each icon has a feature
+//! named after its heroicons name, like `arrow-left`. The shapes for each style are in their own
+//! crate, like `dioxus-heroicons-outline`, which its module re-exports, so a style whose feature is
+//! off isn't even compiled. A shape is compiled when both its style's feature and its icon's
+//! feature are enabled. The default features enable all three styles, and
+//! the `all-icons` feature enables every icon, so to only compile the icons you use, turn off the
+//! default features and list the styles and icons instead, along with `components` for the Dioxus
+//! components:
+//!
+//! ```toml
+//! [dependencies.dioxus-heroicons]
+//! version = "0.5"
+//! default-features = false
+//! features = ["components", "outline", "arrow-left", "trash"]
+//! ```
+//!
+//! Listing a lot of icons as features gets unwieldy, so they can also be listed in an `icons.toml`
+//! file, with one list for all styles and one for each style. Point the `DIOXUS_HEROICONS_ICONS`
+//! environment variable at it, for example in the app's `.cargo/config.toml`, and each listed icon
+//! is compiled as if its feature were on:
+//!
+//! ```toml
+//! # icons.toml
+//! icons = ["arrow-left", "trash"]
+//! outline = ["bars-3"]
+//!
+//! # .cargo/config.toml
+//! [env]
+//! DIOXUS_HEROICONS_ICONS = { value = "icons.toml", relative = true }
+//! ```
+//!
+//! The shapes that this crate's own components use are always enabled when the `components`
+//! feature is on. With it off, this crate doesn't depend on Dioxus at all, and just provides the
+//! `Shape` enums along with each shape's name, view box, and [`PathData`], for server-side tools,
+//! other frameworks, or code generators.
+//!
+//! The [`meta`] module says which version of heroicons the shapes were generated from and how many
+//! icons each style has.
+//!
+//! The `devtools` feature adds the [`devtools`] module, which lists every shape that's compiled in,
+//! with its style, name, and path data, so tools can show which icons are available. It's meant for
+//! debug builds, since it keeps every enabled shape in the binary.
+//!
+//! The `free-icons` feature adds the [`free_icons`] module, with wrappers that let heroicons shapes
+//! be drawn by [`dioxus-free-icons`](https://docs.rs/dioxus-free-icons) components and its shapes be
+//! drawn by this crate's components, for apps that use both crates.
+//!
+//! Apps that show a lot of different icons can keep them out of their wasm bundle entirely with
+//! the `remote-icons` feature. This adds a `write_icon_registry` function for writing the icons'
+//! path data to a JSON file at build time, an `IconRegistryProvider` component that fetches that
+//! file at runtime, and a `RemoteIcon` component that shows a placeholder until it arrives.
+
+#[cfg(feature = "components")]
+mod avatar;
+#[cfg(feature = "components")]
+mod bookmark_toggle;
+#[cfg(feature = "components")]
+mod breadcrumbs;
+#[cfg(feature = "components")]
+mod callout;
+#[cfg(feature = "components")]
+mod chip;
+#[cfg(feature = "components")]
+mod copy_button;
+/// A registry of every shape compiled into the app, with its style, name, and path data, for
+/// devtools, hot-reload tooling, and icon previews. This needs the `devtools` feature.
+#[cfg(feature = "devtools")]
+pub mod devtools;
+#[cfg(feature = "components")]
+mod disclosure_chevron;
+#[cfg(feature = "components")]
+mod drag_handle;
+#[cfg(feature = "components")]
+mod dropdown_icon_button;
+#[cfg(feature = "components")]
+mod empty_state;
+#[cfg(feature = "components")]
+mod fab;
+#[cfg(feature = "components")]
+mod favorite_toggle;
+#[cfg(feature = "components")]
+mod file_type_icon;
+#[cfg(feature = "free-icons")]
+pub mod free_icons;
+#[cfg(feature = "components")]
+mod fullscreen_toggle;
+#[cfg(feature = "components")]
+mod icon_checkbox;
+#[cfg(feature = "components")]
+mod icon_data;
+#[cfg(feature = "components")]
+mod icon_link;
+#[cfg(feature = "components")]
+mod icon_picker;
+#[cfg(feature = "components")]
+mod icon_sprite;
+#[cfg(feature = "components")]
+mod icon_stack;
+#[cfg(feature = "components")]
+mod icon_text;
+#[cfg(feature = "components")]
+mod icon_toolbar;
+#[cfg(feature = "components")]
+mod icon_transition;
+#[cfg(feature = "components")]
+mod icon_with_badge;
+#[cfg(feature = "components")]
+mod input_icon;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+mod listener;
+#[cfg(feature = "components")]
+mod menu_toggle;
+pub mod meta;
+/// This module contains all the mini icon shapes, from the `dioxus-heroicons-mini` crate.
+#[cfg(any(feature = "components", feature = "mini"))]
+pub mod mini {
+    pub use dioxus_heroicons_mini::*;
+}
+/// This module contains all the outline icon shapes, from the `dioxus-heroicons-outline` crate.
+#[cfg(any(feature = "components", feature = "outline"))]
+pub mod outline {
+    pub use dioxus_heroicons_outline::*;
+}
+#[cfg(feature = "components")]
+mod pagination_nav;
+#[cfg(feature = "components")]
+mod password_visibility_toggle;
+#[cfg(feature = "components")]
+mod play_pause_button;
+#[cfg(feature = "components")]
+mod progress_ring;
+#[cfg(feature = "components")]
+mod rating;
+#[cfg(feature = "remote-icons")]
+mod remote_icon;
+mod shape_style;
+#[cfg(feature = "components")]
+mod shortcut;
+/// This module contains all the solid icon shapes, from the `dioxus-heroicons-solid` crate.
+#[cfg(any(feature = "components", feature = "solid"))]
+pub mod solid {
+    pub use dioxus_heroicons_solid::*;
+}
+#[cfg(feature = "components")]
+mod sort_indicator;
+#[cfg(feature = "components")]
+mod spinner;
+#[cfg(feature = "components")]
+mod split_button;
+#[cfg(feature = "components")]
+mod state_toggle;
+#[cfg(feature = "components")]
+mod status_icon;
+#[cfg(feature = "components")]
+mod stepper;
+#[cfg(feature = "components")]
+mod theme;
+#[cfg(feature = "components")]
+mod theme_toggle;
+#[cfg(feature = "components")]
+mod timer;
+#[cfg(feature = "components")]
+mod toast_icon;
+#[cfg(feature = "components")]
+mod toggle_icon_button;
+#[cfg(feature = "components")]
+mod tooltip;
+#[cfg(feature = "components")]
+mod volume_button;
+
+#[cfg(feature = "components")]
+pub use avatar::{Avatar, AvatarProps};
+#[cfg(feature = "components")]
+pub use bookmark_toggle::{BookmarkToggle, BookmarkToggleProps};
+#[cfg(feature = "components")]
+pub use breadcrumbs::{BreadcrumbItem, BreadcrumbSeparator, Breadcrumbs, BreadcrumbsProps};
+#[cfg(feature = "components")]
+pub use callout::{Callout, CalloutProps};
+#[cfg(feature = "components")]
+pub use chip::{Chip, ChipProps};
+#[cfg(feature = "components")]
+pub use copy_button::{CopyButton, CopyButtonProps};
+#[cfg(feature = "components")]
+pub use disclosure_chevron::{DisclosureChevron, DisclosureChevronProps};
+#[cfg(feature = "components")]
+pub use drag_handle::{DragHandle, DragHandleIcon, DragHandleProps};
+#[cfg(feature = "components")]
+pub use dropdown_icon_button::{DropdownAlign, DropdownIconButton, DropdownIconButtonProps};
+#[cfg(feature = "components")]
+pub use empty_state::{EmptyState, EmptyStateProps};
+#[cfg(feature = "components")]
+pub use fab::{Fab, FabPosition, FabProps};
+#[cfg(feature = "components")]
+pub use favorite_toggle::{FavoriteToggle, FavoriteToggleProps};
+#[cfg(feature = "components")]
+pub use file_type_icon::{FileType, FileTypeIcon, FileTypeIconProps};
+#[cfg(feature = "components")]
+pub use fullscreen_toggle::{FullscreenToggle, FullscreenToggleProps};
+#[cfg(feature = "components")]
+pub use icon_checkbox::{IconCheckbox, IconCheckboxProps};
+#[cfg(feature = "components")]
+pub use icon_link::{IconLink, IconLinkProps};
+#[cfg(feature = "components")]
+pub use icon_picker::{IconPicker, IconPickerProps};
+#[cfg(feature = "components")]
+pub use icon_sprite::{write_sprite_sheet, IconSprite, IconSpriteProps, UseIcon, UseIconProps};
+#[cfg(feature = "components")]
+pub use icon_stack::{IconLayer, IconLayerProps, IconStack, IconStackProps};
+#[cfg(feature = "components")]
+pub use icon_text::{IconText, IconTextProps};
+#[cfg(feature = "components")]
+pub use icon_toolbar::{IconToolbar, IconToolbarProps, ToolbarItem};
+#[cfg(feature = "components")]
+pub use icon_transition::{IconTransition, IconTransitionProps, TransitionEffect};
+#[cfg(feature = "components")]
+pub use icon_with_badge::{BadgeColor, BadgePlacement, IconWithBadge, IconWithBadgeProps};
+#[cfg(feature = "components")]
+pub use input_icon::{input_icon_padding, InputIcon, InputIconProps};
+#[cfg(feature = "components")]
+pub use menu_toggle::{MenuToggle, MenuToggleProps};
+#[cfg(feature = "components")]
+pub use pagination_nav::{PaginationNav, PaginationNavProps};
+#[cfg(feature = "components")]
+pub use password_visibility_toggle::{PasswordVisibilityToggle, PasswordVisibilityToggleProps};
+#[cfg(feature = "components")]
+pub use play_pause_button::{MediaState, PlayPauseButton, PlayPauseButtonProps};
+#[cfg(feature = "components")]
+pub use progress_ring::{ProgressRing, ProgressRingProps};
+#[cfg(feature = "components")]
+pub use rating::{Rating, RatingProps};
+#[cfg(feature = "remote-icons")]
+pub use remote_icon::{
+    write_icon_registry, IconRegistry, IconRegistryProvider, IconRegistryProviderProps, RemoteIcon,
+    RemoteIconProps,
+};
+pub use shape_style::ShapeStyle;
+#[cfg(feature = "components")]
+pub use sort_indicator::{SortDirection, SortIndicator, SortIndicatorProps};
+#[cfg(feature = "components")]
+pub use spinner::{Spinner, SpinnerProps};
+#[cfg(feature = "components")]
+pub use split_button::{SplitButton, SplitButtonProps};
+#[cfg(feature = "components")]
+pub use status_icon::{Status, StatusIcon, StatusIconProps};
+#[cfg(feature = "components")]
+pub use stepper::{Stepper, StepperProps};
+#[cfg(feature = "components")]
+pub use theme::{ButtonSize, ButtonVariant, IconTheme};
+#[cfg(feature = "components")]
+pub use theme_toggle::{ColorScheme, ThemeToggle, ThemeToggleProps};
+#[cfg(feature = "components")]
+pub use toast_icon::{ToastIcon, ToastIconProps, ToastLevel};
+#[cfg(feature = "components")]
+pub use toggle_icon_button::{ToggleIconButton, ToggleIconButtonProps};
+#[cfg(feature = "components")]
+pub use tooltip::{Tooltip, TooltipOptions, TooltipPlacement, TooltipProps};
+#[cfg(feature = "components")]
+pub use volume_button::{VolumeButton, VolumeButtonProps};
+
+#[cfg(feature = "components")]
+use dioxus::{
+    events::{MountedEvent, MouseEvent},
+    prelude::*,
+};
+#[cfg(feature = "components")]
+use icon_data::IconData;
+#[cfg(feature = "components")]
+use icon_with_badge::{dot_style, BADGE_CONTAINER_STYLE};
+#[cfg(feature = "components")]
+use shortcut::{is_apple, use_shortcut, Shortcut};
+#[cfg(feature = "components")]
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, OnceLock, PoisonError,
+    },
+};
+#[cfg(feature = "components")]
+use theme::{join_classes, use_icon_theme};
+
+#[cfg(feature = "components")]
+pub(crate) const DISABLED_FILL_COLOR: &str = "#9CA3AF";
+
+/// The default color for the dot on an [`Icon`] or [`IconButton`], which is "red 600" from
+/// tailwindcss.
+#[cfg(feature = "components")]
+pub(crate) const DOT_COLOR: &str = "#DC2626";
+
+/// The inline style used for text that should only be visible to screen readers. This is the same
+/// as tailwindcss's `sr-only` class, but using it doesn't require any CSS.
+#[cfg(feature = "components")]
+pub(crate) const SR_ONLY_STYLE: &str = "position: absolute; width: 1px; height: 1px; padding: 0; \
+     margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border-width: 0;";
+
+/// Lets an optional `Cow<'static, str>` property be set to a `&'static str` or a `String`, or to an
+/// `Option` of either one, the same way that Dioxus lets an `Option<String>` property be set to a
+/// `&str`. Setting it to a literal doesn't allocate.
+#[cfg(feature = "components")]
+#[doc(hidden)]
+pub struct OptionCowMarker;
+
+#[cfg(feature = "components")]
+impl SuperFrom<&'static str, OptionCowMarker> for Option<Cow<'static, str>> {
+    fn super_from(input: &'static str) -> Self {
+        Some(Cow::Borrowed(input))
+    }
+}
+
+#[cfg(feature = "components")]
+impl SuperFrom<String, OptionCowMarker> for Option<Cow<'static, str>> {
+    fn super_from(input: String) -> Self {
+        Some(Cow::Owned(input))
+    }
+}
+
+#[cfg(feature = "components")]
+impl SuperFrom<Option<&'static str>, OptionCowMarker> for Option<Cow<'static, str>> {
+    fn super_from(input: Option<&'static str>) -> Self {
+        input.map(Cow::Borrowed)
+    }
+}
 
-/// This module contains all the mini icon shapes.
-pub mod mini;
-/// This module contains all the outline icon shapes.
-pub mod outline;
-/// This module contains all the solid icon shapes.
-pub mod solid;
+#[cfg(feature = "components")]
+impl SuperFrom<Option<String>, OptionCowMarker> for Option<Cow<'static, str>> {
+    fn super_from(input: Option<String>) -> Self {
+        input.map(Cow::Owned)
+    }
+}
 
-use dioxus::{events::MouseEvent, prelude::*};
+/// Returns an `id` that's unique within the app, for wiring up attributes like `aria-controls`. The
+/// `id` is created when the component is first rendered and doesn't change after that.
+#[cfg(feature = "components")]
+pub(crate) fn use_unique_id(prefix: &str) -> String {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+    use_hook(|| format!("{prefix}-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed)))
+}
 
-const DISABLED_FILL_COLOR: &str = "#9CA3AF";
+/// Returns true once `flag` has been true for `delay_ms`, and false as soon as it's false again.
+/// With a delay of 0 this just returns `flag`.
+#[cfg(feature = "components")]
+fn use_delayed_flag(flag: bool, delay_ms: u32) -> bool {
+    let mut elapsed = use_signal(|| false);
+    let mut timer = use_signal(|| None::<Task>);
+    use_effect(use_reactive((&flag, &delay_ms), move |(flag, delay_ms)| {
+        if let Some(task) = timer.take() {
+            task.cancel();
+        }
+        elapsed.set(false);
+        if flag && delay_ms > 0 {
+            timer.set(Some(spawn(async move {
+                timer::sleep(delay_ms).await;
+                elapsed.set(true);
+            })));
+        }
+    }));
+    flag && (delay_ms == 0 || elapsed())
+}
 
 /// This trait is used to abstract the icon shape so you can use shapes from the [`outline`] or
 /// [`solid`] modules for any property that accepts a shape.
+#[cfg(feature = "components")]
 pub trait IconShape: Clone + PartialEq + std::fmt::Debug {
     fn view_box(&self) -> &str;
     #[allow(clippy::missing_errors_doc)]
     fn path(&self) -> Element;
+    /// Returns the shape's path elements as an HTML string, if the shape has one. An [`Icon`] with
+    /// `prerender` set uses this instead of [`path`](IconShape::path). This defaults to `None`, in
+    /// which case the [`Icon`] uses [`path`](IconShape::path) anyway.
+    fn path_html(&self) -> Option<&'static str> {
+        None
+    }
+    /// Returns the data for each of the shape's path elements, if the shape has it. This is used to
+    /// store a shape in an icon registry. This defaults to `None`.
+    fn paths(&self) -> Option<&'static [PathData]> {
+        None
+    }
+}
+
+/// This trait is implemented by the `Shape` enum in each of the [`mini`], [`outline`], and
+/// [`solid`] modules. It lets you list all the shapes in a module and get their original heroicons
+/// names and search tags, for example to search them.
+#[cfg(feature = "components")]
+pub trait IconSet: IconShape + Copy + 'static {
+    /// Returns all the shapes in the set, in alphabetical order.
+    fn all() -> &'static [Self];
+    /// Returns the original heroicons name for the shape, like "arrow-left".
+    fn name(&self) -> &'static str;
+    /// Returns the shape's search tags, which are other words for what it shows, like "garbage"
+    /// for the trash shape. The tags for the heroicons shapes are generated from the tags that
+    /// heroicons uses to search its website. This returns no tags by default.
+    fn tags(&self) -> &'static [&'static str] {
+        &[]
+    }
+    /// Returns true if the shape points or reads in a direction, like an arrow, a chevron, or a
+    /// bulleted list, so that it should be mirrored in a right-to-left layout. The heroicons shapes
+    /// are classified by the generator, from their names and a curated list. This returns false by
+    /// default.
+    fn is_directional(&self) -> bool {
+        false
+    }
+    /// Returns true if the shape's name or one of its tags contains a search query. The match is
+    /// case-insensitive, and whitespace in the query matches the hyphens in a name, so "arrow left"
+    /// matches "arrow-left". An empty query matches every shape.
+    fn matches(&self, query: &str) -> bool {
+        let normalize = |s: &str| {
+            s.to_lowercase()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join("-")
+        };
+        let query = normalize(query);
+        std::iter::once(self.name())
+            .chain(self.tags().iter().copied())
+            .any(|t| normalize(t).contains(&query))
+    }
+}
+
+pub use dioxus_heroicons_core::PathData;
+
+/// Each of the [`mini`], [`outline`], and [`solid`] modules has a static of this type for each of
+/// its shapes, like [`outline::ARROW_LEFT`]. An app that only uses the statics doesn't need to turn
+/// off any cargo features to keep unused shapes out of its binary:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_heroicons::{outline, Icon};
+///
+/// fn PointsLeft() -> Element {
+///     rsx! {
+///         Icon {
+///             icon: outline::ARROW_LEFT,
+///         }
+///     }
+/// }
+/// ```
+pub use dioxus_heroicons_core::StaticShape;
+
+#[cfg(feature = "components")]
+impl IconShape for StaticShape {
+    fn view_box(&self) -> &str {
+        StaticShape::view_box(*self)
+    }
+
+    fn path(&self) -> Element {
+        render_path(self.path_data())
+    }
+
+    fn path_html(&self) -> Option<&'static str> {
+        Some(path_html(self.path_data()))
+    }
+
+    fn paths(&self) -> Option<&'static [PathData]> {
+        Some(self.path_data())
+    }
+}
+
+/// Implements [`IconSet`] and [`IconShape`] for the `Shape` enum from one of the style crates.
+#[cfg(feature = "components")]
+macro_rules! impl_icon_set {
+    ($style:ident) => {
+        impl IconSet for $style::Shape {
+            fn all() -> &'static [Self] {
+                Self::ALL
+            }
+
+            fn name(&self) -> &'static str {
+                $style::Shape::name(*self)
+            }
+
+            fn tags(&self) -> &'static [&'static str] {
+                $style::Shape::tags(*self)
+            }
+
+            fn is_directional(&self) -> bool {
+                $style::Shape::is_directional(*self)
+            }
+        }
+
+        impl IconShape for $style::Shape {
+            fn view_box(&self) -> &str {
+                $style::Shape::view_box(*self)
+            }
+
+            fn path(&self) -> Element {
+                render_path(self.path_data())
+            }
+
+            fn path_html(&self) -> Option<&'static str> {
+                Some(path_html(self.path_data()))
+            }
+
+            fn paths(&self) -> Option<&'static [PathData]> {
+                Some(self.path_data())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "components")]
+impl_icon_set!(mini);
+#[cfg(feature = "components")]
+impl_icon_set!(outline);
+#[cfg(feature = "components")]
+impl_icon_set!(solid);
+
+/// Returns the HTML for the `<path>` elements of an icon shape, the same as [`render_path`] would
+/// render them. The HTML is built the first time it's asked for and cached after that, so later
+/// calls just return the cached string.
+#[cfg(feature = "components")]
+pub(crate) fn path_html(data: &'static [PathData]) -> &'static str {
+    static CACHE: OnceLock<Mutex<HashMap<usize, &'static str>>> = OnceLock::new();
+    let mut cache = CACHE
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    cache.entry(data.as_ptr() as usize).or_insert_with(|| {
+        let html = data
+            .iter()
+            .map(|p| {
+                let attributes = p
+                    .attributes()
+                    .map(|(name, value)| format!(r#" {name}="{value}""#))
+                    .collect::<Vec<_>>()
+                    .concat();
+                format!(r#"<path d="{}"{attributes}></path>"#, p.d)
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        Box::leak(html.into_boxed_str())
+    })
+}
+
+/// Renders the `<path>` elements for an icon shape, each with its own rules. This is the only place
+/// that icon paths are turned into elements, so the style crates contain just data, not an `rsx!`
+/// block per shape.
+#[cfg(feature = "components")]
+pub(crate) fn render_path(data: &[PathData]) -> Element {
+    rsx! {
+        for p in data {
+            path {
+                d: p.d,
+                clip_rule: p.clip_rule,
+                fill_rule: p.fill_rule,
+                fill: p.fill,
+                fill_opacity: p.fill_opacity,
+                opacity: p.opacity,
+                stroke_width: p.stroke_width,
+                stroke_linecap: p.stroke_linecap,
+                stroke_linejoin: p.stroke_linejoin,
+            }
+        }
+    }
 }
 
 /// The properties for the [`IconButton`] component.
+#[cfg(feature = "components")]
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, PartialEq, Props)]
 pub struct IconButtonProps<S: IconShape + 'static> {
     /// An optional onclick handler for the button.
     #[props(default, strip_option)]
     pub onclick: Option<EventHandler<MouseEvent>>,
+    /// An optional onmounted handler for the button. You can use this to get the button's
+    /// [`MountedData`], for example to focus it.
+    #[props(default, strip_option)]
+    pub onmounted: Option<EventHandler<MountedEvent>>,
     #[props(default, strip_option)]
     /// An optional class for the *button itself*.
-    pub class: Option<String>,
+    pub class: Option<Cow<'static, str>>,
+    /// An optional visual variant for the button. The classes for the variant come from the
+    /// [`IconTheme`] and are added before any `class` you set.
+    #[props(default, strip_option)]
+    pub variant: Option<ButtonVariant>,
     /// An optional title for the button element.
     #[props(default, strip_option)]
     pub title: Option<String>,
     /// The size of the icon. This defaults to 20 pixels.
     #[props(default = 20)]
     pub size: u32,
+    /// An optional preset size for the button. This sets the size of the icon, overriding `size`,
+    /// and adds the padding classes for the size from the [`IconTheme`].
+    #[props(default, strip_option)]
+    pub button_size: Option<ButtonSize>,
+    /// If this is true then the button gets the `touch_target_class` from the [`IconTheme`], which
+    /// gives it a minimum hit area of 44 by 44 pixels for touch screens. This defaults to false.
+    #[props(default = false)]
+    pub touch_target: bool,
     /// The fill color to use for the icon. This defaults to "currentColor".
-    #[props(default = "currentColor".to_string())]
-    pub fill: String,
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
     /// If this is true then the button's `disabled` attribute will be true, and this will be passed
     /// to the `Icon` when it is rendered.
     #[props(default = false)]
@@ -90,20 +694,107 @@ pub struct IconButtonProps<S: IconShape + 'static> {
     pub disabled: bool,
     /// The fill color to use when `disabled` is true. This is only relevant for solid icons. This
     /// defaults to "#9CA3AF", which is "coolGray 400" from tailwindcss.
-    #[props(default = DISABLED_FILL_COLOR.to_string())]
-    pub disabled_fill: String,
+    #[props(into, default = DISABLED_FILL_COLOR)]
+    pub disabled_fill: Cow<'static, str>,
+    /// If this is true then the icon is replaced by a [`mini::Shape::ArrowPath`] with the
+    /// `spinner_class` from the [`IconTheme`], and the button is disabled and has
+    /// `aria-busy="true"`. This defaults to false.
+    #[props(default = false)]
+    pub loading: bool,
+    /// How long `loading` must be true before the spinner appears, in milliseconds. The button is
+    /// still disabled and busy as soon as `loading` is true, but operations that finish quickly
+    /// don't flash the spinner. This defaults to 0, which shows the spinner right away.
+    #[props(default = 0)]
+    pub loading_delay_ms: u32,
+    /// If this is true then a small dot is shown over one of the icon's corners. See [`Icon`] for
+    /// details. This defaults to false.
+    #[props(default = false)]
+    pub dot: bool,
+    /// The color of the dot. This defaults to "#DC2626", which is "red 600" from tailwindcss.
+    #[props(into, default = DOT_COLOR)]
+    pub dot_color: Cow<'static, str>,
+    /// Where the dot is placed. This defaults to [`BadgePlacement::TopRight`].
+    #[props(default)]
+    pub dot_placement: BadgePlacement,
+    /// An optional keyboard shortcut that clicks the button, like "mod+k". This is a list of
+    /// modifiers and a key separated by `+`. The modifiers are `ctrl`, `alt`, `shift`, `meta`, and
+    /// `mod`, which is ⌘ on Apple platforms and `ctrl` everywhere else. The shortcut is also set as
+    /// the button's `aria-keyshortcuts`. Listening for the shortcut needs the `wasm` feature.
+    /// Shortcuts that can't be parsed are ignored.
+    #[props(default, strip_option)]
+    pub shortcut: Option<String>,
+    /// If this is true then a hint for the `shortcut`, like "Ctrl+K", is shown after the icon in a
+    /// `<kbd>` with the `shortcut_class` from the [`IconTheme`]. This defaults to false.
+    #[props(default = false)]
+    pub show_shortcut: bool,
+    /// Optional text for a [`Tooltip`] that is shown while the button is hovered or focused. The
+    /// button gets an `aria-describedby` that refers to the tooltip. When this is set, the button
+    /// is wrapped in the tooltip's `<span>`.
+    #[props(default, strip_option)]
+    pub tooltip: Option<String>,
+    /// The placement, delays, and offset for the `tooltip`.
+    #[props(default)]
+    pub tooltip_options: TooltipOptions,
     /// The icon shape to use.
     pub icon: S,
     /// An optional class for the `<span>` that is part of this component.
     #[props(default, strip_option)]
-    pub span_class: Option<String>,
+    pub span_class: Option<Cow<'static, str>>,
     /// An optional class that will be passed to the [`Icon`].
     #[props(default, strip_option)]
-    pub icon_class: Option<String>,
+    pub icon_class: Option<Cow<'static, str>>,
+    /// Optional text for screen readers. This is rendered in a `<span>` that is visually hidden,
+    /// which gives the button an accessible label without any visible text.
+    #[props(default, strip_option)]
+    pub sr_only: Option<String>,
+    /// An optional class to use for the button's focus indicator instead of the `focus_class` from
+    /// the [`IconTheme`]. Set this to an empty string to remove the focus indicator classes.
+    #[props(default, strip_option)]
+    pub focus_class: Option<Cow<'static, str>>,
+    /// Any other attributes for the *button itself*, such as `aria-*` or `data-*` attributes, or
+    /// native button attributes like `type` or `name`.
+    #[props(extends = GlobalAttributes, extends = button)]
+    pub attributes: Vec<Attribute>,
     /// These are the child elements of the `IconButton` component.
     pub children: Element,
 }
 
+#[cfg(feature = "components")]
+impl<S: IconShape + 'static> IconButtonProps<S> {
+    /// Converts the props to use an [`IconData`] for the icon.
+    fn into_data(self) -> IconButtonProps<IconData> {
+        IconButtonProps {
+            onclick: self.onclick,
+            onmounted: self.onmounted,
+            class: self.class,
+            variant: self.variant,
+            title: self.title,
+            size: self.size,
+            button_size: self.button_size,
+            touch_target: self.touch_target,
+            fill: self.fill,
+            disabled: self.disabled,
+            disabled_fill: self.disabled_fill,
+            loading: self.loading,
+            loading_delay_ms: self.loading_delay_ms,
+            dot: self.dot,
+            dot_color: self.dot_color,
+            dot_placement: self.dot_placement,
+            shortcut: self.shortcut,
+            show_shortcut: self.show_shortcut,
+            tooltip: self.tooltip,
+            tooltip_options: self.tooltip_options,
+            icon: IconData::new(self.icon),
+            span_class: self.span_class,
+            icon_class: self.icon_class,
+            sr_only: self.sr_only,
+            focus_class: self.focus_class,
+            attributes: self.attributes,
+            children: self.children,
+        }
+    }
+}
+
 /// Renders a `<button>` containing an SVG icon.
 ///
 /// This component will generate HTML like this:
@@ -111,6 +802,7 @@ pub struct IconButtonProps<S: IconShape + 'static> {
 /// ```html
 /// <button>
 ///   <svg ...>
+///   <span style="...">Screen reader text goes here</span>
 ///   <span>
 ///     Child elements go here
 ///   </span>
@@ -121,55 +813,136 @@ pub struct IconButtonProps<S: IconShape + 'static> {
 ///
 /// Passing children is optional. This is there so you can add some additional text or other HTML
 /// to the button.
+#[cfg(feature = "components")]
 #[allow(clippy::missing_errors_doc, non_snake_case)]
 #[component]
 pub fn IconButton<S: IconShape>(props: IconButtonProps<S>) -> Element {
-    let disabled = props.disabled;
+    render_icon_button(props.into_data())
+}
+
+/// Renders an [`IconButton`]. This isn't generic, so there's only one copy of it no matter how
+/// many shape types are used with [`IconButton`].
+#[cfg(feature = "components")]
+#[allow(clippy::too_many_lines)]
+fn render_icon_button(props: IconButtonProps<IconData>) -> Element {
+    let theme = use_icon_theme();
+    let loading = props.loading;
+    let disabled = props.disabled || loading;
+    let spinning = use_delayed_flag(loading, props.loading_delay_ms);
     let onclick = props.onclick;
-    rsx! {
+    let onmounted = props.onmounted;
+    let class = join_classes([
+        props.variant.map(|v| theme.variant_class(v)),
+        props.button_size.map(|s| theme.size_class(s)),
+        props
+            .touch_target
+            .then_some(theme.touch_target_class.as_str()),
+        Some(props.focus_class.as_deref().unwrap_or(&theme.focus_class)),
+        props.class.as_deref(),
+    ]);
+    let size = props.button_size.map_or(props.size, ButtonSize::icon_size);
+    let shortcut = props.shortcut.as_deref().and_then(Shortcut::parse);
+    let shortcut_id = use_unique_id("shortcut");
+    use_shortcut(shortcut.as_ref(), &shortcut_id);
+    let shortcut_hint = shortcut
+        .as_ref()
+        .filter(|_| props.show_shortcut)
+        .map(|s| s.hint(is_apple()));
+    let tooltip = props.tooltip;
+    let tooltip_id = use_unique_id("tooltip");
+    let button = rsx! {
         button {
             onclick: move |evt| if !disabled {
                 if let Some(oc) = onclick {
                     oc.call(evt);
                 }
             },
-            class: if let Some(class) = props.class { class },
+            onmounted: move |evt| if let Some(om) = onmounted {
+                om.call(evt);
+            },
+            class: if let Some(class) = class { class },
             title: if let Some(title) = props.title { title },
             disabled: disabled,
-            Icon {
-                ..IconProps {
-                    class: props.icon_class,
-                    size: props.size,
+            aria_busy: if loading { Some("true") } else { None },
+            aria_keyshortcuts: shortcut.as_ref().map(Shortcut::aria),
+            "data-shortcut": if shortcut.is_some() { Some(shortcut_id) } else { None },
+            aria_describedby: if tooltip.is_some() { Some(tooltip_id.clone()) } else { None },
+            ..props.attributes,
+            if spinning {
+                Icon {
+                    class: join_classes([Some(theme.spinner_class.as_str()), props.icon_class.as_deref()]),
+                    size,
                     fill: props.fill,
-                    icon: props.icon.clone(),
-                    disabled: props.disabled,
-                    disabled_fill: props.disabled_fill,
+                    icon: mini::Shape::ArrowPath,
+                },
+            } else {
+                Icon {
+                    ..IconProps {
+                        class: props.icon_class,
+                        size,
+                        fill: props.fill,
+                        icon: props.icon.clone(),
+                        disabled: props.disabled,
+                        disabled_fill: props.disabled_fill,
+                        dot: props.dot,
+                        dot_color: props.dot_color,
+                        dot_placement: props.dot_placement,
+                        prerender: false,
+                    },
+                },
+            },
+            if let Some(hint) = shortcut_hint {
+                kbd {
+                    class: theme.shortcut_class.as_str(),
+                    "{hint}"
+                },
+            }
+            if let Some(sr_only) = props.sr_only {
+                span {
+                    style: SR_ONLY_STYLE,
+                    {sr_only}
                 },
             },
             if props.children != VNode::empty() {
                 span {
-                    class: if let Some(span_class) = props.span_class { span_class },
+                    class: if let Some(span_class) = props.span_class.as_deref() { span_class },
                     { props.children }
                 },
             }
         },
+    };
+    let Some(text) = tooltip else {
+        return button;
+    };
+    let options = props.tooltip_options;
+    rsx! {
+        Tooltip {
+            text,
+            id: tooltip_id,
+            placement: options.placement,
+            show_delay_ms: options.show_delay_ms,
+            hide_delay_ms: options.hide_delay_ms,
+            offset: options.offset,
+            children: button,
+        }
     }
 }
 
 /// The properties for the [`Icon`] component.
+#[cfg(feature = "components")]
 #[derive(Clone, PartialEq, Props)]
 pub struct IconProps<S: IconShape + 'static> {
     /// An optional class for the `<svg>` element.
     #[props(default)]
-    pub class: Option<String>,
+    pub class: Option<Cow<'static, str>>,
     /// The size of the `<svg>` element. All the heroicons are square, so this will be turned into
     /// the `height` and `width` attributes for the `<svg>`. Defaults to 20.
     #[props(default = 20)]
     pub size: u32,
     /// The color to use for filling the icon. This is only relevant for solid icons. Defaults to
     /// "currentColor".
-    #[props(default = "currentColor".to_string())]
-    pub fill: String,
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
     /// The icon shape to use.
     pub icon: S,
     /// If this is true then the fill color will be the one set in
@@ -178,39 +951,127 @@ pub struct IconProps<S: IconShape + 'static> {
     pub disabled: bool,
     /// The fill color to use when `disabled` is true. This is only relevant for solid icons. This
     /// defaults to "#9CA3AF", which is "coolGray 400" from tailwindcss.
-    #[props(default = DISABLED_FILL_COLOR.to_string())]
-    pub disabled_fill: String,
+    #[props(into, default = DISABLED_FILL_COLOR)]
+    pub disabled_fill: Cow<'static, str>,
+    /// If this is true then a small dot is shown over one of the icon's corners, for example to
+    /// show that there is something new. The dot is hidden from screen readers, so say what it
+    /// means some other way. For a count, use [`IconWithBadge`] instead. This defaults to false.
+    #[props(default = false)]
+    pub dot: bool,
+    /// The color of the dot. This defaults to "#DC2626", which is "red 600" from tailwindcss.
+    #[props(into, default = DOT_COLOR)]
+    pub dot_color: Cow<'static, str>,
+    /// Where the dot is placed. This defaults to [`BadgePlacement::TopRight`].
+    #[props(default)]
+    pub dot_placement: BadgePlacement,
+    /// If this is true then the icon's path is added to the `<svg>` as a pre-rendered HTML string
+    /// with `dangerous_inner_html`, instead of as elements. This makes server-side rendering with
+    /// `dioxus-ssr` faster, since the path's elements don't need to be built for every render.
+    /// The HTML for each shape is built the first time it's used and cached after that. This
+    /// defaults to false.
+    #[props(default = false)]
+    pub prerender: bool,
+}
+
+#[cfg(feature = "components")]
+impl<S: IconShape + 'static> IconProps<S> {
+    /// Converts the props to use an [`IconData`] for the icon.
+    fn into_data(self) -> IconProps<IconData> {
+        IconProps {
+            class: self.class,
+            size: self.size,
+            fill: self.fill,
+            icon: IconData::new(self.icon),
+            disabled: self.disabled,
+            disabled_fill: self.disabled_fill,
+            dot: self.dot,
+            dot_color: self.dot_color,
+            dot_placement: self.dot_placement,
+            prerender: self.prerender,
+        }
+    }
 }
 
 /// Renders an `<svg>` element for a heroicon.
 ///
+/// If `dot` is true then the `<svg>` is wrapped in a `<span>` along with the dot:
+///
+/// ```html
+/// <span style="position: relative; display: inline-block;">
+///   <svg ...>
+///   <span aria-hidden="true" style="position: absolute; ..."></span>
+/// </span>
+/// ```
+///
 /// See the [`IconProps`] field documentation for details on the properties it accepts.
+#[cfg(feature = "components")]
 #[allow(clippy::missing_errors_doc, non_snake_case)]
 #[component]
 pub fn Icon<S: IconShape>(props: IconProps<S>) -> Element {
+    render_icon(props.into_data())
+}
+
+/// Renders an [`Icon`]. Like [`render_icon_button`], this isn't generic.
+#[cfg(feature = "components")]
+fn render_icon(props: IconProps<IconData>) -> Element {
     let fill = if props.disabled {
         props.disabled_fill
     } else {
         props.fill
     };
-    rsx! {
+    let path_html = if props.prerender {
+        props.icon.path_html()
+    } else {
+        None
+    };
+    let svg = rsx! {
         svg {
-            class: if let Some(class) = props.class { class },
-            height: format_args!("{}", props.size),
-            width: format_args!("{}", props.size),
-            view_box: format_args!("{}", props.icon.view_box()),
-            fill: "{fill}",
-            { props.icon.path() }
+            class: if let Some(class) = props.class.as_deref() { class },
+            height: i64::from(props.size),
+            width: i64::from(props.size),
+            view_box: props.icon.view_box(),
+            fill: &*fill,
+            dangerous_inner_html: path_html,
+            if path_html.is_none() {
+                IconPath {
+                    icon: props.icon.clone(),
+                },
+            }
+        }
+    };
+    if !props.dot {
+        return svg;
+    }
+    rsx! {
+        span {
+            style: BADGE_CONTAINER_STYLE,
+            { svg },
+            span {
+                aria_hidden: true,
+                style: dot_style(props.dot_placement, &props.dot_color),
+            },
         }
     }
 }
 
-#[cfg(test)]
-mod test {
+/// Renders the path elements for a shape. This is its own component so that, like any component,
+/// it's only re-rendered when its props change. An [`Icon`] that re-renders with a new `fill` or
+/// `size` doesn't rebuild and diff its path, which is most of its elements.
+#[cfg(feature = "components")]
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+fn IconPath(icon: IconData) -> Element {
+    icon.path()
+}
+
+#[cfg(all(test, feature = "components"))]
+pub(crate) mod test {
     use super::*;
-    use dioxus_ssr;
     use html_compare_rs::assert_html_eq;
 
+    pub(crate) const FOCUS_CLASS: &str =
+        "focus:outline-none focus-visible:ring-2 focus-visible:ring-blue-500";
+
     #[test]
     fn icon_default() {
         assert_rsx_eq(
@@ -274,6 +1135,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn icon_dot() {
+        assert_rsx_eq(
+            rsx! {
+                Icon {
+                    icon: outline::Shape::Bell,
+                    dot: true,
+                    dot_color: "green",
+                    dot_placement: BadgePlacement::BottomLeft,
+                },
+            },
+            rsx! {
+                span {
+                    style: "position: relative; display: inline-block;",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Bell.path() },
+                    },
+                    span {
+                        aria_hidden: true,
+                        style: "position: absolute; bottom: 0; left: 0; transform: translate(-50%, 50%); width: 8px; height: 8px; border-radius: 9999px; background-color: green;",
+                    },
+                },
+            },
+        );
+    }
+
     #[test]
     fn icon_button_default() {
         assert_rsx_eq(
@@ -284,6 +1175,7 @@ mod test {
             },
             rsx! {
                 button {
+                    class: FOCUS_CLASS,
                     svg {
                         height: 20,
                         width: 20,
@@ -311,6 +1203,7 @@ mod test {
             },
             rsx! {
                 button {
+                    class: FOCUS_CLASS,
                     svg {
                         height: 20,
                         width: 20,
@@ -342,7 +1235,7 @@ mod test {
             },
             rsx! {
                 button {
-                    class: "some-button",
+                    class: "{FOCUS_CLASS} some-button",
                     title: "Foo",
                     svg {
                         height: 20,
@@ -369,6 +1262,7 @@ mod test {
             },
             rsx! {
                 button {
+                    class: FOCUS_CLASS,
                     disabled: true,
                     svg {
                         height: 20,
@@ -384,7 +1278,608 @@ mod test {
         );
     }
 
-    fn assert_rsx_eq(first: Element, second: Element) {
+    #[test]
+    fn icon_button_loading() {
+        assert_rsx_eq(
+            rsx! {
+                IconButton {
+                    icon: outline::Shape::ArrowLeft,
+                    loading: true,
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    disabled: true,
+                    aria_busy: "true",
+                    svg {
+                        class: "animate-spin",
+                        height: 20,
+                        width: 20,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        {
+                            mini::Shape::ArrowPath.path()
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_button_loading_delay() {
+        assert_rsx_eq(
+            rsx! {
+                IconButton {
+                    icon: outline::Shape::ArrowLeft,
+                    loading: true,
+                    loading_delay_ms: 300,
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    disabled: true,
+                    aria_busy: "true",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        {
+                            outline::Shape::ArrowLeft.path()
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_button_dot() {
+        assert_rsx_eq(
+            rsx! {
+                IconButton {
+                    icon: outline::Shape::Bell,
+                    dot: true,
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    span {
+                        style: "position: relative; display: inline-block;",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: outline::VIEW_BOX,
+                            fill: "currentColor",
+                            { outline::Shape::Bell.path() },
+                        },
+                        span {
+                            aria_hidden: true,
+                            style: "position: absolute; top: 0; right: 0; transform: translate(50%, -50%); width: 8px; height: 8px; border-radius: 9999px; background-color: #DC2626;",
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_button_shortcut() {
+        // The data-shortcut attribute has a generated id, so this checks the parts of the HTML
+        // that are predictable.
+        let html = dioxus_ssr::render_element(rsx! {
+            IconButton {
+                icon: outline::Shape::MagnifyingGlass,
+                shortcut: "mod+k",
+                show_shortcut: true,
+            },
+        });
+        assert!(html.contains(r#"aria-keyshortcuts="Control+K Meta+K""#));
+        assert!(html.contains(r#"data-shortcut="shortcut-"#));
+        assert!(html.contains(r#"<kbd class="ml-1 text-xs text-gray-500">Ctrl+K</kbd>"#));
+    }
+
+    #[test]
+    fn icon_button_tooltip() {
+        // The tooltip's id is generated, so this checks that the button and the tooltip agree on
+        // it rather than comparing the whole HTML.
+        let html = dioxus_ssr::render_element(rsx! {
+            IconButton {
+                icon: outline::Shape::Trash,
+                tooltip: "Delete",
+                tooltip_options: TooltipOptions {
+                    placement: TooltipPlacement::Bottom,
+                    ..TooltipOptions::default()
+                },
+            },
+        });
+        let id = html
+            .split(r#"aria-describedby=""#)
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        assert!(id.starts_with("tooltip-"));
+        assert!(html.contains(&format!(r#"role="tooltip" id="{id}" hidden=true"#)));
+        assert!(html.contains("top: 100%;"));
+        assert!(html.ends_with(">Delete</span></span>"));
+    }
+
+    #[test]
+    fn icon_button_variant() {
+        assert_rsx_eq(
+            rsx! {
+                IconButton {
+                    class: "some-button",
+                    icon: outline::Shape::ArrowLeft,
+                    variant: ButtonVariant::Danger,
+                },
+            },
+            rsx! {
+                button {
+                    class: "bg-red-600 text-white hover:bg-red-700 {FOCUS_CLASS} some-button",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        {
+                            outline::Shape::ArrowLeft.path()
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_button_size() {
+        assert_rsx_eq(
+            rsx! {
+                IconButton {
+                    icon: outline::Shape::ArrowLeft,
+                    size: 50,
+                    button_size: ButtonSize::Lg,
+                    touch_target: true,
+                },
+            },
+            rsx! {
+                button {
+                    class: "p-2 min-h-11 min-w-11 {FOCUS_CLASS}",
+                    svg {
+                        height: 24,
+                        width: 24,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        {
+                            outline::Shape::ArrowLeft.path()
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_button_attributes() {
+        assert_rsx_eq(
+            rsx! {
+                IconButton {
+                    icon: outline::Shape::ArrowLeft,
+                    r#type: "submit",
+                    aria_label: "Go back",
+                    "data-testid": "back",
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    r#type: "submit",
+                    aria_label: "Go back",
+                    "data-testid": "back",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        {
+                            outline::Shape::ArrowLeft.path()
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_button_focus_class() {
+        assert_rsx_eq(
+            rsx! {
+                IconButton {
+                    icon: outline::Shape::ArrowLeft,
+                    focus_class: "my-focus",
+                },
+            },
+            rsx! {
+                button {
+                    class: "my-focus",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        {
+                            outline::Shape::ArrowLeft.path()
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_button_sr_only() {
+        assert_rsx_eq(
+            rsx! {
+                IconButton {
+                    icon: outline::Shape::ArrowLeft,
+                    sr_only: "Go back",
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        {
+                            outline::Shape::ArrowLeft.path()
+                        },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Go back"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_button_variant_from_theme() {
+        #[allow(non_snake_case)]
+        fn Themed() -> Element {
+            use_context_provider(|| IconTheme {
+                ghost_class: "ghost".to_string(),
+                ..IconTheme::default()
+            });
+            rsx! {
+                IconButton {
+                    icon: outline::Shape::ArrowLeft,
+                    variant: ButtonVariant::Ghost,
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Themed {},
+            },
+            rsx! {
+                button {
+                    class: "ghost {FOCUS_CLASS}",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        {
+                            outline::Shape::ArrowLeft.path()
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_button_variant_from_shared_theme() {
+        #[allow(non_snake_case)]
+        fn Themed() -> Element {
+            use_context_provider(|| {
+                std::rc::Rc::new(IconTheme {
+                    ghost_class: "shared-ghost".to_string(),
+                    ..IconTheme::default()
+                })
+            });
+            rsx! {
+                IconButton {
+                    icon: outline::Shape::ArrowLeft,
+                    variant: ButtonVariant::Ghost,
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Themed {},
+            },
+            rsx! {
+                button {
+                    class: "shared-ghost {FOCUS_CLASS}",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        {
+                            outline::Shape::ArrowLeft.path()
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn icon_prerender() {
+        for prerender in [false, true] {
+            assert_rsx_eq(
+                rsx! {
+                    Icon {
+                        icon: mini::Shape::XCircle,
+                        prerender,
+                    },
+                },
+                rsx! {
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::XCircle.path() },
+                    },
+                },
+            );
+        }
+        assert!(std::ptr::eq(
+            mini::Shape::XCircle.path_html().unwrap(),
+            mini::Shape::XCircle.path_html().unwrap(),
+        ));
+    }
+
+    #[test]
+    fn icon_path_is_memoized() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static PATH_RENDERS: Cell<u32> = const { Cell::new(0) };
+            static FILL: Cell<Option<Signal<String>>> = const { Cell::new(None) };
+        }
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct CountingShape;
+
+        impl IconShape for CountingShape {
+            fn view_box(&self) -> &'static str {
+                "0 0 20 20"
+            }
+
+            fn path(&self) -> Element {
+                PATH_RENDERS.with(|r| r.set(r.get() + 1));
+                rsx! {
+                    path {
+                        d: "M0 0L20 20Z",
+                    }
+                }
+            }
+        }
+
+        #[allow(non_snake_case)]
+        fn App() -> Element {
+            let fill = use_signal(|| "red".to_string());
+            FILL.with(|f| f.set(Some(fill)));
+            rsx! {
+                Icon {
+                    icon: CountingShape,
+                    fill: fill(),
+                },
+            }
+        }
+
+        let mut dom = VirtualDom::new(App);
+        dom.rebuild_in_place();
+        assert_eq!(PATH_RENDERS.with(Cell::get), 1);
+
+        let mut fill = FILL.with(Cell::get).unwrap();
+        dom.in_runtime(|| fill.set("blue".to_string()));
+        dom.process_events();
+        dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        assert!(dioxus_ssr::render(&dom).contains(r#"fill="blue""#));
+        assert_eq!(PATH_RENDERS.with(Cell::get), 1);
+    }
+
+    #[test]
+    fn string_props_borrow_literals() {
+        let props = IconProps::builder()
+            .icon(outline::Shape::ArrowLeft)
+            .class("foo")
+            .fill("#FFFFFF")
+            .dot_color(format!("#{}", "000000"))
+            .build();
+        assert!(matches!(props.class, Some(Cow::Borrowed("foo"))));
+        assert!(matches!(props.fill, Cow::Borrowed("#FFFFFF")));
+        assert!(matches!(
+            props.disabled_fill,
+            Cow::Borrowed(DISABLED_FILL_COLOR)
+        ));
+        assert!(matches!(props.dot_color, Cow::Owned(ref c) if c == "#000000"));
+    }
+
+    #[test]
+    fn shape_data() {
+        let shape = mini::Shape::XCircle;
+        assert_eq!(shape.name(), "x-circle");
+        assert_eq!(shape.view_box(), "0 0 20 20");
+        assert_eq!(shape.path_data().len(), 1);
+        assert_eq!(shape.path_data()[0].clip_rule, Some("evenodd"));
+        assert_eq!(shape.path_data()[0].fill_rule, Some("evenodd"));
+        assert!(shape.path_data()[0].d.starts_with('M'));
+        assert_eq!(outline::Shape::ArrowLeft.view_box(), "0 0 24 24");
+        assert_eq!(outline::Shape::ArrowLeft.path_data()[0].clip_rule, None);
+        assert_eq!(
+            outline::Shape::from_discriminant(outline::Shape::ArrowLeft.discriminant()),
+            Some(outline::Shape::ArrowLeft),
+        );
+        assert_eq!(outline::Shape::from_discriminant(u32::MAX), None);
+    }
+
+    #[cfg(feature = "all-icons")]
+    #[test]
+    fn shape_entries() {
+        for &shape in solid::Shape::ALL {
+            assert_eq!(
+                solid::Shape::from_discriminant(shape.discriminant()),
+                Some(shape),
+            );
+            assert_eq!(shape.static_shape().name(), shape.name());
+        }
+    }
+
+    #[cfg(feature = "all-icons")]
+    #[test]
+    fn directional_shapes() {
+        assert!(outline::Shape::ArrowLeft.is_directional());
+        assert!(IconSet::is_directional(&mini::Shape::ChevronDoubleRight));
+        assert!(outline::Shape::Bars3BottomLeft.is_directional());
+        assert!(!mini::Shape::ChevronUp.is_directional());
+    }
+
+    #[cfg(feature = "all-icons")]
+    #[test]
+    fn meta_icon_counts() {
+        assert_eq!(mini::Shape::ALL.len(), meta::MINI_ICON_COUNT);
+        assert_eq!(outline::Shape::ALL.len(), meta::OUTLINE_ICON_COUNT);
+        assert_eq!(solid::Shape::ALL.len(), meta::SOLID_ICON_COUNT);
+    }
+
+    #[test]
+    fn meta_variants() {
+        assert_eq!(
+            meta::variants("arrow-left"),
+            &[ShapeStyle::Mini, ShapeStyle::Outline, ShapeStyle::Solid],
+        );
+        assert_eq!(meta::variants("not-an-icon"), &[]);
+        assert!(meta::available_in("x-circle", ShapeStyle::Outline));
+        assert!(!meta::available_in("not-an-icon", ShapeStyle::Outline));
+        assert_eq!(
+            meta::style_or_fallback("x-circle", ShapeStyle::Solid),
+            Some(ShapeStyle::Solid),
+        );
+        assert_eq!(
+            meta::style_or_fallback("not-an-icon", ShapeStyle::Solid),
+            None
+        );
+        assert!(meta::VARIANTS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn static_shapes() {
+        assert_eq!(
+            outline::Shape::ArrowLeft.static_shape(),
+            outline::ARROW_LEFT
+        );
+        assert_eq!(mini::X_CIRCLE.name(), "x-circle");
+        assert_eq!(mini::X_CIRCLE.view_box(), mini::VIEW_BOX);
+        assert!(std::ptr::eq(
+            mini::X_CIRCLE.path_data(),
+            mini::Shape::XCircle.path_data(),
+        ));
+        assert_rsx_eq(
+            rsx! {
+                Icon {
+                    icon: outline::ARROW_LEFT,
+                    prerender: true,
+                },
+            },
+            rsx! {
+                Icon {
+                    icon: outline::Shape::ArrowLeft,
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn path_data() {
+        assert_rsx_eq(
+            render_path(&[PathData {
+                d: "M0 0L20 20Z",
+                ..PathData::EMPTY
+            }]),
+            rsx! {
+                path {
+                    d: "M0 0L20 20Z",
+                },
+            },
+        );
+
+        let paths: &'static [PathData] = &[
+            PathData {
+                d: "M0 0L20 20Z",
+                clip_rule: Some("evenodd"),
+                fill_rule: Some("evenodd"),
+                ..PathData::EMPTY
+            },
+            PathData {
+                d: "M20 0L0 20Z",
+                fill_rule: Some("nonzero"),
+                fill: Some("#FFFFFF"),
+                fill_opacity: Some("0.5"),
+                opacity: Some("0.8"),
+                stroke_width: Some("1.5"),
+                stroke_linecap: Some("round"),
+                stroke_linejoin: Some("round"),
+                ..PathData::EMPTY
+            },
+        ];
+        assert_rsx_eq(
+            render_path(paths),
+            rsx! {
+                path {
+                    d: "M0 0L20 20Z",
+                    clip_rule: "evenodd",
+                    fill_rule: "evenodd",
+                },
+                path {
+                    d: "M20 0L0 20Z",
+                    fill_rule: "nonzero",
+                    fill: "#FFFFFF",
+                    fill_opacity: "0.5",
+                    opacity: "0.8",
+                    stroke_width: "1.5",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                },
+            },
+        );
+        assert_eq!(
+            path_html(paths),
+            concat!(
+                r#"<path d="M0 0L20 20Z" clip-rule="evenodd" fill-rule="evenodd"></path>"#,
+                r##"<path d="M20 0L0 20Z" fill-rule="nonzero" fill="#FFFFFF" fill-opacity="0.5" "##,
+                r#"opacity="0.8" stroke-width="1.5" stroke-linecap="round" "#,
+                r#"stroke-linejoin="round"></path>"#,
+            ),
+        );
+    }
+
+    pub(crate) fn assert_rsx_eq(first: Element, second: Element) {
         let first = dioxus_ssr::render_element(first);
         let second = dioxus_ssr::render_element(second);
         assert_html_eq!(first, second);
@@ -0,0 +1,44 @@
+//! Event listeners on the browser's document, for components that need to know about events that
+//! don't happen on their own elements, like [`FullscreenToggle`](crate::FullscreenToggle) and
+//! keyboard shortcuts.
+
+use wasm_bindgen::{closure::Closure, JsCast};
+
+/// A listener for an event on the document, which is removed when this is dropped.
+pub(crate) struct Listener {
+    document: web_sys::Document,
+    event: &'static str,
+    closure: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+impl Listener {
+    /// Adds a listener that calls `handler` with each `event` on the document. The handler takes
+    /// the event's type, like [`web_sys::KeyboardEvent`] for `keydown`. This returns `None` if
+    /// there's no document or the listener can't be added.
+    pub(crate) fn add<E: JsCast + 'static>(
+        event: &'static str,
+        mut handler: impl FnMut(&web_sys::Document, E) + 'static,
+    ) -> Option<Self> {
+        let document = web_sys::window()?.document()?;
+        let doc = document.clone();
+        let closure = Closure::<dyn FnMut(_)>::new(move |evt: web_sys::Event| {
+            handler(&doc, evt.unchecked_into());
+        });
+        document
+            .add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+            .ok()?;
+        Some(Self {
+            document,
+            event,
+            closure,
+        })
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        let _ = self
+            .document
+            .remove_event_listener_with_callback(self.event, self.closure.as_ref().unchecked_ref());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{
+    outline,
+    theme::{join_classes, use_icon_theme},
+    IconTransition, TransitionEffect, SR_ONLY_STYLE,
+};
+use dioxus::prelude::*;
+
+/// The properties for the [`MenuToggle`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct MenuToggleProps {
+    /// Whether the menu is open.
+    pub open: bool,
+    /// The handler that is called with the new state when the toggle is clicked.
+    pub onchange: EventHandler<bool>,
+    /// The `id` of the menu, which the toggle refers to with `aria-controls`.
+    #[props(default, strip_option)]
+    pub controls: Option<String>,
+    /// The accessible label for the toggle. This is also used as its title. This defaults to
+    /// "Menu".
+    #[props(default = "Menu".to_string())]
+    pub label: String,
+    /// An optional class for the button.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icons. This defaults to 24 pixels.
+    #[props(default = 24)]
+    pub size: u32,
+    /// How long the crossfade between the icons takes, in milliseconds. Set this to 0 to turn the
+    /// animation off. This defaults to 200.
+    #[props(default = 200)]
+    pub transition_ms: u32,
+}
+
+/// Renders a hamburger button that turns into a close button when its menu is open, for mobile
+/// navigation.
+///
+/// The button uses an [`IconTransition`] to rotate between [`outline::Shape::Bars3`] and
+/// [`outline::Shape::XMark`]. Its label stays the same, and whether the menu is open is exposed
+/// with `aria-expanded`. The toggle is controlled, so it's up to you to store whether the menu is
+/// open and show or hide it.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button title="Menu" aria-expanded="false" aria-controls="...">
+///   <span style="position: relative; ...">
+///     <span style="... opacity: 1; ...">
+///       <svg ...>
+///     </span>
+///     <span style="... opacity: 0; ...">
+///       <svg ...>
+///     </span>
+///   </span>
+///   <span style="...">Menu</span>
+/// </button>
+/// ```
+///
+/// See the [`MenuToggleProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn MenuToggle(props: MenuToggleProps) -> Element {
+    let theme = use_icon_theme();
+    let class = join_classes([Some(theme.focus_class.as_str()), props.class.as_deref()]);
+    let open = props.open;
+    let onchange = props.onchange;
+    let label = props.label;
+    rsx! {
+        button {
+            r#type: "button",
+            class: if let Some(class) = class { class },
+            title: label.as_str(),
+            aria_expanded: open,
+            aria_controls: props.controls,
+            onclick: move |_| onchange.call(!open),
+            IconTransition {
+                icon_on: outline::Shape::XMark,
+                icon_off: outline::Shape::Bars3,
+                active: open,
+                effect: TransitionEffect::Rotate,
+                size: props.size,
+                transition_ms: props.transition_ms,
+            },
+            span {
+                style: SR_ONLY_STYLE,
+                "{label}"
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape,
+    };
+
+    #[test]
+    fn menu_toggle_closed() {
+        #[allow(non_snake_case)]
+        fn Toggle() -> Element {
+            rsx! {
+                MenuToggle {
+                    open: false,
+                    onchange: |_| {},
+                    controls: "nav",
+                },
+            }
+        }
+
+        let icon = |shape: outline::Shape| {
+            rsx! {
+                svg {
+                    height: 24,
+                    width: 24,
+                    view_box: outline::VIEW_BOX,
+                    fill: "currentColor",
+                    { shape.path() },
+                }
+            }
+        };
+        assert_rsx_eq(
+            rsx! {
+                Toggle {},
+            },
+            rsx! {
+                button {
+                    r#type: "button",
+                    class: FOCUS_CLASS,
+                    title: "Menu",
+                    aria_expanded: "false",
+                    aria_controls: "nav",
+                    span {
+                        style: "position: relative; display: inline-block; width: 24px; height: 24px;",
+                        span {
+                            style: "position: absolute; top: 0; left: 0; transition: opacity 200ms, transform 200ms; opacity: 1; transform: rotate(0deg);",
+                            { icon(outline::Shape::Bars3) },
+                        },
+                        span {
+                            style: "position: absolute; top: 0; left: 0; transition: opacity 200ms, transform 200ms; opacity: 0; transform: rotate(90deg);",
+                            { icon(outline::Shape::XMark) },
+                        },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Menu"
+                    },
+                },
+            },
+        );
+    }
+}
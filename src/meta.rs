@@ -0,0 +1,351 @@
+//! Information about the heroicons that this crate's shapes were generated from, for showing where
+//! the icons came from or checking that an app was built with the icons it expects.
+//!
+//! This module was written by the `gen` crate, so don't edit it by hand.
+
+use crate::ShapeStyle::{self, Mini, Outline, Solid};
+
+/// The version of heroicons that the shapes come from, like "2.0.13".
+pub const HEROICONS_VERSION: &str = "2.0.13";
+
+/// When the shapes were generated, as an RFC 3339 timestamp in UTC.
+pub const GENERATED_AT: &str = "2026-10-15T08:47:59Z";
+
+/// The number of shapes in the `mini` style, including any whose features are off. Compare this
+/// to the length of `mini::Shape::ALL` to see how many of them are enabled.
+pub const MINI_ICON_COUNT: usize = 292;
+
+/// The number of shapes in the `outline` style, including any whose features are off. Compare this
+/// to the length of `outline::Shape::ALL` to see how many of them are enabled.
+pub const OUTLINE_ICON_COUNT: usize = 292;
+
+/// The number of shapes in the `solid` style, including any whose features are off. Compare this
+/// to the length of `solid::Shape::ALL` to see how many of them are enabled.
+pub const SOLID_ICON_COUNT: usize = 292;
+
+/// The styles that each icon is in, by its heroicons name, sorted by name. Not every icon is in
+/// every style, so code that switches between styles can use this to find the ones that it can
+/// switch to. This lists every icon, including those whose features are off.
+pub const VARIANTS: &[(&str, &[ShapeStyle])] = &[
+    ("academic-cap", &[Mini, Outline, Solid]),
+    ("adjustments-horizontal", &[Mini, Outline, Solid]),
+    ("adjustments-vertical", &[Mini, Outline, Solid]),
+    ("archive-box", &[Mini, Outline, Solid]),
+    ("archive-box-arrow-down", &[Mini, Outline, Solid]),
+    ("archive-box-x-mark", &[Mini, Outline, Solid]),
+    ("arrow-down", &[Mini, Outline, Solid]),
+    ("arrow-down-circle", &[Mini, Outline, Solid]),
+    ("arrow-down-left", &[Mini, Outline, Solid]),
+    ("arrow-down-on-square", &[Mini, Outline, Solid]),
+    ("arrow-down-on-square-stack", &[Mini, Outline, Solid]),
+    ("arrow-down-right", &[Mini, Outline, Solid]),
+    ("arrow-down-tray", &[Mini, Outline, Solid]),
+    ("arrow-left", &[Mini, Outline, Solid]),
+    ("arrow-left-circle", &[Mini, Outline, Solid]),
+    ("arrow-left-on-rectangle", &[Mini, Outline, Solid]),
+    ("arrow-long-down", &[Mini, Outline, Solid]),
+    ("arrow-long-left", &[Mini, Outline, Solid]),
+    ("arrow-long-right", &[Mini, Outline, Solid]),
+    ("arrow-long-up", &[Mini, Outline, Solid]),
+    ("arrow-path", &[Mini, Outline, Solid]),
+    ("arrow-path-rounded-square", &[Mini, Outline, Solid]),
+    ("arrow-right", &[Mini, Outline, Solid]),
+    ("arrow-right-circle", &[Mini, Outline, Solid]),
+    ("arrow-right-on-rectangle", &[Mini, Outline, Solid]),
+    ("arrow-small-down", &[Mini, Outline, Solid]),
+    ("arrow-small-left", &[Mini, Outline, Solid]),
+    ("arrow-small-right", &[Mini, Outline, Solid]),
+    ("arrow-small-up", &[Mini, Outline, Solid]),
+    ("arrow-top-right-on-square", &[Mini, Outline, Solid]),
+    ("arrow-trending-down", &[Mini, Outline, Solid]),
+    ("arrow-trending-up", &[Mini, Outline, Solid]),
+    ("arrow-up", &[Mini, Outline, Solid]),
+    ("arrow-up-circle", &[Mini, Outline, Solid]),
+    ("arrow-up-left", &[Mini, Outline, Solid]),
+    ("arrow-up-on-square", &[Mini, Outline, Solid]),
+    ("arrow-up-on-square-stack", &[Mini, Outline, Solid]),
+    ("arrow-up-right", &[Mini, Outline, Solid]),
+    ("arrow-up-tray", &[Mini, Outline, Solid]),
+    ("arrow-uturn-down", &[Mini, Outline, Solid]),
+    ("arrow-uturn-left", &[Mini, Outline, Solid]),
+    ("arrow-uturn-right", &[Mini, Outline, Solid]),
+    ("arrow-uturn-up", &[Mini, Outline, Solid]),
+    ("arrows-pointing-in", &[Mini, Outline, Solid]),
+    ("arrows-pointing-out", &[Mini, Outline, Solid]),
+    ("arrows-right-left", &[Mini, Outline, Solid]),
+    ("arrows-up-down", &[Mini, Outline, Solid]),
+    ("at-symbol", &[Mini, Outline, Solid]),
+    ("backspace", &[Mini, Outline, Solid]),
+    ("backward", &[Mini, Outline, Solid]),
+    ("banknotes", &[Mini, Outline, Solid]),
+    ("bars-2", &[Mini, Outline, Solid]),
+    ("bars-3", &[Mini, Outline, Solid]),
+    ("bars-3-bottom-left", &[Mini, Outline, Solid]),
+    ("bars-3-bottom-right", &[Mini, Outline, Solid]),
+    ("bars-3-center-left", &[Mini, Outline, Solid]),
+    ("bars-4", &[Mini, Outline, Solid]),
+    ("bars-arrow-down", &[Mini, Outline, Solid]),
+    ("bars-arrow-up", &[Mini, Outline, Solid]),
+    ("battery-0", &[Mini, Outline, Solid]),
+    ("battery-100", &[Mini, Outline, Solid]),
+    ("battery-50", &[Mini, Outline, Solid]),
+    ("beaker", &[Mini, Outline, Solid]),
+    ("bell", &[Mini, Outline, Solid]),
+    ("bell-alert", &[Mini, Outline, Solid]),
+    ("bell-slash", &[Mini, Outline, Solid]),
+    ("bell-snooze", &[Mini, Outline, Solid]),
+    ("bolt", &[Mini, Outline, Solid]),
+    ("bolt-slash", &[Mini, Outline, Solid]),
+    ("book-open", &[Mini, Outline, Solid]),
+    ("bookmark", &[Mini, Outline, Solid]),
+    ("bookmark-slash", &[Mini, Outline, Solid]),
+    ("bookmark-square", &[Mini, Outline, Solid]),
+    ("briefcase", &[Mini, Outline, Solid]),
+    ("bug-ant", &[Mini, Outline, Solid]),
+    ("building-library", &[Mini, Outline, Solid]),
+    ("building-office", &[Mini, Outline, Solid]),
+    ("building-office-2", &[Mini, Outline, Solid]),
+    ("building-storefront", &[Mini, Outline, Solid]),
+    ("cake", &[Mini, Outline, Solid]),
+    ("calculator", &[Mini, Outline, Solid]),
+    ("calendar", &[Mini, Outline, Solid]),
+    ("calendar-days", &[Mini, Outline, Solid]),
+    ("camera", &[Mini, Outline, Solid]),
+    ("chart-bar", &[Mini, Outline, Solid]),
+    ("chart-bar-square", &[Mini, Outline, Solid]),
+    ("chart-pie", &[Mini, Outline, Solid]),
+    ("chat-bubble-bottom-center", &[Mini, Outline, Solid]),
+    ("chat-bubble-bottom-center-text", &[Mini, Outline, Solid]),
+    ("chat-bubble-left", &[Mini, Outline, Solid]),
+    ("chat-bubble-left-ellipsis", &[Mini, Outline, Solid]),
+    ("chat-bubble-left-right", &[Mini, Outline, Solid]),
+    ("chat-bubble-oval-left", &[Mini, Outline, Solid]),
+    ("chat-bubble-oval-left-ellipsis", &[Mini, Outline, Solid]),
+    ("check", &[Mini, Outline, Solid]),
+    ("check-badge", &[Mini, Outline, Solid]),
+    ("check-circle", &[Mini, Outline, Solid]),
+    ("chevron-double-down", &[Mini, Outline, Solid]),
+    ("chevron-double-left", &[Mini, Outline, Solid]),
+    ("chevron-double-right", &[Mini, Outline, Solid]),
+    ("chevron-double-up", &[Mini, Outline, Solid]),
+    ("chevron-down", &[Mini, Outline, Solid]),
+    ("chevron-left", &[Mini, Outline, Solid]),
+    ("chevron-right", &[Mini, Outline, Solid]),
+    ("chevron-up", &[Mini, Outline, Solid]),
+    ("chevron-up-down", &[Mini, Outline, Solid]),
+    ("circle-stack", &[Mini, Outline, Solid]),
+    ("clipboard", &[Mini, Outline, Solid]),
+    ("clipboard-document", &[Mini, Outline, Solid]),
+    ("clipboard-document-check", &[Mini, Outline, Solid]),
+    ("clipboard-document-list", &[Mini, Outline, Solid]),
+    ("clock", &[Mini, Outline, Solid]),
+    ("cloud", &[Mini, Outline, Solid]),
+    ("cloud-arrow-down", &[Mini, Outline, Solid]),
+    ("cloud-arrow-up", &[Mini, Outline, Solid]),
+    ("code-bracket", &[Mini, Outline, Solid]),
+    ("code-bracket-square", &[Mini, Outline, Solid]),
+    ("cog", &[Mini, Outline, Solid]),
+    ("cog-6-tooth", &[Mini, Outline, Solid]),
+    ("cog-8-tooth", &[Mini, Outline, Solid]),
+    ("command-line", &[Mini, Outline, Solid]),
+    ("computer-desktop", &[Mini, Outline, Solid]),
+    ("cpu-chip", &[Mini, Outline, Solid]),
+    ("credit-card", &[Mini, Outline, Solid]),
+    ("cube", &[Mini, Outline, Solid]),
+    ("cube-transparent", &[Mini, Outline, Solid]),
+    ("currency-bangladeshi", &[Mini, Outline, Solid]),
+    ("currency-dollar", &[Mini, Outline, Solid]),
+    ("currency-euro", &[Mini, Outline, Solid]),
+    ("currency-pound", &[Mini, Outline, Solid]),
+    ("currency-rupee", &[Mini, Outline, Solid]),
+    ("currency-yen", &[Mini, Outline, Solid]),
+    ("cursor-arrow-rays", &[Mini, Outline, Solid]),
+    ("cursor-arrow-ripple", &[Mini, Outline, Solid]),
+    ("device-phone-mobile", &[Mini, Outline, Solid]),
+    ("device-tablet", &[Mini, Outline, Solid]),
+    ("document", &[Mini, Outline, Solid]),
+    ("document-arrow-down", &[Mini, Outline, Solid]),
+    ("document-arrow-up", &[Mini, Outline, Solid]),
+    ("document-chart-bar", &[Mini, Outline, Solid]),
+    ("document-check", &[Mini, Outline, Solid]),
+    ("document-duplicate", &[Mini, Outline, Solid]),
+    ("document-magnifying-glass", &[Mini, Outline, Solid]),
+    ("document-minus", &[Mini, Outline, Solid]),
+    ("document-plus", &[Mini, Outline, Solid]),
+    ("document-text", &[Mini, Outline, Solid]),
+    ("ellipsis-horizontal", &[Mini, Outline, Solid]),
+    ("ellipsis-horizontal-circle", &[Mini, Outline, Solid]),
+    ("ellipsis-vertical", &[Mini, Outline, Solid]),
+    ("envelope", &[Mini, Outline, Solid]),
+    ("envelope-open", &[Mini, Outline, Solid]),
+    ("exclamation-circle", &[Mini, Outline, Solid]),
+    ("exclamation-triangle", &[Mini, Outline, Solid]),
+    ("eye", &[Mini, Outline, Solid]),
+    ("eye-dropper", &[Mini, Outline, Solid]),
+    ("eye-slash", &[Mini, Outline, Solid]),
+    ("face-frown", &[Mini, Outline, Solid]),
+    ("face-smile", &[Mini, Outline, Solid]),
+    ("film", &[Mini, Outline, Solid]),
+    ("finger-print", &[Mini, Outline, Solid]),
+    ("fire", &[Mini, Outline, Solid]),
+    ("flag", &[Mini, Outline, Solid]),
+    ("folder", &[Mini, Outline, Solid]),
+    ("folder-arrow-down", &[Mini, Outline, Solid]),
+    ("folder-minus", &[Mini, Outline, Solid]),
+    ("folder-open", &[Mini, Outline, Solid]),
+    ("folder-plus", &[Mini, Outline, Solid]),
+    ("forward", &[Mini, Outline, Solid]),
+    ("funnel", &[Mini, Outline, Solid]),
+    ("gif", &[Mini, Outline, Solid]),
+    ("gift", &[Mini, Outline, Solid]),
+    ("gift-top", &[Mini, Outline, Solid]),
+    ("globe-alt", &[Mini, Outline, Solid]),
+    ("globe-americas", &[Mini, Outline, Solid]),
+    ("globe-asia-australia", &[Mini, Outline, Solid]),
+    ("globe-europe-africa", &[Mini, Outline, Solid]),
+    ("hand-raised", &[Mini, Outline, Solid]),
+    ("hand-thumb-down", &[Mini, Outline, Solid]),
+    ("hand-thumb-up", &[Mini, Outline, Solid]),
+    ("hashtag", &[Mini, Outline, Solid]),
+    ("heart", &[Mini, Outline, Solid]),
+    ("home", &[Mini, Outline, Solid]),
+    ("home-modern", &[Mini, Outline, Solid]),
+    ("identification", &[Mini, Outline, Solid]),
+    ("inbox", &[Mini, Outline, Solid]),
+    ("inbox-arrow-down", &[Mini, Outline, Solid]),
+    ("inbox-stack", &[Mini, Outline, Solid]),
+    ("information-circle", &[Mini, Outline, Solid]),
+    ("key", &[Mini, Outline, Solid]),
+    ("language", &[Mini, Outline, Solid]),
+    ("lifebuoy", &[Mini, Outline, Solid]),
+    ("light-bulb", &[Mini, Outline, Solid]),
+    ("link", &[Mini, Outline, Solid]),
+    ("list-bullet", &[Mini, Outline, Solid]),
+    ("lock-closed", &[Mini, Outline, Solid]),
+    ("lock-open", &[Mini, Outline, Solid]),
+    ("magnifying-glass", &[Mini, Outline, Solid]),
+    ("magnifying-glass-circle", &[Mini, Outline, Solid]),
+    ("magnifying-glass-minus", &[Mini, Outline, Solid]),
+    ("magnifying-glass-plus", &[Mini, Outline, Solid]),
+    ("map", &[Mini, Outline, Solid]),
+    ("map-pin", &[Mini, Outline, Solid]),
+    ("megaphone", &[Mini, Outline, Solid]),
+    ("microphone", &[Mini, Outline, Solid]),
+    ("minus", &[Mini, Outline, Solid]),
+    ("minus-circle", &[Mini, Outline, Solid]),
+    ("minus-small", &[Mini, Outline, Solid]),
+    ("moon", &[Mini, Outline, Solid]),
+    ("musical-note", &[Mini, Outline, Solid]),
+    ("newspaper", &[Mini, Outline, Solid]),
+    ("no-symbol", &[Mini, Outline, Solid]),
+    ("paint-brush", &[Mini, Outline, Solid]),
+    ("paper-airplane", &[Mini, Outline, Solid]),
+    ("paper-clip", &[Mini, Outline, Solid]),
+    ("pause", &[Mini, Outline, Solid]),
+    ("pause-circle", &[Mini, Outline, Solid]),
+    ("pencil", &[Mini, Outline, Solid]),
+    ("pencil-square", &[Mini, Outline, Solid]),
+    ("phone", &[Mini, Outline, Solid]),
+    ("phone-arrow-down-left", &[Mini, Outline, Solid]),
+    ("phone-arrow-up-right", &[Mini, Outline, Solid]),
+    ("phone-x-mark", &[Mini, Outline, Solid]),
+    ("photo", &[Mini, Outline, Solid]),
+    ("play", &[Mini, Outline, Solid]),
+    ("play-circle", &[Mini, Outline, Solid]),
+    ("play-pause", &[Mini, Outline, Solid]),
+    ("plus", &[Mini, Outline, Solid]),
+    ("plus-circle", &[Mini, Outline, Solid]),
+    ("plus-small", &[Mini, Outline, Solid]),
+    ("power", &[Mini, Outline, Solid]),
+    ("presentation-chart-bar", &[Mini, Outline, Solid]),
+    ("presentation-chart-line", &[Mini, Outline, Solid]),
+    ("printer", &[Mini, Outline, Solid]),
+    ("puzzle-piece", &[Mini, Outline, Solid]),
+    ("qr-code", &[Mini, Outline, Solid]),
+    ("question-mark-circle", &[Mini, Outline, Solid]),
+    ("queue-list", &[Mini, Outline, Solid]),
+    ("radio", &[Mini, Outline, Solid]),
+    ("receipt-percent", &[Mini, Outline, Solid]),
+    ("receipt-refund", &[Mini, Outline, Solid]),
+    ("rectangle-group", &[Mini, Outline, Solid]),
+    ("rectangle-stack", &[Mini, Outline, Solid]),
+    ("rocket-launch", &[Mini, Outline, Solid]),
+    ("rss", &[Mini, Outline, Solid]),
+    ("scale", &[Mini, Outline, Solid]),
+    ("scissors", &[Mini, Outline, Solid]),
+    ("server", &[Mini, Outline, Solid]),
+    ("server-stack", &[Mini, Outline, Solid]),
+    ("share", &[Mini, Outline, Solid]),
+    ("shield-check", &[Mini, Outline, Solid]),
+    ("shield-exclamation", &[Mini, Outline, Solid]),
+    ("shopping-bag", &[Mini, Outline, Solid]),
+    ("shopping-cart", &[Mini, Outline, Solid]),
+    ("signal", &[Mini, Outline, Solid]),
+    ("signal-slash", &[Mini, Outline, Solid]),
+    ("sparkles", &[Mini, Outline, Solid]),
+    ("speaker-wave", &[Mini, Outline, Solid]),
+    ("speaker-x-mark", &[Mini, Outline, Solid]),
+    ("square-2-stack", &[Mini, Outline, Solid]),
+    ("square-3-stack-3d", &[Mini, Outline, Solid]),
+    ("squares-2x2", &[Mini, Outline, Solid]),
+    ("squares-plus", &[Mini, Outline, Solid]),
+    ("star", &[Mini, Outline, Solid]),
+    ("stop", &[Mini, Outline, Solid]),
+    ("stop-circle", &[Mini, Outline, Solid]),
+    ("sun", &[Mini, Outline, Solid]),
+    ("swatch", &[Mini, Outline, Solid]),
+    ("table-cells", &[Mini, Outline, Solid]),
+    ("tag", &[Mini, Outline, Solid]),
+    ("ticket", &[Mini, Outline, Solid]),
+    ("trash", &[Mini, Outline, Solid]),
+    ("trophy", &[Mini, Outline, Solid]),
+    ("truck", &[Mini, Outline, Solid]),
+    ("tv", &[Mini, Outline, Solid]),
+    ("user", &[Mini, Outline, Solid]),
+    ("user-circle", &[Mini, Outline, Solid]),
+    ("user-group", &[Mini, Outline, Solid]),
+    ("user-minus", &[Mini, Outline, Solid]),
+    ("user-plus", &[Mini, Outline, Solid]),
+    ("users", &[Mini, Outline, Solid]),
+    ("variable", &[Mini, Outline, Solid]),
+    ("video-camera", &[Mini, Outline, Solid]),
+    ("video-camera-slash", &[Mini, Outline, Solid]),
+    ("view-columns", &[Mini, Outline, Solid]),
+    ("viewfinder-circle", &[Mini, Outline, Solid]),
+    ("wallet", &[Mini, Outline, Solid]),
+    ("wifi", &[Mini, Outline, Solid]),
+    ("window", &[Mini, Outline, Solid]),
+    ("wrench", &[Mini, Outline, Solid]),
+    ("wrench-screwdriver", &[Mini, Outline, Solid]),
+    ("x-circle", &[Mini, Outline, Solid]),
+    ("x-mark", &[Mini, Outline, Solid]),
+];
+
+/// Returns the styles that heroicons has an icon in, in the order of [`ShapeStyle`], or an empty
+/// slice if it doesn't have an icon with that name.
+#[must_use]
+pub fn variants(name: &str) -> &'static [ShapeStyle] {
+    VARIANTS
+        .binary_search_by(|(n, _)| (*n).cmp(name))
+        .map_or(&[], |i| VARIANTS[i].1)
+}
+
+/// Returns true if heroicons has an icon in a style. The shape might still not be compiled in, if
+/// its style or icon feature is off.
+#[must_use]
+pub fn available_in(name: &str, style: ShapeStyle) -> bool {
+    variants(name).contains(&style)
+}
+
+/// Returns `style` if heroicons has an icon in it, or else the first style that has the icon, so
+/// that an app which lets users pick a style can fall back to another one for an icon that's
+/// missing from theirs. Returns `None` if there's no icon with that name.
+#[must_use]
+pub fn style_or_fallback(name: &str, style: ShapeStyle) -> Option<ShapeStyle> {
+    let variants = variants(name);
+    if variants.contains(&style) {
+        Some(style)
+    } else {
+        variants.first().copied()
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{mini, IconButton};
+use dioxus::prelude::*;
+
+/// The properties for the [`PaginationNav`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct PaginationNavProps {
+    /// The current page, starting from 1.
+    pub page: usize,
+    /// The number of pages.
+    pub page_count: usize,
+    /// The handler that is called with the new page when a button is clicked.
+    pub onchange: EventHandler<usize>,
+    /// If this is true then there are also buttons for the first and last pages. This defaults to
+    /// true.
+    #[props(default = true)]
+    pub show_edges: bool,
+    /// If this is true then the current page and the page count are shown between the buttons,
+    /// like "Page 2 of 10". This defaults to true.
+    #[props(default = true)]
+    pub show_count: bool,
+    /// The accessible label for the `<nav>`. This defaults to "Pagination".
+    #[props(default = "Pagination".to_string())]
+    pub label: String,
+    /// The accessible label for the first page button. This defaults to "First page".
+    #[props(default = "First page".to_string())]
+    pub first_label: String,
+    /// The accessible label for the previous page button. This defaults to "Previous page".
+    #[props(default = "Previous page".to_string())]
+    pub previous_label: String,
+    /// The accessible label for the next page button. This defaults to "Next page".
+    #[props(default = "Next page".to_string())]
+    pub next_label: String,
+    /// The accessible label for the last page button. This defaults to "Last page".
+    #[props(default = "Last page".to_string())]
+    pub last_label: String,
+    /// An optional class for the `<nav>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for the buttons.
+    #[props(default, strip_option)]
+    pub button_class: Option<String>,
+    /// The size of the icons. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+}
+
+/// Renders previous and next page buttons, with optional first and last page buttons.
+///
+/// The buttons use [`mini::Shape::ChevronLeft`] and [`mini::Shape::ChevronRight`], and the first
+/// and last page buttons use [`mini::Shape::ChevronDoubleLeft`] and
+/// [`mini::Shape::ChevronDoubleRight`]. The buttons that would go past the first or last page are
+/// disabled. The `page` is clamped between 1 and `page_count`, and a `page_count` of 0 is treated
+/// as a single page.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <nav aria-label="Pagination" style="display: inline-flex; align-items: center;">
+///   <button title="First page" type="button" disabled="true">...</button>
+///   <button title="Previous page" type="button" disabled="true">...</button>
+///   <span aria-live="polite">Page 1 of 10</span>
+///   <button title="Next page" type="button">...</button>
+///   <button title="Last page" type="button">...</button>
+/// </nav>
+/// ```
+///
+/// See the [`PaginationNavProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn PaginationNav(props: PaginationNavProps) -> Element {
+    let page_count = props.page_count.max(1);
+    let page = clamp_page(props.page, page_count);
+    let onchange = props.onchange;
+    let button_class = props.button_class;
+    let size = props.size;
+    let button = |to: usize, label: String, icon: mini::Shape| {
+        rsx! {
+            IconButton {
+                onclick: move |_| onchange.call(to),
+                class: button_class.clone(),
+                title: label.clone(),
+                size,
+                disabled: to == page,
+                icon,
+                sr_only: label,
+                r#type: "button",
+            }
+        }
+    };
+    let previous = clamp_page(page - 1, page_count);
+    let next = clamp_page(page + 1, page_count);
+
+    rsx! {
+        nav {
+            aria_label: props.label,
+            class: if let Some(class) = props.class { class },
+            style: "display: inline-flex; align-items: center;",
+            if props.show_edges {
+                { button(1, props.first_label, mini::Shape::ChevronDoubleLeft) },
+            }
+            { button(previous, props.previous_label, mini::Shape::ChevronLeft) },
+            if props.show_count {
+                span {
+                    aria_live: "polite",
+                    "Page {page} of {page_count}"
+                },
+            }
+            { button(next, props.next_label, mini::Shape::ChevronRight) },
+            if props.show_edges {
+                { button(page_count, props.last_label, mini::Shape::ChevronDoubleRight) },
+            }
+        }
+    }
+}
+
+/// Returns `page` clamped between 1 and `page_count`.
+fn clamp_page(page: usize, page_count: usize) -> usize {
+    page.clamp(1, page_count.max(1))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, DISABLED_FILL_COLOR, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn pagination_nav_last_page() {
+        #[allow(non_snake_case)]
+        fn Pages() -> Element {
+            rsx! {
+                PaginationNav {
+                    page: 4,
+                    page_count: 3,
+                    onchange: |_| {},
+                    show_edges: false,
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Pages {},
+            },
+            rsx! {
+                nav {
+                    aria_label: "Pagination",
+                    style: "display: inline-flex; align-items: center;",
+                    button {
+                        class: FOCUS_CLASS,
+                        title: "Previous page",
+                        r#type: "button",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::ChevronLeft.path() },
+                        },
+                        span {
+                            style: SR_ONLY_STYLE,
+                            "Previous page"
+                        },
+                    },
+                    span {
+                        aria_live: "polite",
+                        "Page 3 of 3"
+                    },
+                    button {
+                        class: FOCUS_CLASS,
+                        title: "Next page",
+                        disabled: true,
+                        r#type: "button",
+                        svg {
+                            height: 20,
+                            width: 20,
+                            view_box: mini::VIEW_BOX,
+                            fill: DISABLED_FILL_COLOR,
+                            { mini::Shape::ChevronRight.path() },
+                        },
+                        span {
+                            style: SR_ONLY_STYLE,
+                            "Next page"
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn page_clamping() {
+        assert_eq!(clamp_page(0, 5), 1);
+        assert_eq!(clamp_page(3, 5), 3);
+        assert_eq!(clamp_page(9, 5), 5);
+        assert_eq!(clamp_page(2, 0), 1);
+    }
+}
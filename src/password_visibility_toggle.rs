@@ -0,0 +1,161 @@
+use crate::{outline, ToggleIconButton};
+use dioxus::prelude::*;
+
+/// The properties for the [`PasswordVisibilityToggle`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct PasswordVisibilityToggleProps {
+    /// Whether the password is visible. If this is set then the toggle is controlled, and it will
+    /// only change state when you change this value, usually in response to `onchange`. If this is
+    /// not set then the toggle keeps track of its own state.
+    #[props(default, strip_option)]
+    pub visible: Option<bool>,
+    /// The initial state of the toggle when `visible` is not set. This defaults to false.
+    #[props(default = false)]
+    pub default_visible: bool,
+    /// A signal to bind the state to. If this is set then the toggle shows the signal's value, and
+    /// clicking it sets the signal to the new state before calling `onchange`. This takes
+    /// precedence over `visible`.
+    #[props(default, strip_option)]
+    pub bind: Option<Signal<bool>>,
+    /// An optional handler that is called with the new state when the toggle is clicked. Use this
+    /// to switch the password input's `type` between "password" and "text".
+    #[props(default, strip_option)]
+    pub onchange: Option<EventHandler<bool>>,
+    /// The `id` of the password input, which the toggle refers to with `aria-controls`.
+    #[props(default, strip_option)]
+    pub controls: Option<String>,
+    /// The accessible label for the toggle. This is also used as its title. This defaults to "Show
+    /// password".
+    #[props(default = "Show password".to_string())]
+    pub label: String,
+    /// An optional class for the button.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// If this is true then the toggle is disabled. This defaults to false.
+    #[props(default = false)]
+    pub disabled: bool,
+}
+
+/// Renders an eye toggle button for showing and hiding a password.
+///
+/// This is a [`ToggleIconButton`] that shows [`outline::Shape::Eye`] while the password is hidden
+/// and [`outline::Shape::EyeSlash`] while it's visible. Its label stays the same, and its state is
+/// exposed with `aria-pressed`, so a screen reader announces something like "Show password, toggle
+/// button, pressed". The toggle doesn't change the input itself, so you need to use its state to set
+/// the input's `type`, for example:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_heroicons::PasswordVisibilityToggle;
+///
+/// fn PasswordField() -> Element {
+///     let visible = use_signal(|| false);
+///     rsx! {
+///         input {
+///             id: "password",
+///             r#type: if visible() { "text" } else { "password" },
+///         }
+///         PasswordVisibilityToggle {
+///             bind: visible,
+///             controls: "password",
+///         }
+///     }
+/// }
+/// ```
+///
+/// See the [`PasswordVisibilityToggleProps`] field documentation for details on the properties it
+/// accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn PasswordVisibilityToggle(props: PasswordVisibilityToggleProps) -> Element {
+    rsx! {
+        ToggleIconButton {
+            icon_on: outline::Shape::EyeSlash,
+            icon_off: outline::Shape::Eye,
+            pressed: props.visible,
+            default_pressed: props.default_visible,
+            bind: props.bind,
+            onchange: props.onchange,
+            class: props.class,
+            title: props.label.clone(),
+            size: props.size,
+            disabled: props.disabled,
+            sr_only: props.label,
+            r#type: "button",
+            aria_controls: props.controls,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn password_visibility_toggle() {
+        assert_rsx_eq(
+            rsx! {
+                PasswordVisibilityToggle {
+                    controls: "password",
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    title: "Show password",
+                    aria_pressed: "false",
+                    r#type: "button",
+                    aria_controls: "password",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Eye.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Show password"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn password_visibility_toggle_visible() {
+        assert_rsx_eq(
+            rsx! {
+                PasswordVisibilityToggle {
+                    visible: true,
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    title: "Show password",
+                    aria_pressed: "true",
+                    r#type: "button",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::EyeSlash.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Show password"
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{solid, IconButton};
+use dioxus::prelude::*;
+
+/// The state of a media player.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MediaState {
+    /// The media is playing.
+    Playing,
+    /// The media is paused partway through.
+    Paused,
+    /// The media is stopped, so it will play from the start.
+    #[default]
+    Stopped,
+}
+
+/// The properties for the [`PlayPauseButton`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct PlayPauseButtonProps {
+    /// The current state of the media.
+    pub state: MediaState,
+    /// The handler that is called with the new state when a button is clicked.
+    pub onchange: EventHandler<MediaState>,
+    /// If this is true then there is also a stop button, which is disabled while the media is
+    /// stopped. This defaults to false.
+    #[props(default = false)]
+    pub show_stop: bool,
+    /// The accessible label for the button when clicking it would start playing. This defaults to
+    /// "Play".
+    #[props(default = "Play".to_string())]
+    pub play_label: String,
+    /// The accessible label for the button when clicking it would pause. This defaults to "Pause".
+    #[props(default = "Pause".to_string())]
+    pub pause_label: String,
+    /// The accessible label for the stop button. This defaults to "Stop".
+    #[props(default = "Stop".to_string())]
+    pub stop_label: String,
+    /// An optional class for the buttons.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icons. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+}
+
+/// Renders a play/pause button for a media player, with an optional stop button.
+///
+/// The button shows the action that clicking it would take, so it's a [`solid::Shape::Play`] with
+/// the `play_label` unless the media is playing, and a [`solid::Shape::Pause`] with the
+/// `pause_label` while it is. These are native `<button>` elements, so `Space` and `Enter` work as
+/// you would expect.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button title="Play" type="button">
+///   <svg ...>
+///   <span style="...">Play</span>
+/// </button>
+/// <button title="Stop" type="button" disabled="true">
+///   <svg ...>
+///   <span style="...">Stop</span>
+/// </button>
+/// ```
+///
+/// See the [`PlayPauseButtonProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn PlayPauseButton(props: PlayPauseButtonProps) -> Element {
+    let state = props.state;
+    let onchange = props.onchange;
+    let playing = state == MediaState::Playing;
+    let label = if playing {
+        props.pause_label
+    } else {
+        props.play_label
+    };
+    rsx! {
+        IconButton {
+            onclick: move |_| {
+                onchange.call(if playing { MediaState::Paused } else { MediaState::Playing });
+            },
+            class: props.class.clone(),
+            title: label.clone(),
+            size: props.size,
+            icon: if playing { solid::Shape::Pause } else { solid::Shape::Play },
+            sr_only: label,
+            r#type: "button",
+        },
+        if props.show_stop {
+            IconButton {
+                onclick: move |_| onchange.call(MediaState::Stopped),
+                class: props.class,
+                title: props.stop_label.clone(),
+                size: props.size,
+                disabled: state == MediaState::Stopped,
+                icon: solid::Shape::Stop,
+                sr_only: props.stop_label,
+                r#type: "button",
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, DISABLED_FILL_COLOR, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn play_pause_button_playing() {
+        #[allow(non_snake_case)]
+        fn Player() -> Element {
+            rsx! {
+                PlayPauseButton {
+                    state: MediaState::Playing,
+                    onchange: |_| {},
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Player {},
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    title: "Pause",
+                    r#type: "button",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: solid::VIEW_BOX,
+                        fill: "currentColor",
+                        { solid::Shape::Pause.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Pause"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn play_pause_button_stopped_with_stop() {
+        #[allow(non_snake_case)]
+        fn Player() -> Element {
+            rsx! {
+                PlayPauseButton {
+                    state: MediaState::Stopped,
+                    onchange: |_| {},
+                    show_stop: true,
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Player {},
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    title: "Play",
+                    r#type: "button",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: solid::VIEW_BOX,
+                        fill: "currentColor",
+                        { solid::Shape::Play.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Play"
+                    },
+                },
+                button {
+                    class: FOCUS_CLASS,
+                    title: "Stop",
+                    disabled: true,
+                    r#type: "button",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: solid::VIEW_BOX,
+                        fill: DISABLED_FILL_COLOR,
+                        { solid::Shape::Stop.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Stop"
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use crate::theme::use_icon_theme;
+use dioxus::prelude::*;
+use std::f32::consts::PI;
+
+/// The radius of the ring, in the same 24 unit coordinate space as the outline heroicons.
+const RADIUS: f32 = 10.0;
+
+/// The properties for the [`ProgressRing`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct ProgressRingProps {
+    /// The progress, from 0.0 to 1.0. If this is not set then the ring is indeterminate, and shows
+    /// a spinning arc instead.
+    #[props(default, strip_option)]
+    pub value: Option<f32>,
+    /// The accessible label for the ring. This defaults to "Progress".
+    #[props(default = "Progress".to_string())]
+    pub label: String,
+    /// An optional class for the outer `<span>`. The ring is drawn with "currentColor", so this is
+    /// where its color comes from.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the ring. This defaults to 20 pixels, like [`Icon`](crate::Icon).
+    #[props(default = 20)]
+    pub size: u32,
+    /// The width of the ring's stroke, in the same 24 unit coordinate space as the outline
+    /// heroicons. This defaults to 2.
+    #[props(default = 2.0)]
+    pub stroke_width: f32,
+    /// Anything to show in the center of the ring, usually a small [`Icon`](crate::Icon) like a
+    /// check when the progress is complete.
+    pub children: Element,
+}
+
+/// Renders a circular progress indicator, with an optional icon in its center.
+///
+/// The ring is an `<svg>` with a faded track and an arc for the progress, both drawn with
+/// "currentColor". When `value` is not set, the ring is indeterminate, and its arc spins using the
+/// `spinner_class` from the [`IconTheme`](crate::IconTheme). For example, this shows a check once
+/// an upload finishes:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_heroicons::{mini, Icon, ProgressRing};
+///
+/// fn Upload(progress: f32) -> Element {
+///     rsx! {
+///         ProgressRing {
+///             value: progress,
+///             size: 32,
+///             if progress >= 1.0 {
+///                 Icon { icon: mini::Shape::Check, size: 16 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span role="progressbar" aria-label="Progress" aria-valuemin="0" aria-valuemax="100"
+///       aria-valuenow="40" style="...">
+///   <svg ...>
+///     <circle ...>
+///     <circle ...>
+///   </svg>
+///   <span style="position: absolute; display: inline-flex;">
+///     Center content goes here
+///   </span>
+/// </span>
+/// ```
+///
+/// See the [`ProgressRingProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn ProgressRing(props: ProgressRingProps) -> Element {
+    let theme = use_icon_theme();
+    let value = props.value.map(|v| v.clamp(0.0, 1.0));
+    let circumference = 2.0 * PI * RADIUS;
+    let (dash_array, dash_offset) = match value {
+        Some(v) => (
+            format!("{circumference:.2}"),
+            format!("{:.2}", circumference * (1.0 - v)),
+        ),
+        None => (
+            format!("{:.2} {circumference:.2}", circumference / 4.0),
+            "0".to_string(),
+        ),
+    };
+    let size = props.size;
+    let stroke_width = props.stroke_width;
+    rsx! {
+        span {
+            role: "progressbar",
+            aria_label: props.label,
+            aria_valuemin: if value.is_some() { Some(0) } else { None },
+            aria_valuemax: if value.is_some() { Some(100) } else { None },
+            aria_valuenow: value.map(percent),
+            class: if let Some(class) = props.class { class },
+            style: "position: relative; display: inline-flex; align-items: center; justify-content: center;",
+            svg {
+                class: if value.is_none() { Some(theme.spinner_class.as_str()) } else { None },
+                height: format_args!("{size}"),
+                width: format_args!("{size}"),
+                view_box: "0 0 24 24",
+                fill: "none",
+                circle {
+                    cx: 12,
+                    cy: 12,
+                    r: RADIUS,
+                    stroke: "currentColor",
+                    stroke_opacity: 0.25,
+                    stroke_width,
+                },
+                circle {
+                    cx: 12,
+                    cy: 12,
+                    r: RADIUS,
+                    stroke: "currentColor",
+                    stroke_width,
+                    stroke_linecap: "round",
+                    stroke_dasharray: dash_array,
+                    stroke_dashoffset: dash_offset,
+                    transform: "rotate(-90 12 12)",
+                },
+            },
+            if props.children != VNode::empty() {
+                span {
+                    style: "position: absolute; display: inline-flex;",
+                    { props.children }
+                },
+            }
+        },
+    }
+}
+
+/// Returns a progress fraction as a whole percentage.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn percent(value: f32) -> u8 {
+    (value * 100.0).round() as u8
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mini, test::assert_rsx_eq, Icon, IconShape};
+
+    const RING_STYLE: &str =
+        "position: relative; display: inline-flex; align-items: center; justify-content: center;";
+
+    #[test]
+    fn progress_ring_determinate() {
+        assert_rsx_eq(
+            rsx! {
+                ProgressRing {
+                    value: 1.5,
+                    size: 32,
+                    Icon {
+                        size: 16,
+                        icon: mini::Shape::Check,
+                    },
+                },
+            },
+            rsx! {
+                span {
+                    role: "progressbar",
+                    aria_label: "Progress",
+                    aria_valuemin: 0,
+                    aria_valuemax: 100,
+                    aria_valuenow: 100,
+                    style: RING_STYLE,
+                    svg {
+                        height: 32,
+                        width: 32,
+                        view_box: "0 0 24 24",
+                        fill: "none",
+                        circle {
+                            cx: 12,
+                            cy: 12,
+                            r: 10,
+                            stroke: "currentColor",
+                            stroke_opacity: 0.25,
+                            stroke_width: 2,
+                        },
+                        circle {
+                            cx: 12,
+                            cy: 12,
+                            r: 10,
+                            stroke: "currentColor",
+                            stroke_width: 2,
+                            stroke_linecap: "round",
+                            stroke_dasharray: "62.83",
+                            stroke_dashoffset: "0.00",
+                            transform: "rotate(-90 12 12)",
+                        },
+                    },
+                    span {
+                        style: "position: absolute; display: inline-flex;",
+                        svg {
+                            height: 16,
+                            width: 16,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::Check.path() },
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn progress_ring_indeterminate() {
+        assert_rsx_eq(
+            rsx! {
+                ProgressRing {
+                    label: "Saving",
+                },
+            },
+            rsx! {
+                span {
+                    role: "progressbar",
+                    aria_label: "Saving",
+                    style: RING_STYLE,
+                    svg {
+                        class: "animate-spin",
+                        height: 20,
+                        width: 20,
+                        view_box: "0 0 24 24",
+                        fill: "none",
+                        circle {
+                            cx: 12,
+                            cy: 12,
+                            r: 10,
+                            stroke: "currentColor",
+                            stroke_opacity: 0.25,
+                            stroke_width: 2,
+                        },
+                        circle {
+                            cx: 12,
+                            cy: 12,
+                            r: 10,
+                            stroke: "currentColor",
+                            stroke_width: 2,
+                            stroke_linecap: "round",
+                            stroke_dasharray: "15.71 62.83",
+                            stroke_dashoffset: "0",
+                            transform: "rotate(-90 12 12)",
+                        },
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{solid, Icon};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`Rating`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct RatingProps {
+    /// The current rating, from 0 to `max`.
+    pub value: f32,
+    /// The number of stars. This defaults to 5.
+    #[props(default = 5)]
+    pub max: u8,
+    /// If this is true then the rating can be a half star, both when it's displayed and when it's
+    /// changed. Otherwise the rating is rounded to a whole star. This defaults to false.
+    #[props(default = false)]
+    pub half: bool,
+    /// If this is true then the rating can't be changed. This defaults to false.
+    #[props(default = false)]
+    pub readonly: bool,
+    /// An optional handler that is called with the new rating when the user clicks a star or uses
+    /// the keyboard to change the rating. It isn't called if the rating stays the same.
+    #[props(default, strip_option)]
+    pub onchange: Option<EventHandler<f32>>,
+    /// An optional class for the outer `<span>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of each star. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color for the filled part of the rating. This defaults to "#FACC15", which is
+    /// "yellow 400" from tailwindcss.
+    #[props(into, default = "#FACC15")]
+    pub fill_on: Cow<'static, str>,
+    /// The fill color for the empty part of the rating. This defaults to "#D1D5DB", which is
+    /// "gray 300" from tailwindcss.
+    #[props(into, default = "#D1D5DB")]
+    pub fill_off: Cow<'static, str>,
+    /// The accessible label for the rating. This defaults to "Rating".
+    #[props(default = "Rating".to_string())]
+    pub label: String,
+}
+
+/// Renders a star rating.
+///
+/// Each star is a [`solid::Shape::Star`], filled with `fill_on` or `fill_off`. A half star is an
+/// empty star with the left half of a filled star on top of it.
+///
+/// Unless it's `readonly`, the rating is a `slider` that can be changed by clicking a star, or with
+/// the arrow keys, `Home`, and `End`. When `half` is true, clicking the left half of a star sets a
+/// half-star rating.
+///
+/// See the [`RatingProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn Rating(props: RatingProps) -> Element {
+    let max = props.max;
+    let step = if props.half { 0.5 } else { 1.0 };
+    let value = round_to_step(props.value.clamp(0.0, f32::from(max)), step);
+    let readonly = props.readonly;
+    let onchange = props.onchange;
+    let size = props.size;
+    // The values are always whole or half stars, so they're exactly equal when a click or key
+    // doesn't change the rating, like pressing `End` when it's already at `max`.
+    #[allow(clippy::float_cmp)]
+    let change = move |new: f32| {
+        if new == value {
+            return;
+        }
+        if let Some(oc) = onchange {
+            oc.call(new);
+        }
+    };
+    let value_text = format!("{value} out of {max}");
+    let fill_on = props.fill_on;
+    let fill_off = props.fill_off;
+
+    rsx! {
+        span {
+            class: if let Some(class) = props.class { class },
+            style: "display: inline-flex;",
+            role: if readonly { "img" } else { "slider" },
+            aria_label: if readonly {
+                format!("{}: {value_text}", props.label)
+            } else {
+                props.label
+            },
+            aria_valuemin: if !readonly { Some(0) } else { None },
+            aria_valuemax: if !readonly { Some(max) } else { None },
+            aria_valuenow: if !readonly { Some(value) } else { None },
+            aria_valuetext: if !readonly { Some(value_text) } else { None },
+            tabindex: if !readonly { Some(0) } else { None },
+            onkeydown: move |evt| {
+                if readonly {
+                    return;
+                }
+                if let Some(new) = key_value(value, max, step, &evt.key()) {
+                    evt.prevent_default();
+                    change(new);
+                }
+            },
+            for i in 0..max {
+                span {
+                    key: "{i}",
+                    style: "position: relative; display: inline-block;",
+                    onclick: move |evt| {
+                        if readonly {
+                            return;
+                        }
+                        let left_half = evt.element_coordinates().x < f64::from(size) / 2.0;
+                        change(if step < 1.0 && left_half {
+                            f32::from(i) + 0.5
+                        } else {
+                            f32::from(i) + 1.0
+                        });
+                    },
+                    Icon {
+                        size,
+                        fill: if value >= f32::from(i) + 1.0 { fill_on.clone() } else { fill_off.clone() },
+                        icon: solid::Shape::Star,
+                    },
+                    if value > f32::from(i) && value < f32::from(i) + 1.0 {
+                        span {
+                            style: "position: absolute; top: 0; left: 0; width: 50%; overflow: hidden;",
+                            Icon {
+                                size,
+                                fill: fill_on.clone(),
+                                icon: solid::Shape::Star,
+                            },
+                        },
+                    }
+                },
+            }
+        },
+    }
+}
+
+fn round_to_step(value: f32, step: f32) -> f32 {
+    (value / step).round() * step
+}
+
+/// Returns the new rating for a key press, or `None` if it's not one of the keys that changes the
+/// rating. The new rating is the same as the old one when the key would move it past either end.
+fn key_value(value: f32, max: u8, step: f32, key: &Key) -> Option<f32> {
+    let new = match key {
+        Key::ArrowRight | Key::ArrowUp => value + step,
+        Key::ArrowLeft | Key::ArrowDown => value - step,
+        Key::Home => 0.0,
+        Key::End => f32::from(max),
+        _ => return None,
+    };
+    Some(new.clamp(0.0, f32::from(max)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::assert_rsx_eq, IconShape};
+
+    #[test]
+    fn rating_readonly_half() {
+        let star = |fill: &'static str| {
+            rsx! {
+                svg {
+                    height: 20,
+                    width: 20,
+                    view_box: solid::VIEW_BOX,
+                    fill,
+                    { solid::Shape::Star.path() },
+                }
+            }
+        };
+        assert_rsx_eq(
+            rsx! {
+                Rating {
+                    value: 1.6,
+                    max: 3,
+                    half: true,
+                    readonly: true,
+                },
+            },
+            rsx! {
+                span {
+                    style: "display: inline-flex;",
+                    role: "img",
+                    aria_label: "Rating: 1.5 out of 3",
+                    span {
+                        style: "position: relative; display: inline-block;",
+                        { star("#FACC15") },
+                    },
+                    span {
+                        style: "position: relative; display: inline-block;",
+                        { star("#D1D5DB") },
+                        span {
+                            style: "position: absolute; top: 0; left: 0; width: 50%; overflow: hidden;",
+                            { star("#FACC15") },
+                        },
+                    },
+                    span {
+                        style: "position: relative; display: inline-block;",
+                        { star("#D1D5DB") },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn rating_interactive() {
+        let star = |fill: &'static str| {
+            rsx! {
+                svg {
+                    height: 20,
+                    width: 20,
+                    view_box: solid::VIEW_BOX,
+                    fill,
+                    { solid::Shape::Star.path() },
+                }
+            }
+        };
+        assert_rsx_eq(
+            rsx! {
+                Rating {
+                    value: 1.6,
+                    max: 2,
+                },
+            },
+            rsx! {
+                span {
+                    style: "display: inline-flex;",
+                    role: "slider",
+                    aria_label: "Rating",
+                    aria_valuemin: 0,
+                    aria_valuemax: 2,
+                    aria_valuenow: 2,
+                    aria_valuetext: "2 out of 2",
+                    tabindex: 0,
+                    span {
+                        style: "position: relative; display: inline-block;",
+                        { star("#FACC15") },
+                    },
+                    span {
+                        style: "position: relative; display: inline-block;",
+                        { star("#FACC15") },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn rating_keys() {
+        assert_eq!(key_value(2.0, 5, 1.0, &Key::ArrowRight), Some(3.0));
+        assert_eq!(key_value(2.0, 5, 0.5, &Key::ArrowLeft), Some(1.5));
+        assert_eq!(key_value(5.0, 5, 1.0, &Key::ArrowUp), Some(5.0));
+        assert_eq!(key_value(0.0, 5, 1.0, &Key::ArrowDown), Some(0.0));
+        assert_eq!(key_value(2.0, 5, 1.0, &Key::Home), Some(0.0));
+        assert_eq!(key_value(2.0, 5, 1.0, &Key::End), Some(5.0));
+        assert_eq!(key_value(2.0, 5, 1.0, &Key::Enter), None);
+    }
+}
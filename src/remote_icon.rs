@@ -0,0 +1,437 @@
+use crate::{IconSet, PathData};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+/// The path data for a set of icon shapes, loaded at runtime instead of compiled into the app.
+///
+/// Each shape is stored under an `id` made from a prefix and the shape's heroicons name, like
+/// "heroicon-arrow-left", the same way as the `id`s in an [`IconSprite`](crate::IconSprite). Write
+/// a registry file with [`write_icon_registry`], serve it as a static file, and give its URL to an
+/// [`IconRegistryProvider`]. Then a [`RemoteIcon`] can show any shape in it by `id`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IconRegistry(BTreeMap<String, RegistryShape>);
+
+/// One shape in an [`IconRegistry`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct RegistryShape {
+    view_box: String,
+    paths: Vec<RegistryPath>,
+}
+
+/// One `<path>` element of a shape in an [`IconRegistry`], with the same attributes as a
+/// [`PathData`]. These are only ever rendered as attributes, never as HTML, so a registry can't
+/// add any other elements to the page.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct RegistryPath {
+    d: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    clip_rule: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fill_rule: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fill: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fill_opacity: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    opacity: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stroke_width: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stroke_linecap: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stroke_linejoin: Option<String>,
+}
+
+impl From<&PathData> for RegistryPath {
+    fn from(p: &PathData) -> Self {
+        let owned = |v: Option<&str>| v.map(str::to_string);
+        Self {
+            d: p.d.to_string(),
+            clip_rule: owned(p.clip_rule),
+            fill_rule: owned(p.fill_rule),
+            fill: owned(p.fill),
+            fill_opacity: owned(p.fill_opacity),
+            opacity: owned(p.opacity),
+            stroke_width: owned(p.stroke_width),
+            stroke_linecap: owned(p.stroke_linecap),
+            stroke_linejoin: owned(p.stroke_linejoin),
+        }
+    }
+}
+
+impl IconRegistry {
+    /// Creates a registry with the given shapes. The `id` for each shape is the prefix, a hyphen,
+    /// and the shape's heroicons name.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if one of the shapes doesn't have
+    /// [`paths`](crate::IconShape::paths).
+    pub fn new<S: IconSet>(shapes: &[S], prefix: &str) -> io::Result<Self> {
+        let mut registry = Self::default();
+        registry.add(shapes, prefix)?;
+        Ok(registry)
+    }
+
+    /// Adds more shapes to the registry, for example from another style with a different prefix.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if one of the shapes doesn't have
+    /// [`paths`](crate::IconShape::paths).
+    pub fn add<S: IconSet>(&mut self, shapes: &[S], prefix: &str) -> io::Result<()> {
+        for shape in shapes {
+            let paths = shape.paths().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("the {} shape has no path data", shape.name()),
+                )
+            })?;
+            self.0.insert(
+                format!("{prefix}-{}", shape.name()),
+                RegistryShape {
+                    view_box: shape.view_box().to_string(),
+                    paths: paths.iter().map(RegistryPath::from).collect(),
+                },
+            );
+        }
+        Ok(())
+    }
+
+    /// Parses a registry from the JSON written by [`write_icon_registry`] or
+    /// [`to_json`](IconRegistry::to_json).
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the JSON isn't a valid registry.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the registry as JSON.
+    ///
+    /// # Panics
+    ///
+    /// This can't actually panic, since a registry only contains strings, which can always be
+    /// serialized.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("an icon registry can always be serialized")
+    }
+
+    /// Returns true if the registry has a shape with the given `id`.
+    #[must_use]
+    pub fn contains(&self, id: &str) -> bool {
+        self.0.contains_key(id)
+    }
+
+    /// Returns the number of shapes in the registry.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the registry has no shapes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Writes an [`IconRegistry`] file with the given shapes, as JSON.
+///
+/// Like [`write_sprite_sheet`](crate::write_sprite_sheet), this is meant to be called from a
+/// `build.rs`. Writing the file to the app's `assets` directory lets an [`IconRegistryProvider`]
+/// load it with `asset!`, so that it's bundled and fingerprinted by Dioxus. For example:
+///
+/// ```rust,no_run
+/// use dioxus_heroicons::{outline, write_icon_registry, IconSet};
+///
+/// write_icon_registry(outline::Shape::all(), "heroicon", "assets/icons.json").unwrap();
+/// ```
+///
+/// # Errors
+///
+/// This returns an error if the file can't be written, or if one of the shapes doesn't have
+/// [`paths`](crate::IconShape::paths).
+pub fn write_icon_registry<S: IconSet>(
+    shapes: &[S],
+    prefix: &str,
+    file: impl AsRef<Path>,
+) -> io::Result<()> {
+    fs::write(file, IconRegistry::new(shapes, prefix)?.to_json())
+}
+
+/// The registry that an [`IconRegistryProvider`] shares with its [`RemoteIcon`]s. This is `None`
+/// until the registry is loaded.
+#[derive(Clone, Copy)]
+struct RegistryContext(Signal<Option<IconRegistry>>);
+
+/// The properties for the [`IconRegistryProvider`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct IconRegistryProviderProps {
+    /// The URL of the registry file, like one written by [`write_icon_registry`]. This can also be
+    /// an `Asset` from Dioxus' `asset!` macro, like `asset!("/assets/icons.json")`, so that the
+    /// registry is bundled with the app and its URL is fingerprinted.
+    ///
+    /// The registry's path data is only ever rendered as the attributes of `<path>` elements, so it
+    /// can't add scripts or other elements to the page, but it can still draw anything in place of
+    /// an icon. Only load a registry from a source that you trust, like your own app's assets.
+    #[props(into)]
+    pub url: String,
+    /// An optional registry to use right away instead of fetching `url`, for example when
+    /// rendering on the server.
+    #[props(default, strip_option)]
+    pub registry: Option<IconRegistry>,
+    /// An optional handler that is called with an error message if the registry can't be fetched
+    /// or parsed.
+    #[props(default, strip_option)]
+    pub onerror: Option<EventHandler<String>>,
+    /// The child elements, which can contain any number of [`RemoteIcon`]s.
+    pub children: Element,
+}
+
+/// Loads an [`IconRegistry`] for the [`RemoteIcon`]s inside it.
+///
+/// The registry is fetched from `url` after the provider is first rendered, so the icons' path data
+/// isn't part of the app's wasm bundle. Until it arrives, each [`RemoteIcon`] renders an empty
+/// placeholder `<svg>` of the same size. Put one provider near the root of the app, so that the
+/// registry is only fetched once.
+///
+/// Fetching the registry uses the browser's Fetch API, so it needs the `wasm` feature. Without
+/// that feature, the provider calls `onerror` unless it's given a `registry`.
+///
+/// This component doesn't render any HTML of its own, just its children.
+///
+/// See the [`IconRegistryProviderProps`] field documentation for details on the properties it
+/// accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn IconRegistryProvider(props: IconRegistryProviderProps) -> Element {
+    let initial = props.registry;
+    let preloaded = initial.is_some();
+    let mut registry = use_signal(|| initial);
+    use_context_provider(|| RegistryContext(registry));
+    let url = props.url;
+    let onerror = props.onerror;
+    use_hook(move || {
+        if !preloaded {
+            spawn(async move {
+                match fetch_registry(&url).await {
+                    Ok(loaded) => registry.set(Some(loaded)),
+                    Err(e) => {
+                        if let Some(oe) = onerror {
+                            oe.call(e);
+                        }
+                    }
+                }
+            });
+        }
+    });
+    rsx! {
+        { props.children }
+    }
+}
+
+/// The properties for the [`RemoteIcon`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct RemoteIconProps {
+    /// The `id` of the shape in the [`IconRegistry`], like "heroicon-arrow-left".
+    pub icon: String,
+    /// An optional class for the `<svg>` element.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the `<svg>` element. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The color to use for filling the icon. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+}
+
+/// Renders an icon from the [`IconRegistry`] loaded by the nearest [`IconRegistryProvider`].
+///
+/// Until the registry is loaded, or if the registry doesn't have the `icon`, this renders an empty
+/// `<svg>` with the same size, class, and fill, so the page's layout doesn't shift when the icon
+/// appears. There must be an [`IconRegistryProvider`] above this component, or it only ever
+/// renders the placeholder.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <svg height="20" width="20" viewBox="0 0 24 24" fill="currentColor">
+///   <path ...>
+/// </svg>
+/// ```
+///
+/// See the [`RemoteIconProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn RemoteIcon(props: RemoteIconProps) -> Element {
+    let registry = try_use_context::<RegistryContext>().map(|r| r.0);
+    let registry = registry.as_ref().map(Readable::read);
+    let shape = registry
+        .as_ref()
+        .and_then(|r| r.as_ref())
+        .and_then(|r| r.0.get(&props.icon));
+    let paths = shape.map_or(&[][..], |s| &s.paths);
+    rsx! {
+        svg {
+            class: if let Some(class) = props.class { class },
+            height: i64::from(props.size),
+            width: i64::from(props.size),
+            view_box: shape.map(|s| s.view_box.as_str()),
+            fill: &*props.fill,
+            for p in paths {
+                path {
+                    d: p.d.as_str(),
+                    clip_rule: p.clip_rule.as_deref(),
+                    fill_rule: p.fill_rule.as_deref(),
+                    fill: p.fill.as_deref(),
+                    fill_opacity: p.fill_opacity.as_deref(),
+                    opacity: p.opacity.as_deref(),
+                    stroke_width: p.stroke_width.as_deref(),
+                    stroke_linecap: p.stroke_linecap.as_deref(),
+                    stroke_linejoin: p.stroke_linejoin.as_deref(),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+async fn fetch_registry(url: &str) -> Result<IconRegistry, String> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let fetch_error = |e| format!("could not fetch the icon registry from {url}: {e:?}");
+    let window = web_sys::window().ok_or_else(|| "there is no browser window".to_string())?;
+    let response = JsFuture::from(window.fetch_with_str(url))
+        .await
+        .map_err(fetch_error)?
+        .dyn_into::<web_sys::Response>()
+        .map_err(fetch_error)?;
+    if !response.ok() {
+        return Err(format!(
+            "could not fetch the icon registry from {url}: the status was {}",
+            response.status(),
+        ));
+    }
+    let text = JsFuture::from(response.text().map_err(fetch_error)?)
+        .await
+        .map_err(fetch_error)?
+        .as_string()
+        .ok_or_else(|| format!("the icon registry from {url} is not text"))?;
+    IconRegistry::from_json(&text).map_err(|e| format!("could not parse the icon registry: {e}"))
+}
+
+#[cfg(not(feature = "wasm"))]
+#[allow(clippy::unused_async)]
+async fn fetch_registry(_url: &str) -> Result<IconRegistry, String> {
+    Err("fetching the icon registry requires the wasm feature".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{mini, outline, test::assert_rsx_eq, IconShape};
+
+    #[test]
+    fn icon_registry() {
+        let mut registry = IconRegistry::new(&[outline::Shape::Folder], "outline").unwrap();
+        registry.add(&[mini::Shape::Folder], "mini").unwrap();
+        assert_eq!(registry.len(), 2);
+        assert!(registry.contains("outline-folder"));
+        assert!(registry.contains("mini-folder"));
+        assert!(!registry.contains("heroicon-folder"));
+
+        let json = registry.to_json();
+        assert!(json.starts_with(r#"{"mini-folder":{"view_box":"0 0 20 20","paths":[{"d":"M"#));
+        assert_eq!(IconRegistry::from_json(&json).unwrap(), registry);
+        assert!(IconRegistry::from_json("[]").is_err());
+    }
+
+    #[test]
+    fn remote_icon_loaded() {
+        let registry = IconRegistry::new(&[outline::Shape::Folder], "heroicon").unwrap();
+        assert_rsx_eq(
+            rsx! {
+                IconRegistryProvider {
+                    url: "/icons.json",
+                    registry,
+                    RemoteIcon {
+                        icon: "heroicon-folder",
+                        class: "foo",
+                    },
+                },
+            },
+            rsx! {
+                svg {
+                    class: "foo",
+                    height: 20,
+                    width: 20,
+                    view_box: outline::VIEW_BOX,
+                    fill: "currentColor",
+                    { outline::Shape::Folder.path() },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn remote_icon_attributes() {
+        let registry = IconRegistry::from_json(
+            r#"{"x":{"view_box":"0 0 24 24","paths":[{"d":"M0 0\"/><script></script>","fill_rule":"evenodd"}]}}"#,
+        )
+        .unwrap();
+        assert_rsx_eq(
+            rsx! {
+                IconRegistryProvider {
+                    url: "/icons.json",
+                    registry,
+                    RemoteIcon {
+                        icon: "x",
+                        fill: "red",
+                    },
+                },
+            },
+            rsx! {
+                svg {
+                    height: 20,
+                    width: 20,
+                    view_box: "0 0 24 24",
+                    fill: "red",
+                    path {
+                        d: "M0 0\"/><script></script>",
+                        fill_rule: "evenodd",
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn remote_icon_placeholder() {
+        assert_rsx_eq(
+            rsx! {
+                IconRegistryProvider {
+                    url: "/icons.json",
+                    RemoteIcon {
+                        icon: "heroicon-folder",
+                        size: 16,
+                    },
+                },
+            },
+            rsx! {
+                svg {
+                    height: 16,
+                    width: 16,
+                    fill: "currentColor",
+                },
+            },
+        );
+    }
+}
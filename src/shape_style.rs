@@ -0,0 +1,22 @@
+/// The style of a shape, which is also the module it's in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ShapeStyle {
+    /// A shape from the [`mini`](crate::mini) module.
+    Mini,
+    /// A shape from the [`outline`](crate::outline) module.
+    Outline,
+    /// A shape from the [`solid`](crate::solid) module.
+    Solid,
+}
+
+impl ShapeStyle {
+    /// Returns the style's name, which is also its module name, like "outline".
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            ShapeStyle::Mini => "mini",
+            ShapeStyle::Outline => "outline",
+            ShapeStyle::Solid => "solid",
+        }
+    }
+}
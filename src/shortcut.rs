@@ -0,0 +1,248 @@
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+use crate::listener::Listener;
+
+/// A keyboard shortcut for an [`IconButton`](crate::IconButton), like "mod+k".
+///
+/// A shortcut is a list of modifiers and a key, separated by `+`. The modifiers are `ctrl`, `alt`,
+/// `shift`, `meta`, and `mod`, which is `meta` (⌘) on Apple platforms and `ctrl` everywhere else.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Shortcut {
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    meta: bool,
+    primary: bool,
+    key: String,
+}
+
+impl Shortcut {
+    /// Parses a shortcut, returning `None` if it doesn't have exactly one key or has an unknown
+    /// modifier.
+    pub(crate) fn parse(shortcut: &str) -> Option<Self> {
+        let mut parsed = Self::default();
+        let mut parts = shortcut.split('+').map(str::trim).peekable();
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                if part.is_empty() {
+                    return None;
+                }
+                parsed.key = part.to_lowercase();
+                break;
+            }
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" => parsed.ctrl = true,
+                "alt" | "option" => parsed.alt = true,
+                "shift" => parsed.shift = true,
+                "meta" | "cmd" | "command" => parsed.meta = true,
+                "mod" => parsed.primary = true,
+                _ => return None,
+            }
+        }
+        Some(parsed)
+    }
+
+    /// Returns true if a key press matches this shortcut.
+    #[allow(clippy::fn_params_excessive_bools)]
+    #[cfg_attr(not(all(feature = "wasm", target_arch = "wasm32")), allow(dead_code))]
+    pub(crate) fn matches(
+        &self,
+        key: &str,
+        ctrl: bool,
+        alt: bool,
+        shift: bool,
+        meta: bool,
+        apple: bool,
+    ) -> bool {
+        key.to_lowercase() == self.key
+            && ctrl == (self.ctrl || (self.primary && !apple))
+            && meta == (self.meta || (self.primary && apple))
+            && alt == self.alt
+            && shift == self.shift
+    }
+
+    /// Returns true if this shortcut has any modifiers. Shortcuts without modifiers are ignored
+    /// while the user is typing in a form field.
+    #[cfg_attr(not(all(feature = "wasm", target_arch = "wasm32")), allow(dead_code))]
+    pub(crate) fn has_modifier(&self) -> bool {
+        self.ctrl || self.alt || self.shift || self.meta || self.primary
+    }
+
+    /// Returns the hint to show for this shortcut, like "⌘K" on Apple platforms or "Ctrl+K"
+    /// everywhere else.
+    pub(crate) fn hint(&self, apple: bool) -> String {
+        let ctrl = self.ctrl || (self.primary && !apple);
+        let meta = self.meta || (self.primary && apple);
+        let key = self.display_key();
+        if apple {
+            let mut hint = String::new();
+            for (on, symbol) in [(ctrl, "⌃"), (self.alt, "⌥"), (self.shift, "⇧"), (meta, "⌘")]
+            {
+                if on {
+                    hint.push_str(symbol);
+                }
+            }
+            hint + &key
+        } else {
+            let mut parts = [
+                (ctrl, "Ctrl"),
+                (self.alt, "Alt"),
+                (self.shift, "Shift"),
+                (meta, "Meta"),
+            ]
+            .iter()
+            .filter_map(|&(on, name)| on.then_some(name))
+            .collect::<Vec<_>>();
+            parts.push(&key);
+            parts.join("+")
+        }
+    }
+
+    /// Returns the value for the `aria-keyshortcuts` attribute. A `mod` shortcut lists both its
+    /// `Control` and `Meta` versions, since the attribute can't depend on the platform.
+    pub(crate) fn aria(&self) -> String {
+        let key = self.display_key();
+        let version = |ctrl: bool, meta: bool| {
+            let mut parts = [
+                (ctrl, "Control"),
+                (self.alt, "Alt"),
+                (self.shift, "Shift"),
+                (meta, "Meta"),
+            ]
+            .iter()
+            .filter_map(|&(on, name)| on.then_some(name))
+            .collect::<Vec<_>>();
+            parts.push(&key);
+            parts.join("+")
+        };
+        if self.primary {
+            format!("{} {}", version(true, self.meta), version(self.ctrl, true))
+        } else {
+            version(self.ctrl, self.meta)
+        }
+    }
+
+    /// Returns the key with its first letter in upper case, like "K" or "Enter".
+    fn display_key(&self) -> String {
+        let mut chars = self.key.chars();
+        chars.next().map_or_else(String::new, |first| {
+            first.to_uppercase().chain(chars).collect()
+        })
+    }
+}
+
+/// Returns true if the app is running on an Apple platform, where `mod` means ⌘.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub(crate) fn is_apple() -> bool {
+    web_sys::window()
+        .and_then(|w| w.navigator().user_agent().ok())
+        .is_some_and(|ua| ["Mac", "iPhone", "iPad"].iter().any(|p| ua.contains(p)))
+}
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub(crate) fn is_apple() -> bool {
+    false
+}
+
+/// Listens for a shortcut on the document, and clicks the element with a matching
+/// `data-shortcut` attribute when it's pressed. The listener is replaced when the shortcut changes
+/// and removed when the component is unmounted.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub(crate) fn use_shortcut(shortcut: Option<&Shortcut>, target: &str) {
+    use dioxus::prelude::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    let shortcut = shortcut.cloned();
+    let listener = use_hook(|| Rc::new(RefCell::new(None::<Listener>)));
+    let target = target.to_string();
+    {
+        let listener = Rc::clone(&listener);
+        use_effect(use_reactive((&shortcut,), move |(shortcut,)| {
+            *listener.borrow_mut() = shortcut.and_then(|s| add_listener(s, &target));
+        }));
+    }
+    use_drop(move || {
+        listener.borrow_mut().take();
+    });
+}
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub(crate) fn use_shortcut(_shortcut: Option<&Shortcut>, _target: &str) {}
+
+/// Adds a `keydown` listener that clicks the target when the shortcut is pressed.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+fn add_listener(shortcut: Shortcut, target: &str) -> Option<Listener> {
+    use wasm_bindgen::JsCast;
+
+    let apple = is_apple();
+    let selector = format!("[data-shortcut=\"{target}\"]");
+    Listener::add("keydown", move |document, evt: web_sys::KeyboardEvent| {
+        if !shortcut.matches(
+            &evt.key(),
+            evt.ctrl_key(),
+            evt.alt_key(),
+            evt.shift_key(),
+            evt.meta_key(),
+            apple,
+        ) {
+            return;
+        }
+        if !shortcut.has_modifier() && is_editable(evt.target()) {
+            return;
+        }
+        if let Ok(Some(element)) = document.query_selector(&selector) {
+            if let Ok(element) = element.dyn_into::<web_sys::HtmlElement>() {
+                evt.prevent_default();
+                element.click();
+            }
+        }
+    })
+}
+
+/// Returns true if the event target is a form field or other editable element.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+fn is_editable(target: Option<web_sys::EventTarget>) -> bool {
+    use wasm_bindgen::JsCast;
+
+    target
+        .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+        .is_some_and(|element| {
+            matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                || element.is_content_editable()
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let shortcut = Shortcut::parse("mod+k").unwrap();
+        assert!(shortcut.primary);
+        assert_eq!(shortcut.key, "k");
+        assert!(Shortcut::parse("Ctrl + Shift + Enter").is_some());
+        assert!(Shortcut::parse("/").is_some());
+        assert!(Shortcut::parse("ctrl+").is_none());
+        assert!(Shortcut::parse("hyper+k").is_none());
+    }
+
+    #[test]
+    fn matching() {
+        let shortcut = Shortcut::parse("mod+k").unwrap();
+        assert!(shortcut.matches("k", true, false, false, false, false));
+        assert!(shortcut.matches("K", false, false, false, true, true));
+        assert!(!shortcut.matches("k", true, false, false, false, true));
+        assert!(!shortcut.matches("k", true, false, true, false, false));
+        assert!(!shortcut.matches("j", true, false, false, false, false));
+    }
+
+    #[test]
+    fn hints() {
+        let shortcut = Shortcut::parse("mod+shift+p").unwrap();
+        assert_eq!(shortcut.hint(true), "⇧⌘P");
+        assert_eq!(shortcut.hint(false), "Ctrl+Shift+P");
+        assert_eq!(shortcut.aria(), "Control+Shift+P Shift+Meta+P");
+        assert_eq!(Shortcut::parse("alt+enter").unwrap().aria(), "Alt+Enter");
+    }
+}
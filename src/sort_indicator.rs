@@ -0,0 +1,192 @@
+use crate::{mini, Icon, IconButton};
+use dioxus::prelude::*;
+
+/// The direction a table column is sorted in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    /// The column is not sorted.
+    #[default]
+    None,
+    /// The column is sorted in ascending order.
+    Ascending,
+    /// The column is sorted in descending order.
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the next direction in the cycle, which goes from `None` to `Ascending` to
+    /// `Descending` and back to `None`.
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            SortDirection::None => SortDirection::Ascending,
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::None,
+        }
+    }
+
+    /// Returns the icon shape for this direction.
+    #[must_use]
+    pub fn shape(self) -> mini::Shape {
+        match self {
+            SortDirection::None => mini::Shape::ChevronUpDown,
+            SortDirection::Ascending => mini::Shape::ChevronUp,
+            SortDirection::Descending => mini::Shape::ChevronDown,
+        }
+    }
+
+    /// Returns the value for the `aria-sort` attribute of the column's header cell.
+    #[must_use]
+    pub fn aria_sort(self) -> &'static str {
+        match self {
+            SortDirection::None => "none",
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+}
+
+/// The properties for the [`SortIndicator`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct SortIndicatorProps {
+    /// The current sort direction.
+    pub direction: SortDirection,
+    /// An optional handler that is called with [`SortDirection::next`] when the indicator is
+    /// clicked. If this is set then the indicator is a button, otherwise it's just an icon.
+    #[props(default, strip_option)]
+    pub onchange: Option<EventHandler<SortDirection>>,
+    /// The accessible label for the button, like "Sort by name". This is only used when `onchange`
+    /// is set, and defaults to "Sort".
+    #[props(default = "Sort".to_string())]
+    pub label: String,
+    /// An optional class for the icon's `<svg>` element, or for the button when `onchange` is set.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 16 pixels.
+    #[props(default = 16)]
+    pub size: u32,
+}
+
+/// Renders the icon for a table column's [`SortDirection`].
+///
+/// Without an `onchange` handler this is a decorative icon with `aria-hidden="true"`, so you should
+/// put [`SortDirection::aria_sort`] in the `aria-sort` attribute of the header cell. With a handler
+/// it's an [`IconButton`] that cycles through the directions when it's clicked.
+///
+/// This component will generate HTML like this without a handler:
+///
+/// ```html
+/// <span aria-hidden="true" style="display: inline-flex;">
+///   <svg ...>
+/// </span>
+/// ```
+///
+/// See the [`SortIndicatorProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn SortIndicator(props: SortIndicatorProps) -> Element {
+    let direction = props.direction;
+    match props.onchange {
+        Some(onchange) => rsx! {
+            IconButton {
+                onclick: move |_| onchange.call(direction.next()),
+                class: props.class,
+                title: props.label.clone(),
+                size: props.size,
+                icon: direction.shape(),
+                sr_only: props.label,
+                r#type: "button",
+            }
+        },
+        None => rsx! {
+            span {
+                aria_hidden: true,
+                style: "display: inline-flex;",
+                Icon {
+                    class: props.class,
+                    size: props.size,
+                    icon: direction.shape(),
+                }
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn sort_indicator_icon() {
+        assert_rsx_eq(
+            rsx! {
+                SortIndicator {
+                    direction: SortDirection::Ascending,
+                },
+            },
+            rsx! {
+                span {
+                    aria_hidden: "true",
+                    style: "display: inline-flex;",
+                    svg {
+                        height: 16,
+                        width: 16,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::ChevronUp.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn sort_indicator_button() {
+        #[allow(non_snake_case)]
+        fn Header() -> Element {
+            rsx! {
+                SortIndicator {
+                    direction: SortDirection::None,
+                    onchange: |_| {},
+                    label: "Sort by name",
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Header {},
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    title: "Sort by name",
+                    r#type: "button",
+                    svg {
+                        height: 16,
+                        width: 16,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::ChevronUpDown.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Sort by name"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn sort_direction_cycle() {
+        assert_eq!(SortDirection::None.next(), SortDirection::Ascending);
+        assert_eq!(SortDirection::Ascending.next(), SortDirection::Descending);
+        assert_eq!(SortDirection::Descending.next(), SortDirection::None);
+        assert_eq!(SortDirection::Descending.aria_sort(), "descending");
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    mini,
+    theme::{join_classes, use_icon_theme},
+    Icon,
+};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`Spinner`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct SpinnerProps {
+    /// An optional class for the `<span>` that wraps the icon.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color to use for the icon. This defaults to "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill: Cow<'static, str>,
+    /// How long one full rotation takes, in milliseconds. Smaller values make the spinner faster.
+    /// This defaults to 1000.
+    #[props(default = 1000)]
+    pub duration_ms: u32,
+    /// The accessible label for the spinner. This defaults to "Loading".
+    #[props(default = "Loading".to_string())]
+    pub label: String,
+}
+
+/// Renders a spinning [`mini::Shape::ArrowPath`] icon to show that something is loading.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span role="status" aria-label="Loading" class="animate-spin" style="...">
+///   <svg ...>
+/// </span>
+/// ```
+///
+/// The rotation comes from the `spinner_class` in the [`IconTheme`](crate::IconTheme), while the
+/// speed is set with an inline `animation-duration` style.
+///
+/// See the [`SpinnerProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn Spinner(props: SpinnerProps) -> Element {
+    let theme = use_icon_theme();
+    let class = join_classes([Some(theme.spinner_class.as_str()), props.class.as_deref()]);
+    rsx! {
+        span {
+            role: "status",
+            aria_label: props.label,
+            class: if let Some(class) = class { class },
+            style: "display: inline-block; animation-duration: {props.duration_ms}ms;",
+            Icon {
+                size: props.size,
+                fill: props.fill,
+                icon: mini::Shape::ArrowPath,
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::assert_rsx_eq, IconShape};
+
+    #[test]
+    fn spinner_default() {
+        assert_rsx_eq(
+            rsx! {
+                Spinner {},
+            },
+            rsx! {
+                span {
+                    role: "status",
+                    aria_label: "Loading",
+                    class: "animate-spin",
+                    style: "display: inline-block; animation-duration: 1000ms;",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::ArrowPath.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn spinner_with_props() {
+        assert_rsx_eq(
+            rsx! {
+                Spinner {
+                    class: "text-blue-500",
+                    size: 32,
+                    fill: "blue",
+                    duration_ms: 500,
+                    label: "Saving",
+                },
+            },
+            rsx! {
+                span {
+                    role: "status",
+                    aria_label: "Saving",
+                    class: "animate-spin text-blue-500",
+                    style: "display: inline-block; animation-duration: 500ms;",
+                    svg {
+                        height: 32,
+                        width: 32,
+                        view_box: mini::VIEW_BOX,
+                        fill: "blue",
+                        { mini::Shape::ArrowPath.path() },
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{mini, ButtonVariant, DropdownAlign, DropdownIconButton, IconButton, IconShape};
+use dioxus::prelude::*;
+
+/// The properties for the [`SplitButton`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct SplitButtonProps<S: IconShape + 'static> {
+    /// The icon shape for the primary button.
+    pub icon: S,
+    /// The handler that is called when the primary button is clicked.
+    pub onclick: EventHandler<MouseEvent>,
+    /// The secondary actions, which are shown in a panel when the chevron button is clicked.
+    pub menu: Element,
+    /// The accessible label for the chevron button. This defaults to "More actions".
+    #[props(default = "More actions".to_string())]
+    pub menu_label: String,
+    /// An optional title for the primary button.
+    #[props(default, strip_option)]
+    pub title: Option<String>,
+    /// An optional visual variant, which is used for both buttons.
+    #[props(default, strip_option)]
+    pub variant: Option<ButtonVariant>,
+    /// If this is true then both buttons are disabled. This defaults to false.
+    #[props(default = false)]
+    pub disabled: bool,
+    /// If this is true then the primary button shows a spinner and has `aria-busy="true"`, and both
+    /// buttons are disabled. This defaults to false.
+    #[props(default = false)]
+    pub loading: bool,
+    /// How long `loading` must be true before the spinner appears, in milliseconds. See
+    /// [`IconButtonProps`](crate::IconButtonProps) for details. This defaults to 0.
+    #[props(default = 0)]
+    pub loading_delay_ms: u32,
+    /// An optional class for the outer `<div>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for both buttons.
+    #[props(default, strip_option)]
+    pub button_class: Option<String>,
+    /// An optional class for the panel of secondary actions.
+    #[props(default, strip_option)]
+    pub panel_class: Option<String>,
+    /// An optional `id` for the panel of secondary actions. If this is not set then a unique `id` is
+    /// generated.
+    #[props(default, strip_option)]
+    pub panel_id: Option<String>,
+    /// The size of the icons. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The label for the primary button, which can be plain text or any other elements.
+    pub children: Element,
+}
+
+/// Renders a primary action button with an attached chevron button that opens a panel of secondary
+/// actions.
+///
+/// The primary button is an [`IconButton`] and the chevron button is a [`DropdownIconButton`] with
+/// a [`mini::Shape::ChevronDown`], so the panel closes on `Escape` and on clicks outside of it.
+/// Both buttons are disabled together, and while `loading` is true the primary button shows a
+/// spinner.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <div role="group" style="display: inline-flex; align-items: stretch;">
+///   <button>
+///     <svg ...>
+///     <span>Child elements go here</span>
+///   </button>
+///   <div style="position: relative; display: inline-block;">
+///     <button title="More actions" aria-haspopup="true" aria-expanded="false" ...>
+///       <svg ...>
+///       <span style="...">More actions</span>
+///     </button>
+///   </div>
+/// </div>
+/// ```
+///
+/// See the [`SplitButtonProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn SplitButton<S: IconShape>(props: SplitButtonProps<S>) -> Element {
+    rsx! {
+        div {
+            role: "group",
+            class: if let Some(class) = props.class { class },
+            style: "display: inline-flex; align-items: stretch;",
+            IconButton {
+                onclick: props.onclick,
+                class: props.button_class.clone(),
+                variant: props.variant,
+                title: props.title,
+                size: props.size,
+                disabled: props.disabled,
+                loading: props.loading,
+                loading_delay_ms: props.loading_delay_ms,
+                icon: props.icon,
+                children: props.children,
+            },
+            DropdownIconButton {
+                icon: mini::Shape::ChevronDown,
+                label: props.menu_label,
+                align: DropdownAlign::End,
+                panel_id: props.panel_id,
+                button_class: props.button_class,
+                panel_class: props.panel_class,
+                variant: props.variant,
+                size: props.size,
+                disabled: props.disabled || props.loading,
+                children: props.menu,
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        outline,
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn split_button_loading() {
+        #[allow(non_snake_case)]
+        fn Save() -> Element {
+            rsx! {
+                SplitButton {
+                    icon: outline::Shape::ArrowDownTray,
+                    onclick: |_| {},
+                    menu: rsx! { "Save as..." },
+                    panel_id: "save-menu",
+                    loading: true,
+                    "Save"
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Save {},
+            },
+            rsx! {
+                div {
+                    role: "group",
+                    style: "display: inline-flex; align-items: stretch;",
+                    button {
+                        class: FOCUS_CLASS,
+                        disabled: true,
+                        aria_busy: "true",
+                        svg {
+                            class: "animate-spin",
+                            height: 20,
+                            width: 20,
+                            view_box: mini::VIEW_BOX,
+                            fill: "currentColor",
+                            { mini::Shape::ArrowPath.path() },
+                        },
+                        span {
+                            "Save"
+                        },
+                    },
+                    div {
+                        style: "position: relative; display: inline-block;",
+                        button {
+                            class: FOCUS_CLASS,
+                            title: "More actions",
+                            disabled: true,
+                            aria_haspopup: "true",
+                            aria_expanded: "false",
+                            svg {
+                                height: 20,
+                                width: 20,
+                                view_box: mini::VIEW_BOX,
+                                fill: crate::DISABLED_FILL_COLOR,
+                                { mini::Shape::ChevronDown.path() },
+                            },
+                            span {
+                                style: SR_ONLY_STYLE,
+                                "More actions"
+                            },
+                        },
+                    },
+                },
+            },
+        );
+    }
+}
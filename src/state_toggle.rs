@@ -0,0 +1,127 @@
+use crate::{
+    theme::{join_classes, use_icon_theme},
+    timer::sleep,
+    Icon, IconShape, SR_ONLY_STYLE,
+};
+use dioxus::prelude::*;
+
+/// How long the icon stays scaled up when the toggle is turned on, in milliseconds.
+const POP_MS: u32 = 150;
+
+/// The properties for the [`StateToggle`] component. See the public toggles that use it, like
+/// [`FavoriteToggleProps`](crate::FavoriteToggleProps), for details.
+#[derive(Clone, PartialEq, Props)]
+pub(crate) struct StateToggleProps<A: IconShape + 'static, B: IconShape + 'static> {
+    pub(crate) icon_on: A,
+    pub(crate) icon_off: B,
+    pub(crate) fill_on: String,
+    pub(crate) on: Option<bool>,
+    pub(crate) default_on: bool,
+    #[props(default)]
+    pub(crate) bind: Option<Signal<bool>>,
+    pub(crate) onchange: Option<EventHandler<bool>>,
+    pub(crate) pending: bool,
+    pub(crate) label: String,
+    pub(crate) class: Option<String>,
+    pub(crate) size: u32,
+    pub(crate) pop: bool,
+    /// The messages that are announced in a live region when the toggle is turned on and off. If
+    /// these are not set then there is no live region.
+    #[props(default)]
+    pub(crate) announcements: Option<(String, String)>,
+}
+
+/// Renders a button that switches between two icons, which may come from different sets, with
+/// `aria-pressed`, a `pending` state for optimistic updates, and an optional pop animation.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button title="..." aria-pressed="false">
+///   <span style="display: inline-flex; transition: transform 150ms; transform: scale(1);">
+///     <svg ...>
+///   </span>
+///   <span style="...">Label goes here</span>
+/// </button>
+/// <span role="status" style="...">Announcement goes here</span>
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub(crate) fn StateToggle<A: IconShape, B: IconShape>(props: StateToggleProps<A, B>) -> Element {
+    let theme = use_icon_theme();
+    let mut uncontrolled = use_signal(|| props.default_on);
+    let mut popping = use_signal(|| false);
+    let mut announcement = use_signal(String::new);
+    let bind = props.bind;
+    let controlled = bind.map(|b| b()).or(props.on);
+    let on = controlled.unwrap_or(uncontrolled());
+    let onchange = props.onchange;
+    let pending = props.pending;
+    let pop = props.pop;
+    let class = join_classes([Some(theme.focus_class.as_str()), props.class.as_deref()]);
+    let size = props.size;
+    let label = props.label;
+    let announcements = props.announcements;
+    let has_announcements = announcements.is_some();
+    let scale = if popping() { "1.25" } else { "1" };
+    rsx! {
+        button {
+            r#type: "button",
+            class: if let Some(class) = class { class },
+            title: label.as_str(),
+            aria_pressed: on,
+            aria_busy: if pending { Some("true") } else { None },
+            onclick: move |_| {
+                if pending {
+                    return;
+                }
+                if let Some(mut bind) = bind {
+                    bind.set(!on);
+                } else if controlled.is_none() {
+                    uncontrolled.set(!on);
+                }
+                if let Some(oc) = onchange {
+                    oc.call(!on);
+                }
+                if let Some((on_message, off_message)) = &announcements {
+                    announcement.set(if on { off_message.clone() } else { on_message.clone() });
+                }
+                if pop && !on {
+                    popping.set(true);
+                    spawn(async move {
+                        sleep(POP_MS).await;
+                        popping.set(false);
+                    });
+                }
+            },
+            span {
+                style: "display: inline-flex; transition: transform {POP_MS}ms; transform: scale({scale});",
+                if on {
+                    Icon {
+                        size,
+                        fill: props.fill_on,
+                        icon: props.icon_on,
+                    },
+                } else {
+                    Icon {
+                        size,
+                        icon: props.icon_off,
+                    },
+                }
+            },
+            span {
+                style: SR_ONLY_STYLE,
+                "{label}"
+            },
+        }
+        // The live region is outside of the button so that its text isn't part of the button's
+        // accessible name.
+        if has_announcements {
+            span {
+                role: "status",
+                style: SR_ONLY_STYLE,
+                "{announcement}"
+            },
+        }
+    }
+}
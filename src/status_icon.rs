@@ -0,0 +1,157 @@
+use crate::{
+    mini,
+    theme::{join_classes, use_icon_theme},
+    Icon,
+};
+use dioxus::prelude::*;
+
+/// A semantic status, like the result of an operation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// Something worked.
+    Success,
+    /// Something needs attention.
+    Warning,
+    /// Something failed.
+    Error,
+    /// Something the user should know about.
+    Info,
+    /// Something hasn't happened yet.
+    Pending,
+}
+
+impl Status {
+    /// Returns the conventional icon shape for this status.
+    #[must_use]
+    pub fn shape(self) -> mini::Shape {
+        match self {
+            Status::Success => mini::Shape::CheckCircle,
+            Status::Warning => mini::Shape::ExclamationTriangle,
+            Status::Error => mini::Shape::XCircle,
+            Status::Info => mini::Shape::InformationCircle,
+            Status::Pending => mini::Shape::Clock,
+        }
+    }
+
+    /// Returns the default accessible label for this status.
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Status::Success => "Success",
+            Status::Warning => "Warning",
+            Status::Error => "Error",
+            Status::Info => "Info",
+            Status::Pending => "Pending",
+        }
+    }
+}
+
+/// The properties for the [`StatusIcon`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct StatusIconProps {
+    /// The status to show.
+    pub status: Status,
+    /// An optional class for the `<span>` that wraps the icon. This is added after the class for the
+    /// status from the [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// An optional accessible label to use instead of the default label for the status.
+    #[props(default, strip_option)]
+    pub label: Option<String>,
+}
+
+/// Renders the conventional icon for a [`Status`].
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span role="img" aria-label="Success" class="text-green-600">
+///   <svg ...>
+/// </span>
+/// ```
+///
+/// The icon is filled with "currentColor", so its color comes from the status's class in the
+/// [`IconTheme`](crate::IconTheme).
+///
+/// See the [`StatusIconProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn StatusIcon(props: StatusIconProps) -> Element {
+    let theme = use_icon_theme();
+    let status = props.status;
+    let class = join_classes([Some(theme.status_class(status)), props.class.as_deref()]);
+    let label = props.label.unwrap_or_else(|| status.label().to_string());
+    rsx! {
+        span {
+            role: "img",
+            aria_label: label,
+            class: if let Some(class) = class { class },
+            Icon {
+                size: props.size,
+                icon: status.shape(),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::assert_rsx_eq, IconShape};
+
+    #[test]
+    fn status_icon_default() {
+        assert_rsx_eq(
+            rsx! {
+                StatusIcon {
+                    status: Status::Warning,
+                },
+            },
+            rsx! {
+                span {
+                    role: "img",
+                    aria_label: "Warning",
+                    class: "text-yellow-500",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::ExclamationTriangle.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn status_icon_with_props() {
+        assert_rsx_eq(
+            rsx! {
+                StatusIcon {
+                    status: Status::Error,
+                    class: "inline",
+                    size: 16,
+                    label: "Upload failed",
+                },
+            },
+            rsx! {
+                span {
+                    role: "img",
+                    aria_label: "Upload failed",
+                    class: "text-red-600 inline",
+                    svg {
+                        height: 16,
+                        width: 16,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::XCircle.path() },
+                    },
+                },
+            },
+        );
+    }
+}
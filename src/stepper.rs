@@ -0,0 +1,271 @@
+use crate::{mini, timer::sleep, IconButton};
+use dioxus::prelude::*;
+use std::{cell::Cell, rc::Rc};
+
+/// The properties for the [`Stepper`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct StepperProps {
+    /// The current value.
+    pub value: i64,
+    /// The handler that is called with the new value when it's increased or decreased.
+    pub onchange: EventHandler<i64>,
+    /// How much each click changes the value by. This defaults to 1.
+    #[props(default = 1)]
+    pub step: i64,
+    /// The optional minimum value. The decrease button is disabled at this value.
+    #[props(default, strip_option)]
+    pub min: Option<i64>,
+    /// The optional maximum value. The increase button is disabled at this value.
+    #[props(default, strip_option)]
+    pub max: Option<i64>,
+    /// The accessible label for the stepper as a whole, like "Quantity".
+    #[props(default, strip_option)]
+    pub label: Option<String>,
+    /// The accessible label for the decrease button. This defaults to "Decrease".
+    #[props(default = "Decrease".to_string())]
+    pub decrease_label: String,
+    /// The accessible label for the increase button. This defaults to "Increase".
+    #[props(default = "Increase".to_string())]
+    pub increase_label: String,
+    /// How long a button must be held before the value starts repeating, in milliseconds. This
+    /// defaults to 400.
+    #[props(default = 400)]
+    pub repeat_delay_ms: u32,
+    /// How often the value changes while a button is held, in milliseconds. This defaults to 100.
+    #[props(default = 100)]
+    pub repeat_interval_ms: u32,
+    /// An optional class for the outer `<div>`.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// An optional class for the buttons.
+    #[props(default, strip_option)]
+    pub button_class: Option<String>,
+    /// The size of the icons. This defaults to 16 pixels.
+    #[props(default = 16)]
+    pub size: u32,
+    /// How to show the value. If this is empty then the value is shown as plain text.
+    pub children: Element,
+}
+
+/// Renders a numeric stepper, with [`mini::Shape::Minus`] and [`mini::Shape::Plus`] buttons
+/// around the value.
+///
+/// The value is clamped between `min` and `max`, and the buttons are disabled at the edges.
+/// Holding a button down repeats its change after `repeat_delay_ms`, every `repeat_interval_ms`.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <div role="group" aria-label="Quantity" style="display: inline-flex; align-items: center;">
+///   <span style="display: contents;">
+///     <button title="Decrease" type="button">...</button>
+///   </span>
+///   <span aria-live="polite">3</span>
+///   <span style="display: contents;">
+///     <button title="Increase" type="button">...</button>
+///   </span>
+/// </div>
+/// ```
+///
+/// See the [`StepperProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn Stepper(props: StepperProps) -> Element {
+    // The value that the next step starts from. This is updated when a step is emitted, so that
+    // repeated steps don't have to wait for the parent to re-render.
+    let latest = use_hook(|| Rc::new(Cell::new(props.value)));
+    latest.set(props.value);
+    // The task that repeats the step while a button is held. It's cancelled when the button is
+    // released or pressed again, so two presses never repeat at once.
+    let mut repeat = use_signal(|| None::<Task>);
+    let mut repeated = use_signal(|| false);
+    let value = props.value;
+    let (min, max) = (props.min, props.max);
+    let onchange = props.onchange;
+    let delay = props.repeat_delay_ms;
+    let interval = props.repeat_interval_ms;
+
+    let step_by = {
+        let latest = Rc::clone(&latest);
+        move |delta: i64| {
+            let current = latest.get();
+            let new = clamp_step(current, delta, min, max);
+            if new != current {
+                latest.set(new);
+                onchange.call(new);
+            }
+        }
+    };
+    let start_repeat = {
+        let step_by = step_by.clone();
+        move |delta: i64| {
+            if let Some(task) = repeat.take() {
+                task.cancel();
+            }
+            repeated.set(false);
+            let step_by = step_by.clone();
+            repeat.set(Some(spawn(async move {
+                sleep(delay).await;
+                loop {
+                    repeated.set(true);
+                    step_by(delta);
+                    sleep(interval).await;
+                }
+            })));
+        }
+    };
+    let click = move |delta: i64| {
+        // A held button has already been stepping, so the click when it's released is ignored.
+        if repeated() {
+            repeated.set(false);
+        } else {
+            step_by(delta);
+        }
+    };
+    let stop_repeat = move |_| {
+        if let Some(task) = repeat.take() {
+            task.cancel();
+        }
+    };
+
+    let button_class = props.button_class;
+    let size = props.size;
+    let button = |delta: i64, label: String, icon: mini::Shape, disabled: bool| {
+        let mut start_repeat = start_repeat.clone();
+        let mut click = click.clone();
+        rsx! {
+            span {
+                style: "display: contents;",
+                onpointerdown: move |_| start_repeat(delta),
+                onpointerup: stop_repeat,
+                onpointerleave: stop_repeat,
+                onpointercancel: stop_repeat,
+                IconButton {
+                    onclick: move |_| click(delta),
+                    class: button_class.clone(),
+                    title: label.clone(),
+                    size,
+                    disabled,
+                    icon,
+                    sr_only: label,
+                    r#type: "button",
+                },
+            }
+        }
+    };
+    let step = props.step;
+
+    rsx! {
+        div {
+            role: "group",
+            aria_label: props.label,
+            class: if let Some(class) = props.class { class },
+            style: "display: inline-flex; align-items: center;",
+            { button(-step, props.decrease_label, mini::Shape::Minus, min.is_some_and(|m| value <= m)) },
+            span {
+                aria_live: "polite",
+                if props.children == VNode::empty() {
+                    "{value}"
+                } else {
+                    { props.children }
+                }
+            },
+            { button(step, props.increase_label, mini::Shape::Plus, max.is_some_and(|m| value >= m)) },
+        }
+    }
+}
+
+/// Returns `value` changed by `delta` and clamped between `min` and `max`.
+fn clamp_step(value: i64, delta: i64, min: Option<i64>, max: Option<i64>) -> i64 {
+    let new = value.saturating_add(delta);
+    let new = min.map_or(new, |m| new.max(m));
+    max.map_or(new, |m| new.min(m))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, DISABLED_FILL_COLOR, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn stepper_at_min() {
+        #[allow(non_snake_case)]
+        fn Quantity() -> Element {
+            rsx! {
+                Stepper {
+                    value: 1,
+                    onchange: |_| {},
+                    min: 1,
+                    label: "Quantity",
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Quantity {},
+            },
+            rsx! {
+                div {
+                    role: "group",
+                    aria_label: "Quantity",
+                    style: "display: inline-flex; align-items: center;",
+                    span {
+                        style: "display: contents;",
+                        button {
+                            class: FOCUS_CLASS,
+                            title: "Decrease",
+                            disabled: true,
+                            r#type: "button",
+                            svg {
+                                height: 16,
+                                width: 16,
+                                view_box: mini::VIEW_BOX,
+                                fill: DISABLED_FILL_COLOR,
+                                { mini::Shape::Minus.path() },
+                            },
+                            span {
+                                style: SR_ONLY_STYLE,
+                                "Decrease"
+                            },
+                        },
+                    },
+                    span {
+                        aria_live: "polite",
+                        "1"
+                    },
+                    span {
+                        style: "display: contents;",
+                        button {
+                            class: FOCUS_CLASS,
+                            title: "Increase",
+                            r#type: "button",
+                            svg {
+                                height: 16,
+                                width: 16,
+                                view_box: mini::VIEW_BOX,
+                                fill: "currentColor",
+                                { mini::Shape::Plus.path() },
+                            },
+                            span {
+                                style: SR_ONLY_STYLE,
+                                "Increase"
+                            },
+                        },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn clamping() {
+        assert_eq!(clamp_step(5, 1, None, None), 6);
+        assert_eq!(clamp_step(5, -10, Some(0), None), 0);
+        assert_eq!(clamp_step(9, 5, None, Some(10)), 10);
+        assert_eq!(clamp_step(i64::MAX, 1, None, None), i64::MAX);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::Status;
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// The visual variant of a button.
+///
+/// Each variant maps to a set of classes in the [`IconTheme`]. When a button has no variant, no
+/// variant classes are added to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ButtonVariant {
+    /// The main call to action.
+    Primary,
+    /// A less prominent action.
+    Secondary,
+    /// A button with no background or border until it is hovered.
+    Ghost,
+    /// A destructive action, like deleting something.
+    Danger,
+}
+
+/// A preset size for a button.
+///
+/// Each size sets the size of the icon and maps to a set of padding classes in the [`IconTheme`], so
+/// the icon and the button's hit area scale together.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ButtonSize {
+    /// A 16 pixel icon with small padding.
+    Sm,
+    /// A 20 pixel icon with medium padding.
+    Md,
+    /// A 24 pixel icon with large padding.
+    Lg,
+}
+
+impl ButtonSize {
+    /// Returns the size of the icon, in pixels, for this button size.
+    #[must_use]
+    pub fn icon_size(self) -> u32 {
+        match self {
+            ButtonSize::Sm => 16,
+            ButtonSize::Md => 20,
+            ButtonSize::Lg => 24,
+        }
+    }
+}
+
+/// The classes used by the components in this crate.
+///
+/// The defaults are [tailwindcss](https://tailwindcss.com/) utility classes. To use your own
+/// classes, provide an `Rc<IconTheme>` as a context somewhere above the components that should use
+/// it:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_heroicons::IconTheme;
+/// use std::rc::Rc;
+///
+/// fn App() -> Element {
+///     use_context_provider(|| {
+///         Rc::new(IconTheme {
+///             primary_class: "btn btn-primary".to_string(),
+///             ..IconTheme::default()
+///         })
+///     });
+///     rsx! {}
+/// }
+/// ```
+///
+/// Every component shares the one theme. A plain `IconTheme` context works too, but then each
+/// component gets its own copy of it when it's first rendered. Components that are not inside
+/// either context use [`IconTheme::default`], which is also shared.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IconTheme {
+    /// The classes for a [`ButtonVariant::Primary`] button.
+    pub primary_class: String,
+    /// The classes for a [`ButtonVariant::Secondary`] button.
+    pub secondary_class: String,
+    /// The classes for a [`ButtonVariant::Ghost`] button.
+    pub ghost_class: String,
+    /// The classes for a [`ButtonVariant::Danger`] button.
+    pub danger_class: String,
+    /// The classes for a [`ButtonSize::Sm`] button.
+    pub sm_button_class: String,
+    /// The classes for a [`ButtonSize::Md`] button.
+    pub md_button_class: String,
+    /// The classes for a [`ButtonSize::Lg`] button.
+    pub lg_button_class: String,
+    /// The classes for a button with `touch_target` set. These should give the button a minimum
+    /// hit area of 44 by 44 pixels.
+    pub touch_target_class: String,
+    /// The classes that give a button a visible focus indicator. These should use `:focus-visible`
+    /// so that the indicator is only shown when the button is focused via the keyboard.
+    pub focus_class: String,
+    /// The classes that make a [`Spinner`](crate::Spinner) spin.
+    pub spinner_class: String,
+    /// The classes for a [`Status::Success`] icon.
+    pub success_class: String,
+    /// The classes for a [`Status::Warning`] icon.
+    pub warning_class: String,
+    /// The classes for a [`Status::Error`] icon.
+    pub error_class: String,
+    /// The classes for a [`Status::Info`] icon.
+    pub info_class: String,
+    /// The classes for a [`Status::Pending`] icon.
+    pub pending_class: String,
+    /// The classes for the badge in an [`IconWithBadge`](crate::IconWithBadge).
+    pub badge_class: String,
+    /// The classes that make the badge in an [`IconWithBadge`](crate::IconWithBadge) pulse when
+    /// `pulse` is set.
+    pub badge_pulse_class: String,
+    /// The classes for the box of an [`IconCheckbox`](crate::IconCheckbox).
+    pub checkbox_class: String,
+    /// The classes that are added to the box of an [`IconCheckbox`](crate::IconCheckbox) when it's
+    /// checked or indeterminate.
+    pub checkbox_checked_class: String,
+    /// The classes for a [`Callout`](crate::Callout).
+    pub callout_class: String,
+    /// The classes for a [`Chip`](crate::Chip).
+    pub chip_class: String,
+    /// The classes for the popup panel of a [`DropdownIconButton`](crate::DropdownIconButton).
+    pub dropdown_class: String,
+    /// The classes for a [`Fab`](crate::Fab).
+    pub fab_class: String,
+    /// The classes for an [`Avatar`](crate::Avatar).
+    pub avatar_class: String,
+    /// The classes for an [`EmptyState`](crate::EmptyState).
+    pub empty_state_class: String,
+    /// The classes for the icon in an [`EmptyState`](crate::EmptyState).
+    pub empty_state_icon_class: String,
+    /// The classes for the shortcut hint in an [`IconButton`](crate::IconButton).
+    pub shortcut_class: String,
+    /// The classes for a [`Tooltip`](crate::Tooltip).
+    pub tooltip_class: String,
+}
+
+impl Default for IconTheme {
+    fn default() -> Self {
+        Self {
+            primary_class: "bg-blue-600 text-white hover:bg-blue-700".to_string(),
+            secondary_class:
+                "bg-white text-gray-900 ring-1 ring-inset ring-gray-300 hover:bg-gray-50"
+                    .to_string(),
+            ghost_class: "bg-transparent text-gray-700 hover:bg-gray-100".to_string(),
+            danger_class: "bg-red-600 text-white hover:bg-red-700".to_string(),
+            sm_button_class: "p-1".to_string(),
+            md_button_class: "p-1.5".to_string(),
+            lg_button_class: "p-2".to_string(),
+            touch_target_class: "min-h-11 min-w-11".to_string(),
+            focus_class: "focus:outline-none focus-visible:ring-2 focus-visible:ring-blue-500"
+                .to_string(),
+            spinner_class: "animate-spin".to_string(),
+            success_class: "text-green-600".to_string(),
+            warning_class: "text-yellow-500".to_string(),
+            error_class: "text-red-600".to_string(),
+            info_class: "text-blue-600".to_string(),
+            pending_class: "text-gray-500".to_string(),
+            badge_class: "rounded-full bg-red-600 px-1 text-xs leading-4 text-white".to_string(),
+            badge_pulse_class: "animate-pulse".to_string(),
+            checkbox_class: "rounded border border-gray-300 bg-white text-white \
+                 peer-focus-visible:ring-2 peer-focus-visible:ring-blue-500"
+                .to_string(),
+            checkbox_checked_class: "border-blue-600 bg-blue-600".to_string(),
+            callout_class: "rounded-md border border-gray-200 p-4".to_string(),
+            chip_class: "rounded-full bg-gray-100 px-2 py-0.5 text-sm text-gray-700".to_string(),
+            dropdown_class: "mt-1 rounded-md border border-gray-200 bg-white p-1 shadow-lg"
+                .to_string(),
+            fab_class: "rounded-full bg-blue-600 text-white shadow-lg hover:bg-blue-700"
+                .to_string(),
+            avatar_class: "rounded-full bg-gray-200 text-gray-500".to_string(),
+            empty_state_class: "py-12".to_string(),
+            empty_state_icon_class: "text-gray-400".to_string(),
+            shortcut_class: "ml-1 text-xs text-gray-500".to_string(),
+            tooltip_class: "rounded bg-gray-900 px-2 py-1 text-xs text-white".to_string(),
+        }
+    }
+}
+
+impl IconTheme {
+    /// Returns the classes for the given variant.
+    #[must_use]
+    pub fn variant_class(&self, variant: ButtonVariant) -> &str {
+        match variant {
+            ButtonVariant::Primary => &self.primary_class,
+            ButtonVariant::Secondary => &self.secondary_class,
+            ButtonVariant::Ghost => &self.ghost_class,
+            ButtonVariant::Danger => &self.danger_class,
+        }
+    }
+
+    /// Returns the classes for the given button size.
+    #[must_use]
+    pub fn size_class(&self, size: ButtonSize) -> &str {
+        match size {
+            ButtonSize::Sm => &self.sm_button_class,
+            ButtonSize::Md => &self.md_button_class,
+            ButtonSize::Lg => &self.lg_button_class,
+        }
+    }
+
+    /// Returns the classes for the given status.
+    #[must_use]
+    pub fn status_class(&self, status: Status) -> &str {
+        match status {
+            Status::Success => &self.success_class,
+            Status::Warning => &self.warning_class,
+            Status::Error => &self.error_class,
+            Status::Info => &self.info_class,
+            Status::Pending => &self.pending_class,
+        }
+    }
+}
+
+thread_local! {
+    static DEFAULT_THEME: Rc<IconTheme> = Rc::new(IconTheme::default());
+}
+
+/// Returns the [`IconTheme`] from the current context, or the default theme if there isn't one.
+/// The theme is looked up once, when the component is first rendered, and after that each render
+/// only clones the `Rc`, so rendering a lot of themed components doesn't copy its classes.
+pub(crate) fn use_icon_theme() -> Rc<IconTheme> {
+    use_hook(|| {
+        try_consume_context::<Rc<IconTheme>>()
+            .or_else(|| try_consume_context::<IconTheme>().map(Rc::new))
+            .unwrap_or_else(|| DEFAULT_THEME.with(Rc::clone))
+    })
+}
+
+/// Joins all the classes that are set into a single class string. This returns `None` if none of
+/// them are set so that components don't render an empty `class` attribute.
+pub(crate) fn join_classes<'a>(
+    classes: impl IntoIterator<Item = Option<&'a str>>,
+) -> Option<String> {
+    let joined = classes
+        .into_iter()
+        .flatten()
+        .filter(|c| !c.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if joined.is_empty() {
+        None
+    } else {
+        Some(joined)
+    }
+}
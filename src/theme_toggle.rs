@@ -0,0 +1,179 @@
+use crate::{
+    outline,
+    theme::{join_classes, use_icon_theme},
+    IconTransition, TransitionEffect, SR_ONLY_STYLE,
+};
+use dioxus::prelude::*;
+
+/// A light or dark color scheme.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorScheme {
+    /// The light color scheme.
+    #[default]
+    Light,
+    /// The dark color scheme.
+    Dark,
+}
+
+impl ColorScheme {
+    /// Returns the other color scheme.
+    #[must_use]
+    pub fn toggled(self) -> Self {
+        match self {
+            ColorScheme::Light => ColorScheme::Dark,
+            ColorScheme::Dark => ColorScheme::Light,
+        }
+    }
+}
+
+/// The properties for the [`ThemeToggle`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct ThemeToggleProps {
+    /// The current color scheme.
+    pub scheme: ColorScheme,
+    /// The handler that is called with the new color scheme when the toggle is clicked.
+    pub onchange: EventHandler<ColorScheme>,
+    /// The accessible label for the toggle. This is also used as its title. This defaults to "Dark
+    /// mode".
+    #[props(default = "Dark mode".to_string())]
+    pub label: String,
+    /// An optional class for the button.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icons. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// How long the crossfade between the icons takes, in milliseconds. Set this to 0 to turn the
+    /// animation off. This defaults to 200.
+    #[props(default = 200)]
+    pub transition_ms: u32,
+}
+
+/// Renders a button that switches between a light and a dark [`ColorScheme`].
+///
+/// The button shows [`outline::Shape::Sun`] for the light scheme and [`outline::Shape::Moon`] for
+/// the dark one. Both icons are always rendered on top of each other, and switching schemes fades
+/// and rotates one out and the other in. The toggle is controlled, so it's up to you to store the
+/// scheme and apply it, for example by adding a `dark` class to the page.
+///
+/// The button's label stays the same, and whether the dark scheme is on is exposed with
+/// `aria-pressed`.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button title="Dark mode" aria-pressed="false">
+///   <span style="position: relative; ...">
+///     <span style="position: absolute; ... opacity: 1; ...">
+///       <svg ...>
+///     </span>
+///     <span style="position: absolute; ... opacity: 0; ...">
+///       <svg ...>
+///     </span>
+///   </span>
+///   <span style="...">Dark mode</span>
+/// </button>
+/// ```
+///
+/// See the [`ThemeToggleProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn ThemeToggle(props: ThemeToggleProps) -> Element {
+    let theme = use_icon_theme();
+    let class = join_classes([Some(theme.focus_class.as_str()), props.class.as_deref()]);
+    let scheme = props.scheme;
+    let onchange = props.onchange;
+    let size = props.size;
+    let transition_ms = props.transition_ms;
+    let label = props.label;
+    rsx! {
+        button {
+            r#type: "button",
+            class: if let Some(class) = class { class },
+            title: label.as_str(),
+            aria_pressed: scheme == ColorScheme::Dark,
+            onclick: move |_| onchange.call(scheme.toggled()),
+            IconTransition {
+                icon_on: outline::Shape::Moon,
+                icon_off: outline::Shape::Sun,
+                active: scheme == ColorScheme::Dark,
+                effect: TransitionEffect::Rotate,
+                size,
+                transition_ms,
+            },
+            span {
+                style: SR_ONLY_STYLE,
+                "{label}"
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape,
+    };
+
+    #[test]
+    fn theme_toggle_dark() {
+        #[allow(non_snake_case)]
+        fn Toggle() -> Element {
+            rsx! {
+                ThemeToggle {
+                    scheme: ColorScheme::Dark,
+                    onchange: |_| {},
+                    transition_ms: 0,
+                },
+            }
+        }
+
+        let icon = |shape: outline::Shape| {
+            rsx! {
+                svg {
+                    height: 20,
+                    width: 20,
+                    view_box: outline::VIEW_BOX,
+                    fill: "currentColor",
+                    { shape.path() },
+                }
+            }
+        };
+        assert_rsx_eq(
+            rsx! {
+                Toggle {},
+            },
+            rsx! {
+                button {
+                    r#type: "button",
+                    class: FOCUS_CLASS,
+                    title: "Dark mode",
+                    aria_pressed: "true",
+                    span {
+                        style: "position: relative; display: inline-block; width: 20px; height: 20px;",
+                        span {
+                            style: "position: absolute; top: 0; left: 0; transition: opacity 0ms, transform 0ms; opacity: 0; transform: rotate(90deg);",
+                            { icon(outline::Shape::Sun) },
+                        },
+                        span {
+                            style: "position: absolute; top: 0; left: 0; transition: opacity 0ms, transform 0ms; opacity: 1; transform: rotate(0deg);",
+                            { icon(outline::Shape::Moon) },
+                        },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Dark mode"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn color_scheme_toggled() {
+        assert_eq!(ColorScheme::Light.toggled(), ColorScheme::Dark);
+        assert_eq!(ColorScheme::Dark.toggled(), ColorScheme::Light);
+    }
+}
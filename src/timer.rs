@@ -0,0 +1,172 @@
+//! A small async timer for components that need to wait, like [`CopyButton`](crate::CopyButton)
+//! resetting its icon.
+
+/// Waits for `ms` milliseconds. This uses the browser's `setTimeout`.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub(crate) async fn sleep(ms: u32) {
+    use std::convert::TryFrom;
+
+    let ms = i32::try_from(ms).unwrap_or(i32::MAX);
+    let promise = js_sys::Promise::new(&mut |resolve, _| match web_sys::window() {
+        Some(window) => {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms);
+        }
+        None => {
+            let _ = resolve.call0(&resolve);
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Waits for `ms` milliseconds. This is woken by the shared timer thread, so it doesn't block the
+/// async runtime, and waiting doesn't start a new thread each time.
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub(crate) async fn sleep(ms: u32) {
+    use std::{
+        future::poll_fn,
+        sync::{Arc, PoisonError},
+        task::Poll,
+        time::{Duration, Instant},
+    };
+
+    let state = Arc::new(timers::TimerState::default());
+    timers::add(
+        Instant::now() + Duration::from_millis(ms.into()),
+        Arc::clone(&state),
+    );
+    poll_fn(|cx| {
+        let mut state = state.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.0 {
+            Poll::Ready(())
+        } else {
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    })
+    .await;
+}
+
+/// The one thread that wakes every [`sleep`] when its time is up.
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+mod timers {
+    use std::{
+        collections::BTreeMap,
+        sync::{
+            mpsc::{self, Receiver, RecvTimeoutError, Sender},
+            Arc, Mutex, OnceLock, PoisonError,
+        },
+        task::Waker,
+        thread,
+        time::Instant,
+    };
+
+    /// A timer's deadline and its state.
+    type Timer = (Instant, Arc<TimerState>);
+
+    /// Whether a timer is done, and the waker for the task that's waiting for it.
+    #[derive(Default)]
+    pub(super) struct TimerState(pub(super) Mutex<(bool, Option<Waker>)>);
+
+    /// Adds a timer that's done at `deadline`, starting the thread the first time.
+    pub(super) fn add(deadline: Instant, state: Arc<TimerState>) {
+        static SENDER: OnceLock<Mutex<Sender<Timer>>> = OnceLock::new();
+        let sender = SENDER.get_or_init(|| {
+            let (sender, receiver) = mpsc::channel();
+            thread::Builder::new()
+                .name("dioxus-heroicons-timer".to_string())
+                .spawn(move || run(&receiver))
+                .expect("could not start the timer thread");
+            Mutex::new(sender)
+        });
+        // The thread never exits while the sender exists, so this can't fail.
+        let _ = sender
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .send((deadline, state));
+    }
+
+    /// Waits for new timers and for the earliest one to be done, and wakes each one when it is.
+    fn run(receiver: &Receiver<Timer>) {
+        // The timers are keyed by their deadline and a counter, so two with the same deadline
+        // don't replace each other.
+        let mut timers = BTreeMap::new();
+        let mut count = 0_u64;
+        loop {
+            let now = Instant::now();
+            while let Some(entry) = timers.first_entry() {
+                let &(deadline, _) = entry.key();
+                if deadline > now {
+                    break;
+                }
+                let state: Arc<TimerState> = entry.remove();
+                let mut state = state.0.lock().unwrap_or_else(PoisonError::into_inner);
+                state.0 = true;
+                if let Some(waker) = state.1.take() {
+                    waker.wake();
+                }
+            }
+            let next = match timers.keys().next() {
+                Some(&(deadline, _)) => match receiver.recv_timeout(deadline - now) {
+                    Ok(timer) => Some(timer),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                },
+                None => match receiver.recv() {
+                    Ok(timer) => Some(timer),
+                    Err(_) => return,
+                },
+            };
+            if let Some((deadline, state)) = next {
+                timers.insert((deadline, count), state);
+                count += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::Arc,
+        task::{Context, Wake, Waker},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    // Runs a future on the current thread, parking it until the future is woken.
+    fn block_on(future: impl Future<Output = ()>) {
+        struct Unpark(thread::Thread);
+
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        while future.as_mut().poll(&mut cx).is_pending() {
+            thread::park();
+        }
+    }
+
+    #[test]
+    fn sleeps() {
+        let start = Instant::now();
+        let waits = [60, 20, 40, 20];
+        let handles = waits.map(|ms| {
+            thread::spawn(move || {
+                block_on(sleep(ms));
+                start.elapsed()
+            })
+        });
+        for (&ms, handle) in waits.iter().zip(handles) {
+            assert!(handle.join().unwrap() >= Duration::from_millis(ms.into()));
+        }
+        block_on(sleep(0));
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    mini,
+    theme::{join_classes, use_icon_theme},
+    Spinner, Status, StatusIcon,
+};
+use dioxus::prelude::*;
+
+/// The level of a toast or other notification.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastLevel {
+    /// Something worked.
+    Success,
+    /// Something the user should know about.
+    #[default]
+    Info,
+    /// Something needs attention.
+    Warning,
+    /// Something failed.
+    Error,
+    /// Something is still happening, like a save that hasn't finished yet.
+    Loading,
+}
+
+impl ToastLevel {
+    /// Returns the [`Status`] whose icon and class this level uses. [`ToastLevel::Loading`] uses
+    /// [`Status::Pending`].
+    #[must_use]
+    pub fn status(self) -> Status {
+        match self {
+            ToastLevel::Success => Status::Success,
+            ToastLevel::Info => Status::Info,
+            ToastLevel::Warning => Status::Warning,
+            ToastLevel::Error => Status::Error,
+            ToastLevel::Loading => Status::Pending,
+        }
+    }
+
+    /// Returns the icon shape for this level. This is the same as the shape for its
+    /// [`status`](ToastLevel::status), except that [`ToastLevel::Loading`] uses the
+    /// [`mini::Shape::ArrowPath`] from the [`Spinner`].
+    #[must_use]
+    pub fn shape(self) -> mini::Shape {
+        match self {
+            ToastLevel::Loading => mini::Shape::ArrowPath,
+            _ => self.status().shape(),
+        }
+    }
+
+    /// Returns a hex color for this level, for toast libraries that take a color rather than a
+    /// class. These match the default status classes in the [`IconTheme`](crate::IconTheme).
+    #[must_use]
+    pub fn color(self) -> &'static str {
+        match self {
+            // green 600
+            ToastLevel::Success => "#16A34A",
+            // blue 600
+            ToastLevel::Info => "#2563EB",
+            // yellow 500
+            ToastLevel::Warning => "#EAB308",
+            // red 600
+            ToastLevel::Error => "#DC2626",
+            // gray 500
+            ToastLevel::Loading => "#6B7280",
+        }
+    }
+}
+
+/// The properties for the [`ToastIcon`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct ToastIconProps {
+    /// The level of the notification.
+    pub level: ToastLevel,
+    /// An optional class for the `<span>` that wraps the icon. This is added after the class for the
+    /// level's status from the [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// An optional accessible label to use instead of the default label for the level.
+    #[props(default, strip_option)]
+    pub label: Option<String>,
+}
+
+/// Renders the icon for a toast or other notification, colored by its [`ToastLevel`].
+///
+/// Most levels render a [`StatusIcon`] for the level's [`status`](ToastLevel::status).
+/// [`ToastLevel::Loading`] renders a [`Spinner`] with the class for [`Status::Pending`]. To use the
+/// same icons with a toast library that takes its own icon, see [`ToastLevel::shape`] and
+/// [`ToastLevel::color`].
+///
+/// See the [`ToastIconProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn ToastIcon(props: ToastIconProps) -> Element {
+    let theme = use_icon_theme();
+    let level = props.level;
+    if level == ToastLevel::Loading {
+        let class = join_classes([
+            Some(theme.status_class(level.status())),
+            props.class.as_deref(),
+        ]);
+        let label = props.label.unwrap_or_else(|| "Loading".to_string());
+        return rsx! {
+            Spinner {
+                class,
+                size: props.size,
+                label,
+            },
+        };
+    }
+    rsx! {
+        StatusIcon {
+            status: level.status(),
+            class: props.class,
+            size: props.size,
+            label: props.label,
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::assert_rsx_eq, IconShape};
+
+    #[test]
+    fn toast_icon_error() {
+        assert_rsx_eq(
+            rsx! {
+                ToastIcon {
+                    level: ToastLevel::Error,
+                },
+            },
+            rsx! {
+                span {
+                    role: "img",
+                    aria_label: "Error",
+                    class: "text-red-600",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::XCircle.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn toast_icon_loading() {
+        assert_rsx_eq(
+            rsx! {
+                ToastIcon {
+                    level: ToastLevel::Loading,
+                    size: 16,
+                },
+            },
+            rsx! {
+                span {
+                    role: "status",
+                    aria_label: "Loading",
+                    class: "animate-spin text-gray-500",
+                    style: "display: inline-block; animation-duration: 1000ms;",
+                    svg {
+                        height: 16,
+                        width: 16,
+                        view_box: mini::VIEW_BOX,
+                        fill: "currentColor",
+                        { mini::Shape::ArrowPath.path() },
+                    },
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{IconButton, IconButtonProps, IconShape, DISABLED_FILL_COLOR};
+use dioxus::prelude::*;
+use std::borrow::Cow;
+
+/// The properties for the [`ToggleIconButton`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct ToggleIconButtonProps<S: IconShape + 'static> {
+    /// The icon shape to use when the button is pressed.
+    pub icon_on: S,
+    /// The icon shape to use when the button is not pressed.
+    pub icon_off: S,
+    /// The pressed state of the button. If this is set then the button is controlled, and it will
+    /// only change state when you change this value, usually in response to `onchange`. If this is
+    /// not set then the button keeps track of its own state.
+    #[props(default, strip_option)]
+    pub pressed: Option<bool>,
+    /// The initial pressed state of the button when `pressed` is not set. This defaults to false.
+    #[props(default = false)]
+    pub default_pressed: bool,
+    /// A signal to bind the pressed state to. If this is set then the button shows the signal's
+    /// value, and clicking it sets the signal to the new state before calling `onchange`, so you
+    /// don't need to set `pressed` or write an `onchange` handler to keep them in sync. This takes
+    /// precedence over `pressed`.
+    #[props(default, strip_option)]
+    pub bind: Option<Signal<bool>>,
+    /// An optional handler that is called with the new pressed state when the button is clicked.
+    #[props(default, strip_option)]
+    pub onchange: Option<EventHandler<bool>>,
+    /// An optional class for the *button itself*.
+    #[props(default, strip_option)]
+    pub class: Option<Cow<'static, str>>,
+    /// An optional title for the button element.
+    #[props(default, strip_option)]
+    pub title: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+    /// The fill color to use for the icon when the button is pressed. This defaults to
+    /// "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill_on: Cow<'static, str>,
+    /// The fill color to use for the icon when the button is not pressed. This defaults to
+    /// "currentColor".
+    #[props(into, default = "currentColor")]
+    pub fill_off: Cow<'static, str>,
+    /// If this is true then the button's `disabled` attribute will be true, and clicking it will not
+    /// change its state.
+    #[props(default = false)]
+    pub disabled: bool,
+    /// The fill color to use when `disabled` is true. This is only relevant for solid icons. This
+    /// defaults to "#9CA3AF", which is "coolGray 400" from tailwindcss.
+    #[props(into, default = DISABLED_FILL_COLOR)]
+    pub disabled_fill: Cow<'static, str>,
+    /// An optional class that will be passed to the [`Icon`](crate::Icon).
+    #[props(default, strip_option)]
+    pub icon_class: Option<Cow<'static, str>>,
+    /// Optional text for screen readers. See [`IconButtonProps`](crate::IconButtonProps) for
+    /// details.
+    #[props(default, strip_option)]
+    pub sr_only: Option<String>,
+    /// Any other attributes for the *button itself*.
+    #[props(extends = GlobalAttributes, extends = button)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// Renders an [`IconButton`] that toggles between two icons.
+///
+/// The button shows `icon_on` when it is pressed and `icon_off` when it is not, and sets
+/// `aria-pressed` to match. This covers things like mute, favorite, and visibility toggles.
+///
+/// See the [`ToggleIconButtonProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn ToggleIconButton<S: IconShape>(props: ToggleIconButtonProps<S>) -> Element {
+    let mut uncontrolled = use_signal(|| props.default_pressed);
+    let bind = props.bind;
+    let controlled = bind.map(|b| b()).or(props.pressed);
+    let pressed = controlled.unwrap_or(uncontrolled());
+    let onchange = props.onchange;
+    let (icon, fill) = if pressed {
+        (props.icon_on, props.fill_on)
+    } else {
+        (props.icon_off, props.fill_off)
+    };
+    let mut button = IconButtonProps::builder()
+        .onclick(move |_| {
+            if let Some(mut bind) = bind {
+                bind.set(!pressed);
+            } else if controlled.is_none() {
+                uncontrolled.set(!pressed);
+            }
+            if let Some(oc) = onchange {
+                oc.call(!pressed);
+            }
+        })
+        .class(props.class)
+        .title(props.title)
+        .size(props.size)
+        .fill(fill)
+        .disabled(props.disabled)
+        .disabled_fill(props.disabled_fill)
+        .icon(icon)
+        .icon_class(props.icon_class)
+        .sr_only(props.sr_only)
+        .aria_pressed(pressed);
+    for attr in props.attributes {
+        button = button.push_attribute(attr.name, attr.namespace, attr.value, attr.volatile);
+    }
+    let button = button.build();
+    rsx! {
+        IconButton { ..button }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        outline,
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape,
+    };
+
+    #[test]
+    fn toggle_icon_button_default() {
+        assert_rsx_eq(
+            rsx! {
+                ToggleIconButton {
+                    icon_on: outline::Shape::SpeakerXMark,
+                    icon_off: outline::Shape::SpeakerWave,
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    aria_pressed: "false",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::SpeakerWave.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn toggle_icon_button_pressed() {
+        assert_rsx_eq(
+            rsx! {
+                ToggleIconButton {
+                    icon_on: outline::Shape::SpeakerXMark,
+                    icon_off: outline::Shape::SpeakerWave,
+                    pressed: true,
+                    fill_on: "red",
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    aria_pressed: "true",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "red",
+                        { outline::Shape::SpeakerXMark.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn toggle_icon_button_bind() {
+        #[allow(non_snake_case)]
+        fn Mute() -> Element {
+            let muted = use_signal(|| true);
+            rsx! {
+                ToggleIconButton {
+                    icon_on: outline::Shape::SpeakerXMark,
+                    icon_off: outline::Shape::SpeakerWave,
+                    bind: muted,
+                    pressed: false,
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Mute {},
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    aria_pressed: "true",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::SpeakerXMark.path() },
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn toggle_icon_button_default_pressed() {
+        assert_rsx_eq(
+            rsx! {
+                ToggleIconButton {
+                    icon_on: outline::Shape::Eye,
+                    icon_off: outline::Shape::EyeSlash,
+                    default_pressed: true,
+                },
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    aria_pressed: "true",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::Eye.path() },
+                    },
+                },
+            },
+        );
+    }
+}
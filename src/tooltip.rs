@@ -0,0 +1,349 @@
+use crate::{
+    theme::{join_classes, use_icon_theme},
+    timer::sleep,
+    use_unique_id,
+};
+use dioxus::{
+    html::geometry::{PixelsRect, PixelsSize},
+    prelude::*,
+};
+use std::rc::Rc;
+
+/// Which side of its trigger a [`Tooltip`] is shown on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TooltipPlacement {
+    /// Above the trigger.
+    #[default]
+    Top,
+    /// Below the trigger.
+    Bottom,
+    /// To the left of the trigger.
+    Left,
+    /// To the right of the trigger.
+    Right,
+}
+
+impl TooltipPlacement {
+    /// Returns the placement on the other side of the trigger.
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        match self {
+            TooltipPlacement::Top => TooltipPlacement::Bottom,
+            TooltipPlacement::Bottom => TooltipPlacement::Top,
+            TooltipPlacement::Left => TooltipPlacement::Right,
+            TooltipPlacement::Right => TooltipPlacement::Left,
+        }
+    }
+
+    /// Returns the inline style that positions a tooltip on this side of its trigger, `offset`
+    /// pixels away from it.
+    fn style(self, offset: u32) -> String {
+        let position = match self {
+            TooltipPlacement::Top => format!(
+                "bottom: 100%; left: 50%; transform: translateX(-50%); margin-bottom: {offset}px;"
+            ),
+            TooltipPlacement::Bottom => {
+                format!(
+                    "top: 100%; left: 50%; transform: translateX(-50%); margin-top: {offset}px;"
+                )
+            }
+            TooltipPlacement::Left => format!(
+                "right: 100%; top: 50%; transform: translateY(-50%); margin-right: {offset}px;"
+            ),
+            TooltipPlacement::Right => {
+                format!(
+                    "left: 100%; top: 50%; transform: translateY(-50%); margin-left: {offset}px;"
+                )
+            }
+        };
+        format!(
+            "position: absolute; {position} z-index: 20; white-space: nowrap; \
+             pointer-events: none;"
+        )
+    }
+}
+
+/// The placement and timing options for the tooltip on an [`IconButton`](crate::IconButton). See
+/// [`TooltipProps`] for what each option does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TooltipOptions {
+    /// Which side of the trigger the tooltip is shown on. This defaults to
+    /// [`TooltipPlacement::Top`].
+    pub placement: TooltipPlacement,
+    /// How long to wait before showing the tooltip, in milliseconds. This defaults to 500.
+    pub show_delay_ms: u32,
+    /// How long to wait before hiding the tooltip, in milliseconds. This defaults to 0.
+    pub hide_delay_ms: u32,
+    /// The gap between the trigger and the tooltip, in pixels. This defaults to 8.
+    pub offset: u32,
+}
+
+impl Default for TooltipOptions {
+    fn default() -> Self {
+        Self {
+            placement: TooltipPlacement::default(),
+            show_delay_ms: 500,
+            hide_delay_ms: 0,
+            offset: 8,
+        }
+    }
+}
+
+/// The properties for the [`Tooltip`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct TooltipProps {
+    /// The text of the tooltip.
+    pub text: String,
+    /// The `id` of the tooltip. Give the trigger an `aria-describedby` with this `id` so that
+    /// screen readers read the tooltip. If this is not set then a unique `id` is generated.
+    #[props(default, strip_option)]
+    pub id: Option<String>,
+    /// Which side of the trigger the tooltip is shown on. If the tooltip would overflow the
+    /// viewport on that side, and it fits on the other side, then it's shown on the other side
+    /// instead. This defaults to [`TooltipPlacement::Top`].
+    #[props(default)]
+    pub placement: TooltipPlacement,
+    /// How long to wait before showing the tooltip, in milliseconds. This defaults to 500.
+    #[props(default = 500)]
+    pub show_delay_ms: u32,
+    /// How long to wait before hiding the tooltip, in milliseconds. This defaults to 0.
+    #[props(default = 0)]
+    pub hide_delay_ms: u32,
+    /// The gap between the trigger and the tooltip, in pixels. This defaults to 8.
+    #[props(default = 8)]
+    pub offset: u32,
+    /// An optional class for the tooltip. This is added after the `tooltip_class` from the
+    /// [`IconTheme`](crate::IconTheme).
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The element that shows the tooltip, usually a button.
+    pub children: Element,
+}
+
+/// Renders a tooltip that is shown while its trigger is hovered or focused.
+///
+/// The tooltip is shown after `show_delay_ms` and hidden after `hide_delay_ms`, or right away when
+/// `Escape` is pressed. It's always in the DOM, with the `hidden` attribute while it's not shown,
+/// so that screen readers can read it through the trigger's `aria-describedby`. The
+/// [`IconButton`](crate::IconButton) `tooltip` prop uses this and sets up `aria-describedby` for
+/// you.
+///
+/// Flipping to the other side when the tooltip would overflow the viewport needs the `wasm`
+/// feature.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <span style="position: relative; display: inline-block;">
+///   <button aria-describedby="tooltip-0">...</button>
+///   <span role="tooltip" id="tooltip-0" hidden="true" class="..." style="position: absolute; ...">
+///     Tooltip text goes here
+///   </span>
+/// </span>
+/// ```
+///
+/// See the [`TooltipProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn Tooltip(props: TooltipProps) -> Element {
+    let theme = use_icon_theme();
+    let generated_id = use_unique_id("tooltip");
+    let id = props.id.unwrap_or(generated_id);
+    let mut visible = use_signal(|| false);
+    let mut timer = use_signal(|| None::<Task>);
+    let mut flipped = use_signal(|| false);
+    let mut trigger = use_signal(|| None::<Rc<MountedData>>);
+    let mut tip = use_signal(|| None::<Rc<MountedData>>);
+    let preferred = props.placement;
+    let offset = props.offset;
+    let (show_delay, hide_delay) = (props.show_delay_ms, props.hide_delay_ms);
+
+    let mut set_visible = move |show: bool, delay: u32| {
+        if let Some(task) = timer.take() {
+            task.cancel();
+        }
+        if delay == 0 {
+            visible.set(show);
+        } else {
+            timer.set(Some(spawn(async move {
+                sleep(delay).await;
+                visible.set(show);
+            })));
+        }
+    };
+
+    // Once the tooltip is shown, measure it and flip it if it doesn't fit.
+    use_effect(move || {
+        if !visible() {
+            flipped.set(false);
+            return;
+        }
+        let (Some(trigger), Some(tip)) = (trigger.peek().clone(), tip.peek().clone()) else {
+            return;
+        };
+        spawn(async move {
+            let (Ok(trigger), Ok(tip), Some(viewport)) = (
+                trigger.get_client_rect().await,
+                tip.get_client_rect().await,
+                viewport_size(),
+            ) else {
+                return;
+            };
+            flipped.set(should_flip(preferred, trigger, tip.size, viewport, offset));
+        });
+    });
+
+    let placement = if flipped() {
+        preferred.opposite()
+    } else {
+        preferred
+    };
+    let class = join_classes([Some(theme.tooltip_class.as_str()), props.class.as_deref()]);
+    rsx! {
+        span {
+            style: "position: relative; display: inline-block;",
+            onmounted: move |evt| trigger.set(Some(evt.data())),
+            onmouseenter: move |_| set_visible(true, show_delay),
+            onmouseleave: move |_| set_visible(false, hide_delay),
+            onfocusin: move |_| set_visible(true, show_delay),
+            onfocusout: move |_| set_visible(false, hide_delay),
+            onkeydown: move |evt| {
+                if evt.key() == Key::Escape {
+                    set_visible(false, 0);
+                }
+            },
+            { props.children },
+            span {
+                role: "tooltip",
+                id,
+                hidden: !visible(),
+                class: if let Some(class) = class { class },
+                style: placement.style(offset),
+                onmounted: move |evt| tip.set(Some(evt.data())),
+                "{props.text}"
+            },
+        }
+    }
+}
+
+/// Returns true if a tooltip should be flipped to the opposite side of its trigger, because it
+/// overflows the viewport on the `preferred` side and fits on the other side.
+fn should_flip(
+    preferred: TooltipPlacement,
+    trigger: PixelsRect,
+    tip: PixelsSize,
+    viewport: PixelsSize,
+    offset: u32,
+) -> bool {
+    let offset = f64::from(offset);
+    let fits = |placement: TooltipPlacement| match placement {
+        TooltipPlacement::Top => trigger.min_y() - offset - tip.height >= 0.0,
+        TooltipPlacement::Bottom => trigger.max_y() + offset + tip.height <= viewport.height,
+        TooltipPlacement::Left => trigger.min_x() - offset - tip.width >= 0.0,
+        TooltipPlacement::Right => trigger.max_x() + offset + tip.width <= viewport.width,
+    };
+    !fits(preferred) && fits(preferred.opposite())
+}
+
+/// Returns the size of the browser's viewport.
+#[cfg(feature = "wasm")]
+fn viewport_size() -> Option<PixelsSize> {
+    let window = web_sys::window()?;
+    Some(PixelsSize::new(
+        window.inner_width().ok()?.as_f64()?,
+        window.inner_height().ok()?.as_f64()?,
+    ))
+}
+
+#[cfg(not(feature = "wasm"))]
+fn viewport_size() -> Option<PixelsSize> {
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::assert_rsx_eq;
+    use dioxus::html::geometry::euclid::{Point2D, Size2D};
+
+    #[test]
+    fn tooltip_hidden() {
+        assert_rsx_eq(
+            rsx! {
+                Tooltip {
+                    text: "Save",
+                    id: "save-tip",
+                    placement: TooltipPlacement::Right,
+                    offset: 4,
+                    button {
+                        aria_describedby: "save-tip",
+                        "Save"
+                    },
+                },
+            },
+            rsx! {
+                span {
+                    style: "position: relative; display: inline-block;",
+                    button {
+                        aria_describedby: "save-tip",
+                        "Save"
+                    },
+                    span {
+                        role: "tooltip",
+                        id: "save-tip",
+                        hidden: true,
+                        class: "rounded bg-gray-900 px-2 py-1 text-xs text-white",
+                        style: "position: absolute; left: 100%; top: 50%; transform: translateY(-50%); margin-left: 4px; z-index: 20; white-space: nowrap; pointer-events: none;",
+                        "Save"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn flipping() {
+        let viewport = Size2D::new(800.0, 600.0);
+        let tip = Size2D::new(60.0, 24.0);
+        let near_top = PixelsRect::new(Point2D::new(100.0, 10.0), Size2D::new(32.0, 32.0));
+        let middle = PixelsRect::new(Point2D::new(400.0, 300.0), Size2D::new(32.0, 32.0));
+        let near_right = PixelsRect::new(Point2D::new(760.0, 300.0), Size2D::new(32.0, 32.0));
+        assert!(should_flip(
+            TooltipPlacement::Top,
+            near_top,
+            tip,
+            viewport,
+            8
+        ));
+        assert!(!should_flip(
+            TooltipPlacement::Bottom,
+            near_top,
+            tip,
+            viewport,
+            8
+        ));
+        assert!(!should_flip(
+            TooltipPlacement::Top,
+            middle,
+            tip,
+            viewport,
+            8
+        ));
+        assert!(should_flip(
+            TooltipPlacement::Right,
+            near_right,
+            tip,
+            viewport,
+            8
+        ));
+        // If it doesn't fit on either side then it stays where it was asked to be.
+        let huge = Size2D::new(60.0, 400.0);
+        assert!(!should_flip(
+            TooltipPlacement::Top,
+            middle,
+            huge,
+            viewport,
+            8
+        ));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{outline, ToggleIconButton};
+use dioxus::prelude::*;
+
+/// The properties for the [`VolumeButton`] component.
+#[derive(Clone, PartialEq, Props)]
+pub struct VolumeButtonProps {
+    /// The current volume, from 0.0 to 1.0.
+    pub volume: f32,
+    /// Whether the sound is muted.
+    pub muted: bool,
+    /// The handler that is called with the new muted state when the button is clicked.
+    pub onmutechange: EventHandler<bool>,
+    /// The accessible label for the button. This is also used as its title. This defaults to
+    /// "Mute".
+    #[props(default = "Mute".to_string())]
+    pub label: String,
+    /// An optional class for the button.
+    #[props(default, strip_option)]
+    pub class: Option<String>,
+    /// The size of the icon. This defaults to 20 pixels.
+    #[props(default = 20)]
+    pub size: u32,
+}
+
+/// Renders a mute button for an audio or video player.
+///
+/// The button shows an [`outline::Shape::SpeakerXMark`] when the sound is muted or the volume is 0,
+/// and an [`outline::Shape::SpeakerWave`] otherwise. It's a [`ToggleIconButton`], so whether the
+/// sound is muted is exposed with `aria-pressed`.
+///
+/// This component will generate HTML like this:
+///
+/// ```html
+/// <button title="Mute" aria-pressed="false" type="button">
+///   <svg ...>
+///   <span style="...">Mute</span>
+/// </button>
+/// ```
+///
+/// See the [`VolumeButtonProps`] field documentation for details on the properties it accepts.
+#[allow(clippy::missing_errors_doc, non_snake_case)]
+#[component]
+pub fn VolumeButton(props: VolumeButtonProps) -> Element {
+    rsx! {
+        ToggleIconButton {
+            icon_on: outline::Shape::SpeakerXMark,
+            icon_off: unmuted_shape(props.volume),
+            pressed: props.muted,
+            onchange: props.onmutechange,
+            class: props.class,
+            title: props.label.clone(),
+            size: props.size,
+            sr_only: props.label,
+            r#type: "button",
+        }
+    }
+}
+
+/// Returns the icon for a volume level when the sound isn't muted.
+fn unmuted_shape(volume: f32) -> outline::Shape {
+    if volume <= 0.0 {
+        outline::Shape::SpeakerXMark
+    } else {
+        outline::Shape::SpeakerWave
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        test::{assert_rsx_eq, FOCUS_CLASS},
+        IconShape, SR_ONLY_STYLE,
+    };
+
+    #[test]
+    fn volume_button() {
+        #[allow(non_snake_case)]
+        fn Volume() -> Element {
+            rsx! {
+                VolumeButton {
+                    volume: 0.5,
+                    muted: false,
+                    onmutechange: |_| {},
+                },
+            }
+        }
+
+        assert_rsx_eq(
+            rsx! {
+                Volume {},
+            },
+            rsx! {
+                button {
+                    class: FOCUS_CLASS,
+                    title: "Mute",
+                    aria_pressed: "false",
+                    r#type: "button",
+                    svg {
+                        height: 20,
+                        width: 20,
+                        view_box: outline::VIEW_BOX,
+                        fill: "currentColor",
+                        { outline::Shape::SpeakerWave.path() },
+                    },
+                    span {
+                        style: SR_ONLY_STYLE,
+                        "Mute"
+                    },
+                },
+            },
+        );
+    }
+
+    #[test]
+    fn volume_shapes() {
+        assert_eq!(unmuted_shape(0.5), outline::Shape::SpeakerWave);
+        assert_eq!(unmuted_shape(0.0), outline::Shape::SpeakerXMark);
+    }
+}
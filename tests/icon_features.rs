@@ -0,0 +1,48 @@
+//! Checks that the icon features in each manifest, which the `gen` crate writes, match the shapes
+//! that the generated code gates on them, so that a feature can't be missing or left over.
+
+#![cfg(all(
+    feature = "all-icons",
+    feature = "components",
+    feature = "mini",
+    feature = "outline",
+    feature = "solid"
+))]
+
+use dioxus_heroicons::{mini, outline, solid, IconSet};
+use std::{collections::BTreeSet, fs, path::Path};
+
+// Returns the icon features between the generated markers in a manifest.
+fn icon_features(manifest: &Path) -> BTreeSet<String> {
+    heroicons_codegen::icon_features(&fs::read_to_string(manifest).unwrap())
+}
+
+fn names<S: IconSet>() -> BTreeSet<String> {
+    S::all().iter().map(|s| s.name().to_string()).collect()
+}
+
+#[test]
+fn style_features() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for (style, shapes) in [
+        ("mini", names::<mini::Shape>()),
+        ("outline", names::<outline::Shape>()),
+        ("solid", names::<solid::Shape>()),
+    ] {
+        let manifest = root.join("crates").join(style).join("Cargo.toml");
+        assert_eq!(
+            icon_features(&manifest),
+            shapes,
+            "the icon features for the {style} crate",
+        );
+    }
+}
+
+#[test]
+fn main_features() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut shapes = names::<mini::Shape>();
+    shapes.extend(names::<outline::Shape>());
+    shapes.extend(names::<solid::Shape>());
+    assert_eq!(icon_features(&root.join("Cargo.toml")), shapes);
+}